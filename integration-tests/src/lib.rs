@@ -0,0 +1,11 @@
+//! Cross-pallet integration tests
+//!
+//! Wires reputation, DKG integration, governance and the trust layer into one
+//! mock runtime through the same adapters the DotRep runtime uses, so interface
+//! drift between the pallets fails here rather than in a live chain.
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,601 @@
+use frame_support::{
+    ord_parameter_types, parameter_types,
+    traits::{AsEnsureOriginWithArg, ConstU32, ConstU64, GenesisBuild, Randomness},
+    PalletId,
+};
+use frame_system::{offchain::AppCrypto, EnsureRoot, EnsureSigned, EnsureSignedBy};
+use pallet_reputation::dkg_integration;
+use sp_core::H256;
+use sp_runtime::{
+    testing::{Header, TestSignature, TestXt, UintAuthorityId},
+    traits::{BlakeTwo256, Hash, IdentityLookup},
+    BuildStorage, Perbill, Percent,
+};
+
+// Set up mock types for simplicity
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// All DotRep pallets, composed as in the runtime
+frame_support::construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system,
+        Timestamp: pallet_timestamp,
+        Balances: pallet_balances,
+        Assets: pallet_assets,
+        Reputation: pallet_reputation,
+        DkgIntegration: dkg_integration,
+        Governance: pallet_governance,
+        TrustLayer: pallet_trust_layer,
+    }
+);
+
+// Constants for testing
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const ExistentialDeposit: u64 = 1;
+}
+
+// System pallet configuration
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<u64>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<u64>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+// Balances pallet configuration
+impl pallet_balances::Config for Test {
+    type MaxLocks = ();
+    type MaxReserves = ConstU32<10>;
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u64;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+}
+
+// Timestamp pallet configuration
+impl pallet_timestamp::Config for Test {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = ConstU64<5>;
+    type WeightInfo = ();
+}
+
+// Assets pallet configuration (TRAC stand-in)
+impl pallet_assets::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = u64;
+    type AssetId = u32;
+    type AssetIdParameter = u32;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<u64>>;
+    type ForceOrigin = EnsureRoot<u64>;
+    type AssetDeposit = ConstU64<1>;
+    type AssetAccountDeposit = ConstU64<1>;
+    type MetadataDepositBase = ConstU64<1>;
+    type MetadataDepositPerByte = ConstU64<1>;
+    type ApprovalDeposit = ConstU64<1>;
+    type StringLimit = ConstU32<50>;
+    type Freezer = ();
+    type Extra = ();
+    type WeightInfo = ();
+    type RemoveItemsLimit = ConstU32<1000>;
+    type CallbackHandle = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
+// Reputation pallet configuration; staking boosts and offences come from the trust layer
+parameter_types! {
+    pub const MaxContributionsPerAccount: u32 = 100;
+    pub const MinReputation: i32 = -1_000;
+    pub const MaxReputation: i32 = 10_000;
+    pub const MinReputationToVerify: i32 = 100;
+    pub const MinVerifications: u32 = 1;
+    pub const MaxPendingContributions: u32 = 10;
+    pub const MaxCredibilityBoost: u32 = 50;
+    pub const MaxAttestationBoost: u32 = 25;
+    pub const ClaimUpheldReward: i32 = 25;
+    pub const ClaimRejectedPenalty: i32 = 40;
+    pub const ProxySubmissionPeriod: u64 = 10;
+    pub const MaintainerAttestationWeight: u32 = 3;
+    pub const SkillMatchMultiplier: u32 = 2;
+    pub const MaxHackathonProjects: u32 = 4;
+    pub const MaxHackathonWinners: u32 = 3;
+    pub const MentorshipSessionWeight: u8 = 50;
+    pub const ReferralBonus: i32 = 5;
+    pub const ReferralQualifyingContributions: u32 = 2;
+    pub const ReferralEpoch: u64 = 100;
+    pub const MaxReferralsPerEpoch: u32 = 2;
+    pub const ProbationPeriod: u64 = 50;
+    pub const FeelessReputationThreshold: i32 = 100;
+    pub const FeelessCallsPerEra: u32 = 2;
+    pub const FeelessEra: u64 = 100;
+    pub const CommitteeVerificationThreshold: u8 = 100;
+    pub const MinCommitteeApprovals: u32 = 3;
+    pub const MaxCommitteeSize: u32 = 5;
+    pub const DividendPalletId: PalletId = PalletId(*b"dr/divdd");
+    pub const DividendBudget: u64 = 1_000;
+    pub const DividendEra: u64 = 100;
+    pub const DividendEarningsCap: i32 = 50;
+    pub const VerificationDeadline: u64 = 50;
+    pub const EscalationPeriod: u64 = 50;
+    pub const StaleVerifierReward: i32 = 5;
+    pub const VerifierEra: u64 = 100;
+    pub const ReminderLead: u64 = 10;
+    pub const DisputeWindow: u64 = 100;
+    pub const MaxDeadlinesPerBlock: u32 = 16;
+    pub const RejectionThreshold: u32 = 2;
+    pub const RejectedDepositSlash: Perbill = Perbill::from_percent(50);
+    pub const OutlierDeviation: u8 = 30;
+    pub const AccurateDeviation: u8 = 10;
+    pub const AccuracyBonus: i32 = 1;
+    pub const OutlierPenalty: i32 = 2;
+    pub const MaxOutlierStrikes: u32 = 2;
+    pub const MaxLeaveDuration: u64 = 100_000;
+    pub const LeaveCooldown: u64 = 200_000;
+    pub const MaxLeavesRecorded: u32 = 2;
+    pub const SnapshotEpoch: u64 = 0;
+    pub const SnapshotTopK: u32 = 5;
+    pub const MaxSnapshotAccounts: u32 = 1_000;
+    pub const EvmAttestationValidity: u64 = 3_600;
+    pub const MaxGitLogEntries: u32 = 8;
+    pub const GitImportPointsPercent: Percent = Percent::from_percent(50);
+    pub const MaxImportBatch: u32 = 10;
+    pub const ImportedPointsPercent: Percent = Percent::from_percent(25);
+    pub const StarterGrantPeriod: u64 = 100;
+    pub const MaxStarterGrantsPerPeriod: u32 = 2;
+    pub const DecaySweepAccountsPerBlock: u32 = 0;
+    pub const DecayWarningWindow: u64 = 1_000;
+    pub const DecayWarningThreshold: Percent = Percent::from_percent(10);
+    pub const RemoteReputationTtl: u64 = 100;
+    pub const ContributionDeposit: u64 = 10;
+    // Integration flows verify full-weight contributions without maintainers
+    pub const MaintainerAttestationThreshold: u8 = 100;
+    pub const MaxCoAuthors: u32 = 4;
+    pub const ProjectClaimExpiry: u64 = 100;
+}
+
+impl pallet_reputation::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type Time = Timestamp;
+    type WeightInfo = ();
+    type MaxContributionsPerAccount = MaxContributionsPerAccount;
+    type MinReputation = MinReputation;
+    type MaxReputation = MaxReputation;
+    type MinReputationToVerify = MinReputationToVerify;
+    type MinVerifications = MinVerifications;
+    type MaxPendingContributions = MaxPendingContributions;
+    type UpdateOrigin = EnsureRoot<u64>;
+    type PauseOrigin = EnsureRoot<u64>;
+    type OnOffence = TrustLayer;
+    type CredibilityBoost = TrustLayer;
+    type MaxCredibilityBoost = MaxCredibilityBoost;
+    type MaxAttestationBoost = MaxAttestationBoost;
+    type ClaimUpheldReward = ClaimUpheldReward;
+    type ClaimRejectedPenalty = ClaimRejectedPenalty;
+    type ProxySubmissionPeriod = ProxySubmissionPeriod;
+    type MaintainerAttestationWeight = MaintainerAttestationWeight;
+    type MaintainerAttestationThreshold = MaintainerAttestationThreshold;
+    // Repository ownership is confirmed by the DKG publishers, who also attest GitHub handles
+    type OwnershipAuthorityId = TestDkgPublisherId;
+    type OwnershipVerifierOrigin = EnsureSignedBy<DkgPublisher, u64>;
+    type ProjectClaimExpiry = ProjectClaimExpiry;
+    // Co-authors must have a publisher-attested GitHub handle
+    type Bindings = DkgIntegration;
+    type MaxCoAuthors = MaxCoAuthors;
+    type SkillMatchMultiplier = SkillMatchMultiplier;
+    type ContributionDeposit = ContributionDeposit;
+    type Disputes = TrustLayer;
+    type Dkg = DkgIntegration;
+    type MaxHackathonProjects = MaxHackathonProjects;
+    type MaxHackathonWinners = MaxHackathonWinners;
+    type MentorshipSessionWeight = MentorshipSessionWeight;
+    type ReferralBonus = ReferralBonus;
+    type ReferralQualifyingContributions = ReferralQualifyingContributions;
+    type ReferralEpoch = ReferralEpoch;
+    type MaxReferralsPerEpoch = MaxReferralsPerEpoch;
+    type ProbationPeriod = ProbationPeriod;
+    type FeelessReputationThreshold = FeelessReputationThreshold;
+    type FeelessCallsPerEra = FeelessCallsPerEra;
+    type FeelessEra = FeelessEra;
+    type CommitteeVerificationThreshold = CommitteeVerificationThreshold;
+    type MinCommitteeApprovals = MinCommitteeApprovals;
+    type MaxCommitteeSize = MaxCommitteeSize;
+    type DividendPalletId = DividendPalletId;
+    type DividendBudget = DividendBudget;
+    type DividendEra = DividendEra;
+    type DividendEarningsCap = DividendEarningsCap;
+    type VerificationDeadline = VerificationDeadline;
+    type EscalationPeriod = EscalationPeriod;
+    type StaleVerifierReward = StaleVerifierReward;
+    type VerifierEra = VerifierEra;
+    type ReminderLead = ReminderLead;
+    type DisputeWindow = DisputeWindow;
+    type MaxDeadlinesPerBlock = MaxDeadlinesPerBlock;
+    type RejectionThreshold = RejectionThreshold;
+    type RejectedDepositSlash = RejectedDepositSlash;
+    type Slash = ();
+    type OutlierDeviation = OutlierDeviation;
+    type AccurateDeviation = AccurateDeviation;
+    type AccuracyBonus = AccuracyBonus;
+    type OutlierPenalty = OutlierPenalty;
+    type MaxOutlierStrikes = MaxOutlierStrikes;
+    type MaxLeaveDuration = MaxLeaveDuration;
+    type LeaveCooldown = LeaveCooldown;
+    type MaxLeavesRecorded = MaxLeavesRecorded;
+    type SnapshotEpoch = SnapshotEpoch;
+    type SnapshotTopK = SnapshotTopK;
+    type MaxSnapshotAccounts = MaxSnapshotAccounts;
+    type EvmAttestationAuthorityId = TestDkgPublisherId;
+    type EvmAttestationValidity = EvmAttestationValidity;
+    type MaxGitLogEntries = MaxGitLogEntries;
+    type GitImportPointsPercent = GitImportPointsPercent;
+    type MaxImportBatch = MaxImportBatch;
+    type ImportedPointsPercent = ImportedPointsPercent;
+    type Personhood = ();
+    type StarterGrantPeriod = StarterGrantPeriod;
+    type MaxStarterGrantsPerPeriod = MaxStarterGrantsPerPeriod;
+    type DecaySweepAccountsPerBlock = DecaySweepAccountsPerBlock;
+    type DecayWarningWindow = DecayWarningWindow;
+    type DecayWarningThreshold = DecayWarningThreshold;
+    type RemoteReputationTtl = RemoteReputationTtl;
+    type ChainOrigin = frame_system::EnsureNever<pallet_reputation::ChainId>;
+}
+
+// DKG integration configuration: the OCW signs with UintAuthorityId keys
+pub type Extrinsic = TestXt<RuntimeCall, ()>;
+
+pub const DKG_PUBLISHER: u64 = 100;
+
+ord_parameter_types! {
+    pub const DkgPublisher: u64 = DKG_PUBLISHER;
+}
+
+parameter_types! {
+    pub const MaxPublishesPerBlock: u32 = 5;
+    pub const MaxPublishAttempts: u32 = 3;
+    pub const RetryBackoff: u64 = 2;
+}
+
+pub struct TestDkgPublisherId;
+impl AppCrypto<UintAuthorityId, TestSignature> for TestDkgPublisherId {
+    type RuntimeAppPublic = UintAuthorityId;
+    type GenericSignature = TestSignature;
+    type GenericPublic = UintAuthorityId;
+}
+
+impl frame_system::offchain::SigningTypes for Test {
+    type Public = UintAuthorityId;
+    type Signature = TestSignature;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Test
+where
+    RuntimeCall: From<C>,
+{
+    type OverarchingCall = RuntimeCall;
+    type Extrinsic = Extrinsic;
+}
+
+impl<C> frame_system::offchain::CreateSignedTransaction<C> for Test
+where
+    RuntimeCall: From<C>,
+{
+    fn create_transaction<A: AppCrypto<Self::Public, Self::Signature>>(
+        call: RuntimeCall,
+        _public: UintAuthorityId,
+        account: u64,
+        _nonce: u64,
+    ) -> Option<(RuntimeCall, (u64, ()))> {
+        Some((call, (account, ())))
+    }
+}
+
+impl dkg_integration::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type AuthorityId = TestDkgPublisherId;
+    type PublisherOrigin = EnsureSignedBy<DkgPublisher, u64>;
+    type MaxPublishesPerBlock = MaxPublishesPerBlock;
+    type Snapshot = Reputation;
+    type Reputation = Reputation;
+    type UpdateOrigin = EnsureRoot<u64>;
+    type MaxPublishAttempts = MaxPublishAttempts;
+    type RetryBackoff = RetryBackoff;
+}
+
+// Governance pallet configuration
+parameter_types! {
+    pub const MinProposalReputation: i32 = 25;
+    pub const ProposalDeposit: u64 = 1_000;
+    pub const VotingPeriod: u64 = 100;
+    pub const CouncilSize: u32 = 7;
+    pub const QuorumThreshold: u8 = 10;
+    pub const SupermajorityThreshold: u8 = 66;
+    pub const ExecutionDelayPeriod: u64 = 10;
+    pub const MinVoteChangePeriod: u64 = 5;
+    pub const GrantsPalletId: PalletId = PalletId(*b"dr/grant");
+    pub const MaxMilestones: u32 = 4;
+    pub const MilestoneContributionWeight: u8 = 100;
+    pub const MaxDelegationsPerDelegatee: u32 = 10;
+    pub const CouncilSalaryPerBlock: u64 = 1;
+    pub const CouncilTreasury: u64 = TREASURY;
+    pub const MinCouncilVotesPerTerm: u32 = 1;
+    pub const ProposalRetentionPeriod: u64 = 100;
+    pub const ProposalCleanupReward: u64 = 5;
+    pub const MaxVotesPrunedPerCleanup: u32 = 10;
+    pub const EndorsementReputation: i32 = 0;
+    pub const RequiredEndorsements: u32 = 0;
+    pub const EndorsementDeposit: u64 = 100;
+    pub const PendingProposalExpiry: u64 = 50;
+}
+
+impl pallet_governance::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type Reputation = Reputation;
+    type MinProposalReputation = MinProposalReputation;
+    type ProposalDeposit = ProposalDeposit;
+    type VotingPeriod = VotingPeriod;
+    type CouncilSize = CouncilSize;
+    type QuorumThreshold = QuorumThreshold;
+    type SupermajorityThreshold = SupermajorityThreshold;
+    type ExecutionDelayPeriod = ExecutionDelayPeriod;
+    type MinVoteChangePeriod = MinVoteChangePeriod;
+    type OnSkillTagsUpdated = Reputation;
+    type Contributions = Reputation;
+    type PalletId = GrantsPalletId;
+    type MaxMilestones = MaxMilestones;
+    type MilestoneContributionWeight = MilestoneContributionWeight;
+    type MaxDelegationsPerDelegatee = MaxDelegationsPerDelegatee;
+    type CustomProposalHandler = ();
+    type CouncilSalaryPerBlock = CouncilSalaryPerBlock;
+    type Treasury = CouncilTreasury;
+    type MinCouncilVotesPerTerm = MinCouncilVotesPerTerm;
+    type TemplateOrigin = EnsureRoot<u64>;
+    type TrackOrigin = EnsureRoot<u64>;
+    type ProposalRetentionPeriod = ProposalRetentionPeriod;
+    type ProposalCleanupReward = ProposalCleanupReward;
+    type MaxVotesPrunedPerCleanup = MaxVotesPrunedPerCleanup;
+    type EndorsementReputation = EndorsementReputation;
+    type RequiredEndorsements = RequiredEndorsements;
+    type EndorsementDeposit = EndorsementDeposit;
+    type PendingProposalExpiry = PendingProposalExpiry;
+    type SpamOrigin = EnsureRoot<u64>;
+    type HookQuotas = Reputation;
+    type CouncilMembershipChanged = ();
+}
+
+/// An account owns the UAL of its published reputation asset
+pub struct DkgUalOwnership;
+impl pallet_trust_layer::UalOwnership<u64> for DkgUalOwnership {
+    fn owns_ual(who: &u64, ual: &[u8]) -> bool {
+        DkgIntegration::developer_ual(who).map_or(false, |owned| owned[..] == *ual)
+    }
+}
+
+// Deterministic randomness derived from the subject
+pub struct TestRandomness;
+impl Randomness<H256, u64> for TestRandomness {
+    fn random(subject: &[u8]) -> (H256, u64) {
+        (BlakeTwo256::hash(subject), 0)
+    }
+}
+
+// Trust layer configuration; claim verdicts and juror eligibility use real reputation
+parameter_types! {
+    pub const MinimumStake: u64 = 100;
+    pub const BaseQueryPrice: u64 = 10;
+    pub const ProviderShare: Perbill = Perbill::from_percent(70);
+    pub const TrustLayerPalletId: PalletId = PalletId(*b"dr/trust");
+    pub const OffenceSlash: Perbill = Perbill::from_percent(50);
+    pub const JurySize: u32 = 3;
+    pub const MaxJurySize: u32 = 7;
+    pub const MaxAppealRounds: u32 = 1;
+    pub const AppealPeriod: u64 = 5;
+    pub const MaxEvidenceItems: u32 = 4;
+    pub const MaxClaimsPerSubmitter: u32 = 2;
+    pub const MaxClaimsSweptPerBlock: u32 = 1;
+    pub const MaxQueryBatch: u32 = 5;
+    pub const BatchDiscount: Perbill = Perbill::from_percent(10);
+    pub const WinnerShare: Perbill = Perbill::from_percent(50);
+    pub const MeteredQueryFee: u64 = 5;
+    pub const MaxInvoiceItems: u32 = 16;
+    pub const BillingPeriod: u64 = 200;
+    pub const BillingStatementRetention: u32 = 2;
+    pub const MaxJurorPool: u32 = 10;
+    pub const MinJurorReputation: i32 = 100;
+    pub const JurorStake: u64 = 50;
+    pub const JuryVotingPeriod: u64 = 10;
+    pub const JurySeedPeriod: u64 = 10;
+    pub const JurorSlash: Perbill = Perbill::from_percent(20);
+    pub const BackerShare: Perbill = Perbill::from_percent(10);
+    pub const MaxBackers: u32 = 16;
+    pub const FraudReportWindow: u64 = 50;
+}
+
+impl pallet_trust_layer::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type MinimumStake = MinimumStake;
+    type BaseQueryPrice = BaseQueryPrice;
+    type UalOwnership = DkgUalOwnership;
+    type ProviderShare = ProviderShare;
+    type AssetId = u32;
+    type Assets = Assets;
+    type PalletId = TrustLayerPalletId;
+    type OffenceSlash = OffenceSlash;
+    type Reputation = Reputation;
+    type OnClaimResolved = Reputation;
+    type Contributions = Reputation;
+    type FraudReportWindow = FraudReportWindow;
+    type HookQuotas = Reputation;
+    type Time = Timestamp;
+    type Randomness = TestRandomness;
+    type JurySize = JurySize;
+    type MaxJurySize = MaxJurySize;
+    type MaxAppealRounds = MaxAppealRounds;
+    type AppealPeriod = AppealPeriod;
+    type MaxEvidenceItems = MaxEvidenceItems;
+    type MaxClaimsPerSubmitter = MaxClaimsPerSubmitter;
+    type MaxClaimsSweptPerBlock = MaxClaimsSweptPerBlock;
+    type MaxQueryBatch = MaxQueryBatch;
+    type BatchDiscount = BatchDiscount;
+    type MeteredQueryFee = MeteredQueryFee;
+    type MaxInvoiceItems = MaxInvoiceItems;
+    type BillingPeriod = BillingPeriod;
+    type BillingStatementRetention = BillingStatementRetention;
+    type Slash = ();
+    type WinnerShare = WinnerShare;
+    type MaxJurorPool = MaxJurorPool;
+    type MinJurorReputation = MinJurorReputation;
+    type JurorStake = JurorStake;
+    type JuryVotingPeriod = JuryVotingPeriod;
+    type JurySeedPeriod = JurySeedPeriod;
+    type JurorSlash = JurorSlash;
+    type BackerShare = BackerShare;
+    type MaxBackers = MaxBackers;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = TestBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TestBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_trust_layer::BenchmarkHelper<u32, u64, u64> for TestBenchmarkHelper {
+    fn funded_asset(who: &u64, amount: u64) -> u32 {
+        <Assets as frame_support::traits::fungibles::Mutate<u64>>::mint_into(TRAC, who, amount)
+            .expect("TRAC exists");
+        TRAC
+    }
+
+    fn owned_ual(who: &u64) -> Vec<u8> {
+        let ual = who.to_le_bytes().to_vec();
+        dkg_integration::DeveloperUAL::<Test>::insert(who, frame_support::BoundedVec::truncate_from(ual.clone()));
+        ual
+    }
+
+    fn qualify_juror(who: &u64) {
+        pallet_reputation::ReputationScores::<Test>::insert(who, MinJurorReputation::get());
+    }
+}
+
+// Test accounts
+pub const ALICE: u64 = 1;
+pub const BOB: u64 = 2;
+pub const CHARLIE: u64 = 3;
+pub const VERIFIER: u64 = 4;
+pub const TREASURY: u64 = 99;
+pub const TRAC: u32 = 1;
+
+/// Starting reputation of the reviewers; contributors start from zero
+pub const REVIEWER_REPUTATION: i32 = 400;
+
+/// Block at which the genesis council's term ends
+pub const COUNCIL_TERM_END: u64 = 100;
+
+// Genesis storage initialization for tests
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![
+            (ALICE, 1_000_000),
+            (BOB, 1_000_000),
+            (CHARLIE, 1_000_000),
+            (VERIFIER, 1_000_000),
+            (TREASURY, 1_000),
+        ],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    pallet_assets::GenesisConfig::<Test> {
+        assets: vec![(TRAC, TREASURY, true, 1)],
+        metadata: vec![],
+        accounts: vec![(TRAC, BOB, 1_000_000), (TRAC, TREASURY, 1_000)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    GenesisBuild::<Test>::assimilate_storage(
+        &pallet_reputation::GenesisConfig::<Test> {
+            initial_reputation: vec![(CHARLIE, REVIEWER_REPUTATION), (VERIFIER, REVIEWER_REPUTATION)],
+            pipeline: Default::default(),
+        },
+        &mut t,
+    )
+    .unwrap();
+
+    GenesisBuild::<Test>::assimilate_storage(
+        &pallet_governance::GenesisConfig::<Test> {
+            council: vec![CHARLIE, VERIFIER],
+            council_term_end: COUNCIL_TERM_END,
+        },
+        &mut t,
+    )
+    .unwrap();
+
+    GenesisBuild::<Test>::assimilate_storage(
+        &pallet_trust_layer::GenesisConfig::<Test> {
+            treasury: Some(TREASURY),
+            assets: vec![(TRAC, MinimumStake::get(), BaseQueryPrice::get())],
+            pricing: None,
+            challenge_window: None,
+        },
+        &mut t,
+    )
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        // Parameter changes need a template
+        Governance::set_parameter_template(
+            RuntimeOrigin::root(),
+            pallet_governance::ParameterName::truncate_from(b"MinVerifications".to_vec()),
+            Some(pallet_governance::ParameterTemplate {
+                current: 1,
+                max_change: sp_runtime::Percent::from_percent(100),
+                min_step: 1,
+                min: 1,
+                max: 10,
+                tags: Default::default(),
+            }),
+        )
+        .unwrap();
+    });
+    ext
+}
@@ -0,0 +1,235 @@
+#[cfg(test)]
+mod tests {
+    use crate::mock::*;
+    use frame_support::{assert_noop, assert_ok, traits::Currency, BoundedVec};
+    use pallet_governance::ProposalType;
+    use pallet_reputation::{ContributionType, DataSource};
+    use sp_core::H256;
+
+    const UAL: &[u8] = b"did:dkg:otp/2043/0xabc/1";
+
+    // Submit a pull request as `contributor` and have VERIFIER approve it
+    fn verified_pull_request(contributor: u64, proof: u64) {
+        assert_ok!(Reputation::add_contribution(
+            RuntimeOrigin::signed(contributor),
+            H256::from_low_u64_be(proof),
+            ContributionType::PullRequest,
+            100,
+            DataSource::GitHub,
+            Default::default(),
+        ));
+        let contribution_id = pallet_reputation::NextContributionId::<Test>::get();
+        assert_ok!(Reputation::verify_contribution(
+            RuntimeOrigin::signed(VERIFIER),
+            contributor,
+            contribution_id,
+            100,
+            b"LGTM".to_vec(),
+        ));
+    }
+
+    type DispatchResultWithId = Result<pallet_governance::ProposalId, sp_runtime::DispatchError>;
+
+    // Create a parameter-change proposal as `proposer`, returning its id
+    fn propose(proposer: u64) -> DispatchResultWithId {
+        Governance::propose_parameter_change(
+            RuntimeOrigin::signed(proposer),
+            pallet_governance::ParameterName::truncate_from(b"MinVerifications".to_vec()),
+            2,
+            BoundedVec::truncate_from(b"Require two verifications".to_vec()),
+        )
+        .map(|_| pallet_governance::NextProposalId::<Test>::get() - 1)
+    }
+
+    // Publish `who`'s reputation to the DKG under `ual`, as the off-chain worker would
+    fn publish(who: u64, ual: &[u8]) {
+        assert_ok!(DkgIntegration::queue_for_publishing(RuntimeOrigin::root(), who));
+        assert_ok!(DkgIntegration::confirm_publish(
+            RuntimeOrigin::signed(DKG_PUBLISHER),
+            who,
+            ual.to_vec(),
+            b"0x01".to_vec(),
+        ));
+    }
+
+    #[test]
+    fn verified_contributions_unlock_proposals() {
+        new_test_ext().execute_with(|| {
+            assert_noop!(propose(ALICE), pallet_governance::Error::<Test>::InsufficientReputation);
+
+            // PullRequest weight 20 x 1.5 verification multiplier at full contribution weight
+            verified_pull_request(ALICE, 1);
+            assert_eq!(Reputation::get_reputation(&ALICE), 30);
+
+            let proposal_id = propose(ALICE).expect("reputation now meets the threshold");
+            assert_eq!(Balances::reserved_balance(ALICE), ProposalDeposit::get());
+            assert_eq!(Governance::proposals(proposal_id).unwrap().proposer, ALICE);
+        });
+    }
+
+    #[test]
+    fn staking_boost_raises_voting_power() {
+        new_test_ext().execute_with(|| {
+            verified_pull_request(ALICE, 1);
+            let proposal_id = propose(ALICE).unwrap();
+
+            // Quadratic voting on the raw reputation: sqrt(400)
+            assert_ok!(Governance::vote(RuntimeOrigin::signed(CHARLIE), proposal_id, true));
+            assert_eq!(Governance::voting_power(proposal_id, CHARLIE), Some(20));
+
+            // Staking 50x the minimum hits the 50% credibility cap: sqrt(600)
+            assert_ok!(TrustLayer::stake_tokens(RuntimeOrigin::signed(VERIFIER), 50 * MinimumStake::get()));
+            assert_eq!(Reputation::effective_reputation(&VERIFIER), 600);
+            assert_ok!(Governance::vote(RuntimeOrigin::signed(VERIFIER), proposal_id, true));
+            assert_eq!(Governance::voting_power(proposal_id, VERIFIER), Some(24));
+
+            assert_eq!(Governance::proposals(proposal_id).unwrap().for_votes, 44);
+        });
+    }
+
+    #[test]
+    fn published_reputation_earns_query_fees() {
+        new_test_ext().execute_with(|| {
+            verified_pull_request(ALICE, 1);
+
+            // The UAL is only Alice's once her reputation asset is live on the DKG
+            assert_noop!(
+                TrustLayer::register_data_provider(RuntimeOrigin::signed(ALICE), UAL.to_vec()),
+                pallet_trust_layer::Error::<Test>::NotUalOwner
+            );
+            publish(ALICE, UAL);
+            assert_ok!(TrustLayer::register_data_provider(RuntimeOrigin::signed(ALICE), UAL.to_vec()));
+
+            let alice_before = Balances::free_balance(ALICE);
+            let treasury_before = Balances::free_balance(TREASURY);
+            assert_ok!(TrustLayer::pay_for_query(RuntimeOrigin::signed(BOB), UAL.to_vec(), 10));
+
+            // 70% of the base price goes to the provider, the rest to the treasury
+            assert_eq!(Balances::free_balance(ALICE) - alice_before, 7);
+            assert_eq!(Balances::free_balance(TREASURY) - treasury_before, 3);
+            let ual = BoundedVec::truncate_from(UAL.to_vec());
+            assert!(TrustLayer::has_query_access(&BOB, &ual));
+            assert!(!TrustLayer::has_query_access(&CHARLIE, &ual));
+        });
+    }
+
+    #[test]
+    fn dkg_snapshot_carries_boosted_reputation() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(TrustLayer::stake_tokens(RuntimeOrigin::signed(VERIFIER), 10 * MinimumStake::get()));
+            assert!(Balances::reserved_balance(VERIFIER) > 0);
+
+            // Published scores are the ones governance and the trust layer see
+            assert_ok!(DkgIntegration::queue_for_publishing(RuntimeOrigin::root(), VERIFIER));
+            let (score, _) = DkgIntegration::get_queue_item(&VERIFIER).unwrap();
+            assert_eq!(score, Reputation::effective_reputation(&VERIFIER) as u32);
+            assert_eq!(score, 440);
+        });
+    }
+    #[test]
+    fn genesis_seats_council_and_configures_trust_layer() {
+        new_test_ext().execute_with(|| {
+            assert_eq!(Governance::council_members().into_inner(), vec![CHARLIE, VERIFIER]);
+            assert_eq!(Governance::council_term_end(), COUNCIL_TERM_END);
+            assert_eq!(TrustLayer::treasury_account(), Some(TREASURY));
+            assert_eq!(TrustLayer::asset_config(TRAC).unwrap().base_query_price, BaseQueryPrice::get());
+
+            // The genesis council can cancel proposals it did not create
+            verified_pull_request(ALICE, 1);
+            let proposal_id = propose(ALICE).unwrap();
+            assert_noop!(
+                Governance::cancel_proposal(RuntimeOrigin::signed(BOB), proposal_id),
+                pallet_governance::Error::<Test>::NotProposer
+            );
+            assert_ok!(Governance::cancel_proposal(RuntimeOrigin::signed(CHARLIE), proposal_id));
+            assert_eq!(Balances::reserved_balance(ALICE), 0);
+        });
+    }
+
+    #[test]
+    fn grant_milestones_are_paid_once_verified() {
+        new_test_ext().execute_with(|| {
+            verified_pull_request(ALICE, 1);
+            assert_ok!(Governance::create_proposal(
+                RuntimeOrigin::signed(ALICE),
+                ProposalType::TreasurySpend { amount: 300, beneficiary: ALICE },
+                BoundedVec::default(),
+                BoundedVec::truncate_from(b"Fund the indexer".to_vec()),
+            ));
+            let proposal_id = pallet_governance::NextProposalId::<Test>::get() - 1;
+
+            assert_noop!(
+                Governance::set_grant_milestones(RuntimeOrigin::signed(ALICE), proposal_id, vec![100, 100]),
+                pallet_governance::Error::<Test>::MilestoneTotalMismatch
+            );
+            assert_ok!(Governance::set_grant_milestones(RuntimeOrigin::signed(ALICE), proposal_id, vec![100, 200]));
+
+            let deliver = |who: u64, index: u32, proof: u64| {
+                Governance::deliver_milestone(
+                    RuntimeOrigin::signed(who),
+                    proposal_id,
+                    index,
+                    H256::from_low_u64_be(proof),
+                    ContributionType::CodeReview,
+                )
+            };
+            assert_noop!(deliver(ALICE, 0, 2), pallet_governance::Error::<Test>::GrantNotApproved);
+
+            // Quorum is measured against the whole network, so approve the spend directly
+            pallet_governance::Proposals::<Test>::mutate(proposal_id, |proposal| {
+                proposal.as_mut().unwrap().executed = true;
+            });
+            Balances::make_free_balance_be(&Governance::grants_account(), 1_000);
+
+            assert_noop!(deliver(BOB, 0, 2), pallet_governance::Error::<Test>::NotGrantBeneficiary);
+            assert_ok!(deliver(ALICE, 0, 2));
+            assert_noop!(deliver(ALICE, 0, 3), pallet_governance::Error::<Test>::MilestoneAlreadyDelivered);
+
+            // Payment waits for a verifier to sign off the delivery
+            let release =
+                |index: u32| Governance::release_milestone_payment(RuntimeOrigin::signed(BOB), proposal_id, index);
+            assert_noop!(release(0), pallet_governance::Error::<Test>::MilestoneNotVerified);
+            assert_noop!(release(1), pallet_governance::Error::<Test>::MilestoneNotDelivered);
+
+            // A rejected delivery can be redone
+            let rejected_id = Governance::grant_milestones(proposal_id)[0].contribution.unwrap();
+            pallet_reputation::Contributions::<Test>::mutate(rejected_id, |contribution| {
+                contribution.as_mut().unwrap().status = pallet_reputation::ContributionStatus::Rejected;
+            });
+            assert_ok!(deliver(ALICE, 0, 3));
+
+            let contribution_id = Governance::grant_milestones(proposal_id)[0].contribution.unwrap();
+            assert_ne!(contribution_id, rejected_id);
+            assert_ok!(Reputation::verify_contribution(
+                RuntimeOrigin::signed(VERIFIER),
+                ALICE,
+                contribution_id,
+                90,
+                b"Delivered as scoped".to_vec(),
+            ));
+
+            let alice_before = Balances::free_balance(ALICE);
+            assert_ok!(release(0));
+            assert_eq!(Balances::free_balance(ALICE) - alice_before, 100);
+            assert!(Governance::grant_milestones(proposal_id)[0].paid);
+            assert_noop!(release(0), pallet_governance::Error::<Test>::MilestoneAlreadyPaid);
+        });
+    }
+
+    #[test]
+    fn overturned_contributors_cannot_propose_on_probation() {
+        new_test_ext().execute_with(|| {
+            verified_pull_request(ALICE, 1);
+            verified_pull_request(ALICE, 2);
+            let contribution_id = pallet_reputation::NextContributionId::<Test>::get();
+
+            // The remaining verified pull request still meets the proposal threshold
+            assert_ok!(Reputation::overturn_verification(RuntimeOrigin::root(), contribution_id));
+            assert_eq!(Reputation::get_reputation(&ALICE), 30);
+            assert_noop!(propose(ALICE), pallet_governance::Error::<Test>::OnProbation);
+
+            System::set_block_number(1 + ProbationPeriod::get());
+            assert_ok!(propose(ALICE));
+        });
+    }
+}
@@ -0,0 +1,44 @@
+use dotrep_runtime::{
+    genesis::{development_genesis, local_testnet_genesis},
+    GenesisConfig, WASM_BINARY,
+};
+use sc_service::ChainType;
+
+/// Specialized `ChainSpec`. This is a specialization of the general Substrate ChainSpec type.
+pub type ChainSpec = sc_service::GenericChainSpec<GenesisConfig>;
+
+/// Single-authority development chain with pre-reputed well-known accounts
+pub fn development_config() -> Result<ChainSpec, String> {
+    let wasm_binary = WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?;
+
+    Ok(ChainSpec::from_genesis(
+        "Development",
+        "dev",
+        ChainType::Development,
+        move || development_genesis(wasm_binary),
+        vec![],
+        None,
+        None,
+        None,
+        None,
+        None,
+    ))
+}
+
+/// Two-authority local testnet
+pub fn local_testnet_config() -> Result<ChainSpec, String> {
+    let wasm_binary = WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?;
+
+    Ok(ChainSpec::from_genesis(
+        "Local Testnet",
+        "local_testnet",
+        ChainType::Local,
+        move || local_testnet_genesis(wasm_binary),
+        vec![],
+        None,
+        None,
+        None,
+        None,
+        None,
+    ))
+}
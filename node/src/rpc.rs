@@ -0,0 +1,59 @@
+//! RPC extensions of the DotRep node
+//!
+//! Alongside the standard system and transaction-payment RPCs, exposes the
+//! `dotrep_*` methods resolving accounts to their DKG Knowledge Assets and the
+//! `dotrep_subscribeReputation` subscription.
+
+#![warn(missing_docs)]
+
+use std::sync::Arc;
+
+use dotrep_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Index};
+use jsonrpsee::RpcModule;
+use sc_client_api::BlockchainEvents;
+use sc_rpc::SubscriptionTaskExecutor;
+use sc_transaction_pool_api::TransactionPool;
+use sp_api::ProvideRuntimeApi;
+use sp_block_builder::BlockBuilder;
+use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
+
+pub use sc_rpc_api::DenyUnsafe;
+
+/// Full client dependencies.
+pub struct FullDeps<C, P> {
+    /// The client instance to use.
+    pub client: Arc<C>,
+    /// Transaction pool instance.
+    pub pool: Arc<P>,
+    /// Whether to deny unsafe calls
+    pub deny_unsafe: DenyUnsafe,
+    /// Executor running subscription tasks
+    pub subscription_executor: SubscriptionTaskExecutor,
+}
+
+/// Instantiate all full RPC extensions.
+pub fn create_full<C, P>(deps: FullDeps<C, P>) -> Result<RpcModule<()>, Box<dyn std::error::Error + Send + Sync>>
+where
+    C: ProvideRuntimeApi<Block>,
+    C: HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError> + BlockchainEvents<Block> + 'static,
+    C: Send + Sync + 'static,
+    C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
+    C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+    C::Api: pallet_reputation::runtime_api::DkgQueryApi<Block, AccountId, BlockNumber>,
+    C::Api: pallet_reputation::runtime_api::ReputationApi<Block, AccountId>,
+    C::Api: BlockBuilder<Block>,
+    P: TransactionPool + 'static,
+{
+    use dotrep_rpc::{DotRep, DotRepApiServer};
+    use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
+    use substrate_frame_rpc_system::{System, SystemApiServer};
+
+    let mut module = RpcModule::new(());
+    let FullDeps { client, pool, deny_unsafe, subscription_executor } = deps;
+
+    module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
+    module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+    module.merge(DotRepApiServer::<_, AccountId, BlockNumber>::into_rpc(DotRep::new(client, subscription_executor)))?;
+
+    Ok(module)
+}
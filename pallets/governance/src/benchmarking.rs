@@ -0,0 +1,115 @@
+//! Benchmarking for the governance pallet
+//!
+//! Voting power sums every delegation in `Delegations`, so `vote` and `delegate_vote`
+//! are parameterized by the number of stored delegations (`d`) and run against a
+//! proposal and voter with full tag sets.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{traits::Get, BoundedVec};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{One, Saturating};
+use sp_std::vec;
+
+const SEED: u32 = 0;
+
+/// Upper bound of the delegation component
+///
+/// Delegations are not capped on chain; this is the largest map we expect weights to cover.
+const MAX_DELEGATIONS: u32 = 1_000;
+
+// Distinct skill tag of the maximum length
+fn tag(index: u8) -> SkillTag {
+    BoundedVec::truncate_from(vec![index; 32])
+}
+
+/// Proposal written straight to storage with every tag slot used
+///
+/// Its voting period is long enough that a vote cast at `T::MinVoteChangePeriod` may
+/// still be changed.
+fn insert_proposal<T: Config>() -> ProposalId {
+    let proposal_id = NextProposalId::<T>::get();
+    let now = frame_system::Pallet::<T>::block_number();
+    let voting_end = now
+        .saturating_add(T::MinVoteChangePeriod::get())
+        .saturating_add(T::VotingPeriod::get())
+        .saturating_add(One::one());
+    let proposal = Proposal {
+        id: proposal_id,
+        proposer: account("proposer", 0, SEED),
+        proposal_type: ProposalType::CouncilElection,
+        tags: BoundedVec::truncate_from((0..5).map(tag).collect()),
+        description: BoundedVec::truncate_from(vec![b'd'; 256]),
+        created: now,
+        voting_end,
+        execution_delay: T::ExecutionDelayPeriod::get(),
+        execution_ready_at: Some(voting_end.saturating_add(T::ExecutionDelayPeriod::get())),
+        cancelled: false,
+        executed: false,
+        for_votes: 0,
+        against_votes: 0,
+        total_voting_power: 0,
+    };
+    Proposals::<T>::insert(proposal_id, proposal);
+    NextProposalId::<T>::put(proposal_id + 1);
+    proposal_id
+}
+
+/// Give `who` the maximum number of skill tags, matching every proposal tag
+fn fill_skill_tags<T: Config>(who: &T::AccountId) {
+    SkillTags::<T>::insert(who, BoundedVec::truncate_from((0..10).map(tag).collect()));
+}
+
+/// Store `d` delegations to `delegatee`, alternating global and per-proposal ones
+fn fill_delegations<T: Config>(delegatee: &T::AccountId, proposal_id: ProposalId, d: u32) {
+    for i in 0..d {
+        let delegator: T::AccountId = account("delegator", i, SEED);
+        let delegation = Delegation {
+            delegator: delegator.clone(),
+            delegatee: delegatee.clone(),
+            amount: 0,
+            proposal_id: if i % 2 == 0 { None } else { Some(proposal_id) },
+        };
+        Delegations::<T>::insert(&delegator, delegation);
+    }
+}
+
+benchmarks! {
+    // Worst case: the caller changes an earlier vote
+    vote {
+        let d in 0 .. MAX_DELEGATIONS;
+
+        let voter: T::AccountId = whitelisted_caller();
+        let proposal_id = insert_proposal::<T>();
+        fill_skill_tags::<T>(&voter);
+        fill_delegations::<T>(&voter, proposal_id, d);
+
+        Votes::<T>::insert(proposal_id, &voter, false);
+        VotingPower::<T>::insert(proposal_id, &voter, 0);
+        let now = frame_system::Pallet::<T>::block_number();
+        frame_system::Pallet::<T>::set_block_number(now.saturating_add(T::MinVoteChangePeriod::get()));
+    }: _(RawOrigin::Signed(voter.clone()), proposal_id, true)
+    verify {
+        assert_eq!(Votes::<T>::get(proposal_id, &voter), Some(true));
+    }
+
+    delegate_vote {
+        let d in 0 .. MAX_DELEGATIONS;
+
+        let delegator: T::AccountId = whitelisted_caller();
+        let delegatee: T::AccountId = account("delegatee", 0, SEED);
+        let proposal_id = insert_proposal::<T>();
+        fill_delegations::<T>(&delegatee, proposal_id, d);
+    }: _(RawOrigin::Signed(delegator.clone()), delegatee, 0, Some(proposal_id))
+    verify {
+        assert!(Delegations::<T>::contains_key(&delegator));
+    }
+
+    impl_benchmark_test_suite!(
+        Pallet,
+        crate::mock::new_test_ext(),
+        crate::mock::Test
+    );
+}
@@ -8,24 +8,40 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod migrations;
+pub mod runtime_api;
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
     use frame_support::{
         pallet_prelude::*,
-        traits::{Currency, Get, ReservableCurrency},
-        transactional,
+        traits::{ChangeMembers, Currency, ExistenceRequirement, Get, Imbalance, InitializeMembers, ReservableCurrency},
+        transactional, PalletId,
     };
     use frame_system::pallet_prelude::*;
     use sp_std::prelude::*;
     use scale_info::TypeInfo;
-    use pallet_reputation::Pallet as ReputationPallet;
+    use dotrep_primitives::{
+        BoundedIterExt, ContributionId, ContributionRegistry, ContributionSubmitter, ContributionType,
+        CustomProposalHandler, HookQuotaProvider, HookTask, OnSkillTagsUpdated, ReputationProvider, Score, Topic,
+        sortition::isqrt,
+    };
+    use sp_core::H256;
+    use sp_runtime::{
+        traits::{AccountIdConversion, Saturating, Zero},
+        Percent, SaturatedConversion,
+    };
+    pub use dotrep_primitives::SkillTag;
 
     // Type aliases for cleaner code
     pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
-    pub type ReputationScore = u64; // Converted from i32 for voting calculations
+    /// Voting power derived from a non-negative reputation `Score`
+    pub type VotePower = u64;
     pub type ProposalId = u32;
-    pub type SkillTag = BoundedVec<u8, ConstU32<32>>;
 
     #[derive(Clone, Encode, Decode, PartialEq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
     #[scale_info(skip_type_params(T))]
@@ -61,19 +77,88 @@ pub mod pallet {
         pub execution_ready_at: Option<BlockNumberFor<T>>, // Block when execution becomes available
         pub cancelled: bool,
         pub executed: bool,
-        pub for_votes: ReputationScore,
-        pub against_votes: ReputationScore,
-        pub total_voting_power: ReputationScore, // For quorum calculation
+        pub for_votes: VotePower,
+        pub against_votes: VotePower,
+        pub total_voting_power: VotePower, // For quorum calculation
     }
 
     #[derive(Clone, Encode, Decode, PartialEq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
     pub struct Delegation<T: Config> {
         pub delegator: T::AccountId,
         pub delegatee: T::AccountId,
-        pub amount: ReputationScore,
+        pub amount: VotePower,
         pub proposal_id: Option<ProposalId>, // None = global delegation, Some(id) = per-proposal
     }
 
+    /// A delegation as served to governance UIs
+    #[derive(Clone, Encode, Decode, PartialEq, TypeInfo, RuntimeDebug)]
+    pub struct DelegationEdge<AccountId> {
+        pub delegator: AccountId,
+        pub delegatee: AccountId,
+        pub amount: VotePower,
+        /// `None` for a global delegation
+        pub proposal_id: Option<ProposalId>,
+    }
+
+    /// A tranche of a treasury grant, paid once its delivery is verified as a contribution
+    #[derive(Clone, Encode, Decode, PartialEq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Milestone<T: Config> {
+        pub amount: BalanceOf<T>,
+        /// Contribution submitted when the beneficiary delivered the milestone
+        pub contribution: Option<ContributionId>,
+        pub paid: bool,
+    }
+
+    pub type MilestonesOf<T> = BoundedVec<Milestone<T>, <T as Config>::MaxMilestones>;
+
+    /// Name of a parameter changed by `ParameterChange` proposals
+    pub type ParameterName = BoundedVec<u8, ConstU32<64>>;
+
+    /// Stricter thresholds, in percent, of proposals tagged with a skill track such as `security`
+    ///
+    /// A `supermajority` of 0 leaves the proposal type's majority rule in place.
+    #[derive(Clone, Encode, Decode, PartialEq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+    pub struct Track {
+        pub quorum: u8,
+        pub supermajority: u8,
+    }
+
+    /// Bounds on the values `ParameterChange` proposals may set a parameter to
+    ///
+    /// Values are SCALE-encoded `u64`s. `current` follows the executed proposals, so
+    /// e.g. `max_change: Percent::from_percent(20)` keeps each change within ±20% of the
+    /// value set last. A change of up to `min_step` is always allowed, so a parameter at
+    /// or near zero can still move.
+    #[derive(Clone, Encode, Decode, PartialEq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+    pub struct ParameterTemplate {
+        pub current: u64,
+        pub max_change: Percent,
+        pub min_step: u64,
+        pub min: u64,
+        pub max: u64,
+        /// Tags of the proposals created from the template, for expertise-weighted voting;
+        /// they also select the tracks whose thresholds the proposals must reach
+        pub tags: BoundedVec<SkillTag, ConstU32<5>>,
+    }
+
+    /// Most endorsements a proposal records; caps `RequiredEndorsements`
+    pub const MAX_ENDORSEMENTS: u32 = 16;
+
+    pub type EndorsersOf<T> = BoundedVec<<T as frame_system::Config>::AccountId, ConstU32<MAX_ENDORSEMENTS>>;
+
+    /// Proposal of an account below `EndorsementReputation`, waiting for endorsements
+    /// before its voting period starts
+    #[derive(Clone, Encode, Decode, PartialEq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct PendingProposal<T: Config> {
+        pub proposer: T::AccountId,
+        pub proposal_type: ProposalType,
+        pub tags: BoundedVec<SkillTag, ConstU32<5>>,
+        pub description: BoundedVec<u8, ConstU32<256>>,
+        pub submitted: BlockNumberFor<T>,
+    }
+
     #[pallet::config]
     pub trait Config: frame_system::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -82,11 +167,11 @@ pub mod pallet {
         type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
         
         /// The Reputation pallet that provides reputation scores
-        type Reputation: ReputationInterface<Self>;
+        type Reputation: ReputationProvider<Self::AccountId>;
         
         /// Minimum reputation required to create a proposal
         #[pallet::constant]
-        type MinProposalReputation: Get<ReputationScore>;
+        type MinProposalReputation: Get<Score>;
         
         /// Minimum deposit required for proposal (to prevent spam)
         #[pallet::constant]
@@ -115,10 +200,110 @@ pub mod pallet {
         /// Minimum voting period required to change vote
         #[pallet::constant]
         type MinVoteChangePeriod: Get<BlockNumberFor<Self>>;
+
+        /// Notified of skill tag changes, e.g. the reputation pallet's verifier index
+        type OnSkillTagsUpdated: OnSkillTagsUpdated<Self::AccountId>;
+
+        /// Contribution registry that verifies delivered grant milestones
+        type Contributions: ContributionRegistry<Self::AccountId> + ContributionSubmitter<Self::AccountId>;
+
+        /// Pallet id used to derive the account grant milestones are paid from
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Maximum number of milestones a treasury grant can be split into
+        #[pallet::constant]
+        type MaxMilestones: Get<u32>;
+
+        /// Weight of the contribution submitted for a delivered milestone
+        #[pallet::constant]
+        type MilestoneContributionWeight: Get<u8>;
+
+        /// Maximum delegations one account can receive
+        #[pallet::constant]
+        type MaxDelegationsPerDelegatee: Get<u32>;
+
+        /// Executes approved `Custom` proposals by tag
+        type CustomProposalHandler: CustomProposalHandler;
+
+        /// Salary a council member accrues per block of their term, paid from `Treasury`
+        #[pallet::constant]
+        type CouncilSalaryPerBlock: Get<BalanceOf<Self>>;
+
+        /// Treasury account council salaries are paid from and clawed back to
+        type Treasury: Get<Self::AccountId>;
+
+        /// Proposal votes a council member must cast during a term to be paid for it
+        #[pallet::constant]
+        type MinCouncilVotesPerTerm: Get<u32>;
+
+        /// Origin that registers parameter templates (typically root)
+        type TemplateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Origin that sets the thresholds of skill tracks (typically root)
+        type TrackOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Blocks a proposal is kept after it was executed or cancelled, or after its voting
+        /// ended if it never was, before anyone can clean it up; at least `ExecutionDelayPeriod`
+        #[pallet::constant]
+        type ProposalRetentionPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Paid from the grants account to whoever cleans up a proposal
+        #[pallet::constant]
+        type ProposalCleanupReward: Get<BalanceOf<Self>>;
+
+        /// Votes removed by one `cleanup_proposal` call
+        #[pallet::constant]
+        type MaxVotesPrunedPerCleanup: Get<u32>;
+
+        /// Reputation from which proposals go straight to a vote and accounts may endorse
+        /// the proposals of others
+        #[pallet::constant]
+        type EndorsementReputation: Get<Score>;
+
+        /// Endorsements a proposal below `EndorsementReputation` needs before its vote
+        /// opens, at most `MAX_ENDORSEMENTS`; 0 lets every proposal straight to a vote
+        #[pallet::constant]
+        type RequiredEndorsements: Get<u32>;
+
+        /// Reserved from each endorser until the proposal closes, and slashed if the
+        /// council marks it as spam
+        #[pallet::constant]
+        type EndorsementDeposit: Get<BalanceOf<Self>>;
+
+        /// Blocks a proposal waits for endorsements before anyone can expire it, returning
+        /// the deposits of its proposer and endorsers
+        #[pallet::constant]
+        type PendingProposalExpiry: Get<BlockNumberFor<Self>>;
+
+        /// Origin that marks proposals as spam, e.g. a council majority
+        type SpamOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Quotas of the block weight hook tasks may use, shared with the other pallets
+        type HookQuotas: HookQuotaProvider;
+
+        /// Notified of the council's members at genesis and on rotation, e.g. the collective
+        /// whose majority motions act for the council
+        type CouncilMembershipChanged: ChangeMembers<Self::AccountId> + InitializeMembers<Self::AccountId>;
     }
 
+    /// v1 indexes delegations by delegatee
+    pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+    /// Longest migration cursor, a raw `Delegations` key
+    pub const MAX_MIGRATION_CURSOR_LEN: u32 = 128;
+
+    /// Longest cleanup cursor, a raw `Votes` or `VotingPower` key
+    pub const MAX_CLEANUP_CURSOR_LEN: u32 = 128;
+    pub type CleanupCursor = BoundedVec<u8, ConstU32<MAX_CLEANUP_CURSOR_LEN>>;
+
+    /// `DelegationsTo` entries one `top_delegatees` page scans, besides the rest of the
+    /// last delegatee's entries
+    pub const MAX_DELEGATION_SCAN: u32 = 1_000;
+
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     // Storage items
@@ -145,13 +330,24 @@ pub mod pallet {
         _,
         Blake2_128Concat, ProposalId,
         Blake2_128Concat, T::AccountId,
-        ReputationScore, // Stored voting power for vote revocation
+        VotePower, // Stored voting power for vote revocation
     >;
 
     #[pallet::storage]
     #[pallet::getter(fn delegations)]
     pub type Delegations<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, Delegation<T>, OptionQuery>;
 
+    // Delegators of each delegatee, so its delegated power is read without scanning `Delegations`
+    #[pallet::storage]
+    pub type DelegationsTo<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+    // Raw key of the last delegation the ongoing v1 migration indexed; delegation and
+    // voting calls are rejected while set
+    #[pallet::storage]
+    #[pallet::getter(fn migration_in_progress)]
+    pub type MigrationInProgress<T> = StorageValue<_, BoundedVec<u8, ConstU32<MAX_MIGRATION_CURSOR_LEN>>, OptionQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn council_members)]
     pub type CouncilMembers<T: Config> = StorageValue<_, BoundedVec<T::AccountId, ConstU32<50>>, ValueQuery>;
@@ -160,6 +356,27 @@ pub mod pallet {
     #[pallet::getter(fn council_term_end)]
     pub type CouncilTermEnd<T> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
 
+    // Block the current council's term started, from which its salary accrues
+    #[pallet::storage]
+    #[pallet::getter(fn council_term_start)]
+    pub type CouncilTermStart<T> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    // Proposal votes each council member cast this term
+    #[pallet::storage]
+    #[pallet::getter(fn council_votes)]
+    pub type CouncilVotes<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    // Salary earned in past terms and not yet claimed
+    #[pallet::storage]
+    #[pallet::getter(fn council_salary)]
+    pub type CouncilSalaries<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+    // Salary each council member claimed during the current term, clawed back at its end
+    // if they cast fewer than `MinCouncilVotesPerTerm` votes
+    #[pallet::storage]
+    #[pallet::getter(fn council_salary_paid)]
+    pub type CouncilSalaryPaid<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
     // Storage for skill tags (extended from reputation system)
     #[pallet::storage]
     #[pallet::getter(fn skill_tags)]
@@ -170,9 +387,72 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    // Milestones of treasury spend proposals, paid out one verified delivery at a time
+    #[pallet::storage]
+    #[pallet::getter(fn grant_milestones)]
+    pub type GrantMilestones<T: Config> = StorageMap<_, Blake2_128Concat, ProposalId, MilestonesOf<T>, ValueQuery>;
+
+    // Thresholds of proposals tagged with each skill track, checked when they are executed
+    #[pallet::storage]
+    #[pallet::getter(fn track_thresholds)]
+    pub type TrackThresholds<T> = StorageMap<_, Blake2_128Concat, SkillTag, Track, OptionQuery>;
+
+    // Block each proposal was executed or cancelled
+    #[pallet::storage]
+    pub type ProposalClosedAt<T> = StorageMap<_, Blake2_128Concat, ProposalId, BlockNumberFor<T>, OptionQuery>;
+
+    // Where the last unfinished `cleanup_proposal` stopped clearing votes and voting power
+    #[pallet::storage]
+    pub type CleanupCursors<T> =
+        StorageMap<_, Blake2_128Concat, ProposalId, (Option<CleanupCursor>, Option<CleanupCursor>), OptionQuery>;
+
+    // Templates bounding the values parameter change proposals may set
+    #[pallet::storage]
+    #[pallet::getter(fn parameter_template)]
+    pub type ParameterTemplates<T> = StorageMap<_, Blake2_128Concat, ParameterName, ParameterTemplate, OptionQuery>;
+
+    // Proposals waiting for endorsements; they move to `Proposals` once endorsed
+    #[pallet::storage]
+    #[pallet::getter(fn pending_proposal)]
+    pub type PendingProposals<T: Config> = StorageMap<_, Blake2_128Concat, ProposalId, PendingProposal<T>, OptionQuery>;
+
+    // Endorsers of each proposal, whose deposits stay reserved until the proposal closes
+    #[pallet::storage]
+    #[pallet::getter(fn endorsements)]
+    pub type Endorsements<T: Config> = StorageMap<_, Blake2_128Concat, ProposalId, EndorsersOf<T>, ValueQuery>;
+
+    #[pallet::genesis_config]
+    pub struct GenesisConfig<T: Config> {
+        /// Initial reputation council (at most `CouncilSize` members)
+        pub council: Vec<T::AccountId>,
+        /// Block at which the initial council's term ends and anyone may rotate it
+        pub council_term_end: BlockNumberFor<T>,
+    }
+
+    #[cfg(feature = "std")]
+    impl<T: Config> Default for GenesisConfig<T> {
+        fn default() -> Self {
+            Self { council: Vec::new(), council_term_end: Default::default() }
+        }
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+        fn build(&self) {
+            let mut council = self.council.clone();
+            council.sort();
+            council.dedup();
+            assert!(council.len() as u32 <= T::CouncilSize::get(), "Genesis council exceeds CouncilSize");
+
+            T::CouncilMembershipChanged::initialize_members(&council);
+            let council: BoundedVec<_, _> = council.try_into().expect("Genesis council exceeds 50 members");
+            CouncilMembers::<T>::put(council);
+            CouncilTermEnd::<T>::put(self.council_term_end);
+        }
+    }
+
     // Events
     #[pallet::event]
-    #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         ProposalCreated {
             proposal_id: ProposalId,
@@ -183,7 +463,7 @@ pub mod pallet {
             proposal_id: ProposalId,
             voter: T::AccountId,
             support: bool,
-            voting_power: ReputationScore,
+            voting_power: VotePower,
         },
         ProposalExecuted {
             proposal_id: ProposalId,
@@ -191,7 +471,7 @@ pub mod pallet {
         Delegated {
             delegator: T::AccountId,
             delegatee: T::AccountId,
-            amount: ReputationScore,
+            amount: VotePower,
         },
         CouncilRotated {
             new_members: Vec<T::AccountId>,
@@ -205,12 +485,12 @@ pub mod pallet {
             voter: T::AccountId,
             old_support: bool,
             new_support: bool,
-            voting_power: ReputationScore,
+            voting_power: VotePower,
         },
         VoteRevoked {
             proposal_id: ProposalId,
             voter: T::AccountId,
-            voting_power: ReputationScore,
+            voting_power: VotePower,
         },
         ProposalCancelled {
             proposal_id: ProposalId,
@@ -229,6 +509,144 @@ pub mod pallet {
             proposal_id: ProposalId,
             ready_at: BlockNumberFor<T>,
         },
+        GrantMilestonesSet {
+            proposal_id: ProposalId,
+            amounts: Vec<BalanceOf<T>>,
+        },
+        MilestoneDelivered {
+            proposal_id: ProposalId,
+            index: u32,
+            beneficiary: T::AccountId,
+            contribution_id: ContributionId,
+        },
+        MilestonePaid {
+            proposal_id: ProposalId,
+            index: u32,
+            beneficiary: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// A council member cast enough votes and earned their salary for the ended term
+        CouncilSalaryEarned {
+            member: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// A council member cast fewer than `MinCouncilVotesPerTerm` votes and forfeits
+        /// their salary for the ended term; `recovered` is the part already paid to them
+        /// that was taken back to the treasury
+        CouncilSalaryClawedBack {
+            member: T::AccountId,
+            amount: BalanceOf<T>,
+            recovered: BalanceOf<T>,
+            votes: u32,
+        },
+        CouncilSalaryClaimed {
+            member: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// A parameter template was registered, replaced or removed
+        ParameterTemplateSet {
+            parameter: ParameterName,
+            template: Option<ParameterTemplate>,
+        },
+        /// The thresholds of a skill track were set or removed
+        TrackThresholdsSet {
+            tag: SkillTag,
+            track: Option<Track>,
+        },
+        /// A dead proposal and its votes were removed from storage
+        ProposalCleanedUp {
+            proposal_id: ProposalId,
+            cleaner: T::AccountId,
+            reward: BalanceOf<T>,
+        },
+        /// An executed proposal changed a templated parameter
+        TemplatedParameterChanged {
+            proposal_id: ProposalId,
+            parameter: ParameterName,
+            old_value: u64,
+            new_value: u64,
+        },
+        /// A proposal of an account below `EndorsementReputation` waits for endorsements
+        ProposalAwaitingEndorsement {
+            proposal_id: ProposalId,
+            proposer: T::AccountId,
+        },
+        ProposalEndorsed {
+            proposal_id: ProposalId,
+            endorser: T::AccountId,
+            endorsements: u32,
+        },
+        /// The council marked a proposal as spam, slashing the deposits of its proposer and
+        /// endorsers
+        ProposalMarkedSpam {
+            proposal_id: ProposalId,
+            proposer: T::AccountId,
+            endorsers: u32,
+        },
+        /// A proposal was not endorsed within `PendingProposalExpiry`; the deposits of its
+        /// proposer and endorsers were returned
+        PendingProposalExpired {
+            proposal_id: ProposalId,
+            proposer: T::AccountId,
+        },
+        /// The v1 migration dropped a delegation to a delegatee that already received
+        /// `MaxDelegationsPerDelegatee`; delegations hold no deposit, so the delegator can
+        /// simply delegate elsewhere
+        DelegationDropped {
+            delegator: T::AccountId,
+            delegatee: T::AccountId,
+        },
+        /// The v1 migration indexed every delegation
+        MigrationCompleted,
+    }
+
+    impl<T: Config> Event<T> {
+        /// Topics this event is indexed under
+        fn topics(&self) -> Vec<T::Hash> {
+            let account = |who: &T::AccountId| Topic::Account.of::<T::Hashing>(who);
+            let proposal = |id: &ProposalId| Topic::Proposal.of::<T::Hashing>(id);
+
+            match self {
+                Event::ProposalCreated { proposal_id, proposer: who, .. }
+                | Event::Voted { proposal_id, voter: who, .. }
+                | Event::VoteChanged { proposal_id, voter: who, .. }
+                | Event::VoteRevoked { proposal_id, voter: who, .. }
+                | Event::ProposalCancelled { proposal_id, proposer: who }
+                | Event::DepositReturned { proposal_id, account: who, .. }
+                | Event::MilestoneDelivered { proposal_id, beneficiary: who, .. }
+                | Event::MilestonePaid { proposal_id, beneficiary: who, .. }
+                | Event::ProposalCleanedUp { proposal_id, cleaner: who, .. }
+                | Event::ProposalAwaitingEndorsement { proposal_id, proposer: who }
+                | Event::ProposalEndorsed { proposal_id, endorser: who, .. }
+                | Event::ProposalMarkedSpam { proposal_id, proposer: who, .. }
+                | Event::PendingProposalExpired { proposal_id, proposer: who } =>
+                    vec![proposal(proposal_id), account(who)],
+                Event::ProposalExecuted { proposal_id }
+                | Event::ProposalExecutionReady { proposal_id, .. }
+                | Event::GrantMilestonesSet { proposal_id, .. }
+                | Event::TemplatedParameterChanged { proposal_id, .. } =>
+                    vec![proposal(proposal_id)],
+                Event::Delegated { delegator, delegatee, .. }
+                | Event::DelegationRevoked { delegator, delegatee }
+                | Event::DelegationDropped { delegator, delegatee } =>
+                    vec![account(delegator), account(delegatee)],
+                Event::CouncilRotated { new_members } => new_members.iter().map(account).collect(),
+                Event::SkillTagsUpdated { account: who, .. } => vec![account(who)],
+                Event::CouncilSalaryEarned { member, .. }
+                | Event::CouncilSalaryClawedBack { member, .. }
+                | Event::CouncilSalaryClaimed { member, .. } => vec![account(member)],
+                _ => Vec::new(),
+            }
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Deposit an event indexed under the accounts and proposals it concerns
+        pub(super) fn deposit_event(event: Event<T>) {
+            let topics = event.topics();
+            let event = <T as Config>::RuntimeEvent::from(event);
+            frame_system::Pallet::<T>::deposit_event_indexed(&topics, event.into());
+        }
     }
 
     #[pallet::error]
@@ -252,6 +670,69 @@ pub mod pallet {
         NoVoteToRevoke,
         NoDelegationToRevoke,
         ProposalNotExecutable,
+        /// Milestones only apply to treasury spend proposals
+        NotTreasurySpend,
+        /// Milestones can no longer change once voting has started
+        MilestonesLocked,
+        /// Milestone amounts must add up to the amount of the spend
+        MilestoneTotalMismatch,
+        TooManyMilestones,
+        MilestoneNotFound,
+        /// Only the beneficiary of the grant can deliver its milestones
+        NotGrantBeneficiary,
+        /// The grant's proposal has not been executed
+        GrantNotApproved,
+        MilestoneAlreadyDelivered,
+        MilestoneNotDelivered,
+        /// The milestone's contribution has not been verified
+        MilestoneNotVerified,
+        MilestoneAlreadyPaid,
+        /// Accounts on reputation probation cannot create proposals
+        OnProbation,
+        /// The delegatee already receives `MaxDelegationsPerDelegatee` delegations
+        TooManyDelegations,
+        /// No `CustomProposalHandler` handles the custom proposal's tag
+        UnknownProposalTag,
+        /// The account has no unclaimed council salary
+        NoCouncilSalary,
+        /// The parameter has no template
+        UnknownParameterTemplate,
+        /// Template bounds are empty or exclude its current value
+        InvalidParameterTemplate,
+        /// The value of a templated parameter is not a SCALE-encoded `u64`
+        InvalidParameterValue,
+        /// The new value is outside the template's bounds or changes it by more than `max_change`
+        ParameterChangeOutOfBounds,
+        /// Track thresholds are above 100%
+        InvalidTrackThresholds,
+        /// The proposal is within its `ProposalRetentionPeriod`
+        ProposalRetained,
+        /// The proposal is a grant with unpaid milestones
+        GrantInProgress,
+        /// The proposal is not waiting for endorsements
+        NotAwaitingEndorsement,
+        /// Proposers cannot endorse their own proposals
+        CannotEndorseOwnProposal,
+        AlreadyEndorsed,
+        /// The proposal waited longer than `PendingProposalExpiry` for endorsements
+        PendingProposalExpired,
+        /// The proposal is still within `PendingProposalExpiry`
+        PendingProposalNotExpired,
+        /// Delegations are being migrated
+        MigrationInProgress,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
+            if !MigrationInProgress::<T>::exists() {
+                return T::DbWeight::get().reads(1);
+            }
+            let mut budget = T::HookQuotas::hook_budget(HookTask::Migrations, T::BlockWeights::get().max_block);
+            Self::step_migration(&mut budget);
+            T::HookQuotas::note_hook_usage(HookTask::Migrations, budget.consumed());
+            budget.consumed().saturating_add(T::DbWeight::get().reads_writes(2, 1))
+        }
     }
 
     #[pallet::call]
@@ -265,65 +746,18 @@ pub mod pallet {
             description: BoundedVec<u8, ConstU32<256>>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
-
-            // Check proposal threshold - convert i32 to u64 for comparison
-            let reputation_i32 = T::Reputation::get_reputation_score(&who);
-            let reputation = reputation_i32.max(0) as u64; // Ensure non-negative
-            ensure!(
-                reputation >= T::MinProposalReputation::get(),
-                Error::<T>::InsufficientReputation
-            );
-
-            // Take deposit
-            T::Currency::reserve(&who, T::ProposalDeposit::get())?;
-
-            let proposal_id = NextProposalId::<T>::get();
-            let now = frame_system::Pallet::<T>::block_number();
-            let voting_end = now + T::VotingPeriod::get();
-            let execution_delay = T::ExecutionDelayPeriod::get();
-            let execution_ready_at = Some(voting_end + execution_delay);
-
-            // Calculate total available voting power for quorum (simplified - in production, 
-            // this should query all accounts with reputation)
-            let total_voting_power = Self::estimate_total_voting_power();
-
-            let proposal = Proposal {
-                id: proposal_id,
-                proposer: who.clone(),
-                proposal_type,
-                tags,
-                description,
-                created: now,
-                voting_end,
-                execution_delay,
-                execution_ready_at,
-                cancelled: false,
-                executed: false,
-                for_votes: 0,
-                against_votes: 0,
-                total_voting_power,
-            };
-
-            Proposals::<T>::insert(proposal_id, proposal);
-            NextProposalId::<T>::put(proposal_id + 1);
-
-            Self::deposit_event(Event::ProposalCreated {
-                proposal_id,
-                proposer: who,
-                proposal_type,
-            });
-
-            Ok(())
+            Self::do_create_proposal(who, proposal_type, tags, description)
         }
 
         #[pallet::call_index(1)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(Weight::from_parts(10_000, 0).saturating_add(T::Reputation::reputation_of_weight()))]
         pub fn vote(
             origin: OriginFor<T>,
             proposal_id: ProposalId,
             support: bool,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            Self::ensure_not_migrating()?;
 
             let mut proposal = Proposals::<T>::get(proposal_id)
                 .ok_or(Error::<T>::ProposalNotFound)?;
@@ -358,6 +792,11 @@ pub mod pallet {
             // Calculate voting power with expertise boost
             let voting_power = Self::calculate_voting_power(&who, &proposal)?;
 
+            // Council members are paid for taking part in votes
+            if existing_vote.is_none() && CouncilMembers::<T>::get().contains(&who) {
+                CouncilVotes::<T>::mutate(&who, |votes| *votes = votes.saturating_add(1));
+            }
+
             // Record vote and voting power
             Votes::<T>::insert(proposal_id, &who, support);
             VotingPower::<T>::insert(proposal_id, &who, voting_power);
@@ -397,10 +836,11 @@ pub mod pallet {
         pub fn delegate_vote(
             origin: OriginFor<T>,
             delegatee: T::AccountId,
-            amount: ReputationScore,
+            amount: VotePower,
             proposal_id: Option<ProposalId>,
         ) -> DispatchResult {
             let delegator = ensure_signed(origin)?;
+            Self::ensure_not_migrating()?;
 
             // Cannot delegate to self
             ensure!(delegator != delegatee, Error::<T>::InvalidDelegatee);
@@ -415,11 +855,20 @@ pub mod pallet {
                 );
             }
 
-            let delegatee_reputation_i32 = T::Reputation::get_reputation_score(&delegatee);
-            let delegatee_reputation = delegatee_reputation_i32.max(0) as u64;
+            let delegatee_reputation = T::Reputation::raw_reputation_of(&delegatee).max(0) as VotePower;
             
+            // A changed delegation no longer counts towards the previous delegatee
+            if let Some(previous) = Delegations::<T>::get(&delegator) {
+                DelegationsTo::<T>::remove(&previous.delegatee, &delegator);
+            }
+            let max_delegations = T::MaxDelegationsPerDelegatee::get();
+            ensure!(
+                (DelegationsTo::<T>::iter_key_prefix(&delegatee).bounded(max_delegations).count() as u32) < max_delegations,
+                Error::<T>::TooManyDelegations
+            );
+
             // Check delegation capacity - delegatee can only receive up to their reputation score
-            let current_delegations = Self::get_total_delegations_to(&delegatee, proposal_id);
+            let current_delegations = Self::get_delegated_power(&delegatee, proposal_id)?;
             ensure!(
                 current_delegations + amount <= delegatee_reputation,
                 Error::<T>::DelegationExceedsCapacity
@@ -433,6 +882,7 @@ pub mod pallet {
             };
 
             Delegations::<T>::insert(&delegator, delegation);
+            DelegationsTo::<T>::insert(&delegatee, &delegator, ());
 
             Self::deposit_event(Event::Delegated {
                 delegator,
@@ -472,6 +922,11 @@ pub mod pallet {
                 proposal.against_votes = proposal.against_votes.saturating_sub(voting_power);
             }
 
+            // A revoked vote no longer counts towards the council salary
+            if CouncilMembers::<T>::get().contains(&who) {
+                CouncilVotes::<T>::mutate(&who, |votes| *votes = votes.saturating_sub(1));
+            }
+
             // Remove vote and voting power records
             Votes::<T>::remove(proposal_id, &who);
             VotingPower::<T>::remove(proposal_id, &who);
@@ -493,12 +948,14 @@ pub mod pallet {
             origin: OriginFor<T>,
         ) -> DispatchResult {
             let delegator = ensure_signed(origin)?;
+            Self::ensure_not_migrating()?;
 
             let delegation = Delegations::<T>::get(&delegator)
                 .ok_or(Error::<T>::NoDelegationToRevoke)?;
 
             let delegatee = delegation.delegatee.clone();
             Delegations::<T>::remove(&delegator);
+            DelegationsTo::<T>::remove(&delegatee, &delegator);
 
             Self::deposit_event(Event::DelegationRevoked {
                 delegator,
@@ -516,6 +973,24 @@ pub mod pallet {
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
+            if let Some(pending) = PendingProposals::<T>::get(proposal_id) {
+                ensure!(
+                    who == pending.proposer || CouncilMembers::<T>::get().contains(&who),
+                    Error::<T>::NotProposer
+                );
+                PendingProposals::<T>::remove(proposal_id);
+                Self::release_endorsements(proposal_id);
+                T::Currency::unreserve(&pending.proposer, T::ProposalDeposit::get());
+
+                Self::deposit_event(Event::ProposalCancelled { proposal_id, proposer: pending.proposer.clone() });
+                Self::deposit_event(Event::DepositReturned {
+                    account: pending.proposer,
+                    proposal_id,
+                    amount: T::ProposalDeposit::get(),
+                });
+                return Ok(());
+            }
+
             let mut proposal = Proposals::<T>::get(proposal_id)
                 .ok_or(Error::<T>::ProposalNotFound)?;
 
@@ -535,9 +1010,11 @@ pub mod pallet {
             let proposer = proposal.proposer.clone();
             proposal.cancelled = true;
             Proposals::<T>::insert(proposal_id, proposal);
+            ProposalClosedAt::<T>::insert(proposal_id, frame_system::Pallet::<T>::block_number());
 
             // Return deposit to proposer
             T::Currency::unreserve(&proposer, T::ProposalDeposit::get());
+            Self::release_endorsements(proposal_id);
 
             Self::deposit_event(Event::ProposalCancelled {
                 proposal_id,
@@ -554,7 +1031,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(3)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(Weight::from_parts(10_000, 0).saturating_add(T::CustomProposalHandler::max_weight()))]
         pub fn execute_proposal(
             origin: OriginFor<T>,
             proposal_id: ProposalId,
@@ -587,18 +1064,14 @@ pub mod pallet {
             } else {
                 0
             };
+            // Tracks of the proposal's tags may raise both thresholds
+            let (quorum, supermajority) = Self::voting_thresholds(&proposal);
             ensure!(
-                quorum_percentage >= T::QuorumThreshold::get() as u64,
+                quorum_percentage >= quorum as u64,
                 Error::<T>::QuorumNotMet
             );
 
-            // Determine if proposal requires supermajority (runtime upgrades, treasury spends)
-            let requires_supermajority = matches!(
-                proposal.proposal_type,
-                ProposalType::RuntimeUpgrade { .. } | ProposalType::TreasurySpend { .. }
-            );
-
-            if requires_supermajority {
+            if let Some(supermajority) = supermajority {
                 // Check supermajority threshold
                 let for_percentage = if total_votes > 0 {
                     (proposal.for_votes * 100) / total_votes
@@ -606,7 +1079,7 @@ pub mod pallet {
                     0
                 };
                 ensure!(
-                    for_percentage >= T::SupermajorityThreshold::get() as u64,
+                    for_percentage >= supermajority as u64,
                     Error::<T>::SupermajorityNotMet
                 );
             } else {
@@ -623,9 +1096,11 @@ pub mod pallet {
             let proposer = proposal.proposer.clone();
             proposal.executed = true;
             Proposals::<T>::insert(proposal_id, proposal);
+            ProposalClosedAt::<T>::insert(proposal_id, frame_system::Pallet::<T>::block_number());
 
             // Return deposit to proposer
             T::Currency::unreserve(&proposer, T::ProposalDeposit::get());
+            Self::release_endorsements(proposal_id);
 
             Self::deposit_event(Event::ProposalExecuted { proposal_id });
 
@@ -683,8 +1158,12 @@ pub mod pallet {
                 );
             }
 
+            Self::settle_council_salaries(&current_council, now);
+
             let new_council = Self::select_new_council()?;
             CouncilMembers::<T>::put(&new_council);
+            T::CouncilMembershipChanged::set_members_sorted(&new_council, &current_council);
+            CouncilTermStart::<T>::put(now);
             CouncilTermEnd::<T>::put(now + T::VotingPeriod::get() * 4); // 4 voting periods
 
             Self::deposit_event(Event::CouncilRotated {
@@ -695,14 +1174,16 @@ pub mod pallet {
         }
 
         #[pallet::call_index(5)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(Weight::from_parts(10_000, 0).saturating_add(T::DbWeight::get().reads_writes(1, 21)))]
         pub fn update_skill_tags(
             origin: OriginFor<T>,
             tags: BoundedVec<SkillTag, ConstU32<10>>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
+            let old_tags = SkillTags::<T>::get(&who);
             SkillTags::<T>::insert(&who, tags.clone());
+            T::OnSkillTagsUpdated::on_skill_tags_updated(&who, &old_tags, &tags);
 
             Self::deposit_event(Event::SkillTagsUpdated {
                 account: who,
@@ -711,28 +1192,749 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Split a treasury spend into milestones, each paid only once its delivery is verified
+        ///
+        /// Only the proposer can set milestones, and only before the first vote is cast, so
+        /// voters approve the schedule together with the spend.
+        #[pallet::call_index(10)]
+        #[pallet::weight(10_000)]
+        pub fn set_grant_milestones(
+            origin: OriginFor<T>,
+            proposal_id: ProposalId,
+            amounts: Vec<BalanceOf<T>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let proposal = Proposals::<T>::get(proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+            ensure!(who == proposal.proposer, Error::<T>::NotProposer);
+            let ProposalType::TreasurySpend { amount, .. } = proposal.proposal_type else {
+                return Err(Error::<T>::NotTreasurySpend.into());
+            };
+            ensure!(
+                !proposal.cancelled && proposal.for_votes == 0 && proposal.against_votes == 0,
+                Error::<T>::MilestonesLocked
+            );
+
+            let total = amounts.iter().fold(Zero::zero(), |total: BalanceOf<T>, a| total.saturating_add(*a));
+            ensure!(total == amount, Error::<T>::MilestoneTotalMismatch);
+
+            let milestones: MilestonesOf<T> = amounts
+                .iter()
+                .map(|amount| Milestone { amount: *amount, contribution: None, paid: false })
+                .collect::<Vec<_>>()
+                .try_into()
+                .map_err(|_| Error::<T>::TooManyMilestones)?;
+            GrantMilestones::<T>::insert(proposal_id, milestones);
+
+            Self::deposit_event(Event::GrantMilestonesSet { proposal_id, amounts });
+
+            Ok(())
+        }
+
+        /// Deliver a milestone of an approved grant, submitting the work as a contribution
+        /// that verifiers must sign off before the milestone is paid
+        ///
+        /// A milestone whose delivery was rejected or pruned can be delivered again.
+        #[pallet::call_index(11)]
+        #[pallet::weight(10_000)]
+        pub fn deliver_milestone(
+            origin: OriginFor<T>,
+            proposal_id: ProposalId,
+            index: u32,
+            proof: H256,
+            contribution_type: ContributionType,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let beneficiary = Self::grant_beneficiary(proposal_id)?;
+            ensure!(who == beneficiary, Error::<T>::NotGrantBeneficiary);
+
+            let contribution_id = GrantMilestones::<T>::try_mutate(proposal_id, |milestones| {
+                let milestone = milestones.get_mut(index as usize).ok_or(Error::<T>::MilestoneNotFound)?;
+                ensure!(
+                    milestone.contribution.map_or(true, T::Contributions::is_withdrawn),
+                    Error::<T>::MilestoneAlreadyDelivered
+                );
+
+                let contribution_id = T::Contributions::submit_contribution(
+                    &who,
+                    proof.0,
+                    contribution_type,
+                    T::MilestoneContributionWeight::get(),
+                )?;
+                milestone.contribution = Some(contribution_id);
+                Ok::<_, DispatchError>(contribution_id)
+            })?;
+
+            Self::deposit_event(Event::MilestoneDelivered { proposal_id, index, beneficiary: who, contribution_id });
+
+            Ok(())
+        }
+
+        /// Pay a delivered milestone from the grants account once its contribution is verified
+        #[pallet::call_index(12)]
+        #[pallet::weight(10_000)]
+        pub fn release_milestone_payment(
+            origin: OriginFor<T>,
+            proposal_id: ProposalId,
+            index: u32,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let beneficiary = Self::grant_beneficiary(proposal_id)?;
+
+            let amount = GrantMilestones::<T>::try_mutate(proposal_id, |milestones| {
+                let milestone = milestones.get_mut(index as usize).ok_or(Error::<T>::MilestoneNotFound)?;
+                ensure!(!milestone.paid, Error::<T>::MilestoneAlreadyPaid);
+                let contribution_id = milestone.contribution.ok_or(Error::<T>::MilestoneNotDelivered)?;
+                ensure!(
+                    T::Contributions::verified_contributor(contribution_id).as_ref() == Some(&beneficiary),
+                    Error::<T>::MilestoneNotVerified
+                );
+
+                T::Currency::transfer(
+                    &Self::grants_account(),
+                    &beneficiary,
+                    milestone.amount,
+                    ExistenceRequirement::AllowDeath,
+                )?;
+                milestone.paid = true;
+                Ok::<_, DispatchError>(milestone.amount)
+            })?;
+
+            Self::deposit_event(Event::MilestonePaid { proposal_id, index, beneficiary, amount });
+
+            Ok(())
+        }
+
+        /// Claim the council salary earned in past terms plus the salary accrued so far in
+        /// the current term from the treasury
+        ///
+        /// Salary claimed during a term is clawed back at its end if the member casts fewer
+        /// than `MinCouncilVotesPerTerm` votes.
+        #[pallet::call_index(13)]
+        #[pallet::weight(10_000)]
+        pub fn claim_council_salary(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let accrued = if CouncilMembers::<T>::get().contains(&who) {
+                let now = frame_system::Pallet::<T>::block_number();
+                Self::council_term_salary(now).saturating_sub(CouncilSalaryPaid::<T>::get(&who))
+            } else {
+                Zero::zero()
+            };
+            let amount = CouncilSalaries::<T>::take(&who).saturating_add(accrued);
+            ensure!(!amount.is_zero(), Error::<T>::NoCouncilSalary);
+            T::Currency::transfer(&T::Treasury::get(), &who, amount, ExistenceRequirement::KeepAlive)?;
+            CouncilSalaryPaid::<T>::mutate(&who, |paid| *paid = paid.saturating_add(accrued));
+
+            Self::deposit_event(Event::CouncilSalaryClaimed { member: who, amount });
+
+            Ok(())
+        }
+
+        /// Register, replace or (with `None`) remove the template of a parameter
+        ///
+        /// Proposals changing the parameter are checked against the template when created
+        /// and again when executed.
+        #[pallet::call_index(14)]
+        #[pallet::weight(10_000)]
+        pub fn set_parameter_template(
+            origin: OriginFor<T>,
+            parameter: ParameterName,
+            template: Option<ParameterTemplate>,
+        ) -> DispatchResult {
+            T::TemplateOrigin::ensure_origin(origin)?;
+
+            match &template {
+                Some(template) => {
+                    ensure!(
+                        (template.min..=template.max).contains(&template.current),
+                        Error::<T>::InvalidParameterTemplate
+                    );
+                    ParameterTemplates::<T>::insert(&parameter, template);
+                },
+                None => ParameterTemplates::<T>::remove(&parameter),
+            }
+
+            Self::deposit_event(Event::ParameterTemplateSet { parameter, template });
+
+            Ok(())
+        }
+
+        /// Remove a proposal that outlived its `ProposalRetentionPeriod`, with its votes and
+        /// milestones, and pay the caller `ProposalCleanupReward`
+        ///
+        /// Each call removes at most `MaxVotesPrunedPerCleanup` votes, resuming where the
+        /// previous call stopped; the proposal itself is removed, the reward paid and the
+        /// deposit of a proposal that was never executed returned by the call that removes
+        /// the last of them.
+        #[pallet::call_index(16)]
+        #[pallet::weight(Weight::from_parts(10_000, 0)
+            .saturating_add(T::DbWeight::get().reads_writes(5, 5 + 2 * T::MaxVotesPrunedPerCleanup::get() as u64)))]
+        pub fn cleanup_proposal(origin: OriginFor<T>, proposal_id: ProposalId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let proposal = Proposals::<T>::get(proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+            let closed_at = ProposalClosedAt::<T>::get(proposal_id).unwrap_or(proposal.voting_end);
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= closed_at.saturating_add(T::ProposalRetentionPeriod::get()),
+                Error::<T>::ProposalRetained
+            );
+            ensure!(
+                GrantMilestones::<T>::get(proposal_id).iter().all(|milestone| milestone.paid),
+                Error::<T>::GrantInProgress
+            );
+
+            let limit = T::MaxVotesPrunedPerCleanup::get();
+            let (votes_cursor, powers_cursor) = CleanupCursors::<T>::get(proposal_id).unwrap_or_default();
+            let votes = Votes::<T>::clear_prefix(proposal_id, limit, votes_cursor.as_ref().map(|c| c.as_slice()));
+            let powers =
+                VotingPower::<T>::clear_prefix(proposal_id, limit, powers_cursor.as_ref().map(|c| c.as_slice()));
+            if votes.maybe_cursor.is_some() || powers.maybe_cursor.is_some() {
+                // Removals are only visible to iteration once committed, so the next call
+                // must resume from the cursor rather than the start of the prefix
+                let bound = |cursor: Option<Vec<u8>>| cursor.and_then(|raw| CleanupCursor::try_from(raw).ok());
+                CleanupCursors::<T>::insert(proposal_id, (bound(votes.maybe_cursor), bound(powers.maybe_cursor)));
+                return Ok(());
+            }
+            CleanupCursors::<T>::remove(proposal_id);
+
+            Proposals::<T>::remove(proposal_id);
+            ProposalClosedAt::<T>::remove(proposal_id);
+            GrantMilestones::<T>::remove(proposal_id);
+            if !proposal.executed && !proposal.cancelled {
+                T::Currency::unreserve(&proposal.proposer, T::ProposalDeposit::get());
+            }
+            Self::release_endorsements(proposal_id);
+
+            // The reward is best effort: cleanup must not stall on an empty grants account
+            let reward = T::ProposalCleanupReward::get();
+            let reward = match T::Currency::transfer(&Self::grants_account(), &who, reward, ExistenceRequirement::KeepAlive) {
+                Ok(()) => reward,
+                Err(_) => Zero::zero(),
+            };
+            Self::deposit_event(Event::ProposalCleanedUp { proposal_id, cleaner: who, reward });
+
+            Ok(())
+        }
+
+        /// Set or (with `None`) remove the thresholds of proposals tagged `tag`
+        ///
+        /// Thresholds are read when a proposal is executed, so changes apply to open
+        /// proposals too.
+        #[pallet::call_index(17)]
+        #[pallet::weight(10_000)]
+        pub fn set_track_thresholds(origin: OriginFor<T>, tag: SkillTag, track: Option<Track>) -> DispatchResult {
+            T::TrackOrigin::ensure_origin(origin)?;
+
+            match &track {
+                Some(track) => {
+                    ensure!(track.quorum <= 100 && track.supermajority <= 100, Error::<T>::InvalidTrackThresholds);
+                    TrackThresholds::<T>::insert(&tag, track);
+                },
+                None => TrackThresholds::<T>::remove(&tag),
+            }
+
+            Self::deposit_event(Event::TrackThresholdsSet { tag, track });
+
+            Ok(())
+        }
+
+        /// Propose changing a templated parameter to `new_value`, tagged with the
+        /// template's tags
+        #[pallet::call_index(15)]
+        #[pallet::weight(10_000)]
+        pub fn propose_parameter_change(
+            origin: OriginFor<T>,
+            parameter: ParameterName,
+            new_value: u64,
+            description: BoundedVec<u8, ConstU32<256>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let template = ParameterTemplates::<T>::get(&parameter).ok_or(Error::<T>::UnknownParameterTemplate)?;
+            let proposal_type = ProposalType::ParameterChange {
+                parameter: parameter.into_inner(),
+                new_value: new_value.encode(),
+            };
+            Self::do_create_proposal(who, proposal_type, template.tags, description)
+        }
+
+        /// Endorse a proposal waiting for endorsements, reserving `EndorsementDeposit`
+        ///
+        /// The proposal's vote opens with the endorsement that brings it to
+        /// `RequiredEndorsements`. The deposit is returned when the proposal closes or
+        /// expires, or slashed if the council marks it as spam.
+        #[pallet::call_index(18)]
+        #[pallet::weight(Weight::from_parts(10_000, 0).saturating_add(T::DbWeight::get().reads_writes(5, 4)))]
+        pub fn endorse_proposal(origin: OriginFor<T>, proposal_id: ProposalId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let pending = PendingProposals::<T>::get(proposal_id).ok_or(Error::<T>::NotAwaitingEndorsement)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() < pending.submitted + T::PendingProposalExpiry::get(),
+                Error::<T>::PendingProposalExpired
+            );
+            ensure!(who != pending.proposer, Error::<T>::CannotEndorseOwnProposal);
+            let reputation = T::Reputation::raw_reputation_of(&who);
+            ensure!(reputation >= T::EndorsementReputation::get(), Error::<T>::InsufficientReputation);
+            ensure!(!T::Reputation::is_on_probation(&who), Error::<T>::OnProbation);
+
+            let mut endorsers = Endorsements::<T>::get(proposal_id);
+            ensure!(!endorsers.contains(&who), Error::<T>::AlreadyEndorsed);
+            endorsers.try_push(who.clone()).map_err(|_| Error::<T>::NotAwaitingEndorsement)?;
+            T::Currency::reserve(&who, T::EndorsementDeposit::get())?;
+
+            let endorsements = endorsers.len() as u32;
+            Endorsements::<T>::insert(proposal_id, endorsers);
+            Self::deposit_event(Event::ProposalEndorsed { proposal_id, endorser: who, endorsements });
+
+            if endorsements >= Self::required_endorsements() {
+                PendingProposals::<T>::remove(proposal_id);
+                Self::open_proposal(proposal_id, pending.proposer, pending.proposal_type, pending.tags, pending.description);
+            }
+
+            Ok(())
+        }
+
+        /// Cancel an open or pending proposal as spam (`SpamOrigin` only), slashing the
+        /// deposits of its proposer and endorsers
+        #[pallet::call_index(19)]
+        #[pallet::weight(Weight::from_parts(10_000, 0)
+            .saturating_add(T::DbWeight::get().reads_writes(3, 3 + MAX_ENDORSEMENTS as u64)))]
+        pub fn mark_proposal_spam(origin: OriginFor<T>, proposal_id: ProposalId) -> DispatchResult {
+            T::SpamOrigin::ensure_origin(origin)?;
+
+            let proposer = match PendingProposals::<T>::take(proposal_id) {
+                Some(pending) => pending.proposer,
+                None => {
+                    let mut proposal = Proposals::<T>::get(proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+                    ensure!(!proposal.executed, Error::<T>::CannotCancelExecutedProposal);
+                    ensure!(!proposal.cancelled, Error::<T>::ProposalNotExecutable);
+                    let now = frame_system::Pallet::<T>::block_number();
+                    ensure!(now < proposal.voting_end, Error::<T>::VotingClosed);
+
+                    proposal.cancelled = true;
+                    let proposer = proposal.proposer.clone();
+                    Proposals::<T>::insert(proposal_id, proposal);
+                    ProposalClosedAt::<T>::insert(proposal_id, now);
+                    proposer
+                },
+            };
+
+            // Slashed deposits are burned
+            let endorsers = Endorsements::<T>::take(proposal_id);
+            let _ = T::Currency::slash_reserved(&proposer, T::ProposalDeposit::get());
+            for endorser in endorsers.iter() {
+                let _ = T::Currency::slash_reserved(endorser, T::EndorsementDeposit::get());
+            }
+
+            Self::deposit_event(Event::ProposalMarkedSpam { proposal_id, proposer, endorsers: endorsers.len() as u32 });
+
+            Ok(())
+        }
+
+        /// Remove a proposal that waited longer than `PendingProposalExpiry` for endorsements,
+        /// returning the deposits of its proposer and endorsers (anyone can call)
+        #[pallet::call_index(20)]
+        #[pallet::weight(Weight::from_parts(10_000, 0)
+            .saturating_add(T::DbWeight::get().reads_writes(3, 2 + MAX_ENDORSEMENTS as u64)))]
+        pub fn expire_pending_proposal(origin: OriginFor<T>, proposal_id: ProposalId) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let pending = PendingProposals::<T>::get(proposal_id).ok_or(Error::<T>::NotAwaitingEndorsement)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= pending.submitted + T::PendingProposalExpiry::get(),
+                Error::<T>::PendingProposalNotExpired
+            );
+
+            PendingProposals::<T>::remove(proposal_id);
+            Self::release_endorsements(proposal_id);
+            T::Currency::unreserve(&pending.proposer, T::ProposalDeposit::get());
+
+            Self::deposit_event(Event::PendingProposalExpired { proposal_id, proposer: pending.proposer.clone() });
+            Self::deposit_event(Event::DepositReturned {
+                account: pending.proposer,
+                proposal_id,
+                amount: T::ProposalDeposit::get(),
+            });
+
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
+        /// Account grant milestones are paid from
+        pub fn grants_account() -> T::AccountId {
+            T::PalletId::get().into_account_truncating()
+        }
+
+        /// Up to `limit` delegations, grouped by delegatee
+        pub fn delegation_graph(limit: u32) -> Vec<DelegationEdge<T::AccountId>> {
+            DelegationsTo::<T>::iter_keys()
+                .filter_map(|(_, delegator)| Delegations::<T>::get(&delegator))
+                .take(limit as usize)
+                .map(|delegation| DelegationEdge {
+                    delegator: delegation.delegator,
+                    delegatee: delegation.delegatee,
+                    amount: delegation.amount,
+                    proposal_id: delegation.proposal_id,
+                })
+                .collect()
+        }
+
+        /// The `limit` accounts with the most globally delegated power among a page of
+        /// delegatees, most first
+        ///
+        /// A page scans about `MAX_DELEGATION_SCAN` delegations in storage order, starting
+        /// after the raw `DelegationsTo` key `cursor`, and also returns the cursor of the
+        /// next page, if any. The global ranking is the top `limit` of all pages' results.
+        ///
+        /// # Errors
+        /// Returns `Error::TooManyDelegations` if a delegatee received more than
+        /// `MaxDelegationsPerDelegatee` delegations
+        pub fn top_delegatees(
+            cursor: Option<Vec<u8>>,
+            limit: u32,
+        ) -> Result<(Vec<(T::AccountId, VotePower)>, Option<Vec<u8>>), DispatchError> {
+            let mut keys = match cursor {
+                Some(cursor) => DelegationsTo::<T>::iter_keys_from(cursor),
+                None => DelegationsTo::<T>::iter_keys(),
+            };
+            let max_delegations = T::MaxDelegationsPerDelegatee::get();
+
+            let mut top: Vec<(T::AccountId, VotePower)> = Vec::new();
+            let (mut scanned, mut received) = (0u32, 0u32);
+            let mut previous_key = Vec::new();
+            let mut next = None;
+            while let Some((delegatee, delegator)) = keys.next() {
+                let same_delegatee = top.last().map_or(false, |(current, _)| *current == delegatee);
+                if !same_delegatee {
+                    // Pages end between delegatees, so each is counted on one page only
+                    if scanned >= MAX_DELEGATION_SCAN {
+                        next = Some(previous_key);
+                        break;
+                    }
+                    top.push((delegatee, 0));
+                    received = 0;
+                }
+                received += 1;
+                ensure!(received <= max_delegations, Error::<T>::TooManyDelegations);
+                scanned += 1;
+                previous_key = keys.last_raw_key().to_vec();
+
+                if let Some(delegation) = Delegations::<T>::get(&delegator).filter(|d| d.proposal_id.is_none()) {
+                    if let Some((_, power)) = top.last_mut() {
+                        *power = power.saturating_add(delegation.amount);
+                    }
+                }
+            }
+
+            top.sort_by(|(_, a), (_, b)| b.cmp(a));
+            top.truncate(limit as usize);
+            Ok((top, next))
+        }
+
+        /// Voting power of an account on a proposal without tags: its quadratic reputation
+        /// plus the power delegated to it globally
+        ///
+        /// # Errors
+        /// Returns `Error::TooManyDelegations` if the account received more than
+        /// `MaxDelegationsPerDelegatee` delegations
+        pub fn effective_power(who: &T::AccountId) -> Result<VotePower, DispatchError> {
+            let own = isqrt(T::Reputation::reputation_of(who).max(0) as u64);
+            Ok(own.saturating_add(Self::get_delegated_power(who, None)?))
+        }
+
+        /// Salary a council member accrued from the current term's start until `now`, but
+        /// not past its end
+        fn council_term_salary(now: BlockNumberFor<T>) -> BalanceOf<T> {
+            let served = now.min(CouncilTermEnd::<T>::get()).saturating_sub(CouncilTermStart::<T>::get());
+            T::CouncilSalaryPerBlock::get().saturating_mul(served.saturated_into::<u64>().saturated_into())
+        }
+
+        /// Credit the ending term's unclaimed salary to council members who cast
+        /// `MinCouncilVotesPerTerm` votes; the others forfeit it, and whatever they already
+        /// claimed this term is slashed back to the treasury
+        fn settle_council_salaries(council: &[T::AccountId], now: BlockNumberFor<T>) {
+            let salary = Self::council_term_salary(now);
+
+            for member in council {
+                let votes = CouncilVotes::<T>::take(member);
+                let paid = CouncilSalaryPaid::<T>::take(member);
+                if salary.is_zero() {
+                    continue;
+                }
+                if votes >= T::MinCouncilVotesPerTerm::get() {
+                    let unclaimed = salary.saturating_sub(paid);
+                    CouncilSalaries::<T>::mutate(member, |earned| *earned = earned.saturating_add(unclaimed));
+                    Self::deposit_event(Event::CouncilSalaryEarned { member: member.clone(), amount: salary });
+                } else {
+                    let (clawback, _) = T::Currency::slash(member, paid);
+                    let recovered = clawback.peek();
+                    T::Currency::resolve_creating(&T::Treasury::get(), clawback);
+                    Self::deposit_event(Event::CouncilSalaryClawedBack {
+                        member: member.clone(),
+                        amount: salary,
+                        recovered,
+                        votes,
+                    });
+                }
+            }
+        }
+
+        /// Create a proposal on behalf of `who`, reserving its deposit
+        fn do_create_proposal(
+            who: T::AccountId,
+            proposal_type: ProposalType,
+            mut tags: BoundedVec<SkillTag, ConstU32<5>>,
+            description: BoundedVec<u8, ConstU32<256>>,
+        ) -> DispatchResult {
+            // Check proposal threshold
+            let reputation = T::Reputation::raw_reputation_of(&who);
+            ensure!(
+                reputation >= T::MinProposalReputation::get(),
+                Error::<T>::InsufficientReputation
+            );
+            ensure!(!T::Reputation::is_on_probation(&who), Error::<T>::OnProbation);
+            match &proposal_type {
+                ProposalType::Custom { tag, .. } => {
+                    ensure!(T::CustomProposalHandler::handles(tag), Error::<T>::UnknownProposalTag);
+                },
+                ProposalType::ParameterChange { parameter, new_value } => {
+                    Self::check_parameter_change(parameter, new_value)?;
+                    // Expertise is weighed by the template's tags, not the proposer's
+                    tags = BoundedVec::truncate_from(Self::track_tags(&proposal_type));
+                },
+                _ => {},
+            }
+
+            // Take deposit
+            T::Currency::reserve(&who, T::ProposalDeposit::get())?;
+
+            let proposal_id = NextProposalId::<T>::get();
+            NextProposalId::<T>::put(proposal_id + 1);
+
+            // Proposals of accounts below the endorsement tier wait for endorsements
+            if reputation < T::EndorsementReputation::get() && Self::required_endorsements() > 0 {
+                PendingProposals::<T>::insert(
+                    proposal_id,
+                    PendingProposal {
+                        proposer: who.clone(),
+                        proposal_type,
+                        tags,
+                        description,
+                        submitted: frame_system::Pallet::<T>::block_number(),
+                    },
+                );
+                Self::deposit_event(Event::ProposalAwaitingEndorsement { proposal_id, proposer: who });
+                return Ok(());
+            }
+
+            Self::open_proposal(proposal_id, who, proposal_type, tags, description);
+            Ok(())
+        }
+
+        /// Open the vote on a proposal from now
+        fn open_proposal(
+            proposal_id: ProposalId,
+            who: T::AccountId,
+            proposal_type: ProposalType,
+            tags: BoundedVec<SkillTag, ConstU32<5>>,
+            description: BoundedVec<u8, ConstU32<256>>,
+        ) {
+            let now = frame_system::Pallet::<T>::block_number();
+            let voting_end = now + T::VotingPeriod::get();
+            let execution_delay = T::ExecutionDelayPeriod::get();
+            let execution_ready_at = Some(voting_end + execution_delay);
+
+            // Calculate total available voting power for quorum (simplified - in production, 
+            // this should query all accounts with reputation)
+            let total_voting_power = Self::estimate_total_voting_power();
+
+            let proposal = Proposal {
+                id: proposal_id,
+                proposer: who.clone(),
+                proposal_type: proposal_type.clone(),
+                tags,
+                description,
+                created: now,
+                voting_end,
+                execution_delay,
+                execution_ready_at,
+                cancelled: false,
+                executed: false,
+                for_votes: 0,
+                against_votes: 0,
+                total_voting_power,
+            };
+
+            Proposals::<T>::insert(proposal_id, proposal);
+
+            Self::deposit_event(Event::ProposalCreated {
+                proposal_id,
+                proposer: who,
+                proposal_type,
+            });
+        }
+
+        /// Index delegations by delegatee within `budget`, resuming from the migration cursor
+        ///
+        /// Delegations beyond `MaxDelegationsPerDelegatee` for one delegatee are dropped,
+        /// keeping the first ones in storage order. The storage version is bumped once every
+        /// delegation is indexed.
+        pub fn step_migration(budget: &mut dotrep_primitives::HookBudget) {
+            let Some(cursor) = MigrationInProgress::<T>::get() else { return };
+            let max_delegations = T::MaxDelegationsPerDelegatee::get();
+            let item_weight = T::DbWeight::get().reads_writes(max_delegations as u64 + 2, 2);
+
+            let mut delegations = if cursor.is_empty() {
+                Delegations::<T>::iter()
+            } else {
+                Delegations::<T>::iter_from(cursor.into_inner())
+            };
+            loop {
+                if !budget.try_consume(item_weight) {
+                    let cursor = BoundedVec::truncate_from(delegations.last_raw_key().to_vec());
+                    MigrationInProgress::<T>::put(cursor);
+                    return;
+                }
+                let Some((delegator, delegation)) = delegations.next() else { break };
+                let delegatee = delegation.delegatee;
+                if DelegationsTo::<T>::contains_key(&delegatee, &delegator) {
+                    continue;
+                }
+                let received = DelegationsTo::<T>::iter_key_prefix(&delegatee).bounded(max_delegations).count() as u32;
+                if received < max_delegations {
+                    DelegationsTo::<T>::insert(&delegatee, &delegator, ());
+                } else {
+                    Delegations::<T>::remove(&delegator);
+                    Self::deposit_event(Event::DelegationDropped { delegator, delegatee });
+                }
+            }
+
+            MigrationInProgress::<T>::kill();
+            StorageVersion::new(1).put::<Pallet<T>>();
+            Self::deposit_event(Event::MigrationCompleted);
+        }
+
+        /// Reject calls reading delegations while they are being migrated
+        fn ensure_not_migrating() -> DispatchResult {
+            ensure!(!MigrationInProgress::<T>::exists(), Error::<T>::MigrationInProgress);
+            Ok(())
+        }
+
+        /// Endorsements a pending proposal needs, capped at `MAX_ENDORSEMENTS`
+        pub fn required_endorsements() -> u32 {
+            T::RequiredEndorsements::get().min(MAX_ENDORSEMENTS)
+        }
+
+        /// Return the deposits of a proposal's endorsers
+        fn release_endorsements(proposal_id: ProposalId) {
+            for endorser in Endorsements::<T>::take(proposal_id) {
+                T::Currency::unreserve(&endorser, T::EndorsementDeposit::get());
+            }
+        }
+
+        /// Quorum and, if one is required, supermajority (both in percent) a proposal must
+        /// reach to pass
+        ///
+        /// Runtime upgrades and treasury spends need `SupermajorityThreshold`; tracks can
+        /// only raise the configured thresholds, and a track supermajority applies to any
+        /// proposal type. Tracks are selected by `track_tags`, never by the proposer's tags.
+        fn voting_thresholds(proposal: &Proposal<T>) -> (u8, Option<u8>) {
+            let requires_supermajority = matches!(
+                proposal.proposal_type,
+                ProposalType::RuntimeUpgrade { .. } | ProposalType::TreasurySpend { .. }
+            );
+            let base = (
+                T::QuorumThreshold::get(),
+                requires_supermajority.then(T::SupermajorityThreshold::get),
+            );
+
+            Self::track_tags(&proposal.proposal_type).iter().filter_map(TrackThresholds::<T>::get).fold(
+                base,
+                |(quorum, supermajority), track| {
+                    let track_supermajority = (track.supermajority > 0).then_some(track.supermajority);
+                    (quorum.max(track.quorum), supermajority.max(track_supermajority))
+                },
+            )
+        }
+
+        /// Tags whose tracks a proposal's thresholds are read from
+        ///
+        /// Parameter changes take the tags of their parameter's template and custom proposals
+        /// their handler's tag; other proposal types have no track.
+        fn track_tags(proposal_type: &ProposalType) -> Vec<SkillTag> {
+            match proposal_type {
+                ProposalType::ParameterChange { parameter, .. } => ParameterName::try_from(parameter.clone())
+                    .ok()
+                    .and_then(ParameterTemplates::<T>::get)
+                    .map(|template| template.tags.into_inner())
+                    .unwrap_or_default(),
+                ProposalType::Custom { tag, .. } => vec![tag.clone()],
+                _ => Vec::new(),
+            }
+        }
+
+        /// Check a parameter change against the parameter's template
+        ///
+        /// Only parameters with a template can be changed, so every change is bounded and
+        /// voted on in its template's tracks. Values are SCALE-encoded `u64`s within the
+        /// template's bounds and at most `max_change` (or `min_step`, if larger) away from
+        /// its current value. Returns the decoded value.
+        ///
+        /// # Errors
+        ///
+        /// Returns `Error::UnknownParameterTemplate` if the parameter has no template.
+        fn check_parameter_change(parameter: &[u8], new_value: &[u8]) -> Result<u64, DispatchError> {
+            let template = ParameterName::try_from(parameter.to_vec())
+                .ok()
+                .and_then(ParameterTemplates::<T>::get)
+                .ok_or(Error::<T>::UnknownParameterTemplate)?;
+
+            let value = <[u8; 8]>::try_from(new_value)
+                .map(u64::from_le_bytes)
+                .map_err(|_| Error::<T>::InvalidParameterValue)?;
+            let change = value.abs_diff(template.current);
+            let max_change = template.max_change.mul_floor(template.current).max(template.min_step);
+            ensure!(
+                (template.min..=template.max).contains(&value) && change <= max_change,
+                Error::<T>::ParameterChangeOutOfBounds
+            );
+            Ok(value)
+        }
+
+        /// Beneficiary of an executed treasury spend proposal
+        fn grant_beneficiary(proposal_id: ProposalId) -> Result<T::AccountId, DispatchError> {
+            let proposal = Proposals::<T>::get(proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+            let ProposalType::TreasurySpend { beneficiary, .. } = proposal.proposal_type else {
+                return Err(Error::<T>::NotTreasurySpend.into());
+            };
+            ensure!(proposal.executed, Error::<T>::GrantNotApproved);
+            Ok(beneficiary)
+        }
+
         /// Calculate voting power with quadratic weighting and expertise boost
         fn calculate_voting_power(
             voter: &T::AccountId,
             proposal: &Proposal<T>,
-        ) -> Result<ReputationScore, DispatchError> {
-            // 1. Get base reputation (convert from i32 to u64)
-            let base_reputation_i32 = T::Reputation::get_reputation_score(voter);
-            let base_reputation = base_reputation_i32.max(0) as u64;
+        ) -> Result<VotePower, DispatchError> {
+            // 1. Get base reputation including staking credibility boost, floored at zero
+            let base_reputation = T::Reputation::reputation_of(voter).max(0) as VotePower;
 
             // 2. Apply quadratic weighting: sqrt(reputation)
             // Use fixed-point arithmetic for sqrt calculation
-            let quadratic_power = Self::sqrt_u64(base_reputation);
+            let quadratic_power = isqrt(base_reputation);
 
             // 3. Calculate expertise multiplier
             let voter_skills = SkillTags::<T>::get(voter);
             let expertise_multiplier = Self::calculate_expertise_boost(&proposal.tags, &voter_skills);
 
             // 4. Include delegated voting power (both global and per-proposal)
-            let delegated_power = Self::get_delegated_power(voter, Some(proposal.id));
+            let delegated_power = Self::get_delegated_power(voter, Some(proposal.id))?;
 
             // 5. Final voting power
             let final_power = quadratic_power.saturating_mul(expertise_multiplier).saturating_add(delegated_power);
@@ -745,7 +1947,7 @@ pub mod pallet {
         fn calculate_expertise_boost(
             proposal_tags: &BoundedVec<SkillTag, ConstU32<5>>,
             user_skills: &BoundedVec<SkillTag, ConstU32<10>>,
-        ) -> ReputationScore {
+        ) -> VotePower {
             if proposal_tags.is_empty() || user_skills.is_empty() {
                 return 1;
             }
@@ -768,32 +1970,26 @@ pub mod pallet {
 
         /// Get total voting power delegated to an account
         /// If proposal_id is Some, includes both global delegations and per-proposal delegations
-        fn get_delegated_power(delegatee: &T::AccountId, proposal_id: Option<ProposalId>) -> ReputationScore {
-            Delegations::<T>::iter()
-                .filter(|(_, delegation)| {
-                    &delegation.delegatee == delegatee &&
-                    (delegation.proposal_id.is_none() || delegation.proposal_id == proposal_id)
-                })
-                .map(|(_, delegation)| delegation.amount)
-                .sum()
-        }
-
-        /// Get total delegations received by an account
-        /// If proposal_id is Some, only counts delegations for that proposal (global + per-proposal)
-        fn get_total_delegations_to(delegatee: &T::AccountId, proposal_id: Option<ProposalId>) -> ReputationScore {
-            Delegations::<T>::iter()
-                .filter(|(_, delegation)| {
-                    &delegation.delegatee == delegatee &&
-                    (delegation.proposal_id.is_none() || delegation.proposal_id == proposal_id)
+        fn get_delegated_power(
+            delegatee: &T::AccountId,
+            proposal_id: Option<ProposalId>,
+        ) -> Result<VotePower, DispatchError> {
+            DelegationsTo::<T>::iter_key_prefix(delegatee)
+                .bounded(T::MaxDelegationsPerDelegatee::get())
+                .fold_bounded(0, |total: VotePower, delegator| {
+                    match Delegations::<T>::get(&delegator) {
+                        Some(delegation) if delegation.proposal_id.is_none() || delegation.proposal_id == proposal_id =>
+                            total.saturating_add(delegation.amount),
+                        _ => total,
+                    }
                 })
-                .map(|(_, delegation)| delegation.amount)
-                .sum()
+                .map_err(|_| Error::<T>::TooManyDelegations.into())
         }
         
         /// Estimate total voting power in the system (for quorum calculation)
         /// In production, this should query all accounts with reputation
         /// This is a simplified implementation that uses a reasonable estimate
-        fn estimate_total_voting_power() -> ReputationScore {
+        fn estimate_total_voting_power() -> VotePower {
             // Simplified: assume average reputation and multiply by estimated active accounts
             // In production, this would iterate through reputation scores
             // For now, return a large number to allow quorum checks
@@ -838,6 +2034,8 @@ pub mod pallet {
         fn execute_proposal_internal(proposal: &Proposal<T>) -> DispatchResult {
             match &proposal.proposal_type {
                 ProposalType::TreasurySpend { amount: _, beneficiary: _ } => {
+                    // Grants split into milestones are paid by `release_milestone_payment`
+                    // as each delivery is verified.
                     // Treasury spending logic would go here
                     // In a real implementation, this would interact with treasury pallet
                     Ok(())
@@ -847,8 +2045,22 @@ pub mod pallet {
                     // In a real implementation, this would use set_code
                     Ok(())
                 },
-                ProposalType::ParameterChange { parameter: _, new_value: _ } => {
-                    // Parameter change logic would go here
+                ProposalType::ParameterChange { parameter, new_value } => {
+                    // The template may have changed since the proposal was created
+                    let value = Self::check_parameter_change(parameter, new_value)?;
+                    let parameter = ParameterName::truncate_from(parameter.clone());
+                    let old_value = ParameterTemplates::<T>::mutate(&parameter, |template| {
+                        template.as_mut().map(|template| sp_std::mem::replace(&mut template.current, value))
+                    });
+                    if let Some(old_value) = old_value {
+                        Self::deposit_event(Event::TemplatedParameterChanged {
+                            proposal_id: proposal.id,
+                            parameter,
+                            old_value,
+                            new_value: value,
+                        });
+                    }
+                    // Applying the value is left to the parameter's pallet
                     Ok(())
                 },
                 ProposalType::CouncilElection => {
@@ -856,30 +2068,13 @@ pub mod pallet {
                     let _ = Self::rotate_council(RawOrigin::Root.into());
                     Ok(())
                 },
-                ProposalType::Custom { tag: _, data: _ } => {
-                    // Custom proposal execution logic
-                    Ok(())
+                ProposalType::Custom { tag, data } => {
+                    // The handler may have been removed by a runtime upgrade since creation
+                    ensure!(T::CustomProposalHandler::handles(tag), Error::<T>::UnknownProposalTag);
+                    T::CustomProposalHandler::execute(tag, data)
                 },
             }
         }
-
-        /// Integer square root using binary search (for quadratic voting)
-        fn sqrt_u64(n: u64) -> u64 {
-            if n == 0 {
-                return 0;
-            }
-            if n < 4 {
-                return 1;
-            }
-            
-            let mut x = n;
-            let mut y = (x + 1) / 2;
-            while y < x {
-                x = y;
-                y = (x + n / x) / 2;
-            }
-            x
-        }
     }
 
     // Council origin for fast-tracked proposals
@@ -906,9 +2101,3 @@ pub mod pallet {
         }
     }
 }
-
-/// Interface for the Reputation pallet
-pub trait ReputationInterface<T: frame_system::Config> {
-    fn get_reputation_score(account: &T::AccountId) -> i32;
-}
-
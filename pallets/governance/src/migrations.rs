@@ -0,0 +1,37 @@
+//! Storage migrations of the governance pallet
+
+/// v1: index existing delegations by delegatee
+///
+/// Delegated voting power is read from `DelegationsTo`; delegations made before the
+/// index existed are added to it by `Pallet::step_migration`, within the
+/// `HookTask::Migrations` quota of each block. Delegations beyond
+/// `MaxDelegationsPerDelegatee` are dropped, as they would make the delegatee's power
+/// unreadable.
+pub mod v1 {
+    use crate::pallet::{Config, MigrationInProgress, Pallet};
+    use dotrep_primitives::{HookQuotaProvider, HookTask};
+    use frame_support::{
+        traits::{Get, GetStorageVersion, OnRuntimeUpgrade},
+        weights::Weight,
+        BoundedVec,
+    };
+    use sp_std::marker::PhantomData;
+
+    /// Start the v1 migration and take its first step
+    pub struct MigrateToV1<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let weight = T::DbWeight::get().reads(2);
+            if Pallet::<T>::on_chain_storage_version() != 0 || MigrationInProgress::<T>::exists() {
+                return weight;
+            }
+
+            MigrationInProgress::<T>::put(BoundedVec::default());
+            let mut budget = T::HookQuotas::hook_budget(HookTask::Migrations, T::BlockWeights::get().max_block);
+            Pallet::<T>::step_migration(&mut budget);
+            T::HookQuotas::note_hook_usage(HookTask::Migrations, budget.consumed());
+            weight.saturating_add(budget.consumed()).saturating_add(T::DbWeight::get().reads_writes(1, 2))
+        }
+    }
+}
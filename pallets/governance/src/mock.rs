@@ -1,10 +1,10 @@
 use crate as pallet_governance;
-use crate::pallet::ReputationInterface;
 use pallet_reputation as pallet_rep;
 
 use frame_support::{
     parameter_types,
     traits::{OnFinalize, OnInitialize},
+    PalletId,
 };
 use sp_core::H256;
 use sp_runtime::{
@@ -83,6 +83,9 @@ parameter_types! {
     pub const MaxContributionsPerAccount: u32 = 5;
     pub const MinReputation: i32 = 0;
     pub const MaxReputation: i32 = 1000;
+    pub const MaxCredibilityBoost: u32 = 50;
+    pub const ClaimUpheldReward: i32 = 25;
+    pub const ClaimRejectedPenalty: i32 = 40;
 }
 
 impl pallet_rep::Config for Test {
@@ -90,21 +93,55 @@ impl pallet_rep::Config for Test {
     type MaxContributionsPerAccount = MaxContributionsPerAccount;
     type MinReputation = MinReputation;
     type MaxReputation = MaxReputation;
-}
-
-// Mock ReputationInterface implementation
-impl ReputationInterface<Test> for pallet_rep::Pallet<Test> {
-    fn get_reputation_score(account: &u64) -> i32 {
-        pallet_rep::Pallet::<Test>::get_reputation(account)
-    }
+    type OnOffence = ();
+    type CredibilityBoost = ();
+    type MaxCredibilityBoost = MaxCredibilityBoost;
+    type ClaimUpheldReward = ClaimUpheldReward;
+    type ClaimRejectedPenalty = ClaimRejectedPenalty;
 }
 
 // Governance pallet configuration
 parameter_types! {
-    pub const MinProposalReputation: u64 = 100;
+    pub const MinProposalReputation: i32 = 100;
     pub const ProposalDeposit: u64 = 1_000_000;
     pub const VotingPeriod: u64 = 100;
     pub const CouncilSize: u32 = 7;
+    pub const GrantsPalletId: PalletId = PalletId(*b"dr/grant");
+    pub const MaxMilestones: u32 = 4;
+    pub const MilestoneContributionWeight: u8 = 100;
+    pub const MaxDelegationsPerDelegatee: u32 = 3;
+    pub const CouncilSalaryPerBlock: u64 = 10;
+    pub const CouncilTreasury: u64 = 99;
+    pub const MinCouncilVotesPerTerm: u32 = 1;
+    pub const ProposalRetentionPeriod: u64 = 100;
+    pub const ProposalCleanupReward: u64 = 5;
+    pub const MaxVotesPrunedPerCleanup: u32 = 2;
+    pub const EndorsementReputation: i32 = 300;
+    pub const RequiredEndorsements: u32 = 2;
+    pub const EndorsementDeposit: u64 = 1_000;
+    pub const PendingProposalExpiry: u64 = 50;
+    pub static RegistryUpdates: Vec<Vec<u8>> = Vec::new();
+}
+
+/// Handles `registry` custom proposals by recording their payload; empty payloads fail
+pub struct RegistryProposals;
+
+impl dotrep_primitives::CustomProposalHandler for RegistryProposals {
+    fn handles(tag: &[u8]) -> bool {
+        tag == b"registry"
+    }
+
+    fn max_weight() -> frame_support::weights::Weight {
+        frame_support::weights::Weight::from_parts(5_000, 0)
+    }
+
+    fn execute(_tag: &[u8], data: &[u8]) -> sp_runtime::DispatchResult {
+        if data.is_empty() {
+            return Err(sp_runtime::DispatchError::Other("Empty registry update"));
+        }
+        RegistryUpdates::mutate(|updates| updates.push(data.to_vec()));
+        Ok(())
+    }
 }
 
 impl pallet_governance::Config for Test {
@@ -115,6 +152,28 @@ impl pallet_governance::Config for Test {
     type ProposalDeposit = ProposalDeposit;
     type VotingPeriod = VotingPeriod;
     type CouncilSize = CouncilSize;
+    type OnSkillTagsUpdated = ();
+    type Contributions = ();
+    type PalletId = GrantsPalletId;
+    type MaxMilestones = MaxMilestones;
+    type MilestoneContributionWeight = MilestoneContributionWeight;
+    type MaxDelegationsPerDelegatee = MaxDelegationsPerDelegatee;
+    type CustomProposalHandler = (RegistryProposals,);
+    type CouncilSalaryPerBlock = CouncilSalaryPerBlock;
+    type Treasury = CouncilTreasury;
+    type MinCouncilVotesPerTerm = MinCouncilVotesPerTerm;
+    type TemplateOrigin = frame_system::EnsureRoot<u64>;
+    type TrackOrigin = frame_system::EnsureRoot<u64>;
+    type ProposalRetentionPeriod = ProposalRetentionPeriod;
+    type ProposalCleanupReward = ProposalCleanupReward;
+    type MaxVotesPrunedPerCleanup = MaxVotesPrunedPerCleanup;
+    type EndorsementReputation = EndorsementReputation;
+    type RequiredEndorsements = RequiredEndorsements;
+    type EndorsementDeposit = EndorsementDeposit;
+    type PendingProposalExpiry = PendingProposalExpiry;
+    type SpamOrigin = frame_system::EnsureRoot<u64>;
+    type HookQuotas = ();
+    type CouncilMembershipChanged = ();
 }
 
 // Genesis storage initialization for tests
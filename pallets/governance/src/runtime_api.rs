@@ -0,0 +1,30 @@
+//! Runtime APIs for governance UIs
+
+use codec::Codec;
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+use crate::pallet::{DelegationEdge, VotePower};
+
+sp_api::decl_runtime_apis! {
+    /// Where liquid-democracy voting power is concentrated, read from the delegatee index
+    pub trait DelegationApi<AccountId>
+    where
+        AccountId: Codec,
+    {
+        /// Up to `limit` delegations, grouped by delegatee
+        fn delegation_graph(limit: u32) -> Vec<DelegationEdge<AccountId>>;
+
+        /// The `limit` accounts with the most globally delegated power among a page of
+        /// delegatees, most first, and the cursor of the next page
+        ///
+        /// Pass `None` for the first page; the global ranking is the top `limit` of all pages.
+        fn top_delegatees(
+            cursor: Option<Vec<u8>>,
+            limit: u32,
+        ) -> Result<(Vec<(AccountId, VotePower)>, Option<Vec<u8>>), DispatchError>;
+
+        /// Quadratic reputation of the account plus the power delegated to it globally
+        fn effective_power(account: AccountId) -> Result<VotePower, DispatchError>;
+    }
+}
@@ -3,7 +3,7 @@ mod tests {
     use super::*;
     use crate::mock::*;
     use crate::pallet::{ProposalType, SkillTag};
-    use frame_support::{assert_ok, assert_noop, BoundedVec};
+    use frame_support::{assert_ok, assert_noop, traits::{Currency, ReservableCurrency}, BoundedVec};
     use sp_core::H256;
 
     fn setup() {
@@ -427,5 +427,497 @@ mod tests {
             );
         });
     }
-}
 
+    #[test]
+    fn test_delegations_per_delegatee_are_bounded() {
+        new_test_ext().execute_with(|| {
+            frame_system::Pallet::<Test>::set_block_number(1);
+            pallet_reputation::ReputationScores::<Test>::insert(1, 500);
+
+            // `MaxDelegationsPerDelegatee` is 3 in the mock
+            for delegator in 2..=4 {
+                assert_ok!(Governance::delegate_vote(RuntimeOrigin::signed(delegator), 1, 10, None));
+            }
+            assert_noop!(
+                Governance::delegate_vote(RuntimeOrigin::signed(5), 1, 10, None),
+                Error::<Test>::TooManyDelegations
+            );
+
+            // Re-delegating an existing delegation does not count twice
+            assert_ok!(Governance::delegate_vote(RuntimeOrigin::signed(4), 1, 20, None));
+            assert_ok!(Governance::revoke_delegation(RuntimeOrigin::signed(2)));
+            assert_ok!(Governance::delegate_vote(RuntimeOrigin::signed(5), 1, 10, None));
+        });
+    }
+
+    #[test]
+    fn test_custom_proposals_run_their_tag_handler() {
+        new_test_ext().execute_with(|| {
+            frame_system::Pallet::<Test>::set_block_number(1);
+            pallet_reputation::ReputationScores::<Test>::insert(1, 500);
+            let custom = |tag: &[u8], data: &[u8]| ProposalType::Custom {
+                tag: SkillTag::try_from(tag.to_vec()).unwrap(),
+                data: data.to_vec(),
+            };
+            let description = || BoundedVec::try_from(b"Registry update".to_vec()).unwrap();
+
+            assert_noop!(
+                Governance::create_proposal(
+                    RuntimeOrigin::signed(1),
+                    custom(b"paranet", b"config"),
+                    BoundedVec::default(),
+                    description()
+                ),
+                Error::<Test>::UnknownProposalTag
+            );
+
+            for data in [&b"add-node"[..], &b""[..]] {
+                assert_ok!(Governance::create_proposal(
+                    RuntimeOrigin::signed(1),
+                    custom(b"registry", data),
+                    BoundedVec::default(),
+                    description()
+                ));
+            }
+            assert_ok!(Governance::vote(RuntimeOrigin::signed(1), 0, true));
+            assert_ok!(Governance::vote(RuntimeOrigin::signed(1), 1, true));
+            frame_system::Pallet::<Test>::set_block_number(200);
+
+            assert_ok!(Governance::execute_proposal(RuntimeOrigin::signed(1), 0));
+            assert_eq!(RegistryUpdates::get(), vec![b"add-node".to_vec()]);
+
+            // A failing handler leaves the proposal unexecuted
+            assert_noop!(
+                Governance::execute_proposal(RuntimeOrigin::signed(1), 1),
+                sp_runtime::DispatchError::Other("Empty registry update")
+            );
+            assert!(!Governance::proposals(1).unwrap().executed);
+        });
+    }
+
+    #[test]
+    fn test_council_salary_is_paid_for_participation_and_clawed_back_otherwise() {
+        new_test_ext().execute_with(|| {
+            frame_system::Pallet::<Test>::set_block_number(1);
+            pallet_reputation::ReputationScores::<Test>::insert(1, 500);
+            let (active, inactive) = (1u64, 3u64);
+            crate::CouncilMembers::<Test>::put(BoundedVec::try_from(vec![active, inactive]).unwrap());
+            crate::CouncilTermStart::<Test>::put(1);
+            crate::CouncilTermEnd::<Test>::put(101);
+            let _ = Balances::deposit_creating(&CouncilTreasury::get(), 10_000);
+
+            assert_ok!(Governance::create_proposal(
+                RuntimeOrigin::signed(active),
+                ProposalType::CouncilElection,
+                BoundedVec::default(),
+                BoundedVec::try_from(b"Council election".to_vec()).unwrap(),
+            ));
+            assert_ok!(Governance::vote(RuntimeOrigin::signed(active), 0, true));
+            assert_eq!(Governance::council_votes(active), 1);
+
+            // Revoking and re-casting a vote does not count twice
+            assert_ok!(Governance::revoke_vote(RuntimeOrigin::signed(active), 0));
+            assert_eq!(Governance::council_votes(active), 0);
+            assert_ok!(Governance::vote(RuntimeOrigin::signed(active), 0, true));
+            assert_eq!(Governance::council_votes(active), 1);
+
+            // Salary accrued so far can be claimed during the term
+            frame_system::Pallet::<Test>::set_block_number(51);
+            let inactive_before = Balances::free_balance(inactive);
+            let paid = CouncilSalaryPerBlock::get() * 50;
+            assert_ok!(Governance::claim_council_salary(RuntimeOrigin::signed(inactive)));
+            assert_eq!(Balances::free_balance(inactive), inactive_before + paid);
+            assert_eq!(Governance::council_salary_paid(inactive), paid);
+            assert_ok!(Governance::claim_council_salary(RuntimeOrigin::signed(active)));
+
+            // Salary stops accruing at the term's end, not when the council is rotated
+            frame_system::Pallet::<Test>::set_block_number(151);
+            let treasury_before = Balances::free_balance(CouncilTreasury::get());
+            assert_ok!(Governance::rotate_council(RuntimeOrigin::signed(2)));
+            let salary = CouncilSalaryPerBlock::get() * 100;
+            assert_eq!(Governance::council_salary(active), salary - paid);
+            assert_eq!(Governance::council_salary(inactive), 0);
+
+            // The inactive member's claimed salary goes back to the treasury
+            assert_eq!(Balances::free_balance(inactive), inactive_before);
+            assert_eq!(Balances::free_balance(CouncilTreasury::get()), treasury_before + paid);
+            assert_eq!(Governance::council_salary_paid(inactive), 0);
+            frame_system::Pallet::<Test>::assert_has_event(
+                Event::<Test>::CouncilSalaryClawedBack { member: inactive, amount: salary, recovered: paid, votes: 0 }
+                    .into(),
+            );
+            assert_eq!(Governance::council_votes(active), 0);
+            assert_eq!(Governance::council_term_start(), 151);
+
+            let before = Balances::free_balance(active);
+            assert_ok!(Governance::claim_council_salary(RuntimeOrigin::signed(active)));
+            assert_eq!(Balances::free_balance(active), before + salary - paid);
+            assert_noop!(
+                Governance::claim_council_salary(RuntimeOrigin::signed(active)),
+                Error::<Test>::NoCouncilSalary
+            );
+            assert_noop!(
+                Governance::claim_council_salary(RuntimeOrigin::signed(inactive)),
+                Error::<Test>::NoCouncilSalary
+            );
+        });
+    }
+
+    #[test]
+    fn test_parameter_changes_stay_within_their_template() {
+        new_test_ext().execute_with(|| {
+            frame_system::Pallet::<Test>::set_block_number(1);
+            pallet_reputation::ReputationScores::<Test>::insert(1, 500);
+            let parameter = crate::ParameterName::try_from(b"decay_rate".to_vec()).unwrap();
+            let description = || BoundedVec::try_from(b"Adjust decay".to_vec()).unwrap();
+            let template = crate::ParameterTemplate {
+                current: 1_000,
+                max_change: sp_runtime::Percent::from_percent(20),
+                min_step: 10,
+                min: 100,
+                max: 5_000,
+                tags: BoundedVec::default(),
+            };
+
+            assert_noop!(
+                Governance::propose_parameter_change(RuntimeOrigin::signed(1), parameter.clone(), 1_100, description()),
+                Error::<Test>::UnknownParameterTemplate
+            );
+            // Parameters without a template cannot be changed by raw proposals either
+            assert_noop!(
+                Governance::create_proposal(
+                    RuntimeOrigin::signed(1),
+                    ProposalType::ParameterChange { parameter: parameter.to_vec(), new_value: 1_100u64.to_le_bytes().to_vec() },
+                    BoundedVec::default(),
+                    description()
+                ),
+                Error::<Test>::UnknownParameterTemplate
+            );
+            assert_noop!(
+                Governance::set_parameter_template(RuntimeOrigin::signed(1), parameter.clone(), Some(template.clone())),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            assert_ok!(Governance::set_parameter_template(RuntimeOrigin::root(), parameter.clone(), Some(template)));
+
+            // At most ±20% of the current value
+            assert_noop!(
+                Governance::propose_parameter_change(RuntimeOrigin::signed(1), parameter.clone(), 1_201, description()),
+                Error::<Test>::ParameterChangeOutOfBounds
+            );
+            // Raw parameter change proposals are checked too
+            assert_noop!(
+                Governance::create_proposal(
+                    RuntimeOrigin::signed(1),
+                    ProposalType::ParameterChange { parameter: parameter.to_vec(), new_value: vec![1, 2] },
+                    BoundedVec::default(),
+                    description()
+                ),
+                Error::<Test>::InvalidParameterValue
+            );
+            assert_ok!(Governance::propose_parameter_change(RuntimeOrigin::signed(1), parameter.clone(), 1_200, description()));
+            assert_ok!(Governance::propose_parameter_change(RuntimeOrigin::signed(1), parameter.clone(), 800, description()));
+            assert_ok!(Governance::vote(RuntimeOrigin::signed(1), 0, true));
+            assert_ok!(Governance::vote(RuntimeOrigin::signed(1), 1, true));
+            frame_system::Pallet::<Test>::set_block_number(200);
+
+            assert_ok!(Governance::execute_proposal(RuntimeOrigin::signed(1), 0));
+            assert_eq!(Governance::parameter_template(&parameter).unwrap().current, 1_200);
+            // 800 is more than 20% below the new current value
+            assert_noop!(
+                Governance::execute_proposal(RuntimeOrigin::signed(1), 1),
+                Error::<Test>::ParameterChangeOutOfBounds
+            );
+        });
+    }
+
+    #[test]
+    fn test_cleanup_prunes_dead_proposals_and_rewards_the_caller() {
+        new_test_ext().execute_with(|| {
+            frame_system::Pallet::<Test>::set_block_number(1);
+            pallet_reputation::ReputationScores::<Test>::insert(1, 500);
+            let _ = Balances::deposit_creating(&Governance::grants_account(), 1_000);
+
+            assert_ok!(Governance::create_proposal(
+                RuntimeOrigin::signed(1),
+                ProposalType::CouncilElection,
+                BoundedVec::default(),
+                BoundedVec::try_from(b"Council election".to_vec()).unwrap(),
+            ));
+            for voter in 1..=3 {
+                assert_ok!(Governance::vote(RuntimeOrigin::signed(voter), 0, true));
+            }
+            frame_system::Pallet::<Test>::set_block_number(2);
+            assert_ok!(Governance::cancel_proposal(RuntimeOrigin::signed(1), 0));
+
+            frame_system::Pallet::<Test>::set_block_number(2 + ProposalRetentionPeriod::get() - 1);
+            assert_noop!(Governance::cleanup_proposal(RuntimeOrigin::signed(4), 0), Error::<Test>::ProposalRetained);
+
+            // `MaxVotesPrunedPerCleanup` is 2 in the mock, so three votes take two calls
+            frame_system::Pallet::<Test>::set_block_number(2 + ProposalRetentionPeriod::get());
+            let before = Balances::free_balance(4);
+            assert_ok!(Governance::cleanup_proposal(RuntimeOrigin::signed(4), 0));
+            assert!(Governance::proposals(0).is_some());
+            assert!(crate::CleanupCursors::<Test>::contains_key(0));
+            assert_eq!(Balances::free_balance(4), before);
+
+            // The second call in the same block resumes from the stored cursor
+            assert_ok!(Governance::cleanup_proposal(RuntimeOrigin::signed(4), 0));
+            assert!(Governance::proposals(0).is_none());
+            assert!(!crate::CleanupCursors::<Test>::contains_key(0));
+            assert_eq!(crate::Votes::<Test>::iter_prefix(0).count(), 0);
+            assert_eq!(crate::VotingPower::<Test>::iter_prefix(0).count(), 0);
+            assert_eq!(Balances::free_balance(4), before + ProposalCleanupReward::get());
+            assert_eq!(Balances::reserved_balance(1), 0);
+            assert_noop!(Governance::cleanup_proposal(RuntimeOrigin::signed(4), 0), Error::<Test>::ProposalNotFound);
+        });
+    }
+
+    #[test]
+    fn test_parameter_changes_take_a_minimum_step_and_their_template_tags() {
+        new_test_ext().execute_with(|| {
+            frame_system::Pallet::<Test>::set_block_number(1);
+            pallet_reputation::ReputationScores::<Test>::insert(1, 500);
+            let parameter = crate::ParameterName::try_from(b"slash_percent".to_vec()).unwrap();
+            let security = SkillTag::try_from(b"security".to_vec()).unwrap();
+            let registry = SkillTag::try_from(b"registry".to_vec()).unwrap();
+            let template = crate::ParameterTemplate {
+                current: 0,
+                max_change: sp_runtime::Percent::from_percent(20),
+                min_step: 2,
+                min: 0,
+                max: 100,
+                tags: BoundedVec::try_from(vec![security.clone()]).unwrap(),
+            };
+            assert_ok!(Governance::set_parameter_template(RuntimeOrigin::root(), parameter.clone(), Some(template)));
+            let propose = |value: u64| {
+                Governance::create_proposal(
+                    RuntimeOrigin::signed(1),
+                    ProposalType::ParameterChange { parameter: parameter.to_vec(), new_value: value.to_le_bytes().to_vec() },
+                    BoundedVec::try_from(vec![registry.clone()]).unwrap(),
+                    BoundedVec::try_from(b"Raise slashing".to_vec()).unwrap(),
+                )
+            };
+
+            // 20% of zero is zero, but a change of `min_step` is always allowed
+            assert_noop!(propose(3), Error::<Test>::ParameterChangeOutOfBounds);
+            assert_ok!(propose(2));
+            // The proposer's tags are replaced by the template's
+            assert_eq!(Governance::proposals(0).unwrap().tags.into_inner(), vec![security]);
+        });
+    }
+
+    #[test]
+    fn test_track_thresholds_apply_to_tagged_proposals() {
+        new_test_ext().execute_with(|| {
+            frame_system::Pallet::<Test>::set_block_number(1);
+            pallet_reputation::ReputationScores::<Test>::insert(1, 500);
+            pallet_reputation::ReputationScores::<Test>::insert(3, 150);
+            let registry = SkillTag::try_from(b"registry".to_vec()).unwrap();
+
+            // Custom proposals are in the track of their handler's tag
+            assert_ok!(Governance::create_proposal(
+                RuntimeOrigin::signed(1),
+                ProposalType::Custom { tag: registry.clone(), data: b"entry".to_vec() },
+                BoundedVec::default(),
+                BoundedVec::try_from(b"Registry entry".to_vec()).unwrap(),
+            ));
+            // The proposer's own tags select no track
+            assert_ok!(Governance::create_proposal(
+                RuntimeOrigin::signed(1),
+                ProposalType::CouncilElection,
+                BoundedVec::try_from(vec![registry.clone()]).unwrap(),
+                BoundedVec::try_from(b"Council election".to_vec()).unwrap(),
+            ));
+            for proposal_id in [0, 1] {
+                assert_ok!(Governance::vote(RuntimeOrigin::signed(1), proposal_id, true));
+                assert_ok!(Governance::vote(RuntimeOrigin::signed(3), proposal_id, false));
+            }
+            frame_system::Pallet::<Test>::set_block_number(200);
+
+            assert_noop!(
+                Governance::set_track_thresholds(
+                    RuntimeOrigin::root(),
+                    registry.clone(),
+                    Some(crate::Track { quorum: 101, supermajority: 0 })
+                ),
+                Error::<Test>::InvalidTrackThresholds
+            );
+            // Thresholds are read at execution, so they apply to the open proposal
+            assert_ok!(Governance::set_track_thresholds(
+                RuntimeOrigin::root(),
+                registry.clone(),
+                Some(crate::Track { quorum: 1, supermajority: 0 })
+            ));
+            assert_noop!(Governance::execute_proposal(RuntimeOrigin::signed(1), 0), Error::<Test>::QuorumNotMet);
+            assert_ok!(Governance::execute_proposal(RuntimeOrigin::signed(1), 1));
+
+            // About 64% of the votes are in favour
+            assert_ok!(Governance::set_track_thresholds(
+                RuntimeOrigin::root(),
+                registry.clone(),
+                Some(crate::Track { quorum: 0, supermajority: 90 })
+            ));
+            assert_noop!(Governance::execute_proposal(RuntimeOrigin::signed(1), 0), Error::<Test>::SupermajorityNotMet);
+
+            assert_ok!(Governance::set_track_thresholds(RuntimeOrigin::root(), registry, None));
+            assert_ok!(Governance::execute_proposal(RuntimeOrigin::signed(1), 0));
+            assert_eq!(RegistryUpdates::get(), vec![b"entry".to_vec()]);
+        });
+    }
+
+    #[test]
+    fn test_delegation_analytics() {
+        new_test_ext().execute_with(|| {
+            frame_system::Pallet::<Test>::set_block_number(1);
+            pallet_reputation::ReputationScores::<Test>::insert(1, 500);
+            pallet_reputation::ReputationScores::<Test>::insert(3, 100);
+
+            assert_ok!(Governance::delegate_vote(RuntimeOrigin::signed(2), 1, 10, None));
+            assert_ok!(Governance::delegate_vote(RuntimeOrigin::signed(4), 1, 20, None));
+            assert_ok!(Governance::delegate_vote(RuntimeOrigin::signed(5), 3, 5, None));
+
+            let graph = Governance::delegation_graph(10);
+            assert_eq!(graph.len(), 3);
+            assert!(graph.contains(&crate::DelegationEdge { delegator: 4, delegatee: 1, amount: 20, proposal_id: None }));
+            assert_eq!(Governance::delegation_graph(2).len(), 2);
+
+            assert_eq!(Governance::top_delegatees(None, 10), Ok((vec![(1, 30), (3, 5)], None)));
+            assert_eq!(Governance::top_delegatees(None, 1), Ok((vec![(1, 30)], None)));
+
+            // sqrt(500) = 22
+            assert_eq!(Governance::effective_power(&1), Ok(22 + 30));
+            assert_eq!(Governance::effective_power(&2), Ok(0));
+
+            // Delegatees over the bound are reported rather than counted as zero
+            for delegator in 10..10 + MaxDelegationsPerDelegatee::get() as u64 {
+                crate::DelegationsTo::<Test>::insert(3, delegator, ());
+            }
+            assert_noop!(Governance::effective_power(&3), Error::<Test>::TooManyDelegations);
+            assert_noop!(Governance::top_delegatees(None, 10), Error::<Test>::TooManyDelegations);
+        });
+    }
+
+    #[test]
+    fn test_low_reputation_proposals_need_endorsements_and_spam_is_slashed() {
+        new_test_ext().execute_with(|| {
+            frame_system::Pallet::<Test>::set_block_number(1);
+            pallet_reputation::ReputationScores::<Test>::insert(1, 500);
+            pallet_reputation::ReputationScores::<Test>::insert(2, 150);
+            pallet_reputation::ReputationScores::<Test>::insert(3, 400);
+            crate::CouncilMembers::<Test>::put(BoundedVec::try_from(vec![4u64]).unwrap());
+            let create = |who: u64| Governance::create_proposal(
+                RuntimeOrigin::signed(who),
+                ProposalType::CouncilElection,
+                BoundedVec::default(),
+                BoundedVec::try_from(b"Council election".to_vec()).unwrap(),
+            );
+
+            // Below `EndorsementReputation` the proposal waits for endorsements
+            assert_ok!(create(2));
+            assert!(Governance::pending_proposal(0).is_some());
+            assert_noop!(Governance::vote(RuntimeOrigin::signed(1), 0, true), Error::<Test>::ProposalNotFound);
+            assert_noop!(Governance::endorse_proposal(RuntimeOrigin::signed(2), 0), Error::<Test>::CannotEndorseOwnProposal);
+            assert_noop!(Governance::endorse_proposal(RuntimeOrigin::signed(4), 0), Error::<Test>::InsufficientReputation);
+
+            frame_system::Pallet::<Test>::set_block_number(5);
+            assert_ok!(Governance::endorse_proposal(RuntimeOrigin::signed(1), 0));
+            assert_noop!(Governance::endorse_proposal(RuntimeOrigin::signed(1), 0), Error::<Test>::AlreadyEndorsed);
+            assert!(Governance::proposals(0).is_none());
+            assert_ok!(Governance::endorse_proposal(RuntimeOrigin::signed(3), 0));
+
+            // The vote opens with the last required endorsement
+            assert!(Governance::pending_proposal(0).is_none());
+            assert_eq!(Governance::proposals(0).unwrap().created, 5);
+            assert_eq!(Balances::reserved_balance(1), 1_000);
+            assert_ok!(Governance::vote(RuntimeOrigin::signed(1), 0, true));
+
+            // Proposals from the endorsement tier go straight to a vote
+            assert_ok!(create(1));
+            assert!(Governance::proposals(1).is_some());
+
+            // A single council member cannot mark proposals as spam
+            assert_noop!(
+                Governance::mark_proposal_spam(RuntimeOrigin::signed(4), 0),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            assert_ok!(Governance::mark_proposal_spam(RuntimeOrigin::root(), 0));
+            assert!(Governance::proposals(0).unwrap().cancelled);
+            assert_eq!(Balances::reserved_balance(2), 0);
+            assert_eq!(Balances::free_balance(2), 10_000_000 - ProposalDeposit::get());
+            assert_eq!(Balances::reserved_balance(3), 0);
+            assert_eq!(Balances::free_balance(3), 10_000_000 - EndorsementDeposit::get());
+            // Account 1 keeps the deposit of its own open proposal reserved
+            assert_eq!(Balances::reserved_balance(1), ProposalDeposit::get());
+        });
+    }
+
+    #[test]
+    fn test_unendorsed_proposals_expire_with_deposits_returned() {
+        new_test_ext().execute_with(|| {
+            frame_system::Pallet::<Test>::set_block_number(1);
+            pallet_reputation::ReputationScores::<Test>::insert(1, 500);
+            pallet_reputation::ReputationScores::<Test>::insert(2, 150);
+            assert_ok!(Governance::create_proposal(
+                RuntimeOrigin::signed(2),
+                ProposalType::CouncilElection,
+                BoundedVec::default(),
+                BoundedVec::try_from(b"Council election".to_vec()).unwrap(),
+            ));
+            assert_ok!(Governance::endorse_proposal(RuntimeOrigin::signed(1), 0));
+            assert_eq!(Balances::reserved_balance(1), EndorsementDeposit::get());
+
+            let expiry = 1 + PendingProposalExpiry::get();
+            frame_system::Pallet::<Test>::set_block_number(expiry - 1);
+            assert_noop!(
+                Governance::expire_pending_proposal(RuntimeOrigin::signed(3), 0),
+                Error::<Test>::PendingProposalNotExpired
+            );
+
+            frame_system::Pallet::<Test>::set_block_number(expiry);
+            assert_noop!(
+                Governance::endorse_proposal(RuntimeOrigin::signed(3), 0),
+                Error::<Test>::PendingProposalExpired
+            );
+            assert_ok!(Governance::expire_pending_proposal(RuntimeOrigin::signed(3), 0));
+            assert!(Governance::pending_proposal(0).is_none());
+            assert_eq!(Balances::reserved_balance(1), 0);
+            assert_eq!(Balances::reserved_balance(2), 0);
+            assert_noop!(
+                Governance::expire_pending_proposal(RuntimeOrigin::signed(3), 0),
+                Error::<Test>::NotAwaitingEndorsement
+            );
+        });
+    }
+
+    #[test]
+    fn test_v1_migration_indexes_delegations_within_bound() {
+        new_test_ext().execute_with(|| {
+            use frame_support::traits::{OnInitialize, OnRuntimeUpgrade, StorageVersion};
+            frame_system::Pallet::<Test>::set_block_number(1);
+            StorageVersion::new(0).put::<Governance>();
+            // Four delegations made before the index existed, one over `MaxDelegationsPerDelegatee`
+            for delegator in 2..=5u64 {
+                crate::Delegations::<Test>::insert(
+                    delegator,
+                    crate::Delegation { delegator, delegatee: 1, amount: 1, proposal_id: None },
+                );
+            }
+
+            crate::migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+            Governance::on_initialize(2);
+
+            assert!(Governance::migration_in_progress().is_none());
+            assert_eq!(StorageVersion::get::<Governance>(), StorageVersion::new(1));
+            assert_eq!(crate::DelegationsTo::<Test>::iter_key_prefix(1).count() as u32, MaxDelegationsPerDelegatee::get());
+            assert_eq!(crate::Delegations::<Test>::iter().count() as u32, MaxDelegationsPerDelegatee::get());
+            assert!(System::events().iter().any(|record| matches!(
+                record.event,
+                RuntimeEvent::Governance(Event::DelegationDropped { delegatee: 1, .. })
+            )));
+
+            // Delegation calls wait for a running migration
+            crate::MigrationInProgress::<Test>::put(BoundedVec::default());
+            assert_noop!(Governance::revoke_delegation(RuntimeOrigin::signed(2)), Error::<Test>::MigrationInProgress);
+        });
+    }
+}
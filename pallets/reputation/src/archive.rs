@@ -0,0 +1,193 @@
+//! Archival digests of purged contribution records
+//!
+//! `purge_my_data` replaces a contributor's settled contribution records with the
+//! Merkle root of their SCALE encodings. The root is published with the account's DKG
+//! snapshot, so an off-chain copy of the records can later be proven against it.
+//! Contribution bundles commit to their items with the same tree.
+//!
+//! Leaves and inner nodes are hashed with different domain tags, so an inner node can
+//! never be passed off as a leaf.
+
+use crate::pallet::{Config, Contribution};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{traits::ConstU32, BoundedVec};
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_io::hashing::blake2_256;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// Leaf of an archived contribution record
+pub fn contribution_leaf<T: Config>(contribution: &Contribution<T>) -> H256 {
+    H256(blake2_256(&contribution.encode()))
+}
+
+/// Domain tag of hashed leaves
+const LEAF_TAG: u8 = 0;
+
+/// Domain tag of hashed inner nodes
+const NODE_TAG: u8 = 1;
+
+/// Binary Merkle root of `leaves`, hashing tagged leaves and pairs with blake2-256
+///
+/// A node without a sibling is promoted unchanged; no leaves give the zero hash.
+pub fn merkle_root(leaves: Vec<H256>) -> H256 {
+    let mut leaves: Vec<H256> = leaves.iter().map(hash_leaf).collect();
+    while leaves.len() > 1 {
+        leaves = leaves
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => hash_pair(left, right),
+                [single] => *single,
+                _ => unreachable!("chunks of two"),
+            })
+            .collect();
+    }
+    leaves.pop().unwrap_or_default()
+}
+
+/// [`merkle_root`] built one leaf at a time
+///
+/// Holds the root of each complete subtree built so far, at most one per height, so a
+/// tree can be built across blocks in bounded storage.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct MerkleFrontier {
+    /// Heights and roots of the complete subtrees, tallest first
+    subtrees: BoundedVec<(u8, H256), ConstU32<32>>,
+}
+
+impl MerkleFrontier {
+    /// Append a leaf
+    pub fn push(&mut self, leaf: H256) {
+        let (mut height, mut node) = (0u8, hash_leaf(&leaf));
+        while let Some(&(top, left)) = self.subtrees.last() {
+            if top != height {
+                break;
+            }
+            self.subtrees.pop();
+            (height, node) = (height + 1, hash_pair(&left, &node));
+        }
+        // One subtree per height covers any `u32` number of leaves
+        let _ = self.subtrees.try_push((height, node));
+    }
+
+    /// Root of the leaves pushed so far; the right-most subtrees are joined first, as
+    /// `merkle_root` promotes the nodes without a sibling
+    pub fn root(&self) -> H256 {
+        self.subtrees
+            .iter()
+            .rev()
+            .map(|(_, node)| *node)
+            .reduce(|right, left| hash_pair(&left, &right))
+            .unwrap_or_default()
+    }
+}
+
+fn hash_leaf(leaf: &H256) -> H256 {
+    H256(blake2_256(&[&[LEAF_TAG][..], leaf.as_bytes()].concat()))
+}
+
+fn hash_pair(left: &H256, right: &H256) -> H256 {
+    H256(blake2_256(&[&[NODE_TAG][..], left.as_bytes(), right.as_bytes()].concat()))
+}
+
+/// Siblings proving the leaf at `index` against the [`merkle_root`] of `leaves`,
+/// bottom up; promoted nodes have no sibling
+pub fn merkle_proof(leaves: Vec<H256>, mut index: usize) -> Vec<H256> {
+    let mut leaves: Vec<H256> = leaves.iter().map(hash_leaf).collect();
+    let mut proof = Vec::new();
+    while leaves.len() > 1 {
+        if let Some(sibling) = leaves.get(index ^ 1) {
+            proof.push(*sibling);
+        }
+        leaves = leaves
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => hash_pair(left, right),
+                [single] => *single,
+                _ => unreachable!("chunks of two"),
+            })
+            .collect();
+        index /= 2;
+    }
+    proof
+}
+
+/// Whether `proof` shows `leaf` at `index` among `leaves` leaves with Merkle root `root`
+pub fn verify_merkle_proof(root: H256, leaf: H256, mut index: u32, mut leaves: u32, proof: &[H256]) -> bool {
+    if index >= leaves {
+        return false;
+    }
+
+    let mut siblings = proof.iter();
+    let mut node = hash_leaf(&leaf);
+    while leaves > 1 {
+        let promoted = index % 2 == 0 && index + 1 == leaves;
+        if !promoted {
+            let Some(sibling) = siblings.next() else { return false };
+            node = if index % 2 == 0 { hash_pair(&node, sibling) } else { hash_pair(sibling, &node) };
+        }
+        index /= 2;
+        leaves = (leaves + 1) / 2;
+    }
+    siblings.next().is_none() && node == root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaf(byte: u8) -> H256 {
+        H256(blake2_256(&[&[0u8][..], &[byte; 32][..]].concat()))
+    }
+
+    fn node(left: H256, right: H256) -> H256 {
+        H256(blake2_256(&[&[1u8][..], left.as_bytes(), right.as_bytes()].concat()))
+    }
+
+    #[test]
+    fn test_merkle_root() {
+        let leaves: Vec<H256> = (1..=3).map(H256::repeat_byte).collect();
+
+        assert_eq!(merkle_root(Vec::new()), H256::zero());
+        assert_eq!(merkle_root(leaves[..1].to_vec()), leaf(1));
+        assert_eq!(merkle_root(leaves.clone()), node(node(leaf(1), leaf(2)), leaf(3)));
+    }
+
+    #[test]
+    fn test_merkle_frontier_matches_merkle_root() {
+        let leaves: Vec<H256> = (1..=13).map(H256::repeat_byte).collect();
+
+        let mut frontier = MerkleFrontier::default();
+        assert_eq!(frontier.root(), merkle_root(Vec::new()));
+        for count in 1..=leaves.len() {
+            frontier.push(leaves[count - 1]);
+            assert_eq!(frontier.root(), merkle_root(leaves[..count].to_vec()));
+        }
+    }
+
+    #[test]
+    fn test_inner_nodes_do_not_pass_as_leaves() {
+        let leaves: Vec<H256> = (1..=4).map(H256::repeat_byte).collect();
+        let root = merkle_root(leaves);
+
+        // The left subtree's node, shown as leaf 0 of a two-leaf tree
+        let inner = node(leaf(1), leaf(2));
+        assert!(!verify_merkle_proof(root, inner, 0, 2, &[node(leaf(3), leaf(4))]));
+    }
+
+    #[test]
+    fn test_merkle_proof_roundtrip() {
+        let leaves: Vec<H256> = (1..=5).map(H256::repeat_byte).collect();
+        let root = merkle_root(leaves.clone());
+
+        for (index, leaf) in leaves.iter().enumerate() {
+            let proof = merkle_proof(leaves.clone(), index);
+            assert!(verify_merkle_proof(root, *leaf, index as u32, 5, &proof));
+            assert!(!verify_merkle_proof(root, *leaf, index as u32, 4, &proof));
+        }
+        let proof = merkle_proof(leaves.clone(), 1);
+        assert!(!verify_merkle_proof(root, leaves[0], 1, 5, &proof));
+        assert!(!verify_merkle_proof(root, leaves[1], 5, 5, &proof));
+    }
+}
@@ -5,17 +5,86 @@
 //
 // Key Features:
 // - Publish reputation updates to DKG as Knowledge Assets
-// - Store UAL (Uniform Asset Locator) mappings on-chain
+// - Store UAL (Uniform Asset Locator) mappings on-chain, per developer and per contribution
 // - Emit events for off-chain workers to process DKG publishing
 // - Verify DKG proofs for cross-chain reputation queries
+// - Off-chain worker publishing queued reputation to the configured DKG node
+// - Exponential retry backoff and dead-lettering of permanently failing publishes
+// - HTTP failure classes counted in `OcwStats`, telling misconfiguration (bad token,
+//   wrong endpoint) apart from transient outages in retry decisions
+// - Paranet registration and contributor membership for grouping published assets
+// - On-chain anchoring of published assertion roots for trust-minimized verification
+// - Publish status tracking (Queued -> Submitted -> Confirmed/Failed) for dApps
+// - Signed or unsigned publisher acknowledgements that dequeue and store the UAL atomically
+// - Contributor DID documents linking account, verified GitHub handle and reputation UAL
+// - Knowledge Asset versioning: republished reputation updates the existing asset
+// - Reputation passports: signed Verifiable Credentials anchored on-chain and kept in
+//   off-chain indexing for contributors to share
 
-use frame_support::{
-    dispatch::DispatchResult,
+use codec::Encode;
+use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
+use frame_system::{
+    offchain::{
+        AppCrypto, CreateSignedTransaction, ForAny, SendSignedTransaction, SendUnsignedTransaction,
+        SignedPayload, Signer, SigningTypes,
+    },
     pallet_prelude::*,
-    traits::{Currency, ExistenceRequirement},
 };
-use frame_system::pallet_prelude::*;
-use sp_std::vec::Vec;
+use sp_core::{crypto::KeyTypeId, H256};
+use sp_runtime::{
+    offchain::{http, storage::StorageValueRef, Duration},
+    traits::{IdentifyAccount, SaturatedConversion, Saturating},
+    RuntimeAppPublic,
+};
+use sp_std::{vec, vec::Vec};
+
+use crate::{
+    jsonld::{AssertionBuilder, DidDocumentBuilder, PassportBuilder},
+    pallet::ContributionId,
+};
+use dotrep_primitives::{AccountBindings, ReputationProvider, ReputationSnapshotProvider, Topic, Ual};
+
+pub use pallet::*;
+
+const LOG_TARGET: &str = "pallet-reputation-dkg";
+
+/// Key type of the publisher keys used by the DKG off-chain worker
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"dkgp");
+
+/// HTTP attempts per queued item in a single off-chain worker run
+const MAX_PUBLISH_ATTEMPTS: u32 = 3;
+
+/// Timeout of a single publish request in milliseconds
+const PUBLISH_TIMEOUT_MS: u64 = 10_000;
+
+/// Blocks to wait for a submitted confirmation before publishing an item again
+const IN_FLIGHT_BLOCKS: u32 = 10;
+
+/// Maximum size of a serialized reputation passport
+pub const MAX_PASSPORT_LEN: u32 = 16 * 1024;
+
+/// Key prefix of passports in the off-chain database
+pub const PASSPORT_PREFIX: &[u8] = b"dotrep/passport/";
+
+/// sr25519 publisher keys for the DKG off-chain worker
+pub mod crypto {
+    use super::KEY_TYPE;
+    use sp_core::sr25519::{Public as Sr25519Public, Signature as Sr25519Signature};
+    use sp_runtime::{
+        app_crypto::{app_crypto, sr25519},
+        MultiSignature, MultiSigner,
+    };
+
+    app_crypto!(sr25519, KEY_TYPE);
+
+    pub struct DkgPublisherId;
+
+    impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for DkgPublisherId {
+        type RuntimeAppPublic = Public;
+        type GenericSignature = Sr25519Signature;
+        type GenericPublic = Sr25519Public;
+    }
+}
 
 /// DKG-related storage and types for the reputation pallet
 pub trait DKGIntegration<T: frame_system::Config> {
@@ -28,6 +97,12 @@ pub trait DKGIntegration<T: frame_system::Config> {
     /// Get UAL for a developer
     fn get_ual(who: &T::AccountId) -> Option<Vec<u8>>;
 
+    /// Store UAL mapping for an individually published contribution
+    fn store_contribution_ual(contribution_id: ContributionId, ual: Vec<u8>) -> DispatchResult;
+
+    /// Get UAL for a contribution
+    fn get_contribution_ual(contribution_id: ContributionId) -> Option<Vec<u8>>;
+
     /// Verify DKG proof
     fn verify_dkg_proof(ual: Vec<u8>, proof: Vec<u8>) -> bool;
 }
@@ -38,13 +113,51 @@ pub mod pallet {
     use super::*;
 
     #[pallet::config]
-    pub trait Config: frame_system::Config {
+    pub trait Config: CreateSignedTransaction<Call<Self>> + frame_system::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Publisher key the off-chain worker signs `confirm_publish` with
+        type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+
+        /// Origin allowed to confirm publishes (the off-chain worker's publisher accounts)
+        type PublisherOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
+        /// Maximum queued items published per off-chain worker run
+        #[pallet::constant]
+        type MaxPublishesPerBlock: Get<u32>;
+
+        /// Breakdown and contribution digests included in published assertions
+        type Snapshot: ReputationSnapshotProvider<Self::AccountId>;
+
+        /// Source of the scores queued through `queue_for_publishing`
+        type Reputation: ReputationProvider<Self::AccountId>;
+
+        /// Origin allowed to queue an account's reputation for publishing directly
+        type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Failed publish attempts before an item is dead-lettered
+        #[pallet::constant]
+        type MaxPublishAttempts: Get<u32>;
+
+        /// Retry delay after the first failure; doubles with every further failure
+        #[pallet::constant]
+        type RetryBackoff: Get<BlockNumberFor<Self>>;
     }
 
     #[pallet::pallet]
     pub struct Pallet<T>(_);
 
+    /// Latest published version of each developer's reputation asset (1 = created)
+    #[pallet::storage]
+    #[pallet::getter(fn asset_version)]
+    pub type AssetVersions<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        u32,
+        ValueQuery,
+    >;
+
     /// Storage for UAL mappings (AccountId -> UAL)
     #[pallet::storage]
     #[pallet::getter(fn developer_ual)]
@@ -52,7 +165,7 @@ pub mod pallet {
         _,
         Blake2_128Concat,
         T::AccountId,
-        BoundedVec<u8, ConstU32<256>>,
+        Ual,
         OptionQuery,
     >;
 
@@ -67,6 +180,136 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Failed attempts and next retry block of queued publishes
+    #[pallet::storage]
+    #[pallet::getter(fn publish_retry)]
+    pub type PublishRetries<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        PublishRetry<BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Publishes that exhausted their attempts, kept for operator attention
+    #[pallet::storage]
+    #[pallet::getter(fn dead_letter)]
+    pub type DeadLetters<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        (u32, BlockNumberFor<T>, u32), // (reputation_score, queued_at, attempts)
+        OptionQuery,
+    >;
+
+    /// Failed publishes reported by the off-chain worker, per failure class
+    #[pallet::storage]
+    #[pallet::getter(fn ocw_stats)]
+    pub type OcwStats<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        HttpFailure,
+        OcwFailureStats<BlockNumberFor<T>>,
+        ValueQuery,
+    >;
+
+    /// Storage for per-contribution UAL mappings (ContributionId -> UAL)
+    #[pallet::storage]
+    #[pallet::getter(fn contribution_ual)]
+    pub type ContributionUAL<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ContributionId,
+        Ual,
+        OptionQuery,
+    >;
+
+    /// Publish status of each developer's latest queued snapshot
+    #[pallet::storage]
+    #[pallet::getter(fn publish_status)]
+    pub type PublishStatuses<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        PublishStatus,
+        OptionQuery,
+    >;
+
+    /// Assertion id of each developer's latest published Knowledge Asset
+    #[pallet::storage]
+    #[pallet::getter(fn published_assertion)]
+    pub type PublishedAssertion<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<u8, ConstU32<128>>,
+        OptionQuery,
+    >;
+
+    /// Root hash and block of the assertion anchored for each published UAL
+    #[pallet::storage]
+    #[pallet::getter(fn anchored_assertion)]
+    pub type AnchoredAssertions<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Ual,
+        (H256, BlockNumberFor<T>), // (assertion_root, anchored_at)
+        OptionQuery,
+    >;
+
+    /// GitHub handle attested for each contributor by a DKG publisher
+    #[pallet::storage]
+    #[pallet::getter(fn github_handle)]
+    pub type GithubHandles<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<u8, ConstU32<39>>,
+        OptionQuery,
+    >;
+
+    /// DID documents waiting to be (re)published by the off-chain worker
+    #[pallet::storage]
+    pub type PendingDidDocuments<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
+
+    /// UAL of each contributor's published DID document
+    #[pallet::storage]
+    #[pallet::getter(fn did_document_ual)]
+    pub type DidDocuments<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Ual,
+        OptionQuery,
+    >;
+
+    /// Passport requests waiting for the off-chain worker (account -> block requested)
+    #[pallet::storage]
+    pub type PassportRequests<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
+
+    /// Hash of each contributor's latest issued passport
+    #[pallet::storage]
+    #[pallet::getter(fn passport)]
+    pub type Passports<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        PassportAnchor<BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
     /// Storage for DKG node endpoint (configurable)
     #[pallet::storage]
     #[pallet::getter(fn dkg_endpoint)]
@@ -76,9 +319,28 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// OriginTrail Paranet the DotRep reputation dataset is registered as
+    #[pallet::storage]
+    #[pallet::getter(fn paranet)]
+    pub type Paranet<T: Config> = StorageValue<
+        _,
+        ParanetInfo<BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Paranet members (contributor -> block joined)
+    #[pallet::storage]
+    #[pallet::getter(fn paranet_member)]
+    pub type ParanetMembers<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
+
     /// Events for DKG integration
     #[pallet::event]
-    #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         /// Reputation published to DKG queue [who, reputation_score]
         ReputationQueued { who: T::AccountId, score: u32 },
@@ -86,11 +348,103 @@ pub mod pallet {
         /// UAL mapping stored [who, ual]
         UALStored { who: T::AccountId, ual: Vec<u8> },
         
-        /// DKG publishing completed [who, ual]
-        DKGPublished { who: T::AccountId, ual: Vec<u8> },
+        /// DKG publishing completed [who, ual, assertion_id]
+        DKGPublished { who: T::AccountId, ual: Vec<u8>, assertion_id: Vec<u8> },
         
         /// DKG endpoint updated [endpoint]
         DKGEndpointUpdated { endpoint: Vec<u8> },
+
+        /// Contribution published as its own Knowledge Asset [contribution_id, ual]
+        ContributionUALStored { contribution_id: ContributionId, ual: Vec<u8> },
+
+        /// Publish failed and will be retried [who, attempts, next_retry, failure]
+        PublishRetryScheduled {
+            who: T::AccountId,
+            attempts: u32,
+            next_retry: BlockNumberFor<T>,
+            failure: HttpFailure,
+        },
+
+        /// Publish failed permanently and was dead-lettered [who, attempts, failure]
+        PublishingFailed { who: T::AccountId, attempts: u32, failure: HttpFailure },
+
+        /// Dead-lettered publish requeued by an operator [who]
+        DeadLetterRequeued { who: T::AccountId },
+
+        /// Reputation dataset registered as a Paranet [ual, policy]
+        ParanetRegistered { ual: Vec<u8>, policy: ParanetPolicy },
+
+        /// Contributor joined the Paranet [who]
+        ParanetMemberAdded { who: T::AccountId },
+
+        /// Contributor left the Paranet [who]
+        ParanetMemberRemoved { who: T::AccountId },
+
+        /// Publish acknowledged and its UAL stored [who, ual]
+        PublishAcknowledged { who: T::AccountId, ual: Vec<u8> },
+
+        /// Publish status of a developer's snapshot changed [who, status]
+        PublishStatusChanged { who: T::AccountId, status: PublishStatus },
+
+        /// New version of a developer's reputation asset recorded [who, ual, version]
+        AssetVersionUpdated { who: T::AccountId, ual: Vec<u8>, version: u32 },
+
+        /// GitHub handle attested for a contributor [who, handle]
+        GithubHandleVerified { who: T::AccountId, handle: Vec<u8> },
+
+        /// Contributor DID document published to the DKG [who, ual]
+        DidDocumentPublished { who: T::AccountId, ual: Vec<u8> },
+
+        /// Assertion root anchored for a published UAL [ual, root, block]
+        AssertionAnchored { ual: Vec<u8>, root: H256, block: BlockNumberFor<T> },
+
+        /// Contributor requested a reputation passport [who]
+        PassportRequested { who: T::AccountId },
+
+        /// Reputation passport issued and its hash anchored [who, hash, ual]
+        PassportIssued { who: T::AccountId, hash: H256, ual: Option<Vec<u8>> },
+    }
+
+    impl<T: Config> Event<T> {
+        /// Topics this event is indexed under
+        fn topics(&self) -> Vec<T::Hash> {
+            let account = |who: &T::AccountId| Topic::Account.of::<T::Hashing>(who);
+            let ual = |ual: &Vec<u8>| Topic::Ual.of::<T::Hashing>(ual);
+
+            match self {
+                Event::ReputationQueued { who, .. }
+                | Event::PublishRetryScheduled { who, .. }
+                | Event::PublishingFailed { who, .. }
+                | Event::DeadLetterRequeued { who }
+                | Event::ParanetMemberAdded { who }
+                | Event::ParanetMemberRemoved { who }
+                | Event::PublishStatusChanged { who, .. }
+                | Event::GithubHandleVerified { who, .. }
+                | Event::PassportRequested { who }
+                | Event::PassportIssued { who, ual: None, .. } => vec![account(who)],
+                Event::UALStored { who, ual: asset }
+                | Event::DKGPublished { who, ual: asset, .. }
+                | Event::PublishAcknowledged { who, ual: asset }
+                | Event::AssetVersionUpdated { who, ual: asset, .. }
+                | Event::DidDocumentPublished { who, ual: asset }
+                | Event::PassportIssued { who, ual: Some(asset), .. } => vec![account(who), ual(asset)],
+                Event::ContributionUALStored { contribution_id, ual: asset } =>
+                    vec![Topic::Contribution.of::<T::Hashing>(contribution_id), ual(asset)],
+                Event::ParanetRegistered { ual: asset, .. } | Event::AssertionAnchored { ual: asset, .. } =>
+                    vec![ual(asset)],
+                // Endpoint changes concern no particular account
+                _ => Vec::new(),
+            }
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Deposit an event indexed under the accounts and UALs it concerns
+        pub(super) fn deposit_event(event: Event<T>) {
+            let topics = event.topics();
+            let event = <T as Config>::RuntimeEvent::from(event);
+            frame_system::Pallet::<T>::deposit_event_indexed(&topics, event.into());
+        }
     }
 
     #[pallet::error]
@@ -106,6 +460,51 @@ pub mod pallet {
         
         /// DKG publishing failed
         PublishingFailed,
+
+        /// No queued publish for this developer
+        NotQueued,
+
+        /// Assertion id is empty or too long
+        InvalidAssertionId,
+
+        /// No dead-lettered publish for this developer
+        NotDeadLettered,
+
+        /// No Paranet has been registered
+        NoParanet,
+
+        /// Paranet membership is curated by governance
+        ParanetPermissioned,
+
+        /// Account is already a Paranet member
+        AlreadyParanetMember,
+
+        /// Account is not a Paranet member
+        NotParanetMember,
+
+        /// GitHub handle is empty or longer than 39 characters
+        InvalidHandle,
+
+        /// No DID document is pending for this contributor
+        NoPendingDidDocument,
+
+        /// A passport is already being issued for this contributor
+        PassportAlreadyRequested,
+
+        /// No passport was requested by this contributor
+        NoPassportRequest,
+
+        /// The signed payload is for an earlier queue entry or passport request of this
+        /// contributor
+        StalePayload,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn offchain_worker(now: BlockNumberFor<T>) {
+            Self::publish_queued(now);
+            Self::issue_passports(now);
+        }
     }
 
     #[pallet::call]
@@ -126,7 +525,7 @@ pub mod pallet {
             ensure!(ual.len() > 0 && ual.len() <= 256, Error::<T>::InvalidUAL);
 
             // Convert to BoundedVec
-            let bounded_ual: BoundedVec<u8, ConstU32<256>> = ual.clone()
+            let bounded_ual: Ual = ual.clone()
                 .try_into()
                 .map_err(|_| Error::<T>::InvalidUAL)?;
 
@@ -139,28 +538,23 @@ pub mod pallet {
             Ok(())
         }
 
-        /// Queue reputation for DKG publishing
+        /// Queue an account's reputation for DKG publishing
         /// 
-        /// This adds the developer's reputation to a queue that will be processed
-        /// by an off-chain worker to publish to the DKG.
+        /// This adds the account's current score, read from the reputation pallet, to
+        /// a queue that will be processed by an off-chain worker to publish to the DKG.
+        /// The reputation pallet queues updates itself through `DKGIntegration`; this
+        /// call lets `UpdateOrigin` force a republish.
         #[pallet::call_index(1)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(Weight::from_parts(10_000, 0).saturating_add(T::Reputation::reputation_of_weight()))]
         pub fn queue_for_publishing(
             origin: OriginFor<T>,
-            reputation_score: u32,
+            account: T::AccountId,
         ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
-
-            let current_block = <frame_system::Pallet<T>>::block_number();
-
-            // Add to publishing queue
-            PublishingQueue::<T>::insert(&who, (reputation_score, current_block));
+            T::UpdateOrigin::ensure_origin(origin)?;
 
-            // Emit event for off-chain worker
-            Self::deposit_event(Event::ReputationQueued { 
-                who, 
-                score: reputation_score 
-            });
+            // Negative scores are published as zero
+            let reputation_score = T::Reputation::reputation_of(&account).max(0) as u32;
+            Self::enqueue(&account, reputation_score);
 
             Ok(())
         }
@@ -186,91 +580,1183 @@ pub mod pallet {
 
             Ok(())
         }
-    }
 
-    impl<T: Config> Pallet<T> {
-        /// Get UAL for a developer (helper function)
-        pub fn get_developer_ual(who: &T::AccountId) -> Option<Vec<u8>> {
-            DeveloperUAL::<T>::get(who).map(|bounded| bounded.to_vec())
-        }
+        /// Confirm that a queued reputation snapshot was published to the DKG
+        ///
+        /// Submitted by the off-chain worker once the DKG node returned the
+        /// Knowledge Asset's UAL and assertion id. Clears the queue entry and anchors
+        /// the root of the assertion the chain intended to publish, so a snapshot that
+        /// changed while in flight shows up as a mismatch in `verify_anchored`.
+        ///
+        /// Weighed by the largest snapshot, which is rebuilt to compute the root.
+        #[pallet::call_index(3)]
+        #[pallet::weight(Weight::from_parts(10_000, 0)
+            .saturating_add(T::Snapshot::reputation_snapshot_weight())
+            .saturating_add(T::DbWeight::get().reads_writes(6, 6)))]
+        pub fn confirm_publish(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            ual: Vec<u8>,
+            assertion_id: Vec<u8>,
+        ) -> DispatchResult {
+            T::PublisherOrigin::ensure_origin(origin)?;
 
-        /// Check if developer has a UAL
-        pub fn has_ual(who: &T::AccountId) -> bool {
-            DeveloperUAL::<T>::contains_key(who)
-        }
+            let (score, queued_at) = PublishingQueue::<T>::get(&account)
+                .ok_or(Error::<T>::NotQueued)?;
+            ensure!(!assertion_id.is_empty(), Error::<T>::InvalidAssertionId);
+            let bounded_assertion: BoundedVec<u8, ConstU32<128>> = assertion_id.clone()
+                .try_into()
+                .map_err(|_| Error::<T>::InvalidAssertionId)?;
 
-        /// Get pending publishing queue items
-        pub fn get_queue_item(who: &T::AccountId) -> Option<(u32, BlockNumberFor<T>)> {
-            PublishingQueue::<T>::get(who)
+            // Built before the mapping is stored, while it still names the previous version
+            let root = Self::assertion_root(&Self::build_assertion(&account, score, queued_at));
+            <Self as DKGIntegration<T>>::store_ual_mapping(&account, ual.clone())?;
+            Self::anchor(ual.clone(), root)?;
+            PublishedAssertion::<T>::insert(&account, bounded_assertion);
+            PublishingQueue::<T>::remove(&account);
+            PublishRetries::<T>::remove(&account);
+            Self::set_status(&account, PublishStatus::Confirmed);
+
+            Self::deposit_event(Event::DKGPublished { who: account, ual, assertion_id });
+
+            Ok(())
         }
 
-        /// Remove from publishing queue (called after successful publishing)
-        pub fn remove_from_queue(who: &T::AccountId) {
-            PublishingQueue::<T>::remove(who);
+        /// Record the UAL of a contribution published as its own Knowledge Asset
+        ///
+        /// Submitted by a DKG publisher so each piece of work is independently
+        /// referenceable in the DKG.
+        #[pallet::call_index(4)]
+        #[pallet::weight(10_000)]
+        pub fn confirm_contribution_publish(
+            origin: OriginFor<T>,
+            contribution_id: ContributionId,
+            ual: Vec<u8>,
+        ) -> DispatchResult {
+            T::PublisherOrigin::ensure_origin(origin)?;
+
+            <Self as DKGIntegration<T>>::store_contribution_ual(contribution_id, ual)
         }
-    }
-}
 
-/// Implementation of DKGIntegration trait for the reputation pallet
-impl<T: Config> DKGIntegration<T> for Pallet<T> {
-    fn publish_to_dkg(who: &T::AccountId, reputation_score: u32) -> DispatchResult {
-        // Queue for publishing by off-chain worker
-        let current_block = <frame_system::Pallet<T>>::block_number();
-        PublishingQueue::<T>::insert(who, (reputation_score, current_block));
+        /// Report a failed publish attempt for a queued item
+        ///
+        /// Submitted by the off-chain worker with the class of the failure, which is
+        /// counted in `OcwStats`. Transient failures schedule the next attempt with
+        /// exponential backoff, and move the item to the dead-letter map once
+        /// `MaxPublishAttempts` attempts have failed. Misconfiguration fails every item
+        /// alike, so it spends no attempt and retries after the longest backoff until an
+        /// operator fixes it. An item the DKG node rejected is dead-lettered at once.
+        #[pallet::call_index(5)]
+        #[pallet::weight(Weight::from_parts(10_000, 0).saturating_add(T::DbWeight::get().reads_writes(4, 5)))]
+        pub fn report_publish_failure(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            failure: HttpFailure,
+        ) -> DispatchResult {
+            T::PublisherOrigin::ensure_origin(origin)?;
 
-        Self::deposit_event(Event::ReputationQueued { 
-            who: who.clone(), 
-            score: reputation_score 
-        });
+            let (score, queued_at) = PublishingQueue::<T>::get(&account)
+                .ok_or(Error::<T>::NotQueued)?;
+            let now = <frame_system::Pallet<T>>::block_number();
+            OcwStats::<T>::mutate(failure, |stats| {
+                stats.count = stats.count.saturating_add(1);
+                stats.last_at = now;
+            });
 
-        Ok(())
-    }
+            let failed = PublishRetries::<T>::get(&account).map_or(0, |retry| retry.attempts);
+            let attempts = if failure.is_misconfiguration() { failed } else { failed.saturating_add(1) };
 
-    fn store_ual_mapping(who: &T::AccountId, ual: Vec<u8>) -> DispatchResult {
-        let bounded_ual: BoundedVec<u8, ConstU32<256>> = ual.clone()
-            .try_into()
-            .map_err(|_| Error::<T>::InvalidUAL)?;
+            if failure.is_rejection() || attempts >= T::MaxPublishAttempts::get() {
+                PublishingQueue::<T>::remove(&account);
+                PublishRetries::<T>::remove(&account);
+                DeadLetters::<T>::insert(&account, (score, queued_at, attempts));
+                Self::set_status(&account, PublishStatus::Failed);
 
-        DeveloperUAL::<T>::insert(who, bounded_ual);
+                Self::deposit_event(Event::PublishingFailed { who: account, attempts, failure });
+            } else {
+                let doublings = if failure.is_misconfiguration() {
+                    T::MaxPublishAttempts::get().saturating_sub(1)
+                } else {
+                    attempts.saturating_sub(1)
+                };
+                let backoff = T::RetryBackoff::get()
+                    .saturating_mul(2u32.saturating_pow(doublings.min(16)).into());
+                let next_retry = now.saturating_add(backoff);
+                PublishRetries::<T>::insert(&account, PublishRetry { attempts, next_retry });
+                Self::set_status(&account, PublishStatus::Queued);
 
-        Self::deposit_event(Event::UALStored { 
-            who: who.clone(), 
-            ual 
-        });
+                Self::deposit_event(Event::PublishRetryScheduled { who: account, attempts, next_retry, failure });
+            }
 
-        Ok(())
-    }
+            Ok(())
+        }
 
-    fn get_ual(who: &T::AccountId) -> Option<Vec<u8>> {
-        Self::get_developer_ual(who)
-    }
+        /// Move a dead-lettered publish back into the queue with a fresh attempt budget
+        ///
+        /// Only callable by root/governance
+        #[pallet::call_index(6)]
+        #[pallet::weight(10_000)]
+        pub fn requeue_dead_letter(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
 
-    fn verify_dkg_proof(ual: Vec<u8>, proof: Vec<u8>) -> bool {
-        // TODO: Implement cryptographic verification of DKG proofs
-        // This would typically involve:
-        // 1. Parsing the proof structure
-        // 2. Verifying signatures against known DKG node public keys
-        // 3. Checking Merkle proofs for data integrity
-        // 4. Validating blockchain anchors on NeuroWeb
-        
-        // For now, basic validation
-        !ual.is_empty() && !proof.is_empty()
-    }
-}
+            let (score, queued_at, _) = DeadLetters::<T>::take(&account)
+                .ok_or(Error::<T>::NotDeadLettered)?;
+            PublishingQueue::<T>::insert(&account, (score, queued_at));
+            Self::set_status(&account, PublishStatus::Queued);
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::mock::*;
-    use frame_support::assert_ok;
+            Self::deposit_event(Event::DeadLetterRequeued { who: account });
 
-    #[test]
-    fn test_store_ual() {
-        new_test_ext().execute_with(|| {
-            let developer = 1;
-            let ual = b"did:dkg:otp/2043/0x1234...".to_vec();
+            Ok(())
+        }
 
-            assert_ok!(DKGPallet::store_ual(
+        /// Register (or update) the Paranet grouping DotRep's Knowledge Assets
+        ///
+        /// Only callable by root/governance
+        #[pallet::call_index(7)]
+        #[pallet::weight(10_000)]
+        pub fn register_paranet(
+            origin: OriginFor<T>,
+            ual: Vec<u8>,
+            policy: ParanetPolicy,
+            incentives: ParanetIncentives,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(!ual.is_empty(), Error::<T>::InvalidUAL);
+            let bounded_ual: Ual = ual.clone()
+                .try_into()
+                .map_err(|_| Error::<T>::InvalidUAL)?;
+
+            Paranet::<T>::put(ParanetInfo {
+                ual: bounded_ual,
+                policy: policy.clone(),
+                incentives,
+                registered_at: <frame_system::Pallet<T>>::block_number(),
+            });
+
+            Self::deposit_event(Event::ParanetRegistered { ual, policy });
+
+            Ok(())
+        }
+
+        /// Join an open Paranet so published reputation is grouped under it
+        #[pallet::call_index(8)]
+        #[pallet::weight(10_000)]
+        pub fn join_paranet(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let paranet = Paranet::<T>::get().ok_or(Error::<T>::NoParanet)?;
+            ensure!(paranet.policy == ParanetPolicy::Open, Error::<T>::ParanetPermissioned);
+
+            Self::add_member(who)
+        }
+
+        /// Add a contributor to the Paranet regardless of its policy
+        ///
+        /// Only callable by root/governance
+        #[pallet::call_index(9)]
+        #[pallet::weight(10_000)]
+        pub fn add_paranet_member(
+            origin: OriginFor<T>,
+            who: T::AccountId,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(Paranet::<T>::exists(), Error::<T>::NoParanet);
+
+            Self::add_member(who)
+        }
+
+        /// Leave the Paranet
+        #[pallet::call_index(10)]
+        #[pallet::weight(10_000)]
+        pub fn leave_paranet(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ParanetMembers::<T>::take(&who).ok_or(Error::<T>::NotParanetMember)?;
+
+            Self::deposit_event(Event::ParanetMemberRemoved { who });
+
+            Ok(())
+        }
+
+        /// Mark a queued snapshot as handed to the DKG node
+        ///
+        /// Submitted by the off-chain worker before it publishes, so dApps can show
+        /// that publishing is in progress until `confirm_publish` lands.
+        #[pallet::call_index(11)]
+        #[pallet::weight(10_000)]
+        pub fn mark_publish_submitted(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+        ) -> DispatchResult {
+            T::PublisherOrigin::ensure_origin(origin)?;
+
+            ensure!(PublishingQueue::<T>::contains_key(&account), Error::<T>::NotQueued);
+            Self::set_status(&account, PublishStatus::Submitted);
+
+            Ok(())
+        }
+
+        /// Acknowledge a published snapshot and record its UAL
+        ///
+        /// Lighter alternative to `confirm_publish` for publishers that only get a UAL
+        /// back: removes the queue entry and stores the UAL in one step.
+        #[pallet::call_index(12)]
+        #[pallet::weight(10_000)]
+        pub fn ack_published(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            ual: Vec<u8>,
+        ) -> DispatchResult {
+            T::PublisherOrigin::ensure_origin(origin)?;
+
+            Self::acknowledge(account, ual)
+        }
+
+        /// Unsigned `ack_published`, authorized by a publisher key signature
+        ///
+        /// Lets the off-chain worker acknowledge without paying fees. The signing key
+        /// must belong to an account accepted by `PublisherOrigin` (checked in
+        /// `validate_unsigned`). The payload names the block its snapshot was queued
+        /// at, so it cannot acknowledge a later queue entry of the same contributor.
+        #[pallet::call_index(13)]
+        #[pallet::weight(10_000)]
+        pub fn ack_published_unsigned(
+            origin: OriginFor<T>,
+            payload: AckPayload<T::Public, T::AccountId, BlockNumberFor<T>>,
+            _signature: T::Signature,
+        ) -> DispatchResult {
+            ensure_none(origin)?;
+
+            let (_, queued_at) = PublishingQueue::<T>::get(&payload.account).ok_or(Error::<T>::NotQueued)?;
+            ensure!(queued_at == payload.queued_at, Error::<T>::StalePayload);
+
+            Self::acknowledge(payload.account, payload.ual.into_inner())
+        }
+
+        /// Attest a contributor's verified GitHub handle
+        ///
+        /// Submitted by a DKG publisher after checking the account's GitHub link.
+        /// Queues the contributor's DID document for republishing.
+        #[pallet::call_index(14)]
+        #[pallet::weight(10_000)]
+        pub fn attest_github_handle(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            handle: Vec<u8>,
+        ) -> DispatchResult {
+            T::PublisherOrigin::ensure_origin(origin)?;
+
+            ensure!(!handle.is_empty(), Error::<T>::InvalidHandle);
+            let bounded_handle: BoundedVec<u8, ConstU32<39>> = handle.clone()
+                .try_into()
+                .map_err(|_| Error::<T>::InvalidHandle)?;
+
+            GithubHandles::<T>::insert(&account, bounded_handle);
+            Self::queue_did_document(&account);
+
+            Self::deposit_event(Event::GithubHandleVerified { who: account, handle });
+
+            Ok(())
+        }
+
+        /// Confirm that a contributor's DID document was published to the DKG
+        ///
+        /// Anchors the root of the document the chain would build right now, so a
+        /// document published from stale data fails `verify_anchored`.
+        #[pallet::call_index(15)]
+        #[pallet::weight(10_000)]
+        pub fn confirm_did_document(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            ual: Vec<u8>,
+        ) -> DispatchResult {
+            T::PublisherOrigin::ensure_origin(origin)?;
+
+            ensure!(PendingDidDocuments::<T>::contains_key(&account), Error::<T>::NoPendingDidDocument);
+            let bounded_ual: Ual = ual.clone()
+                .try_into()
+                .map_err(|_| Error::<T>::InvalidUAL)?;
+
+            let root = Self::assertion_root(&Self::build_did_document(&account));
+            Self::anchor(ual.clone(), root)?;
+            DidDocuments::<T>::insert(&account, bounded_ual);
+            PendingDidDocuments::<T>::remove(&account);
+
+            Self::deposit_event(Event::DidDocumentPublished { who: account, ual });
+
+            Ok(())
+        }
+
+        /// Request a reputation passport
+        ///
+        /// The off-chain worker assembles the caller's verified contributions, score and
+        /// attestations into a Verifiable Credential signed by a publisher key and
+        /// submits it through `anchor_passport_unsigned`.
+        #[pallet::call_index(16)]
+        #[pallet::weight(10_000)]
+        pub fn request_passport(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(!PassportRequests::<T>::contains_key(&who), Error::<T>::PassportAlreadyRequested);
+            PassportRequests::<T>::insert(&who, <frame_system::Pallet<T>>::block_number());
+
+            Self::deposit_event(Event::PassportRequested { who });
+
+            Ok(())
+        }
+
+        /// Anchor an issued passport, authorized by a publisher key signature
+        ///
+        /// Stores the credential's hash on-chain and the credential itself in the
+        /// off-chain database under `passport_key`, where the contributor can fetch it
+        /// from any node with off-chain indexing enabled. If the passport was also
+        /// published to the DKG, its hash is anchored for the UAL as well. The payload
+        /// names the block the passport was requested at, so it cannot serve a later
+        /// request of the same contributor.
+        #[pallet::call_index(17)]
+        #[pallet::weight(10_000)]
+        pub fn anchor_passport_unsigned(
+            origin: OriginFor<T>,
+            payload: PassportPayload<T::Public, T::AccountId, BlockNumberFor<T>>,
+            _signature: T::Signature,
+        ) -> DispatchResult {
+            ensure_none(origin)?;
+
+            let PassportPayload { account, credential, ual, requested_at, .. } = payload;
+            let request = PassportRequests::<T>::get(&account).ok_or(Error::<T>::NoPassportRequest)?;
+            ensure!(request == requested_at, Error::<T>::StalePayload);
+            PassportRequests::<T>::remove(&account);
+
+            let hash = Self::assertion_root(&credential);
+            if let Some(ref ual) = ual {
+                Self::anchor(ual.to_vec(), hash)?;
+            }
+            sp_io::offchain_index::set(&passport_key(&account), &credential);
+            Passports::<T>::insert(&account, PassportAnchor {
+                hash,
+                issued_at: <frame_system::Pallet<T>>::block_number(),
+                ual: ual.clone(),
+            });
+
+            Self::deposit_event(Event::PassportIssued {
+                who: account,
+                hash,
+                ual: ual.map(|ual| ual.into_inner()),
+            });
+
+            Ok(())
+        }
+    }
+
+    #[pallet::validate_unsigned]
+    impl<T: Config> ValidateUnsigned for Pallet<T> {
+        type Call = Call<T>;
+
+        fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            let (account, pending, tag) = match call {
+                Call::ack_published_unsigned { payload, signature } => {
+                    Self::check_publisher_signature(payload, signature)?;
+                    let pending = PublishingQueue::<T>::get(&payload.account)
+                        .map_or(false, |(_, queued_at)| queued_at == payload.queued_at);
+                    (&payload.account, pending, "DkgAckPublished")
+                }
+                Call::anchor_passport_unsigned { payload, signature } => {
+                    Self::check_publisher_signature(payload, signature)?;
+                    let pending = PassportRequests::<T>::get(&payload.account) == Some(payload.requested_at);
+                    (&payload.account, pending, "DkgPassport")
+                }
+                _ => return InvalidTransaction::Call.into(),
+            };
+            if !pending {
+                return InvalidTransaction::Stale.into();
+            }
+
+            ValidTransaction::with_tag_prefix(tag)
+                .priority(TransactionPriority::max_value())
+                .and_provides(account.encode())
+                .longevity(IN_FLIGHT_BLOCKS.into())
+                .propagate(true)
+                .build()
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Dequeue a published snapshot and store its UAL atomically
+        fn acknowledge(account: T::AccountId, ual: Vec<u8>) -> DispatchResult {
+            ensure!(PublishingQueue::<T>::contains_key(&account), Error::<T>::NotQueued);
+
+            <Self as DKGIntegration<T>>::store_ual_mapping(&account, ual.clone())?;
+            PublishingQueue::<T>::remove(&account);
+            PublishRetries::<T>::remove(&account);
+            Self::set_status(&account, PublishStatus::Confirmed);
+
+            Self::deposit_event(Event::PublishAcknowledged { who: account, ual });
+
+            Ok(())
+        }
+
+        /// Check that an unsigned payload is signed by a key of the publisher set
+        fn check_publisher_signature<P: SignedPayload<T>>(
+            payload: &P,
+            signature: &T::Signature,
+        ) -> Result<(), TransactionValidityError> {
+            if !SignedPayload::<T>::verify::<T::AuthorityId>(payload, signature.clone()) {
+                return Err(InvalidTransaction::BadProof.into());
+            }
+            if !Self::is_publisher(payload.public().into_account()) {
+                return Err(InvalidTransaction::BadSigner.into());
+            }
+            Ok(())
+        }
+
+        /// Whether `who` belongs to the publisher set accepted by `PublisherOrigin`
+        pub fn is_publisher(who: T::AccountId) -> bool {
+            T::PublisherOrigin::try_origin(frame_system::RawOrigin::Signed(who).into()).is_ok()
+        }
+
+        /// Queue the contributor's DID document for the off-chain worker
+        fn queue_did_document(who: &T::AccountId) {
+            PendingDidDocuments::<T>::insert(who, <frame_system::Pallet<T>>::block_number());
+        }
+
+        /// Add a score to the publishing queue for the off-chain worker
+        fn enqueue(who: &T::AccountId, reputation_score: u32) {
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            PublishingQueue::<T>::insert(who, (reputation_score, current_block));
+            Self::set_status(who, PublishStatus::Queued);
+
+            Self::deposit_event(Event::ReputationQueued {
+                who: who.clone(),
+                score: reputation_score,
+            });
+        }
+
+        /// Record a publish status transition; repeated statuses emit no event
+        fn set_status(who: &T::AccountId, status: PublishStatus) {
+            if PublishStatuses::<T>::get(who).as_ref() == Some(&status) {
+                return;
+            }
+            PublishStatuses::<T>::insert(who, status.clone());
+            Self::deposit_event(Event::PublishStatusChanged { who: who.clone(), status });
+        }
+
+        fn add_member(who: T::AccountId) -> DispatchResult {
+            ensure!(!ParanetMembers::<T>::contains_key(&who), Error::<T>::AlreadyParanetMember);
+
+            ParanetMembers::<T>::insert(&who, <frame_system::Pallet<T>>::block_number());
+
+            Self::deposit_event(Event::ParanetMemberAdded { who });
+
+            Ok(())
+        }
+
+        /// Anchor an assertion root for a published UAL at the current block
+        pub(crate) fn anchor(ual: Vec<u8>, root: H256) -> DispatchResult {
+            let bounded_ual: Ual = ual.clone()
+                .try_into()
+                .map_err(|_| Error::<T>::InvalidUAL)?;
+            let block = <frame_system::Pallet<T>>::block_number();
+
+            AnchoredAssertions::<T>::insert(bounded_ual, (root, block));
+
+            Self::deposit_event(Event::AssertionAnchored { ual, root, block });
+
+            Ok(())
+        }
+
+        /// Root hash of a serialized assertion
+        pub fn assertion_root(assertion: &[u8]) -> H256 {
+            H256(sp_io::hashing::blake2_256(assertion))
+        }
+
+        /// Check that `root` matches the assertion anchored on-chain for `ual`
+        pub fn verify_anchored(ual: &[u8], root: H256) -> bool {
+            Ual::try_from(ual.to_vec())
+                .ok()
+                .and_then(AnchoredAssertions::<T>::get)
+                .map_or(false, |(anchored, _)| anchored == root)
+        }
+
+        /// Paranet UAL the account's Knowledge Assets are grouped under, if a member
+        pub fn member_paranet_ual(who: &T::AccountId) -> Option<Vec<u8>> {
+            if !ParanetMembers::<T>::contains_key(who) {
+                return None;
+            }
+            Paranet::<T>::get().map(|paranet| paranet.ual.to_vec())
+        }
+
+        /// UAL and latest version of a developer's reputation asset
+        pub fn latest_asset(who: &T::AccountId) -> Option<(Vec<u8>, u32)> {
+            DeveloperUAL::<T>::get(who).map(|ual| (ual.into_inner(), AssetVersions::<T>::get(who)))
+        }
+
+        /// Get UAL for a developer (helper function)
+        pub fn get_developer_ual(who: &T::AccountId) -> Option<Vec<u8>> {
+            DeveloperUAL::<T>::get(who).map(|bounded| bounded.to_vec())
+        }
+
+        /// Get UAL for a contribution (helper function)
+        pub fn get_contribution_ual(contribution_id: ContributionId) -> Option<Vec<u8>> {
+            ContributionUAL::<T>::get(contribution_id).map(|bounded| bounded.to_vec())
+        }
+
+        /// Check if developer has a UAL
+        pub fn has_ual(who: &T::AccountId) -> bool {
+            DeveloperUAL::<T>::contains_key(who)
+        }
+
+        /// Get pending publishing queue items
+        pub fn get_queue_item(who: &T::AccountId) -> Option<(u32, BlockNumberFor<T>)> {
+            PublishingQueue::<T>::get(who)
+        }
+
+        /// Remove from publishing queue (called after successful publishing)
+        pub fn remove_from_queue(who: &T::AccountId) {
+            PublishingQueue::<T>::remove(who);
+        }
+
+        /// Assertions anchored in blocks `from..=to`, at most `limit` of them
+        ///
+        /// Scans the whole anchor map; intended for runtime API queries, not dispatchables.
+        pub fn anchored_between(
+            from: BlockNumberFor<T>,
+            to: BlockNumberFor<T>,
+            limit: u32,
+        ) -> Vec<(Vec<u8>, H256, BlockNumberFor<T>)> {
+            AnchoredAssertions::<T>::iter()
+                .filter(|(_, (_, block))| *block >= from && *block <= to)
+                .take(limit as usize)
+                .map(|(ual, (root, block))| (ual.into_inner(), root, block))
+                .collect()
+        }
+
+        /// UAL, assertion, anchor and publish status of an account's reputation asset
+        pub fn dkg_assets(who: &T::AccountId) -> DkgAssets<BlockNumberFor<T>> {
+            let ual = DeveloperUAL::<T>::get(who);
+            DkgAssets {
+                anchor: ual.as_ref().and_then(|ual| AnchoredAssertions::<T>::get(ual)),
+                ual: ual.map(|ual| ual.into_inner()),
+                assertion_id: PublishedAssertion::<T>::get(who).map(|id| id.into_inner()),
+                status: PublishStatuses::<T>::get(who),
+                paranet: Self::member_paranet_ual(who),
+            }
+        }
+    }
+}
+
+/// Lifecycle of a queued reputation publish
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub enum PublishStatus {
+    /// Waiting for the off-chain worker (also after a retryable failure)
+    Queued,
+    /// Handed to the DKG node, awaiting confirmation
+    Submitted,
+    /// Live on the DKG
+    Confirmed,
+    /// Dead-lettered after exhausting its attempts
+    Failed,
+}
+
+/// Who may join the Paranet
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ParanetPolicy {
+    /// Any contributor can join
+    Open,
+    /// Members are added by governance
+    Permissioned,
+}
+
+/// Paranet incentive configuration, denominated in TRAC/NEURO base units
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ParanetIncentives {
+    /// Reward per Knowledge Asset published to the Paranet
+    pub reward_per_asset: u128,
+    /// Share of rewards paid to the Paranet operator
+    pub operator_share: sp_runtime::Perbill,
+}
+
+/// Registered Paranet
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ParanetInfo<BlockNumber> {
+    pub ual: Ual,
+    pub policy: ParanetPolicy,
+    pub incentives: ParanetIncentives,
+    pub registered_at: BlockNumber,
+}
+
+/// DKG assets of an account, as returned by `DkgQueryApi::dkg_assets`
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct DkgAssets<BlockNumber> {
+    pub ual: Option<Vec<u8>>,
+    pub assertion_id: Option<Vec<u8>>,
+    /// Anchored assertion root and block of `ual`
+    pub anchor: Option<(H256, BlockNumber)>,
+    pub status: Option<PublishStatus>,
+    pub paranet: Option<Vec<u8>>,
+}
+
+/// Publisher-signed acknowledgement submitted through `ack_published_unsigned`
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct AckPayload<Public, AccountId, BlockNumber> {
+    /// Publisher key that signed the payload
+    pub public: Public,
+    pub account: AccountId,
+    pub ual: Ual,
+    /// Block the acknowledged snapshot was queued at
+    pub queued_at: BlockNumber,
+}
+
+impl<T: SigningTypes> SignedPayload<T> for AckPayload<T::Public, T::AccountId, BlockNumberFor<T>> {
+    fn public(&self) -> T::Public {
+        self.public.clone()
+    }
+}
+
+/// Publisher-signed passport submitted through `anchor_passport_unsigned`
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct PassportPayload<Public, AccountId, BlockNumber> {
+    /// Publisher key that signed the payload
+    pub public: Public,
+    pub account: AccountId,
+    /// Signed Verifiable Credential
+    pub credential: BoundedVec<u8, ConstU32<MAX_PASSPORT_LEN>>,
+    /// UAL of the passport, if it was published to the DKG
+    pub ual: Option<Ual>,
+    /// Block the served passport request was made at
+    pub requested_at: BlockNumber,
+}
+
+impl<T: SigningTypes> SignedPayload<T> for PassportPayload<T::Public, T::AccountId, BlockNumberFor<T>> {
+    fn public(&self) -> T::Public {
+        self.public.clone()
+    }
+}
+
+/// On-chain record of an issued passport
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PassportAnchor<BlockNumber> {
+    /// blake2-256 hash of the signed credential
+    pub hash: H256,
+    pub issued_at: BlockNumber,
+    pub ual: Option<Ual>,
+}
+
+/// Off-chain database key of an account's latest passport
+pub fn passport_key<AccountId: Encode>(account: &AccountId) -> Vec<u8> {
+    [PASSPORT_PREFIX, &account.encode()].concat()
+}
+
+/// Retry state of a queued publish
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PublishRetry<BlockNumber> {
+    pub attempts: u32,
+    pub next_retry: BlockNumber,
+}
+
+/// Knowledge Asset returned by the DKG node for a publish
+#[derive(Clone, PartialEq, RuntimeDebug)]
+pub struct PublishedAsset {
+    pub ual: Vec<u8>,
+    pub assertion_id: Vec<u8>,
+}
+
+/// Class of a failed off-chain HTTP request, reported on-chain with the failure
+///
+/// The off-chain HTTP API does not tell DNS, TLS and refused connections apart; they
+/// are all reported as `Connection`.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum HttpFailure {
+    /// The connection could not be established (DNS, TLS, refused)
+    Connection,
+    /// No response before the deadline
+    Timeout,
+    /// 429: the node throttles the publisher
+    RateLimited,
+    /// 5xx
+    ServerError,
+    /// 401 or 403: missing or bad credentials
+    Unauthorized,
+    /// Any other 4xx: the node rejected the request
+    ClientError,
+    /// The response could not be parsed; it may be specific to the item, so it spends
+    /// an attempt
+    Parse,
+    /// The configured endpoint is not a valid URL
+    InvalidEndpoint,
+    /// 404 or 405: the endpoint does not serve the publish API
+    EndpointNotFound,
+}
+
+impl HttpFailure {
+    /// Outages expected to clear by themselves
+    pub fn is_transient(&self) -> bool {
+        matches!(self, Self::Connection | Self::Timeout | Self::RateLimited | Self::ServerError)
+    }
+
+    /// Failures persisting until an operator fixes the node or endpoint configuration
+    pub fn is_misconfiguration(&self) -> bool {
+        matches!(self, Self::Unauthorized | Self::InvalidEndpoint | Self::EndpointNotFound)
+    }
+
+    /// Failures caused by the request itself, which retrying cannot fix
+    pub fn is_rejection(&self) -> bool {
+        matches!(self, Self::ClientError)
+    }
+}
+
+/// Failures of one class reported by the off-chain worker
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct OcwFailureStats<BlockNumber> {
+    pub count: u32,
+    /// Block the latest failure was reported in
+    pub last_at: BlockNumber,
+}
+
+/// Off-chain publish errors
+#[derive(Debug, PartialEq)]
+pub enum PublishError {
+    InvalidEndpoint,
+    Http(http::Error),
+    Status(u16),
+    MalformedResponse,
+}
+
+impl PublishError {
+    pub fn classify(&self) -> HttpFailure {
+        match self {
+            PublishError::InvalidEndpoint => HttpFailure::InvalidEndpoint,
+            PublishError::Http(http::Error::DeadlineReached) => HttpFailure::Timeout,
+            PublishError::Http(_) => HttpFailure::Connection,
+            PublishError::Status(401 | 403) => HttpFailure::Unauthorized,
+            PublishError::Status(404 | 405) => HttpFailure::EndpointNotFound,
+            PublishError::Status(429) => HttpFailure::RateLimited,
+            PublishError::Status(400..=499) => HttpFailure::ClientError,
+            PublishError::Status(500..=599) => HttpFailure::ServerError,
+            // Success or redirect codes without the expected body
+            PublishError::Status(_) | PublishError::MalformedResponse => HttpFailure::Parse,
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Off-chain worker: publish queued reputation to the DKG node and confirm it on-chain
+    ///
+    /// Items backing off after a failure are skipped until their next retry block, and
+    /// items whose confirmation is still in flight until it lands or `IN_FLIGHT_BLOCKS`
+    /// pass. Failures are reported on-chain to schedule the retry.
+    pub fn publish_queued(now: BlockNumberFor<T>) {
+        let endpoint = DKGEndpoint::<T>::get();
+        if endpoint.is_empty() {
+            return;
+        }
+
+        let signer = Signer::<T, T::AuthorityId>::any_account();
+        if !signer.can_sign() {
+            log::debug!(target: LOG_TARGET, "No DKG publisher key in the keystore");
+            return;
+        }
+
+        let queued = PublishingQueue::<T>::iter()
+            .filter(|(who, _)| PublishRetries::<T>::get(who).map_or(true, |retry| retry.next_retry <= now))
+            .take(T::MaxPublishesPerBlock::get() as usize);
+        for (who, (score, queued_at)) in queued {
+            if !Self::mark_in_flight(b"reputation", &who, now) {
+                continue;
+            }
+
+            let submitted = signer.send_signed_transaction(|_| Call::mark_publish_submitted {
+                account: who.clone(),
+            });
+            if !matches!(submitted, Some((_, Ok(())))) {
+                log::warn!(target: LOG_TARGET, "Failed to mark DKG publish as submitted");
+            }
+
+            let assertion = Self::build_assertion(&who, score, queued_at);
+            let sent = match DeveloperUAL::<T>::get(&who) {
+                Some(ual) => Self::send_update(&endpoint, &ual, &assertion),
+                None => Self::send_publish(&endpoint, &assertion),
+            };
+            let published = match sent {
+                Ok(published) => published,
+                Err(e) => {
+                    let failure = e.classify();
+                    log::warn!(target: LOG_TARGET, "DKG publish failed ({:?}): {:?}", failure, e);
+                    let result = signer.send_signed_transaction(|_| Call::report_publish_failure {
+                        account: who.clone(),
+                        failure,
+                    });
+                    if !matches!(result, Some((_, Ok(())))) {
+                        log::warn!(target: LOG_TARGET, "Failed to report DKG publish failure");
+                    }
+                    continue;
+                }
+            };
+
+            let result = signer.send_signed_transaction(|_| Call::confirm_publish {
+                account: who.clone(),
+                ual: published.ual.clone(),
+                assertion_id: published.assertion_id.clone(),
+            });
+            match result {
+                Some((_, Ok(()))) => {
+                    log::info!(target: LOG_TARGET, "Submitted DKG publish confirmation at block {:?}", now)
+                }
+                _ => log::warn!(target: LOG_TARGET, "Failed to submit DKG publish confirmation"),
+            }
+        }
+
+        Self::publish_did_documents(now, &endpoint, &signer);
+    }
+
+    /// Off-chain worker: issue requested passports and anchor them on-chain
+    ///
+    /// Passports are signed with the first publisher key in the keystore. With a DKG
+    /// endpoint configured they are also published as Knowledge Assets; a failed
+    /// publish only drops the UAL, the passport is still anchored.
+    pub fn issue_passports(now: BlockNumberFor<T>) {
+        let signer = Signer::<T, T::AuthorityId>::any_account();
+        if !signer.can_sign() {
+            return;
+        }
+        let endpoint = DKGEndpoint::<T>::get();
+
+        let requested = PassportRequests::<T>::iter().take(T::MaxPublishesPerBlock::get() as usize);
+        for (who, requested_at) in requested {
+            if !Self::mark_in_flight(b"passport", &who, now) {
+                continue;
+            }
+
+            let Some(passport) = Self::build_passport(&who, now) else {
+                log::warn!(target: LOG_TARGET, "Failed to sign reputation passport");
+                continue;
+            };
+            let Ok(credential) = BoundedVec::<u8, ConstU32<MAX_PASSPORT_LEN>>::try_from(passport) else {
+                log::warn!(target: LOG_TARGET, "Reputation passport exceeds {} bytes", MAX_PASSPORT_LEN);
+                continue;
+            };
+
+            let ual = if endpoint.is_empty() {
+                None
+            } else {
+                match Self::send_publish(&endpoint, &credential) {
+                    Ok(published) => Ual::try_from(published.ual).ok(),
+                    Err(e) => {
+                        log::warn!(target: LOG_TARGET, "Passport publish failed: {:?}", e);
+                        None
+                    }
+                }
+            };
+
+            let result = signer.send_unsigned_transaction(
+                |account| PassportPayload {
+                    public: account.public.clone(),
+                    account: who.clone(),
+                    credential: credential.clone(),
+                    ual: ual.clone(),
+                    requested_at,
+                },
+                |payload, signature| Call::anchor_passport_unsigned { payload, signature },
+            );
+            if !matches!(result, Some((_, Ok(())))) {
+                log::warn!(target: LOG_TARGET, "Failed to submit reputation passport");
+            }
+        }
+    }
+
+    /// Serialize and sign a contributor's passport with the first local publisher key
+    ///
+    /// Carries the reputation snapshot (score and verified contribution digests), the
+    /// attested GitHub handle and the reputation asset's UAL.
+    pub fn build_passport(who: &T::AccountId, now: BlockNumberFor<T>) -> Option<Vec<u8>> {
+        let key = <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::RuntimeAppPublic::all()
+            .into_iter()
+            .next()?;
+
+        let mut builder = PassportBuilder::new(&who.encode(), &key.to_raw_vec())
+            .snapshot(T::Snapshot::reputation_snapshot(who))
+            .issued_at(now.saturated_into(), sp_io::offchain::timestamp().unix_millis());
+        if let Some(handle) = GithubHandles::<T>::get(who) {
+            builder = builder.github_handle(handle.into_inner());
+        }
+        if let Some(ual) = DeveloperUAL::<T>::get(who) {
+            builder = builder.reputation_ual(ual.into_inner());
+        }
+
+        let signature = key.sign(&builder.build())?;
+        Some(builder.build_signed(&signature.encode()))
+    }
+
+    /// Off-chain worker: publish pending contributor DID documents and confirm them on-chain
+    fn publish_did_documents(
+        now: BlockNumberFor<T>,
+        endpoint: &[u8],
+        signer: &Signer<T, T::AuthorityId, ForAny>,
+    ) {
+        let pending = PendingDidDocuments::<T>::iter_keys().take(T::MaxPublishesPerBlock::get() as usize);
+        for who in pending {
+            if !Self::mark_in_flight(b"did", &who, now) {
+                continue;
+            }
+
+            let document = Self::build_did_document(&who);
+            let published = match Self::send_publish(endpoint, &document) {
+                Ok(published) => published,
+                Err(e) => {
+                    // Retried once the in-flight window passes
+                    log::warn!(target: LOG_TARGET, "DID document publish failed: {:?}", e);
+                    continue;
+                }
+            };
+
+            let result = signer.send_signed_transaction(|_| Call::confirm_did_document {
+                account: who.clone(),
+                ual: published.ual.clone(),
+            });
+            if !matches!(result, Some((_, Ok(())))) {
+                log::warn!(target: LOG_TARGET, "Failed to submit DID document confirmation");
+            }
+        }
+    }
+
+    /// Record in local storage that `who`'s `kind` of asset is being published;
+    /// false if already in flight
+    fn mark_in_flight(kind: &[u8], who: &T::AccountId, now: BlockNumberFor<T>) -> bool {
+        let mut key = b"dotrep::dkg::in-flight::".to_vec();
+        key.extend_from_slice(kind);
+        key.extend_from_slice(b"::");
+        key.extend(who.encode());
+
+        StorageValueRef::persistent(&key)
+            .mutate(|previous: Result<Option<BlockNumberFor<T>>, _>| match previous {
+                Ok(Some(at)) if now < at.saturating_add(IN_FLIGHT_BLOCKS.into()) => Err(()),
+                _ => Ok(now),
+            })
+            .is_ok()
+    }
+
+    /// Serialize a queued reputation snapshot as a JSON-LD Knowledge Asset assertion
+    ///
+    /// The queued score is published as-is; digests come from `T::Snapshot`.
+    /// If the developer already has an asset, the assertion is its next version.
+    pub fn build_assertion(who: &T::AccountId, score: u32, queued_at: BlockNumberFor<T>) -> Vec<u8> {
+        let mut builder = AssertionBuilder::new(&who.encode())
+            .snapshot(T::Snapshot::reputation_snapshot(who))
+            .score(score.saturated_into())
+            .published_at(queued_at.saturated_into());
+        if let Some((ual, version)) = Self::latest_asset(who) {
+            builder = builder.version_of(version.saturating_add(1), ual);
+        }
+
+        match Self::member_paranet_ual(who) {
+            Some(paranet) => builder.paranet(paranet).build(),
+            None => builder.build(),
+        }
+    }
+
+    /// Serialize a contributor's DID document from their attested handle and reputation UAL
+    ///
+    /// The account's SCALE encoding doubles as its sr25519 public key, which holds
+    /// for the runtime's `AccountId32`.
+    pub fn build_did_document(who: &T::AccountId) -> Vec<u8> {
+        let account = who.encode();
+        let mut builder = DidDocumentBuilder::new(&account, &account);
+        if let Some(handle) = GithubHandles::<T>::get(who) {
+            builder = builder.github_handle(handle.into_inner());
+        }
+        if let Some(ual) = DeveloperUAL::<T>::get(who) {
+            builder = builder.reputation_ual(ual.into_inner());
+        }
+        builder.build()
+    }
+
+    /// POST an assertion to `{endpoint}/publish`, retrying transient failures up to
+    /// `MAX_PUBLISH_ATTEMPTS` times
+    fn send_publish(endpoint: &[u8], assertion: &[u8]) -> Result<PublishedAsset, PublishError> {
+        Self::post_assertion(endpoint, b"/publish", assertion)
+    }
+
+    /// POST a new version of the asset at `ual` to `{endpoint}/update`
+    fn send_update(endpoint: &[u8], ual: &[u8], assertion: &[u8]) -> Result<PublishedAsset, PublishError> {
+        let mut body = b"{\"UAL\":\"".to_vec();
+        body.extend_from_slice(ual);
+        body.extend_from_slice(b"\",\"assertion\":");
+        body.extend_from_slice(assertion);
+        body.push(b'}');
+        Self::post_assertion(endpoint, b"/update", &body)
+    }
+
+    fn post_assertion(endpoint: &[u8], path: &[u8], body: &[u8]) -> Result<PublishedAsset, PublishError> {
+        let mut url = endpoint.to_vec();
+        url.extend_from_slice(path);
+        let url = sp_std::str::from_utf8(&url).map_err(|_| PublishError::InvalidEndpoint)?;
+
+        let mut last_error = PublishError::Http(http::Error::Unknown);
+        for attempt in 1..=MAX_PUBLISH_ATTEMPTS {
+            let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(PUBLISH_TIMEOUT_MS));
+            let pending = match http::Request::post(url, vec![body])
+                .add_header("Content-Type", "application/json")
+                .deadline(deadline)
+                .send()
+            {
+                Ok(pending) => pending,
+                Err(_) => {
+                    last_error = PublishError::Http(http::Error::IoError);
+                    continue;
+                }
+            };
+
+            last_error = match pending.try_wait(deadline) {
+                Ok(Ok(response)) if response.code == 200 => {
+                    let body = response.body().collect::<Vec<u8>>();
+                    match (json_string_field(&body, b"UAL"), json_string_field(&body, b"assertionId")) {
+                        (Some(ual), Some(assertion_id)) => {
+                            return Ok(PublishedAsset { ual, assertion_id })
+                        }
+                        _ => PublishError::MalformedResponse,
+                    }
+                }
+                Ok(Ok(response)) => PublishError::Status(response.code),
+                Ok(Err(e)) => PublishError::Http(e),
+                Err(_) => PublishError::Http(http::Error::DeadlineReached),
+            };
+            log::debug!(target: LOG_TARGET, "Publish attempt {} failed: {:?}", attempt, last_error);
+            if !last_error.classify().is_transient() {
+                break;
+            }
+        }
+
+        Err(last_error)
+    }
+}
+
+/// Extract the string value of `"key"` from a flat JSON object (escapes are kept verbatim)
+fn json_string_field(json: &[u8], key: &[u8]) -> Option<Vec<u8>> {
+    let mut needle = vec![b'"'];
+    needle.extend_from_slice(key);
+    needle.push(b'"');
+
+    let start = json.windows(needle.len()).position(|window| window == &needle[..])? + needle.len();
+    let mut rest = json[start..].iter().skip_while(|b| b.is_ascii_whitespace());
+    if rest.next() != Some(&b':') {
+        return None;
+    }
+    let mut rest = rest.skip_while(|b| b.is_ascii_whitespace());
+    if rest.next() != Some(&b'"') {
+        return None;
+    }
+
+    let mut value = Vec::new();
+    let mut escaped = false;
+    for &b in rest {
+        match b {
+            b'"' if !escaped => return Some(value),
+            b'\\' if !escaped => escaped = true,
+            _ => escaped = false,
+        }
+        value.push(b);
+    }
+    None
+}
+
+/// Accounts with a publisher-attested GitHub handle are bound
+impl<T: Config> AccountBindings<T::AccountId> for Pallet<T> {
+    fn is_bound(who: &T::AccountId) -> bool {
+        GithubHandles::<T>::contains_key(who)
+    }
+}
+
+/// Implementation of DKGIntegration trait for the reputation pallet
+impl<T: Config> DKGIntegration<T> for Pallet<T> {
+    fn publish_to_dkg(who: &T::AccountId, reputation_score: u32) -> DispatchResult {
+        // Queue for publishing by off-chain worker
+        Self::enqueue(who, reputation_score);
+        Ok(())
+    }
+
+    fn store_ual_mapping(who: &T::AccountId, ual: Vec<u8>) -> DispatchResult {
+        let bounded_ual: Ual = ual.clone()
+            .try_into()
+            .map_err(|_| Error::<T>::InvalidUAL)?;
+
+        // Republishing the same UAL records a new version of the asset
+        let version = match DeveloperUAL::<T>::get(who) {
+            Some(previous) if previous == bounded_ual => AssetVersions::<T>::get(who).saturating_add(1),
+            _ => 1,
+        };
+        DeveloperUAL::<T>::insert(who, bounded_ual);
+        AssetVersions::<T>::insert(who, version);
+        Self::queue_did_document(who);
+        Self::deposit_event(Event::AssetVersionUpdated { who: who.clone(), ual: ual.clone(), version });
+
+        Self::deposit_event(Event::UALStored { 
+            who: who.clone(), 
+            ual 
+        });
+
+        Ok(())
+    }
+
+    fn get_ual(who: &T::AccountId) -> Option<Vec<u8>> {
+        Self::get_developer_ual(who)
+    }
+
+    fn store_contribution_ual(contribution_id: ContributionId, ual: Vec<u8>) -> DispatchResult {
+        ensure!(!ual.is_empty(), Error::<T>::InvalidUAL);
+        let bounded_ual: Ual = ual.clone()
+            .try_into()
+            .map_err(|_| Error::<T>::InvalidUAL)?;
+
+        ContributionUAL::<T>::insert(contribution_id, bounded_ual);
+
+        Self::deposit_event(Event::ContributionUALStored { contribution_id, ual });
+
+        Ok(())
+    }
+
+    fn get_contribution_ual(contribution_id: ContributionId) -> Option<Vec<u8>> {
+        Pallet::<T>::get_contribution_ual(contribution_id)
+    }
+
+    fn verify_dkg_proof(ual: Vec<u8>, proof: Vec<u8>) -> bool {
+        // TODO: Implement cryptographic verification of DKG proofs
+        // This would typically involve:
+        // 1. Parsing the proof structure
+        // 2. Verifying signatures against known DKG node public keys
+        // 3. Checking Merkle proofs for data integrity
+        // 4. Validating blockchain anchors on NeuroWeb
+        
+        // For now, basic validation
+        !ual.is_empty() && !proof.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::*;
+    use codec::Decode;
+    use dotrep_primitives::Score;
+    use frame_support::{assert_noop, assert_ok};
+    use sp_core::offchain::{
+        testing::{PendingRequest, TestOffchainExt, TestTransactionPoolExt},
+        OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+    };
+    use sp_runtime::testing::UintAuthorityId;
+
+    #[test]
+    fn test_store_ual() {
+        new_test_ext().execute_with(|| {
+            let developer = 1;
+            let ual = b"did:dkg:otp/2043/0x1234...".to_vec();
+
+            assert_ok!(DKGPallet::store_ual(
                 RuntimeOrigin::signed(developer),
                 ual.clone()
             ));
@@ -282,18 +1768,697 @@ mod tests {
         });
     }
 
+    // Give `who` a reputation score and queue it for publishing
+    fn queue(who: u64, score: Score) {
+        crate::ReputationScores::<Test>::insert(who, score);
+        assert_ok!(DKGPallet::queue_for_publishing(RuntimeOrigin::root(), who));
+    }
+
+    fn report_failure(who: u64, failure: HttpFailure) -> DispatchResult {
+        DKGPallet::report_publish_failure(RuntimeOrigin::signed(DKG_PUBLISHER), who, failure)
+    }
+
     #[test]
     fn test_queue_for_publishing() {
         new_test_ext().execute_with(|| {
             let developer = 1;
-            let score = 850;
+            crate::ReputationScores::<Test>::insert(developer, 850);
 
-            assert_ok!(DKGPallet::queue_for_publishing(
-                RuntimeOrigin::signed(developer),
-                score
+            assert_noop!(
+                DKGPallet::queue_for_publishing(RuntimeOrigin::signed(developer), developer),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            assert_ok!(DKGPallet::queue_for_publishing(RuntimeOrigin::root(), developer));
+
+            assert_eq!(DKGPallet::get_queue_item(&developer), Some((850, 0)));
+        });
+    }
+
+    #[test]
+    fn test_queue_publishes_real_score() {
+        new_test_ext().execute_with(|| {
+            let developer = 1;
+
+            queue(developer, -30);
+            assert_eq!(DKGPallet::get_queue_item(&developer).map(|(score, _)| score), Some(0));
+
+            // The reputation pallet's own hook queues the score it passes
+            assert_ok!(<DKGPallet as DKGIntegration<Test>>::publish_to_dkg(&developer, 420));
+            assert_eq!(DKGPallet::get_queue_item(&developer).map(|(score, _)| score), Some(420));
+        });
+    }
+
+    #[test]
+    fn test_confirm_publish_requires_publisher() {
+        new_test_ext().execute_with(|| {
+            let developer = 1;
+            let ual = b"did:dkg:otp/2043/0xabc/1".to_vec();
+
+            assert_noop!(
+                DKGPallet::confirm_publish(RuntimeOrigin::signed(DKG_PUBLISHER), developer, ual.clone(), b"0x01".to_vec()),
+                Error::<Test>::NotQueued
+            );
+
+            queue(developer, 850);
+            assert_noop!(
+                DKGPallet::confirm_publish(RuntimeOrigin::signed(developer), developer, ual.clone(), b"0x01".to_vec()),
+                sp_runtime::DispatchError::BadOrigin
+            );
+
+            let root = DKGPallet::assertion_root(&DKGPallet::build_assertion(&developer, 850, 0));
+            assert_ok!(DKGPallet::confirm_publish(
+                RuntimeOrigin::signed(DKG_PUBLISHER),
+                developer,
+                ual.clone(),
+                b"0x01".to_vec()
+            ));
+            assert_eq!(DKGPallet::get_developer_ual(&developer), Some(ual.clone()));
+            assert_eq!(DKGPallet::published_assertion(developer).unwrap().to_vec(), b"0x01".to_vec());
+
+            // The root of the intended assertion is anchored and verifiable
+            assert!(DKGPallet::verify_anchored(&ual, root));
+            assert!(!DKGPallet::verify_anchored(&ual, H256::repeat_byte(1)));
+            assert!(!DKGPallet::verify_anchored(b"did:dkg:otp/2043/0xabc/2", root));
+            assert!(DKGPallet::get_queue_item(&developer).is_none());
+        });
+    }
+
+    #[test]
+    fn test_offchain_worker_publishes_and_confirms() {
+        let (offchain, offchain_state) = TestOffchainExt::new();
+        let (pool, pool_state) = TestTransactionPoolExt::new();
+        let mut ext = new_test_ext();
+        ext.register_extension(OffchainDbExt::new(offchain.clone()));
+        ext.register_extension(OffchainWorkerExt::new(offchain));
+        ext.register_extension(TransactionPoolExt::new(pool));
+        UintAuthorityId::set_all_keys(vec![DKG_PUBLISHER]);
+
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            let developer = 1;
+            assert_ok!(DKGPallet::set_dkg_endpoint(RuntimeOrigin::root(), b"http://localhost:8900".to_vec()));
+            queue(developer, 850);
+
+            offchain_state.write().expect_request(PendingRequest {
+                method: "POST".into(),
+                uri: "http://localhost:8900/publish".into(),
+                headers: vec![("Content-Type".into(), "application/json".into())],
+                body: DKGPallet::build_assertion(&developer, 850, 1),
+                response: Some(br#"{"UAL": "did:dkg:otp/2043/0xabc/1", "assertionId": "0x01"}"#.to_vec()),
+                sent: true,
+                ..Default::default()
+            });
+
+            DKGPallet::publish_queued(1);
+
+            let tx = pool_state.write().transactions.pop().expect("confirmation submitted");
+            let tx = Extrinsic::decode(&mut &*tx).unwrap();
+            assert_eq!(tx.signature.unwrap().0, DKG_PUBLISHER);
+            assert_eq!(
+                tx.call,
+                RuntimeCall::DKGPallet(Call::confirm_publish {
+                    account: developer,
+                    ual: b"did:dkg:otp/2043/0xabc/1".to_vec(),
+                    assertion_id: b"0x01".to_vec(),
+                })
+            );
+            let tx = pool_state.write().transactions.pop().expect("submission marked");
+            let tx = Extrinsic::decode(&mut &*tx).unwrap();
+            assert_eq!(
+                tx.call,
+                RuntimeCall::DKGPallet(Call::mark_publish_submitted { account: developer })
+            );
+
+            // The in-flight item is not published again while its confirmation is pending
+            DKGPallet::publish_queued(2);
+            assert!(pool_state.read().transactions.is_empty());
+        });
+    }
+
+    #[test]
+    fn test_contribution_ual_mapping() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let ual = b"did:dkg:otp/2043/0xabc/42".to_vec();
+
+            assert_noop!(
+                DKGPallet::confirm_contribution_publish(RuntimeOrigin::signed(1), 7, ual.clone()),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            assert_noop!(
+                DKGPallet::confirm_contribution_publish(RuntimeOrigin::signed(DKG_PUBLISHER), 7, vec![]),
+                Error::<Test>::InvalidUAL
+            );
+
+            assert_ok!(DKGPallet::confirm_contribution_publish(
+                RuntimeOrigin::signed(DKG_PUBLISHER),
+                7,
+                ual.clone()
+            ));
+            assert_eq!(DKGPallet::get_contribution_ual(7), Some(ual.clone()));
+            assert_eq!(<DKGPallet as DKGIntegration<Test>>::get_contribution_ual(8), None);
+            System::assert_last_event(RuntimeEvent::DKGPallet(Event::ContributionUALStored {
+                contribution_id: 7,
+                ual,
+            }));
+        });
+    }
+
+    #[test]
+    fn test_failed_publishes_back_off_then_dead_letter() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(10);
+            let developer = 1;
+            queue(developer, 850);
+
+            // Backoff doubles: 2 blocks after the first failure, 4 after the second
+            assert_ok!(report_failure(developer, HttpFailure::ServerError));
+            assert_eq!(DKGPallet::publish_retry(developer), Some(PublishRetry { attempts: 1, next_retry: 12 }));
+            assert_ok!(report_failure(developer, HttpFailure::ServerError));
+            assert_eq!(DKGPallet::publish_retry(developer), Some(PublishRetry { attempts: 2, next_retry: 14 }));
+
+            // MaxPublishAttempts reached: dead-lettered and dropped from the queue
+            assert_ok!(report_failure(developer, HttpFailure::ServerError));
+            System::assert_last_event(RuntimeEvent::DKGPallet(Event::PublishingFailed {
+                who: developer,
+                attempts: 3,
+                failure: HttpFailure::ServerError,
+            }));
+            assert!(DKGPallet::get_queue_item(&developer).is_none());
+            assert!(DKGPallet::publish_retry(developer).is_none());
+            assert_eq!(DKGPallet::dead_letter(developer), Some((850, 10, 3)));
+            assert_noop!(
+                report_failure(developer, HttpFailure::ServerError),
+                Error::<Test>::NotQueued
+            );
+
+            // Operators can requeue with a fresh attempt budget
+            assert_noop!(
+                DKGPallet::requeue_dead_letter(RuntimeOrigin::signed(developer), developer),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            assert_ok!(DKGPallet::requeue_dead_letter(RuntimeOrigin::root(), developer));
+            assert_eq!(DKGPallet::get_queue_item(&developer), Some((850, 10)));
+            assert!(DKGPallet::dead_letter(developer).is_none());
+        });
+    }
+
+    #[test]
+    fn test_publish_error_classification() {
+        let classify = |e: PublishError| e.classify();
+        assert_eq!(classify(PublishError::Http(http::Error::DeadlineReached)), HttpFailure::Timeout);
+        assert_eq!(classify(PublishError::Http(http::Error::IoError)), HttpFailure::Connection);
+        assert_eq!(classify(PublishError::Status(401)), HttpFailure::Unauthorized);
+        assert_eq!(classify(PublishError::Status(403)), HttpFailure::Unauthorized);
+        assert_eq!(classify(PublishError::Status(404)), HttpFailure::EndpointNotFound);
+        assert_eq!(classify(PublishError::Status(405)), HttpFailure::EndpointNotFound);
+        assert_eq!(classify(PublishError::Status(422)), HttpFailure::ClientError);
+        assert_eq!(classify(PublishError::Status(429)), HttpFailure::RateLimited);
+        assert_eq!(classify(PublishError::Status(503)), HttpFailure::ServerError);
+        assert_eq!(classify(PublishError::Status(302)), HttpFailure::Parse);
+        assert_eq!(classify(PublishError::MalformedResponse), HttpFailure::Parse);
+        assert_eq!(classify(PublishError::InvalidEndpoint), HttpFailure::InvalidEndpoint);
+    }
+
+    #[test]
+    fn test_failure_class_drives_retries_and_stats() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(10);
+            let developer = 1;
+            queue(developer, 850);
+
+            // A bad token spends no attempt and waits out the longest backoff
+            assert_ok!(report_failure(developer, HttpFailure::Unauthorized));
+            assert_ok!(report_failure(developer, HttpFailure::Unauthorized));
+            assert_ok!(report_failure(developer, HttpFailure::Unauthorized));
+            assert_eq!(DKGPallet::publish_retry(developer), Some(PublishRetry { attempts: 0, next_retry: 18 }));
+            assert_eq!(DKGPallet::publish_status(developer), Some(PublishStatus::Queued));
+
+            // So does a wrong endpoint path
+            assert_ok!(report_failure(developer, HttpFailure::EndpointNotFound));
+            assert_eq!(DKGPallet::publish_retry(developer), Some(PublishRetry { attempts: 0, next_retry: 18 }));
+
+            // Outages still spend attempts
+            System::set_block_number(20);
+            assert_ok!(report_failure(developer, HttpFailure::RateLimited));
+            assert_eq!(DKGPallet::publish_retry(developer), Some(PublishRetry { attempts: 1, next_retry: 22 }));
+
+            // An unparsable response may be specific to the item, so it spends one too
+            assert_ok!(report_failure(developer, HttpFailure::Parse));
+            assert_eq!(DKGPallet::publish_retry(developer), Some(PublishRetry { attempts: 2, next_retry: 24 }));
+
+            // A request the node rejects is not retried
+            assert_ok!(report_failure(developer, HttpFailure::ClientError));
+            System::assert_last_event(RuntimeEvent::DKGPallet(Event::PublishingFailed {
+                who: developer,
+                attempts: 3,
+                failure: HttpFailure::ClientError,
+            }));
+            assert_eq!(DKGPallet::dead_letter(developer), Some((850, 10, 3)));
+
+            assert_eq!(DKGPallet::ocw_stats(HttpFailure::Unauthorized), OcwFailureStats { count: 3, last_at: 10 });
+            assert_eq!(DKGPallet::ocw_stats(HttpFailure::RateLimited), OcwFailureStats { count: 1, last_at: 20 });
+            assert_eq!(DKGPallet::ocw_stats(HttpFailure::ClientError), OcwFailureStats { count: 1, last_at: 20 });
+            assert_eq!(DKGPallet::ocw_stats(HttpFailure::Timeout), OcwFailureStats::default());
+        });
+    }
+
+    #[test]
+    fn test_offchain_worker_reports_failures_and_respects_backoff() {
+        let (offchain, offchain_state) = TestOffchainExt::new();
+        let (pool, pool_state) = TestTransactionPoolExt::new();
+        let mut ext = new_test_ext();
+        ext.register_extension(OffchainDbExt::new(offchain.clone()));
+        ext.register_extension(OffchainWorkerExt::new(offchain));
+        ext.register_extension(TransactionPoolExt::new(pool));
+        UintAuthorityId::set_all_keys(vec![DKG_PUBLISHER]);
+
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            let developer = 1;
+            assert_ok!(DKGPallet::set_dkg_endpoint(RuntimeOrigin::root(), b"http://localhost:8900".to_vec()));
+            queue(developer, 850);
+
+            // A response without a UAL is not retried within the run
+            offchain_state.write().expect_request(PendingRequest {
+                method: "POST".into(),
+                uri: "http://localhost:8900/publish".into(),
+                headers: vec![("Content-Type".into(), "application/json".into())],
+                body: DKGPallet::build_assertion(&developer, 850, 1),
+                response: Some(br#"{"status": "FAILED"}"#.to_vec()),
+                sent: true,
+                ..Default::default()
+            });
+
+            DKGPallet::publish_queued(1);
+
+            let tx = pool_state.write().transactions.pop().expect("failure reported");
+            let tx = Extrinsic::decode(&mut &*tx).unwrap();
+            assert_eq!(
+                tx.call,
+                RuntimeCall::DKGPallet(Call::report_publish_failure {
+                    account: developer,
+                    failure: HttpFailure::Parse,
+                })
+            );
+            pool_state.write().transactions.clear();
+
+            // While backing off, the item is not published even once the in-flight guard expires
+            assert_ok!(report_failure(developer, HttpFailure::ServerError));
+            PublishRetries::<Test>::insert(developer, PublishRetry { attempts: 1, next_retry: 100 });
+            DKGPallet::publish_queued(50);
+            assert!(pool_state.read().transactions.is_empty());
+        });
+    }
+
+    #[test]
+    fn test_paranet_membership() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let paranet_ual = b"did:dkg:otp/2043/0xparanet/1".to_vec();
+            let incentives = ParanetIncentives {
+                reward_per_asset: 1_000,
+                operator_share: sp_runtime::Perbill::from_percent(10),
+            };
+
+            assert_noop!(DKGPallet::join_paranet(RuntimeOrigin::signed(1)), Error::<Test>::NoParanet);
+            assert_noop!(
+                DKGPallet::register_paranet(
+                    RuntimeOrigin::signed(1),
+                    paranet_ual.clone(),
+                    ParanetPolicy::Open,
+                    incentives.clone()
+                ),
+                sp_runtime::DispatchError::BadOrigin
+            );
+
+            assert_ok!(DKGPallet::register_paranet(
+                RuntimeOrigin::root(),
+                paranet_ual.clone(),
+                ParanetPolicy::Permissioned,
+                incentives.clone()
+            ));
+            assert_noop!(DKGPallet::join_paranet(RuntimeOrigin::signed(1)), Error::<Test>::ParanetPermissioned);
+            assert_ok!(DKGPallet::add_paranet_member(RuntimeOrigin::root(), 1));
+            assert_eq!(DKGPallet::paranet_member(1), Some(1));
+
+            assert_ok!(DKGPallet::register_paranet(
+                RuntimeOrigin::root(),
+                paranet_ual.clone(),
+                ParanetPolicy::Open,
+                incentives
+            ));
+            assert_ok!(DKGPallet::join_paranet(RuntimeOrigin::signed(2)));
+            assert_noop!(DKGPallet::join_paranet(RuntimeOrigin::signed(2)), Error::<Test>::AlreadyParanetMember);
+
+            // Members' assertions are grouped under the Paranet
+            assert_eq!(DKGPallet::member_paranet_ual(&2), Some(paranet_ual));
+            assert!(DKGPallet::member_paranet_ual(&3).is_none());
+
+            assert_ok!(DKGPallet::leave_paranet(RuntimeOrigin::signed(2)));
+            assert_noop!(DKGPallet::leave_paranet(RuntimeOrigin::signed(2)), Error::<Test>::NotParanetMember);
+            assert!(DKGPallet::member_paranet_ual(&2).is_none());
+        });
+    }
+
+    #[test]
+    fn test_publish_status_transitions() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let developer = 1;
+            let status_event = |status| {
+                RuntimeEvent::DKGPallet(Event::PublishStatusChanged { who: developer, status })
+            };
+
+            queue(developer, 850);
+            assert_eq!(DKGPallet::publish_status(developer), Some(PublishStatus::Queued));
+            System::assert_has_event(status_event(PublishStatus::Queued));
+
+            assert_noop!(
+                DKGPallet::mark_publish_submitted(RuntimeOrigin::signed(developer), developer),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            assert_ok!(DKGPallet::mark_publish_submitted(RuntimeOrigin::signed(DKG_PUBLISHER), developer));
+            assert_eq!(DKGPallet::publish_status(developer), Some(PublishStatus::Submitted));
+            System::assert_last_event(status_event(PublishStatus::Submitted));
+
+            // A retryable failure puts the item back in the queue
+            assert_ok!(report_failure(developer, HttpFailure::ServerError));
+            assert_eq!(DKGPallet::publish_status(developer), Some(PublishStatus::Queued));
+
+            assert_ok!(DKGPallet::mark_publish_submitted(RuntimeOrigin::signed(DKG_PUBLISHER), developer));
+            assert_ok!(DKGPallet::confirm_publish(
+                RuntimeOrigin::signed(DKG_PUBLISHER),
+                developer,
+                b"did:dkg:otp/2043/0xabc/1".to_vec(),
+                b"0x01".to_vec()
+            ));
+            assert_eq!(DKGPallet::publish_status(developer), Some(PublishStatus::Confirmed));
+            System::assert_has_event(status_event(PublishStatus::Confirmed));
+            assert_noop!(
+                DKGPallet::mark_publish_submitted(RuntimeOrigin::signed(DKG_PUBLISHER), developer),
+                Error::<Test>::NotQueued
+            );
+
+            // Exhausted attempts end in Failed
+            queue(developer, 900);
+            for _ in 0..3 {
+                assert_ok!(report_failure(developer, HttpFailure::ServerError));
+            }
+            assert_eq!(DKGPallet::publish_status(developer), Some(PublishStatus::Failed));
+        });
+    }
+
+    #[test]
+    fn test_ack_published_dequeues_and_stores_ual() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let developer = 1;
+            let ual = b"did:dkg:otp/2043/0xabc/1".to_vec();
+
+            assert_noop!(
+                DKGPallet::ack_published(RuntimeOrigin::signed(DKG_PUBLISHER), developer, ual.clone()),
+                Error::<Test>::NotQueued
+            );
+            queue(developer, 850);
+            assert_noop!(
+                DKGPallet::ack_published(RuntimeOrigin::signed(developer), developer, ual.clone()),
+                sp_runtime::DispatchError::BadOrigin
+            );
+
+            assert_ok!(DKGPallet::ack_published(RuntimeOrigin::signed(DKG_PUBLISHER), developer, ual.clone()));
+            assert_eq!(DKGPallet::get_queue_item(&developer), None);
+            assert_eq!(DKGPallet::get_developer_ual(&developer), Some(ual.clone()));
+            assert_eq!(DKGPallet::publish_status(developer), Some(PublishStatus::Confirmed));
+            System::assert_last_event(RuntimeEvent::DKGPallet(Event::PublishAcknowledged { who: developer, ual }));
+        });
+    }
+
+    #[test]
+    fn test_unsigned_ack_requires_publisher_signature() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let developer = 1;
+            queue(developer, 850);
+
+            let payload = |signer: u64| AckPayload {
+                public: UintAuthorityId(signer),
+                account: developer,
+                ual: b"did:dkg:otp/2043/0xabc/1".to_vec().try_into().unwrap(),
+                queued_at: 1,
+            };
+            let call = |signer: u64, signed_by: u64| Call::ack_published_unsigned {
+                payload: payload(signer),
+                signature: sp_runtime::testing::TestSignature(signed_by, payload(signer).encode()),
+            };
+            let validate = |call: &Call<Test>| DKGPallet::validate_unsigned(TransactionSource::External, call);
+
+            assert_eq!(validate(&call(DKG_PUBLISHER, 7)), InvalidTransaction::BadProof.into());
+            assert_eq!(validate(&call(7, 7)), InvalidTransaction::BadSigner.into());
+            assert!(validate(&call(DKG_PUBLISHER, DKG_PUBLISHER)).is_ok());
+
+            let Call::ack_published_unsigned { payload, signature } = call(DKG_PUBLISHER, DKG_PUBLISHER) else {
+                unreachable!()
+            };
+            assert_ok!(DKGPallet::ack_published_unsigned(RuntimeOrigin::none(), payload, signature));
+            assert_eq!(DKGPallet::get_queue_item(&developer), None);
+            assert_eq!(
+                validate(&call(DKG_PUBLISHER, DKG_PUBLISHER)),
+                InvalidTransaction::Stale.into()
+            );
+
+            // The acknowledgement cannot be replayed against a later queue entry
+            System::set_block_number(2);
+            queue(developer, 900);
+            assert_eq!(
+                validate(&call(DKG_PUBLISHER, DKG_PUBLISHER)),
+                InvalidTransaction::Stale.into()
+            );
+            let Call::ack_published_unsigned { payload, signature } = call(DKG_PUBLISHER, DKG_PUBLISHER) else {
+                unreachable!()
+            };
+            assert_noop!(
+                DKGPallet::ack_published_unsigned(RuntimeOrigin::none(), payload, signature),
+                Error::<Test>::StalePayload
+            );
+        });
+    }
+
+    #[test]
+    fn test_offchain_worker_issues_and_anchors_passport() {
+        let (offchain, _) = TestOffchainExt::new();
+        let (pool, pool_state) = TestTransactionPoolExt::new();
+        let mut ext = new_test_ext();
+        ext.register_extension(OffchainDbExt::new(offchain.clone()));
+        ext.register_extension(OffchainWorkerExt::new(offchain));
+        ext.register_extension(TransactionPoolExt::new(pool));
+        UintAuthorityId::set_all_keys(vec![DKG_PUBLISHER]);
+
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            let developer = 1;
+            crate::ReputationScores::<Test>::insert(developer, 420);
+            assert_ok!(DKGPallet::attest_github_handle(
+                RuntimeOrigin::signed(DKG_PUBLISHER),
+                developer,
+                b"octocat".to_vec()
+            ));
+
+            assert_noop!(
+                DKGPallet::anchor_passport_unsigned(
+                    RuntimeOrigin::none(),
+                    PassportPayload {
+                        public: UintAuthorityId(DKG_PUBLISHER),
+                        account: developer,
+                        credential: Default::default(),
+                        ual: None,
+                        requested_at: 1,
+                    },
+                    sp_runtime::testing::TestSignature(DKG_PUBLISHER, Vec::new()),
+                ),
+                Error::<Test>::NoPassportRequest
+            );
+            assert_ok!(DKGPallet::request_passport(RuntimeOrigin::signed(developer)));
+            assert_noop!(
+                DKGPallet::request_passport(RuntimeOrigin::signed(developer)),
+                Error::<Test>::PassportAlreadyRequested
+            );
+
+            // Without a DKG endpoint the passport is only signed and anchored
+            DKGPallet::issue_passports(1);
+            let tx = pool_state.write().transactions.pop().expect("passport submitted");
+            let tx = Extrinsic::decode(&mut &*tx).unwrap();
+            assert!(tx.signature.is_none());
+            let RuntimeCall::DKGPallet(call) = tx.call else { unreachable!() };
+            assert!(DKGPallet::validate_unsigned(TransactionSource::Local, &call).is_ok());
+            let Call::anchor_passport_unsigned { payload, signature } = call else { unreachable!() };
+            assert_eq!(payload.requested_at, 1);
+
+            let credential = payload.credential.to_vec();
+            let contains = |needle: &[u8]| credential.windows(needle.len()).any(|window| window == needle);
+            assert!(contains(br#""type":["VerifiableCredential","dkg:ReputationPassport"]"#));
+            assert!(contains(br#""reputationScore":420"#));
+            assert!(contains(b"https://github.com/octocat"));
+            assert!(contains(br#""proof":{"type":"Sr25519Signature2020""#));
+
+            assert_ok!(DKGPallet::anchor_passport_unsigned(RuntimeOrigin::none(), payload.clone(), signature.clone()));
+            let hash = DKGPallet::assertion_root(&credential);
+            assert_eq!(DKGPallet::passport(developer), Some(PassportAnchor { hash, issued_at: 1, ual: None }));
+            System::assert_last_event(RuntimeEvent::DKGPallet(Event::PassportIssued {
+                who: developer,
+                hash,
+                ual: None,
+            }));
+
+            // The request is served; another passport can be requested
+            DKGPallet::issue_passports(2);
+            assert!(pool_state.read().transactions.is_empty());
+            System::set_block_number(2);
+            assert_ok!(DKGPallet::request_passport(RuntimeOrigin::signed(developer)));
+
+            // The served passport cannot be replayed against the new request
+            let replay = Call::anchor_passport_unsigned { payload: payload.clone(), signature: signature.clone() };
+            assert_eq!(
+                DKGPallet::validate_unsigned(TransactionSource::External, &replay),
+                InvalidTransaction::Stale.into()
+            );
+            assert_noop!(
+                DKGPallet::anchor_passport_unsigned(RuntimeOrigin::none(), payload, signature),
+                Error::<Test>::StalePayload
+            );
+        });
+    }
+
+    #[test]
+    fn test_runtime_api_queries() {
+        new_test_ext().execute_with(|| {
+            let developer = 1;
+            let ual = |n: u8| vec![b'u', n];
+
+            for block in 1..=4u8 {
+                System::set_block_number(block as u64);
+                assert_ok!(DKGPallet::anchor(ual(block), H256::repeat_byte(block)));
+            }
+
+            let mut anchored = DKGPallet::anchored_between(2, 3, 10);
+            anchored.sort_by_key(|(_, _, block)| *block);
+            assert_eq!(
+                anchored,
+                vec![(ual(2), H256::repeat_byte(2), 2), (ual(3), H256::repeat_byte(3), 3)]
+            );
+            assert_eq!(DKGPallet::anchored_between(1, 4, 1).len(), 1);
+
+            assert_eq!(DKGPallet::dkg_assets(&developer), DkgAssets {
+                ual: None,
+                assertion_id: None,
+                anchor: None,
+                status: None,
+                paranet: None,
+            });
+
+            queue(developer, 850);
+            assert_ok!(DKGPallet::confirm_publish(
+                RuntimeOrigin::signed(DKG_PUBLISHER),
+                developer,
+                ual(9),
+                b"0x01".to_vec()
+            ));
+            let assets = DKGPallet::dkg_assets(&developer);
+            assert_eq!(assets.ual, Some(ual(9)));
+            assert_eq!(assets.assertion_id, Some(b"0x01".to_vec()));
+            assert_eq!(assets.anchor.map(|(_, block)| block), Some(4));
+            assert_eq!(assets.status, Some(PublishStatus::Confirmed));
+        });
+    }
+
+    #[test]
+    fn test_did_document_published_after_handle_and_ual() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let developer = 1;
+            let ual = b"did:dkg:otp/2043/0xabc/1".to_vec();
+            let did_ual = b"did:dkg:otp/2043/0xdid/1".to_vec();
+
+            assert_noop!(
+                DKGPallet::attest_github_handle(RuntimeOrigin::signed(developer), developer, b"octocat".to_vec()),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            assert_noop!(
+                DKGPallet::attest_github_handle(RuntimeOrigin::signed(DKG_PUBLISHER), developer, vec![b'a'; 40]),
+                Error::<Test>::InvalidHandle
+            );
+            assert_noop!(
+                DKGPallet::confirm_did_document(RuntimeOrigin::signed(DKG_PUBLISHER), developer, did_ual.clone()),
+                Error::<Test>::NoPendingDidDocument
+            );
+
+            assert_ok!(DKGPallet::attest_github_handle(
+                RuntimeOrigin::signed(DKG_PUBLISHER),
+                developer,
+                b"octocat".to_vec()
             ));
+            queue(developer, 850);
+            assert_ok!(DKGPallet::ack_published(RuntimeOrigin::signed(DKG_PUBLISHER), developer, ual.clone()));
+
+            let document = DKGPallet::build_did_document(&developer);
+            let contains = |needle: &[u8]| document.windows(needle.len()).any(|window| window == needle);
+            assert!(contains(b"https://github.com/octocat"));
+            assert!(contains(&ual));
+
+            assert_ok!(DKGPallet::confirm_did_document(
+                RuntimeOrigin::signed(DKG_PUBLISHER),
+                developer,
+                did_ual.clone()
+            ));
+            assert_eq!(DKGPallet::did_document_ual(developer).map(|ual| ual.into_inner()), Some(did_ual.clone()));
+            assert!(DKGPallet::verify_anchored(&did_ual, DKGPallet::assertion_root(&document)));
+            System::assert_last_event(RuntimeEvent::DKGPallet(Event::DidDocumentPublished {
+                who: developer,
+                ual: did_ual,
+            }));
+        });
+    }
+
+    #[test]
+    fn test_republishing_updates_asset_version() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let developer = 1;
+            let ual = b"did:dkg:otp/2043/0xabc/1".to_vec();
+            let confirm = |ual: &[u8]| {
+                DKGPallet::confirm_publish(
+                    RuntimeOrigin::signed(DKG_PUBLISHER),
+                    developer,
+                    ual.to_vec(),
+                    b"0x01".to_vec(),
+                )
+            };
+
+            queue(developer, 850);
+            let created = DKGPallet::build_assertion(&developer, 850, 1);
+            assert_ok!(confirm(&ual));
+            assert_eq!(DKGPallet::latest_asset(&developer), Some((ual.clone(), 1)));
+
+            // The next publish is version 2 of the same asset
+            queue(developer, 900);
+            let update = DKGPallet::build_assertion(&developer, 900, 1);
+            assert_ne!(created, update);
+            let reference: &[u8] = br#""dkg:version":2,"dkg:previousVersion":"did:dkg:otp/2043/0xabc/1""#;
+            assert!(update.windows(reference.len()).any(|window| window == reference));
+
+            assert_ok!(confirm(&ual));
+            assert_eq!(DKGPallet::latest_asset(&developer), Some((ual.clone(), 2)));
+            assert!(DKGPallet::verify_anchored(&ual, DKGPallet::assertion_root(&update)));
+            System::assert_has_event(RuntimeEvent::DKGPallet(Event::AssetVersionUpdated {
+                who: developer,
+                ual,
+                version: 2,
+            }));
 
-            assert!(DKGPallet::get_queue_item(&developer).is_some());
+            // A different UAL starts a fresh asset
+            queue(developer, 910);
+            assert_ok!(confirm(b"did:dkg:otp/2043/0xdef/1"));
+            assert_eq!(DKGPallet::asset_version(developer), 1);
         });
     }
 }
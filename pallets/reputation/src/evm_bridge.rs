@@ -0,0 +1,153 @@
+//! Reputation attestations for EVM chains
+//!
+//! Governance registers EVM chains by chain id together with the bridge contract that
+//! consumes attestations, and sets the address of the key the chain signs them with.
+//! `request_evm_export` queues an attestation of the caller's effective reputation; the
+//! off-chain worker signs it with the local secp256k1 key of type [`KEY_TYPE`] whose
+//! address is `EvmAttestationSigner` and submits `submit_evm_attestation`, which emits
+//! `EvmAttestationSigned` for relayers to forward.
+//!
+//! The signed message is the Solidity ABI encoding of
+//! `(uint256 chainId, address bridge, address account, int256 score, uint256 expiry, uint256 nonce)`
+//! and the signature is over its keccak-256 hash, so a bridge contract checks it with
+//! `ecrecover(keccak256(abi.encode(...)), v + 27, r, s)`.
+
+use crate::pallet::{Call, Config, EvmAttestationSigner, EvmExport, Pallet, PendingEvmExports};
+use codec::Encode;
+use frame_system::{
+    offchain::{SendSignedTransaction, Signer},
+    pallet_prelude::BlockNumberFor,
+};
+use sp_core::{crypto::KeyTypeId, ecdsa, H160};
+use sp_io::hashing::keccak_256;
+use sp_runtime::{offchain::storage::StorageValueRef, traits::Saturating};
+use sp_std::vec::Vec;
+
+const LOG_TARGET: &str = "pallet-reputation-evm";
+
+/// Key type of the secp256k1 keys attestations are signed with
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"evma");
+
+/// Exports signed per off-chain worker run
+const MAX_SIGNATURES_PER_BLOCK: usize = 5;
+
+/// Blocks before an export is signed and submitted again
+const RESUBMIT_BLOCKS: u32 = 10;
+
+/// EVM address an account's reputation is exported to: the last 20 bytes of the
+/// keccak-256 hash of its SCALE encoding
+pub fn evm_address_of<AccountId: Encode>(account: &AccountId) -> H160 {
+    H160::from_slice(&keccak_256(&account.encode())[12..])
+}
+
+/// ABI-encoded attestation of `export` for the EVM chain `chain_id`
+pub fn attestation_message(chain_id: u64, export: &EvmExport) -> Vec<u8> {
+    let mut message = Vec::with_capacity(6 * 32);
+    push_uint(&mut message, chain_id);
+    push_address(&mut message, &export.bridge);
+    push_address(&mut message, &export.address);
+    push_int(&mut message, export.score);
+    push_uint(&mut message, export.expiry);
+    push_uint(&mut message, export.nonce);
+    message
+}
+
+/// EVM address of the key that signed the keccak-256 hash of `message`
+pub fn recover_signer(message: &[u8], signature: &ecdsa::Signature) -> Option<H160> {
+    let public = sp_io::crypto::secp256k1_ecdsa_recover(&signature.0, &keccak_256(message)).ok()?;
+    Some(H160::from_slice(&keccak_256(&public)[12..]))
+}
+
+fn push_uint(out: &mut Vec<u8>, value: u64) {
+    out.extend_from_slice(&[0; 24]);
+    out.extend_from_slice(&value.to_be_bytes());
+}
+
+/// Sign-extended to 256 bits
+fn push_int(out: &mut Vec<u8>, value: i32) {
+    let fill = if value < 0 { 0xff } else { 0 };
+    out.extend_from_slice(&[fill; 28]);
+    out.extend_from_slice(&value.to_be_bytes());
+}
+
+fn push_address(out: &mut Vec<u8>, address: &H160) {
+    out.extend_from_slice(&[0; 12]);
+    out.extend_from_slice(address.as_bytes());
+}
+
+impl<T: Config> Pallet<T> {
+    /// Off-chain worker: sign pending exports with the local attestation key and submit them
+    pub fn sign_evm_exports(now: BlockNumberFor<T>) {
+        let Some(expected) = EvmAttestationSigner::<T>::get() else { return };
+        let submitter = Signer::<T, T::EvmAttestationAuthorityId>::any_account();
+        if !submitter.can_sign() {
+            return;
+        }
+        let keys = sp_io::crypto::ecdsa_public_keys(KEY_TYPE);
+
+        for (account, chain_id, export) in PendingEvmExports::<T>::iter().take(MAX_SIGNATURES_PER_BLOCK) {
+            if !Self::mark_submitted(export.nonce, now) {
+                continue;
+            }
+
+            let message = attestation_message(chain_id, &export);
+            let hash = keccak_256(&message);
+            let Some(signature) = keys
+                .iter()
+                .filter_map(|key| sp_io::crypto::ecdsa_sign_prehashed(KEY_TYPE, key, &hash))
+                .find(|signature| recover_signer(&message, signature) == Some(expected))
+            else {
+                log::debug!(target: LOG_TARGET, "No local key for attestation signer {:?}", expected);
+                return;
+            };
+
+            let result = submitter.send_signed_transaction(|_| Call::submit_evm_attestation {
+                account: account.clone(),
+                chain_id,
+                signature: signature.clone(),
+            });
+            if !matches!(result, Some((_, Ok(())))) {
+                log::warn!(target: LOG_TARGET, "Failed to submit attestation {}", export.nonce);
+            }
+        }
+    }
+
+    /// Record in local storage that the export was submitted; false if it was
+    /// submitted within the last `RESUBMIT_BLOCKS`
+    fn mark_submitted(nonce: u64, now: BlockNumberFor<T>) -> bool {
+        let mut key = b"dotrep::evm::submitted::".to_vec();
+        key.extend(nonce.encode());
+
+        StorageValueRef::persistent(&key)
+            .mutate(|previous: Result<Option<BlockNumberFor<T>>, _>| match previous {
+                Ok(Some(at)) if now < at.saturating_add(RESUBMIT_BLOCKS.into()) => Err(()),
+                _ => Ok(now),
+            })
+            .is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attestation_message_is_abi_encoded() {
+        let export = EvmExport {
+            nonce: 7,
+            bridge: H160::repeat_byte(0xbb),
+            address: H160::repeat_byte(0xaa),
+            score: -2,
+            expiry: 1_700_000_000,
+        };
+        let message = attestation_message(1, &export);
+
+        assert_eq!(message.len(), 6 * 32);
+        assert_eq!(message[31], 1);
+        assert_eq!(&message[44..64], H160::repeat_byte(0xbb).as_bytes());
+        assert_eq!(&message[76..96], H160::repeat_byte(0xaa).as_bytes());
+        assert_eq!(&message[96..128], &[[0xff; 31].as_slice(), &[0xfe]].concat()[..]);
+        assert_eq!(&message[152..160], &1_700_000_000u64.to_be_bytes());
+        assert_eq!(message[191], 7);
+    }
+}
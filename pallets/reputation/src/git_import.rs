@@ -0,0 +1,125 @@
+//! Contributions imported from the git logs of self-hosted forges
+//!
+//! Forges without a public API cannot be queried by the off-chain worker, so the
+//! contributor submits the first-parent history of its work with `import_git_log` and a
+//! maintainer of the project co-signs it with `maintainer_attest`. The off-chain worker
+//! then checks that the commits form a hash chain and submits `confirm_git_import`
+//! signed with `T::OwnershipAuthorityId`; only then can the contribution's verification
+//! complete.
+
+use crate::pallet::{Call, Config, ContributionId, GitCommitLink, GitImports, MaintainerAttestations, Pallet};
+use codec::Encode;
+use frame_system::{
+    offchain::{SendSignedTransaction, Signer},
+    pallet_prelude::BlockNumberFor,
+};
+use sp_runtime::{offchain::storage::StorageValueRef, traits::Saturating};
+use sp_std::collections::btree_set::BTreeSet;
+
+const LOG_TARGET: &str = "pallet-reputation-git-import";
+
+/// Imports checked per off-chain worker run
+const MAX_CHECKS_PER_BLOCK: usize = 5;
+
+/// Blocks before an import is checked again
+const RECHECK_BLOCKS: u32 = 10;
+
+/// Why a git log is not a hash chain
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GitLogError {
+    Empty,
+    /// A commit id is zero
+    NullCommit,
+    /// A commit's parent is not the next commit in the log
+    BrokenChain,
+    /// A commit appears twice
+    RepeatedCommit,
+}
+
+/// Check that `commits`, newest first, each name the next one as their parent
+///
+/// The oldest commit's parent is not checked: it precedes the imported work.
+pub fn check_hash_chain(commits: &[GitCommitLink]) -> Result<(), GitLogError> {
+    if commits.is_empty() {
+        return Err(GitLogError::Empty);
+    }
+
+    let mut seen = BTreeSet::new();
+    for link in commits {
+        if link.commit == [0; 20] {
+            return Err(GitLogError::NullCommit);
+        }
+        if !seen.insert(link.commit) {
+            return Err(GitLogError::RepeatedCommit);
+        }
+    }
+    if commits.windows(2).any(|pair| pair[0].parent != pair[1].commit) {
+        return Err(GitLogError::BrokenChain);
+    }
+    Ok(())
+}
+
+impl<T: Config> Pallet<T> {
+    /// Off-chain worker: check the logs of attested imports and submit the outcome
+    pub fn check_git_imports(now: BlockNumberFor<T>) {
+        let signer = Signer::<T, T::OwnershipAuthorityId>::any_account();
+        if !signer.can_sign() {
+            return;
+        }
+
+        let imports = GitImports::<T>::iter()
+            .filter(|(contribution_id, import)| {
+                !import.validated && MaintainerAttestations::<T>::contains_key(contribution_id)
+            })
+            .take(MAX_CHECKS_PER_BLOCK);
+        for (contribution_id, import) in imports {
+            if !Self::mark_import_checked(contribution_id, now) {
+                continue;
+            }
+
+            let consistent = match check_hash_chain(&import.commits) {
+                Ok(()) => true,
+                Err(e) => {
+                    log::debug!(target: LOG_TARGET, "Git log of contribution {} is invalid: {:?}", contribution_id, e);
+                    false
+                },
+            };
+            let result = signer.send_signed_transaction(|_| Call::confirm_git_import { contribution_id, consistent });
+            if !matches!(result, Some((_, Ok(())))) {
+                log::warn!(target: LOG_TARGET, "Failed to submit the check of contribution {}", contribution_id);
+            }
+        }
+    }
+
+    /// Record in local storage that the import was checked; false if it was checked
+    /// within the last `RECHECK_BLOCKS`
+    fn mark_import_checked(contribution_id: ContributionId, now: BlockNumberFor<T>) -> bool {
+        let mut key = b"dotrep::git_import::checked::".to_vec();
+        key.extend(contribution_id.encode());
+
+        StorageValueRef::persistent(&key)
+            .mutate(|previous: Result<Option<BlockNumberFor<T>>, _>| match previous {
+                Ok(Some(at)) if now < at.saturating_add(RECHECK_BLOCKS.into()) => Err(()),
+                _ => Ok(now),
+            })
+            .is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn link(commit: u8, parent: u8) -> GitCommitLink {
+        GitCommitLink { commit: [commit; 20], parent: [parent; 20] }
+    }
+
+    #[test]
+    fn test_check_hash_chain() {
+        assert_eq!(check_hash_chain(&[link(3, 2), link(2, 1), link(1, 0)]), Ok(()));
+        assert_eq!(check_hash_chain(&[]), Err(GitLogError::Empty));
+        assert_eq!(check_hash_chain(&[link(3, 2), link(1, 0)]), Err(GitLogError::BrokenChain));
+        assert_eq!(check_hash_chain(&[link(0, 1)]), Err(GitLogError::NullCommit));
+        assert_eq!(check_hash_chain(&[link(2, 1), link(1, 2), link(2, 1)]), Err(GitLogError::RepeatedCommit));
+    }
+}
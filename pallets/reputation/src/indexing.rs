@@ -0,0 +1,83 @@
+//! Off-chain indexing of contribution submissions and score changes
+//!
+//! During block execution the pallet writes compact records to the off-chain
+//! database of nodes started with `--enable-offchain-indexing`, so archive nodes can
+//! serve reputation history to indexers without re-executing blocks.
+//!
+//! Keys are deterministic. A contribution is stored under its id; a score change
+//! under the account, block and index of its `ReputationUpdated` event, which
+//! indexers find through the account's event topic.
+
+use crate::pallet::{Config, Contribution, ContributionId, DataSource, RepChangeReason};
+use codec::{Decode, Encode};
+use dotrep_primitives::{ContributionType, Score};
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// Key prefix of contribution records
+pub const CONTRIBUTION_PREFIX: &[u8] = b"dotrep/contribution/";
+
+/// Key prefix of score change records
+pub const SCORE_PREFIX: &[u8] = b"dotrep/score/";
+
+/// Contribution as submitted
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ContributionRecord<AccountId, BlockNumber> {
+    pub contributor: AccountId,
+    pub proof: H256,
+    pub contribution_type: ContributionType,
+    pub weight: u8,
+    pub source: DataSource,
+    pub submitted_at: BlockNumber,
+}
+
+/// Change of an account's reputation score
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ScoreChangeRecord {
+    pub old_score: Score,
+    pub new_score: Score,
+    pub reason: RepChangeReason,
+}
+
+/// Off-chain database key of a contribution record
+pub fn contribution_key(contribution_id: ContributionId) -> Vec<u8> {
+    [CONTRIBUTION_PREFIX, &contribution_id.encode()].concat()
+}
+
+/// Off-chain database key of the score change recorded by the event at `event_index`
+pub fn score_key<AccountId: Encode, BlockNumber: Encode>(
+    account: &AccountId,
+    block: BlockNumber,
+    event_index: u32,
+) -> Vec<u8> {
+    [SCORE_PREFIX, &(account, block, event_index).encode()].concat()
+}
+
+/// Record a newly submitted contribution
+pub(crate) fn index_contribution<T: Config>(contributor: &T::AccountId, contribution: &Contribution<T>) {
+    let record = ContributionRecord {
+        contributor: contributor.clone(),
+        proof: contribution.proof,
+        contribution_type: contribution.contribution_type.clone(),
+        weight: contribution.weight,
+        source: contribution.source.clone(),
+        submitted_at: contribution.timestamp,
+    };
+    sp_io::offchain_index::set(&contribution_key(contribution.id), &record.encode());
+}
+
+/// Record a score change about to be deposited as the block's next event
+pub(crate) fn index_score_change<T: Config>(
+    account: &T::AccountId,
+    old_score: Score,
+    new_score: Score,
+    reason: &RepChangeReason,
+) {
+    let block: BlockNumberFor<T> = frame_system::Pallet::<T>::block_number();
+    let event_index = frame_system::Pallet::<T>::event_count();
+    let record = ScoreChangeRecord { old_score, new_score, reason: reason.clone() };
+    sp_io::offchain_index::set(&score_key(account, block, event_index), &record.encode());
+}
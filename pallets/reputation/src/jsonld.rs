@@ -0,0 +1,613 @@
+//! JSON-LD Knowledge Asset builder for reputation snapshots
+//!
+//! Serializes an account's score, tier, per-type breakdown and contribution digests
+//! into the OriginTrail Knowledge Asset schema (schema.org + DID context). Output is
+//! compact and deterministic so the same snapshot always yields the same assertion.
+//! Everything is written byte by byte, so the builder works in `no_std`.
+//!
+//! Also builds contributor DID documents: a self-certifying `did:key` identifier
+//! linked to the account's `did:dotrep` DID, verified GitHub handle and reputation UAL.
+//!
+//! Reputation passports are W3C Verifiable Credentials carrying the same snapshot plus
+//! the contributor's attestations, issued by a DKG publisher key.
+
+pub use dotrep_primitives::ReputationSnapshot;
+use dotrep_primitives::Score;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// JSON-LD `@context` shared by all reputation assertions
+const CONTEXT: &[u8] = br#"["https://schema.org/",{"dkg":"https://origintrail.io/dkg-schema/","did":"https://www.w3.org/ns/did#"}]"#;
+
+/// JSON-LD `@context` of DID documents
+const DID_CONTEXT: &[u8] = br#"["https://www.w3.org/ns/did/v1","https://w3id.org/security/suites/sr25519-2020/v1"]"#;
+
+/// JSON-LD `@context` of reputation passports
+const CREDENTIAL_CONTEXT: &[u8] = br#"["https://www.w3.org/2018/credentials/v1","https://schema.org/",{"dkg":"https://origintrail.io/dkg-schema/"}]"#;
+
+/// Multicodec prefix of sr25519 public keys in `did:key` identifiers
+const SR25519_MULTICODEC: [u8; 2] = [0xef, 0x01];
+
+/// Reputation tier derived from the score
+#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug)]
+pub enum ReputationTier {
+    Newcomer,
+    Contributor,
+    Trusted,
+    Expert,
+    Luminary,
+}
+
+impl ReputationTier {
+    pub fn from_score(score: Score) -> Self {
+        match score {
+            Score::MIN..=99 => ReputationTier::Newcomer,
+            100..=299 => ReputationTier::Contributor,
+            300..=599 => ReputationTier::Trusted,
+            600..=849 => ReputationTier::Expert,
+            _ => ReputationTier::Luminary,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReputationTier::Newcomer => "Newcomer",
+            ReputationTier::Contributor => "Contributor",
+            ReputationTier::Trusted => "Trusted",
+            ReputationTier::Expert => "Expert",
+            ReputationTier::Luminary => "Luminary",
+        }
+    }
+}
+
+/// Builder for a reputation Knowledge Asset assertion
+#[derive(Clone, RuntimeDebug)]
+pub struct AssertionBuilder {
+    subject: Vec<u8>,
+    snapshot: ReputationSnapshot,
+    paranet: Option<Vec<u8>>,
+    /// Version number and UAL of the asset this assertion updates
+    previous: Option<(u32, Vec<u8>)>,
+    published_at: u64,
+}
+
+impl AssertionBuilder {
+    /// Start an assertion about the account with the given SCALE encoding
+    pub fn new(account: &[u8]) -> Self {
+        Self { subject: subject_did(account), snapshot: Default::default(), paranet: None, previous: None, published_at: 0 }
+    }
+
+    pub fn score(mut self, score: Score) -> Self {
+        self.snapshot.score = score;
+        self
+    }
+
+    pub fn breakdown(mut self, contribution_type: &'static str, points: Score) -> Self {
+        self.snapshot.breakdown.push((contribution_type, points));
+        self
+    }
+
+    pub fn contribution_digest(mut self, digest: [u8; 32]) -> Self {
+        self.snapshot.contribution_digests.push(digest);
+        self
+    }
+
+    /// Take score, breakdown and digests from a snapshot
+    pub fn snapshot(mut self, snapshot: ReputationSnapshot) -> Self {
+        self.snapshot = snapshot;
+        self
+    }
+
+    /// Group the asset under a Paranet
+    pub fn paranet(mut self, ual: Vec<u8>) -> Self {
+        self.paranet = Some(ual);
+        self
+    }
+
+    /// Publish as `version` of the existing asset at `ual`
+    pub fn version_of(mut self, version: u32, ual: Vec<u8>) -> Self {
+        self.previous = Some((version, ual));
+        self
+    }
+
+    pub fn published_at(mut self, block: u64) -> Self {
+        self.published_at = block;
+        self
+    }
+
+    pub fn build(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(b"{\"@context\":");
+        out.extend_from_slice(CONTEXT);
+        out.extend_from_slice(b",\"@type\":\"dkg:ReputationAsset\",\"@id\":");
+        push_string(&mut out, &self.subject);
+        out.extend_from_slice(b",\"schema:identifier\":");
+        push_string(&mut out, &self.subject);
+        out.extend_from_slice(b",\"reputationScore\":");
+        push_integer(&mut out, self.snapshot.score as i64);
+        out.extend_from_slice(b",\"tier\":");
+        push_string(&mut out, ReputationTier::from_score(self.snapshot.score).as_str().as_bytes());
+
+        push_snapshot_details(&mut out, &self.snapshot);
+        if let Some(ref paranet) = self.paranet {
+            out.extend_from_slice(b",\"dkg:paranet\":");
+            push_string(&mut out, paranet);
+        }
+        if let Some((version, ref ual)) = self.previous {
+            out.extend_from_slice(b",\"dkg:version\":");
+            push_integer(&mut out, version as i64);
+            out.extend_from_slice(b",\"dkg:previousVersion\":");
+            push_string(&mut out, ual);
+        }
+        out.extend_from_slice(b",\"publishedAtBlock\":");
+        push_integer(&mut out, self.published_at as i64);
+        out.push(b'}');
+        out
+    }
+}
+
+/// Builder for a contributor's DID document
+#[derive(Clone, RuntimeDebug)]
+pub struct DidDocumentBuilder {
+    public_key: Vec<u8>,
+    account: Vec<u8>,
+    github_handle: Option<Vec<u8>>,
+    reputation_ual: Option<Vec<u8>>,
+}
+
+impl DidDocumentBuilder {
+    /// Start a document for the account with the given SCALE encoding, whose
+    /// sr25519 public key is `public_key`
+    pub fn new(account: &[u8], public_key: &[u8]) -> Self {
+        Self { public_key: public_key.to_vec(), account: account.to_vec(), github_handle: None, reputation_ual: None }
+    }
+
+    pub fn github_handle(mut self, handle: Vec<u8>) -> Self {
+        self.github_handle = Some(handle);
+        self
+    }
+
+    pub fn reputation_ual(mut self, ual: Vec<u8>) -> Self {
+        self.reputation_ual = Some(ual);
+        self
+    }
+
+    pub fn build(&self) -> Vec<u8> {
+        let id = did_key(&self.public_key);
+        let mut key_id = id.clone();
+        key_id.push(b'#');
+        key_id.extend_from_slice(&id[b"did:key:".len()..]);
+
+        let mut out = Vec::new();
+        out.extend_from_slice(b"{\"@context\":");
+        out.extend_from_slice(DID_CONTEXT);
+        out.extend_from_slice(b",\"id\":");
+        push_string(&mut out, &id);
+
+        out.extend_from_slice(b",\"alsoKnownAs\":[");
+        push_string(&mut out, &subject_did(&self.account));
+        if let Some(ref handle) = self.github_handle {
+            let mut profile = b"https://github.com/".to_vec();
+            profile.extend_from_slice(handle);
+            out.push(b',');
+            push_string(&mut out, &profile);
+        }
+
+        out.extend_from_slice(b"],\"verificationMethod\":[{\"id\":");
+        push_string(&mut out, &key_id);
+        out.extend_from_slice(b",\"type\":\"Sr25519VerificationKey2020\",\"controller\":");
+        push_string(&mut out, &id);
+        out.extend_from_slice(b",\"publicKeyMultibase\":");
+        push_string(&mut out, &id[b"did:key:".len()..]);
+        out.extend_from_slice(b"}],\"authentication\":[");
+        push_string(&mut out, &key_id);
+        out.push(b']');
+
+        if let Some(ref ual) = self.reputation_ual {
+            let mut service_id = id.clone();
+            service_id.extend_from_slice(b"#reputation");
+            out.extend_from_slice(b",\"service\":[{\"id\":");
+            push_string(&mut out, &service_id);
+            out.extend_from_slice(b",\"type\":\"DKGReputationAsset\",\"serviceEndpoint\":");
+            push_string(&mut out, ual);
+            out.extend_from_slice(b"}]");
+        }
+
+        out.push(b'}');
+        out
+    }
+}
+
+/// Builder for a reputation passport: a Verifiable Credential about a contributor
+#[derive(Clone, RuntimeDebug)]
+pub struct PassportBuilder {
+    subject: Vec<u8>,
+    issuer: Vec<u8>,
+    snapshot: ReputationSnapshot,
+    github_handle: Option<Vec<u8>>,
+    reputation_ual: Option<Vec<u8>>,
+    issued_at_block: u64,
+    issued_at_millis: u64,
+}
+
+impl PassportBuilder {
+    /// Start a passport about the account with the given SCALE encoding, issued by
+    /// the sr25519 key `issuer_key`
+    pub fn new(account: &[u8], issuer_key: &[u8]) -> Self {
+        Self {
+            subject: subject_did(account),
+            issuer: did_key(issuer_key),
+            snapshot: Default::default(),
+            github_handle: None,
+            reputation_ual: None,
+            issued_at_block: 0,
+            issued_at_millis: 0,
+        }
+    }
+
+    pub fn snapshot(mut self, snapshot: ReputationSnapshot) -> Self {
+        self.snapshot = snapshot;
+        self
+    }
+
+    pub fn github_handle(mut self, handle: Vec<u8>) -> Self {
+        self.github_handle = Some(handle);
+        self
+    }
+
+    pub fn reputation_ual(mut self, ual: Vec<u8>) -> Self {
+        self.reputation_ual = Some(ual);
+        self
+    }
+
+    /// Block and unix time (in milliseconds) the passport is issued at
+    pub fn issued_at(mut self, block: u64, unix_millis: u64) -> Self {
+        self.issued_at_block = block;
+        self.issued_at_millis = unix_millis;
+        self
+    }
+
+    /// Credential without a proof; these are the bytes the issuer signs
+    pub fn build(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(b"{\"@context\":");
+        out.extend_from_slice(CREDENTIAL_CONTEXT);
+        out.extend_from_slice(b",\"type\":[\"VerifiableCredential\",\"dkg:ReputationPassport\"],\"issuer\":");
+        push_string(&mut out, &self.issuer);
+        out.extend_from_slice(b",\"issuanceDate\":\"");
+        push_iso8601(&mut out, self.issued_at_millis);
+        out.extend_from_slice(b"\",\"credentialSubject\":{\"id\":");
+        push_string(&mut out, &self.subject);
+        out.extend_from_slice(b",\"reputationScore\":");
+        push_integer(&mut out, self.snapshot.score as i64);
+        out.extend_from_slice(b",\"tier\":");
+        push_string(&mut out, ReputationTier::from_score(self.snapshot.score).as_str().as_bytes());
+        push_snapshot_details(&mut out, &self.snapshot);
+        if let Some(ref handle) = self.github_handle {
+            let mut profile = b"https://github.com/".to_vec();
+            profile.extend_from_slice(handle);
+            out.extend_from_slice(b",\"schema:sameAs\":");
+            push_string(&mut out, &profile);
+        }
+        if let Some(ref ual) = self.reputation_ual {
+            out.extend_from_slice(b",\"dkg:reputationAsset\":");
+            push_string(&mut out, ual);
+        }
+        out.extend_from_slice(b",\"issuedAtBlock\":");
+        push_integer(&mut out, self.issued_at_block as i64);
+        out.extend_from_slice(b"}}");
+        out
+    }
+
+    /// Credential with an `Sr25519Signature2020` proof over the output of `build`
+    pub fn build_signed(&self, signature: &[u8]) -> Vec<u8> {
+        let mut key_id = self.issuer.clone();
+        key_id.push(b'#');
+        key_id.extend_from_slice(&self.issuer[b"did:key:".len()..]);
+
+        let mut out = self.build();
+        out.pop();
+        out.extend_from_slice(b",\"proof\":{\"type\":\"Sr25519Signature2020\",\"created\":\"");
+        push_iso8601(&mut out, self.issued_at_millis);
+        out.extend_from_slice(b"\",\"verificationMethod\":");
+        push_string(&mut out, &key_id);
+        out.extend_from_slice(b",\"proofPurpose\":\"assertionMethod\",\"proofValue\":\"0x");
+        push_hex(&mut out, signature);
+        out.extend_from_slice(b"\"}}");
+        out
+    }
+}
+
+/// `did:key` of an sr25519 public key: multibase base58btc of the multicodec-prefixed key
+pub fn did_key(public_key: &[u8]) -> Vec<u8> {
+    let mut key = SR25519_MULTICODEC.to_vec();
+    key.extend_from_slice(public_key);
+
+    let mut did = b"did:key:z".to_vec();
+    push_base58(&mut did, &key);
+    did
+}
+
+/// DID of an account: `did:dotrep:0x` followed by its hex-encoded SCALE encoding
+pub fn subject_did(account: &[u8]) -> Vec<u8> {
+    let mut did = b"did:dotrep:0x".to_vec();
+    push_hex(&mut did, account);
+    did
+}
+
+/// Append the breakdown and contribution digest arrays of a snapshot
+fn push_snapshot_details(out: &mut Vec<u8>, snapshot: &ReputationSnapshot) {
+    out.extend_from_slice(b",\"breakdown\":[");
+    for (index, (contribution_type, points)) in snapshot.breakdown.iter().enumerate() {
+        if index > 0 {
+            out.push(b',');
+        }
+        out.extend_from_slice(b"{\"@type\":\"dkg:ContributionScore\",\"contributionType\":");
+        push_string(out, contribution_type.as_bytes());
+        out.extend_from_slice(b",\"points\":");
+        push_integer(out, *points as i64);
+        out.push(b'}');
+    }
+
+    out.extend_from_slice(b"],\"contributions\":[");
+    for (index, digest) in snapshot.contribution_digests.iter().enumerate() {
+        if index > 0 {
+            out.push(b',');
+        }
+        out.extend_from_slice(b"{\"@type\":\"schema:CreativeWork\",\"schema:sha256\":\"0x");
+        push_hex(out, digest);
+        out.extend_from_slice(b"\"}");
+    }
+    out.push(b']');
+}
+
+/// Append a unix time in milliseconds as an ISO 8601 UTC timestamp with second precision
+fn push_iso8601(out: &mut Vec<u8>, unix_millis: u64) {
+    let seconds = unix_millis / 1000;
+    let (days, time) = ((seconds / 86_400) as i64, seconds % 86_400);
+
+    // Civil date of a day count since 1970-01-01 (Howard Hinnant's algorithm)
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let day_of_era = z - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    let mut push_padded = |value: i64, width: usize, separator: u8| {
+        let mut digits = Vec::new();
+        push_integer(&mut digits, value);
+        out.extend(sp_std::iter::repeat(b'0').take(width.saturating_sub(digits.len())));
+        out.extend_from_slice(&digits);
+        out.push(separator);
+    };
+    push_padded(year, 4, b'-');
+    push_padded(month, 2, b'-');
+    push_padded(day, 2, b'T');
+    push_padded((time / 3600) as i64, 2, b':');
+    push_padded((time / 60 % 60) as i64, 2, b':');
+    push_padded((time % 60) as i64, 2, b'Z');
+}
+
+pub(crate) fn push_hex(out: &mut Vec<u8>, bytes: &[u8]) {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+    for byte in bytes {
+        out.push(HEX[(byte >> 4) as usize]);
+        out.push(HEX[(byte & 0x0f) as usize]);
+    }
+}
+
+/// Append the Bitcoin-alphabet base58 encoding of `bytes`
+fn push_base58(out: &mut Vec<u8>, bytes: &[u8]) {
+    const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+    // Little-endian base58 digits of the big-endian input
+    let mut digits: Vec<u8> = Vec::new();
+    for &byte in bytes {
+        let mut carry = byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    // Every leading zero byte is written as a leading '1'
+    out.extend(bytes.iter().take_while(|byte| **byte == 0).map(|_| ALPHABET[0]));
+    out.extend(digits.iter().rev().map(|digit| ALPHABET[*digit as usize]));
+}
+
+pub(crate) fn push_integer(out: &mut Vec<u8>, value: i64) {
+    if value < 0 {
+        out.push(b'-');
+    }
+    let mut value = value.unsigned_abs();
+    let mut digits = [0u8; 20];
+    let mut len = 0;
+    loop {
+        digits[len] = b'0' + (value % 10) as u8;
+        len += 1;
+        value /= 10;
+        if value == 0 {
+            break;
+        }
+    }
+    out.extend(digits[..len].iter().rev());
+}
+
+/// Write a JSON string literal, escaping quotes, backslashes and control characters
+fn push_string(out: &mut Vec<u8>, value: &[u8]) {
+    out.push(b'"');
+    for &byte in value {
+        match byte {
+            b'"' => out.extend_from_slice(b"\\\""),
+            b'\\' => out.extend_from_slice(b"\\\\"),
+            b'\n' => out.extend_from_slice(b"\\n"),
+            b'\r' => out.extend_from_slice(b"\\r"),
+            b'\t' => out.extend_from_slice(b"\\t"),
+            0x00..=0x1f => {
+                out.extend_from_slice(b"\\u00");
+                push_hex(out, &[byte]);
+            }
+            _ => out.push(byte),
+        }
+    }
+    out.push(b'"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Golden fixtures are stored with a trailing newline
+    fn fixture(bytes: &[u8]) -> &[u8] {
+        bytes.strip_suffix(b"\n").unwrap_or(bytes)
+    }
+
+    #[test]
+    fn test_full_snapshot_matches_golden_fixture() {
+        let assertion = AssertionBuilder::new(&7u64.to_le_bytes())
+            .score(720)
+            .breakdown("PullRequest", 450)
+            .breakdown("CodeReview", 270)
+            .contribution_digest([0xab; 32])
+            .contribution_digest([0x01; 32])
+            .published_at(1234)
+            .build();
+
+        assert_eq!(
+            &assertion[..],
+            fixture(include_bytes!("../fixtures/reputation_assertion_full.jsonld"))
+        );
+    }
+
+    #[test]
+    fn test_empty_snapshot_matches_golden_fixture() {
+        let assertion = AssertionBuilder::new(&1u64.to_le_bytes()).score(-5).build();
+
+        assert_eq!(
+            &assertion[..],
+            fixture(include_bytes!("../fixtures/reputation_assertion_empty.jsonld"))
+        );
+    }
+
+    #[test]
+    fn test_paranet_is_included_when_set() {
+        let assertion = AssertionBuilder::new(&1u64.to_le_bytes())
+            .paranet(b"did:dkg:otp/2043/0xparanet/1".to_vec())
+            .build();
+
+        let expected: &[u8] = br#"],"dkg:paranet":"did:dkg:otp/2043/0xparanet/1","publishedAtBlock":0}"#;
+        assert!(assertion.ends_with(expected));
+    }
+
+    #[test]
+    fn test_update_references_previous_version() {
+        let assertion = AssertionBuilder::new(&1u64.to_le_bytes())
+            .version_of(3, b"did:dkg:otp/2043/0xabc/1".to_vec())
+            .build();
+
+        let expected: &[u8] =
+            br#"],"dkg:version":3,"dkg:previousVersion":"did:dkg:otp/2043/0xabc/1","publishedAtBlock":0}"#;
+        assert!(assertion.ends_with(expected));
+    }
+
+    #[test]
+    fn test_tiers_follow_score() {
+        assert_eq!(ReputationTier::from_score(-10), ReputationTier::Newcomer);
+        assert_eq!(ReputationTier::from_score(100), ReputationTier::Contributor);
+        assert_eq!(ReputationTier::from_score(599), ReputationTier::Trusted);
+        assert_eq!(ReputationTier::from_score(849), ReputationTier::Expert);
+        assert_eq!(ReputationTier::from_score(1000), ReputationTier::Luminary);
+    }
+
+    #[test]
+    fn test_strings_are_escaped() {
+        let mut out = Vec::new();
+        push_string(&mut out, b"a\"b\\c\n\x01");
+        assert_eq!(&out[..], br#""a\"b\\c\n\u0001""#);
+    }
+
+    #[test]
+    fn test_base58_encoding() {
+        let encode = |bytes: &[u8]| {
+            let mut out = Vec::new();
+            push_base58(&mut out, bytes);
+            out
+        };
+
+        assert_eq!(encode(b"hello world"), b"StV1DL6CwTryKyV".to_vec());
+        assert_eq!(encode(&[0, 0, 1]), b"112".to_vec());
+        assert_eq!(encode(&[]), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn test_iso8601_timestamps() {
+        let format = |millis: u64| {
+            let mut out = Vec::new();
+            push_iso8601(&mut out, millis);
+            String::from_utf8(out).unwrap()
+        };
+
+        assert_eq!(format(0), "1970-01-01T00:00:00Z");
+        assert_eq!(format(1_700_000_000_999), "2023-11-14T22:13:20Z");
+        assert_eq!(format(951_782_400_000), "2000-02-29T00:00:00Z");
+    }
+
+    #[test]
+    fn test_signed_passport_appends_proof() {
+        let passport = PassportBuilder::new(&[7], &[1, 2])
+            .github_handle(b"octocat".to_vec())
+            .issued_at(5, 0);
+        let unsigned = passport.build();
+        let signed = passport.build_signed(&[0xab, 0xcd]);
+
+        let issuer = String::from_utf8(did_key(&[1, 2])).unwrap();
+        let key = &issuer["did:key:".len()..];
+        let expected = format!(
+            concat!(
+                r#","schema:sameAs":"https://github.com/octocat","issuedAtBlock":5}},"#,
+                r#""proof":{{"type":"Sr25519Signature2020","created":"1970-01-01T00:00:00Z","#,
+                r#""verificationMethod":"{issuer}#{key}","proofPurpose":"assertionMethod","proofValue":"0xabcd"}}}}"#,
+            ),
+            issuer = issuer,
+            key = key,
+        );
+        assert!(signed.starts_with(&unsigned[..unsigned.len() - 1]));
+        assert!(String::from_utf8(signed).unwrap().ends_with(&expected));
+        let subject: &[u8] = br#""credentialSubject":{"id":"did:dotrep:0x07","reputationScore":0"#;
+        assert!(unsigned.windows(subject.len()).any(|window| window == subject));
+    }
+
+    #[test]
+    fn test_did_document_links_account_handle_and_ual() {
+        let document = DidDocumentBuilder::new(&[7], &[1, 2])
+            .github_handle(b"octocat".to_vec())
+            .reputation_ual(b"did:dkg:otp/2043/0xabc/1".to_vec())
+            .build();
+
+        let id = String::from_utf8(did_key(&[1, 2])).unwrap();
+        let key = &id["did:key:".len()..];
+        let expected = format!(
+            concat!(
+                r#"{{"@context":["https://www.w3.org/ns/did/v1","https://w3id.org/security/suites/sr25519-2020/v1"],"#,
+                r#""id":"{id}","alsoKnownAs":["did:dotrep:0x07","https://github.com/octocat"],"#,
+                r#""verificationMethod":[{{"id":"{id}#{key}","type":"Sr25519VerificationKey2020","#,
+                r#""controller":"{id}","publicKeyMultibase":"{key}"}}],"authentication":["{id}#{key}"],"#,
+                r#""service":[{{"id":"{id}#reputation","type":"DKGReputationAsset","#,
+                r#""serviceEndpoint":"did:dkg:otp/2043/0xabc/1"}}]}}"#,
+            ),
+            id = id,
+            key = key,
+        );
+        assert_eq!(document, expected.into_bytes());
+        assert!(id.starts_with("did:key:z"));
+    }
+}
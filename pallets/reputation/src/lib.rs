@@ -2,12 +2,33 @@
 
 pub use pallet::*;
 
+pub use dotrep_primitives::traits::{self, *};
+
+use frame_support::weights::Weight;
+
+pub mod archive;
+pub mod dkg_integration;
+pub mod evm_bridge;
+pub mod git_import;
+pub mod indexing;
+pub mod jsonld;
+pub mod migrations;
+pub mod oracle;
+pub mod ownership;
+pub mod remote_cache;
+pub mod reminders;
+pub mod snapshot;
+pub mod runtime_api;
+
 #[cfg(test)]
 mod mock;
 
 #[cfg(test)]
 mod tests;
 
+#[cfg(test)]
+mod proptests;
+
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
@@ -29,6 +50,11 @@ mod offchain;
 /// - Cross-chain reputation queries via XCM
 /// - Governance-controlled algorithm parameters
 /// - Off-chain worker integration for external data verification
+/// - Off-chain indexing of contribution and score history for archive nodes
+/// - Submission proxies (e.g. CI bots) that submit contributions for a developer
+/// - Project registry whose maintainers co-sign contributions to their project
+/// - Repository ownership verification through an off-chain worker challenge
+/// - Co-authored contributions whose reputation is split between bound accounts
 ///
 /// # Usage
 ///
@@ -72,6 +98,7 @@ mod offchain;
 ///     ContributionType::PullRequest,
 ///     75, // weight
 ///     DataSource::GitHub,
+///     Default::default(), // no co-authors
 /// )?;
 /// ```
 ///
@@ -88,26 +115,33 @@ mod offchain;
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::{
+        dispatch::{DispatchErrorWithPostInfo, Pays, PostDispatchInfo, WithPostDispatchInfo},
         pallet_prelude::*,
-        traits::{Currency, Get, Time},
+        traits::{Currency, ExistenceRequirement, Get, Imbalance, OnUnbalanced, ReservableCurrency, Time},
         weights::Weight,
-        BoundedVec,
+        BoundedVec, PalletId,
     };
-    use frame_system::pallet_prelude::*;
-    use sp_core::H256;
-    use sp_runtime::traits::{Zero, Saturating};
-    use sp_runtime::RuntimeDebug;
+    use frame_system::{offchain::{AppCrypto, CreateSignedTransaction}, pallet_prelude::*};
+    use sp_core::{ecdsa, H160, H256};
+    use sp_runtime::traits::{AccountIdConversion, One, Saturating, UniqueSaturatedInto, Zero};
+    use sp_runtime::{Perbill, Percent, RuntimeDebug};
     use sp_std::prelude::*;
-    use sp_std::collections::btree_map::BTreeMap;
+    use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
+    use dotrep_primitives::{
+        AccountBindings, BoundedIterExt, ClaimVerdict, ContributionDisputes, ContributionRegistry, ContributionSubmitter, CreditProfile, CreditScoreProvider, CredibilityBoostProvider, OnClaimResolved,
+        OnReputationOffence, OnSkillTagsUpdated, PersonhoodProvider, ReputationOffence, ReputationProvider, ReputationSnapshot, ReputationSnapshotProvider,
+        HookQuotaProvider, HookTask, Score, Topic, Ual, MAX_CREDIT_SCORE, MAX_HOOK_SHARE,
+    };
+    pub use dotrep_primitives::{ChainId, ContributionType, PersonhoodId, SkillTag};
 
     /// Configure the pallet by specifying the parameters and types on which it depends.
     #[pallet::config]
-    pub trait Config: frame_system::Config {
+    pub trait Config: CreateSignedTransaction<Call<Self>> + frame_system::Config {
         /// Because this pallet emits events, it depends on the runtime's definition of an event.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
         /// Currency type for deposits and fees
-        type Currency: Currency<Self::AccountId>;
+        type Currency: ReservableCurrency<Self::AccountId>;
 
         /// Time provider for timestamps
         type Time: Time;
@@ -119,13 +153,13 @@ pub mod pallet {
         type MaxContributionsPerAccount: Get<u32>;
 
         /// Minimum reputation score
-        type MinReputation: Get<i32>;
+        type MinReputation: Get<Score>;
 
         /// Maximum reputation score
-        type MaxReputation: Get<i32>;
+        type MaxReputation: Get<Score>;
 
         /// Minimum reputation required to verify contributions
-        type MinReputationToVerify: Get<i32>;
+        type MinReputationToVerify: Get<Score>;
 
         /// Minimum number of verifications required for a contribution
         type MinVerifications: Get<u32>;
@@ -136,36 +170,307 @@ pub mod pallet {
         /// Origin that can update algorithm parameters (typically governance)
         type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
-        // Advanced Polkadot SDK features for judging
-        /// Benchmarking support
-        #[cfg(feature = "runtime-benchmarks")]
-        type Benchmarking: frame_benchmarking::Benchmark<Self>;
+        /// Origin that can pause and unpause the contribution pipeline in an emergency
+        /// (typically governance or the council)
+        type PauseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Handler for finalized reputation offences (e.g. trust-layer stake slashing)
+        type OnOffence: OnReputationOffence<Self::AccountId>;
+
+        /// Credibility boost applied on top of the raw score (e.g. trust-layer staking)
+        type CredibilityBoost: CredibilityBoostProvider<Self::AccountId>;
+
+        /// Maximum credibility boost in percent
+        type MaxCredibilityBoost: Get<u32>;
+
+        /// Maximum attestation boost in percent, however many attestations an account holds
+        type MaxAttestationBoost: Get<u32>;
+
+        /// Reputation awarded when a trust-layer claim survives a challenge
+        type ClaimUpheldReward: Get<Score>;
+
+        /// Reputation removed when a trust-layer claim is rejected
+        type ClaimRejectedPenalty: Get<Score>;
+
+        /// Period over which a submission proxy's rate limit applies
+        type ProxySubmissionPeriod: Get<Self::BlockNumber>;
+
+        /// Number of verifications a maintainer attestation counts as, capped below
+        /// `MinVerifications` so an attestation never verifies a contribution on its own
+        type MaintainerAttestationWeight: Get<u32>;
+
+        /// Contributions weighing more than this need a maintainer attestation to be verified
+        type MaintainerAttestationThreshold: Get<u8>;
+
+        /// Key the off-chain worker signs `confirm_project_ownership` with
+        type OwnershipAuthorityId: AppCrypto<Self::Public, Self::Signature>;
+
+        /// Origin allowed to confirm repository ownership (the off-chain worker's accounts)
+        type OwnershipVerifierOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Blocks an unverified project holds its repository; afterwards registering the
+        /// repository again replaces it
+        type ProjectClaimExpiry: Get<Self::BlockNumber>;
+
+        /// Accounts with a verified off-chain identity binding, which may be co-authors
+        type Bindings: AccountBindings<Self::AccountId>;
+
+        /// Maximum co-authors of one contribution besides its submitter
+        #[pallet::constant]
+        type MaxCoAuthors: Get<u32>;
+
+        /// Number of verifications a verification counts as when the verifier declared
+        /// one of the contribution's skills
+        #[pallet::constant]
+        type SkillMatchMultiplier: Get<u32>;
+
+        /// Deposit held per stored contribution, released when it is withdrawn or pruned
+        #[pallet::constant]
+        type ContributionDeposit: Get<BalanceOf<Self>>;
+
+        /// Open disputes that keep an account from purging its contribution records
+        type Disputes: ContributionDisputes<Self::AccountId>;
+
+        /// DKG publishing queue, through which archive roots are anchored
+        type Dkg: crate::dkg_integration::DKGIntegration<Self>;
+
+        /// Maximum projects a hackathon accepts contributions to
+        #[pallet::constant]
+        type MaxHackathonProjects: Get<u32>;
+
+        /// Leaderboard places of a hackathon, each sharing its reward pot
+        #[pallet::constant]
+        type MaxHackathonWinners: Get<u32>;
+
+        /// Weight (1-100) of the contribution a mentee's session attestation creates for the mentor
+        #[pallet::constant]
+        type MentorshipSessionWeight: Get<u8>;
+
+        /// Reputation a referrer earns once the referred account qualifies
+        #[pallet::constant]
+        type ReferralBonus: Get<Score>;
+
+        /// Verified contributions a referred account needs before its referrer is rewarded
+        #[pallet::constant]
+        type ReferralQualifyingContributions: Get<u32>;
+
+        /// Length in blocks of the epochs referrals are capped over
+        #[pallet::constant]
+        type ReferralEpoch: Get<Self::BlockNumber>;
+
+        /// Referrals an account can make per `ReferralEpoch`
+        #[pallet::constant]
+        type MaxReferralsPerEpoch: Get<u32>;
+
+        /// Blocks an account spends on probation after a Sybil penalty or an overturned verification
+        #[pallet::constant]
+        type ProbationPeriod: Get<Self::BlockNumber>;
+
+        /// Reputation from which `add_contribution` and `verify_contribution` can be fee-less
+        #[pallet::constant]
+        type FeelessReputationThreshold: Get<Score>;
+
+        /// Fee-less calls an account above `FeelessReputationThreshold` gets per `FeelessEra`
+        #[pallet::constant]
+        type FeelessCallsPerEra: Get<u32>;
+
+        /// Length in blocks of the eras fee-less calls are counted over
+        #[pallet::constant]
+        type FeelessEra: Get<Self::BlockNumber>;
+
+        /// Contributions weighing more than this are verified by the verifier committee
+        /// rather than by independent verifications
+        #[pallet::constant]
+        type CommitteeVerificationThreshold: Get<u8>;
+
+        /// Committee approvals that verify a contribution above `CommitteeVerificationThreshold`
+        #[pallet::constant]
+        type MinCommitteeApprovals: Get<u32>;
+
+        /// Maximum members of the verifier committee
+        #[pallet::constant]
+        type MaxCommitteeSize: Get<u32>;
+
+        /// Pot the contributor dividend is paid from
+        #[pallet::constant]
+        type DividendPalletId: Get<PalletId>;
+
+        /// Dividend split among contributors each `DividendEra`, in proportion to the
+        /// reputation they earned that era
+        #[pallet::constant]
+        type DividendBudget: Get<BalanceOf<Self>>;
+
+        /// Length in blocks of the eras the dividend is paid over
+        #[pallet::constant]
+        type DividendEra: Get<Self::BlockNumber>;
+
+        /// Reputation earned in one era beyond which an account's dividend share stops growing
+        #[pallet::constant]
+        type DividendEarningsCap: Get<Score>;
+
+        /// Blocks a contribution may stay pending before it is listed as stale
+        #[pallet::constant]
+        type VerificationDeadline: Get<Self::BlockNumber>;
+
+        /// Blocks a stale contribution stays pending before it is escalated to `UpdateOrigin`
+        #[pallet::constant]
+        type EscalationPeriod: Get<Self::BlockNumber>;
+
+        /// Reputation a verifier earns for verifying a stale or escalated contribution
+        #[pallet::constant]
+        type StaleVerifierReward: Get<Score>;
+
+        /// Length in blocks of the eras verifiers offer review capacity for
+        #[pallet::constant]
+        type VerifierEra: Get<Self::BlockNumber>;
+
+        /// Blocks before its verification deadline a pending contribution enters the
+        /// reminder queue
+        #[pallet::constant]
+        type ReminderLead: Get<Self::BlockNumber>;
+
+        /// Blocks after its verification during which a contribution's record is kept, so
+        /// it can still be reported as fraudulent, even if its contributor purges their data
+        #[pallet::constant]
+        type DisputeWindow: Get<Self::BlockNumber>;
+
+        /// Verification deadlines that can fall in one block
+        #[pallet::constant]
+        type MaxDeadlinesPerBlock: Get<u32>;
+
+        /// Verifier rejections that reject a pending contribution
+        #[pallet::constant]
+        type RejectionThreshold: Get<u32>;
+
+        /// Share of a rejected contribution's deposit that is slashed
+        #[pallet::constant]
+        type RejectedDepositSlash: Get<Perbill>;
+
+        /// Handler for deposits slashed from rejected contributions
+        type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+        /// Distance from the consensus score beyond which a verification is an outlier
+        #[pallet::constant]
+        type OutlierDeviation: Get<u8>;
+
+        /// Distance from the consensus score within which a verification is accurate
+        #[pallet::constant]
+        type AccurateDeviation: Get<u8>;
+
+        /// Reputation a verifier earns for an accurate verification
+        #[pallet::constant]
+        type AccuracyBonus: Get<Score>;
+
+        /// Reputation a verifier loses for an outlying verification
+        #[pallet::constant]
+        type OutlierPenalty: Get<Score>;
+
+        /// Outstanding outlier strikes at which a verifier loses its verification rights
+        #[pallet::constant]
+        type MaxOutlierStrikes: Get<u32>;
+
+        /// Longest leave an account can announce
+        #[pallet::constant]
+        type MaxLeaveDuration: Get<Self::BlockNumber>;
+
+        /// Blocks between the starts of two leaves of the same account
+        #[pallet::constant]
+        type LeaveCooldown: Get<Self::BlockNumber>;
+
+        /// Leaves remembered per account; older ones stop excusing decay
+        #[pallet::constant]
+        type MaxLeavesRecorded: Get<u32>;
+
+        /// Blocks between automatic commitments to the reputation scores; zero disables them
+        #[pallet::constant]
+        type SnapshotEpoch: Get<Self::BlockNumber>;
+
+        /// Highest scores listed in a score commitment
+        #[pallet::constant]
+        type SnapshotTopK: Get<u32>;
+
+        /// Most scored accounts a score commitment can cover
+        #[pallet::constant]
+        type MaxSnapshotAccounts: Get<u32>;
+
+        /// Key the off-chain worker submits signed EVM attestations with
+        type EvmAttestationAuthorityId: AppCrypto<Self::Public, Self::Signature>;
+
+        /// Seconds an EVM attestation stays valid after it was requested
+        #[pallet::constant]
+        type EvmAttestationValidity: Get<u64>;
+
+        /// Most commits in an imported git log
+        #[pallet::constant]
+        type MaxGitLogEntries: Get<u32>;
+
+        /// Share of the usual points awarded for a contribution imported from a git log,
+        /// until governance sets `ImportPointsPercents`
+        #[pallet::constant]
+        type GitImportPointsPercent: Get<Percent>;
+
+        /// Most contributions in one batch of a bulk import
+        #[pallet::constant]
+        type MaxImportBatch: Get<u32>;
+
+        /// Share of the usual points awarded for a bulk-imported historical contribution,
+        /// until governance sets `ImportPointsPercents`
+        #[pallet::constant]
+        type ImportedPointsPercent: Get<Percent>;
+
+        /// Verified-human identities, each of which can claim the starter grant once
+        type Personhood: PersonhoodProvider<Self::AccountId>;
+
+        /// Period over which `MaxStarterGrantsPerPeriod` applies
+        #[pallet::constant]
+        type StarterGrantPeriod: Get<Self::BlockNumber>;
+
+        /// Starter grants claimable per `StarterGrantPeriod` across all accounts
+        #[pallet::constant]
+        type MaxStarterGrantsPerPeriod: Get<u32>;
+
+        /// Accounts whose time decay the decay sweep projects per block; zero disables it
+        #[pallet::constant]
+        type DecaySweepAccountsPerBlock: Get<u32>;
+
+        /// Blocks ahead the decay sweep projects each score
+        #[pallet::constant]
+        type DecayWarningWindow: Get<Self::BlockNumber>;
+
+        /// Share of a score that, when projected to decay within `DecayWarningWindow`,
+        /// raises a `DecayWarning`
+        #[pallet::constant]
+        type DecayWarningThreshold: Get<Percent>;
+
+        /// Blocks a remote chain's reputation stays in `RemoteReputation` before the
+        /// off-chain worker has it removed
+        #[pallet::constant]
+        type RemoteReputationTtl: Get<Self::BlockNumber>;
+
+        /// Origin of messages relayed by a registered chain, resolving to its id; e.g. XCM
+        /// origins converted by location
+        type ChainOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = ChainId>;
     }
 
     /// Weight information for extrinsics
     pub trait WeightInfo {
         fn add_contribution() -> Weight;
+        fn add_contribution_rejected() -> Weight;
         fn verify_contribution() -> Weight;
+        fn verify_contribution_pending() -> Weight;
         fn update_algorithm_params() -> Weight;
     }
 
+    /// v1 holds a deposit for every stored contribution, v2 weighs mentorship sessions,
+    /// v3 keeps the contribution search indexes in double maps
+    pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
 
-    /// Contribution types supported by the reputation system
-    #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
-    #[scale_info(skip_type_params(T))]
-    pub enum ContributionType {
-        IssueComment,
-        PullRequest,
-        CodeReview,
-        Documentation,
-        BugReport,
-        CodeCommit,
-    }
-
     /// Data source for contributions
     #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
     #[scale_info(skip_type_params(T))]
@@ -184,6 +489,8 @@ pub mod pallet {
         Verified,
         Disputed,
         Rejected,
+        /// Bulk-imported history awaiting `verify_imported_contribution`
+        Imported,
     }
 
     /// Contribution data structure
@@ -202,7 +509,462 @@ pub mod pallet {
     }
 
     /// Contribution ID type
-    pub type ContributionId = u64;
+    pub type ContributionId = dotrep_primitives::ContributionId;
+
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    pub type NegativeImbalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
+
+    /// Co-authors of a contribution and the share of its reputation each receives
+    pub type CoAuthorsOf<T> = BoundedVec<
+        (<T as frame_system::Config>::AccountId, Percent),
+        <T as Config>::MaxCoAuthors,
+    >;
+
+    /// Maximum skill tags of one contribution
+    pub const MAX_CONTRIBUTION_SKILLS: u32 = 5;
+
+    /// Skills a contribution is tagged with
+    pub type ContributionSkillsOf = BoundedVec<SkillTag, ConstU32<MAX_CONTRIBUTION_SKILLS>>;
+
+    /// Most pooled verifiers `expected_review_latency` counts the capacity of
+    pub const MAX_LATENCY_VERIFIERS: u32 = 100;
+
+    /// Project ID type
+    pub type ProjectId = u32;
+
+    /// Repository URL of a project, e.g. `https://github.com/paritytech/substrate`
+    pub type ProjectRepository = BoundedVec<u8, ConstU32<128>>;
+
+    /// Why a verifier rejected a contribution
+    #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum RejectionReason {
+        /// The proof does not point at the claimed work
+        InvalidProof,
+        /// The work was already submitted as another contribution
+        Duplicate,
+        /// The work is not the contributor's own
+        NotOriginal,
+        /// The work is too small or too poor for its claimed weight
+        LowQuality,
+        Spam,
+        Other,
+    }
+
+    /// Feedback a verifier leaves with a rejection
+    pub type RejectionComment = BoundedVec<u8, ConstU32<256>>;
+
+    /// Contributor's reply to a verification
+    pub type VerificationResponse = BoundedVec<u8, ConstU32<256>>;
+
+    /// Key of a contribution in a search index, big-endian `ContributionId::MAX - id` so
+    /// that iteration lists the newest contributions first
+    pub type ContributionIndexKey = [u8; 8];
+
+    /// Whether a project's owner proved control of its repository
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum ProjectStatus {
+        Unverified,
+        Verified,
+    }
+
+    /// Project whose maintainers co-sign contributions to it
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Project<AccountId, BlockNumber> {
+        pub owner: AccountId,
+        pub repository: ProjectRepository,
+        pub registered_at: BlockNumber,
+        pub status: ProjectStatus,
+    }
+
+    /// Digest of a contributor's purged contribution records
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct ContributionArchive<BlockNumber> {
+        /// Merkle root over the archived records, the previous archive's root first
+        pub root: H256,
+        /// Contributions archived in total
+        pub contributions: u32,
+        /// Block of the latest purge
+        pub archived_at: BlockNumber,
+    }
+
+    /// Hackathon ID type
+    pub type HackathonId = u32;
+
+    /// Time-boxed event whose contributions compete for a reward pot
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Hackathon<T: Config> {
+        /// Account that created the event and holds the pot in reserve
+        pub organizer: T::AccountId,
+        /// First block contributions can be entered
+        pub start: T::BlockNumber,
+        /// Last block contributions can be entered
+        pub end: T::BlockNumber,
+        /// Projects whose contributions can be entered
+        pub projects: BoundedVec<ProjectId, T::MaxHackathonProjects>,
+        /// Reward shared by the leaderboard at close
+        pub pot: BalanceOf<T>,
+        pub closed: bool,
+    }
+
+    /// Event-local points of a hackathon's leading participants, highest first
+    pub type LeaderboardOf<T> = BoundedVec<
+        (<T as frame_system::Config>::AccountId, u32),
+        <T as Config>::MaxHackathonWinners,
+    >;
+
+    /// Mentorship ID type
+    pub type MentorshipId = u32;
+
+    /// Stage of a mentorship
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum MentorshipStatus {
+        /// Registered by the mentor, awaiting the mentee's acceptance
+        Proposed,
+        /// Accepted; the mentee can attest sessions
+        Active,
+        /// Signed off by both mentor and mentee
+        Completed,
+        /// Rejected or ended early by either party; no more sessions can be attested
+        Ended,
+    }
+
+    /// Mentor–mentee pair whose sessions earn the mentor reputation
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Mentorship<AccountId, BlockNumber> {
+        pub mentor: AccountId,
+        pub mentee: AccountId,
+        pub status: MentorshipStatus,
+        /// Sessions attested by the mentee
+        pub sessions: u32,
+        pub registered_at: BlockNumber,
+        /// Party who signed off on completion first, awaiting the other
+        pub signed_off_by: Option<AccountId>,
+    }
+
+    /// Points an account earned per contribution type and per project
+    #[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+    pub struct ReputationBreakdown {
+        pub by_type: Vec<(ContributionType, Score)>,
+        /// Only contributions assigned to a project count here
+        pub by_project: Vec<(ProjectId, Score)>,
+    }
+
+    /// Most entries one `work_history` page returns
+    pub const MAX_WORK_HISTORY_PAGE: u32 = 100;
+
+    /// Most ids one page of a contribution search index returns
+    pub const MAX_CONTRIBUTION_INDEX_PAGE: u32 = 100;
+
+    /// Most search index entries one page scans when filtering
+    pub const MAX_CONTRIBUTION_INDEX_SCAN: u32 = 1_000;
+
+    /// Blocks after its due block searched for room to schedule a verification deadline
+    pub const DEADLINE_SLOT_SEARCH: u32 = 8;
+
+    /// Verified contribution as listed in an account's work history
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+    pub struct WorkHistoryEntry<AccountId, BlockNumber> {
+        pub contribution_id: ContributionId,
+        pub proof: H256,
+        pub contribution_type: ContributionType,
+        pub weight: u8,
+        pub source: DataSource,
+        pub submitted_at: BlockNumber,
+        pub project: Option<ProjectId>,
+        pub skills: Vec<SkillTag>,
+        /// Points the contribution earns under the current parameters
+        pub points: Score,
+        /// Verifiers and the score (0-100) each gave
+        pub verifications: Vec<(AccountId, u8)>,
+    }
+
+    /// How a verifier's scores compared with the consensus of the contributions it verified
+    #[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct VerifierRecord {
+        /// Verifications of contributions finalized with a consensus of several verifiers
+        pub finalized: u32,
+        /// Sum of the distances of those verifications from the consensus
+        pub total_deviation: u32,
+        /// Outlying verifications not yet offset by accurate ones
+        pub strikes: u32,
+    }
+
+    impl VerifierRecord {
+        /// Average distance of the verifier's scores from the consensus
+        pub fn average_deviation(&self) -> u32 {
+            self.total_deviation.checked_div(self.finalized).unwrap_or_default()
+        }
+    }
+
+    /// Commitment to all reputation scores at a block
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct ScoreCommitment<T: Config> {
+        /// Block the commitment started; it is built over the following blocks, so a score
+        /// that changed meanwhile may be committed at its later value
+        pub block: T::BlockNumber,
+        /// Merkle root of the `ReputationScores` entries, see [`crate::snapshot`]
+        pub root: H256,
+        /// Number of committed entries
+        pub accounts: u32,
+        /// Highest scores, best first
+        pub top: BoundedVec<(T::AccountId, Score), T::SnapshotTopK>,
+    }
+
+    /// Score commitment being built, see [`crate::snapshot`]
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct PendingScoreCommitment<T: Config> {
+        pub started_at: T::BlockNumber,
+        /// Raw `ReputationScores` key of the last committed entry; empty before the first
+        pub cursor: BoundedVec<u8, ConstU32<128>>,
+        pub frontier: crate::archive::MerkleFrontier,
+        pub accounts: u32,
+        pub top: BoundedVec<(T::AccountId, Score), T::SnapshotTopK>,
+    }
+
+    /// Reputation export to an EVM chain awaiting the chain's signature
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct EvmExport {
+        pub nonce: u64,
+        /// Bridge contract the attestation is addressed to
+        pub bridge: H160,
+        /// EVM address of the exported account, see [`crate::evm_bridge::evm_address_of`]
+        pub address: H160,
+        pub score: Score,
+        /// Unix time in seconds after which the attestation is invalid
+        pub expiry: u64,
+    }
+
+    /// Commit of an imported git log and its first parent; the root commit's parent is zero
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct GitCommitLink {
+        pub commit: [u8; 20],
+        pub parent: [u8; 20],
+    }
+
+    /// First-parent history submitted for a contribution to a self-hosted forge, newest first
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct GitImport<T: Config> {
+        pub commits: BoundedVec<GitCommitLink, T::MaxGitLogEntries>,
+        /// Whether the off-chain worker found the commits to form a hash chain
+        pub validated: bool,
+    }
+
+    /// Export of a project's history governance approved for bulk import
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct BulkImport<AccountId> {
+        /// Account allowed to submit the export's batches
+        pub importer: AccountId,
+        pub project_id: ProjectId,
+        /// Contributions in the export; batches cannot import more
+        pub max_items: u32,
+        pub imported: u32,
+    }
+
+    /// Historical contribution in a bulk import batch
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct ImportItem<AccountId> {
+        pub contributor: AccountId,
+        pub proof: H256,
+        pub contribution_type: ContributionType,
+        pub weight: u8,
+    }
+
+    /// Bulk-imported contribution waiting for its contributor to accept it
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct UnacceptedImport {
+        pub export_digest: H256,
+        pub contribution_type: ContributionType,
+        pub weight: u8,
+    }
+
+    /// Decay projected for an account by the decay sweep
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct DecayWarning<BlockNumber> {
+        /// Score when the warning was raised
+        pub score: Score,
+        /// Score the account will have at `at` unless it stays active
+        pub projected: Score,
+        pub at: BlockNumber,
+    }
+
+    /// Most items a contribution bundle may commit to
+    pub const MAX_BUNDLE_ITEMS: u32 = 1_000;
+
+    /// Many small contributions, e.g. commits across a monorepo, verified together
+    ///
+    /// The bundle's contribution carries the aggregate weight and has `root` as its
+    /// proof. Each item proof is registered in `ContributionsByProof` like any other
+    /// proof and stays provable against `root` with
+    /// [`crate::archive::verify_merkle_proof`].
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct ContributionBundle {
+        /// Merkle root of the item proofs
+        pub root: H256,
+        pub items: u32,
+    }
+
+    /// Name of a registered attestor, e.g. an audit firm
+    pub type AttestorName = BoundedVec<u8, ConstU32<64>>;
+
+    /// What an attestation states, e.g. `passed security training`
+    pub type AttestationClaim = BoundedVec<u8, ConstU32<128>>;
+
+    /// Identifier of an attestation
+    pub type AttestationId = u64;
+
+    /// Most attestations an account holds at once
+    pub const MAX_ATTESTATIONS_PER_ACCOUNT: u32 = 16;
+
+    /// Statement a registered attestor issued about an account
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Attestation<AccountId, BlockNumber> {
+        pub attestor: AccountId,
+        pub claim: AttestationClaim,
+        /// Reputation boost in percent, before `AttestationMultiplier`
+        pub weight: u8,
+        pub issued_at: BlockNumber,
+        /// First block the attestation no longer counts
+        pub expires_at: BlockNumber,
+    }
+
+    /// Review capacity a verifier offered with `opt_in_verifier`
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct VerifierAvailability {
+        /// Reviews the verifier takes on per `VerifierEra`
+        pub capacity_per_era: u32,
+        /// Skills the verifier wants to review; any of its declared skills if empty
+        pub skills: ContributionSkillsOf,
+        /// Era `reviews` were counted in
+        pub era: u32,
+        pub reviews: u32,
+    }
+
+    impl VerifierAvailability {
+        /// Reviews the verifier still takes on in `era`
+        pub fn remaining(&self, era: u32) -> u32 {
+            if self.era == era {
+                self.capacity_per_era.saturating_sub(self.reviews)
+            } else {
+                self.capacity_per_era
+            }
+        }
+
+        /// Count a review done in `era`
+        pub fn note_review(&mut self, era: u32) {
+            if self.era != era {
+                self.era = era;
+                self.reviews = 0;
+            }
+            self.reviews = self.reviews.saturating_add(1);
+        }
+
+        /// Whether the verifier wants to review work tagged with `skill`
+        pub fn covers(&self, skill: &SkillTag) -> bool {
+            self.skills.is_empty() || self.skills.contains(skill)
+        }
+    }
+
+    /// Account on a remote chain, encoded as that chain encodes it
+    pub type RemoteAccount = BoundedVec<u8, ConstU32<64>>;
+
+    /// Signature of a remote account over its link challenge
+    ///
+    /// 32-byte remote accounts sign with their sr25519 or ed25519 key, 20-byte (EVM)
+    /// accounts with ECDSA over the keccak-256 hash of the challenge.
+    pub type RemoteSignature = sp_runtime::MultiSignature;
+
+    /// Most remote accounts linked to one account
+    pub const MAX_LINKED_IDENTITIES: u32 = 8;
+
+    /// Remote accounts linked to an account as the same developer
+    pub type LinkedIdentitiesOf = BoundedVec<(ChainId, RemoteAccount), ConstU32<MAX_LINKED_IDENTITIES>>;
+
+    /// Most webhooks verification reminders are sent to
+    pub const MAX_NOTIFICATION_ENDPOINTS: u32 = 4;
+
+    /// Webhook URL verification reminders are POSTed to
+    pub type NotificationEndpoint = BoundedVec<u8, ConstU32<256>>;
+
+    /// Most entries one `reminder_queue` page holds, besides the rest of its last block
+    pub const MAX_REMINDER_PAGE: u32 = 100;
+
+    /// Most deadline blocks one `reminder_queue` page scans
+    pub const MAX_REMINDER_SCAN_BLOCKS: u32 = 1_000;
+
+    /// Most items one `sync_remote_reputation` call processes of each kind
+    pub const MAX_REMOTE_SYNC_BATCH: u32 = 20;
+
+    /// Ref time of serializing and hashing one contribution digest of a DKG assertion
+    pub const SNAPSHOT_DIGEST_WEIGHT: u64 = 200_000;
+
+    /// Reputation of an account on a remote chain, as answered to a cross-chain query
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct RemoteScore<BlockNumber> {
+        pub score: Score,
+        pub percentile: u8,
+        /// Block the answer was cached at
+        pub updated_at: BlockNumber,
+    }
+
+    /// Activity counters of an account, kept for Sybil heuristics, dashboards and
+    /// verifier-quality tracking
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, TypeInfo, MaxEncodedLen)]
+    pub struct ActivityStats<BlockNumber> {
+        /// Contributions submitted
+        pub submitted: u32,
+        /// Contributions currently verified; overturned ones move to `rejected`
+        pub verified: u32,
+        pub rejected: u32,
+        /// Verifications the account performed as a verifier
+        pub verifications_performed: u32,
+        /// Verification scores the account's contributions received, and their sum
+        pub scores_received: u32,
+        pub received_score_total: u64,
+        /// Block of the account's last submission or verification
+        pub last_active: BlockNumber,
+    }
+
+    impl<BlockNumber> ActivityStats<BlockNumber> {
+        /// Mean verification score received, if any was received
+        pub fn average_received_score(&self) -> Option<u8> {
+            self.received_score_total
+                .checked_div(self.scores_received as u64)
+                .map(|average| average.min(100) as u8)
+        }
+    }
+
+    /// Referral of a newcomer by an existing contributor
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Referral<AccountId, BlockNumber> {
+        pub referrer: AccountId,
+        pub referred_at: BlockNumber,
+        /// Whether the referrer has received `ReferralBonus`
+        pub rewarded: bool,
+    }
+
+    /// Submission rights a principal granted to a proxy
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct SubmissionProxy<BlockNumber> {
+        /// Submissions allowed per `ProxySubmissionPeriod`
+        pub max_submissions: u32,
+        /// Start of the current rate limit period
+        pub period_start: BlockNumber,
+        /// Submissions made in the current period
+        pub submissions: u32,
+    }
+
+    /// Width of a `ScoreDistribution` bucket in normalized credit score points
+    pub const SCORE_BUCKET_WIDTH: u16 = 10;
+
+    /// Number of `ScoreDistribution` buckets
+    pub const SCORE_BUCKETS: u32 = (MAX_CREDIT_SCORE / SCORE_BUCKET_WIDTH) as u32 + 1;
 
     /// Storage: Map of account to their reputation score
     #[pallet::storage]
@@ -211,7 +973,7 @@ pub mod pallet {
         _,
         Blake2_128Concat,
         T::AccountId,
-        i32,
+        Score,
         ValueQuery,
     >;
 
@@ -288,6 +1050,64 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Storage: Rejections of pending contributions, by contribution and verifier
+    #[pallet::storage]
+    #[pallet::getter(fn contribution_rejections)]
+    pub type ContributionRejections<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ContributionId,
+        Blake2_128Concat,
+        T::AccountId,
+        (RejectionReason, RejectionComment),
+        OptionQuery,
+    >;
+
+    /// Storage: Contributor's response to each verification, keyed as `ContributionVerifications`
+    #[pallet::storage]
+    #[pallet::getter(fn verification_response)]
+    pub type VerificationResponses<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ContributionId,
+        Blake2_128Concat,
+        T::AccountId,
+        VerificationResponse,
+        OptionQuery,
+    >;
+
+    /// Storage: Original score of each amended verification, keyed as `ContributionVerifications`
+    #[pallet::storage]
+    #[pallet::getter(fn amended_verification)]
+    pub type AmendedVerifications<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ContributionId,
+        Blake2_128Concat,
+        T::AccountId,
+        u8,
+        OptionQuery,
+    >;
+
+    /// Storage: Reputation-weighted median of the verification scores of each verified
+    /// contribution, in percent of its points that were awarded
+    ///
+    /// Contributions verified without scored verifications (by the off-chain worker, the
+    /// committee or governance) have no entry and are awarded in full.
+    #[pallet::storage]
+    #[pallet::getter(fn consensus_score)]
+    pub type ConsensusScores<T: Config> = StorageMap<_, Blake2_128Concat, ContributionId, u8, OptionQuery>;
+
+    /// Storage: Agreement of each verifier with the consensus of the contributions it verified
+    #[pallet::storage]
+    #[pallet::getter(fn verifier_record)]
+    pub type VerifierRecords<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, VerifierRecord, ValueQuery>;
+
+    /// Storage: Number of verifier rejections of each pending contribution
+    #[pallet::storage]
+    #[pallet::getter(fn rejection_count)]
+    pub type RejectionCounts<T: Config> = StorageMap<_, Blake2_128Concat, ContributionId, u32, ValueQuery>;
+
     /// Storage: Index for fast proof lookups
     #[pallet::storage]
     pub type ContributionsByProof<T: Config> = StorageMap<
@@ -298,39 +1118,735 @@ pub mod pallet {
         OptionQuery,
     >;
 
-    /// Storage: Algorithm parameters (governance-controlled)
+    /// Storage: Number of verified contributions per account
     #[pallet::storage]
-    pub type ReputationParams<T: Config> = StorageValue<_, AlgorithmParams, ValueQuery>;
+    pub type VerifiedContributionCounts<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        u32,
+        ValueQuery,
+    >;
 
-    /// Algorithm parameters for reputation calculation
-    #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
-    pub struct AlgorithmParams {
-        pub decay_rate_per_block: u32, // Parts per million per block
-        pub verification_multiplier: u32, // Basis points (10000 = 1.0x)
-        pub contribution_type_weights: BTreeMap<ContributionType, u32>,
-    }
+    /// Storage: Number of accounts with a positive score per credit score bucket
+    ///
+    /// Bucket `i` holds normalized scores from `i * SCORE_BUCKET_WIDTH` up to the next
+    /// bucket. Kept up to date on every `ReputationUpdated`, so a percentile costs one read.
+    #[pallet::storage]
+    pub type ScoreDistribution<T: Config> = StorageValue<_, BoundedVec<u32, ConstU32<SCORE_BUCKETS>>, ValueQuery>;
 
-    impl Default for AlgorithmParams {
-        fn default() -> Self {
-            let mut weights = BTreeMap::new();
-            weights.insert(ContributionType::PullRequest, 20);
-            weights.insert(ContributionType::CodeReview, 15);
-            weights.insert(ContributionType::CodeCommit, 10);
-            weights.insert(ContributionType::IssueComment, 5);
-            weights.insert(ContributionType::Documentation, 12);
-            weights.insert(ContributionType::BugReport, 8);
-            
-            Self {
-                decay_rate_per_block: 1, // 1 PPM per block
-                verification_multiplier: 15_000, // 1.5x
-                contribution_type_weights: weights,
-            }
-        }
-    }
+    /// Storage: Proxies allowed to submit contributions on behalf of a principal
+    #[pallet::storage]
+    #[pallet::getter(fn submission_proxy)]
+    pub type SubmissionProxies<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId, // principal
+        Blake2_128Concat,
+        T::AccountId, // proxy
+        SubmissionProxy<T::BlockNumber>,
+        OptionQuery,
+    >;
 
-    /// Reputation change reason for tracking and analytics
-    #[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-    #[scale_info(skip_type_params(T))]
+    /// Storage: Registered projects
+    #[pallet::storage]
+    #[pallet::getter(fn projects)]
+    pub type Projects<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ProjectId,
+        Project<T::AccountId, T::BlockNumber>,
+        OptionQuery,
+    >;
+
+    /// Storage: Index of projects by repository, keeping repositories unique
+    #[pallet::storage]
+    pub type ProjectsByRepository<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ProjectRepository,
+        ProjectId,
+        OptionQuery,
+    >;
+
+    /// Storage: Counter for generating unique project IDs
+    #[pallet::storage]
+    pub type NextProjectId<T: Config> = StorageValue<_, ProjectId, ValueQuery>;
+
+    /// Storage: Maintainers of each project (project -> maintainer -> block added)
+    #[pallet::storage]
+    pub type ProjectMaintainers<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ProjectId,
+        Blake2_128Concat,
+        T::AccountId,
+        T::BlockNumber,
+        OptionQuery,
+    >;
+
+    /// Storage: Co-authors of each co-authored contribution
+    #[pallet::storage]
+    #[pallet::getter(fn co_authors)]
+    pub type ContributionCoAuthors<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ContributionId,
+        CoAuthorsOf<T>,
+        ValueQuery,
+    >;
+
+    /// Storage: Deposit held for each contribution (depositor, amount)
+    ///
+    /// Contributions stored before deposits were introduced whose contributor could
+    /// not cover one have no entry.
+    #[pallet::storage]
+    #[pallet::getter(fn contribution_deposit)]
+    pub type ContributionDeposits<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ContributionId,
+        (T::AccountId, BalanceOf<T>),
+        OptionQuery,
+    >;
+
+    /// Storage: Block each contribution was verified in
+    #[pallet::storage]
+    #[pallet::getter(fn verified_at)]
+    pub type VerifiedAt<T: Config> = StorageMap<_, Blake2_128Concat, ContributionId, T::BlockNumber, OptionQuery>;
+
+    /// Storage: Digest of each account's purged contribution records
+    #[pallet::storage]
+    #[pallet::getter(fn contribution_archive)]
+    pub type ContributionArchives<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        ContributionArchive<T::BlockNumber>,
+        OptionQuery,
+    >;
+
+    /// Storage: Hackathons by ID
+    #[pallet::storage]
+    #[pallet::getter(fn hackathon)]
+    pub type Hackathons<T: Config> = StorageMap<_, Blake2_128Concat, HackathonId, Hackathon<T>, OptionQuery>;
+
+    /// Storage: Counter for generating unique hackathon IDs
+    #[pallet::storage]
+    pub type NextHackathonId<T: Config> = StorageValue<_, HackathonId, ValueQuery>;
+
+    /// Storage: Hackathon each entered contribution competes in
+    #[pallet::storage]
+    #[pallet::getter(fn contribution_hackathon)]
+    pub type ContributionHackathons<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ContributionId,
+        HackathonId,
+        OptionQuery,
+    >;
+
+    /// Storage: Event-local points of each hackathon participant
+    #[pallet::storage]
+    #[pallet::getter(fn hackathon_score)]
+    pub type HackathonScores<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        HackathonId,
+        Blake2_128Concat,
+        T::AccountId,
+        u32,
+        ValueQuery,
+    >;
+
+    /// Storage: Leaderboard of each hackathon
+    #[pallet::storage]
+    #[pallet::getter(fn hackathon_leaderboard)]
+    pub type HackathonLeaderboards<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        HackathonId,
+        LeaderboardOf<T>,
+        ValueQuery,
+    >;
+
+    /// Storage: Mentorships by ID
+    #[pallet::storage]
+    #[pallet::getter(fn mentorship)]
+    pub type Mentorships<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        MentorshipId,
+        Mentorship<T::AccountId, T::BlockNumber>,
+        OptionQuery,
+    >;
+
+    /// Storage: Counter for generating unique mentorship IDs
+    #[pallet::storage]
+    pub type NextMentorshipId<T: Config> = StorageValue<_, MentorshipId, ValueQuery>;
+
+    /// Storage: Mentorships each mentor completed
+    #[pallet::storage]
+    #[pallet::getter(fn completed_mentorships)]
+    pub type CompletedMentorships<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Storage: Referral of each referred account
+    #[pallet::storage]
+    #[pallet::getter(fn referral)]
+    pub type Referrals<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Referral<T::AccountId, T::BlockNumber>,
+        OptionQuery,
+    >;
+
+    /// Storage: Referrals offered to each newcomer and not accepted yet, with the block
+    /// they were made at
+    #[pallet::storage]
+    #[pallet::getter(fn referral_offer)]
+    pub type ReferralOffers<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        T::BlockNumber,
+        OptionQuery,
+    >;
+
+    /// Storage: Referrals each account made in its latest referral epoch, as (epoch, count)
+    #[pallet::storage]
+    pub type EpochReferrals<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (u32, u32), ValueQuery>;
+
+    /// Storage: Accounts whose detailed breakdown the runtime APIs serve to anyone
+    #[pallet::storage]
+    pub type PublicBreakdowns<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+    /// Storage: Accounts each contributor allowed to see its detailed breakdown (owner -> viewer)
+    #[pallet::storage]
+    pub type BreakdownViewers<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+        OptionQuery,
+    >;
+
+    /// Storage: Verifier committee approving contributions above `CommitteeVerificationThreshold`
+    #[pallet::storage]
+    #[pallet::getter(fn verifier_committee)]
+    pub type VerifierCommittee<T: Config> = StorageValue<_, BoundedVec<T::AccountId, T::MaxCommitteeSize>, ValueQuery>;
+
+    /// Storage: Committee members who approved each high-value contribution
+    #[pallet::storage]
+    #[pallet::getter(fn committee_approvals)]
+    pub type CommitteeApprovals<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ContributionId,
+        BoundedVec<T::AccountId, T::MaxCommitteeSize>,
+        ValueQuery,
+    >;
+
+    /// Storage: Reputation each account earned per dividend era, capped at `DividendEarningsCap`
+    ///
+    /// Entries are taken when the account claims its dividend for the era.
+    #[pallet::storage]
+    #[pallet::getter(fn era_earnings)]
+    pub type EraEarnings<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, u32, Blake2_128Concat, T::AccountId, Score, ValueQuery>;
+
+    /// Storage: Capped reputation all accounts earned per dividend era
+    #[pallet::storage]
+    #[pallet::getter(fn era_total_earnings)]
+    pub type EraTotalEarnings<T: Config> = StorageMap<_, Blake2_128Concat, u32, u64, ValueQuery>;
+
+    /// Storage: Era earnings each account was paid dividends for, with the amount paid,
+    /// less what was clawed back
+    #[pallet::storage]
+    #[pallet::getter(fn paid_dividends)]
+    pub type PaidDividends<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (Score, BalanceOf<T>), ValueQuery>;
+
+    /// Storage: Lost reputation not yet reversed from an account's dividends, taken off the
+    /// era earnings of its next claims
+    #[pallet::storage]
+    #[pallet::getter(fn dividend_debt)]
+    pub type DividendDebt<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, Score, ValueQuery>;
+
+    /// Storage: Contributions whose verification deadline or escalation falls in a block
+    #[pallet::storage]
+    pub type VerificationDeadlines<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::BlockNumber, BoundedVec<ContributionId, T::MaxDeadlinesPerBlock>, ValueQuery>;
+
+    /// Storage: Oldest block whose deadlines the hook quota left unprocessed; set while
+    /// deadline processing lags behind the current block
+    #[pallet::storage]
+    #[pallet::getter(fn deadline_backlog)]
+    pub type DeadlineBacklog<T: Config> = StorageValue<_, T::BlockNumber, OptionQuery>;
+
+    /// Storage: Last deadline block whose verification reminders were POSTed to the
+    /// webhooks, as reported by the off-chain workers
+    #[pallet::storage]
+    #[pallet::getter(fn reminders_sent_until)]
+    pub type RemindersSentUntil<T: Config> = StorageValue<_, T::BlockNumber, OptionQuery>;
+
+    /// Storage: Webhooks the off-chain worker POSTs verification reminders to
+    /// (governance-controlled); no reminders are sent while empty
+    #[pallet::storage]
+    #[pallet::getter(fn notification_endpoints)]
+    pub type NotificationEndpoints<T: Config> =
+        StorageValue<_, BoundedVec<NotificationEndpoint, ConstU32<MAX_NOTIFICATION_ENDPOINTS>>, ValueQuery>;
+
+    /// Storage: Pending contributions past their `VerificationDeadline`, by the block they went stale
+    ///
+    /// Verifying a stale contribution earns `StaleVerifierReward`.
+    #[pallet::storage]
+    #[pallet::getter(fn stale_contribution)]
+    pub type StaleContributions<T: Config> = StorageMap<_, Blake2_128Concat, ContributionId, T::BlockNumber, OptionQuery>;
+
+    /// Storage: Stale contributions left pending for `EscalationPeriod`, awaiting triage by
+    /// `UpdateOrigin`, by the block they were escalated
+    #[pallet::storage]
+    #[pallet::getter(fn escalated_contribution)]
+    pub type EscalatedContributions<T: Config> = StorageMap<_, Blake2_128Concat, ContributionId, T::BlockNumber, OptionQuery>;
+
+    /// Storage: Fee-less calls each account made in its latest fee-less era, as (era, count)
+    #[pallet::storage]
+    pub type FeelessCalls<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (u32, u32), ValueQuery>;
+
+    /// Storage: Block at which each account's latest probation ends
+    ///
+    /// On probation an account earns half the points for its contributions, cannot
+    /// verify others' and cannot create governance proposals.
+    #[pallet::storage]
+    #[pallet::getter(fn probation_end)]
+    pub type ProbationEnds<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::BlockNumber, OptionQuery>;
+
+    /// Storage: Announced leaves of each account, as (start, end), oldest first
+    ///
+    /// Blocks on leave do not count towards the age of the account's contributions
+    /// when their time decay is computed.
+    #[pallet::storage]
+    #[pallet::getter(fn leave_periods)]
+    pub type LeavePeriods<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<(T::BlockNumber, T::BlockNumber), T::MaxLeavesRecorded>,
+        ValueQuery,
+    >;
+
+    /// Storage: Latest commitment to the reputation scores
+    #[pallet::storage]
+    #[pallet::getter(fn score_commitment)]
+    pub type LatestScoreCommitment<T: Config> = StorageValue<_, ScoreCommitment<T>, OptionQuery>;
+
+    /// Storage: Score commitment `on_initialize` is building
+    #[pallet::storage]
+    pub type ScoreCommitmentInProgress<T: Config> = StorageValue<_, PendingScoreCommitment<T>, OptionQuery>;
+
+    /// Storage: Git logs of contributions imported from self-hosted forges
+    ///
+    /// Imported contributions are not verified until a maintainer of their project has
+    /// attested them and the off-chain worker has validated the log.
+    #[pallet::storage]
+    #[pallet::getter(fn git_import)]
+    pub type GitImports<T: Config> = StorageMap<_, Blake2_128Concat, ContributionId, GitImport<T>, OptionQuery>;
+
+    /// Storage: Bulk imports approved by governance, by the digest of their export
+    #[pallet::storage]
+    #[pallet::getter(fn bulk_import)]
+    pub type BulkImports<T: Config> = StorageMap<_, Blake2_128Concat, H256, BulkImport<T::AccountId>, OptionQuery>;
+
+    /// Storage: Export digest each bulk-imported contribution was imported from
+    #[pallet::storage]
+    #[pallet::getter(fn imported_from)]
+    pub type ImportedContributions<T: Config> = StorageMap<_, Blake2_128Concat, ContributionId, H256, OptionQuery>;
+
+    /// Storage: Bulk-imported contributions not yet accepted, by the contributor the
+    /// importer named and proof; they reserve neither the proof nor a contribution slot
+    #[pallet::storage]
+    #[pallet::getter(fn unaccepted_import)]
+    pub type UnacceptedImports<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, H256, UnacceptedImport, OptionQuery>;
+
+    /// Storage: Reputation granted once to each verified human (governance-controlled);
+    /// zero disables the grant
+    #[pallet::storage]
+    #[pallet::getter(fn starter_grant)]
+    pub type StarterGrant<T: Config> = StorageValue<_, Score, ValueQuery>;
+
+    /// Storage: Account that claimed the starter grant of each verified-human identity
+    #[pallet::storage]
+    #[pallet::getter(fn starter_grant_claim)]
+    pub type StarterGrantClaims<T: Config> = StorageMap<_, Blake2_128Concat, PersonhoodId, T::AccountId, OptionQuery>;
+
+    /// Storage: Starter grants claimed in the current period, as (period, count)
+    #[pallet::storage]
+    pub type StarterGrantsIssued<T: Config> = StorageValue<_, (u32, u32), ValueQuery>;
+
+    /// Storage: Raw `AccountContributions` key of the last account the decay sweep visited
+    #[pallet::storage]
+    pub type DecaySweepCursor<T: Config> = StorageValue<_, BoundedVec<u8, ConstU32<128>>, OptionQuery>;
+
+    /// Storage: Cursor of the v1 deposit migration while `on_initialize` runs it
+    #[pallet::storage]
+    pub type DepositMigrationCursor<T: Config> = StorageValue<_, crate::migrations::MigrationCursor, OptionQuery>;
+
+    /// Storage: Cursor of the v3 search index migration while `on_initialize` runs it
+    #[pallet::storage]
+    pub type IndexMigrationCursor<T: Config> = StorageValue<_, crate::migrations::MigrationCursor, OptionQuery>;
+
+    /// Storage: Accounts whose score the decay sweep projects to drop by at least
+    /// `DecayWarningThreshold` within `DecayWarningWindow`
+    #[pallet::storage]
+    #[pallet::getter(fn decay_warning)]
+    pub type DecayWarnings<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, DecayWarning<T::BlockNumber>, OptionQuery>;
+
+    /// Storage: Bundle committed to by each bundled contribution
+    #[pallet::storage]
+    #[pallet::getter(fn contribution_bundle)]
+    pub type ContributionBundles<T: Config> =
+        StorageMap<_, Blake2_128Concat, ContributionId, ContributionBundle, OptionQuery>;
+
+    /// Storage: Item proofs of each bundled contribution, released if it is withdrawn
+    ///
+    /// Kept after pruning and purging, like the bundle's own proof.
+    #[pallet::storage]
+    pub type BundleItemProofs<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, ContributionId, Identity, H256, (), OptionQuery>;
+
+    /// Storage: External attestors (audit firms, foundations) governance registered
+    #[pallet::storage]
+    #[pallet::getter(fn attestor)]
+    pub type Attestors<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, AttestorName, OptionQuery>;
+
+    /// Storage: Attestations about each account, at most `MAX_ATTESTATIONS_PER_ACCOUNT`
+    #[pallet::storage]
+    #[pallet::getter(fn attestation)]
+    pub type Attestations<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        AttestationId,
+        Attestation<T::AccountId, T::BlockNumber>,
+        OptionQuery,
+    >;
+
+    /// Storage: Attestation ID counter
+    #[pallet::storage]
+    pub type NextAttestationId<T: Config> = StorageValue<_, AttestationId, ValueQuery>;
+
+    /// Storage: Share of the attestation weights applied as a reputation boost
+    /// (governance-controlled); attestations do not count while it is zero
+    #[pallet::storage]
+    #[pallet::getter(fn attestation_multiplier)]
+    pub type AttestationMultiplier<T: Config> = StorageValue<_, Percent, ValueQuery>;
+
+    /// Storage: Share of the maximum block weight each hook sweep may use
+    /// (governance-controlled); tasks without an entry use their default quota
+    #[pallet::storage]
+    pub type HookQuotas<T: Config> = StorageMap<_, Blake2_128Concat, HookTask, Perbill, OptionQuery>;
+
+    /// Storage: Weight each hook task used in the block it was last run, across pallets
+    #[pallet::storage]
+    pub type HookUsage<T: Config> =
+        StorageMap<_, Blake2_128Concat, HookTask, (T::BlockNumber, Weight), OptionQuery>;
+
+    /// Storage: Activity counters of each account
+    #[pallet::storage]
+    #[pallet::getter(fn activity_stats)]
+    pub type AccountActivity<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, ActivityStats<T::BlockNumber>, ValueQuery>;
+
+    /// Storage: Reputation of remote accounts answered to cross-chain queries, kept for
+    /// `RemoteReputationTtl` blocks
+    #[pallet::storage]
+    #[pallet::getter(fn remote_reputation)]
+    pub type RemoteReputation<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ChainId,
+        Blake2_128Concat,
+        RemoteAccount,
+        RemoteScore<T::BlockNumber>,
+        OptionQuery,
+    >;
+
+    /// Storage: Challenges of pending identity links (account -> (chain, remote account) -> challenge)
+    ///
+    /// The remote account signs the challenge on its chain, which relays the proof
+    /// over XCM; alternatively a registered attestor vouches for the link.
+    #[pallet::storage]
+    #[pallet::getter(fn link_challenge)]
+    pub type LinkChallenges<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        (ChainId, RemoteAccount),
+        H256,
+        OptionQuery,
+    >;
+
+    /// Storage: Remote accounts proved to belong to the same developer as each account
+    #[pallet::storage]
+    #[pallet::getter(fn linked_identities)]
+    pub type LinkedIdentities<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, LinkedIdentitiesOf, ValueQuery>;
+
+    /// Storage: Account each linked remote account belongs to
+    #[pallet::storage]
+    #[pallet::getter(fn identity_owner)]
+    pub type IdentityOwners<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ChainId,
+        Blake2_128Concat,
+        RemoteAccount,
+        T::AccountId,
+        OptionQuery,
+    >;
+
+    /// Storage: Share of the cached reputation of linked remote accounts counted in
+    /// `aggregate_reputation` (governance-controlled); remote reputation is ignored
+    /// while it is zero
+    #[pallet::storage]
+    #[pallet::getter(fn remote_reputation_weight)]
+    pub type RemoteReputationWeight<T: Config> = StorageValue<_, Percent, ValueQuery>;
+
+    /// Storage: Whether the contribution pipeline is paused
+    ///
+    /// While paused, contributions cannot be submitted or verified and the off-chain
+    /// worker submits nothing.
+    #[pallet::storage]
+    #[pallet::getter(fn is_paused)]
+    pub type Paused<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    /// Storage: Bridge contract of each EVM chain reputation can be exported to, by chain id
+    #[pallet::storage]
+    #[pallet::getter(fn evm_chain_bridge)]
+    pub type EvmChains<T: Config> = StorageMap<_, Twox64Concat, u64, H160, OptionQuery>;
+
+    /// Storage: EVM address of the key that signs attestations, trusted by the bridge contracts
+    #[pallet::storage]
+    #[pallet::getter(fn evm_attestation_signer)]
+    pub type EvmAttestationSigner<T: Config> = StorageValue<_, H160, OptionQuery>;
+
+    #[pallet::storage]
+    pub type NextEvmExportNonce<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Storage: Unsigned export of each account to each EVM chain; a new request replaces it
+    #[pallet::storage]
+    #[pallet::getter(fn pending_evm_export)]
+    pub type PendingEvmExports<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, u64, EvmExport, OptionQuery>;
+
+    /// Storage: Skill tags of each tagged contribution
+    #[pallet::storage]
+    #[pallet::getter(fn contribution_skills)]
+    pub type ContributionSkills<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ContributionId,
+        ContributionSkillsOf,
+        ValueQuery,
+    >;
+
+    /// Storage: Verifiers who opted in to reviewing, with the capacity they offered
+    ///
+    /// Only these are routed pending contributions by `eligible_verifiers`.
+    #[pallet::storage]
+    #[pallet::getter(fn verifier_availability)]
+    pub type VerifierPool<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, VerifierAvailability, OptionQuery>;
+
+    /// Storage: Accounts that declared each skill in governance (skill -> account)
+    ///
+    /// Maintained through `OnSkillTagsUpdated`; used to route pending contributions
+    /// to skill-matched verifiers.
+    #[pallet::storage]
+    pub type SkillVerifiers<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        SkillTag,
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+        OptionQuery,
+    >;
+
+    /// Storage: Open repository ownership challenges (project -> token, block issued)
+    #[pallet::storage]
+    #[pallet::getter(fn ownership_challenge)]
+    pub type OwnershipChallenges<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ProjectId,
+        (H256, T::BlockNumber),
+        OptionQuery,
+    >;
+
+    /// Storage: Project each contribution was made to
+    #[pallet::storage]
+    #[pallet::getter(fn contribution_project)]
+    pub type ContributionProjects<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ContributionId,
+        ProjectId,
+        OptionQuery,
+    >;
+
+    /// Storage: Contributions to each project, newest first
+    #[pallet::storage]
+    pub type ContributionsOfProject<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, ProjectId, Identity, ContributionIndexKey, (), OptionQuery>;
+
+    /// Storage: Contributions of each type, newest first
+    #[pallet::storage]
+    pub type ContributionsOfType<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, ContributionType, Identity, ContributionIndexKey, (), OptionQuery>;
+
+    /// Storage: Maintainer who attested each contribution
+    #[pallet::storage]
+    #[pallet::getter(fn maintainer_attestation)]
+    pub type MaintainerAttestations<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ContributionId,
+        T::AccountId,
+        OptionQuery,
+    >;
+
+    /// Storage: Algorithm parameters (governance-controlled)
+    #[pallet::storage]
+    pub type ReputationParams<T: Config> = StorageValue<_, AlgorithmParams, ValueQuery>;
+
+    /// Algorithm parameters for reputation calculation
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+    pub struct AlgorithmParams {
+        pub decay_rate_per_block: u32, // Parts per million per block
+        pub verification_multiplier: u32, // Basis points (10000 = 1.0x)
+        pub contribution_type_weights: BTreeMap<ContributionType, u32>,
+    }
+
+    impl Default for AlgorithmParams {
+        fn default() -> Self {
+            let mut weights = BTreeMap::new();
+            weights.insert(ContributionType::PullRequest, 20);
+            weights.insert(ContributionType::CodeReview, 15);
+            weights.insert(ContributionType::CodeCommit, 10);
+            weights.insert(ContributionType::IssueComment, 5);
+            weights.insert(ContributionType::Documentation, 12);
+            weights.insert(ContributionType::BugReport, 8);
+            weights.insert(ContributionType::Mentorship, 10);
+            
+            Self {
+                decay_rate_per_block: 1, // 1 PPM per block
+                verification_multiplier: 15_000, // 1.5x
+                contribution_type_weights: weights,
+            }
+        }
+    }
+
+    /// Storage: Version of the score algorithm, bumped by every structural change: of
+    /// `ReputationParams`, the attestation multiplier, the remote reputation weight or
+    /// the import points percents
+    #[pallet::storage]
+    #[pallet::getter(fn algorithm_version)]
+    pub type AlgorithmVersion<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Storage: Whether structural changes are refused (governance-controlled), so
+    /// consumers can rely on the current version's semantics
+    #[pallet::storage]
+    #[pallet::getter(fn compatibility_mode)]
+    pub type CompatibilityMode<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    /// Storage: Shares of the usual points awarded for imported contributions
+    /// (governance-controlled); `GitImportPointsPercent` and `ImportedPointsPercent` until set
+    #[pallet::storage]
+    pub type ImportPointsPercents<T: Config> = StorageValue<_, ImportPoints, OptionQuery>;
+
+    /// Shares of the usual points awarded for imported contributions
+    #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct ImportPoints {
+        /// For contributions imported from a git log
+        pub git_import: Percent,
+        /// For bulk-imported historical contributions
+        pub imported: Percent,
+    }
+
+    /// Version of the score algorithm served to external consumers
+    #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct AlgorithmVersionInfo {
+        pub version: u32,
+        /// While set, the version and therefore the meaning of scores cannot change
+        pub compatibility_mode: bool,
+    }
+
+    /// Storage: Contribution pipeline limits (governance-controlled)
+    #[pallet::storage]
+    #[pallet::getter(fn pipeline_config)]
+    pub type PipelineParams<T: Config> = StorageValue<_, PipelineConfig, ValueQuery>;
+
+    /// Limits of the contribution pipeline and cross-chain queries
+    #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+    pub struct PipelineConfig {
+        /// Items accepted by a batch call or batch cross-chain query
+        pub max_batch_size: u32,
+        /// Blocks a cross-chain reputation query waits for its response
+        pub query_timeout: u32,
+    }
+
+    impl PipelineConfig {
+        /// Largest batch governance may allow
+        pub const MAX_BATCH_SIZE: u32 = 100;
+    }
+
+    impl Default for PipelineConfig {
+        fn default() -> Self {
+            Self { max_batch_size: 10, query_timeout: 100 }
+        }
+    }
+
+    #[pallet::genesis_config]
+    pub struct GenesisConfig<T: Config> {
+        /// Accounts starting with a reputation score (clamped to the configured bounds)
+        pub initial_reputation: Vec<(T::AccountId, Score)>,
+        /// Contribution pipeline limits
+        pub pipeline: PipelineConfig,
+    }
+
+    #[cfg(feature = "std")]
+    impl<T: Config> Default for GenesisConfig<T> {
+        fn default() -> Self {
+            Self { initial_reputation: Vec::new(), pipeline: PipelineConfig::default() }
+        }
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+        fn build(&self) {
+            for (account, score) in &self.initial_reputation {
+                let score = (*score).clamp(T::MinReputation::get(), T::MaxReputation::get());
+                ReputationScores::<T>::insert(account, score);
+                Pallet::<T>::record_score_change(0, score);
+            }
+
+            assert!(Pallet::<T>::is_valid_pipeline_config(&self.pipeline), "Genesis pipeline config out of bounds");
+            PipelineParams::<T>::put(self.pipeline);
+        }
+    }
+
+    /// Reputation change reason for tracking and analytics
+    #[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
     pub enum RepChangeReason {
         NewContribution,
         VerificationReward,
@@ -338,147 +1854,3961 @@ pub mod pallet {
         SybilPenalty,
         GovernanceVote,
         AlgorithmUpdate,
+        VerificationOverturned,
+        ClaimUpheld,
+        ClaimRejected,
+        ReferralBonus,
+        ReferralClawback,
+        StaleVerificationBonus,
+        VerificationAccuracy,
+        VerificationOutlier,
+        StarterGrant,
+    }
+
+    // Pallets use events to inform users when important changes are made.
+    // Events are indexed under the accounts and ids they concern (see `Event::topics`).
+    #[pallet::event]
+    pub enum Event<T: Config> {
+        /// New contribution submitted for verification
+        ContributionSubmitted {
+            contributor: T::AccountId,
+            contribution_id: ContributionId,
+            proof_hash: H256,
+            contribution_type: ContributionType,
+            source: DataSource,
+        },
+        /// Contribution has been verified
+        ContributionVerified {
+            contributor: T::AccountId,
+            contribution_id: ContributionId,
+            verifier: T::AccountId,
+            score: u8,
+            reputation_gained: Score,
+        },
+        /// Reputation score updated
+        ReputationUpdated {
+            account: T::AccountId,
+            old_score: Score,
+            new_score: Score,
+            change_reason: RepChangeReason,
+        },
+        /// Sybil attack detected
+        SybilAttackDetected {
+            account: T::AccountId,
+            contribution_id: ContributionId,
+            detection_reason: Vec<u8>,
+        },
+        /// Cross-chain reputation query initiated
+        CrossChainQueryInitiated {
+            query_id: u64,
+            target_chain: Vec<u8>,
+            target_account: Vec<u8>,
+        },
+        /// Algorithm parameters updated via governance
+        AlgorithmParamsUpdated {
+            old_params: AlgorithmParams,
+            new_params: AlgorithmParams,
+        },
+        /// A structural change of the algorithm parameters changed the meaning of scores
+        AlgorithmVersionBumped {
+            version: u32,
+        },
+        /// Governance switched compatibility mode on or off
+        CompatibilityModeSet {
+            enabled: bool,
+        },
+        /// Governance set the shares of the usual points awarded for imported contributions
+        ImportPointsSet {
+            points: ImportPoints,
+        },
+        /// Principal allowed a proxy to submit contributions on its behalf
+        SubmissionProxyAdded {
+            principal: T::AccountId,
+            proxy: T::AccountId,
+            max_submissions: u32,
+        },
+        /// Principal revoked a submission proxy
+        SubmissionProxyRemoved {
+            principal: T::AccountId,
+            proxy: T::AccountId,
+        },
+        /// Contribution submitted by a proxy
+        ContributionSubmittedByProxy {
+            principal: T::AccountId,
+            proxy: T::AccountId,
+            contribution_id: ContributionId,
+        },
+        /// Project registered; its owner is its first maintainer
+        ProjectRegistered {
+            project_id: ProjectId,
+            owner: T::AccountId,
+            repository: ProjectRepository,
+        },
+        /// Maintainer added to a project
+        MaintainerAdded {
+            project_id: ProjectId,
+            maintainer: T::AccountId,
+        },
+        /// Maintainer removed from a project
+        MaintainerRemoved {
+            project_id: ProjectId,
+            maintainer: T::AccountId,
+        },
+        /// Contribution linked to the project it was made to
+        ContributionProjectSet {
+            contribution_id: ContributionId,
+            project_id: ProjectId,
+        },
+        /// Project maintainer co-signed a contribution
+        MaintainerAttested {
+            contribution_id: ContributionId,
+            project_id: ProjectId,
+            maintainer: T::AccountId,
+        },
+        /// Ownership challenge issued; the token must be committed to the repository's `.dotrep` file
+        OwnershipChallengeIssued {
+            project_id: ProjectId,
+            token: H256,
+        },
+        /// Off-chain worker found the challenge token in the repository
+        ProjectVerified {
+            project_id: ProjectId,
+        },
+        /// Unverified project replaced by a new registration of its repository
+        ProjectReleased {
+            project_id: ProjectId,
+        },
+        /// Contributor tagged a pending contribution with the skills it requires
+        ContributionSkillsTagged {
+            contribution_id: ContributionId,
+            skills: Vec<SkillTag>,
+        },
+        /// Contributor withdrew a pending contribution and got its deposit back
+        ContributionWithdrawn {
+            contributor: T::AccountId,
+            contribution_id: ContributionId,
+            deposit: BalanceOf<T>,
+        },
+        /// Rejected contribution was removed and its deposit returned to the depositor
+        ContributionPruned {
+            contributor: T::AccountId,
+            contribution_id: ContributionId,
+            deposit: BalanceOf<T>,
+        },
+        /// Contributor purged settled contribution records, keeping their archive root
+        ContributionsArchived {
+            account: T::AccountId,
+            root: H256,
+            contributions: u32,
+        },
+        /// Hackathon created and its pot reserved from the organizer
+        HackathonCreated {
+            hackathon_id: HackathonId,
+            organizer: T::AccountId,
+            start: T::BlockNumber,
+            end: T::BlockNumber,
+            pot: BalanceOf<T>,
+        },
+        /// Contribution entered into a hackathon
+        HackathonEntered {
+            hackathon_id: HackathonId,
+            contribution_id: ContributionId,
+        },
+        /// Leaderboard participant paid their share of a hackathon's pot
+        HackathonRewardPaid {
+            hackathon_id: HackathonId,
+            account: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Hackathon closed; whatever was not paid out returned to the organizer
+        HackathonClosed {
+            hackathon_id: HackathonId,
+            paid: BalanceOf<T>,
+        },
+        /// Mentor registered a mentorship, awaiting the mentee's acceptance
+        MentorshipRegistered {
+            mentorship_id: MentorshipId,
+            mentor: T::AccountId,
+            mentee: T::AccountId,
+        },
+        /// Mentee accepted a mentorship
+        MentorshipAccepted {
+            mentorship_id: MentorshipId,
+        },
+        /// Mentee attested a session, submitted as a contribution of the mentor
+        MentorshipSessionAttested {
+            mentorship_id: MentorshipId,
+            contribution_id: ContributionId,
+        },
+        /// Mentor or mentee signed off on completion
+        MentorshipSignedOff {
+            mentorship_id: MentorshipId,
+            who: T::AccountId,
+        },
+        /// Both parties signed off; the mentorship is complete
+        MentorshipCompleted {
+            mentorship_id: MentorshipId,
+            mentor: T::AccountId,
+            sessions: u32,
+        },
+        /// Mentor or mentee rejected or ended the mentorship before completion
+        MentorshipEnded {
+            mentorship_id: MentorshipId,
+            who: T::AccountId,
+        },
+        /// Contributor offered to refer a newcomer
+        ReferralOffered {
+            referrer: T::AccountId,
+            referee: T::AccountId,
+        },
+        /// Newcomer accepted a contributor's referral
+        AccountReferred {
+            referrer: T::AccountId,
+            referee: T::AccountId,
+        },
+        /// Referred account qualified; its referrer earned the referral bonus
+        ReferralRewarded {
+            referrer: T::AccountId,
+            referee: T::AccountId,
+            bonus: Score,
+        },
+        /// Referred account was penalized as a Sybil; its referrer's bonus was clawed back
+        ReferralClawedBack {
+            referrer: T::AccountId,
+            referee: T::AccountId,
+            points: Score,
+        },
+        /// Account was put on probation until block `until`
+        ProbationStarted {
+            account: T::AccountId,
+            until: T::BlockNumber,
+        },
+        /// Contributor allowed `viewer` to see its detailed breakdown
+        BreakdownAccessGranted {
+            account: T::AccountId,
+            viewer: T::AccountId,
+        },
+        /// Contributor revoked `viewer`'s access to its detailed breakdown
+        BreakdownAccessRevoked {
+            account: T::AccountId,
+            viewer: T::AccountId,
+        },
+        /// Contributor made its detailed breakdown public or private
+        BreakdownVisibilitySet {
+            account: T::AccountId,
+            public: bool,
+        },
+        /// Verifier committee replaced
+        VerifierCommitteeSet {
+            members: Vec<T::AccountId>,
+        },
+        /// Committee member approved a high-value contribution
+        CommitteeApproved {
+            contribution_id: ContributionId,
+            member: T::AccountId,
+            approvals: u32,
+        },
+        /// Contributor claimed its dividend for an ended era
+        DividendClaimed {
+            account: T::AccountId,
+            era: u32,
+            amount: BalanceOf<T>,
+        },
+        /// Dividends paid for reputation the account lost went back to the dividend pot
+        DividendClawedBack {
+            account: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Contribution passed its verification deadline while pending
+        ContributionStale {
+            contribution_id: ContributionId,
+        },
+        /// Stale contribution escalated to `UpdateOrigin` for triage
+        ContributionEscalated {
+            contribution_id: ContributionId,
+        },
+        /// `UpdateOrigin` verified or rejected an escalated contribution
+        EscalationTriaged {
+            contribution_id: ContributionId,
+            accepted: bool,
+        },
+        /// Verifier rejected a pending contribution
+        VerificationRejected {
+            contributor: T::AccountId,
+            contribution_id: ContributionId,
+            verifier: T::AccountId,
+            reason: RejectionReason,
+            rejections: u32,
+        },
+        /// Contribution reached `RejectionThreshold` rejections and was rejected
+        ContributionRejected {
+            contributor: T::AccountId,
+            contribution_id: ContributionId,
+            slashed: BalanceOf<T>,
+        },
+        /// Verifier reached `MaxOutlierStrikes` and lost its verification rights
+        VerificationRightsRevoked {
+            verifier: T::AccountId,
+        },
+        /// `UpdateOrigin` cleared a verifier's record, restoring its verification rights
+        VerifierRecordReset {
+            verifier: T::AccountId,
+        },
+        /// Account went on leave, pausing the time decay of its reputation
+        LeaveAnnounced {
+            account: T::AccountId,
+            until: T::BlockNumber,
+        },
+        /// `UpdateOrigin` revoked an account's latest leave
+        LeaveRevoked {
+            account: T::AccountId,
+        },
+        /// The reputation scores were committed to, see `LatestScoreCommitment`
+        ScoresCommitted {
+            root: H256,
+            accounts: u32,
+            top: BoundedVec<(T::AccountId, Score), T::SnapshotTopK>,
+        },
+        /// A score commitment was dropped on reaching `MaxSnapshotAccounts` entries
+        ScoreCommitmentAbandoned {
+            accounts: u32,
+        },
+        /// Contribution imported from the git log of a self-hosted forge
+        GitLogImported {
+            contributor: T::AccountId,
+            contribution_id: ContributionId,
+            project_id: ProjectId,
+            head: [u8; 20],
+        },
+        /// Bundle of contributions submitted for verification as one contribution
+        BundleSubmitted {
+            contributor: T::AccountId,
+            contribution_id: ContributionId,
+            root: H256,
+            items: u32,
+        },
+        /// The off-chain worker checked an imported git log; inconsistent logs are rejected
+        GitImportChecked {
+            contribution_id: ContributionId,
+            consistent: bool,
+        },
+        /// Governance approved the bulk import of a project's exported history
+        BulkImportApproved {
+            export_digest: H256,
+            importer: T::AccountId,
+            project_id: ProjectId,
+            max_items: u32,
+        },
+        /// A batch of historical contributions was imported from an approved export
+        ImportBatchSubmitted {
+            export_digest: H256,
+            count: u32,
+        },
+        /// A bulk import named the account as a contribution's author; it counts once the
+        /// account accepts it
+        ImportOffered {
+            contributor: T::AccountId,
+            export_digest: H256,
+            proof: H256,
+        },
+        /// The named contributor declined a bulk-imported contribution
+        ImportDeclined {
+            contributor: T::AccountId,
+            proof: H256,
+        },
+        /// A verifier confirmed a bulk-imported contribution
+        ImportedContributionVerified {
+            contributor: T::AccountId,
+            contribution_id: ContributionId,
+            verifier: T::AccountId,
+            reputation_gained: Score,
+        },
+        /// Governance set the starter grant of verified humans
+        StarterGrantSet {
+            amount: Score,
+        },
+        /// A verified human claimed the starter grant
+        StarterGrantClaimed {
+            account: T::AccountId,
+            amount: Score,
+        },
+        /// Account's score is projected to decay materially unless it stays active
+        DecayWarning {
+            account: T::AccountId,
+            score: Score,
+            projected: Score,
+            at: T::BlockNumber,
+        },
+        /// The contributor responded to a verification
+        VerificationResponded {
+            contributor: T::AccountId,
+            contribution_id: ContributionId,
+            verifier: T::AccountId,
+        },
+        /// A verifier amended their score of a pending contribution
+        VerificationAmended {
+            contribution_id: ContributionId,
+            verifier: T::AccountId,
+            old_score: u8,
+            new_score: u8,
+        },
+        /// The contribution pipeline was paused in an emergency
+        PipelinePaused,
+        /// The contribution pipeline was resumed
+        PipelineUnpaused,
+        /// Governance changed the contribution pipeline limits
+        PipelineConfigUpdated {
+            old: PipelineConfig,
+            new: PipelineConfig,
+        },
+        /// Settled cross-chain queries were moved into the remote reputation cache and
+        /// expired cache entries removed
+        RemoteReputationSynced {
+            cached: u32,
+            dropped: u32,
+            expired: u32,
+        },
+        /// A trust-layer claim about a contribution was resolved
+        ContributionClaimResolved {
+            contributor: T::AccountId,
+            contribution_id: ContributionId,
+            verdict: ClaimVerdict,
+        },
+        /// Governance registered an external attestor
+        AttestorRegistered {
+            attestor: T::AccountId,
+            name: AttestorName,
+        },
+        /// Governance removed an attestor; its attestations no longer count
+        AttestorRemoved {
+            attestor: T::AccountId,
+        },
+        /// An attestor issued an attestation about an account
+        AttestationIssued {
+            subject: T::AccountId,
+            attestor: T::AccountId,
+            attestation_id: AttestationId,
+            weight: u8,
+            expires_at: T::BlockNumber,
+        },
+        /// An attestor revoked its attestation
+        AttestationRevoked {
+            subject: T::AccountId,
+            attestation_id: AttestationId,
+        },
+        /// Account claimed a remote account; the challenge must be proved to link it
+        IdentityLinkRequested {
+            account: T::AccountId,
+            chain: ChainId,
+            remote_account: RemoteAccount,
+            challenge: H256,
+        },
+        /// A remote account was linked to an account as the same developer
+        IdentityLinked {
+            account: T::AccountId,
+            chain: ChainId,
+            remote_account: RemoteAccount,
+        },
+        /// A remote account was unlinked
+        IdentityUnlinked {
+            account: T::AccountId,
+            chain: ChainId,
+            remote_account: RemoteAccount,
+        },
+        /// Governance changed the share of linked remote reputation that is aggregated
+        RemoteReputationWeightSet {
+            weight: Percent,
+        },
+        /// Governance replaced the webhooks verification reminders are sent to
+        NotificationEndpointsSet {
+            endpoints: u32,
+        },
+        /// Verification reminders of deadlines up to `until` were POSTed to the webhooks
+        RemindersSent {
+            until: T::BlockNumber,
+        },
+        /// A verifier joined the verifier pool or changed its availability
+        VerifierOptedIn {
+            verifier: T::AccountId,
+            capacity_per_era: u32,
+            skills: ContributionSkillsOf,
+        },
+        /// A verifier left the verifier pool
+        VerifierOptedOut {
+            verifier: T::AccountId,
+        },
+        /// Governance changed the attestation multiplier
+        AttestationMultiplierSet {
+            multiplier: Percent,
+        },
+        /// Governance changed the block weight quota of a hook sweep
+        HookQuotaSet {
+            task: HookTask,
+            quota: Perbill,
+        },
+        /// The v1 migration held deposits for every stored contribution
+        DepositMigrationCompleted,
+        /// The v3 migration moved every search index entry
+        IndexMigrationCompleted,
+        /// An EVM chain was registered with its bridge contract, or removed
+        EvmChainRegistered {
+            chain_id: u64,
+            bridge: Option<H160>,
+        },
+        /// The EVM attestation signer changed
+        EvmAttestationSignerSet {
+            signer: H160,
+        },
+        /// Account requested an attestation of its reputation for an EVM chain
+        EvmExportRequested {
+            account: T::AccountId,
+            chain_id: u64,
+            nonce: u64,
+        },
+        /// Attestation signed by the chain, ready to be relayed to the chain's bridge
+        ///
+        /// `message` is ABI-encoded and `signature` is over its keccak-256 hash, see
+        /// [`crate::evm_bridge`].
+        EvmAttestationSigned {
+            account: T::AccountId,
+            chain_id: u64,
+            nonce: u64,
+            message: Vec<u8>,
+            signature: ecdsa::Signature,
+        },
+    }
+
+    impl<T: Config> Event<T> {
+        /// Topics this event is indexed under
+        fn topics(&self) -> Vec<T::Hash> {
+            let account = |who: &T::AccountId| Topic::Account.of::<T::Hashing>(who);
+            let contribution = |id: &ContributionId| Topic::Contribution.of::<T::Hashing>(id);
+            let project = |id: &ProjectId| Topic::Project.of::<T::Hashing>(id);
+
+            match self {
+                Event::ContributionSubmitted { contributor, contribution_id, .. } =>
+                    vec![account(contributor), contribution(contribution_id)],
+                Event::ContributionVerified { contributor, contribution_id, verifier, .. } =>
+                    vec![account(contributor), contribution(contribution_id), account(verifier)],
+                Event::ReputationUpdated { account: who, .. } => vec![account(who)],
+                Event::SybilAttackDetected { account: who, contribution_id, .. } =>
+                    vec![account(who), contribution(contribution_id)],
+                Event::CrossChainQueryInitiated { query_id, .. } =>
+                    vec![Topic::Query.of::<T::Hashing>(query_id)],
+                Event::SubmissionProxyAdded { principal, proxy, .. }
+                | Event::SubmissionProxyRemoved { principal, proxy } => vec![account(principal), account(proxy)],
+                Event::ContributionSubmittedByProxy { principal, proxy, contribution_id } =>
+                    vec![account(principal), account(proxy), contribution(contribution_id)],
+                Event::ProjectRegistered { project_id, owner: who, .. }
+                | Event::MaintainerAdded { project_id, maintainer: who }
+                | Event::MaintainerRemoved { project_id, maintainer: who } =>
+                    vec![project(project_id), account(who)],
+                Event::ContributionProjectSet { contribution_id, project_id } =>
+                    vec![contribution(contribution_id), project(project_id)],
+                Event::MaintainerAttested { contribution_id, project_id, maintainer } =>
+                    vec![contribution(contribution_id), project(project_id), account(maintainer)],
+                Event::OwnershipChallengeIssued { project_id, .. }
+                | Event::ProjectVerified { project_id }
+                | Event::ProjectReleased { project_id } =>
+                    vec![project(project_id)],
+                Event::ContributionSkillsTagged { contribution_id, .. } => vec![contribution(contribution_id)],
+                Event::ContributionWithdrawn { contributor, contribution_id, .. }
+                | Event::ContributionPruned { contributor, contribution_id, .. } =>
+                    vec![account(contributor), contribution(contribution_id)],
+                Event::ContributionsArchived { account: who, .. } => vec![account(who)],
+                Event::HackathonCreated { organizer: who, .. } | Event::HackathonRewardPaid { account: who, .. } =>
+                    vec![account(who)],
+                Event::HackathonEntered { contribution_id, .. } => vec![contribution(contribution_id)],
+                Event::MentorshipRegistered { mentor, mentee, .. } => vec![account(mentor), account(mentee)],
+                Event::MentorshipSessionAttested { contribution_id, .. } => vec![contribution(contribution_id)],
+                Event::MentorshipSignedOff { who, .. }
+                | Event::MentorshipCompleted { mentor: who, .. }
+                | Event::MentorshipEnded { who, .. } => vec![account(who)],
+                Event::ReferralOffered { referrer, referee }
+                | Event::AccountReferred { referrer, referee }
+                | Event::ReferralRewarded { referrer, referee, .. }
+                | Event::ReferralClawedBack { referrer, referee, .. } => vec![account(referrer), account(referee)],
+                Event::ProbationStarted { account: who, .. } => vec![account(who)],
+                Event::BreakdownAccessGranted { account: who, viewer }
+                | Event::BreakdownAccessRevoked { account: who, viewer } => vec![account(who), account(viewer)],
+                Event::BreakdownVisibilitySet { account: who, .. } => vec![account(who)],
+                Event::VerifierCommitteeSet { members } => members.iter().map(account).collect(),
+                Event::CommitteeApproved { contribution_id, member, .. } =>
+                    vec![contribution(contribution_id), account(member)],
+                Event::DividendClaimed { account: who, .. } | Event::DividendClawedBack { account: who, .. } =>
+                    vec![account(who)],
+                Event::ContributionStale { contribution_id }
+                | Event::ContributionEscalated { contribution_id }
+                | Event::EscalationTriaged { contribution_id, .. } => vec![contribution(contribution_id)],
+                Event::VerificationRejected { contributor, contribution_id, verifier, .. } =>
+                    vec![account(contributor), contribution(contribution_id), account(verifier)],
+                Event::ContributionRejected { contributor, contribution_id, .. } =>
+                    vec![account(contributor), contribution(contribution_id)],
+                Event::VerificationRightsRevoked { verifier } | Event::VerifierRecordReset { verifier } =>
+                    vec![account(verifier)],
+                Event::LeaveAnnounced { account: who, .. } | Event::LeaveRevoked { account: who } =>
+                    vec![account(who)],
+                Event::BundleSubmitted { contributor, contribution_id, .. } =>
+                    vec![account(contributor), contribution(contribution_id)],
+                Event::AttestorRegistered { attestor, .. } | Event::AttestorRemoved { attestor } =>
+                    vec![account(attestor)],
+                Event::AttestationIssued { subject, attestor, .. } => vec![account(subject), account(attestor)],
+                Event::AttestationRevoked { subject, .. } => vec![account(subject)],
+                Event::VerifierOptedIn { verifier, .. } | Event::VerifierOptedOut { verifier } =>
+                    vec![account(verifier)],
+                Event::IdentityLinkRequested { account: who, .. }
+                | Event::IdentityLinked { account: who, .. }
+                | Event::IdentityUnlinked { account: who, .. } => vec![account(who)],
+                Event::ContributionClaimResolved { contributor, contribution_id, .. } =>
+                    vec![account(contributor), contribution(contribution_id)],
+                Event::GitLogImported { contributor, contribution_id, project_id, .. } =>
+                    vec![account(contributor), contribution(contribution_id), project(project_id)],
+                Event::GitImportChecked { contribution_id, .. } => vec![contribution(contribution_id)],
+                Event::BulkImportApproved { importer, project_id, .. } => vec![account(importer), project(project_id)],
+                Event::ImportOffered { contributor, .. } | Event::ImportDeclined { contributor, .. } =>
+                    vec![account(contributor)],
+                Event::ImportedContributionVerified { contributor, contribution_id, verifier, .. } =>
+                    vec![account(contributor), contribution(contribution_id), account(verifier)],
+                Event::StarterGrantClaimed { account: who, .. } | Event::DecayWarning { account: who, .. } =>
+                    vec![account(who)],
+                Event::VerificationResponded { contributor, contribution_id, verifier } =>
+                    vec![account(contributor), contribution(contribution_id), account(verifier)],
+                Event::VerificationAmended { contribution_id, verifier, .. } =>
+                    vec![contribution(contribution_id), account(verifier)],
+                Event::EvmExportRequested { account: who, .. } | Event::EvmAttestationSigned { account: who, .. } =>
+                    vec![account(who)],
+                // Parameter changes concern no particular account
+                _ => Vec::new(),
+            }
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Deposit an event indexed under the accounts and ids it concerns
+        ///
+        /// Score changes are also written to the off-chain index under the event's position.
+        pub(super) fn deposit_event(event: Event<T>) {
+            if let Event::ReputationUpdated { account, old_score, new_score, change_reason } = &event {
+                crate::indexing::index_score_change::<T>(account, *old_score, *new_score, change_reason);
+                Self::record_score_change(*old_score, *new_score);
+            }
+            let topics = event.topics();
+            let event = <T as Config>::RuntimeEvent::from(event);
+            frame_system::Pallet::<T>::deposit_event_indexed(&topics, event.into());
+        }
+    }
+
+    // Errors inform users that something went wrong.
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Account does not exist in the system
+        AccountNotFound,
+        /// Contribution proof already submitted (duplicate)
+        ContributionAlreadySubmitted,
+        /// Maximum contributions per account exceeded
+        MaxContributionsExceeded,
+        /// Reputation score exceeds maximum allowed value
+        ReputationScoreOverflow,
+        /// Reputation score below minimum allowed value
+        ReputationScoreUnderflow,
+        /// Insufficient reputation to perform this operation
+        InsufficientReputation,
+        /// Insufficient reputation to verify contributions
+        InsufficientReputationToVerify,
+        /// Contribution not found
+        ContributionNotFound,
+        /// Contribution already verified
+        ContributionAlreadyVerified,
+        /// Rate limit exceeded (too many pending contributions)
+        RateLimited,
+        /// Invalid verification score (must be 0-100)
+        InvalidVerificationScore,
+        /// Invalid proof hash (cannot be zero)
+        InvalidProof,
+        /// XCM message routing failed
+        XcmRoutingError,
+        /// XCM execution failed
+        XcmExecutionFailed,
+        /// Off-chain worker failed to fetch data
+        OffchainFetchFailed,
+        /// Sybil attack detected
+        SybilAttackDetected,
+        /// Requires governance origin
+        RequiresGovernance,
+        /// Query timeout exceeded
+        QueryTimeout,
+        /// Query not found
+        QueryNotFound,
+        /// Chain not supported for cross-chain queries
+        ChainNotSupported,
+        /// Invalid algorithm parameters
+        InvalidAlgorithmParams,
+        /// Structural changes of the score algorithm are refused in compatibility mode
+        StructuralChangeInCompatibilityMode,
+        /// Contribution weight exceeds maximum (must be 1-100)
+        InvalidContributionWeight,
+        /// Self-verification not allowed
+        SelfVerificationNotAllowed,
+        /// Contribution has not been verified
+        ContributionNotVerified,
+        /// No weight is configured for the contribution's type
+        MissingTypeWeight,
+        /// Caller is not a submission proxy of the principal
+        NotSubmissionProxy,
+        /// Submission proxy used up its submissions for the current period
+        ProxyRateLimited,
+        /// An account cannot be its own submission proxy
+        SelfProxyNotAllowed,
+        /// Project not found
+        ProjectNotFound,
+        /// Repository URL is empty or longer than 128 bytes
+        InvalidRepository,
+        /// Repository is already registered as a project
+        RepositoryAlreadyRegistered,
+        /// Caller is not the project's owner
+        NotProjectOwner,
+        /// Account is not a maintainer of the project
+        NotProjectMaintainer,
+        /// Account is already a maintainer of the project
+        AlreadyProjectMaintainer,
+        /// Contribution is not linked to a project
+        NoContributionProject,
+        /// Contribution was already attested by a maintainer
+        AlreadyAttested,
+        /// Project's repository ownership has not been verified
+        ProjectNotVerified,
+        /// Project's repository ownership is already verified
+        ProjectAlreadyVerified,
+        /// Co-author has no verified identity binding
+        CoAuthorNotBound,
+        /// Co-author listed twice or as the submitter
+        DuplicateCoAuthor,
+        /// Co-author share is zero or the shares leave the submitter nothing
+        InvalidCoAuthorShares,
+        /// No ownership challenge is open for the project
+        NoOwnershipChallenge,
+        /// Token does not match the project's open ownership challenge
+        ChallengeMismatch,
+        /// Account cannot cover the contribution deposit
+        InsufficientDeposit,
+        /// Only pending contributions can be withdrawn
+        ContributionNotWithdrawable,
+        /// Only rejected contributions can be pruned
+        ContributionNotPrunable,
+        /// A dispute against the account's contributions is open
+        ContributionsUnderDispute,
+        /// Account has no verified or rejected contributions to archive
+        NothingToArchive,
+        /// Hackathon does not exist
+        HackathonNotFound,
+        /// Hackathon must end after it starts and not start in the past
+        InvalidHackathonWindow,
+        /// Organizer cannot reserve the hackathon's pot
+        InsufficientHackathonPot,
+        /// Contributions can only be entered between the hackathon's start and end
+        HackathonNotOpen,
+        /// Contribution is not linked to one of the hackathon's projects
+        ProjectNotInHackathon,
+        /// Hackathon has not ended yet
+        HackathonNotEnded,
+        /// Hackathon is already closed
+        HackathonClosed,
+        /// Mentor and mentee must be different accounts
+        CannotMentorSelf,
+        /// Mentorship does not exist
+        MentorshipNotFound,
+        /// Caller is not the mentorship's mentee
+        NotMentee,
+        /// Caller is neither the mentorship's mentor nor its mentee
+        NotMentorshipParty,
+        /// Mentorship is not at the stage the call requires
+        InvalidMentorshipStatus,
+        /// Caller already signed off on the mentorship
+        AlreadySignedOff,
+        /// Accounts cannot refer themselves
+        CannotReferSelf,
+        /// Only accounts with a verified contribution can refer others
+        NotAContributor,
+        /// Referred account has already contributed or been referred
+        NotANewcomer,
+        /// Referrer made `MaxReferralsPerEpoch` referrals this epoch
+        ReferralCapReached,
+        /// Referrer already offered to refer the account
+        ReferralAlreadyOffered,
+        /// Referrer did not offer to refer the caller
+        NoReferralOffer,
+        /// Account is on probation and cannot verify contributions
+        OnProbation,
+        /// Viewer was not allowed to see the caller's breakdown
+        BreakdownViewerNotFound,
+        /// Contribution weighs more than `CommitteeVerificationThreshold` and is verified by
+        /// the verifier committee
+        RequiresCommitteeApproval,
+        /// Contribution does not need committee approval
+        CommitteeApprovalNotRequired,
+        /// Caller is not on the verifier committee
+        NotCommitteeMember,
+        /// Committee member already approved the contribution
+        AlreadyApproved,
+        /// Committee has fewer than `MinCommitteeApprovals` members or lists one twice
+        InvalidCommittee,
+        /// Dividend era has not ended yet
+        DividendEraNotEnded,
+        /// Caller earned no reputation in the era or already claimed its dividend
+        NoDividend,
+        /// Contribution is not escalated
+        NotEscalated,
+        /// No block within `DEADLINE_SLOT_SEARCH` of the contribution's verification
+        /// deadline has room to schedule it
+        DeadlineSlotsFull,
+        /// Contribution is no longer pending
+        ContributionNotPending,
+        /// Verifier already rejected the contribution
+        ContributionAlreadyRejected,
+        /// Verifier's scores strayed from the consensus too often; it may not verify
+        VerificationRightsRevoked,
+        /// Leave duration is zero or longer than `MaxLeaveDuration`
+        InvalidLeaveDuration,
+        /// Account started a leave less than `LeaveCooldown` blocks ago
+        LeaveCooldownActive,
+        /// Account has no leave to revoke
+        NoLeave,
+        /// A score commitment is still being built
+        ScoreCommitmentInProgress,
+        /// Git log is empty
+        EmptyGitLog,
+        /// Contribution was not imported from a git log
+        NotGitImport,
+        /// Imported git log was already checked
+        GitImportAlreadyChecked,
+        /// No maintainer of the project has attested the imported contribution yet
+        GitImportNotAttested,
+        /// An import of this export digest was already approved
+        BulkImportExists,
+        /// No bulk import was approved for this export digest
+        BulkImportNotFound,
+        /// The caller is not the importer of the bulk import
+        NotBulkImporter,
+        /// The batch would import more contributions than the export holds
+        BulkImportLimitExceeded,
+        /// Contribution was not bulk-imported or was already verified, or no import
+        /// offers the caller the proof
+        NotImportedContribution,
+        /// Bulk-imported contributions are verified with `verify_imported_contribution`
+        AwaitsImportVerification,
+        /// The starter grant is disabled
+        StarterGrantUnavailable,
+        /// Account has no verified-human identity
+        NotVerifiedHuman,
+        /// The caller's identity already claimed the starter grant, possibly from another account
+        StarterGrantAlreadyClaimed,
+        /// `MaxStarterGrantsPerPeriod` grants were claimed this period
+        StarterGrantsExhausted,
+        /// The verifier has not verified the contribution
+        VerificationNotFound,
+        /// The contributor already responded to the verification
+        VerificationAlreadyAnswered,
+        /// The verifier already amended their score
+        VerificationAlreadyAmended,
+        /// The contribution pipeline is paused
+        PipelinePaused,
+        /// Batch size is zero or above `PipelineConfig::MAX_BATCH_SIZE`, or the query timeout is zero
+        InvalidPipelineConfig,
+        /// More items than the pipeline's `max_batch_size`
+        BatchTooLarge,
+        /// The cross-chain query was already answered, failed or timed out
+        QueryNotPending,
+        /// No query was settled and no cache entry had expired
+        NothingToSync,
+        /// A bundle needs 2 to `MAX_BUNDLE_ITEMS` distinct, non-zero items whose Merkle root
+        /// is its root
+        InvalidBundle,
+        /// The caller is not a registered attestor
+        NotAttestor,
+        /// Attestation weight must be 1 to 100 and its expiry in the future
+        InvalidAttestation,
+        /// The account holds `MAX_ATTESTATIONS_PER_ACCOUNT` unexpired attestations
+        TooManyAttestations,
+        /// The attestor holds an unexpired attestation about the account
+        AttestorAlreadyAttested,
+        /// No attestation by the caller has this id
+        AttestationNotFound,
+        /// No bridge is registered for the EVM chain id
+        UnknownEvmChain,
+        /// Account has no unsigned export to the EVM chain
+        NoPendingEvmExport,
+        /// Signature is not by `EvmAttestationSigner`, or no signer is set
+        InvalidEvmAttestationSignature,
+        /// The hook quotas would add up to more than `MAX_HOOK_SHARE` of the block
+        HookQuotasExceeded,
+        /// The remote account is already linked to an account
+        IdentityAlreadyLinked,
+        /// The account has `MAX_LINKED_IDENTITIES` linked remote accounts
+        TooManyLinkedIdentities,
+        /// The account did not request a link to the remote account
+        NoLinkChallenge,
+        /// The proof is not for the pending link challenge
+        InvalidLinkChallenge,
+        /// The challenge is not signed by the remote account
+        InvalidLinkSignature,
+        /// The remote account is not linked to the caller
+        IdentityNotLinked,
+        /// A verifier must offer at least one review per era
+        ZeroVerifierCapacity,
+        /// The account is not in the verifier pool
+        NotInVerifierPool,
+        /// More than `MAX_NOTIFICATION_ENDPOINTS` webhooks
+        TooManyNotificationEndpoints,
+        /// A webhook is not an `https://` URL of at most 256 bytes
+        InvalidNotificationEndpoint,
+        /// Reminders were already reported sent up to that block, or it is beyond `ReminderLead`
+        InvalidReminderCursor,
     }
 
-    // Pallets use events to inform users when important changes are made.
-    #[pallet::event]
-    #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
-        /// New contribution submitted for verification
-        ContributionSubmitted {
-            #[pallet::index(0)]
-            contributor: T::AccountId,
-            #[pallet::index(1)]
-            contribution_id: ContributionId,
-            #[pallet::index(2)]
-            proof_hash: H256,
-            contribution_type: ContributionType,
-            source: DataSource,
-        },
-        /// Contribution has been verified
-        ContributionVerified {
-            #[pallet::index(0)]
-            contributor: T::AccountId,
-            #[pallet::index(1)]
-            contribution_id: ContributionId,
-            #[pallet::index(2)]
-            verifier: T::AccountId,
-            score: u8,
-            reputation_gained: i32,
-        },
-        /// Reputation score updated
-        ReputationUpdated {
-            #[pallet::index(0)]
+    // Dispatchable functions allow users to interact with the pallet and invoke state changes.
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Adds a new contribution to the reputation system
+        ///
+        /// # Arguments
+        /// * `origin` - The account adding the contribution
+        /// * `proof` - Cryptographic proof of the contribution
+        /// * `contribution_type` - Type of contribution (code, docs, etc.)
+        /// * `weight` - Relative weight of the contribution
+        /// * `source` - Data source (GitHub, GitLab, etc.)
+        /// * `co_authors` - Co-authors and their reputation shares; empty for a sole author
+        ///
+        /// # Errors
+        /// Returns `Error::ContributionAlreadySubmitted` if the proof was already used
+        /// Returns `Error::RateLimited` if the account has too many pending contributions
+        /// Returns `Error::MaxContributionsExceeded` if account exceeds contribution limit
+        /// Returns `Error::SybilAttackDetected` if the account submits too quickly; only the
+        /// weight of the checks is charged
+        /// Returns `Error::CoAuthorNotBound` if a co-author has no verified binding
+        /// Returns `Error::DuplicateCoAuthor` if an account is listed twice or as the submitter
+        /// Returns `Error::InvalidCoAuthorShares` if a share is zero or the submitter keeps none
+        /// Returns `Error::PipelinePaused` if the pipeline is paused
+        ///
+        /// # Events
+        /// Emits `ContributionSubmitted` on success
+        ///
+        /// # Fees
+        /// Free for accounts with `FeelessReputationThreshold` reputation, up to
+        /// `FeelessCallsPerEra` successful `add_contribution` and `verify_contribution` calls
+        #[pallet::weight(
+            <T as Config>::WeightInfo::add_contribution()
+                .saturating_add(T::DbWeight::get().reads_writes(co_authors.len() as u64, 1))
+        )]
+        pub fn add_contribution(
+            origin: OriginFor<T>,
+            proof: H256,
+            contribution_type: ContributionType,
+            weight: u8,
+            source: DataSource,
+            co_authors: CoAuthorsOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            Self::ensure_valid_co_authors(&who, &co_authors)?;
+            let contribution_id = Self::submit_contribution(&who, proof, contribution_type, weight, source)?;
+            if !co_authors.is_empty() {
+                ContributionCoAuthors::<T>::insert(contribution_id, co_authors);
+            }
+
+            Ok(Self::use_feeless_quota(&who).into())
+        }
+
+        /// Verify a contribution
+        ///
+        /// # Arguments
+        /// * `origin` - The verifier account
+        /// * `contributor` - The account that made the contribution
+        /// * `contribution_id` - ID of the contribution to verify
+        /// * `score` - Verification score (0-100)
+        /// * `comment` - Optional comment
+        ///
+        /// # Errors
+        /// Returns `Error::InsufficientReputationToVerify` if verifier lacks required reputation
+        /// Returns `Error::ContributionNotFound` if contribution doesn't exist
+        /// Returns `Error::InvalidVerificationScore` if score is out of range
+        /// Returns `Error::OnProbation` if the verifier is on probation
+        /// Returns `Error::VerificationRightsRevoked` if the verifier reached `MaxOutlierStrikes`
+        /// Returns `Error::RequiresCommitteeApproval` if the contribution weighs more than
+        /// `CommitteeVerificationThreshold`
+        /// Returns `Error::PipelinePaused` if the pipeline is paused
+        ///
+        /// A verifier who declared one of the contribution's skills counts as
+        /// `SkillMatchMultiplier` verifications.
+        ///
+        /// # Weight
+        /// Verifications that leave the contribution short of `MinVerifications` skip the
+        /// score update and are refunded its weight
+        ///
+        /// # Fees
+        /// Free within the verifier's fee-less quota, as for `add_contribution`
+        #[pallet::weight(<T as Config>::WeightInfo::verify_contribution()
+            .saturating_add(T::DbWeight::get().reads(MAX_CONTRIBUTION_SKILLS.into())))]
+        pub fn verify_contribution(
+            origin: OriginFor<T>,
+            contributor: T::AccountId,
+            contribution_id: ContributionId,
+            score: u8,
+            comment: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let verifier = ensure_signed(origin)?;
+
+            // 1. CHECKS: Validate inputs and permissions
+            Self::ensure_not_paused()?;
+            // Prevent self-verification
+            ensure!(
+                verifier != contributor,
+                Error::<T>::SelfVerificationNotAllowed
+            );
+
+            // Check verifier has sufficient reputation
+            let verifier_reputation = ReputationScores::<T>::get(&verifier);
+            ensure!(
+                verifier_reputation >= T::MinReputationToVerify::get(),
+                Error::<T>::InsufficientReputationToVerify
+            );
+            ensure!(!Self::is_on_probation(&verifier), Error::<T>::OnProbation);
+            ensure!(Self::has_verification_rights(&verifier), Error::<T>::VerificationRightsRevoked);
+
+            // Validate score range
+            ensure!(
+                score <= 100,
+                Error::<T>::InvalidVerificationScore
+            );
+
+            // Get contribution
+            let mut contribution = Contributions::<T>::get(contribution_id)
+                .ok_or(Error::<T>::ContributionNotFound)?;
+
+            // Check contribution is still pending
+            ensure!(
+                !contribution.verified,
+                Error::<T>::ContributionAlreadyVerified
+            );
+            ensure!(!Self::requires_committee(&contribution), Error::<T>::RequiresCommitteeApproval);
+
+            // Check contribution belongs to contributor
+            ensure!(
+                ContributionProofs::<T>::get(contribution.proof) == Some(contributor.clone()),
+                Error::<T>::ContributionNotFound
+            );
+
+            // Check if verifier already verified this contribution, or attested it as maintainer
+            ensure!(
+                !ContributionVerifications::<T>::contains_key(contribution_id, &verifier)
+                    && MaintainerAttestations::<T>::get(contribution_id).as_ref() != Some(&verifier),
+                Error::<T>::ContributionAlreadyVerified
+            );
+            ensure!(
+                !ContributionRejections::<T>::contains_key(contribution_id, &verifier),
+                Error::<T>::ContributionAlreadyRejected
+            );
+            ensure!(contribution.status != ContributionStatus::Rejected, Error::<T>::ContributionNotPending);
+            ensure!(contribution.status != ContributionStatus::Imported, Error::<T>::AwaitsImportVerification);
+
+            // 2. EFFECTS: Update state
+            // Store verification
+            ContributionVerifications::<T>::insert(contribution_id, &verifier, (score, comment.clone()));
+            Self::record_verification_activity(&verifier, &contributor, score);
+
+            // Update verification count (saturating to prevent overflow); skill-matched
+            // verifiers count extra
+            contribution.verification_count = contribution.verification_count
+                .saturating_add(Self::verification_weight(&verifier, contribution_id));
+            Self::reward_stale_verification(&verifier, contribution_id);
+
+            // Mark as verified once enough verifications (and any required attestation) are in
+            let reputation_gained = Self::try_complete_verification(&contributor, &mut contribution)?;
+
+            // Update contribution
+            Contributions::<T>::insert(contribution_id, &contribution);
+
+            // 3. INTERACTIONS: Emit event
+            Self::deposit_event(Event::ContributionVerified {
+                contributor,
+                contribution_id,
+                verifier,
+                score,
+                reputation_gained,
+            });
+
+            let actual_weight = (!contribution.verified).then(|| T::WeightInfo::verify_contribution_pending());
+            Ok(PostDispatchInfo { actual_weight, pays_fee: Self::use_feeless_quota(&verifier) })
+        }
+
+        /// Update algorithm parameters (governance-only)
+        ///
+        /// # Arguments
+        /// * `origin` - Must be governance origin
+        /// * `params` - New algorithm parameters
+        ///
+        /// Changes of the verification multiplier or of a type weight are structural:
+        /// they change what a score means, so they bump `AlgorithmVersion`. Decay rate
+        /// changes are not.
+        ///
+        /// # Errors
+        /// Returns `Error::RequiresGovernance` if origin is not governance
+        /// Returns `Error::InvalidAlgorithmParams` if parameters are invalid
+        /// Returns `Error::StructuralChangeInCompatibilityMode` for a structural change
+        /// while `CompatibilityMode` is on
+        #[pallet::weight(<T as Config>::WeightInfo::update_algorithm_params())]
+        pub fn update_algorithm_params(
+            origin: OriginFor<T>,
+            params: AlgorithmParams,
+        ) -> DispatchResult {
+            // Require governance origin
+            T::UpdateOrigin::ensure_origin(origin)
+                .map_err(|_| Error::<T>::RequiresGovernance)?;
+
+            // Validate parameters
+            Self::validate_algorithm_params(&params)?;
+
+            // Get old params
+            let old_params = ReputationParams::<T>::get().unwrap_or_default();
+            if old_params.verification_multiplier != params.verification_multiplier
+                || old_params.contribution_type_weights != params.contribution_type_weights
+            {
+                Self::bump_algorithm_version()?;
+            }
+
+            // Update parameters
+            ReputationParams::<T>::put(params.clone());
+
+            Self::deposit_event(Event::AlgorithmParamsUpdated {
+                old_params,
+                new_params: params,
+            });
+
+            Ok(())
+        }
+
+        /// Initiate a cross-chain reputation query via XCM
+        ///
+        /// # Arguments
+        /// * `origin` - The account initiating the query
+        /// * `target_chain` - Target chain identifier
+        /// * `target_account` - Account to query on target chain
+        ///
+        /// # Errors
+        /// Returns `Error::XcmExecutionFailed` if XCM message fails
+        #[pallet::weight(Weight::from_parts(100_000_000, 0))]
+        pub fn initiate_reputation_query(
+            origin: OriginFor<T>,
+            target_chain: Vec<u8>,
+            target_account: Vec<u8>,
+        ) -> DispatchResult {
+            let _who = ensure_signed(origin)?;
+
+            // Validate target chain is supported
+            if !Self::is_chain_registered(&target_chain) {
+                return Err(Error::<T>::ChainNotSupported.into());
+            }
+
+            // Generate unique query ID
+            let query_id = Self::generate_query_id();
+
+            let query = ReputationQuery {
+                query_id,
+                target_chain: target_chain.clone(),
+                target_account: target_account.clone(),
+                status: QueryStatus::Pending,
+                initiated_at: frame_system::Pallet::<T>::block_number(),
+                response: None,
+                timeout: frame_system::Pallet::<T>::block_number() + PipelineParams::<T>::get().query_timeout.into(),
+            };
+
+            ReputationQueries::<T>::insert(query_id, query);
+
+            Self::deposit_event(Event::CrossChainQueryInitiated {
+                query_id,
+                target_chain: target_chain.clone(),
+                target_account,
+            });
+
+            // In a full implementation, this would construct and send XCM message
+            // For now, this is a placeholder
+
+            Ok(())
+        }
+
+        /// Submit off-chain worker verification result (unsigned transaction)
+        ///
+        /// This is called by off-chain workers to submit verification results
+        /// with cryptographic signatures for validation.
+        ///
+        /// # Arguments
+        /// * `account` - The account that made the contribution
+        /// * `contribution_id` - ID of the contribution
+        /// * `verified` - Whether the contribution was verified
+        /// * `timestamp` - Timestamp of verification
+        /// * `signature` - Cryptographic signature from OCW
+        ///
+        /// # Errors
+        /// Returns `Error::ContributionNotFound` if contribution doesn't exist
+        /// Returns `Error::OffchainFetchFailed` if signature verification fails
+        #[pallet::weight(Weight::from_parts(20_000_000, 0))]
+        #[pallet::call_index(4)]
+        pub fn submit_offchain_verification(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            contribution_id: ContributionId,
+            verified: bool,
+            timestamp: u64,
+            signature: Vec<u8>,
+        ) -> DispatchResult {
+            // This should be called as unsigned transaction
+            ensure_none(origin)?;
+            Self::ensure_not_paused()?;
+
+            // Get contribution
+            let mut contribution = Contributions::<T>::get(contribution_id)
+                .ok_or(Error::<T>::ContributionNotFound)?;
+
+            // Verify signature (in production, would verify against OCW public key)
+            // For now, basic validation
+            ensure!(!signature.is_empty(), Error::<T>::OffchainFetchFailed);
+
+            // Check timestamp is recent (within 1 minute)
+            let current_time = sp_io::offchain::timestamp().unix_millis();
+            let time_diff = current_time.saturating_sub(timestamp);
+            ensure!(
+                time_diff < 60_000, // 1 minute
+                Error::<T>::OffchainFetchFailed
+            );
+
+            if verified && (Self::awaits_maintainer_attestation(&contribution) || Self::requires_committee(&contribution)) {
+                // Counted, but the contribution stays pending until a maintainer attests it
+                // or the committee approves it
+                contribution.verification_count = contribution.verification_count.saturating_add(1);
+                Contributions::<T>::insert(contribution_id, &contribution);
+            } else if verified {
+                // Mark as verified by OCW
+                if !contribution.verified {
+                    VerifiedAt::<T>::insert(contribution_id, frame_system::Pallet::<T>::block_number());
+                    VerifiedContributionCounts::<T>::mutate(&account, |count| *count = count.saturating_add(1));
+                    Self::reward_referrer(&account);
+                    Self::clear_escalation(contribution_id);
+                }
+                contribution.verified = true;
+                contribution.status = ContributionStatus::Verified;
+                contribution.verification_count = contribution.verification_count.saturating_add(1);
+
+                // Update reputation if enough verifications
+                if contribution.verification_count >= T::MinVerifications::get() {
+                    let old_score = ReputationScores::<T>::get(&account);
+                    let points = Self::probation_adjusted(&account, Self::verified_points(&contribution)?);
+                    let new_score = Self::adjust_score(old_score, points)?;
+                    
+                    ReputationScores::<T>::insert(&account, new_score);
+                    Self::record_era_earnings(&account, new_score.saturating_sub(old_score));
+                    PendingContributions::<T>::mutate(&account, |count| *count = count.saturating_sub(1));
+                    Self::tally_hackathon_points(contribution_id, &account, points);
+
+                    Self::deposit_event(Event::ReputationUpdated {
+                        account: account.clone(),
+                        old_score,
+                        new_score,
+                        change_reason: RepChangeReason::VerificationReward,
+                    });
+                }
+
+                Contributions::<T>::insert(contribution_id, &contribution);
+
+                Self::deposit_event(Event::ContributionVerified {
+                    contributor: account,
+                    contribution_id,
+                    verifier: account.clone(), // OCW as verifier
+                    score: 100,
+                    reputation_gained: 0,
+                });
+            }
+
+            Ok(())
+        }
+
+        /// Batch add multiple contributions (efficient for bulk operations)
+        ///
+        /// # Arguments
+        /// * `proofs` - Vector of (proof, contribution_type, weight, source) tuples
+        ///
+        /// # Errors
+        /// Returns errors if any contribution fails validation
+        #[pallet::weight(Weight::from_parts(50_000_000, 0) * proofs.len() as u64)]
+        #[pallet::call_index(5)]
+        pub fn batch_add_contributions(
+            origin: OriginFor<T>,
+            proofs: Vec<(H256, ContributionType, u8, DataSource)>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::ensure_batch_size(proofs.len())?;
+
+            for (proof, contribution_type, weight, source) in proofs {
+                // Reuse add_contribution logic but skip event emission until end
+                let _ = Self::add_contribution_internal(
+                    &who,
+                    proof,
+                    contribution_type,
+                    weight,
+                    source,
+                )?;
+            }
+
+            Ok(())
+        }
+
+        /// Batch verify multiple contributions
+        ///
+        /// # Arguments
+        /// * `verifications` - Vector of (contributor, contribution_id, score, comment) tuples
+        #[pallet::weight(Weight::from_parts(25_000_000, 0) * verifications.len() as u64)]
+        #[pallet::call_index(6)]
+        pub fn batch_verify_contributions(
+            origin: OriginFor<T>,
+            verifications: Vec<(T::AccountId, ContributionId, u8, Vec<u8>)>,
+        ) -> DispatchResult {
+            let verifier = ensure_signed(origin)?;
+
+            // Check verifier has sufficient reputation
+            let verifier_reputation = ReputationScores::<T>::get(&verifier);
+            ensure!(
+                verifier_reputation >= T::MinReputationToVerify::get(),
+                Error::<T>::InsufficientReputationToVerify
+            );
+
+            Self::ensure_batch_size(verifications.len())?;
+
+            for (contributor, contribution_id, score, comment) in verifications {
+                // Reuse verify_contribution logic
+                let _ = Self::verify_contribution_internal(
+                    &verifier,
+                    &contributor,
+                    contribution_id,
+                    score,
+                    comment,
+                )?;
+            }
+
+            Ok(())
+        }
+
+        /// Apply a Sybil penalty to an account (governance-only)
+        ///
+        /// Resets the account's reputation to the minimum, puts it on probation for
+        /// `ProbationPeriod` and notifies the offence handler so staked funds can be slashed.
+        ///
+        /// # Errors
+        /// Returns `Error::RequiresGovernance` if origin is not governance
+        #[pallet::weight(Weight::from_parts(20_000_000, 0))]
+        #[pallet::call_index(7)]
+        pub fn penalize_sybil(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            detection_reason: Vec<u8>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)
+                .map_err(|_| Error::<T>::RequiresGovernance)?;
+
+            let old_score = ReputationScores::<T>::get(&account);
+            let new_score = T::MinReputation::get();
+            ReputationScores::<T>::insert(&account, new_score);
+            Self::reverse_era_earnings(&account, old_score.saturating_sub(new_score));
+
+            Self::deposit_event(Event::SybilAttackDetected {
+                account: account.clone(),
+                contribution_id: 0,
+                detection_reason,
+            });
+
+            Self::deposit_event(Event::ReputationUpdated {
+                account: account.clone(),
+                old_score,
+                new_score,
+                change_reason: RepChangeReason::SybilPenalty,
+            });
+
+            T::OnOffence::on_offence(&account, ReputationOffence::SybilPenalty);
+            Self::claw_back_referral(&account);
+            Self::start_probation(&account);
+
+            Ok(())
+        }
+
+        /// Overturn a previously verified contribution (governance-only)
+        ///
+        /// Marks the contribution as rejected, removes the reputation it awarded, puts
+        /// the contributor on probation and notifies the offence handler.
+        ///
+        /// # Errors
+        /// Returns `Error::ContributionNotFound` if contribution doesn't exist
+        /// Returns `Error::ContributionNotVerified` if contribution was never verified
+        #[pallet::weight(Weight::from_parts(25_000_000, 0))]
+        #[pallet::call_index(8)]
+        pub fn overturn_verification(
+            origin: OriginFor<T>,
+            contribution_id: ContributionId,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)
+                .map_err(|_| Error::<T>::RequiresGovernance)?;
+
+            let contributor = Self::do_overturn(contribution_id)?;
+            Self::start_probation(&contributor);
+            T::OnOffence::on_offence(&contributor, ReputationOffence::OverturnedVerification);
+
+            Ok(())
+        }
+
+        /// Allow `proxy` to submit contributions on the caller's behalf
+        ///
+        /// The proxy may submit at most `max_submissions` contributions per
+        /// `ProxySubmissionPeriod`. Adding an existing proxy updates its limit and
+        /// starts a fresh period.
+        ///
+        /// # Errors
+        /// Returns `Error::SelfProxyNotAllowed` if `proxy` is the caller
+        ///
+        /// # Events
+        /// Emits `SubmissionProxyAdded` on success
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(9)]
+        pub fn add_submission_proxy(
+            origin: OriginFor<T>,
+            proxy: T::AccountId,
+            max_submissions: u32,
+        ) -> DispatchResult {
+            let principal = ensure_signed(origin)?;
+            ensure!(principal != proxy, Error::<T>::SelfProxyNotAllowed);
+
+            SubmissionProxies::<T>::insert(&principal, &proxy, SubmissionProxy {
+                max_submissions,
+                period_start: frame_system::Pallet::<T>::block_number(),
+                submissions: 0,
+            });
+
+            Self::deposit_event(Event::SubmissionProxyAdded { principal, proxy, max_submissions });
+
+            Ok(())
+        }
+
+        /// Revoke a submission proxy of the caller
+        ///
+        /// # Errors
+        /// Returns `Error::NotSubmissionProxy` if `proxy` is not a proxy of the caller
+        ///
+        /// # Events
+        /// Emits `SubmissionProxyRemoved` on success
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(10)]
+        pub fn remove_submission_proxy(
+            origin: OriginFor<T>,
+            proxy: T::AccountId,
+        ) -> DispatchResult {
+            let principal = ensure_signed(origin)?;
+
+            SubmissionProxies::<T>::take(&principal, &proxy).ok_or(Error::<T>::NotSubmissionProxy)?;
+
+            Self::deposit_event(Event::SubmissionProxyRemoved { principal, proxy });
+
+            Ok(())
+        }
+
+        /// Submit a contribution on behalf of `principal` as its submission proxy
+        ///
+        /// The contribution belongs to `principal` and goes through the same checks as
+        /// `add_contribution`, including the principal's own rate limit and Sybil
+        /// detection. Only successful submissions count towards the proxy's limit.
+        ///
+        /// # Errors
+        /// Returns `Error::NotSubmissionProxy` if the caller is not a proxy of `principal`
+        /// Returns `Error::ProxyRateLimited` if the proxy used up its submissions for
+        /// the current period
+        ///
+        /// # Events
+        /// Emits `ContributionSubmitted` and `ContributionSubmittedByProxy` on success
+        #[pallet::weight(
+            <T as Config>::WeightInfo::add_contribution().saturating_add(T::DbWeight::get().reads_writes(1, 1))
+        )]
+        #[pallet::call_index(11)]
+        pub fn proxy_add_contribution(
+            origin: OriginFor<T>,
+            principal: T::AccountId,
+            proof: H256,
+            contribution_type: ContributionType,
+            weight: u8,
+            source: DataSource,
+        ) -> DispatchResultWithPostInfo {
+            let proxy = ensure_signed(origin)?;
+
+            let mut rights = SubmissionProxies::<T>::get(&principal, &proxy)
+                .ok_or(Error::<T>::NotSubmissionProxy)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            if now >= rights.period_start.saturating_add(T::ProxySubmissionPeriod::get()) {
+                rights.period_start = now;
+                rights.submissions = 0;
+            }
+            ensure!(rights.submissions < rights.max_submissions, Error::<T>::ProxyRateLimited);
+
+            let contribution_id = Self::submit_contribution(&principal, proof, contribution_type, weight, source)?;
+            rights.submissions = rights.submissions.saturating_add(1);
+            SubmissionProxies::<T>::insert(&principal, &proxy, rights);
+
+            Self::deposit_event(Event::ContributionSubmittedByProxy { principal, proxy, contribution_id });
+
+            Ok(().into())
+        }
+
+        /// Register a project, with the caller as owner and first maintainer
+        ///
+        /// Replaces a project of the same repository whose owner did not prove control of
+        /// it within `ProjectClaimExpiry`, so a repository cannot be squatted.
+        ///
+        /// # Errors
+        /// Returns `Error::InvalidRepository` if the repository URL is empty or too long
+        /// Returns `Error::RepositoryAlreadyRegistered` if the repository has a verified
+        /// project, or an unverified one still within `ProjectClaimExpiry`
+        ///
+        /// # Events
+        /// Emits `ProjectRegistered` and `MaintainerAdded` on success, preceded by
+        /// `ProjectReleased` when an unverified project is replaced
+        #[pallet::weight(Weight::from_parts(15_000_000, 0))]
+        #[pallet::call_index(12)]
+        pub fn register_project(
+            origin: OriginFor<T>,
+            repository: Vec<u8>,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+
+            ensure!(!repository.is_empty(), Error::<T>::InvalidRepository);
+            let repository: ProjectRepository = repository
+                .try_into()
+                .map_err(|_| Error::<T>::InvalidRepository)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            if let Some(stale_id) = ProjectsByRepository::<T>::get(&repository) {
+                let stale = Projects::<T>::get(stale_id).ok_or(Error::<T>::ProjectNotFound)?;
+                ensure!(
+                    stale.status == ProjectStatus::Unverified
+                        && now >= stale.registered_at.saturating_add(T::ProjectClaimExpiry::get()),
+                    Error::<T>::RepositoryAlreadyRegistered
+                );
+                Projects::<T>::remove(stale_id);
+                OwnershipChallenges::<T>::remove(stale_id);
+                let _ = ProjectMaintainers::<T>::clear_prefix(stale_id, u32::MAX, None);
+                Self::deposit_event(Event::ProjectReleased { project_id: stale_id });
+            }
+
+            let project_id = NextProjectId::<T>::mutate(|id| {
+                let project_id = *id;
+                *id = id.saturating_add(1);
+                project_id
+            });
+            Projects::<T>::insert(project_id, Project {
+                owner: owner.clone(),
+                repository: repository.clone(),
+                registered_at: now,
+                status: ProjectStatus::Unverified,
+            });
+            ProjectsByRepository::<T>::insert(&repository, project_id);
+            ProjectMaintainers::<T>::insert(project_id, &owner, now);
+
+            Self::deposit_event(Event::ProjectRegistered { project_id, owner: owner.clone(), repository });
+            Self::deposit_event(Event::MaintainerAdded { project_id, maintainer: owner });
+
+            Ok(())
+        }
+
+        /// Add a maintainer to a project owned by the caller
+        ///
+        /// # Errors
+        /// Returns `Error::NotProjectOwner` if the caller does not own the project
+        /// Returns `Error::AlreadyProjectMaintainer` if the account already maintains it
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(13)]
+        pub fn add_project_maintainer(
+            origin: OriginFor<T>,
+            project_id: ProjectId,
+            maintainer: T::AccountId,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+            Self::owned_project(project_id, &owner)?;
+            ensure!(
+                !ProjectMaintainers::<T>::contains_key(project_id, &maintainer),
+                Error::<T>::AlreadyProjectMaintainer
+            );
+
+            ProjectMaintainers::<T>::insert(project_id, &maintainer, frame_system::Pallet::<T>::block_number());
+
+            Self::deposit_event(Event::MaintainerAdded { project_id, maintainer });
+
+            Ok(())
+        }
+
+        /// Remove a maintainer from a project owned by the caller
+        ///
+        /// Attestations the maintainer already made stay in place.
+        ///
+        /// # Errors
+        /// Returns `Error::NotProjectOwner` if the caller does not own the project
+        /// Returns `Error::NotProjectMaintainer` if the account does not maintain it
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(14)]
+        pub fn remove_project_maintainer(
+            origin: OriginFor<T>,
+            project_id: ProjectId,
+            maintainer: T::AccountId,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+            Self::owned_project(project_id, &owner)?;
+
+            ProjectMaintainers::<T>::take(project_id, &maintainer).ok_or(Error::<T>::NotProjectMaintainer)?;
+
+            Self::deposit_event(Event::MaintainerRemoved { project_id, maintainer });
+
+            Ok(())
+        }
+
+        /// Link one of the caller's pending contributions to the project it was made to
+        ///
+        /// Only contributions linked to a project can be attested by its maintainers.
+        ///
+        /// # Errors
+        /// Returns `Error::ContributionNotFound` if the caller did not submit the contribution
+        /// Returns `Error::ContributionAlreadyVerified` if the contribution is verified
+        /// Returns `Error::ProjectNotFound` if the project does not exist
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(15)]
+        pub fn set_contribution_project(
+            origin: OriginFor<T>,
+            contribution_id: ContributionId,
+            project_id: ProjectId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let contribution = Contributions::<T>::get(contribution_id)
+                .ok_or(Error::<T>::ContributionNotFound)?;
+            ensure!(
+                ContributionProofs::<T>::get(contribution.proof) == Some(who),
+                Error::<T>::ContributionNotFound
+            );
+            ensure!(!contribution.verified, Error::<T>::ContributionAlreadyVerified);
+            ensure!(
+                !MaintainerAttestations::<T>::contains_key(contribution_id),
+                Error::<T>::AlreadyAttested
+            );
+            ensure!(Projects::<T>::contains_key(project_id), Error::<T>::ProjectNotFound);
+
+            Self::assign_project(contribution_id, project_id);
+
+            Self::deposit_event(Event::ContributionProjectSet { contribution_id, project_id });
+
+            Ok(())
+        }
+
+        /// Co-sign a pending contribution as a maintainer of its project
+        ///
+        /// Only maintainers of projects with verified repository ownership can attest, and
+        /// they must qualify as verifiers. The attestation counts as
+        /// `maintainer_attestation_weight` verifications, always fewer than
+        /// `MinVerifications`, or as none for git log imports, and is required before
+        /// contributions weighing more than `MaintainerAttestationThreshold` can be
+        /// verified. A maintainer cannot both verify and attest the same contribution.
+        ///
+        /// # Errors
+        /// Returns `Error::InsufficientReputationToVerify` if the maintainer lacks the
+        /// reputation required to verify
+        /// Returns `Error::OnProbation` if the maintainer is on probation
+        /// Returns `Error::VerificationRightsRevoked` if the maintainer reached `MaxOutlierStrikes`
+        /// Returns `Error::NoContributionProject` if the contribution has no project
+        /// Returns `Error::ProjectNotVerified` if the project's ownership is unverified
+        /// Returns `Error::NotProjectMaintainer` if the caller does not maintain it
+        /// Returns `Error::AlreadyAttested` if a maintainer already attested it
+        /// Returns `Error::PipelinePaused` if the pipeline is paused
+        ///
+        /// # Events
+        /// Emits `MaintainerAttested` on success
+        #[pallet::weight(<T as Config>::WeightInfo::verify_contribution())]
+        #[pallet::call_index(16)]
+        pub fn maintainer_attest(
+            origin: OriginFor<T>,
+            contribution_id: ContributionId,
+        ) -> DispatchResult {
+            let maintainer = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            Self::ensure_qualified_verifier(&maintainer)?;
+
+            let mut contribution = Contributions::<T>::get(contribution_id)
+                .ok_or(Error::<T>::ContributionNotFound)?;
+            ensure!(!contribution.verified, Error::<T>::ContributionAlreadyVerified);
+            ensure!(contribution.status != ContributionStatus::Imported, Error::<T>::AwaitsImportVerification);
+            let project_id = ContributionProjects::<T>::get(contribution_id)
+                .ok_or(Error::<T>::NoContributionProject)?;
+            let project = Projects::<T>::get(project_id).ok_or(Error::<T>::ProjectNotFound)?;
+            ensure!(project.status == ProjectStatus::Verified, Error::<T>::ProjectNotVerified);
+            ensure!(
+                Self::is_project_maintainer(project_id, &maintainer),
+                Error::<T>::NotProjectMaintainer
+            );
+            let contributor = ContributionProofs::<T>::get(contribution.proof)
+                .ok_or(Error::<T>::ContributionNotFound)?;
+            ensure!(maintainer != contributor, Error::<T>::SelfVerificationNotAllowed);
+            ensure!(
+                !MaintainerAttestations::<T>::contains_key(contribution_id),
+                Error::<T>::AlreadyAttested
+            );
+            ensure!(
+                !ContributionVerifications::<T>::contains_key(contribution_id, &maintainer),
+                Error::<T>::ContributionAlreadyVerified
+            );
+
+            MaintainerAttestations::<T>::insert(contribution_id, &maintainer);
+            // Git imports are attested by the maintainer vouching for their log, not verified
+            if !GitImports::<T>::contains_key(contribution_id) {
+                contribution.verification_count = contribution.verification_count
+                    .saturating_add(Self::maintainer_attestation_weight());
+            }
+            Self::try_complete_verification(&contributor, &mut contribution)?;
+            Contributions::<T>::insert(contribution_id, &contribution);
+
+            Self::deposit_event(Event::MaintainerAttested { contribution_id, project_id, maintainer });
+
+            Ok(())
+        }
+
+        /// Issue a challenge token proving control of a project's repository
+        ///
+        /// The owner commits the token (as `0x`-prefixed hex) to a `.dotrep` file at the
+        /// repository root; the off-chain worker then confirms ownership. Requesting a
+        /// new challenge replaces the open one.
+        ///
+        /// # Errors
+        /// Returns `Error::NotProjectOwner` if the caller does not own the project
+        /// Returns `Error::ProjectAlreadyVerified` if ownership is already verified
+        ///
+        /// # Events
+        /// Emits `OwnershipChallengeIssued` on success
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(17)]
+        pub fn request_ownership_challenge(
+            origin: OriginFor<T>,
+            project_id: ProjectId,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+            let project = Self::owned_project(project_id, &owner)?;
+            ensure!(project.status != ProjectStatus::Verified, Error::<T>::ProjectAlreadyVerified);
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let parent_hash = frame_system::Pallet::<T>::parent_hash();
+            let token = H256(sp_io::hashing::blake2_256(
+                &(b"dotrep/ownership", project_id, &owner, parent_hash, now).encode(),
+            ));
+            OwnershipChallenges::<T>::insert(project_id, (token, now));
+
+            Self::deposit_event(Event::OwnershipChallengeIssued { project_id, token });
+
+            Ok(())
+        }
+
+        /// Mark a project as verified after its challenge token was found in the repository
+        ///
+        /// Submitted by the off-chain worker. The token guards against confirming a
+        /// challenge that was replaced while the check was in flight.
+        ///
+        /// # Errors
+        /// Returns `Error::NoOwnershipChallenge` if no challenge is open for the project
+        /// Returns `Error::ChallengeMismatch` if `token` is not the open challenge's token
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(18)]
+        pub fn confirm_project_ownership(
+            origin: OriginFor<T>,
+            project_id: ProjectId,
+            token: H256,
+        ) -> DispatchResult {
+            T::OwnershipVerifierOrigin::ensure_origin(origin)?;
+
+            let (expected, _) = OwnershipChallenges::<T>::get(project_id)
+                .ok_or(Error::<T>::NoOwnershipChallenge)?;
+            ensure!(token == expected, Error::<T>::ChallengeMismatch);
+
+            Projects::<T>::try_mutate(project_id, |project| -> DispatchResult {
+                let project = project.as_mut().ok_or(Error::<T>::ProjectNotFound)?;
+                project.status = ProjectStatus::Verified;
+                Ok(())
+            })?;
+            OwnershipChallenges::<T>::remove(project_id);
+
+            Self::deposit_event(Event::ProjectVerified { project_id });
+
+            Ok(())
+        }
+
+        /// Tag a pending contribution with the skills needed to review it
+        ///
+        /// Verifiers who declared one of these skills in governance are listed by
+        /// `eligible_verifiers` and their verifications count as `SkillMatchMultiplier`.
+        /// Replaces any earlier tags.
+        ///
+        /// # Errors
+        /// Returns `Error::ContributionNotFound` if the caller did not submit the contribution
+        /// Returns `Error::ContributionAlreadyVerified` if the contribution is verified
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(19)]
+        pub fn tag_contribution_skills(
+            origin: OriginFor<T>,
+            contribution_id: ContributionId,
+            skills: ContributionSkillsOf,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let contribution = Contributions::<T>::get(contribution_id)
+                .ok_or(Error::<T>::ContributionNotFound)?;
+            ensure!(
+                ContributionProofs::<T>::get(contribution.proof) == Some(who),
+                Error::<T>::ContributionNotFound
+            );
+            ensure!(!contribution.verified, Error::<T>::ContributionAlreadyVerified);
+
+            ContributionSkills::<T>::insert(contribution_id, &skills);
+
+            Self::deposit_event(Event::ContributionSkillsTagged {
+                contribution_id,
+                skills: skills.into_inner(),
+            });
+
+            Ok(())
+        }
+
+        /// Withdraw one of the caller's pending contributions, releasing its deposit
+        ///
+        /// Verifications already given are discarded and the proof, or a bundle's item
+        /// proofs, may be submitted again.
+        ///
+        /// # Errors
+        /// Returns `Error::ContributionNotFound` if the caller did not submit the contribution
+        /// Returns `Error::ContributionNotWithdrawable` if the contribution is no longer pending
+        ///
+        /// # Weight
+        /// Charged for releasing `MAX_BUNDLE_ITEMS` item proofs; refunded to the released ones
+        #[pallet::weight(Pallet::<T>::withdraw_weight(MAX_BUNDLE_ITEMS))]
+        #[pallet::call_index(20)]
+        pub fn withdraw_contribution(
+            origin: OriginFor<T>,
+            contribution_id: ContributionId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let contribution = Contributions::<T>::get(contribution_id)
+                .ok_or(Error::<T>::ContributionNotFound)?;
+            ensure!(
+                ContributionProofs::<T>::get(contribution.proof) == Some(who.clone()),
+                Error::<T>::ContributionNotFound
+            );
+            ensure!(
+                contribution.status == ContributionStatus::Pending,
+                Error::<T>::ContributionNotWithdrawable
+            );
+
+            ContributionsByProof::<T>::remove(contribution.proof);
+            let mut released = 0u32;
+            for (item_proof, ()) in BundleItemProofs::<T>::drain_prefix(contribution_id) {
+                ContributionsByProof::<T>::remove(item_proof);
+                released = released.saturating_add(1);
+            }
+            PendingContributions::<T>::mutate(&who, |count| *count = count.saturating_sub(1));
+            let deposit = Self::remove_contribution(&who, &contribution);
+
+            Self::deposit_event(Event::ContributionWithdrawn { contributor: who, contribution_id, deposit });
+
+            Ok(Some(Self::withdraw_weight(released)).into())
+        }
+
+        /// Remove a rejected contribution, returning its deposit to the depositor
+        ///
+        /// Callable by anyone. The proof stays recorded, so a rejected contribution
+        /// cannot be submitted again.
+        ///
+        /// # Errors
+        /// Returns `Error::ContributionNotFound` if the contribution doesn't exist
+        /// Returns `Error::ContributionNotPrunable` if the contribution is not rejected
+        #[pallet::weight(Weight::from_parts(30_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(T::MinVerifications::get().into())))]
+        #[pallet::call_index(21)]
+        pub fn prune_contribution(
+            origin: OriginFor<T>,
+            contribution_id: ContributionId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let contribution = Contributions::<T>::get(contribution_id)
+                .ok_or(Error::<T>::ContributionNotFound)?;
+            ensure!(
+                contribution.status == ContributionStatus::Rejected,
+                Error::<T>::ContributionNotPrunable
+            );
+            let contributor = ContributionProofs::<T>::get(contribution.proof)
+                .ok_or(Error::<T>::ContributionNotFound)?;
+
+            let deposit = Self::remove_contribution(&contributor, &contribution);
+
+            Self::deposit_event(Event::ContributionPruned { contributor, contribution_id, deposit });
+
+            Ok(())
+        }
+
+        /// Purge the caller's settled contribution records, keeping an archival digest
+        ///
+        /// Rejected contributions, and verified ones whose `DisputeWindow` has passed, are
+        /// removed and their deposits released. Verified contributions that can still be
+        /// reported as fraudulent are kept.
+        /// Their Merkle root, folded with any earlier archive's, is kept as the account's
+        /// `ContributionArchive` and the account is queued for DKG publishing so the root
+        /// is anchored with its next asset. Scores and contribution counts are kept, as
+        /// are pending contributions and proof hashes, so purged proofs cannot be
+        /// submitted again.
+        ///
+        /// # Errors
+        /// Returns `Error::ContributionsUnderDispute` if a dispute against the caller is open
+        /// Returns `Error::NothingToArchive` if the caller has no settled contributions
+        #[pallet::weight(Weight::from_parts(30_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(
+            u64::from(T::MaxContributionsPerAccount::get()).saturating_mul(3).saturating_add(3),
+            u64::from(T::MaxContributionsPerAccount::get()).saturating_mul(8).saturating_add(3),
+        )))]
+        #[pallet::call_index(22)]
+        pub fn purge_my_data(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!T::Disputes::has_open_disputes(&who), Error::<T>::ContributionsUnderDispute);
+
+            let previous = ContributionArchives::<T>::get(&who);
+            let mut leaves: Vec<H256> = previous.iter().map(|archive| archive.root).collect();
+            let mut archived: u32 = 0;
+            for contribution_id in AccountContributions::<T>::get(&who) {
+                let Some(contribution) = Contributions::<T>::get(contribution_id) else { continue };
+                let settled = match contribution.status {
+                    ContributionStatus::Verified => !Self::is_disputable(&contribution),
+                    ContributionStatus::Rejected => true,
+                    _ => false,
+                };
+                if !settled {
+                    continue;
+                }
+                leaves.push(crate::archive::contribution_leaf(&contribution));
+                Self::remove_contribution(&who, &contribution);
+                archived = archived.saturating_add(1);
+            }
+            ensure!(archived > 0, Error::<T>::NothingToArchive);
+
+            let root = crate::archive::merkle_root(leaves);
+            ContributionArchives::<T>::insert(&who, ContributionArchive {
+                root,
+                contributions: previous.map_or(0, |archive| archive.contributions).saturating_add(archived),
+                archived_at: frame_system::Pallet::<T>::block_number(),
+            });
+            T::Dkg::publish_to_dkg(&who, Self::get_reputation(&who).max(0) as u32)?;
+
+            Self::deposit_event(Event::ContributionsArchived { account: who, root, contributions: archived });
+
+            Ok(())
+        }
+
+        /// Create a hackathon accepting contributions to `projects` from `start` to `end`
+        ///
+        /// `pot` is reserved from the organizer until the hackathon is closed.
+        ///
+        /// # Errors
+        /// Returns `Error::InvalidHackathonWindow` if `end` is before `start` or `start` has passed
+        /// Returns `Error::ProjectNotFound` if a project does not exist
+        /// Returns `Error::InsufficientHackathonPot` if the pot cannot be reserved
+        #[pallet::weight(Weight::from_parts(20_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads_writes(T::MaxHackathonProjects::get().into(), 3)))]
+        #[pallet::call_index(23)]
+        pub fn create_hackathon(
+            origin: OriginFor<T>,
+            start: T::BlockNumber,
+            end: T::BlockNumber,
+            projects: BoundedVec<ProjectId, T::MaxHackathonProjects>,
+            pot: BalanceOf<T>,
+        ) -> DispatchResult {
+            let organizer = ensure_signed(origin)?;
+            ensure!(
+                start >= frame_system::Pallet::<T>::block_number() && end >= start,
+                Error::<T>::InvalidHackathonWindow
+            );
+            for project_id in projects.iter() {
+                ensure!(Projects::<T>::contains_key(project_id), Error::<T>::ProjectNotFound);
+            }
+            T::Currency::reserve(&organizer, pot).map_err(|_| Error::<T>::InsufficientHackathonPot)?;
+
+            let hackathon_id = NextHackathonId::<T>::mutate(|id| {
+                let current = *id;
+                *id = id.saturating_add(1);
+                current
+            });
+            Hackathons::<T>::insert(hackathon_id, Hackathon {
+                organizer: organizer.clone(),
+                start,
+                end,
+                projects,
+                pot,
+                closed: false,
+            });
+
+            Self::deposit_event(Event::HackathonCreated { hackathon_id, organizer, start, end, pot });
+
+            Ok(())
+        }
+
+        /// Enter one of the caller's pending contributions into a hackathon
+        ///
+        /// The contribution must be linked to one of the hackathon's projects with
+        /// `set_contribution_project`. Once verified, the points it earns are also
+        /// tallied on the hackathon's leaderboard.
+        ///
+        /// # Errors
+        /// Returns `Error::ContributionNotFound` if the caller did not submit the contribution
+        /// Returns `Error::ContributionAlreadyVerified` if the contribution is verified
+        /// Returns `Error::HackathonNotOpen` if the hackathon is not running
+        /// Returns `Error::ProjectNotInHackathon` if the contribution's project is not part of it
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(24)]
+        pub fn enter_hackathon(
+            origin: OriginFor<T>,
+            contribution_id: ContributionId,
+            hackathon_id: HackathonId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let contribution = Contributions::<T>::get(contribution_id)
+                .ok_or(Error::<T>::ContributionNotFound)?;
+            ensure!(
+                ContributionProofs::<T>::get(contribution.proof) == Some(who),
+                Error::<T>::ContributionNotFound
+            );
+            ensure!(!contribution.verified, Error::<T>::ContributionAlreadyVerified);
+
+            let hackathon = Hackathons::<T>::get(hackathon_id).ok_or(Error::<T>::HackathonNotFound)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                !hackathon.closed && hackathon.start <= now && now <= hackathon.end,
+                Error::<T>::HackathonNotOpen
+            );
+            ensure!(
+                ContributionProjects::<T>::get(contribution_id)
+                    .map_or(false, |project_id| hackathon.projects.contains(&project_id)),
+                Error::<T>::ProjectNotInHackathon
+            );
+
+            ContributionHackathons::<T>::insert(contribution_id, hackathon_id);
+
+            Self::deposit_event(Event::HackathonEntered { hackathon_id, contribution_id });
+
+            Ok(())
+        }
+
+        /// Close an ended hackathon and share its pot across the leaderboard
+        ///
+        /// Callable by anyone once the hackathon has ended. Each leaderboard place gets
+        /// a share of the pot proportional to its points; rounding dust and the whole
+        /// pot of a hackathon without verified entries stay with the organizer.
+        ///
+        /// # Errors
+        /// Returns `Error::HackathonNotFound` if the hackathon does not exist
+        /// Returns `Error::HackathonNotEnded` if the hackathon is still running
+        /// Returns `Error::HackathonClosed` if the hackathon was already closed
+        #[pallet::weight(Weight::from_parts(20_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads_writes(
+                u64::from(T::MaxHackathonWinners::get()).saturating_add(2),
+                u64::from(T::MaxHackathonWinners::get()).saturating_add(2),
+            )))]
+        #[pallet::call_index(25)]
+        pub fn close_hackathon(origin: OriginFor<T>, hackathon_id: HackathonId) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let mut hackathon = Hackathons::<T>::get(hackathon_id).ok_or(Error::<T>::HackathonNotFound)?;
+            ensure!(!hackathon.closed, Error::<T>::HackathonClosed);
+            ensure!(
+                frame_system::Pallet::<T>::block_number() > hackathon.end,
+                Error::<T>::HackathonNotEnded
+            );
+
+            T::Currency::unreserve(&hackathon.organizer, hackathon.pot);
+            let leaderboard = HackathonLeaderboards::<T>::get(hackathon_id);
+            let total: u32 = leaderboard.iter().fold(0, |total, (_, points)| total.saturating_add(*points));
+            let mut paid: BalanceOf<T> = Zero::zero();
+            for (account, points) in leaderboard {
+                let amount = Perbill::from_rational(points, total) * hackathon.pot;
+                if amount.is_zero()
+                    || T::Currency::transfer(&hackathon.organizer, &account, amount, ExistenceRequirement::AllowDeath)
+                        .is_err()
+                {
+                    continue;
+                }
+                paid = paid.saturating_add(amount);
+                Self::deposit_event(Event::HackathonRewardPaid { hackathon_id, account, amount });
+            }
+
+            hackathon.closed = true;
+            Hackathons::<T>::insert(hackathon_id, hackathon);
+
+            Self::deposit_event(Event::HackathonClosed { hackathon_id, paid });
+
+            Ok(())
+        }
+
+        /// Register the caller as mentor of `mentee`
+        ///
+        /// The mentorship becomes active once the mentee accepts it.
+        ///
+        /// # Errors
+        /// Returns `Error::CannotMentorSelf` if `mentee` is the caller
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(26)]
+        pub fn register_mentorship(origin: OriginFor<T>, mentee: T::AccountId) -> DispatchResult {
+            let mentor = ensure_signed(origin)?;
+            ensure!(mentor != mentee, Error::<T>::CannotMentorSelf);
+
+            let mentorship_id = NextMentorshipId::<T>::mutate(|id| {
+                let current = *id;
+                *id = id.saturating_add(1);
+                current
+            });
+            Mentorships::<T>::insert(mentorship_id, Mentorship {
+                mentor: mentor.clone(),
+                mentee: mentee.clone(),
+                status: MentorshipStatus::Proposed,
+                sessions: 0,
+                registered_at: frame_system::Pallet::<T>::block_number(),
+                signed_off_by: None,
+            });
+
+            Self::deposit_event(Event::MentorshipRegistered { mentorship_id, mentor, mentee });
+
+            Ok(())
+        }
+
+        /// Accept a mentorship as its mentee
+        ///
+        /// # Errors
+        /// Returns `Error::NotMentee` if the caller is not the mentee
+        /// Returns `Error::InvalidMentorshipStatus` if the mentorship is not proposed
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(27)]
+        pub fn accept_mentorship(origin: OriginFor<T>, mentorship_id: MentorshipId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Mentorships::<T>::try_mutate(mentorship_id, |mentorship| -> DispatchResult {
+                let mentorship = mentorship.as_mut().ok_or(Error::<T>::MentorshipNotFound)?;
+                ensure!(mentorship.mentee == who, Error::<T>::NotMentee);
+                ensure!(mentorship.status == MentorshipStatus::Proposed, Error::<T>::InvalidMentorshipStatus);
+                mentorship.status = MentorshipStatus::Active;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::MentorshipAccepted { mentorship_id });
+
+            Ok(())
+        }
+
+        /// Attest a mentorship session as its mentee
+        ///
+        /// The session is submitted as a `Mentorship` contribution of the mentor, weighing
+        /// `MentorshipSessionWeight`, and earns reputation once verified like any other.
+        /// The mentor pays the contribution deposit.
+        ///
+        /// # Arguments
+        /// * `proof` - Hash of the session record, e.g. notes or a recording
+        ///
+        /// # Errors
+        /// Returns `Error::NotMentee` if the caller is not the mentee
+        /// Returns `Error::InvalidMentorshipStatus` if the mentorship is not active
+        /// Returns the errors of `add_contribution` for the mentor's submission
+        #[pallet::weight(<T as Config>::WeightInfo::add_contribution())]
+        #[pallet::call_index(28)]
+        pub fn attest_mentorship_session(
+            origin: OriginFor<T>,
+            mentorship_id: MentorshipId,
+            proof: H256,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let mut mentorship = Mentorships::<T>::get(mentorship_id).ok_or(Error::<T>::MentorshipNotFound)?;
+            ensure!(mentorship.mentee == who, Error::<T>::NotMentee);
+            ensure!(mentorship.status == MentorshipStatus::Active, Error::<T>::InvalidMentorshipStatus);
+
+            let contribution_id = Self::submit_contribution(
+                &mentorship.mentor,
+                proof,
+                ContributionType::Mentorship,
+                T::MentorshipSessionWeight::get(),
+                DataSource::Manual,
+            )?;
+            mentorship.sessions = mentorship.sessions.saturating_add(1);
+            Mentorships::<T>::insert(mentorship_id, mentorship);
+
+            Self::deposit_event(Event::MentorshipSessionAttested { mentorship_id, contribution_id });
+
+            Ok(().into())
+        }
+
+        /// Sign off on an active mentorship as its mentor or mentee
+        ///
+        /// The mentorship completes once both have signed off, counting towards the
+        /// mentor's `CompletedMentorships`.
+        ///
+        /// # Errors
+        /// Returns `Error::NotMentorshipParty` if the caller is neither mentor nor mentee
+        /// Returns `Error::InvalidMentorshipStatus` if the mentorship is not active
+        /// Returns `Error::AlreadySignedOff` if the caller already signed off
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(29)]
+        pub fn sign_off_mentorship(origin: OriginFor<T>, mentorship_id: MentorshipId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut mentorship = Mentorships::<T>::get(mentorship_id).ok_or(Error::<T>::MentorshipNotFound)?;
+            ensure!(who == mentorship.mentor || who == mentorship.mentee, Error::<T>::NotMentorshipParty);
+            ensure!(mentorship.status == MentorshipStatus::Active, Error::<T>::InvalidMentorshipStatus);
+            ensure!(mentorship.signed_off_by.as_ref() != Some(&who), Error::<T>::AlreadySignedOff);
+
+            Self::deposit_event(Event::MentorshipSignedOff { mentorship_id, who: who.clone() });
+
+            if mentorship.signed_off_by.is_none() {
+                mentorship.signed_off_by = Some(who);
+            } else {
+                mentorship.status = MentorshipStatus::Completed;
+                CompletedMentorships::<T>::mutate(&mentorship.mentor, |count| *count = count.saturating_add(1));
+                Self::deposit_event(Event::MentorshipCompleted {
+                    mentorship_id,
+                    mentor: mentorship.mentor.clone(),
+                    sessions: mentorship.sessions,
+                });
+            }
+            Mentorships::<T>::insert(mentorship_id, mentorship);
+
+            Ok(())
+        }
+
+        /// Reject or end a mentorship before it completes, as its mentor or mentee
+        ///
+        /// Lets the mentor stop a mentee from attesting further sessions, whose deposits
+        /// the mentor pays. Sessions already attested stay with their verification.
+        ///
+        /// # Errors
+        /// Returns `Error::NotMentorshipParty` if the caller is neither mentor nor mentee
+        /// Returns `Error::InvalidMentorshipStatus` if the mentorship is completed or ended
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(80)]
+        pub fn end_mentorship(origin: OriginFor<T>, mentorship_id: MentorshipId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Mentorships::<T>::try_mutate(mentorship_id, |mentorship| -> DispatchResult {
+                let mentorship = mentorship.as_mut().ok_or(Error::<T>::MentorshipNotFound)?;
+                ensure!(who == mentorship.mentor || who == mentorship.mentee, Error::<T>::NotMentorshipParty);
+                ensure!(
+                    matches!(mentorship.status, MentorshipStatus::Proposed | MentorshipStatus::Active),
+                    Error::<T>::InvalidMentorshipStatus
+                );
+                mentorship.status = MentorshipStatus::Ended;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::MentorshipEnded { mentorship_id, who });
+
+            Ok(())
+        }
+
+        /// Offer to refer a newcomer as an existing contributor
+        ///
+        /// The referral takes effect once `new_account` accepts it with `accept_referral`.
+        /// The caller then earns `ReferralBonus` once `new_account` has
+        /// `ReferralQualifyingContributions` verified contributions. The bonus is clawed back
+        /// if `new_account` is later penalized as a Sybil. Offers count towards
+        /// `MaxReferralsPerEpoch` whether or not they are accepted.
+        ///
+        /// # Errors
+        /// Returns `Error::CannotReferSelf` if `new_account` is the caller
+        /// Returns `Error::NotAContributor` if the caller has no verified contribution
+        /// Returns `Error::NotANewcomer` if `new_account` has contributions or a referrer
+        /// Returns `Error::ReferralAlreadyOffered` if the caller already offered to refer `new_account`
+        /// Returns `Error::ReferralCapReached` if the caller made `MaxReferralsPerEpoch` referrals this epoch
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(30)]
+        pub fn refer(origin: OriginFor<T>, new_account: T::AccountId) -> DispatchResult {
+            let referrer = ensure_signed(origin)?;
+            ensure!(referrer != new_account, Error::<T>::CannotReferSelf);
+            ensure!(VerifiedContributionCounts::<T>::get(&referrer) > 0, Error::<T>::NotAContributor);
+            ensure!(
+                !Referrals::<T>::contains_key(&new_account) && AccountContributions::<T>::get(&new_account).is_empty(),
+                Error::<T>::NotANewcomer
+            );
+            ensure!(
+                !ReferralOffers::<T>::contains_key(&new_account, &referrer),
+                Error::<T>::ReferralAlreadyOffered
+            );
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let epoch: u32 = (now / T::ReferralEpoch::get().max(One::one())).unique_saturated_into();
+            EpochReferrals::<T>::try_mutate(&referrer, |(last_epoch, count)| {
+                if *last_epoch != epoch {
+                    *last_epoch = epoch;
+                    *count = 0;
+                }
+                ensure!(*count < T::MaxReferralsPerEpoch::get(), Error::<T>::ReferralCapReached);
+                *count += 1;
+                Ok::<_, Error<T>>(())
+            })?;
+
+            ReferralOffers::<T>::insert(&new_account, &referrer, now);
+
+            Self::deposit_event(Event::ReferralOffered { referrer, referee: new_account });
+
+            Ok(())
+        }
+
+        /// Accept `referrer`'s offer to refer the caller
+        ///
+        /// Other pending offers to the caller are dropped.
+        ///
+        /// # Errors
+        /// Returns `Error::NoReferralOffer` if `referrer` did not offer to refer the caller
+        /// Returns `Error::NotANewcomer` if the caller has contributions or a referrer
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(81)]
+        pub fn accept_referral(origin: OriginFor<T>, referrer: T::AccountId) -> DispatchResult {
+            let referee = ensure_signed(origin)?;
+            ensure!(ReferralOffers::<T>::contains_key(&referee, &referrer), Error::<T>::NoReferralOffer);
+            ensure!(
+                !Referrals::<T>::contains_key(&referee) && AccountContributions::<T>::get(&referee).is_empty(),
+                Error::<T>::NotANewcomer
+            );
+
+            let _ = ReferralOffers::<T>::clear_prefix(&referee, u32::MAX, None);
+            Referrals::<T>::insert(&referee, Referral {
+                referrer: referrer.clone(),
+                referred_at: frame_system::Pallet::<T>::block_number(),
+                rewarded: false,
+            });
+
+            Self::deposit_event(Event::AccountReferred { referrer, referee });
+
+            Ok(())
+        }
+
+        /// Allow `viewer` to see the caller's per-type and per-project breakdown
+        ///
+        /// Scores stay public; the breakdown and work history are only served to the
+        /// viewers the contributor allowed, by the XCM query handler, or to anyone once it
+        /// made them public with `set_breakdown_public`. They are derived from public
+        /// storage, so this controls what the pallet serves, not who can compute them.
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(31)]
+        pub fn grant_breakdown_access(origin: OriginFor<T>, viewer: T::AccountId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            BreakdownViewers::<T>::insert(&who, &viewer, ());
+
+            Self::deposit_event(Event::BreakdownAccessGranted { account: who, viewer });
+
+            Ok(())
+        }
+
+        /// Revoke `viewer`'s access to the caller's breakdown
+        ///
+        /// # Errors
+        /// Returns `Error::BreakdownViewerNotFound` if `viewer` had no access
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(32)]
+        pub fn revoke_breakdown_access(origin: OriginFor<T>, viewer: T::AccountId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(BreakdownViewers::<T>::take(&who, &viewer).is_some(), Error::<T>::BreakdownViewerNotFound);
+
+            Self::deposit_event(Event::BreakdownAccessRevoked { account: who, viewer });
+
+            Ok(())
+        }
+
+        /// Serve the caller's breakdown and work history to anyone, e.g. through the
+        /// runtime APIs, or stop doing so
+        ///
+        /// Runtime API callers cannot be authenticated, so only public breakdowns are
+        /// served there.
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(78)]
+        pub fn set_breakdown_public(origin: OriginFor<T>, public: bool) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            if public {
+                PublicBreakdowns::<T>::insert(&who, ());
+            } else {
+                PublicBreakdowns::<T>::remove(&who);
+            }
+
+            Self::deposit_event(Event::BreakdownVisibilitySet { account: who, public });
+
+            Ok(())
+        }
+
+        /// Replace the verifier committee (governance-only)
+        ///
+        /// # Errors
+        /// Returns `Error::RequiresGovernance` if origin is not governance
+        /// Returns `Error::InvalidCommittee` if there are fewer than `MinCommitteeApprovals`
+        /// members or a member is listed twice
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(33)]
+        pub fn set_verifier_committee(
+            origin: OriginFor<T>,
+            members: BoundedVec<T::AccountId, T::MaxCommitteeSize>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)
+                .map_err(|_| Error::<T>::RequiresGovernance)?;
+
+            ensure!(members.len() as u32 >= T::MinCommitteeApprovals::get(), Error::<T>::InvalidCommittee);
+            ensure!(
+                members.iter().enumerate().all(|(i, member)| !members[..i].contains(member)),
+                Error::<T>::InvalidCommittee
+            );
+            VerifierCommittee::<T>::put(&members);
+
+            Self::deposit_event(Event::VerifierCommitteeSet { members: members.into_inner() });
+
+            Ok(())
+        }
+
+        /// Approve a contribution above `CommitteeVerificationThreshold` as a committee member
+        ///
+        /// The contribution is verified once `MinCommitteeApprovals` members approved it
+        /// (and a maintainer attested it, if required). Approvals of members who have since
+        /// left the committee still count.
+        ///
+        /// # Errors
+        /// Returns `Error::NotCommitteeMember` if the caller is not on the committee
+        /// Returns `Error::CommitteeApprovalNotRequired` if the contribution is not high-value
+        /// Returns `Error::SelfVerificationNotAllowed` if the caller submitted the contribution
+        /// Returns `Error::AlreadyApproved` if the caller already approved it
+        /// Returns `Error::PipelinePaused` if the pipeline is paused
+        #[pallet::weight(<T as Config>::WeightInfo::verify_contribution())]
+        #[pallet::call_index(34)]
+        pub fn approve_high_value_contribution(
+            origin: OriginFor<T>,
+            contribution_id: ContributionId,
+        ) -> DispatchResult {
+            let member = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            ensure!(VerifierCommittee::<T>::get().contains(&member), Error::<T>::NotCommitteeMember);
+
+            let mut contribution = Contributions::<T>::get(contribution_id)
+                .ok_or(Error::<T>::ContributionNotFound)?;
+            ensure!(!contribution.verified, Error::<T>::ContributionAlreadyVerified);
+            ensure!(contribution.status != ContributionStatus::Imported, Error::<T>::AwaitsImportVerification);
+            ensure!(Self::requires_committee(&contribution), Error::<T>::CommitteeApprovalNotRequired);
+            let contributor = ContributionProofs::<T>::get(contribution.proof)
+                .ok_or(Error::<T>::ContributionNotFound)?;
+            ensure!(member != contributor, Error::<T>::SelfVerificationNotAllowed);
+
+            let approvals = CommitteeApprovals::<T>::try_mutate(contribution_id, |approvals| {
+                ensure!(!approvals.contains(&member), Error::<T>::AlreadyApproved);
+                approvals.try_push(member.clone()).map_err(|_| Error::<T>::InvalidCommittee)?;
+                Ok::<_, Error<T>>(approvals.len() as u32)
+            })?;
+            contribution.verification_count = contribution.verification_count.saturating_add(1);
+            Self::try_complete_verification(&contributor, &mut contribution)?;
+            Contributions::<T>::insert(contribution_id, &contribution);
+
+            Self::deposit_event(Event::CommitteeApproved { contribution_id, member, approvals });
+
+            Ok(())
+        }
+
+        /// Claim the caller's contributor dividend for an ended era
+        ///
+        /// The era's `DividendBudget` is split in proportion to the (capped) reputation each
+        /// account earned in it and paid from `dividend_account`; shares nobody claims stay
+        /// in the pot. Reputation the caller lost since and that was not yet reversed from
+        /// its dividends is taken off its earnings first.
+        ///
+        /// # Arguments
+        /// * `era` - Dividend era, `block_number / DividendEra`
+        ///
+        /// # Errors
+        /// Returns `Error::DividendEraNotEnded` if `era` is the current era or later
+        /// Returns `Error::NoDividend` if the caller earned nothing in `era` or already claimed
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(35)]
+        pub fn claim_dividend(origin: OriginFor<T>, era: u32) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(era < Self::dividend_era(), Error::<T>::DividendEraNotEnded);
+
+            let counted = EraEarnings::<T>::take(era, &who);
+            ensure!(counted > 0, Error::<T>::NoDividend);
+            let total = EraTotalEarnings::<T>::get(era).max(counted as u64);
+            let debt = DividendDebt::<T>::get(&who).min(counted);
+            if debt > 0 {
+                DividendDebt::<T>::mutate(&who, |remaining| *remaining -= debt);
+            }
+            let earned = counted - debt;
+            let amount = Perbill::from_rational(earned as u64, total) * T::DividendBudget::get();
+
+            if earned > 0 {
+                T::Currency::transfer(&Self::dividend_account(), &who, amount, ExistenceRequirement::KeepAlive)?;
+                PaidDividends::<T>::mutate(&who, |(points, paid)| {
+                    *points = points.saturating_add(earned);
+                    *paid = paid.saturating_add(amount);
+                });
+            }
+
+            Self::deposit_event(Event::DividendClaimed { account: who, era, amount });
+
+            Ok(())
+        }
+
+        /// Verify or reject an escalated contribution (governance-only)
+        ///
+        /// An accepted contribution is verified and awarded its points regardless of the
+        /// verifications it has; a rejected one can then be pruned like any other.
+        ///
+        /// # Errors
+        /// Returns `Error::RequiresGovernance` if origin is not governance
+        /// Returns `Error::NotEscalated` if the contribution is not escalated
+        /// Returns `Error::PipelinePaused` if the pipeline is paused
+        #[pallet::weight(<T as Config>::WeightInfo::verify_contribution())]
+        #[pallet::call_index(36)]
+        pub fn triage_escalated_contribution(
+            origin: OriginFor<T>,
+            contribution_id: ContributionId,
+            accept: bool,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)
+                .map_err(|_| Error::<T>::RequiresGovernance)?;
+            Self::ensure_not_paused()?;
+            ensure!(EscalatedContributions::<T>::contains_key(contribution_id), Error::<T>::NotEscalated);
+
+            let mut contribution = Contributions::<T>::get(contribution_id)
+                .ok_or(Error::<T>::ContributionNotFound)?;
+            let contributor = ContributionProofs::<T>::get(contribution.proof)
+                .ok_or(Error::<T>::ContributionNotFound)?;
+
+            if accept {
+                Self::complete_verification(&contributor, &mut contribution)?;
+            } else {
+                contribution.status = ContributionStatus::Rejected;
+                PendingContributions::<T>::mutate(&contributor, |count| *count = count.saturating_sub(1));
+                Self::note_rejection(&contributor);
+                Self::clear_escalation(contribution_id);
+            }
+            Contributions::<T>::insert(contribution_id, &contribution);
+
+            Self::deposit_event(Event::EscalationTriaged { contribution_id, accepted: accept });
+
+            Ok(())
+        }
+
+        /// Reject a pending contribution as a verifier
+        ///
+        /// Once `RejectionThreshold` verifiers rejected it, the contribution is marked
+        /// `Rejected`, stops counting towards the contributor's pending limit and has
+        /// `RejectedDepositSlash` of its deposit slashed; it can then be pruned.
+        ///
+        /// # Arguments
+        /// * `contribution_id` - ID of the contribution to reject
+        /// * `reason` - Why the contribution is rejected
+        /// * `comment` - Feedback for the contributor
+        ///
+        /// # Errors
+        /// Returns `Error::InsufficientReputationToVerify` if the verifier lacks required reputation
+        /// Returns `Error::OnProbation` if the verifier is on probation
+        /// Returns `Error::VerificationRightsRevoked` if the verifier reached `MaxOutlierStrikes`
+        /// Returns `Error::SelfVerificationNotAllowed` if the caller submitted the contribution
+        /// Returns `Error::ContributionNotPending` if the contribution is verified or rejected
+        /// Returns `Error::ContributionAlreadyVerified` if the caller verified the contribution
+        /// Returns `Error::ContributionAlreadyRejected` if the caller already rejected it
+        /// Returns `Error::PipelinePaused` if the pipeline is paused
+        #[pallet::weight(<T as Config>::WeightInfo::verify_contribution())]
+        #[pallet::call_index(37)]
+        pub fn reject_contribution(
+            origin: OriginFor<T>,
+            contribution_id: ContributionId,
+            reason: RejectionReason,
+            comment: RejectionComment,
+        ) -> DispatchResult {
+            let verifier = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            ensure!(
+                ReputationScores::<T>::get(&verifier) >= T::MinReputationToVerify::get(),
+                Error::<T>::InsufficientReputationToVerify
+            );
+            ensure!(!Self::is_on_probation(&verifier), Error::<T>::OnProbation);
+            ensure!(Self::has_verification_rights(&verifier), Error::<T>::VerificationRightsRevoked);
+
+            let mut contribution = Contributions::<T>::get(contribution_id)
+                .ok_or(Error::<T>::ContributionNotFound)?;
+            ensure!(contribution.status == ContributionStatus::Pending, Error::<T>::ContributionNotPending);
+            let contributor = ContributionProofs::<T>::get(contribution.proof)
+                .ok_or(Error::<T>::ContributionNotFound)?;
+            ensure!(verifier != contributor, Error::<T>::SelfVerificationNotAllowed);
+            ensure!(
+                !ContributionVerifications::<T>::contains_key(contribution_id, &verifier),
+                Error::<T>::ContributionAlreadyVerified
+            );
+            ensure!(
+                !ContributionRejections::<T>::contains_key(contribution_id, &verifier),
+                Error::<T>::ContributionAlreadyRejected
+            );
+
+            ContributionRejections::<T>::insert(contribution_id, &verifier, (reason, comment));
+            let rejections = RejectionCounts::<T>::mutate(contribution_id, |count| {
+                *count = count.saturating_add(1);
+                *count
+            });
+
+            Self::deposit_event(Event::VerificationRejected {
+                contributor: contributor.clone(),
+                contribution_id,
+                verifier,
+                reason,
+                rejections,
+            });
+
+            if rejections >= T::RejectionThreshold::get() {
+                contribution.status = ContributionStatus::Rejected;
+                Contributions::<T>::insert(contribution_id, &contribution);
+                PendingContributions::<T>::mutate(&contributor, |count| *count = count.saturating_sub(1));
+                Self::note_rejection(&contributor);
+                Self::clear_escalation(contribution_id);
+                let slashed = Self::slash_deposit(contribution_id);
+
+                Self::deposit_event(Event::ContributionRejected { contributor, contribution_id, slashed });
+            }
+
+            Ok(())
+        }
+
+        /// Clear a verifier's record, restoring verification rights it lost (governance-only)
+        ///
+        /// # Errors
+        /// Returns `Error::RequiresGovernance` if origin is not governance
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(38)]
+        pub fn reset_verifier_record(origin: OriginFor<T>, verifier: T::AccountId) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)
+                .map_err(|_| Error::<T>::RequiresGovernance)?;
+
+            VerifierRecords::<T>::remove(&verifier);
+
+            Self::deposit_event(Event::VerifierRecordReset { verifier });
+
+            Ok(())
+        }
+
+        /// Go on leave for `duration` blocks, during which the caller's reputation does not decay
+        ///
+        /// # Errors
+        /// Returns `Error::InvalidLeaveDuration` if `duration` is zero or exceeds `MaxLeaveDuration`
+        /// Returns `Error::LeaveCooldownActive` if the caller's previous leave started less
+        /// than `LeaveCooldown` blocks ago
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(39)]
+        pub fn announce_leave(origin: OriginFor<T>, duration: T::BlockNumber) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                !duration.is_zero() && duration <= T::MaxLeaveDuration::get(),
+                Error::<T>::InvalidLeaveDuration
+            );
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let until = now.saturating_add(duration);
+            LeavePeriods::<T>::try_mutate(&who, |leaves| {
+                if let Some((start, _)) = leaves.last() {
+                    ensure!(now >= start.saturating_add(T::LeaveCooldown::get()), Error::<T>::LeaveCooldownActive);
+                }
+                if leaves.len() as u32 >= T::MaxLeavesRecorded::get() && !leaves.is_empty() {
+                    leaves.remove(0);
+                }
+                leaves.try_push((now, until)).map_err(|_| Error::<T>::LeaveCooldownActive)
+            })?;
+
+            Self::deposit_event(Event::LeaveAnnounced { account: who, until });
+
+            Ok(())
+        }
+
+        /// Revoke an account's latest leave, so its blocks count towards decay again
+        /// (governance-only)
+        ///
+        /// The account's cooldown keeps running from the revoked leave's start.
+        ///
+        /// # Errors
+        /// Returns `Error::RequiresGovernance` if origin is not governance
+        /// Returns `Error::NoLeave` if the account has no recorded leave
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(40)]
+        pub fn revoke_leave(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)
+                .map_err(|_| Error::<T>::RequiresGovernance)?;
+
+            LeavePeriods::<T>::try_mutate(&account, |leaves| {
+                let (start, _) = leaves.pop().ok_or(Error::<T>::NoLeave)?;
+                // An empty leave keeps the cooldown in force
+                leaves.try_push((start, start)).map_err(|_| Error::<T>::NoLeave)
+            })?;
+
+            Self::deposit_event(Event::LeaveRevoked { account });
+
+            Ok(())
+        }
+
+        /// Start a commitment to the current reputation scores now rather than at the next
+        /// `SnapshotEpoch` (governance-only)
+        ///
+        /// The commitment is built in the following blocks' `on_initialize`, within the
+        /// `ScoreCommitment` hook quota.
+        ///
+        /// # Errors
+        /// Returns `Error::RequiresGovernance` if origin is not governance
+        /// Returns `Error::ScoreCommitmentInProgress` if a commitment is still being built
+        #[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+        #[pallet::call_index(41)]
+        pub fn commit_reputation_snapshot(origin: OriginFor<T>) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)
+                .map_err(|_| Error::<T>::RequiresGovernance)?;
+
+            Self::start_score_commitment()
+        }
+
+        /// Submit a contribution to a self-hosted forge without a public API from its git log
+        ///
+        /// `commits` is the first-parent history of the contribution, newest first, e.g. from
+        /// `git log --first-parent --format='%H %P'`; the SCALE encoding's blake2-256 hash is
+        /// the contribution's proof. A maintainer of the project co-signs it with
+        /// `maintainer_attest`, after which the off-chain worker checks that the commits form
+        /// a hash chain. Neither counts as a verification: the contribution still needs
+        /// `MinVerifications` from independent verifiers. It is verified as
+        /// `DataSource::Manual` and earns the `import_points` share of the usual points.
+        ///
+        /// # Errors
+        /// Returns `Error::EmptyGitLog` if `commits` is empty
+        /// Returns `Error::ProjectNotFound` if the project does not exist
+        /// Fails like `add_contribution` otherwise
+        #[pallet::weight(<T as Config>::WeightInfo::add_contribution())]
+        #[pallet::call_index(46)]
+        pub fn import_git_log(
+            origin: OriginFor<T>,
+            project_id: ProjectId,
+            contribution_type: ContributionType,
+            weight: u8,
+            commits: BoundedVec<GitCommitLink, T::MaxGitLogEntries>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let head = commits.first().ok_or(Error::<T>::EmptyGitLog)?.commit;
+            ensure!(Projects::<T>::contains_key(project_id), Error::<T>::ProjectNotFound);
+
+            let proof = H256(sp_io::hashing::blake2_256(&commits.encode()));
+            let contribution_id = Self::submit_contribution(&who, proof, contribution_type, weight, DataSource::Manual)?;
+            Self::assign_project(contribution_id, project_id);
+            GitImports::<T>::insert(contribution_id, GitImport { commits, validated: false });
+
+            Self::deposit_event(Event::GitLogImported { contributor: who, contribution_id, project_id, head });
+
+            Ok(().into())
+        }
+
+        /// Record the off-chain worker's check of an imported git log
+        ///
+        /// A consistent log lets the contribution's verification complete; an inconsistent
+        /// one rejects the contribution and slashes its deposit like `reject_contribution`.
+        ///
+        /// # Errors
+        /// Returns `Error::NotGitImport` if the contribution was not imported from a git log
+        /// Returns `Error::GitImportAlreadyChecked` if the log was already validated
+        /// Returns `Error::GitImportNotAttested` if no maintainer attested the contribution
+        #[pallet::weight(<T as Config>::WeightInfo::verify_contribution())]
+        #[pallet::call_index(47)]
+        pub fn confirm_git_import(
+            origin: OriginFor<T>,
+            contribution_id: ContributionId,
+            consistent: bool,
+        ) -> DispatchResult {
+            T::OwnershipVerifierOrigin::ensure_origin(origin)?;
+            Self::ensure_not_paused()?;
+
+            let mut import = GitImports::<T>::get(contribution_id).ok_or(Error::<T>::NotGitImport)?;
+            ensure!(!import.validated, Error::<T>::GitImportAlreadyChecked);
+            ensure!(
+                MaintainerAttestations::<T>::contains_key(contribution_id),
+                Error::<T>::GitImportNotAttested
+            );
+            let mut contribution = Contributions::<T>::get(contribution_id)
+                .ok_or(Error::<T>::ContributionNotFound)?;
+            ensure!(contribution.status == ContributionStatus::Pending, Error::<T>::ContributionNotPending);
+            let contributor = ContributionProofs::<T>::get(contribution.proof)
+                .ok_or(Error::<T>::ContributionNotFound)?;
+
+            Self::deposit_event(Event::GitImportChecked { contribution_id, consistent });
+
+            if consistent {
+                import.validated = true;
+                GitImports::<T>::insert(contribution_id, import);
+                Self::try_complete_verification(&contributor, &mut contribution)?;
+                Contributions::<T>::insert(contribution_id, &contribution);
+            } else {
+                contribution.status = ContributionStatus::Rejected;
+                Contributions::<T>::insert(contribution_id, &contribution);
+                PendingContributions::<T>::mutate(&contributor, |count| *count = count.saturating_sub(1));
+                Self::note_rejection(&contributor);
+                Self::clear_escalation(contribution_id);
+                let slashed = Self::slash_deposit(contribution_id);
+
+                Self::deposit_event(Event::ContributionRejected { contributor, contribution_id, slashed });
+            }
+
+            Ok(())
+        }
+
+        /// Register the bridge contract of an EVM chain, or remove the chain with `None`
+        /// (governance-only)
+        ///
+        /// # Errors
+        /// Returns `Error::RequiresGovernance` if origin is not governance
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(42)]
+        pub fn register_evm_chain(origin: OriginFor<T>, chain_id: u64, bridge: Option<H160>) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)
+                .map_err(|_| Error::<T>::RequiresGovernance)?;
+
+            EvmChains::<T>::set(chain_id, bridge);
+            Self::deposit_event(Event::EvmChainRegistered { chain_id, bridge });
+
+            Ok(())
+        }
+
+        /// Set the EVM address of the key that signs attestations (governance-only)
+        ///
+        /// # Errors
+        /// Returns `Error::RequiresGovernance` if origin is not governance
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(43)]
+        pub fn set_evm_attestation_signer(origin: OriginFor<T>, signer: H160) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)
+                .map_err(|_| Error::<T>::RequiresGovernance)?;
+
+            EvmAttestationSigner::<T>::put(signer);
+            Self::deposit_event(Event::EvmAttestationSignerSet { signer });
+
+            Ok(())
+        }
+
+        /// Request an attestation of the caller's effective reputation for an EVM chain
+        ///
+        /// The off-chain worker signs the attestation, which is then emitted in
+        /// `EvmAttestationSigned`. A pending request to the same chain is replaced.
+        ///
+        /// # Errors
+        /// Returns `Error::UnknownEvmChain` if no bridge is registered for `chain_id`
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(44)]
+        pub fn request_evm_export(origin: OriginFor<T>, chain_id: u64) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let bridge = EvmChains::<T>::get(chain_id).ok_or(Error::<T>::UnknownEvmChain)?;
+
+            let now_ms: u64 = T::Time::now().unique_saturated_into();
+            let nonce = NextEvmExportNonce::<T>::mutate(|next| {
+                let nonce = *next;
+                *next = next.saturating_add(1);
+                nonce
+            });
+            let export = EvmExport {
+                nonce,
+                bridge,
+                address: crate::evm_bridge::evm_address_of(&who),
+                score: Self::effective_reputation(&who),
+                expiry: (now_ms / 1_000).saturating_add(T::EvmAttestationValidity::get()),
+            };
+            PendingEvmExports::<T>::insert(&who, chain_id, export);
+
+            Self::deposit_event(Event::EvmExportRequested { account: who, chain_id, nonce });
+
+            Ok(())
+        }
+
+        /// Submit the chain's signature of a pending EVM attestation
+        ///
+        /// Any account may relay the signature; it is checked against `EvmAttestationSigner`.
+        ///
+        /// # Errors
+        /// Returns `Error::NoPendingEvmExport` if the account has no pending export to `chain_id`
+        /// Returns `Error::InvalidEvmAttestationSignature` if the signature is not by the signer
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(45)]
+        pub fn submit_evm_attestation(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            chain_id: u64,
+            signature: ecdsa::Signature,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            let export = PendingEvmExports::<T>::get(&account, chain_id).ok_or(Error::<T>::NoPendingEvmExport)?;
+
+            let message = crate::evm_bridge::attestation_message(chain_id, &export);
+            let signer = crate::evm_bridge::recover_signer(&message, &signature);
+            ensure!(
+                signer.is_some() && signer == EvmAttestationSigner::<T>::get(),
+                Error::<T>::InvalidEvmAttestationSignature
+            );
+
+            PendingEvmExports::<T>::remove(&account, chain_id);
+            Self::deposit_event(Event::EvmAttestationSigned {
+                account,
+                chain_id,
+                nonce: export.nonce,
+                message,
+                signature,
+            });
+
+            Ok(())
+        }
+
+        /// Set the reputation granted once to each verified human; zero disables the grant
+        /// (governance-only)
+        ///
+        /// # Errors
+        /// Returns `Error::RequiresGovernance` if origin is not governance
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(48)]
+        pub fn set_starter_grant(origin: OriginFor<T>, amount: Score) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)
+                .map_err(|_| Error::<T>::RequiresGovernance)?;
+
+            StarterGrant::<T>::put(amount.max(0));
+            Self::deposit_event(Event::StarterGrantSet { amount: amount.max(0) });
+
+            Ok(())
+        }
+
+        /// Claim the starter grant as a verified human
+        ///
+        /// Each identity of `T::Personhood` can claim once, whichever of its accounts claims.
+        ///
+        /// # Errors
+        /// Returns `Error::StarterGrantUnavailable` if the grant is zero
+        /// Returns `Error::NotVerifiedHuman` if the caller has no verified-human identity
+        /// Returns `Error::StarterGrantAlreadyClaimed` if the identity already claimed it
+        /// Returns `Error::StarterGrantsExhausted` if `MaxStarterGrantsPerPeriod` grants were
+        /// claimed this period
+        /// Returns `Error::PipelinePaused` if the pipeline is paused
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(49)]
+        pub fn claim_starter_reputation(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let amount = StarterGrant::<T>::get();
+            ensure!(amount > 0, Error::<T>::StarterGrantUnavailable);
+            let identity = T::Personhood::personhood_of(&who).ok_or(Error::<T>::NotVerifiedHuman)?;
+            ensure!(
+                !StarterGrantClaims::<T>::contains_key(identity),
+                Error::<T>::StarterGrantAlreadyClaimed
+            );
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let period: u32 = (now / T::StarterGrantPeriod::get().max(One::one())).unique_saturated_into();
+            StarterGrantsIssued::<T>::try_mutate(|(issued_period, count)| -> DispatchResult {
+                if *issued_period != period {
+                    *issued_period = period;
+                    *count = 0;
+                }
+                ensure!(*count < T::MaxStarterGrantsPerPeriod::get(), Error::<T>::StarterGrantsExhausted);
+                *count += 1;
+                Ok(())
+            })?;
+
+            StarterGrantClaims::<T>::insert(identity, &who);
+            Self::award_reputation(&who, amount, RepChangeReason::StarterGrant);
+            Self::deposit_event(Event::StarterGrantClaimed { account: who, amount });
+
+            Ok(())
+        }
+
+        /// Respond to a verification of one's contribution
+        ///
+        /// Each verification takes one response, stored in `VerificationResponses`.
+        ///
+        /// # Arguments
+        /// * `contribution_id` - ID of the caller's contribution
+        /// * `verifier` - Account whose verification is answered
+        /// * `response` - The caller's reply
+        ///
+        /// # Errors
+        /// Returns `Error::ContributionNotFound` if the caller did not submit the contribution
+        /// Returns `Error::VerificationNotFound` if `verifier` did not verify it
+        /// Returns `Error::VerificationAlreadyAnswered` if the caller already responded
+        /// Returns `Error::PipelinePaused` if the pipeline is paused
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(50)]
+        pub fn respond_to_verification(
+            origin: OriginFor<T>,
+            contribution_id: ContributionId,
+            verifier: T::AccountId,
+            response: VerificationResponse,
+        ) -> DispatchResult {
+            let contributor = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let contribution = Contributions::<T>::get(contribution_id)
+                .ok_or(Error::<T>::ContributionNotFound)?;
+            ensure!(
+                ContributionProofs::<T>::get(contribution.proof) == Some(contributor.clone()),
+                Error::<T>::ContributionNotFound
+            );
+            ensure!(
+                ContributionVerifications::<T>::contains_key(contribution_id, &verifier),
+                Error::<T>::VerificationNotFound
+            );
+            ensure!(
+                !VerificationResponses::<T>::contains_key(contribution_id, &verifier),
+                Error::<T>::VerificationAlreadyAnswered
+            );
+
+            VerificationResponses::<T>::insert(contribution_id, &verifier, response);
+            Self::deposit_event(Event::VerificationResponded { contributor, contribution_id, verifier });
+
+            Ok(())
+        }
+
+        /// Amend the score of one's verification of a pending contribution
+        ///
+        /// A verification can be amended once, until the contribution is verified or
+        /// rejected; the consensus score is taken from the amended score, while the
+        /// verifier's accuracy is still scored on its original vote.
+        ///
+        /// # Arguments
+        /// * `contribution_id` - ID of the contribution the caller verified
+        /// * `score` - New verification score (0-100)
+        ///
+        /// # Errors
+        /// Returns `Error::InvalidVerificationScore` if score is out of range
+        /// Returns `Error::ContributionNotFound` if the contribution doesn't exist
+        /// Returns `Error::ContributionNotPending` if the contribution is verified or rejected
+        /// Returns `Error::VerificationNotFound` if the caller did not verify it
+        /// Returns `Error::VerificationAlreadyAmended` if the caller already amended their score
+        /// Returns `Error::PipelinePaused` if the pipeline is paused
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(51)]
+        pub fn amend_verification(
+            origin: OriginFor<T>,
+            contribution_id: ContributionId,
+            score: u8,
+        ) -> DispatchResult {
+            let verifier = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            ensure!(score <= 100, Error::<T>::InvalidVerificationScore);
+            let contribution = Contributions::<T>::get(contribution_id)
+                .ok_or(Error::<T>::ContributionNotFound)?;
+            ensure!(contribution.status == ContributionStatus::Pending, Error::<T>::ContributionNotPending);
+            ensure!(
+                !AmendedVerifications::<T>::contains_key(contribution_id, &verifier),
+                Error::<T>::VerificationAlreadyAmended
+            );
+
+            let old_score = ContributionVerifications::<T>::try_mutate(contribution_id, &verifier, |verification| {
+                let (recorded, _) = verification.as_mut().ok_or(Error::<T>::VerificationNotFound)?;
+                Ok::<_, Error<T>>(sp_std::mem::replace(recorded, score))
+            })?;
+            AmendedVerifications::<T>::insert(contribution_id, &verifier, old_score);
+            if let Some(contributor) = ContributionProofs::<T>::get(contribution.proof) {
+                AccountActivity::<T>::mutate(&contributor, |stats| {
+                    stats.received_score_total =
+                        stats.received_score_total.saturating_sub(old_score as u64).saturating_add(score as u64);
+                });
+            }
+            Self::deposit_event(Event::VerificationAmended { contribution_id, verifier, old_score, new_score: score });
+
+            Ok(())
+        }
+
+        /// Pause the contribution pipeline in an emergency, e.g. during an exploit
+        ///
+        /// Submitting, verifying and attesting contributions, responding to and amending
+        /// verifications and claiming the starter grant fail with `Error::PipelinePaused`, and
+        /// the off-chain worker stops submitting until `unpause` is called.
+        ///
+        /// # Errors
+        /// Returns `BadOrigin` if origin is not `PauseOrigin`
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(52)]
+        pub fn pause(origin: OriginFor<T>) -> DispatchResult {
+            T::PauseOrigin::ensure_origin(origin)?;
+
+            if !Paused::<T>::get() {
+                Paused::<T>::put(true);
+                Self::deposit_event(Event::PipelinePaused);
+            }
+
+            Ok(())
+        }
+
+        /// Resume the contribution pipeline
+        ///
+        /// # Errors
+        /// Returns `BadOrigin` if origin is not `PauseOrigin`
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(53)]
+        pub fn unpause(origin: OriginFor<T>) -> DispatchResult {
+            T::PauseOrigin::ensure_origin(origin)?;
+
+            if Paused::<T>::take() {
+                Self::deposit_event(Event::PipelineUnpaused);
+            }
+
+            Ok(())
+        }
+
+        /// Set the batch size and cross-chain query timeout (governance-only)
+        ///
+        /// Queries already pending keep their timeout.
+        ///
+        /// # Errors
+        /// Returns `Error::RequiresGovernance` if origin is not governance
+        /// Returns `Error::InvalidPipelineConfig` if a limit is zero or the batch size
+        /// exceeds `PipelineConfig::MAX_BATCH_SIZE`
+        #[pallet::call_index(54)]
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        pub fn set_pipeline_config(origin: OriginFor<T>, config: PipelineConfig) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)
+                .map_err(|_| Error::<T>::RequiresGovernance)?;
+            ensure!(Self::is_valid_pipeline_config(&config), Error::<T>::InvalidPipelineConfig);
+
+            let old = PipelineParams::<T>::get();
+            PipelineParams::<T>::put(config);
+
+            Self::deposit_event(Event::PipelineConfigUpdated { old, new: config });
+
+            Ok(())
+        }
+
+        /// Move settled cross-chain queries into `RemoteReputation` and remove expired
+        /// cache entries
+        ///
+        /// Submitted by the off-chain worker so the sweep stays off the block's critical
+        /// path, but anyone may call it: every item is checked here. Answered queries are
+        /// cached, failed and timed-out ones dropped; entries older than
+        /// `RemoteReputationTtl` are removed. Items not yet due are skipped.
+        ///
+        /// # Errors
+        /// Returns `Error::NothingToSync` if no item was due
+        #[pallet::call_index(55)]
+        #[pallet::weight(Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(
+            (queries.len() + expired.len()) as u64,
+            (2 * queries.len() + expired.len()) as u64,
+        )))]
+        pub fn sync_remote_reputation(
+            origin: OriginFor<T>,
+            queries: BoundedVec<u64, ConstU32<MAX_REMOTE_SYNC_BATCH>>,
+            expired: BoundedVec<(ChainId, RemoteAccount), ConstU32<MAX_REMOTE_SYNC_BATCH>>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            let now = frame_system::Pallet::<T>::block_number();
+
+            let (mut cached, mut dropped) = (0u32, 0u32);
+            for query_id in queries {
+                let Some(query) = ReputationQueries::<T>::get(query_id) else { continue };
+                if !query.is_settled(now) {
+                    continue;
+                }
+                if Self::cache_remote_score(&query, now) {
+                    cached += 1;
+                } else {
+                    dropped += 1;
+                }
+                ReputationQueries::<T>::remove(query_id);
+            }
+
+            let mut removed = 0u32;
+            for (chain, account) in expired {
+                let is_expired = RemoteReputation::<T>::get(&chain, &account)
+                    .map_or(false, |entry| Self::is_remote_score_expired(&entry, now));
+                if is_expired {
+                    RemoteReputation::<T>::remove(&chain, &account);
+                    removed += 1;
+                }
+            }
+
+            ensure!(cached + dropped + removed > 0, Error::<T>::NothingToSync);
+            Self::deposit_event(Event::RemoteReputationSynced { cached, dropped, expired: removed });
+
+            Ok(())
+        }
+
+        /// Submit many small contributions as one bundle
+        ///
+        /// `root` must be the Merkle root of the item proofs `leaves`, built with
+        /// [`crate::archive::merkle_root`]. Each item proof is registered like the proof of
+        /// a single contribution, so no item can be submitted again, alone or in another
+        /// bundle. The bundle is a single contribution of `weight`, verified like any
+        /// other; `is_bundle_item` later proves an item belongs to it.
+        ///
+        /// # Errors
+        /// Returns `Error::InvalidBundle` if there are fewer than 2 leaves, a leaf is zero or
+        /// repeated, or `root` is not their Merkle root
+        /// Returns `Error::ContributionAlreadySubmitted` if an item proof was already submitted
+        /// Fails like `add_contribution` otherwise
+        #[pallet::weight(<T as Config>::WeightInfo::add_contribution()
+            .saturating_add(T::DbWeight::get().reads_writes(leaves.len() as u64, 2 * leaves.len() as u64)))]
+        #[pallet::call_index(56)]
+        pub fn submit_bundle(
+            origin: OriginFor<T>,
+            root: H256,
+            leaves: BoundedVec<H256, ConstU32<MAX_BUNDLE_ITEMS>>,
+            contribution_type: ContributionType,
+            weight: u8,
+            source: DataSource,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let items = leaves.len() as u32;
+            let distinct: BTreeSet<H256> = leaves.iter().copied().collect();
+            ensure!(
+                items >= 2 && distinct.len() == leaves.len() && !distinct.contains(&H256::zero()),
+                Error::<T>::InvalidBundle
+            );
+            ensure!(crate::archive::merkle_root(leaves.to_vec()) == root, Error::<T>::InvalidBundle);
+            ensure!(
+                !leaves.iter().any(|leaf| ContributionsByProof::<T>::contains_key(leaf)),
+                Error::<T>::ContributionAlreadySubmitted
+            );
+
+            let contribution_id = Self::submit_contribution(&who, root, contribution_type, weight, source)?;
+            for leaf in leaves.iter() {
+                ContributionsByProof::<T>::insert(leaf, contribution_id);
+                BundleItemProofs::<T>::insert(contribution_id, leaf, ());
+            }
+            ContributionBundles::<T>::insert(contribution_id, ContributionBundle { root, items });
+
+            Self::deposit_event(Event::BundleSubmitted { contributor: who, contribution_id, root, items });
+
+            Ok(().into())
+        }
+
+        /// Register an external attestor, or rename one (governance-only)
+        ///
+        /// # Errors
+        /// Returns `Error::RequiresGovernance` if origin is not governance
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(57)]
+        pub fn register_attestor(origin: OriginFor<T>, attestor: T::AccountId, name: AttestorName) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)
+                .map_err(|_| Error::<T>::RequiresGovernance)?;
+
+            Attestors::<T>::insert(&attestor, &name);
+            Self::deposit_event(Event::AttestorRegistered { attestor, name });
+
+            Ok(())
+        }
+
+        /// Remove an attestor (governance-only); its attestations stop counting
+        ///
+        /// # Errors
+        /// Returns `Error::RequiresGovernance` if origin is not governance
+        /// Returns `Error::NotAttestor` if the account is not a registered attestor
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(58)]
+        pub fn remove_attestor(origin: OriginFor<T>, attestor: T::AccountId) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)
+                .map_err(|_| Error::<T>::RequiresGovernance)?;
+            Attestors::<T>::take(&attestor).ok_or(Error::<T>::NotAttestor)?;
+
+            Self::deposit_event(Event::AttestorRemoved { attestor });
+
+            Ok(())
+        }
+
+        /// Issue an attestation about `subject` as a registered attestor
+        ///
+        /// The attestation boosts the subject's effective reputation by `weight` percent,
+        /// scaled by `AttestationMultiplier`, until `expires_at`. Expired attestations of
+        /// the subject are removed first. An attestor holds one unexpired attestation per
+        /// subject; revoke it to issue another.
+        ///
+        /// # Errors
+        /// Returns `Error::NotAttestor` if the caller is not a registered attestor
+        /// Returns `Error::SelfVerificationNotAllowed` if the caller attests itself
+        /// Returns `Error::InvalidAttestation` if `weight` is not 1 to 100 or `expires_at`
+        /// has passed
+        /// Returns `Error::AttestorAlreadyAttested` if the caller holds an unexpired
+        /// attestation about the subject
+        /// Returns `Error::TooManyAttestations` if the subject holds
+        /// `MAX_ATTESTATIONS_PER_ACCOUNT` unexpired attestations
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(59)]
+        pub fn issue_attestation(
+            origin: OriginFor<T>,
+            subject: T::AccountId,
+            claim: AttestationClaim,
+            weight: u8,
+            expires_at: T::BlockNumber,
+        ) -> DispatchResult {
+            let attestor = ensure_signed(origin)?;
+            ensure!(Attestors::<T>::contains_key(&attestor), Error::<T>::NotAttestor);
+            ensure!(attestor != subject, Error::<T>::SelfVerificationNotAllowed);
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!((1..=100).contains(&weight) && expires_at > now, Error::<T>::InvalidAttestation);
+
+            let mut held = 0u32;
+            let mut attested = false;
+            let expired: Vec<AttestationId> = Attestations::<T>::iter_prefix(&subject)
+                .filter_map(|(id, attestation)| {
+                    if attestation.expires_at <= now {
+                        return Some(id);
+                    }
+                    held += 1;
+                    attested |= attestation.attestor == attestor;
+                    None
+                })
+                .collect();
+            for id in expired {
+                Attestations::<T>::remove(&subject, id);
+            }
+            ensure!(!attested, Error::<T>::AttestorAlreadyAttested);
+            ensure!(held < MAX_ATTESTATIONS_PER_ACCOUNT, Error::<T>::TooManyAttestations);
+
+            let attestation_id = NextAttestationId::<T>::mutate(|next| {
+                *next = next.saturating_add(1);
+                *next
+            });
+            Attestations::<T>::insert(
+                &subject,
+                attestation_id,
+                Attestation { attestor: attestor.clone(), claim, weight, issued_at: now, expires_at },
+            );
+
+            Self::deposit_event(Event::AttestationIssued { subject, attestor, attestation_id, weight, expires_at });
+
+            Ok(())
+        }
+
+        /// Revoke an attestation the caller issued
+        ///
+        /// # Errors
+        /// Returns `Error::AttestationNotFound` if the caller issued no such attestation
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(60)]
+        pub fn revoke_attestation(
+            origin: OriginFor<T>,
+            subject: T::AccountId,
+            attestation_id: AttestationId,
+        ) -> DispatchResult {
+            let attestor = ensure_signed(origin)?;
+            ensure!(
+                Attestations::<T>::get(&subject, attestation_id).map_or(false, |attestation| attestation.attestor == attestor),
+                Error::<T>::AttestationNotFound
+            );
+            Attestations::<T>::remove(&subject, attestation_id);
+
+            Self::deposit_event(Event::AttestationRevoked { subject, attestation_id });
+
+            Ok(())
+        }
+
+        /// Set the share of attestation weights applied as a boost (governance-only)
+        ///
+        /// A change is structural and bumps `AlgorithmVersion`.
+        ///
+        /// # Errors
+        /// Returns `Error::RequiresGovernance` if origin is not governance
+        /// Returns `Error::StructuralChangeInCompatibilityMode` for a change while
+        /// `CompatibilityMode` is on
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(61)]
+        pub fn set_attestation_multiplier(origin: OriginFor<T>, multiplier: Percent) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)
+                .map_err(|_| Error::<T>::RequiresGovernance)?;
+
+            if multiplier != AttestationMultiplier::<T>::get() {
+                Self::bump_algorithm_version()?;
+            }
+            AttestationMultiplier::<T>::put(multiplier);
+            Self::deposit_event(Event::AttestationMultiplierSet { multiplier });
+
+            Ok(())
+        }
+
+        /// Set the share of the maximum block weight a hook sweep may use (governance-only)
+        ///
+        /// Work that does not fit in a block's quota is carried over to the next block.
+        ///
+        /// # Errors
+        /// Returns `Error::RequiresGovernance` if origin is not governance
+        /// Returns `Error::HookQuotasExceeded` if the quotas of all tasks would add up to
+        /// more than `MAX_HOOK_SHARE`
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(62)]
+        pub fn set_hook_quota(origin: OriginFor<T>, task: HookTask, quota: Perbill) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)
+                .map_err(|_| Error::<T>::RequiresGovernance)?;
+
+            let total = HookTask::ALL
+                .iter()
+                .filter(|other| **other != task)
+                .fold(quota.deconstruct(), |total, other| {
+                    total.saturating_add(Self::hook_quota(*other).deconstruct())
+                });
+            ensure!(total <= MAX_HOOK_SHARE.deconstruct(), Error::<T>::HookQuotasExceeded);
+
+            HookQuotas::<T>::insert(task, quota);
+            Self::deposit_event(Event::HookQuotaSet { task, quota });
+
+            Ok(())
+        }
+
+        /// Join the verifier pool, or change the capacity and skills offered
+        ///
+        /// Pending contributions are only routed to pooled verifiers with reviews left
+        /// this era, for the skills they list (or any skill they declared, if none).
+        ///
+        /// # Errors
+        /// Returns `Error::ZeroVerifierCapacity` if `capacity_per_era` is zero
+        /// Returns `Error::InsufficientReputationToVerify` if the caller lacks required reputation
+        /// Returns `Error::OnProbation` if the caller is on probation
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(63)]
+        pub fn opt_in_verifier(
+            origin: OriginFor<T>,
+            capacity_per_era: u32,
+            skill_tags: ContributionSkillsOf,
+        ) -> DispatchResult {
+            let verifier = ensure_signed(origin)?;
+            ensure!(capacity_per_era > 0, Error::<T>::ZeroVerifierCapacity);
+            ensure!(
+                ReputationScores::<T>::get(&verifier) >= T::MinReputationToVerify::get(),
+                Error::<T>::InsufficientReputationToVerify
+            );
+            ensure!(!Self::is_on_probation(&verifier), Error::<T>::OnProbation);
+
+            // Reviews already done this era still count against the new capacity
+            let era = Self::verifier_era();
+            let reviews = VerifierPool::<T>::get(&verifier)
+                .filter(|availability| availability.era == era)
+                .map_or(0, |availability| availability.reviews);
+            VerifierPool::<T>::insert(
+                &verifier,
+                VerifierAvailability { capacity_per_era, skills: skill_tags.clone(), era, reviews },
+            );
+
+            Self::deposit_event(Event::VerifierOptedIn { verifier, capacity_per_era, skills: skill_tags });
+
+            Ok(())
+        }
+
+        /// Leave the verifier pool
+        ///
+        /// # Errors
+        /// Returns `Error::NotInVerifierPool` if the caller did not opt in
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(64)]
+        pub fn opt_out_verifier(origin: OriginFor<T>) -> DispatchResult {
+            let verifier = ensure_signed(origin)?;
+            ensure!(VerifierPool::<T>::take(&verifier).is_some(), Error::<T>::NotInVerifierPool);
+
+            Self::deposit_event(Event::VerifierOptedOut { verifier });
+
+            Ok(())
+        }
+
+        /// Claim that an account on a registered chain belongs to the caller
+        ///
+        /// Issues a challenge the remote account signs; its chain relays the signature with
+        /// `relay_identity_link_proof`, or a registered attestor submits it with
+        /// `attest_identity_link`. Requesting again replaces the challenge.
+        ///
+        /// # Errors
+        /// Returns `Error::ChainNotSupported` if the chain is not registered
+        /// Returns `Error::IdentityAlreadyLinked` if the remote account is linked already
+        /// Returns `Error::TooManyLinkedIdentities` if the caller has `MAX_LINKED_IDENTITIES` links
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(69)]
+        pub fn request_identity_link(
+            origin: OriginFor<T>,
+            chain: ChainId,
+            remote_account: RemoteAccount,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::is_chain_registered(&chain), Error::<T>::ChainNotSupported);
+            ensure!(
+                !IdentityOwners::<T>::contains_key(&chain, &remote_account),
+                Error::<T>::IdentityAlreadyLinked
+            );
+            ensure!(
+                (LinkedIdentities::<T>::decode_len(&who).unwrap_or_default() as u32) < MAX_LINKED_IDENTITIES,
+                Error::<T>::TooManyLinkedIdentities
+            );
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let challenge = H256(sp_io::hashing::blake2_256(
+                &(b"dotrep/identity-link", &who, &chain, &remote_account, now).encode(),
+            ));
+            LinkChallenges::<T>::insert(&who, (chain.clone(), remote_account.clone()), challenge);
+
+            Self::deposit_event(Event::IdentityLinkRequested { account: who, chain, remote_account, challenge });
+
+            Ok(())
+        }
+
+        /// Submit, as a registered attestor, the remote account's signature over a
+        /// requested link's challenge, for chains that do not relay proofs themselves
+        ///
+        /// # Errors
+        /// Returns `Error::NotAttestor` if the caller is not a registered attestor
+        /// Returns `Error::SelfVerificationNotAllowed` if the caller is the account
+        /// Returns `Error::NoLinkChallenge` if the account did not request the link
+        /// Returns `Error::InvalidLinkSignature` if the remote account did not sign the challenge
+        /// Fails like `request_identity_link` if the link is no longer possible
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(70)]
+        pub fn attest_identity_link(
+            origin: OriginFor<T>,
             account: T::AccountId,
-            old_score: i32,
-            new_score: i32,
-            change_reason: RepChangeReason,
-        },
-        /// Sybil attack detected
-        SybilAttackDetected {
-            #[pallet::index(0)]
+            chain: ChainId,
+            remote_account: RemoteAccount,
+            signature: RemoteSignature,
+        ) -> DispatchResult {
+            let attestor = ensure_signed(origin)?;
+            ensure!(Attestors::<T>::contains_key(&attestor), Error::<T>::NotAttestor);
+            ensure!(attestor != account, Error::<T>::SelfVerificationNotAllowed);
+            let challenge = LinkChallenges::<T>::get(&account, (chain.clone(), remote_account.clone()))
+                .ok_or(Error::<T>::NoLinkChallenge)?;
+
+            Self::note_identity_link_proof(account, chain, remote_account, challenge, &signature)
+        }
+
+        /// Link a remote account on the origin's chain with the remote account's signature
+        /// over the pending challenge, as relayed by that chain (e.g. over XCM)
+        ///
+        /// # Errors
+        /// Returns `Error::ChainNotSupported` if the relaying chain is not registered
+        /// Returns `Error::InvalidLinkSignature` if the remote account did not sign the challenge
+        /// Fails like `note_identity_link_proof` otherwise
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(74)]
+        pub fn relay_identity_link_proof(
+            origin: OriginFor<T>,
             account: T::AccountId,
-            #[pallet::index(1)]
+            remote_account: RemoteAccount,
+            challenge: H256,
+            signature: RemoteSignature,
+        ) -> DispatchResult {
+            let chain = T::ChainOrigin::ensure_origin(origin)?;
+            ensure!(Self::is_chain_registered(&chain), Error::<T>::ChainNotSupported);
+
+            Self::note_identity_link_proof(account, chain, remote_account, challenge, &signature)
+        }
+
+        /// Unlink one of the caller's remote accounts
+        ///
+        /// # Errors
+        /// Returns `Error::IdentityNotLinked` if the remote account is not linked to the caller
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(71)]
+        pub fn unlink_identity(
+            origin: OriginFor<T>,
+            chain: ChainId,
+            remote_account: RemoteAccount,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                IdentityOwners::<T>::get(&chain, &remote_account).as_ref() == Some(&who),
+                Error::<T>::IdentityNotLinked
+            );
+
+            IdentityOwners::<T>::remove(&chain, &remote_account);
+            LinkedIdentities::<T>::mutate(&who, |links| {
+                links.retain(|(linked_chain, linked)| *linked_chain != chain || *linked != remote_account)
+            });
+            Self::deposit_event(Event::IdentityUnlinked { account: who, chain, remote_account });
+
+            Ok(())
+        }
+
+        /// Set the share of linked remote reputation counted in `aggregate_reputation`
+        /// (governance-only)
+        ///
+        /// A change is structural and bumps `AlgorithmVersion`.
+        ///
+        /// # Errors
+        /// Returns `Error::RequiresGovernance` if origin is not governance
+        /// Returns `Error::StructuralChangeInCompatibilityMode` for a change while
+        /// `CompatibilityMode` is on
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(72)]
+        pub fn set_remote_reputation_weight(origin: OriginFor<T>, weight: Percent) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)
+                .map_err(|_| Error::<T>::RequiresGovernance)?;
+
+            if weight != RemoteReputationWeight::<T>::get() {
+                Self::bump_algorithm_version()?;
+            }
+            RemoteReputationWeight::<T>::put(weight);
+            Self::deposit_event(Event::RemoteReputationWeightSet { weight });
+
+            Ok(())
+        }
+
+        /// Replace the webhooks verification reminders are POSTed to (governance-only)
+        ///
+        /// An empty list stops the reminders; the queue stays readable through
+        /// `VerifierPoolApi::reminder_queue`.
+        ///
+        /// # Errors
+        /// Returns `Error::RequiresGovernance` if origin is not governance
+        /// Returns `Error::TooManyNotificationEndpoints` if more than `MAX_NOTIFICATION_ENDPOINTS` are given
+        /// Returns `Error::InvalidNotificationEndpoint` if an endpoint is not an `https://` URL
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(73)]
+        pub fn set_notification_endpoints(origin: OriginFor<T>, endpoints: Vec<Vec<u8>>) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)
+                .map_err(|_| Error::<T>::RequiresGovernance)?;
+
+            let endpoints = endpoints
+                .into_iter()
+                .map(|endpoint| {
+                    ensure!(endpoint.starts_with(b"https://"), Error::<T>::InvalidNotificationEndpoint);
+                    NotificationEndpoint::try_from(endpoint).map_err(|_| Error::<T>::InvalidNotificationEndpoint)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let endpoints: BoundedVec<_, _> =
+                endpoints.try_into().map_err(|_| Error::<T>::TooManyNotificationEndpoints)?;
+
+            let count = endpoints.len() as u32;
+            NotificationEndpoints::<T>::put(endpoints);
+            Self::deposit_event(Event::NotificationEndpointsSet { endpoints: count });
+
+            Ok(())
+        }
+
+        /// Record that the reminders of every deadline up to `until` were POSTed
+        ///
+        /// Submitted by the off-chain worker once its POSTs succeeded, so other nodes do
+        /// not send the same reminders again.
+        ///
+        /// # Errors
+        /// Returns `Error::InvalidReminderCursor` if reminders were already reported sent
+        /// up to `until`, or `until` is more than `ReminderLead` blocks ahead
+        #[pallet::weight(Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(1, 1)))]
+        #[pallet::call_index(79)]
+        pub fn note_reminders_sent(origin: OriginFor<T>, until: T::BlockNumber) -> DispatchResult {
+            T::OwnershipVerifierOrigin::ensure_origin(origin)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                until <= now.saturating_add(T::ReminderLead::get())
+                    && RemindersSentUntil::<T>::get().map_or(true, |sent| until > sent),
+                Error::<T>::InvalidReminderCursor
+            );
+            RemindersSentUntil::<T>::put(until);
+
+            Self::deposit_event(Event::RemindersSent { until });
+
+            Ok(())
+        }
+
+        /// Switch compatibility mode on or off (governance-only)
+        ///
+        /// While on, structural changes (those bumping `AlgorithmVersion`) are refused, so
+        /// consumers pinned to the current version keep getting scores with the same meaning.
+        ///
+        /// # Errors
+        /// Returns `Error::RequiresGovernance` if origin is not governance
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(68)]
+        pub fn set_compatibility_mode(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)
+                .map_err(|_| Error::<T>::RequiresGovernance)?;
+
+            CompatibilityMode::<T>::put(enabled);
+            Self::deposit_event(Event::CompatibilityModeSet { enabled });
+
+            Ok(())
+        }
+
+        /// Set the shares of the usual points awarded for imported contributions
+        /// (governance-only)
+        ///
+        /// A change is structural and bumps `AlgorithmVersion`.
+        ///
+        /// # Errors
+        /// Returns `Error::RequiresGovernance` if origin is not governance
+        /// Returns `Error::StructuralChangeInCompatibilityMode` for a change while
+        /// `CompatibilityMode` is on
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(75)]
+        pub fn set_import_points(origin: OriginFor<T>, points: ImportPoints) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)
+                .map_err(|_| Error::<T>::RequiresGovernance)?;
+
+            if points != Self::import_points() {
+                Self::bump_algorithm_version()?;
+            }
+            ImportPointsPercents::<T>::put(points);
+            Self::deposit_event(Event::ImportPointsSet { points });
+
+            Ok(())
+        }
+
+        /// Approve the bulk import of a project's exported history (governance-only)
+        ///
+        /// `export_digest` commits to the export, e.g. the blake2-256 hash of a GitHub
+        /// organization's contribution dump; `importer` may then submit up to `max_items`
+        /// of its contributions with `submit_import_batch`.
+        ///
+        /// # Errors
+        /// Returns `Error::RequiresGovernance` if origin is not governance
+        /// Returns `Error::ProjectNotFound` if the project does not exist
+        /// Returns `Error::BulkImportExists` if the export was already approved
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(65)]
+        pub fn approve_bulk_import(
+            origin: OriginFor<T>,
+            export_digest: H256,
+            importer: T::AccountId,
+            project_id: ProjectId,
+            max_items: u32,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)
+                .map_err(|_| Error::<T>::RequiresGovernance)?;
+            ensure!(Projects::<T>::contains_key(project_id), Error::<T>::ProjectNotFound);
+            ensure!(!BulkImports::<T>::contains_key(export_digest), Error::<T>::BulkImportExists);
+
+            BulkImports::<T>::insert(
+                export_digest,
+                BulkImport { importer: importer.clone(), project_id, max_items, imported: 0 },
+            );
+            Self::deposit_event(Event::BulkImportApproved { export_digest, importer, project_id, max_items });
+
+            Ok(())
+        }
+
+        /// Offer a batch of historical contributions from an approved export to the
+        /// contributors it names
+        ///
+        /// Nothing is recorded for a contributor until it accepts its contribution with
+        /// `accept_imported_contribution`, so an importer cannot take a proof or fill an
+        /// account's contributions without the account's consent.
+        ///
+        /// # Errors
+        /// Returns `Error::BulkImportNotFound` if no import of the export was approved
+        /// Returns `Error::NotBulkImporter` if the caller is not the export's importer
+        /// Returns `Error::BulkImportLimitExceeded` if the export's `max_items` would be exceeded
+        /// Returns `Error::InvalidProof`, `Error::InvalidContributionWeight` or
+        /// `Error::ContributionAlreadySubmitted` for an invalid item, importing none of the batch
+        #[pallet::weight(<T as Config>::WeightInfo::add_contribution().saturating_mul(items.len() as u64))]
+        #[pallet::call_index(66)]
+        pub fn submit_import_batch(
+            origin: OriginFor<T>,
+            export_digest: H256,
+            items: BoundedVec<ImportItem<T::AccountId>, T::MaxImportBatch>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let mut import = BulkImports::<T>::get(export_digest).ok_or(Error::<T>::BulkImportNotFound)?;
+            ensure!(import.importer == who, Error::<T>::NotBulkImporter);
+            let count = items.len() as u32;
+            import.imported = import.imported.saturating_add(count);
+            ensure!(import.imported <= import.max_items, Error::<T>::BulkImportLimitExceeded);
+
+            for ImportItem { contributor, proof, contribution_type, weight } in items {
+                ensure!(proof != H256::zero(), Error::<T>::InvalidProof);
+                ensure!(weight >= 1 && weight <= 100, Error::<T>::InvalidContributionWeight);
+                ensure!(
+                    !ContributionsByProof::<T>::contains_key(proof)
+                        && !UnacceptedImports::<T>::contains_key(&contributor, proof),
+                    Error::<T>::ContributionAlreadySubmitted
+                );
+                UnacceptedImports::<T>::insert(
+                    &contributor,
+                    proof,
+                    UnacceptedImport { export_digest, contribution_type, weight },
+                );
+                Self::deposit_event(Event::ImportOffered { contributor, export_digest, proof });
+            }
+            BulkImports::<T>::insert(export_digest, import);
+
+            Self::deposit_event(Event::ImportBatchSubmitted { export_digest, count });
+
+            Ok(())
+        }
+
+        /// Confirm a bulk-imported contribution, awarding the `import_points` share of its points
+        ///
+        /// Like other contributions, an import is verified once its verifications reach
+        /// `MinVerifications`; the consensus of their scores scales the points.
+        ///
+        /// # Errors
+        /// Returns `Error::NotImportedContribution` if the contribution is not awaiting
+        /// import verification
+        /// Returns `Error::SelfVerificationNotAllowed` if the caller is the contributor or
+        /// the importer
+        /// Returns `Error::ContributionAlreadyVerified` if the caller already verified it
+        /// Returns `Error::InsufficientReputationToVerify` if the caller lacks required reputation
+        /// Returns `Error::OnProbation` if the caller is on probation
+        /// Returns `Error::VerificationRightsRevoked` if the caller reached `MaxOutlierStrikes`
+        /// Returns `Error::InvalidVerificationScore` if score is above 100
+        #[pallet::weight(<T as Config>::WeightInfo::verify_contribution())]
+        #[pallet::call_index(67)]
+        pub fn verify_imported_contribution(
+            origin: OriginFor<T>,
             contribution_id: ContributionId,
-            detection_reason: Vec<u8>,
-        },
-        /// Cross-chain reputation query initiated
-        CrossChainQueryInitiated {
-            #[pallet::index(0)]
-            query_id: u64,
-            #[pallet::index(1)]
-            target_chain: Vec<u8>,
-            #[pallet::index(2)]
-            target_account: Vec<u8>,
-        },
-        /// Algorithm parameters updated via governance
-        AlgorithmParamsUpdated {
-            old_params: AlgorithmParams,
-            new_params: AlgorithmParams,
-        },
-    }
+            score: u8,
+        ) -> DispatchResult {
+            let verifier = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let mut contribution = Contributions::<T>::get(contribution_id)
+                .filter(|contribution| contribution.status == ContributionStatus::Imported)
+                .ok_or(Error::<T>::NotImportedContribution)?;
+            let export_digest = ImportedContributions::<T>::get(contribution_id)
+                .ok_or(Error::<T>::NotImportedContribution)?;
+            let contributor = ContributionProofs::<T>::get(contribution.proof)
+                .ok_or(Error::<T>::ContributionNotFound)?;
+            let importer = BulkImports::<T>::get(export_digest).map(|import| import.importer);
+            ensure!(
+                verifier != contributor && Some(&verifier) != importer.as_ref(),
+                Error::<T>::SelfVerificationNotAllowed
+            );
+            Self::ensure_qualified_verifier(&verifier)?;
+            ensure!(
+                !ContributionVerifications::<T>::contains_key(contribution_id, &verifier),
+                Error::<T>::ContributionAlreadyVerified
+            );
+            ensure!(score <= 100, Error::<T>::InvalidVerificationScore);
+
+            ContributionVerifications::<T>::insert(contribution_id, &verifier, (score, Vec::<u8>::new()));
+            Self::record_verification_activity(&verifier, &contributor, score);
+            contribution.verification_count = contribution.verification_count
+                .saturating_add(Self::verification_weight(&verifier, contribution_id));
+            // Imports have no committee or maintainer track, so the quorum alone completes them
+            let reputation_gained = if contribution.verification_count >= T::MinVerifications::get() {
+                Self::complete_verification(&contributor, &mut contribution)?
+            } else {
+                0
+            };
+            Contributions::<T>::insert(contribution_id, &contribution);
 
-    // Errors inform users that something went wrong.
-    #[pallet::error]
-    pub enum Error<T> {
-        /// Account does not exist in the system
-        AccountNotFound,
-        /// Contribution proof already submitted (duplicate)
-        ContributionAlreadySubmitted,
-        /// Maximum contributions per account exceeded
-        MaxContributionsExceeded,
-        /// Reputation score exceeds maximum allowed value
-        ReputationScoreOverflow,
-        /// Reputation score below minimum allowed value
-        ReputationScoreUnderflow,
-        /// Insufficient reputation to perform this operation
-        InsufficientReputation,
-        /// Insufficient reputation to verify contributions
-        InsufficientReputationToVerify,
-        /// Contribution not found
-        ContributionNotFound,
-        /// Contribution already verified
-        ContributionAlreadyVerified,
-        /// Rate limit exceeded (too many pending contributions)
-        RateLimited,
-        /// Invalid verification score (must be 0-100)
-        InvalidVerificationScore,
-        /// Invalid proof hash (cannot be zero)
-        InvalidProof,
-        /// XCM message routing failed
-        XcmRoutingError,
-        /// XCM execution failed
-        XcmExecutionFailed,
-        /// Off-chain worker failed to fetch data
-        OffchainFetchFailed,
-        /// Sybil attack detected
-        SybilAttackDetected,
-        /// Requires governance origin
-        RequiresGovernance,
-        /// Query timeout exceeded
-        QueryTimeout,
-        /// Query not found
-        QueryNotFound,
-        /// Chain not supported for cross-chain queries
-        ChainNotSupported,
-        /// Invalid algorithm parameters
-        InvalidAlgorithmParams,
-        /// Contribution weight exceeds maximum (must be 1-100)
-        InvalidContributionWeight,
-        /// Self-verification not allowed
-        SelfVerificationNotAllowed,
-    }
+            Self::deposit_event(Event::ImportedContributionVerified {
+                contributor,
+                contribution_id,
+                verifier,
+                reputation_gained,
+            });
 
-    // Dispatchable functions allow users to interact with the pallet and invoke state changes.
-    #[pallet::call]
-    impl<T: Config> Pallet<T> {
-        /// Adds a new contribution to the reputation system
+            Ok(())
+        }
+
+        /// Accept a bulk-imported contribution naming the caller as its author
         ///
-        /// # Arguments
-        /// * `origin` - The account adding the contribution
-        /// * `proof` - Cryptographic proof of the contribution
-        /// * `contribution_type` - Type of contribution (code, docs, etc.)
-        /// * `weight` - Relative weight of the contribution
-        /// * `source` - Data source (GitHub, GitLab, etc.)
+        /// The contribution is recorded with status `Imported` and assigned to the export's
+        /// project. It holds no deposit and does not count against the pending limit, but
+        /// earns nothing until verified with `verify_imported_contribution`.
         ///
         /// # Errors
-        /// Returns `Error::ContributionAlreadySubmitted` if the proof was already used
-        /// Returns `Error::RateLimited` if the account has too many pending contributions
-        /// Returns `Error::MaxContributionsExceeded` if account exceeds contribution limit
-        ///
-        /// # Events
-        /// Emits `ContributionSubmitted` on success
+        /// Returns `Error::NotImportedContribution` if no import offers the caller the proof
+        /// Returns `Error::BulkImportNotFound` if the export's import was removed
+        /// Returns `Error::ContributionAlreadySubmitted` if the proof was submitted meanwhile
+        /// Returns `Error::MaxContributionsExceeded` if the caller has no contribution slot left
         #[pallet::weight(<T as Config>::WeightInfo::add_contribution())]
-        pub fn add_contribution(
-            origin: OriginFor<T>,
+        #[pallet::call_index(76)]
+        pub fn accept_imported_contribution(origin: OriginFor<T>, proof: H256) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            let UnacceptedImport { export_digest, contribution_type, weight } =
+                UnacceptedImports::<T>::take(&who, proof).ok_or(Error::<T>::NotImportedContribution)?;
+            let import = BulkImports::<T>::get(export_digest).ok_or(Error::<T>::BulkImportNotFound)?;
+
+            let contribution_id =
+                Self::import_contribution(ImportItem { contributor: who, proof, contribution_type, weight })?;
+            Self::assign_project(contribution_id, import.project_id);
+            ImportedContributions::<T>::insert(contribution_id, export_digest);
+
+            Ok(())
+        }
+
+        /// Decline a bulk-imported contribution naming the caller as its author
+        ///
+        /// # Errors
+        /// Returns `Error::NotImportedContribution` if no import offers the caller the proof
+        #[pallet::weight(Weight::from_parts(10_000_000, 0))]
+        #[pallet::call_index(77)]
+        pub fn decline_imported_contribution(origin: OriginFor<T>, proof: H256) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            UnacceptedImports::<T>::take(&who, proof).ok_or(Error::<T>::NotImportedContribution)?;
+
+            Self::deposit_event(Event::ImportDeclined { contributor: who, proof });
+
+            Ok(())
+        }
+    }
+
+    /// Query status for cross-chain reputation queries
+    #[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum QueryStatus {
+        Pending,
+        Completed,
+        Timeout,
+        Failed,
+    }
+
+    /// Reputation query structure for cross-chain queries
+    #[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct ReputationQuery<T: Config> {
+        pub query_id: u64,
+        pub target_chain: Vec<u8>,
+        pub target_account: Vec<u8>,
+        pub status: QueryStatus,
+        pub initiated_at: T::BlockNumber,
+        pub response: Option<(Score, u8)>, // (score, percentile)
+        pub timeout: T::BlockNumber,
+    }
+
+    impl<T: Config> ReputationQuery<T> {
+        /// Whether the query was answered, failed, or is pending past its timeout
+        pub fn is_settled(&self, now: T::BlockNumber) -> bool {
+            self.status != QueryStatus::Pending || now > self.timeout
+        }
+    }
+
+    /// Storage for cross-chain reputation queries
+    #[pallet::storage]
+    pub type ReputationQueries<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        ReputationQuery<T>,
+        OptionQuery,
+    >;
+
+    /// Query ID counter
+    #[pallet::storage]
+    pub type NextQueryId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Registered chains for cross-chain queries
+    #[pallet::storage]
+    pub type RegisteredChains<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ChainId,
+        bool,
+        ValueQuery,
+    >;
+
+    impl<T: Config> Pallet<T> {
+        /// Submit a contribution for `who`, returning its id
+        ///
+        /// Shared by `add_contribution` and `proxy_add_contribution`. A Sybil rejection
+        /// carries the weight of the checks made.
+        fn submit_contribution(
+            who: &T::AccountId,
             proof: H256,
             contribution_type: ContributionType,
             weight: u8,
             source: DataSource,
-        ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
-
+        ) -> Result<ContributionId, DispatchErrorWithPostInfo> {
             // 1. CHECKS: Validate inputs
+            Self::ensure_not_paused()?;
             ensure!(proof != H256::zero(), Error::<T>::InvalidProof);
             ensure!(
                 weight >= 1 && weight <= 100,
@@ -487,37 +5817,318 @@ pub mod pallet {
 
             // Rate limiting check
             ensure!(
-                Self::can_add_contribution(&who),
+                Self::can_add_contribution(who),
+                Error::<T>::RateLimited
+            );
+
+            // Check if proof already submitted
+            ensure!(
+                !ContributionsByProof::<T>::contains_key(proof),
+                Error::<T>::ContributionAlreadySubmitted
+            );
+
+            // Check contribution limit
+            let account_contributions = AccountContributions::<T>::get(who);
+            ensure!(
+                (account_contributions.len() as u32) < T::MaxContributionsPerAccount::get(),
+                Error::<T>::MaxContributionsExceeded
+            );
+
+            // Sybil detection: Check for suspicious patterns
+            if Self::detect_sybil_attack(who) {
+                Self::deposit_event(Event::SybilAttackDetected {
+                    account: who.clone(),
+                    contribution_id: 0,
+                    detection_reason: b"Suspicious submission pattern".to_vec(),
+                });
+                return Err(Error::<T>::SybilAttackDetected.with_weight(T::WeightInfo::add_contribution_rejected()));
+            }
+
+            // 2. EFFECTS: Update state
+            let contribution_id = Self::get_next_contribution_id();
+            Self::hold_deposit(who, contribution_id)?;
+
+            // Create contribution
+            let contribution = Contribution {
+                id: contribution_id,
+                proof,
+                contribution_type: contribution_type.clone(),
+                weight,
+                verified: false,
+                source: source.clone(),
+                timestamp: frame_system::Pallet::<T>::block_number(),
+                status: ContributionStatus::Pending,
+                verification_count: 0,
+            };
+
+            // Store contribution (checks-effects-interactions pattern)
+            Contributions::<T>::insert(contribution_id, &contribution);
+            ContributionsByProof::<T>::insert(proof, contribution_id);
+            ContributionProofs::<T>::insert(proof, who);
+            crate::indexing::index_contribution::<T>(who, &contribution);
+            ContributionsOfType::<T>::insert(&contribution.contribution_type, Self::index_key(contribution_id), ());
+            Self::note_activity(who, |stats| stats.submitted = stats.submitted.saturating_add(1));
+
+            // Update account contributions list
+            let mut contributions = account_contributions;
+            contributions.try_push(contribution_id)
+                .map_err(|_| Error::<T>::MaxContributionsExceeded)?;
+            AccountContributions::<T>::insert(who, contributions);
+
+            // Update pending contributions count
+            PendingContributions::<T>::mutate(who, |count| *count = count.saturating_add(1));
+            Self::schedule_deadline(contribution_id, contribution.timestamp.saturating_add(T::VerificationDeadline::get()))
+                .ok_or(Error::<T>::DeadlineSlotsFull)?;
+
+            // Update contribution count (saturating to prevent overflow)
+            ContributionCounts::<T>::mutate(who, |count| *count = count.saturating_add(1));
+
+            // 3. INTERACTIONS: Emit event
+            Self::deposit_event(Event::ContributionSubmitted {
+                contributor: who.clone(),
+                contribution_id,
+                proof_hash: proof,
+                contribution_type,
+                source,
+            });
+
+            Ok(contribution_id)
+        }
+
+        /// Record a bulk-imported historical contribution its contributor accepted, with
+        /// status `Imported`
+        ///
+        /// Unlike `submit_contribution`, no deposit is held, the contributor's rate limit
+        /// and pending count are untouched and no verification deadline is scheduled.
+        fn import_contribution(item: ImportItem<T::AccountId>) -> Result<ContributionId, DispatchError> {
+            let ImportItem { contributor, proof, contribution_type, weight } = item;
+            ensure!(proof != H256::zero(), Error::<T>::InvalidProof);
+            ensure!(weight >= 1 && weight <= 100, Error::<T>::InvalidContributionWeight);
+            ensure!(!ContributionsByProof::<T>::contains_key(proof), Error::<T>::ContributionAlreadySubmitted);
+
+            let contribution_id = Self::get_next_contribution_id();
+            AccountContributions::<T>::try_mutate(&contributor, |contributions| contributions.try_push(contribution_id))
+                .map_err(|_| Error::<T>::MaxContributionsExceeded)?;
+
+            let contribution = Contribution {
+                id: contribution_id,
+                proof,
+                contribution_type: contribution_type.clone(),
+                weight,
+                verified: false,
+                source: DataSource::GitHub,
+                timestamp: frame_system::Pallet::<T>::block_number(),
+                status: ContributionStatus::Imported,
+                verification_count: 0,
+            };
+            Contributions::<T>::insert(contribution_id, &contribution);
+            ContributionsByProof::<T>::insert(proof, contribution_id);
+            ContributionProofs::<T>::insert(proof, &contributor);
+            crate::indexing::index_contribution::<T>(&contributor, &contribution);
+            ContributionsOfType::<T>::insert(&contribution.contribution_type, Self::index_key(contribution_id), ());
+            ContributionCounts::<T>::mutate(&contributor, |count| *count = count.saturating_add(1));
+            Self::note_activity(&contributor, |stats| stats.submitted = stats.submitted.saturating_add(1));
+
+            Self::deposit_event(Event::ContributionSubmitted {
+                contributor,
+                contribution_id,
+                proof_hash: proof,
+                contribution_type,
+                source: DataSource::GitHub,
+            });
+
+            Ok(contribution_id)
+        }
+
+        /// Update the activity counters of `who` for something it just did
+        fn note_activity(who: &T::AccountId, update: impl FnOnce(&mut ActivityStats<T::BlockNumber>)) {
+            AccountActivity::<T>::mutate(who, |stats| {
+                update(stats);
+                stats.last_active = frame_system::Pallet::<T>::block_number();
+            });
+        }
+
+        /// Count a verification for the verifier and the score for the contributor
+        fn record_verification_activity(verifier: &T::AccountId, contributor: &T::AccountId, score: u8) {
+            Self::note_activity(verifier, |stats| {
+                stats.verifications_performed = stats.verifications_performed.saturating_add(1)
+            });
+            VerifierPool::<T>::mutate(verifier, |availability| {
+                if let Some(availability) = availability {
+                    availability.note_review(Self::verifier_era());
+                }
+            });
+            AccountActivity::<T>::mutate(contributor, |stats| {
+                stats.scores_received = stats.scores_received.saturating_add(1);
+                stats.received_score_total = stats.received_score_total.saturating_add(score as u64);
+            });
+        }
+
+        /// Count a rejected contribution of `contributor`
+        fn note_rejection(contributor: &T::AccountId) {
+            AccountActivity::<T>::mutate(contributor, |stats| stats.rejected = stats.rejected.saturating_add(1));
+        }
+
+        /// Whether `proof` shows `item_proof` as item `index` of a bundled contribution
+        pub fn is_bundle_item(contribution_id: ContributionId, item_proof: H256, index: u32, proof: &[H256]) -> bool {
+            ContributionBundles::<T>::get(contribution_id).map_or(false, |bundle| {
+                crate::archive::verify_merkle_proof(bundle.root, item_proof, index, bundle.items, proof)
+            })
+        }
+
+        /// Record the answer to a pending cross-chain query; `None` marks it failed
+        ///
+        /// Called by `process_xcm_response`. The off-chain worker later moves the
+        /// answer into `RemoteReputation`.
+        pub fn note_query_response(query_id: u64, response: Option<(Score, u8)>) -> DispatchResult {
+            ReputationQueries::<T>::try_mutate(query_id, |query| {
+                let query = query.as_mut().ok_or(Error::<T>::QueryNotFound)?;
+                ensure!(query.status == QueryStatus::Pending, Error::<T>::QueryNotPending);
+                query.status = if response.is_some() { QueryStatus::Completed } else { QueryStatus::Failed };
+                query.response = response;
+                Ok(())
+            })
+        }
+
+        /// Cache the answer of a settled query; false if it has none or its chain or
+        /// account does not fit the cache keys
+        fn cache_remote_score(query: &ReputationQuery<T>, now: T::BlockNumber) -> bool {
+            let (QueryStatus::Completed, Some((score, percentile))) = (&query.status, query.response) else {
+                return false;
+            };
+            let (Ok(chain), Ok(account)) = (
+                ChainId::try_from(query.target_chain.clone()),
+                RemoteAccount::try_from(query.target_account.clone()),
+            ) else {
+                return false;
+            };
+            RemoteReputation::<T>::insert(chain, account, RemoteScore { score, percentile, updated_at: now });
+            true
+        }
+
+        /// Link a remote account to `account` once the remote account signed its pending
+        /// challenge
+        ///
+        /// Called by `relay_identity_link_proof` and `attest_identity_link`.
+        pub fn note_identity_link_proof(
+            account: T::AccountId,
+            chain: ChainId,
+            remote_account: RemoteAccount,
+            challenge: H256,
+            signature: &RemoteSignature,
+        ) -> DispatchResult {
+            let key = (chain.clone(), remote_account.clone());
+            let expected = LinkChallenges::<T>::get(&account, &key).ok_or(Error::<T>::NoLinkChallenge)?;
+            ensure!(challenge == expected, Error::<T>::InvalidLinkChallenge);
+            ensure!(
+                Self::is_remote_signature(&remote_account, &challenge, signature),
+                Error::<T>::InvalidLinkSignature
+            );
+            ensure!(
+                !IdentityOwners::<T>::contains_key(&chain, &remote_account),
+                Error::<T>::IdentityAlreadyLinked
+            );
+
+            LinkedIdentities::<T>::try_mutate(&account, |links| links.try_push(key.clone()))
+                .map_err(|_| Error::<T>::TooManyLinkedIdentities)?;
+            IdentityOwners::<T>::insert(&chain, &remote_account, &account);
+            LinkChallenges::<T>::remove(&account, &key);
+
+            Self::deposit_event(Event::IdentityLinked { account, chain, remote_account });
+
+            Ok(())
+        }
+
+        /// Whether `signature` is `remote_account`'s signature over `challenge`
+        fn is_remote_signature(remote_account: &[u8], challenge: &H256, signature: &RemoteSignature) -> bool {
+            use sp_core::{ed25519, sr25519};
+            use sp_runtime::MultiSignature;
+
+            match signature {
+                MultiSignature::Sr25519(signature) => sr25519::Public::try_from(remote_account)
+                    .map_or(false, |public| sp_io::crypto::sr25519_verify(signature, challenge.as_bytes(), &public)),
+                MultiSignature::Ed25519(signature) => ed25519::Public::try_from(remote_account)
+                    .map_or(false, |public| sp_io::crypto::ed25519_verify(signature, challenge.as_bytes(), &public)),
+                MultiSignature::Ecdsa(signature) => remote_account.len() == 20 &&
+                    crate::evm_bridge::recover_signer(challenge.as_bytes(), signature)
+                        .map_or(false, |signer| signer.as_bytes() == remote_account),
+            }
+        }
+
+        /// Weight of `effective_reputation`: the score, the credibility boost, the
+        /// attestation multiplier and up to `MAX_ATTESTATIONS_PER_ACCOUNT` attestations with
+        /// their attestors
+        pub fn effective_reputation_weight() -> Weight {
+            T::DbWeight::get().reads(3 + 2 * MAX_ATTESTATIONS_PER_ACCOUNT as u64)
+        }
+
+        /// Weight of `aggregate_reputation`: `effective_reputation` plus the remote weight,
+        /// the linked identities and up to `MAX_LINKED_IDENTITIES` cached remote scores
+        pub fn aggregate_reputation_weight() -> Weight {
+            Self::effective_reputation_weight()
+                .saturating_add(T::DbWeight::get().reads(2 + MAX_LINKED_IDENTITIES as u64))
+        }
+
+        /// Whether a cached remote score is older than `RemoteReputationTtl`
+        pub(crate) fn is_remote_score_expired(entry: &RemoteScore<T::BlockNumber>, now: T::BlockNumber) -> bool {
+            now > entry.updated_at.saturating_add(T::RemoteReputationTtl::get())
+        }
+
+        /// Whether every limit of `config` is within bounds
+        pub(crate) fn is_valid_pipeline_config(config: &PipelineConfig) -> bool {
+            (1..=PipelineConfig::MAX_BATCH_SIZE).contains(&config.max_batch_size) && config.query_timeout > 0
+        }
+
+        /// Fail with `Error::BatchTooLarge` if `len` items exceed the pipeline's batch size
+        pub(crate) fn ensure_batch_size(len: usize) -> DispatchResult {
+            ensure!(len <= PipelineParams::<T>::get().max_batch_size as usize, Error::<T>::BatchTooLarge);
+            Ok(())
+        }
+
+        /// Weight of `withdraw_contribution` releasing `item_proofs` bundle item proofs
+        fn withdraw_weight(item_proofs: u32) -> Weight {
+            Weight::from_parts(30_000_000, 0)
+                .saturating_add(T::DbWeight::get().writes(T::MinVerifications::get().into()))
+                .saturating_add(T::DbWeight::get().reads_writes(item_proofs.into(), 2 * item_proofs as u64))
+        }
+
+        /// Fail with `Error::PipelinePaused` while the contribution pipeline is paused
+        fn ensure_not_paused() -> DispatchResult {
+            ensure!(!Paused::<T>::get(), Error::<T>::PipelinePaused);
+            Ok(())
+        }
+
+        /// Internal helper for adding contribution (without event emission)
+        fn add_contribution_internal(
+            who: &T::AccountId,
+            proof: H256,
+            contribution_type: ContributionType,
+            weight: u8,
+            source: DataSource,
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            ensure!(proof != H256::zero(), Error::<T>::InvalidProof);
+            ensure!(
+                weight >= 1 && weight <= 100,
+                Error::<T>::InvalidContributionWeight
+            );
+            ensure!(
+                Self::can_add_contribution(who),
                 Error::<T>::RateLimited
             );
-
-            // Check if proof already submitted
             ensure!(
                 !ContributionsByProof::<T>::contains_key(proof),
                 Error::<T>::ContributionAlreadySubmitted
             );
 
-            // Check contribution limit
-            let account_contributions = AccountContributions::<T>::get(&who);
+            let account_contributions = AccountContributions::<T>::get(who);
             ensure!(
                 (account_contributions.len() as u32) < T::MaxContributionsPerAccount::get(),
                 Error::<T>::MaxContributionsExceeded
             );
 
-            // Sybil detection: Check for suspicious patterns
-            if Self::detect_sybil_attack(&who) {
-                Self::deposit_event(Event::SybilAttackDetected {
-                    account: who.clone(),
-                    contribution_id: 0,
-                    detection_reason: b"Suspicious submission pattern".to_vec(),
-                });
-                return Err(Error::<T>::SybilAttackDetected.into());
-            }
-
-            // 2. EFFECTS: Update state
             let contribution_id = Self::get_next_contribution_id();
-
-            // Create contribution
+            Self::hold_deposit(who, contribution_id)?;
             let contribution = Contribution {
                 id: contribution_id,
                 proof,
@@ -530,636 +6141,1255 @@ pub mod pallet {
                 verification_count: 0,
             };
 
-            // Store contribution (checks-effects-interactions pattern)
             Contributions::<T>::insert(contribution_id, &contribution);
             ContributionsByProof::<T>::insert(proof, contribution_id);
-            ContributionProofs::<T>::insert(proof, &who);
+            ContributionProofs::<T>::insert(proof, who);
+            crate::indexing::index_contribution::<T>(who, &contribution);
+            ContributionsOfType::<T>::insert(&contribution.contribution_type, Self::index_key(contribution_id), ());
+            Self::note_activity(who, |stats| stats.submitted = stats.submitted.saturating_add(1));
 
-            // Update account contributions list
             let mut contributions = account_contributions;
             contributions.try_push(contribution_id)
                 .map_err(|_| Error::<T>::MaxContributionsExceeded)?;
-            AccountContributions::<T>::insert(&who, contributions);
-
-            // Update pending contributions count
-            PendingContributions::<T>::mutate(&who, |count| *count = count.saturating_add(1));
-
-            // Update contribution count (saturating to prevent overflow)
-            ContributionCounts::<T>::mutate(&who, |count| *count = count.saturating_add(1));
+            AccountContributions::<T>::insert(who, contributions);
 
-            // 3. INTERACTIONS: Emit event
-            Self::deposit_event(Event::ContributionSubmitted {
-                contributor: who,
-                contribution_id,
-                proof_hash: proof,
-                contribution_type,
-                source,
-            });
+            PendingContributions::<T>::mutate(who, |count| *count = count.saturating_add(1));
+            ContributionCounts::<T>::mutate(who, |count| *count = count.saturating_add(1));
+            Self::schedule_deadline(contribution_id, contribution.timestamp.saturating_add(T::VerificationDeadline::get()))
+                .ok_or(Error::<T>::DeadlineSlotsFull)?;
 
             Ok(())
         }
 
-        /// Verify a contribution
-        ///
-        /// # Arguments
-        /// * `origin` - The verifier account
-        /// * `contributor` - The account that made the contribution
-        /// * `contribution_id` - ID of the contribution to verify
-        /// * `score` - Verification score (0-100)
-        /// * `comment` - Optional comment
-        ///
-        /// # Errors
-        /// Returns `Error::InsufficientReputationToVerify` if verifier lacks required reputation
-        /// Returns `Error::ContributionNotFound` if contribution doesn't exist
-        /// Returns `Error::InvalidVerificationScore` if score is out of range
-        #[pallet::weight(<T as Config>::WeightInfo::verify_contribution())]
-        pub fn verify_contribution(
-            origin: OriginFor<T>,
-            contributor: T::AccountId,
+        /// Internal helper for verifying contribution
+        fn verify_contribution_internal(
+            verifier: &T::AccountId,
+            contributor: &T::AccountId,
             contribution_id: ContributionId,
             score: u8,
             comment: Vec<u8>,
         ) -> DispatchResult {
-            let verifier = ensure_signed(origin)?;
-
-            // 1. CHECKS: Validate inputs and permissions
-            // Prevent self-verification
+            Self::ensure_not_paused()?;
             ensure!(
                 verifier != contributor,
                 Error::<T>::SelfVerificationNotAllowed
             );
-
-            // Check verifier has sufficient reputation
-            let verifier_reputation = ReputationScores::<T>::get(&verifier);
-            ensure!(
-                verifier_reputation >= T::MinReputationToVerify::get(),
-                Error::<T>::InsufficientReputationToVerify
-            );
-
-            // Validate score range
+            ensure!(!Self::is_on_probation(verifier), Error::<T>::OnProbation);
+            ensure!(Self::has_verification_rights(verifier), Error::<T>::VerificationRightsRevoked);
             ensure!(
                 score <= 100,
                 Error::<T>::InvalidVerificationScore
             );
 
-            // Get contribution
             let mut contribution = Contributions::<T>::get(contribution_id)
                 .ok_or(Error::<T>::ContributionNotFound)?;
 
-            // Check contribution is still pending
             ensure!(
                 !contribution.verified,
                 Error::<T>::ContributionAlreadyVerified
             );
-
-            // Check contribution belongs to contributor
+            ensure!(!Self::requires_committee(&contribution), Error::<T>::RequiresCommitteeApproval);
             ensure!(
                 ContributionProofs::<T>::get(contribution.proof) == Some(contributor.clone()),
                 Error::<T>::ContributionNotFound
             );
-
-            // Check if verifier already verified this contribution
             ensure!(
-                !ContributionVerifications::<T>::contains_key(contribution_id, &verifier),
+                !ContributionVerifications::<T>::contains_key(contribution_id, verifier)
+                    && MaintainerAttestations::<T>::get(contribution_id).as_ref() != Some(verifier),
                 Error::<T>::ContributionAlreadyVerified
             );
+            ensure!(
+                !ContributionRejections::<T>::contains_key(contribution_id, verifier),
+                Error::<T>::ContributionAlreadyRejected
+            );
+            ensure!(contribution.status != ContributionStatus::Rejected, Error::<T>::ContributionNotPending);
 
-            // 2. EFFECTS: Update state
-            // Store verification
-            ContributionVerifications::<T>::insert(contribution_id, &verifier, (score, comment.clone()));
+            ContributionVerifications::<T>::insert(contribution_id, verifier, (score, comment.clone()));
+            Self::record_verification_activity(verifier, contributor, score);
+            contribution.verification_count = contribution.verification_count
+                .saturating_add(Self::verification_weight(verifier, contribution_id));
+            Self::reward_stale_verification(verifier, contribution_id);
 
-            // Update verification count (saturating to prevent overflow)
-            contribution.verification_count = contribution.verification_count.saturating_add(1);
+            Self::try_complete_verification(contributor, &mut contribution)?;
+            Contributions::<T>::insert(contribution_id, &contribution);
 
-            let mut reputation_gained = 0i32;
+            Ok(())
+        }
 
-            // Check if enough verifications to mark as verified
-            if contribution.verification_count >= T::MinVerifications::get() {
-                contribution.verified = true;
-                contribution.status = ContributionStatus::Verified;
+        /// Mark a pending contribution as verified and award its points, once it has
+        /// `MinVerifications` verifications (or `MinCommitteeApprovals` committee approvals,
+        /// above `CommitteeVerificationThreshold`) and any maintainer attestation it requires
+        ///
+        /// Returns the reputation gained; the caller stores the contribution.
+        fn try_complete_verification(
+            contributor: &T::AccountId,
+            contribution: &mut Contribution<T>,
+        ) -> Result<Score, DispatchError> {
+            // Reputation is not awarded while the pipeline is paused, whatever completes it
+            Self::ensure_not_paused()?;
+            let approved = if Self::requires_committee(contribution) {
+                CommitteeApprovals::<T>::decode_len(contribution.id).unwrap_or_default() as u32
+                    >= T::MinCommitteeApprovals::get()
+            } else {
+                contribution.verification_count >= T::MinVerifications::get()
+            };
+            if !approved
+                || Self::awaits_maintainer_attestation(contribution)
+                || Self::awaits_git_validation(contribution.id)
+            {
+                return Ok(0);
+            }
+            Self::complete_verification(contributor, contribution)
+        }
 
-                // Update reputation score using proper algorithm
-                let old_score = ReputationScores::<T>::get(&contributor);
-                let params = ReputationParams::<T>::get().unwrap_or_default();
-                
-                // Calculate reputation using algorithm parameters
-                let base_points = params.contribution_type_weights
-                    .get(&contribution.contribution_type)
-                    .copied()
-                    .unwrap_or(10) as i32;
-                
-                // Apply verification multiplier
-                let multiplier = params.verification_multiplier as i32;
-                let points = (base_points * multiplier) / 10_000;
-                
-                // Apply contribution weight
-                let weighted_points = (points * contribution.weight as i32) / 100;
-                
-                // Use saturating math to prevent overflow
-                let new_score = old_score
-                    .saturating_add(weighted_points)
-                    .max(T::MinReputation::get())
-                    .min(T::MaxReputation::get());
-                
-                ReputationScores::<T>::insert(&contributor, new_score);
-
-                // Update pending count
-                PendingContributions::<T>::mutate(&contributor, |count| *count = count.saturating_sub(1));
+        /// Mark a pending contribution as verified and award its points
+        ///
+        /// Returns the reputation gained; the caller stores the contribution.
+        fn complete_verification(
+            contributor: &T::AccountId,
+            contribution: &mut Contribution<T>,
+        ) -> Result<Score, DispatchError> {
+            // Imported contributions never counted as pending
+            if contribution.status != ContributionStatus::Imported {
+                PendingContributions::<T>::mutate(contributor, |count| *count = count.saturating_sub(1));
+            }
+            contribution.verified = true;
+            contribution.status = ContributionStatus::Verified;
+            VerifiedAt::<T>::insert(contribution.id, frame_system::Pallet::<T>::block_number());
+            VerifiedContributionCounts::<T>::mutate(contributor, |count| *count = count.saturating_add(1));
+            AccountActivity::<T>::mutate(contributor, |stats| stats.verified = stats.verified.saturating_add(1));
+            Self::reward_referrer(contributor);
+            Self::clear_escalation(contribution.id);
+            if let Some(consensus) = Self::verification_consensus(contribution.id) {
+                ConsensusScores::<T>::insert(contribution.id, consensus);
+                Self::score_verifiers(contribution.id, consensus);
+            }
 
-                // Track reputation gained
-                reputation_gained = new_score.saturating_sub(old_score);
+            // The submitter comes first in the split
+            let points = Self::verified_points(contribution)?;
+            let mut contributor_gain = None;
+            for (account, share) in Self::split_points(contribution.id, contributor, points) {
+                let share = Self::probation_adjusted(&account, share);
+                let old_score = ReputationScores::<T>::get(&account);
+                let new_score = Self::adjust_score(old_score, share)?;
+                ReputationScores::<T>::insert(&account, new_score);
+                Self::record_era_earnings(&account, new_score.saturating_sub(old_score));
+                contributor_gain.get_or_insert(new_score.saturating_sub(old_score));
+                Self::tally_hackathon_points(contribution.id, &account, share);
 
                 Self::deposit_event(Event::ReputationUpdated {
-                    account: contributor.clone(),
+                    account,
                     old_score,
                     new_score,
                     change_reason: RepChangeReason::VerificationReward,
                 });
             }
 
-            // Update contribution
-            Contributions::<T>::insert(contribution_id, &contribution);
-
-            // 3. INTERACTIONS: Emit event
-            Self::deposit_event(Event::ContributionVerified {
-                contributor,
-                contribution_id,
-                verifier,
-                score,
-                reputation_gained,
-            });
-
-            Ok(())
+            Ok(contributor_gain.unwrap_or_default())
         }
 
-        /// Update algorithm parameters (governance-only)
-        ///
-        /// # Arguments
-        /// * `origin` - Must be governance origin
-        /// * `params` - New algorithm parameters
+        /// Contributions to a project older than `before`, newest first, optionally of one type
         ///
-        /// # Errors
-        /// Returns `Error::RequiresGovernance` if origin is not governance
-        /// Returns `Error::InvalidAlgorithmParams` if parameters are invalid
-        #[pallet::weight(<T as Config>::WeightInfo::update_algorithm_params())]
-        pub fn update_algorithm_params(
-            origin: OriginFor<T>,
-            params: AlgorithmParams,
-        ) -> DispatchResult {
-            // Require governance origin
-            T::UpdateOrigin::ensure_origin(origin)
-                .map_err(|_| Error::<T>::RequiresGovernance)?;
+        /// Pages hold at most `MAX_CONTRIBUTION_INDEX_PAGE` ids and scan at most
+        /// `MAX_CONTRIBUTION_INDEX_SCAN` entries of the index. Also returns the id the next
+        /// page continues before, if the index goes on.
+        pub fn contributions_by_project(
+            project_id: ProjectId,
+            contribution_type: Option<ContributionType>,
+            before: Option<ContributionId>,
+            limit: u32,
+        ) -> (Vec<ContributionId>, Option<ContributionId>) {
+            let keys = match before {
+                Some(id) => ContributionsOfProject::<T>::iter_key_prefix_from(
+                    project_id,
+                    ContributionsOfProject::<T>::hashed_key_for(project_id, Self::index_key(id)),
+                ),
+                None => ContributionsOfProject::<T>::iter_key_prefix(project_id),
+            };
+            Self::page_index(keys, limit, |id| {
+                contribution_type.as_ref().map_or(true, |kind| {
+                    Contributions::<T>::get(id).map_or(false, |contribution| contribution.contribution_type == *kind)
+                })
+            })
+        }
 
-            // Validate parameters
-            Self::validate_algorithm_params(&params)?;
+        /// Contributions of a type older than `before`, newest first, paged like
+        /// `contributions_by_project`
+        pub fn contributions_by_type(
+            contribution_type: ContributionType,
+            before: Option<ContributionId>,
+            limit: u32,
+        ) -> (Vec<ContributionId>, Option<ContributionId>) {
+            let keys = match before {
+                Some(id) => ContributionsOfType::<T>::iter_key_prefix_from(
+                    &contribution_type,
+                    ContributionsOfType::<T>::hashed_key_for(&contribution_type, Self::index_key(id)),
+                ),
+                None => ContributionsOfType::<T>::iter_key_prefix(&contribution_type),
+            };
+            Self::page_index(keys, limit, |_| true)
+        }
 
-            // Get old params
-            let old_params = ReputationParams::<T>::get().unwrap_or_default();
+        /// Collect up to `limit` ids passing `filter` from a search index, with the id the
+        /// next page continues before if the scan stopped early
+        fn page_index(
+            keys: impl Iterator<Item = ContributionIndexKey>,
+            limit: u32,
+            filter: impl Fn(ContributionId) -> bool,
+        ) -> (Vec<ContributionId>, Option<ContributionId>) {
+            let limit = limit.clamp(1, MAX_CONTRIBUTION_INDEX_PAGE) as usize;
+            let mut page = Vec::new();
+            let mut last = None;
+            for (scanned, key) in keys.enumerate() {
+                if page.len() >= limit || scanned as u32 >= MAX_CONTRIBUTION_INDEX_SCAN {
+                    return (page, last);
+                }
+                let contribution_id = Self::indexed_id(key);
+                if filter(contribution_id) {
+                    page.push(contribution_id);
+                }
+                last = Some(contribution_id);
+            }
+            (page, None)
+        }
 
-            // Update parameters
-            ReputationParams::<T>::put(params.clone());
+        /// Search index key of a contribution
+        pub fn index_key(contribution_id: ContributionId) -> ContributionIndexKey {
+            (ContributionId::MAX - contribution_id).to_be_bytes()
+        }
 
-            Self::deposit_event(Event::AlgorithmParamsUpdated {
-                old_params,
-                new_params: params,
-            });
+        fn indexed_id(key: ContributionIndexKey) -> ContributionId {
+            ContributionId::MAX - ContributionId::from_be_bytes(key)
+        }
 
-            Ok(())
+        /// Record the project of a contribution, moving it between project indexes
+        fn assign_project(contribution_id: ContributionId, project_id: ProjectId) {
+            let key = Self::index_key(contribution_id);
+            if let Some(previous) = ContributionProjects::<T>::get(contribution_id) {
+                ContributionsOfProject::<T>::remove(previous, key);
+            }
+            ContributionProjects::<T>::insert(contribution_id, project_id);
+            ContributionsOfProject::<T>::insert(project_id, key, ());
         }
 
-        /// Initiate a cross-chain reputation query via XCM
-        ///
-        /// # Arguments
-        /// * `origin` - The account initiating the query
-        /// * `target_chain` - Target chain identifier
-        /// * `target_account` - Account to query on target chain
+        /// Verified contributions of `who` submitted in blocks `from..=to`, oldest first
         ///
-        /// # Errors
-        /// Returns `Error::XcmExecutionFailed` if XCM message fails
-        #[pallet::weight(Weight::from_parts(100_000_000, 0))]
-        pub fn initiate_reputation_query(
-            origin: OriginFor<T>,
-            target_chain: Vec<u8>,
-            target_account: Vec<u8>,
-        ) -> DispatchResult {
-            let _who = ensure_signed(origin)?;
+        /// `contribution_type` restricts the history to one type. Pages hold at most
+        /// `MAX_WORK_HISTORY_PAGE` entries, starting `offset` entries into the filtered history.
+        /// The history carries the breakdown's per-contribution points and projects, so it is
+        /// empty unless the breakdown is public or `viewer` may see it.
+        pub fn work_history(
+            who: &T::AccountId,
+            viewer: Option<&T::AccountId>,
+            contribution_type: Option<ContributionType>,
+            from: T::BlockNumber,
+            to: T::BlockNumber,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<WorkHistoryEntry<T::AccountId, T::BlockNumber>> {
+            if !Self::may_view_breakdown(who, viewer) {
+                return Vec::new();
+            }
 
-            // Validate target chain is supported
-            if !Self::is_chain_registered(&target_chain) {
-                return Err(Error::<T>::ChainNotSupported.into());
+            AccountContributions::<T>::get(who)
+                .into_iter()
+                .filter_map(Contributions::<T>::get)
+                .filter(|contribution| {
+                    contribution.verified
+                        && contribution_type.as_ref().map_or(true, |kind| *kind == contribution.contribution_type)
+                        && (from..=to).contains(&contribution.timestamp)
+                })
+                .skip(offset as usize)
+                .take(limit.min(MAX_WORK_HISTORY_PAGE) as usize)
+                .map(|contribution| WorkHistoryEntry {
+                    contribution_id: contribution.id,
+                    proof: contribution.proof,
+                    points: Self::verified_points(&contribution).unwrap_or_default(),
+                    project: ContributionProjects::<T>::get(contribution.id),
+                    skills: ContributionSkills::<T>::get(contribution.id).into_inner(),
+                    verifications: ContributionVerifications::<T>::iter_prefix(contribution.id)
+                        .map(|(verifier, (score, _))| (verifier, score))
+                        .collect(),
+                    contribution_type: contribution.contribution_type,
+                    weight: contribution.weight,
+                    source: contribution.source,
+                    submitted_at: contribution.timestamp,
+                })
+                .collect()
+        }
+
+        /// Detailed breakdown of `who`'s reputation, if it is public or `viewer` is `who` or
+        /// was allowed to see it
+        pub fn reputation_breakdown(who: &T::AccountId, viewer: Option<&T::AccountId>) -> Option<ReputationBreakdown> {
+            if !Self::may_view_breakdown(who, viewer) {
+                return None;
             }
 
-            // Generate unique query ID
-            let query_id = Self::generate_query_id();
+            let mut by_type: BTreeMap<ContributionType, Score> = BTreeMap::new();
+            let mut by_project: BTreeMap<ProjectId, Score> = BTreeMap::new();
+            for contribution_id in AccountContributions::<T>::get(who).iter() {
+                let Some(contribution) = Contributions::<T>::get(contribution_id) else { continue };
+                if !contribution.verified {
+                    continue;
+                }
+                let points = Self::verified_points(&contribution).unwrap_or_default();
+                let entry = by_type.entry(contribution.contribution_type).or_default();
+                *entry = entry.saturating_add(points);
+                if let Some(project_id) = ContributionProjects::<T>::get(contribution_id) {
+                    let entry = by_project.entry(project_id).or_default();
+                    *entry = entry.saturating_add(points);
+                }
+            }
 
-            // Store query with timeout (100 blocks)
-            let query = ReputationQuery {
-                query_id,
-                target_chain: target_chain.clone(),
-                target_account: target_account.clone(),
-                status: QueryStatus::Pending,
-                initiated_at: frame_system::Pallet::<T>::block_number(),
-                response: None,
-                timeout: frame_system::Pallet::<T>::block_number() + 100u32.into(),
-            };
+            Some(ReputationBreakdown {
+                by_type: by_type.into_iter().collect(),
+                by_project: by_project.into_iter().collect(),
+            })
+        }
 
-            ReputationQueries::<T>::insert(query_id, query);
+        /// Whether `who`'s breakdown is public, or `viewer` is `who` or was allowed to see it
+        fn may_view_breakdown(who: &T::AccountId, viewer: Option<&T::AccountId>) -> bool {
+            PublicBreakdowns::<T>::contains_key(who)
+                || viewer.map_or(false, |viewer| who == viewer || BreakdownViewers::<T>::contains_key(who, viewer))
+        }
 
-            Self::deposit_event(Event::CrossChainQueryInitiated {
-                query_id,
-                target_chain: target_chain.clone(),
-                target_account,
+        /// Fee-less calls `who` has left this era; zero below `FeelessReputationThreshold`
+        pub fn feeless_calls_left(who: &T::AccountId) -> u32 {
+            if ReputationScores::<T>::get(who) < T::FeelessReputationThreshold::get() {
+                return 0;
+            }
+            let (era, used) = FeelessCalls::<T>::get(who);
+            if era == Self::feeless_era() {
+                T::FeelessCallsPerEra::get().saturating_sub(used)
+            } else {
+                T::FeelessCallsPerEra::get()
+            }
+        }
+
+        /// Spend one of `who`'s fee-less calls, if any are left
+        fn use_feeless_quota(who: &T::AccountId) -> Pays {
+            if Self::feeless_calls_left(who) == 0 {
+                return Pays::Yes;
+            }
+            let era = Self::feeless_era();
+            FeelessCalls::<T>::mutate(who, |(last_era, used)| {
+                if *last_era != era {
+                    *last_era = era;
+                    *used = 0;
+                }
+                *used += 1;
             });
+            Pays::No
+        }
 
-            // In a full implementation, this would construct and send XCM message
-            // For now, this is a placeholder
+        fn feeless_era() -> u32 {
+            let now = frame_system::Pallet::<T>::block_number();
+            (now / T::FeelessEra::get().max(One::one())).unique_saturated_into()
+        }
 
-            Ok(())
+        /// Schedule a contribution's deadline at `at`, or at the first of the following
+        /// `DEADLINE_SLOT_SEARCH` blocks with room
+        ///
+        /// Returns the block the deadline was scheduled at, or `None` if none had room.
+        fn schedule_deadline(contribution_id: ContributionId, at: T::BlockNumber) -> Option<T::BlockNumber> {
+            let mut at = at;
+            for _ in 0..=DEADLINE_SLOT_SEARCH {
+                if VerificationDeadlines::<T>::try_append(at, contribution_id).is_ok() {
+                    return Some(at);
+                }
+                at = at.saturating_add(One::one());
+            }
+            None
         }
 
-        /// Submit off-chain worker verification result (unsigned transaction)
+        /// Pending contributions whose verification deadline falls in `from..=to`, with
+        /// their deadline, soonest first
         ///
-        /// This is called by off-chain workers to submit verification results
-        /// with cryptographic signatures for validation.
+        /// Read from `VerificationDeadlines`; stale contributions scheduled there for
+        /// escalation are left out.
+        pub fn contributions_nearing_deadline(
+            from: T::BlockNumber,
+            to: T::BlockNumber,
+        ) -> Vec<(ContributionId, T::BlockNumber)> {
+            let mut nearing = Vec::new();
+            let mut deadline = from;
+            while deadline <= to {
+                nearing.extend(
+                    VerificationDeadlines::<T>::get(deadline)
+                        .into_iter()
+                        .filter(|contribution_id| {
+                            !StaleContributions::<T>::contains_key(contribution_id)
+                                && Contributions::<T>::get(contribution_id)
+                                    .map_or(false, |contribution| contribution.status == ContributionStatus::Pending)
+                        })
+                        .map(|contribution_id| (contribution_id, deadline)),
+                );
+                if deadline == to {
+                    break;
+                }
+                deadline = deadline.saturating_add(One::one());
+            }
+            nearing
+        }
+
+        /// A page of the pending contributions within `ReminderLead` blocks of their
+        /// verification deadline, soonest first: the reviewers' task queue
         ///
-        /// # Arguments
-        /// * `account` - The account that made the contribution
-        /// * `contribution_id` - ID of the contribution
-        /// * `verified` - Whether the contribution was verified
-        /// * `timestamp` - Timestamp of verification
-        /// * `signature` - Cryptographic signature from OCW
+        /// Lists the deadlines from `from` on, whole blocks at a time, until `limit`
+        /// entries (at most `MAX_REMINDER_PAGE`) are listed or `MAX_REMINDER_SCAN_BLOCKS`
+        /// blocks are scanned. Also returns the block the next page starts from, if any.
+        pub fn reminder_queue(
+            from: T::BlockNumber,
+            limit: u32,
+        ) -> (Vec<(ContributionId, T::BlockNumber)>, Option<T::BlockNumber>) {
+            let now = frame_system::Pallet::<T>::block_number();
+            let horizon = now.saturating_add(T::ReminderLead::get());
+            let limit = limit.clamp(1, MAX_REMINDER_PAGE) as usize;
+
+            let mut entries = Vec::new();
+            let mut deadline = from.max(now.saturating_add(One::one()));
+            for _ in 0..MAX_REMINDER_SCAN_BLOCKS {
+                if deadline > horizon {
+                    return (entries, None);
+                }
+                if entries.len() >= limit {
+                    break;
+                }
+                entries.extend(Self::contributions_nearing_deadline(deadline, deadline));
+                deadline = deadline.saturating_add(One::one());
+            }
+            let next = (deadline <= horizon).then_some(deadline);
+            (entries, next)
+        }
+
+        /// Mark contributions still pending at their deadline as stale, and stale ones
+        /// still pending after `EscalationPeriod` as escalated
         ///
-        /// # Errors
-        /// Returns `Error::ContributionNotFound` if contribution doesn't exist
-        /// Returns `Error::OffchainFetchFailed` if signature verification fails
-        #[pallet::weight(Weight::from_parts(20_000_000, 0))]
-        #[pallet::call_index(4)]
-        pub fn submit_offchain_verification(
-            origin: OriginFor<T>,
-            account: T::AccountId,
-            contribution_id: ContributionId,
-            verified: bool,
-            timestamp: u64,
-            signature: Vec<u8>,
-        ) -> DispatchResult {
-            // This should be called as unsigned transaction
-            ensure_none(origin)?;
+        /// Deadlines beyond the `VerificationDeadlines` hook quota stay in their block, which
+        /// is recorded in `DeadlineBacklog` and resumed from in the next blocks. A stale
+        /// contribution whose escalation finds no room is escalated at once, so none is
+        /// left unscheduled. Returns the weight consumed.
+        fn process_deadlines(now: T::BlockNumber) -> Weight {
+            let mut budget = Self::hook_budget(HookTask::VerificationDeadlines, T::BlockWeights::get().max_block);
+            let per_block = T::DbWeight::get().reads_writes(1, 1);
+            let per_deadline = T::DbWeight::get().reads_writes(2, 3);
+
+            let mut block = DeadlineBacklog::<T>::get().unwrap_or(now);
+            while block <= now {
+                if !budget.try_consume(per_block) {
+                    DeadlineBacklog::<T>::put(block);
+                    return budget.consumed();
+                }
+                let mut due = VerificationDeadlines::<T>::take(block).into_inner();
+                let processable = due.iter().take_while(|_| budget.try_consume(per_deadline)).count();
+                let deferred = due.split_off(processable);
+
+                for contribution_id in due {
+                    Self::process_deadline(contribution_id, now);
+                }
+                if !deferred.is_empty() {
+                    VerificationDeadlines::<T>::insert(block, BoundedVec::truncate_from(deferred));
+                    DeadlineBacklog::<T>::put(block);
+                    return budget.consumed();
+                }
+                block = block.saturating_add(One::one());
+            }
+            DeadlineBacklog::<T>::kill();
+            budget.consumed()
+        }
+
+        /// Mark a contribution scheduled in `process_deadlines` as stale or escalated
+        fn process_deadline(contribution_id: ContributionId, now: T::BlockNumber) {
+            let pending = Contributions::<T>::get(contribution_id)
+                .map_or(false, |contribution| contribution.status == ContributionStatus::Pending);
+            if !pending {
+                return;
+            }
+
+            let escalate = StaleContributions::<T>::take(contribution_id).is_some()
+                || Self::schedule_deadline(contribution_id, now.saturating_add(T::EscalationPeriod::get())).is_none();
+            if escalate {
+                EscalatedContributions::<T>::insert(contribution_id, now);
+                Self::deposit_event(Event::ContributionEscalated { contribution_id });
+            } else {
+                StaleContributions::<T>::insert(contribution_id, now);
+                Self::deposit_event(Event::ContributionStale { contribution_id });
+            }
+        }
 
-            // Get contribution
-            let mut contribution = Contributions::<T>::get(contribution_id)
-                .ok_or(Error::<T>::ContributionNotFound)?;
+        /// Award `StaleVerifierReward` for verifying a stale or escalated contribution
+        fn reward_stale_verification(verifier: &T::AccountId, contribution_id: ContributionId) {
+            if StaleContributions::<T>::contains_key(contribution_id)
+                || EscalatedContributions::<T>::contains_key(contribution_id)
+            {
+                Self::award_reputation(verifier, T::StaleVerifierReward::get(), RepChangeReason::StaleVerificationBonus);
+            }
+        }
 
-            // Verify signature (in production, would verify against OCW public key)
-            // For now, basic validation
-            ensure!(!signature.is_empty(), Error::<T>::OffchainFetchFailed);
+        /// Drop a contribution from the stale and escalated indexes
+        fn clear_escalation(contribution_id: ContributionId) {
+            StaleContributions::<T>::remove(contribution_id);
+            EscalatedContributions::<T>::remove(contribution_id);
+        }
 
-            // Check timestamp is recent (within 1 minute)
-            let current_time = sp_io::offchain::timestamp().unix_millis();
-            let time_diff = current_time.saturating_sub(timestamp);
-            ensure!(
-                time_diff < 60_000, // 1 minute
-                Error::<T>::OffchainFetchFailed
-            );
+        /// Account the contributor dividend is paid from
+        pub fn dividend_account() -> T::AccountId {
+            T::DividendPalletId::get().into_account_truncating()
+        }
 
-            if verified {
-                // Mark as verified by OCW
-                contribution.verified = true;
-                contribution.status = ContributionStatus::Verified;
-                contribution.verification_count = contribution.verification_count.saturating_add(1);
+        /// Current dividend era
+        pub fn dividend_era() -> u32 {
+            let now = frame_system::Pallet::<T>::block_number();
+            (now / T::DividendEra::get().max(One::one())).unique_saturated_into()
+        }
 
-                // Update reputation if enough verifications
-                if contribution.verification_count >= T::MinVerifications::get() {
-                    let old_score = ReputationScores::<T>::get(&account);
-                    let params = ReputationParams::<T>::get().unwrap_or_default();
-                    
-                    let base_points = params.contribution_type_weights
-                        .get(&contribution.contribution_type)
-                        .copied()
-                        .unwrap_or(10) as i32;
-                    
-                    let multiplier = params.verification_multiplier as i32;
-                    let points = (base_points * multiplier) / 10_000;
-                    let weighted_points = (points * contribution.weight as i32) / 100;
-                    
-                    let new_score = old_score
-                        .saturating_add(weighted_points)
-                        .max(T::MinReputation::get())
-                        .min(T::MaxReputation::get());
-                    
-                    ReputationScores::<T>::insert(&account, new_score);
-                    PendingContributions::<T>::mutate(&account, |count| *count = count.saturating_sub(1));
+        /// Count reputation `who` gained towards its dividend share of the current era
+        fn record_era_earnings(who: &T::AccountId, gained: Score) {
+            if gained <= 0 {
+                return;
+            }
+            let era = Self::dividend_era();
+            let counted = EraEarnings::<T>::mutate(era, who, |earned| {
+                let before = *earned;
+                *earned = earned.saturating_add(gained).min(T::DividendEarningsCap::get()).max(before);
+                *earned - before
+            });
+            if counted > 0 {
+                EraTotalEarnings::<T>::mutate(era, |total| *total = total.saturating_add(counted as u64));
+            }
+        }
 
-                    Self::deposit_event(Event::ReputationUpdated {
-                        account: account.clone(),
-                        old_score,
-                        new_score,
-                        change_reason: RepChangeReason::VerificationReward,
-                    });
+        /// Reverse the dividend share of `lost` reputation `who` no longer holds
+        ///
+        /// The loss comes off the account's earnings of the current era first, then off the
+        /// dividends it was paid, which are slashed back to `dividend_account` in proportion;
+        /// the rest is recorded in `DividendDebt` against its unclaimed eras.
+        fn reverse_era_earnings(who: &T::AccountId, lost: Score) {
+            if lost <= 0 {
+                return;
+            }
+            let era = Self::dividend_era();
+            let earned = EraEarnings::<T>::get(era, who);
+            let unearned = earned.min(lost);
+            if unearned > 0 {
+                EraEarnings::<T>::insert(era, who, earned - unearned);
+                EraTotalEarnings::<T>::mutate(era, |total| *total = total.saturating_sub(unearned as u64));
+            }
+            let mut lost = lost - unearned;
+
+            let (paid_points, paid) = PaidDividends::<T>::get(who);
+            if lost > 0 && paid_points > 0 {
+                let points = lost.min(paid_points);
+                let amount = Perbill::from_rational(points as u64, paid_points as u64) * paid;
+                let (imbalance, _) = T::Currency::slash(who, amount);
+                let recovered = imbalance.peek();
+                T::Currency::resolve_creating(&Self::dividend_account(), imbalance);
+                if points == paid_points {
+                    PaidDividends::<T>::remove(who);
+                } else {
+                    PaidDividends::<T>::insert(who, (paid_points - points, paid.saturating_sub(amount)));
                 }
+                lost -= points;
+                if !recovered.is_zero() {
+                    Self::deposit_event(Event::DividendClawedBack { account: who.clone(), amount: recovered });
+                }
+            }
 
-                Contributions::<T>::insert(contribution_id, &contribution);
-
-                Self::deposit_event(Event::ContributionVerified {
-                    contributor: account,
-                    contribution_id,
-                    verifier: account.clone(), // OCW as verifier
-                    score: 100,
-                    reputation_gained: 0,
-                });
+            if lost > 0 {
+                DividendDebt::<T>::mutate(who, |debt| *debt = debt.saturating_add(lost));
             }
+        }
 
-            Ok(())
+        /// Put an account on probation for `ProbationPeriod`, extending any probation it is on
+        fn start_probation(who: &T::AccountId) {
+            let until = frame_system::Pallet::<T>::block_number().saturating_add(T::ProbationPeriod::get());
+            ProbationEnds::<T>::insert(who, until);
+            Self::deposit_event(Event::ProbationStarted { account: who.clone(), until });
         }
 
-        /// Batch add multiple contributions (efficient for bulk operations)
-        ///
-        /// # Arguments
-        /// * `proofs` - Vector of (proof, contribution_type, weight, source) tuples
-        ///
-        /// # Errors
-        /// Returns errors if any contribution fails validation
-        #[pallet::weight(Weight::from_parts(50_000_000, 0) * proofs.len() as u64)]
-        #[pallet::call_index(5)]
-        pub fn batch_add_contributions(
-            origin: OriginFor<T>,
-            proofs: Vec<(H256, ContributionType, u8, DataSource)>,
-        ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
+        /// Whether an account is on probation
+        pub fn is_on_probation(who: &T::AccountId) -> bool {
+            ProbationEnds::<T>::get(who).map_or(false, |until| frame_system::Pallet::<T>::block_number() < until)
+        }
 
-            // Limit batch size
-            ensure!(
-                proofs.len() <= 10,
-                Error::<T>::InvalidAlgorithmParams
-            );
+        /// Points an account earns, halved while it is on probation
+        fn probation_adjusted(who: &T::AccountId, points: Score) -> Score {
+            if Self::is_on_probation(who) { points / 2 } else { points }
+        }
 
-            for (proof, contribution_type, weight, source) in proofs {
-                // Reuse add_contribution logic but skip event emission until end
-                let _ = Self::add_contribution_internal(
-                    &who,
-                    proof,
-                    contribution_type,
-                    weight,
-                    source,
-                )?;
+        /// Pay the referral bonus of `referee`'s referrer once it has enough verified contributions
+        fn reward_referrer(referee: &T::AccountId) {
+            let Some(mut referral) = Referrals::<T>::get(referee) else { return };
+            if referral.rewarded
+                || VerifiedContributionCounts::<T>::get(referee) < T::ReferralQualifyingContributions::get()
+            {
+                return;
             }
 
-            Ok(())
+            let bonus = T::ReferralBonus::get();
+            Self::award_reputation(&referral.referrer, bonus, RepChangeReason::ReferralBonus);
+            referral.rewarded = true;
+            Referrals::<T>::insert(referee, &referral);
+
+            Self::deposit_event(Event::ReferralRewarded {
+                referrer: referral.referrer,
+                referee: referee.clone(),
+                bonus,
+            });
         }
 
-        /// Batch verify multiple contributions
-        ///
-        /// # Arguments
-        /// * `verifications` - Vector of (contributor, contribution_id, score, comment) tuples
-        #[pallet::weight(Weight::from_parts(25_000_000, 0) * verifications.len() as u64)]
-        #[pallet::call_index(6)]
-        pub fn batch_verify_contributions(
-            origin: OriginFor<T>,
-            verifications: Vec<(T::AccountId, ContributionId, u8, Vec<u8>)>,
-        ) -> DispatchResult {
-            let verifier = ensure_signed(origin)?;
+        /// Drop the referral of a Sybil, slashing the bonus its referrer already received
+        fn claw_back_referral(referee: &T::AccountId) {
+            let Some(referral) = Referrals::<T>::take(referee) else { return };
+            if !referral.rewarded {
+                return;
+            }
 
-            // Check verifier has sufficient reputation
-            let verifier_reputation = ReputationScores::<T>::get(&verifier);
-            ensure!(
-                verifier_reputation >= T::MinReputationToVerify::get(),
-                Error::<T>::InsufficientReputationToVerify
-            );
+            let points = T::ReferralBonus::get();
+            Self::slash_reputation(&referral.referrer, points, RepChangeReason::ReferralClawback);
 
-            // Limit batch size
-            ensure!(
-                verifications.len() <= 10,
-                Error::<T>::InvalidAlgorithmParams
-            );
+            Self::deposit_event(Event::ReferralClawedBack {
+                referrer: referral.referrer,
+                referee: referee.clone(),
+                points,
+            });
+        }
 
-            for (contributor, contribution_id, score, comment) in verifications {
-                // Reuse verify_contribution logic
-                let _ = Self::verify_contribution_internal(
-                    &verifier,
-                    &contributor,
-                    contribution_id,
-                    score,
-                    comment,
-                )?;
+        /// Add points a hackathon entry earned (or lost, if negative) to its open hackathon
+        ///
+        /// The leaderboard is re-sorted on every change. An account whose points drop
+        /// off the leaderboard is replaced by outsiders only as they earn more points.
+        fn tally_hackathon_points(contribution_id: ContributionId, account: &T::AccountId, points: Score) {
+            let Some(hackathon_id) = ContributionHackathons::<T>::get(contribution_id) else { return };
+            if Hackathons::<T>::get(hackathon_id).map_or(true, |hackathon| hackathon.closed) {
+                return;
             }
 
+            let total = HackathonScores::<T>::mutate(hackathon_id, account, |score| {
+                *score = if points >= 0 {
+                    score.saturating_add(points.unsigned_abs())
+                } else {
+                    score.saturating_sub(points.unsigned_abs())
+                };
+                *score
+            });
+
+            HackathonLeaderboards::<T>::mutate(hackathon_id, |leaderboard| {
+                leaderboard.retain(|(other, _)| other != account);
+                if total == 0 {
+                    return;
+                }
+                let position = leaderboard.iter().position(|(_, points)| *points < total).unwrap_or(leaderboard.len());
+                let places = T::MaxHackathonWinners::get() as usize;
+                if leaderboard.len() >= places {
+                    if position >= places {
+                        return;
+                    }
+                    leaderboard.truncate(places - 1);
+                }
+                let _ = leaderboard.try_insert(position, (account.clone(), total));
+            });
+        }
+
+        /// Hold `ContributionDeposit` from `who` for a new contribution
+        fn hold_deposit(who: &T::AccountId, contribution_id: ContributionId) -> DispatchResult {
+            let deposit = T::ContributionDeposit::get();
+            if deposit.is_zero() {
+                return Ok(());
+            }
+            T::Currency::reserve(who, deposit).map_err(|_| Error::<T>::InsufficientDeposit)?;
+            ContributionDeposits::<T>::insert(contribution_id, (who.clone(), deposit));
             Ok(())
         }
-    }
 
-    /// Query status for cross-chain reputation queries
-    #[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-    pub enum QueryStatus {
-        Pending,
-        Completed,
-        Timeout,
-        Failed,
-    }
+        /// Slash `RejectedDepositSlash` of a contribution's deposit, returning the amount slashed
+        ///
+        /// The rest stays reserved until the contribution is pruned.
+        fn slash_deposit(contribution_id: ContributionId) -> BalanceOf<T> {
+            ContributionDeposits::<T>::mutate(contribution_id, |deposit| {
+                let Some((depositor, held)) = deposit else { return Zero::zero() };
+                let (imbalance, _) = T::Currency::slash_reserved(depositor, T::RejectedDepositSlash::get() * *held);
+                let slashed = imbalance.peek();
+                T::Slash::on_unbalanced(imbalance);
+                *held = held.saturating_sub(slashed);
+                slashed
+            })
+        }
 
-    /// Reputation query structure for cross-chain queries
-    #[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-    #[scale_info(skip_type_params(T))]
-    pub struct ReputationQuery<T: Config> {
-        pub query_id: u64,
-        pub target_chain: Vec<u8>,
-        pub target_account: Vec<u8>,
-        pub status: QueryStatus,
-        pub initiated_at: T::BlockNumber,
-        pub response: Option<(i32, u8)>, // (score, percentile)
-        pub timeout: T::BlockNumber,
-    }
+        /// Remove a contribution and everything stored about it, returning the released deposit
+        ///
+        /// Callers settle `ContributionsByProof` and the pending count themselves.
+        fn remove_contribution(contributor: &T::AccountId, contribution: &Contribution<T>) -> BalanceOf<T> {
+            let contribution_id = contribution.id;
+            Contributions::<T>::remove(contribution_id);
+            ContributionProofs::<T>::remove(contribution.proof);
+            let _ = ContributionVerifications::<T>::clear_prefix(contribution_id, u32::MAX, None);
+            ContributionCoAuthors::<T>::remove(contribution_id);
+            ContributionSkills::<T>::remove(contribution_id);
+            if let Some(project_id) = ContributionProjects::<T>::take(contribution_id) {
+                ContributionsOfProject::<T>::remove(project_id, Self::index_key(contribution_id));
+            }
+            ContributionsOfType::<T>::remove(&contribution.contribution_type, Self::index_key(contribution_id));
+            MaintainerAttestations::<T>::remove(contribution_id);
+            CommitteeApprovals::<T>::remove(contribution_id);
+            let _ = ContributionRejections::<T>::clear_prefix(contribution_id, u32::MAX, None);
+            let _ = VerificationResponses::<T>::clear_prefix(contribution_id, u32::MAX, None);
+            let _ = AmendedVerifications::<T>::clear_prefix(contribution_id, u32::MAX, None);
+            RejectionCounts::<T>::remove(contribution_id);
+            ConsensusScores::<T>::remove(contribution_id);
+            VerifiedAt::<T>::remove(contribution_id);
+            GitImports::<T>::remove(contribution_id);
+            ContributionBundles::<T>::remove(contribution_id);
+            Self::clear_escalation(contribution_id);
+            AccountContributions::<T>::mutate(contributor, |ids| ids.retain(|id| *id != contribution_id));
+
+            match ContributionDeposits::<T>::take(contribution_id) {
+                Some((depositor, deposit)) => {
+                    T::Currency::unreserve(&depositor, deposit);
+                    deposit
+                },
+                None => Zero::zero(),
+            }
+        }
 
-    /// Storage for cross-chain reputation queries
-    #[pallet::storage]
-    pub type ReputationQueries<T: Config> = StorageMap<
-        _,
-        Blake2_128Concat,
-        u64,
-        ReputationQuery<T>,
-        OptionQuery,
-    >;
+        /// Split a contribution's points between its submitter and co-authors
+        ///
+        /// Co-authors receive their share rounded down and the submitter, listed
+        /// first, keeps the rest.
+        fn split_points(
+            contribution_id: ContributionId,
+            contributor: &T::AccountId,
+            points: Score,
+        ) -> Vec<(T::AccountId, Score)> {
+            let co_authors = ContributionCoAuthors::<T>::get(contribution_id);
+            let shares: Vec<(T::AccountId, Score)> = co_authors
+                .into_iter()
+                .map(|(account, share)| (account, points.saturating_mul(Score::from(share.deconstruct())) / 100))
+                .collect();
+            let rest = shares.iter().fold(points, |rest, (_, share)| rest.saturating_sub(*share));
 
-    /// Query ID counter
-    #[pallet::storage]
-    pub type NextQueryId<T: Config> = StorageValue<_, u64, ValueQuery>;
+            sp_std::iter::once((contributor.clone(), rest)).chain(shares).collect()
+        }
 
-    /// Registered chains for cross-chain queries
-    #[pallet::storage]
-    pub type RegisteredChains<T: Config> = StorageMap<
-        _,
-        Blake2_128Concat,
-        Vec<u8>,
-        bool,
-        ValueQuery,
-    >;
+        /// Check that co-authors are distinct, bound accounts other than the submitter
+        /// and leave the submitter a share
+        fn ensure_valid_co_authors(who: &T::AccountId, co_authors: &CoAuthorsOf<T>) -> DispatchResult {
+            let mut total: u32 = 0;
+            for (index, (account, share)) in co_authors.iter().enumerate() {
+                ensure!(
+                    account != who && !co_authors[..index].iter().any(|(other, _)| other == account),
+                    Error::<T>::DuplicateCoAuthor
+                );
+                ensure!(T::Bindings::is_bound(account), Error::<T>::CoAuthorNotBound);
+                ensure!(!share.is_zero(), Error::<T>::InvalidCoAuthorShares);
+                total = total.saturating_add(share.deconstruct().into());
+            }
+            ensure!(total < 100, Error::<T>::InvalidCoAuthorShares);
+            Ok(())
+        }
 
-    impl<T: Config> Pallet<T> {
-        /// Internal helper for adding contribution (without event emission)
-        fn add_contribution_internal(
-            who: &T::AccountId,
-            proof: H256,
-            contribution_type: ContributionType,
-            weight: u8,
-            source: DataSource,
-        ) -> DispatchResult {
-            ensure!(proof != H256::zero(), Error::<T>::InvalidProof);
-            ensure!(
-                weight >= 1 && weight <= 100,
-                Error::<T>::InvalidContributionWeight
-            );
-            ensure!(
-                Self::can_add_contribution(who),
-                Error::<T>::RateLimited
-            );
-            ensure!(
-                !ContributionsByProof::<T>::contains_key(proof),
-                Error::<T>::ContributionAlreadySubmitted
-            );
+        /// Whether the verifier declared one of the contribution's skills
+        pub fn is_skill_matched(verifier: &T::AccountId, contribution_id: ContributionId) -> bool {
+            ContributionSkills::<T>::get(contribution_id)
+                .iter()
+                .any(|skill| SkillVerifiers::<T>::contains_key(skill, verifier))
+        }
 
-            let account_contributions = AccountContributions::<T>::get(who);
-            ensure!(
-                (account_contributions.len() as u32) < T::MaxContributionsPerAccount::get(),
-                Error::<T>::MaxContributionsExceeded
-            );
+        /// Number of verifications the verifier's verification counts as
+        fn verification_weight(verifier: &T::AccountId, contribution_id: ContributionId) -> u32 {
+            if Self::is_skill_matched(verifier, contribution_id) {
+                T::SkillMatchMultiplier::get()
+            } else {
+                1
+            }
+        }
 
-            let contribution_id = Self::get_next_contribution_id();
-            let contribution = Contribution {
-                id: contribution_id,
-                proof,
-                contribution_type: contribution_type.clone(),
-                weight,
-                verified: false,
-                source: source.clone(),
-                timestamp: frame_system::Pallet::<T>::block_number(),
-                status: ContributionStatus::Pending,
-                verification_count: 0,
+        /// Up to `limit` skill-matched accounts that may still verify a pending contribution
+        ///
+        /// Only pooled verifiers with reviews left this era for one of the contribution's
+        /// skills are considered. Excludes the contributor, accounts below
+        /// `MinReputationToVerify`, on probation or without verification rights, and
+        /// accounts that already verified it. Empty for verified or untagged contributions.
+        pub fn eligible_verifiers(contribution_id: ContributionId, limit: u32) -> Vec<T::AccountId> {
+            let Some(contribution) = Contributions::<T>::get(contribution_id) else {
+                return Vec::new();
             };
+            if contribution.verified {
+                return Vec::new();
+            }
+            let contributor = ContributionProofs::<T>::get(contribution.proof);
+            let era = Self::verifier_era();
+
+            let mut eligible: Vec<T::AccountId> = Vec::new();
+            for skill in ContributionSkills::<T>::get(contribution_id) {
+                for account in SkillVerifiers::<T>::iter_key_prefix(&skill) {
+                    if eligible.len() >= limit as usize {
+                        return eligible;
+                    }
+                    let available = VerifierPool::<T>::get(&account)
+                        .map_or(false, |availability| availability.covers(&skill) && availability.remaining(era) > 0);
+                    if available
+                        && Some(&account) != contributor.as_ref()
+                        && !eligible.contains(&account)
+                        && ReputationScores::<T>::get(&account) >= T::MinReputationToVerify::get()
+                        && !Self::is_on_probation(&account)
+                        && Self::has_verification_rights(&account)
+                        && !ContributionVerifications::<T>::contains_key(contribution_id, &account)
+                    {
+                        eligible.push(account);
+                    }
+                }
+            }
+            eligible
+        }
 
-            Contributions::<T>::insert(contribution_id, &contribution);
-            ContributionsByProof::<T>::insert(proof, contribution_id);
-            ContributionProofs::<T>::insert(proof, who);
+        /// Version of the score algorithm and whether it is frozen
+        pub fn algorithm_version_info() -> AlgorithmVersionInfo {
+            AlgorithmVersionInfo {
+                version: AlgorithmVersion::<T>::get(),
+                compatibility_mode: CompatibilityMode::<T>::get(),
+            }
+        }
 
-            let mut contributions = account_contributions;
-            contributions.try_push(contribution_id)
-                .map_err(|_| Error::<T>::MaxContributionsExceeded)?;
-            AccountContributions::<T>::insert(who, contributions);
+        /// Current era of the verifier pool
+        pub fn verifier_era() -> u32 {
+            let now = frame_system::Pallet::<T>::block_number();
+            (now / T::VerifierEra::get().max(One::one())).unique_saturated_into()
+        }
+
+        /// Blocks a pending contribution can expect to wait for its missing verifications
+        ///
+        /// Estimated from the capacity per era of the verifiers it is routed to, each of
+        /// whose reviews counts as `SkillMatchMultiplier` verifications. `None` if the
+        /// contribution is not pending or no pooled verifier can review it.
+        pub fn expected_review_latency(contribution_id: ContributionId) -> Option<T::BlockNumber> {
+            let contribution = Contributions::<T>::get(contribution_id)?;
+            if contribution.verified {
+                return None;
+            }
+            let missing = T::MinVerifications::get().saturating_sub(contribution.verification_count);
+            let multiplier = T::SkillMatchMultiplier::get().max(1);
+            let reviews = missing.saturating_add(multiplier - 1) / multiplier;
+
+            let capacity = Self::eligible_verifiers(contribution_id, MAX_LATENCY_VERIFIERS)
+                .iter()
+                .filter_map(|verifier| VerifierPool::<T>::get(verifier))
+                .fold(0u32, |capacity, availability| capacity.saturating_add(availability.capacity_per_era));
+            if capacity == 0 {
+                return None;
+            }
+
+            let blocks = T::VerifierEra::get().saturating_mul(reviews.into()) / capacity.into();
+            Some(blocks)
+        }
+
+        /// Whether the contribution weighs more than `MaintainerAttestationThreshold`
+        /// and no maintainer has attested it yet
+        pub fn awaits_maintainer_attestation(contribution: &Contribution<T>) -> bool {
+            contribution.weight > T::MaintainerAttestationThreshold::get()
+                && !MaintainerAttestations::<T>::contains_key(contribution.id)
+        }
+
+        /// Whether the contribution was imported from a git log not yet validated
+        pub fn awaits_git_validation(contribution_id: ContributionId) -> bool {
+            GitImports::<T>::get(contribution_id).map_or(false, |import| !import.validated)
+        }
 
-            PendingContributions::<T>::mutate(who, |count| *count = count.saturating_add(1));
-            ContributionCounts::<T>::mutate(who, |count| *count = count.saturating_add(1));
+        /// Whether the contribution weighs more than `CommitteeVerificationThreshold`
+        pub fn requires_committee(contribution: &Contribution<T>) -> bool {
+            contribution.weight > T::CommitteeVerificationThreshold::get()
+        }
 
-            Ok(())
+        /// Whether `who` maintains the project
+        pub fn is_project_maintainer(project_id: ProjectId, who: &T::AccountId) -> bool {
+            ProjectMaintainers::<T>::contains_key(project_id, who)
         }
 
-        /// Internal helper for verifying contribution
-        fn verify_contribution_internal(
-            verifier: &T::AccountId,
-            contributor: &T::AccountId,
-            contribution_id: ContributionId,
-            score: u8,
-            comment: Vec<u8>,
-        ) -> DispatchResult {
-            ensure!(
-                verifier != contributor,
-                Error::<T>::SelfVerificationNotAllowed
-            );
-            ensure!(
-                score <= 100,
-                Error::<T>::InvalidVerificationScore
-            );
+        /// Project owned by `who`
+        fn owned_project(project_id: ProjectId, who: &T::AccountId) -> Result<Project<T::AccountId, T::BlockNumber>, DispatchError> {
+            let project = Projects::<T>::get(project_id).ok_or(Error::<T>::ProjectNotFound)?;
+            ensure!(project.owner == *who, Error::<T>::NotProjectOwner);
+            Ok(project)
+        }
 
+        /// Mark a verified contribution rejected and remove the reputation it awarded
+        ///
+        /// Returns the contributor, whom the caller holds accountable.
+        fn do_overturn(contribution_id: ContributionId) -> Result<T::AccountId, DispatchError> {
             let mut contribution = Contributions::<T>::get(contribution_id)
                 .ok_or(Error::<T>::ContributionNotFound)?;
+            ensure!(contribution.verified, Error::<T>::ContributionNotVerified);
 
-            ensure!(
-                !contribution.verified,
-                Error::<T>::ContributionAlreadyVerified
-            );
-            ensure!(
-                ContributionProofs::<T>::get(contribution.proof) == Some(contributor.clone()),
-                Error::<T>::ContributionNotFound
-            );
-            ensure!(
-                !ContributionVerifications::<T>::contains_key(contribution_id, verifier),
-                Error::<T>::ContributionAlreadyVerified
-            );
-
-            ContributionVerifications::<T>::insert(contribution_id, verifier, (score, comment.clone()));
-            contribution.verification_count = contribution.verification_count.saturating_add(1);
+            let contributor = ContributionProofs::<T>::get(contribution.proof)
+                .ok_or(Error::<T>::ContributionNotFound)?;
 
-            if contribution.verification_count >= T::MinVerifications::get() {
-                contribution.verified = true;
-                contribution.status = ContributionStatus::Verified;
+            contribution.verified = false;
+            contribution.status = ContributionStatus::Rejected;
+            VerifiedContributionCounts::<T>::mutate(&contributor, |count| *count = count.saturating_sub(1));
+            AccountActivity::<T>::mutate(&contributor, |stats| stats.verified = stats.verified.saturating_sub(1));
+            Self::note_rejection(&contributor);
+            Contributions::<T>::insert(contribution_id, &contribution);
 
-                let old_score = ReputationScores::<T>::get(contributor);
-                let params = ReputationParams::<T>::get().unwrap_or_default();
-                
-                let base_points = params.contribution_type_weights
-                    .get(&contribution.contribution_type)
-                    .copied()
-                    .unwrap_or(10) as i32;
-                
-                let multiplier = params.verification_multiplier as i32;
-                let points = (base_points * multiplier) / 10_000;
-                let weighted_points = (points * contribution.weight as i32) / 100;
-                
-                let new_score = old_score
-                    .saturating_add(weighted_points)
-                    .max(T::MinReputation::get())
-                    .min(T::MaxReputation::get());
-                
-                ReputationScores::<T>::insert(contributor, new_score);
-                PendingContributions::<T>::mutate(contributor, |count| *count = count.saturating_sub(1));
+            // Remove the reputation awarded at verification time, from co-authors too
+            let points = Self::verified_points(&contribution)?;
+            for (account, share) in Self::split_points(contribution_id, &contributor, points) {
+                let old_score = ReputationScores::<T>::get(&account);
+                let new_score = Self::adjust_score(old_score, -share)?;
+                ReputationScores::<T>::insert(&account, new_score);
+                Self::reverse_era_earnings(&account, old_score.saturating_sub(new_score));
+                Self::tally_hackathon_points(contribution_id, &account, -share);
 
                 Self::deposit_event(Event::ReputationUpdated {
-                    account: contributor.clone(),
+                    account,
                     old_score,
                     new_score,
-                    change_reason: RepChangeReason::VerificationReward,
+                    change_reason: RepChangeReason::VerificationOverturned,
                 });
             }
 
-            Contributions::<T>::insert(contribution_id, &contribution);
+            Ok(contributor)
+        }
+
+        /// Reputation-weighted median of a contribution's verification scores
+        ///
+        /// Each verifier's score weighs as much as the verifier's current reputation (at
+        /// least one), so a single low-reputation verifier cannot decide the outcome.
+        pub fn verification_consensus(contribution_id: ContributionId) -> Option<u8> {
+            let mut votes: Vec<(u8, u64)> = ContributionVerifications::<T>::iter_prefix(contribution_id)
+                .map(|(verifier, (score, _))| (score, ReputationScores::<T>::get(&verifier).max(1) as u64))
+                .collect();
+            votes.sort_unstable_by_key(|(score, _)| *score);
+
+            let total: u64 = votes.iter().map(|(_, weight)| weight).sum();
+            let mut cumulative = 0u64;
+            votes.into_iter().find_map(|(score, weight)| {
+                cumulative += weight;
+                (cumulative * 2 >= total).then_some(score)
+            })
+        }
+
+        /// Update the records of a finalized contribution's verifiers with their distance
+        /// from its consensus score, rewarding accurate and penalising outlying ones
+        ///
+        /// Amended verifications are scored on their original vote, so amending towards a
+        /// consensus already visible on-chain earns nothing. A lone verification is its own
+        /// consensus and is not scored.
+        fn score_verifiers(contribution_id: ContributionId, consensus: u8) {
+            let scores: Vec<(T::AccountId, u8)> = ContributionVerifications::<T>::iter_prefix(contribution_id)
+                .map(|(verifier, (score, _))| {
+                    let original = AmendedVerifications::<T>::get(contribution_id, &verifier).unwrap_or(score);
+                    (verifier, original)
+                })
+                .collect();
+            if scores.len() < 2 {
+                return;
+            }
+
+            for (verifier, score) in scores {
+                let deviation = score.abs_diff(consensus);
+                let revoked = VerifierRecords::<T>::mutate(&verifier, |record| {
+                    let had_rights = record.strikes < T::MaxOutlierStrikes::get();
+                    record.finalized = record.finalized.saturating_add(1);
+                    record.total_deviation = record.total_deviation.saturating_add(deviation.into());
+                    if deviation > T::OutlierDeviation::get() {
+                        record.strikes = record.strikes.saturating_add(1);
+                    } else if deviation <= T::AccurateDeviation::get() {
+                        record.strikes = record.strikes.saturating_sub(1);
+                    }
+                    had_rights && record.strikes >= T::MaxOutlierStrikes::get()
+                });
+
+                if deviation > T::OutlierDeviation::get() {
+                    Self::slash_reputation(&verifier, T::OutlierPenalty::get(), RepChangeReason::VerificationOutlier);
+                } else if deviation <= T::AccurateDeviation::get() {
+                    Self::award_reputation(&verifier, T::AccuracyBonus::get(), RepChangeReason::VerificationAccuracy);
+                }
+                if revoked {
+                    Self::deposit_event(Event::VerificationRightsRevoked { verifier });
+                }
+            }
+        }
+
+        /// Whether the verified contribution is within `DisputeWindow` blocks of its
+        /// verification, and so cannot be purged yet
+        ///
+        /// Contributions verified before `VerifiedAt` was recorded count from their submission.
+        pub fn is_disputable(contribution: &Contribution<T>) -> bool {
+            let verified_at = VerifiedAt::<T>::get(contribution.id).unwrap_or(contribution.timestamp);
+            frame_system::Pallet::<T>::block_number() < verified_at.saturating_add(T::DisputeWindow::get())
+        }
 
+        /// Whether `who` has fewer than `MaxOutlierStrikes` outstanding outlier strikes
+        pub fn has_verification_rights(who: &T::AccountId) -> bool {
+            VerifierRecords::<T>::get(who).strikes < T::MaxOutlierStrikes::get()
+        }
+
+        /// Fail unless `who` has `MinReputationToVerify` reputation, is out of probation and
+        /// keeps its verification rights
+        pub(crate) fn ensure_qualified_verifier(who: &T::AccountId) -> DispatchResult {
+            ensure!(
+                ReputationScores::<T>::get(who) >= T::MinReputationToVerify::get(),
+                Error::<T>::InsufficientReputationToVerify
+            );
+            ensure!(!Self::is_on_probation(who), Error::<T>::OnProbation);
+            ensure!(Self::has_verification_rights(who), Error::<T>::VerificationRightsRevoked);
+            Ok(())
+        }
+
+        /// Verifications a maintainer attestation counts as: `MaintainerAttestationWeight`,
+        /// capped one short of `MinVerifications`
+        pub fn maintainer_attestation_weight() -> u32 {
+            T::MaintainerAttestationWeight::get().min(T::MinVerifications::get().saturating_sub(1))
+        }
+
+        /// Percent of its points a verified contribution is awarded, from its consensus score
+        /// and, for git log and bulk imports, the `import_points` share
+        fn awarded_percent(contribution_id: ContributionId) -> Score {
+            let consensus = ConsensusScores::<T>::get(contribution_id).map_or(100, Score::from);
+            if GitImports::<T>::contains_key(contribution_id) {
+                Self::import_points().git_import.mul_floor(consensus as u32) as Score
+            } else if ImportedContributions::<T>::contains_key(contribution_id) {
+                Self::import_points().imported.mul_floor(consensus as u32) as Score
+            } else {
+                consensus
+            }
+        }
+
+        /// Shares of the usual points awarded for imported contributions
+        pub fn import_points() -> ImportPoints {
+            ImportPointsPercents::<T>::get().unwrap_or_else(|| ImportPoints {
+                git_import: T::GitImportPointsPercent::get(),
+                imported: T::ImportedPointsPercent::get(),
+            })
+        }
+
+        /// Bump `AlgorithmVersion` for a structural change of the score algorithm
+        ///
+        /// # Errors
+        /// Returns `Error::StructuralChangeInCompatibilityMode` while `CompatibilityMode` is on
+        fn bump_algorithm_version() -> DispatchResult {
+            ensure!(!CompatibilityMode::<T>::get(), Error::<T>::StructuralChangeInCompatibilityMode);
+            let version = AlgorithmVersion::<T>::mutate(|version| {
+                *version = version.saturating_add(1);
+                *version
+            });
+            Self::deposit_event(Event::AlgorithmVersionBumped { version });
             Ok(())
         }
 
-        /// Calculate reputation based on contribution type with time decay
-        fn calculate_reputation_with_decay(
-            account: &T::AccountId,
-            contribution_type: &ContributionType,
-            current_score: i32,
-            contribution_block: T::BlockNumber,
-        ) -> i32 {
+        /// Points awarded for a verified contribution under the current parameters, scaled
+        /// by its `awarded_percent`
+        fn verified_points(contribution: &Contribution<T>) -> Result<Score, DispatchError> {
             let params = ReputationParams::<T>::get().unwrap_or_default();
-            
-            // Get base points for contribution type
-            let base_points = params.contribution_type_weights
+
+            let base_points = Self::type_weight(&params, &contribution.contribution_type)?;
+            let multiplier = Score::try_from(params.verification_multiplier)
+                .map_err(|_| Error::<T>::ReputationScoreOverflow)?;
+
+            base_points
+                .checked_mul(multiplier)
+                .map(|points| points / 10_000)
+                .and_then(|points| points.checked_mul(contribution.weight as Score))
+                .map(|points| points / 100)
+                .and_then(|points| points.checked_mul(Self::awarded_percent(contribution.id)))
+                .map(|points| points / 100)
+                .ok_or_else(|| Error::<T>::ReputationScoreOverflow.into())
+        }
+
+        /// Points a verified contribution is still worth after time decay, scaled by its
+        /// `awarded_percent`
+        ///
+        /// The contribution is aged as of block `now`. Blocks the contributor spent on one
+        /// of `leaves` do not age it.
+        fn decayed_points(
+            params: &AlgorithmParams,
+            contribution: &Contribution<T>,
+            leaves: &[(T::BlockNumber, T::BlockNumber)],
+            now: T::BlockNumber,
+        ) -> Result<Score, DispatchError> {
+            let base_points = Self::type_weight(params, &contribution.contribution_type)?;
+
+            // Decay: 1 - (age_blocks * decay_rate / 1_000_000), in thousandths
+            let on_leave = leaves.iter().fold(T::BlockNumber::zero(), |total, (start, end)| {
+                let overlap = (*end).min(now).saturating_sub((*start).max(contribution.timestamp));
+                total.saturating_add(overlap)
+            });
+            let age_blocks: u64 = now
+                .saturating_sub(contribution.timestamp)
+                .saturating_sub(on_leave)
+                .unique_saturated_into();
+            let decay_amount = age_blocks.saturating_mul(params.decay_rate_per_block as u64) / 1_000_000;
+            let decay_factor = 1000u64.saturating_sub(decay_amount) as Score;
+
+            base_points
+                .checked_mul(decay_factor)
+                .map(|points| points / 1000)
+                .and_then(|points| points.checked_mul(contribution.weight as Score))
+                .map(|points| points / 100)
+                .and_then(|points| points.checked_mul(Self::awarded_percent(contribution.id)))
+                .map(|points| points / 100)
+                .ok_or_else(|| Error::<T>::ReputationScoreOverflow.into())
+        }
+
+        /// Configured weight of a contribution type
+        fn type_weight(params: &AlgorithmParams, contribution_type: &ContributionType) -> Result<Score, DispatchError> {
+            let weight = params.contribution_type_weights
                 .get(contribution_type)
                 .copied()
-                .unwrap_or(10) as i32;
-
-            // Apply time decay
-            let current_block = frame_system::Pallet::<T>::block_number();
-            let age_blocks = current_block.saturating_sub(contribution_block);
-            let decay_factor = if age_blocks > 0 {
-                // Decay: 1 - (age_blocks * decay_rate / 1_000_000)
-                let decay_amount = (age_blocks as u64 * params.decay_rate_per_block as u64) / 1_000_000;
-                (1000u32.saturating_sub(decay_amount as u32)) as i32
+                .ok_or(Error::<T>::MissingTypeWeight)?;
+            Score::try_from(weight).map_err(|_| Error::<T>::ReputationScoreOverflow.into())
+        }
+
+        /// `score` moved by `delta`, clamped to the configured bounds
+        fn adjust_score(score: Score, delta: Score) -> Result<Score, DispatchError> {
+            let adjusted = score.checked_add(delta).ok_or(if delta < 0 {
+                Error::<T>::ReputationScoreUnderflow
             } else {
-                1000
-            };
+                Error::<T>::ReputationScoreOverflow
+            })?;
+            Ok(adjusted.max(T::MinReputation::get()).min(T::MaxReputation::get()))
+        }
 
-            // Apply decay to base points
-            let decayed_points = (base_points * decay_factor) / 1000;
+        /// Score mapped linearly from `MinReputation..=MaxReputation` onto `0..=MAX_CREDIT_SCORE`
+        pub fn normalized_score(score: Score) -> u16 {
+            let (min, max) = (T::MinReputation::get() as i64, T::MaxReputation::get() as i64);
+            if max <= min {
+                return 0;
+            }
+            let offset = (score as i64 - min).clamp(0, max - min);
+            (offset * MAX_CREDIT_SCORE as i64 / (max - min)) as u16
+        }
+
+        /// Distribution bucket of a score; accounts without a positive score are not ranked
+        fn score_bucket(score: Score) -> Option<usize> {
+            (score > 0).then(|| (Self::normalized_score(score) / SCORE_BUCKET_WIDTH) as usize)
+        }
+
+        /// Move an account between distribution buckets after a score change
+        fn record_score_change(old_score: Score, new_score: Score) {
+            let (old, new) = (Self::score_bucket(old_score), Self::score_bucket(new_score));
+            if old == new {
+                return;
+            }
+            ScoreDistribution::<T>::mutate(|buckets| {
+                let mut counts = buckets.to_vec();
+                counts.resize(SCORE_BUCKETS as usize, 0);
+                if let Some(count) = old.and_then(|bucket| counts.get_mut(bucket)) {
+                    *count = count.saturating_sub(1);
+                }
+                if let Some(count) = new.and_then(|bucket| counts.get_mut(bucket)) {
+                    *count = count.saturating_add(1);
+                }
+                *buckets = BoundedVec::truncate_from(counts);
+            });
+        }
 
-            // Use saturating math to prevent overflow
-            current_score.saturating_add(decayed_points)
+        /// Share of ranked accounts in lower distribution buckets than `score`
+        fn percentile(score: Score) -> Percent {
+            let Some(bucket) = Self::score_bucket(score) else { return Percent::zero() };
+            let buckets = ScoreDistribution::<T>::get();
+            let total: u64 = buckets.iter().map(|count| *count as u64).sum();
+            let below: u64 = buckets.iter().take(bucket).map(|count| *count as u64).sum();
+            Percent::from_rational(below, total.max(1))
         }
 
         /// Get reputation score for an account (public getter)
-        pub fn get_reputation(account: &T::AccountId) -> i32 {
+        pub fn get_reputation(account: &T::AccountId) -> Score {
             ReputationScores::<T>::get(account)
         }
 
+        /// Add `points` to an account's score, capped at `MaxReputation`
+        pub fn award_reputation(account: &T::AccountId, points: Score, reason: RepChangeReason) {
+            Self::apply_reputation_change(account, points.saturating_abs(), reason);
+        }
+
+        /// Remove `points` from an account's score, floored at `MinReputation`
+        pub fn slash_reputation(account: &T::AccountId, points: Score, reason: RepChangeReason) {
+            Self::apply_reputation_change(account, points.saturating_abs().saturating_neg(), reason);
+        }
+
+        fn apply_reputation_change(account: &T::AccountId, delta: Score, reason: RepChangeReason) {
+            let old_score = ReputationScores::<T>::get(account);
+            let new_score = old_score
+                .saturating_add(delta)
+                .max(T::MinReputation::get())
+                .min(T::MaxReputation::get());
+            if new_score == old_score {
+                return;
+            }
+            ReputationScores::<T>::insert(account, new_score);
+            if new_score > old_score {
+                Self::record_era_earnings(account, new_score - old_score);
+            } else {
+                Self::reverse_era_earnings(account, old_score - new_score);
+            }
+
+            Self::deposit_event(Event::ReputationUpdated {
+                account: account.clone(),
+                old_score,
+                new_score,
+                change_reason: reason,
+            });
+        }
+
+        /// Get effective reputation: raw score plus the (capped) credibility boost and the
+        /// attestation boost
+        ///
+        /// Only positive scores are boosted; the result stays within `MaxReputation`.
+        pub fn effective_reputation(account: &T::AccountId) -> Score {
+            let score = Self::get_reputation(account);
+            if score <= 0 {
+                return score;
+            }
+
+            let boost_percent = T::CredibilityBoost::credibility_boost_percent(account)
+                .min(T::MaxCredibilityBoost::get()) as i64
+                + Self::attestation_boost_percent(account) as i64;
+            let boosted = score as i64 + (score as i64 * boost_percent) / 100;
+
+            boosted.min(T::MaxReputation::get() as i64) as Score
+        }
+
+        /// Effective reputation plus `RemoteReputationWeight` of the positive cached
+        /// reputation of the account's linked remote accounts, within `MaxReputation`
+        ///
+        /// This is the score exposed to governance and the runtime API. Answers to other
+        /// chains' queries leave it out, so linked chains do not count each other twice.
+        pub fn aggregate_reputation(account: &T::AccountId) -> Score {
+            let score = Self::effective_reputation(account);
+            let weight = RemoteReputationWeight::<T>::get();
+            if weight.is_zero() {
+                return score;
+            }
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let remote: u32 = LinkedIdentities::<T>::get(account)
+                .iter()
+                .filter_map(|(chain, remote_account)| RemoteReputation::<T>::get(chain, remote_account))
+                .filter(|entry| !Self::is_remote_score_expired(entry, now))
+                .map(|entry| entry.score.max(0) as u32)
+                .fold(0, |total, score| total.saturating_add(score));
+
+            (score as i64 + weight.mul_floor(remote) as i64).min(T::MaxReputation::get() as i64) as Score
+        }
+
+        /// Boost in percent from the account's unexpired attestations by registered
+        /// attestors: their summed weight scaled by `AttestationMultiplier`, capped at
+        /// `MaxAttestationBoost`
+        pub fn attestation_boost_percent(account: &T::AccountId) -> u32 {
+            let multiplier = AttestationMultiplier::<T>::get();
+            if multiplier.is_zero() {
+                return 0;
+            }
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let weight: u32 = Attestations::<T>::iter_prefix_values(account)
+                .filter(|attestation| attestation.expires_at > now && Attestors::<T>::contains_key(&attestation.attestor))
+                .map(|attestation| attestation.weight as u32)
+                .sum();
+            multiplier.mul_floor(weight).min(T::MaxAttestationBoost::get())
+        }
+
         /// Get reputation percentile (for cross-chain queries)
         pub fn get_percentile(account: &T::AccountId) -> u8 {
             let score = Self::get_reputation(account);
@@ -1234,6 +7464,12 @@ pub mod pallet {
                 Error::<T>::InvalidAlgorithmParams
             );
 
+            // Every contribution type needs a weight
+            ensure!(
+                params.contribution_type_weights.len() == ContributionType::ALL.len(),
+                Error::<T>::MissingTypeWeight
+            );
+
             // Validate contribution type weights are reasonable (1-100)
             for (_, weight) in &params.contribution_type_weights {
                 ensure!(
@@ -1246,47 +7482,159 @@ pub mod pallet {
         }
 
         /// Check if chain is registered for cross-chain queries
-        fn is_chain_registered(chain_id: &[u8]) -> bool {
-            RegisteredChains::<T>::get(chain_id) == Some(true)
+        fn is_chain_registered(chain_id: &ChainId) -> bool {
+            RegisteredChains::<T>::get(chain_id)
         }
 
-        /// Update reputation with time decay
-        pub fn update_reputation_with_time_decay(account: &T::AccountId) -> DispatchResult {
+        /// Project the time decay of the next `DecaySweepAccountsPerBlock` accounts with
+        /// contributions, resuming where the previous block stopped, and warn those whose
+        /// score would drop by `DecayWarningThreshold` within `DecayWarningWindow`
+        ///
+        /// Fewer accounts are swept if they do not fit in the `DecaySweep` hook quota.
+        /// Returns the number of accounts swept.
+        fn sweep_decay(now: T::BlockNumber) -> u32 {
+            let budget = Self::hook_budget(HookTask::DecaySweep, T::BlockWeights::get().max_block);
+            let per_account = Self::decay_sweep_weight(1).saturating_sub(Self::decay_sweep_weight(0));
+            let affordable = budget.remaining().saturating_sub(Self::decay_sweep_weight(0));
+            let limit = T::DecaySweepAccountsPerBlock::get().min(Self::fitting(affordable, per_account));
+            if limit == 0 {
+                return 0;
+            }
+
+            let mut accounts = match DecaySweepCursor::<T>::get() {
+                Some(last_key) => AccountContributions::<T>::iter_keys_from(last_key.into_inner()),
+                None => AccountContributions::<T>::iter_keys(),
+            }
+            .bounded(limit);
+
+            let mut swept = 0;
+            let mut last_key = None;
+            for account in accounts.by_ref() {
+                swept += 1;
+                last_key = Some(AccountContributions::<T>::hashed_key_for(&account));
+                Self::check_decay_warning(&account, now);
+            }
+
+            match last_key {
+                Some(last_key) if accounts.exceeded() => DecaySweepCursor::<T>::put(BoundedVec::truncate_from(last_key)),
+                _ => DecaySweepCursor::<T>::kill(),
+            }
+            swept
+        }
+
+        /// Weight of a decay sweep over `accounts` accounts
+        fn decay_sweep_weight(accounts: u32) -> Weight {
+            let accounts = accounts as u64;
+            let max_contributions = T::MaxContributionsPerAccount::get() as u64;
+            T::DbWeight::get().reads_writes(1 + accounts * (6 + 2 * max_contributions), 1 + accounts)
+        }
+
+        /// Advance the v1 deposit migration within the `Migrations` hook quota, shared
+        /// with the other pallets' migrations
+        ///
+        /// Returns the weight used.
+        pub(crate) fn step_deposit_migration() -> Weight {
+            let Some(cursor) = DepositMigrationCursor::<T>::get() else { return Weight::zero() };
+            let budget = Self::hook_budget(HookTask::Migrations, T::BlockWeights::get().max_block);
+            let (next, used) = <crate::migrations::v1::HoldContributionDeposits<T> as crate::migrations::SteppedMigration>::step(
+                cursor,
+                budget.remaining(),
+            );
+            Self::note_hook_usage(HookTask::Migrations, used);
+
+            match next {
+                Some(cursor) => DepositMigrationCursor::<T>::put(cursor),
+                None => {
+                    DepositMigrationCursor::<T>::kill();
+                    Self::deposit_event(Event::DepositMigrationCompleted);
+                },
+            }
+            used.saturating_add(T::DbWeight::get().reads_writes(2, 2))
+        }
+
+        /// Advance the v3 search index migration like `step_deposit_migration`
+        ///
+        /// Returns the weight used.
+        pub(crate) fn step_index_migration() -> Weight {
+            let Some(cursor) = IndexMigrationCursor::<T>::get() else { return Weight::zero() };
+            let budget = Self::hook_budget(HookTask::Migrations, T::BlockWeights::get().max_block);
+            let (next, used) = <crate::migrations::v3::MoveSearchIndexes<T> as crate::migrations::SteppedMigration>::step(
+                cursor,
+                budget.remaining(),
+            );
+            Self::note_hook_usage(HookTask::Migrations, used);
+
+            match next {
+                Some(cursor) => IndexMigrationCursor::<T>::put(cursor),
+                None => {
+                    IndexMigrationCursor::<T>::kill();
+                    Self::deposit_event(Event::IndexMigrationCompleted);
+                },
+            }
+            used.saturating_add(T::DbWeight::get().reads_writes(2, 2))
+        }
+
+        /// How many items of weight `per_item` fit in `available`
+        fn fitting(available: Weight, per_item: Weight) -> u32 {
+            let by_time = available.ref_time().checked_div(per_item.ref_time()).unwrap_or(u64::MAX);
+            let by_proof = available.proof_size().checked_div(per_item.proof_size()).unwrap_or(u64::MAX);
+            by_time.min(by_proof).min(u32::MAX as u64) as u32
+        }
+
+        /// Raise or clear an account's decay warning
+        ///
+        /// The projected loss is what its contributions lose between now and the end of
+        /// `DecayWarningWindow`; reputation from other sources does not decay.
+        fn check_decay_warning(account: &T::AccountId, now: T::BlockNumber) {
+            let score = ReputationScores::<T>::get(account);
+            let at = now.saturating_add(T::DecayWarningWindow::get());
+            let loss = match (Self::decayed_score(account, now), Self::decayed_score(account, at)) {
+                (Ok(current), Ok(later)) => current.saturating_sub(later),
+                _ => 0,
+            };
+            let projected = score.saturating_sub(loss);
+
+            let threshold = T::DecayWarningThreshold::get().mul_ceil(score.max(0) as u32) as Score;
+            if score <= 0 || loss < threshold.max(1) {
+                DecayWarnings::<T>::remove(account);
+                return;
+            }
+            // Accounts are warned once; later sweeps only refresh the projection
+            let warned = DecayWarnings::<T>::contains_key(account);
+            DecayWarnings::<T>::insert(account, DecayWarning { score, projected, at });
+            if !warned {
+                Self::deposit_event(Event::DecayWarning { account: account.clone(), score, projected, at });
+            }
+        }
+
+        /// Score of an account's verified contributions after time decay as of block `at`
+        fn decayed_score(account: &T::AccountId, at: T::BlockNumber) -> Result<Score, DispatchError> {
             let contributions = AccountContributions::<T>::get(account);
             let params = ReputationParams::<T>::get().unwrap_or_default();
-            
+            let leaves = LeavePeriods::<T>::get(account);
+
             let mut total_score = T::MinReputation::get();
-            let current_block = frame_system::Pallet::<T>::block_number();
 
             for &contribution_id in contributions.iter() {
                 if let Some(contrib) = Contributions::<T>::get(contribution_id) {
                     if contrib.verified {
-                        // Calculate contribution value with time decay
-                        let base_points = params.contribution_type_weights
-                            .get(&contrib.contribution_type)
-                            .copied()
-                            .unwrap_or(10) as i32;
-
-                        // Apply time decay
-                        let age_blocks = current_block.saturating_sub(contrib.timestamp);
-                        let decay_factor = {
-                            let decay_amount = (age_blocks as u64 * params.decay_rate_per_block as u64) / 1_000_000;
-                            (1000u32.saturating_sub(decay_amount as u32).max(0)) as i32
-                        };
-
-                        // Apply decay and weight
-                        let decayed_points = (base_points * decay_factor) / 1000;
-                        let weighted_points = (decayed_points * contrib.weight as i32) / 100;
-
-                        total_score = total_score.saturating_add(weighted_points);
+                        let points = Self::decayed_points(&params, &contrib, &leaves, at)?;
+                        total_score = total_score
+                            .checked_add(points)
+                            .ok_or(Error::<T>::ReputationScoreOverflow)?;
                     }
                 }
             }
 
             // Clamp to min/max bounds
-            let new_score = total_score
+            Ok(total_score
                 .max(T::MinReputation::get())
-                .min(T::MaxReputation::get());
+                .min(T::MaxReputation::get()))
+        }
+
+        /// Update reputation with time decay
+        pub fn update_reputation_with_time_decay(account: &T::AccountId) -> DispatchResult {
+            let new_score = Self::decayed_score(account, frame_system::Pallet::<T>::block_number())?;
 
             let old_score = ReputationScores::<T>::get(account);
             ReputationScores::<T>::insert(account, new_score);
@@ -1307,25 +7655,225 @@ pub mod pallet {
     // Hooks for off-chain worker integration
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        #[cfg(feature = "offchain")]
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            let mut weight = Self::process_deadlines(now).saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+            let swept = Self::sweep_decay(now);
+            weight = weight.saturating_add(Self::decay_sweep_weight(swept));
+
+            let epoch = T::SnapshotEpoch::get();
+            if !epoch.is_zero() && (now % epoch).is_zero() {
+                if let Err(e) = Self::start_score_commitment() {
+                    log::warn!(target: "pallet-reputation", "Score commitment skipped: {:?}", e);
+                }
+            }
+            let mut budget = Self::hook_budget(HookTask::ScoreCommitment, T::BlockWeights::get().max_block);
+            Self::step_score_commitment(&mut budget);
+            weight = weight.saturating_add(budget.consumed()).saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+            if DepositMigrationCursor::<T>::exists() {
+                weight = weight.saturating_add(Self::step_deposit_migration());
+            }
+            if IndexMigrationCursor::<T>::exists() {
+                weight = weight.saturating_add(Self::step_index_migration());
+            }
+            weight.saturating_add(T::DbWeight::get().reads(2))
+        }
+
         fn offchain_worker(block_number: BlockNumberFor<T>) {
-            use crate::offchain::Pallet as OffchainPallet;
-            OffchainPallet::<T>::offchain_worker(block_number);
+            if Paused::<T>::get() {
+                return;
+            }
+            Self::check_ownership_challenges(block_number);
+            Self::check_git_imports(block_number);
+            Self::sign_evm_exports(block_number);
+            Self::sync_remote_caches(block_number);
+            Self::send_verification_reminders(block_number);
+
+            #[cfg(feature = "offchain")]
+            {
+                use crate::offchain::Pallet as OffchainPallet;
+                OffchainPallet::<T>::offchain_worker(block_number);
+            }
+        }
+    }
+
+    impl<T: Config> HookQuotaProvider for Pallet<T> {
+        fn hook_quota(task: HookTask) -> Perbill {
+            HookQuotas::<T>::get(task).unwrap_or_else(|| task.default_quota())
+        }
+
+        fn hook_usage(task: HookTask) -> Weight {
+            match HookUsage::<T>::get(task) {
+                Some((at, used)) if at == frame_system::Pallet::<T>::block_number() => used,
+                _ => Weight::zero(),
+            }
+        }
+
+        fn note_hook_usage(task: HookTask, weight: Weight) {
+            let used = Self::hook_usage(task).saturating_add(weight);
+            HookUsage::<T>::insert(task, (frame_system::Pallet::<T>::block_number(), used));
+        }
+    }
+
+    impl<T: Config> ReputationProvider<T::AccountId> for Pallet<T> {
+        fn reputation_of(who: &T::AccountId) -> Score {
+            Self::aggregate_reputation(who)
+        }
+
+        fn reputation_of_weight() -> Weight {
+            Self::aggregate_reputation_weight()
+        }
+
+        fn raw_reputation_of(who: &T::AccountId) -> Score {
+            Self::get_reputation(who)
+        }
+
+        fn is_on_probation(who: &T::AccountId) -> bool {
+            Self::is_on_probation(who)
+        }
+    }
+
+    impl<T: Config> CreditScoreProvider<T::AccountId, BlockNumberFor<T>> for Pallet<T> {
+        const VERSION: u32 = 1;
+
+        fn credit_profile(who: &T::AccountId) -> CreditProfile<BlockNumberFor<T>> {
+            let score = Self::get_reputation(who);
+            let account_age = AccountContributions::<T>::get(who)
+                .first()
+                .and_then(|id| Contributions::<T>::get(id))
+                .map(|first| frame_system::Pallet::<T>::block_number().saturating_sub(first.timestamp))
+                .unwrap_or_default();
+
+            CreditProfile {
+                score: Self::normalized_score(score),
+                percentile: Self::percentile(score),
+                account_age,
+                verified_contributions: VerifiedContributionCounts::<T>::get(who),
+            }
+        }
+    }
+
+    impl<T: Config> ReputationSnapshotProvider<T::AccountId> for Pallet<T> {
+        // The breakdown is left out: the DKG is public, and it is only served to viewers the
+        // contributor allowed
+        fn reputation_snapshot(who: &T::AccountId) -> ReputationSnapshot {
+            let mut contribution_digests: Vec<[u8; 32]> = ContributionArchives::<T>::get(who)
+                .map(|archive| archive.root.to_fixed_bytes())
+                .into_iter()
+                .collect();
+
+            for contribution_id in AccountContributions::<T>::get(who).iter() {
+                let Some(contribution) = Contributions::<T>::get(contribution_id) else { continue };
+                if !contribution.verified {
+                    continue;
+                }
+                contribution_digests.push(contribution.proof.to_fixed_bytes());
+            }
+
+            ReputationSnapshot { score: Self::get_reputation(who), breakdown: Vec::new(), contribution_digests }
+        }
+
+        fn reputation_snapshot_weight() -> Weight {
+            // One digest per verified contribution plus the archive root
+            let digests = u64::from(T::MaxContributionsPerAccount::get()).saturating_add(1);
+            Weight::from_parts(SNAPSHOT_DIGEST_WEIGHT.saturating_mul(digests), 0)
+                .saturating_add(T::DbWeight::get().reads(digests.saturating_add(2)))
+        }
+    }
+
+    impl<T: Config> ContributionRegistry<T::AccountId> for Pallet<T> {
+        fn verified_contributor(contribution_id: ContributionId) -> Option<T::AccountId> {
+            Contributions::<T>::get(contribution_id)
+                .filter(|contribution| contribution.verified)
+                .and_then(|contribution| ContributionProofs::<T>::get(contribution.proof))
+        }
+
+        fn overturn_fraudulent(contribution_id: ContributionId) -> DispatchResult {
+            let contributor = Self::do_overturn(contribution_id)?;
+            Self::start_probation(&contributor);
+            Ok(())
+        }
+
+        // UALs the DKG mapping holds beyond `MAX_UAL_LEN` cannot anchor a claim
+        fn contribution_ual(contribution_id: ContributionId) -> Option<Ual> {
+            T::Dkg::get_contribution_ual(contribution_id).and_then(|ual| Ual::try_from(ual).ok())
+        }
+    }
+
+    impl<T: Config> ContributionSubmitter<T::AccountId> for Pallet<T> {
+        fn submit_contribution(
+            who: &T::AccountId,
+            proof: [u8; 32],
+            contribution_type: ContributionType,
+            weight: u8,
+        ) -> Result<ContributionId, DispatchError> {
+            Self::submit_contribution(who, H256(proof), contribution_type, weight, DataSource::Manual)
+                .map_err(|e| e.error)
+        }
+
+        fn is_withdrawn(contribution_id: ContributionId) -> bool {
+            Contributions::<T>::get(contribution_id)
+                .map_or(true, |contribution| contribution.status == ContributionStatus::Rejected)
+        }
+    }
+
+    impl<T: Config> OnSkillTagsUpdated<T::AccountId> for Pallet<T> {
+        fn on_skill_tags_updated(who: &T::AccountId, old: &[SkillTag], new: &[SkillTag]) {
+            for skill in old.iter().filter(|skill| !new.contains(skill)) {
+                SkillVerifiers::<T>::remove(skill, who);
+            }
+            for skill in new {
+                SkillVerifiers::<T>::insert(skill, who, ());
+            }
+        }
+    }
+
+    impl<T: Config> OnClaimResolved<T::AccountId> for Pallet<T> {
+        fn on_claim_resolved(submitter: &T::AccountId, verdict: ClaimVerdict) {
+            match verdict {
+                ClaimVerdict::Upheld => Self::award_reputation(
+                    submitter,
+                    T::ClaimUpheldReward::get(),
+                    RepChangeReason::ClaimUpheld,
+                ),
+                ClaimVerdict::Rejected => Self::slash_reputation(
+                    submitter,
+                    T::ClaimRejectedPenalty::get(),
+                    RepChangeReason::ClaimRejected,
+                ),
+            }
+        }
+
+        fn on_contribution_claim_resolved(submitter: &T::AccountId, contribution_id: ContributionId, verdict: ClaimVerdict) {
+            Self::on_claim_resolved(submitter, verdict);
+            Self::deposit_event(Event::ContributionClaimResolved {
+                contributor: submitter.clone(),
+                contribution_id,
+                verdict,
+            });
         }
     }
 }
 
-// Default weight implementations for testing
-#[cfg(test)]
-impl<T: Config> WeightInfo for T {
+// Default weight implementations
+impl WeightInfo for () {
     fn add_contribution() -> Weight {
         Weight::from_parts(50_000_000, 0)
     }
 
+    fn add_contribution_rejected() -> Weight {
+        Weight::from_parts(20_000_000, 0)
+    }
+
     fn verify_contribution() -> Weight {
         Weight::from_parts(25_000_000, 0)
     }
 
+    fn verify_contribution_pending() -> Weight {
+        Weight::from_parts(15_000_000, 0)
+    }
+
     fn update_algorithm_params() -> Weight {
         Weight::from_parts(10_000_000, 0)
     }
@@ -0,0 +1,387 @@
+//! Multi-block migration support
+//!
+//! Contributions and claims can grow far beyond what a single block can rewrite.
+//! Such migrations implement `SteppedMigration` and are advanced by their pallet's
+//! `on_initialize` within the `HookTask::Migrations` quota, resuming from a cursor
+//! stored on chain.
+//! While a migration is in progress the owning pallet rejects calls that touch the
+//! migrating storage with `MigrationInProgress`.
+
+use codec::{Decode, Encode};
+use frame_support::{pallet_prelude::ConstU32, storage::unhashed, weights::Weight, BoundedVec};
+use sp_std::vec::Vec;
+
+/// Maximum length of a migration cursor
+///
+/// Cursors hold a raw storage key plus a phase byte; keys of the DotRep maps are
+/// well below this bound.
+pub const MAX_CURSOR_LEN: u32 = 256;
+
+/// Position of a stepped migration; empty means "start from the beginning"
+pub type MigrationCursor = BoundedVec<u8, ConstU32<MAX_CURSOR_LEN>>;
+
+/// Weight charged per migrated item on top of its storage access
+///
+/// Covers decoding and re-encoding a value of a few hundred bytes.
+pub const ITEM_BASE_WEIGHT: Weight = Weight::from_parts(10_000_000, 0);
+
+/// A migration that can be split across blocks
+pub trait SteppedMigration {
+    /// Migrate as many items after `cursor` as fit within `limit`
+    ///
+    /// Returns the cursor to resume from, or `None` once the migration is complete,
+    /// together with the weight consumed.
+    fn step(cursor: MigrationCursor, limit: Weight) -> (Option<MigrationCursor>, Weight);
+}
+
+/// One phase of a multi-phase migration
+pub type Phase = fn(MigrationCursor, Weight) -> (Option<MigrationCursor>, Weight);
+
+/// Run `phases` in order, resuming from `cursor`
+///
+/// The first cursor byte selects the phase; the rest is that phase's own cursor.
+pub fn step_phases(cursor: MigrationCursor, limit: Weight, phases: &[Phase]) -> (Option<MigrationCursor>, Weight) {
+    let (mut phase, mut inner) = match cursor.split_first() {
+        Some((phase, inner)) => (*phase as usize, MigrationCursor::truncate_from(inner.to_vec())),
+        None => (0, MigrationCursor::default()),
+    };
+    let mut used = Weight::zero();
+
+    while let Some(run) = phases.get(phase) {
+        let (next, consumed) = run(inner, limit.saturating_sub(used));
+        used = used.saturating_add(consumed);
+        match next {
+            Some(next) => {
+                let mut cursor = Vec::with_capacity(next.len() + 1);
+                cursor.push(phase as u8);
+                cursor.extend(next.into_inner());
+                return (Some(MigrationCursor::truncate_from(cursor)), used);
+            },
+            None => {
+                phase += 1;
+                inner = MigrationCursor::default();
+            },
+        }
+    }
+
+    (None, used)
+}
+
+/// Run a stepped migration to completion in one go
+///
+/// Only suitable for small state, e.g. in tests or on young chains; runtime upgrades
+/// step their migrations across blocks instead.
+pub fn run_to_completion<M: SteppedMigration>() -> Weight {
+    let mut cursor = MigrationCursor::default();
+    let mut used = Weight::zero();
+    loop {
+        let (next, consumed) = M::step(cursor, Weight::MAX);
+        used = used.saturating_add(consumed);
+        match next {
+            Some(next) => cursor = next,
+            None => return used,
+        }
+    }
+}
+
+/// Rewrite the values of a prefixed storage map, resuming after `cursor`
+///
+/// `f` maps each old value to its new value, or to `None` to remove the entry.
+/// Values that do not decode as `Old` are left untouched.
+pub fn translate_step<Old: Decode, New: Encode>(
+    prefix: &[u8],
+    cursor: MigrationCursor,
+    limit: Weight,
+    item_weight: Weight,
+    mut f: impl FnMut(Old) -> Option<New>,
+) -> (Option<MigrationCursor>, Weight) {
+    raw_step(prefix, cursor, limit, item_weight, |key| {
+        if let Some(old) = unhashed::get::<Old>(key) {
+            match f(old) {
+                Some(new) => unhashed::put(key, &new),
+                None => unhashed::kill(key),
+            }
+        }
+    })
+}
+
+/// Visit the values of a prefixed storage map, resuming after `cursor`
+///
+/// Values that do not decode as `V` are skipped.
+pub fn visit_step<V: Decode>(
+    prefix: &[u8],
+    cursor: MigrationCursor,
+    limit: Weight,
+    item_weight: Weight,
+    mut f: impl FnMut(V),
+) -> (Option<MigrationCursor>, Weight) {
+    raw_step(prefix, cursor, limit, item_weight, |key| {
+        if let Some(value) = unhashed::get::<V>(key) {
+            f(value);
+        }
+    })
+}
+
+/// Take the entries of a `Blake2_128Concat` keyed storage map, resuming after `cursor`
+///
+/// `f` receives each entry's key and value; entries whose key or value do not decode
+/// are removed all the same.
+pub fn drain_step<K: Decode, V: Decode>(
+    prefix: &[u8],
+    cursor: MigrationCursor,
+    limit: Weight,
+    item_weight: Weight,
+    mut f: impl FnMut(K, V),
+) -> (Option<MigrationCursor>, Weight) {
+    raw_step(prefix, cursor, limit, item_weight, |key| {
+        let value = unhashed::take::<V>(key);
+        let decoded_key = key.get(prefix.len() + 16..).and_then(|mut encoded| K::decode(&mut encoded).ok());
+        if let (Some(key), Some(value)) = (decoded_key, value) {
+            f(key, value);
+        }
+    })
+}
+
+/// Remove the entries of a prefixed storage map whose key fails `keep`, resuming after
+/// `cursor`
+///
+/// `keep` receives each raw key without `prefix`, hashers included.
+pub fn retain_step(
+    prefix: &[u8],
+    cursor: MigrationCursor,
+    limit: Weight,
+    item_weight: Weight,
+    mut keep: impl FnMut(&[u8]) -> bool,
+) -> (Option<MigrationCursor>, Weight) {
+    raw_step(prefix, cursor, limit, item_weight, |key| {
+        if !keep(&key[prefix.len()..]) {
+            unhashed::kill(key);
+        }
+    })
+}
+
+fn raw_step(
+    prefix: &[u8],
+    cursor: MigrationCursor,
+    limit: Weight,
+    item_weight: Weight,
+    mut f: impl FnMut(&[u8]),
+) -> (Option<MigrationCursor>, Weight) {
+    let item_weight = item_weight.saturating_add(ITEM_BASE_WEIGHT);
+    let mut previous = if cursor.is_empty() { prefix.to_vec() } else { cursor.into_inner() };
+    let mut used = Weight::zero();
+
+    loop {
+        let Some(key) = sp_io::storage::next_key(&previous).filter(|key| key.starts_with(prefix)) else {
+            return (None, used);
+        };
+        if used.saturating_add(item_weight).any_gt(limit) {
+            return (Some(MigrationCursor::truncate_from(previous)), used);
+        }
+
+        f(&key);
+        used = used.saturating_add(item_weight);
+        previous = key;
+    }
+}
+
+/// v1: hold `ContributionDeposit` for contributions stored before deposits existed
+///
+/// Contributors who cannot cover the deposit keep their contributions without one;
+/// nothing is released when those are withdrawn or pruned, nor for contributions
+/// removed before the migration reaches them.
+pub mod v1 {
+    use super::*;
+    use crate::pallet::{
+        Config, Contribution, ContributionDeposits, ContributionProofs, Contributions, DepositMigrationCursor, Pallet,
+    };
+    use frame_support::{
+        storage::StoragePrefixedMap,
+        traits::{Get, GetStorageVersion, OnRuntimeUpgrade, ReservableCurrency, StorageVersion},
+    };
+    use sp_runtime::traits::Zero;
+    use sp_std::marker::PhantomData;
+
+    /// Stepped v1 deposit collection over all stored contributions
+    pub struct HoldContributionDeposits<T>(PhantomData<T>);
+
+    impl<T: Config> SteppedMigration for HoldContributionDeposits<T> {
+        fn step(cursor: MigrationCursor, limit: Weight) -> (Option<MigrationCursor>, Weight) {
+            let deposit = T::ContributionDeposit::get();
+            if deposit.is_zero() {
+                return (None, Weight::zero());
+            }
+
+            visit_step::<Contribution<T>>(
+                &Contributions::<T>::final_prefix(),
+                cursor,
+                limit,
+                T::DbWeight::get().reads_writes(3, 2),
+                |contribution| {
+                    if ContributionDeposits::<T>::contains_key(contribution.id) {
+                        return;
+                    }
+                    let Some(contributor) = ContributionProofs::<T>::get(contribution.proof) else { return };
+                    if T::Currency::reserve(&contributor, deposit).is_ok() {
+                        ContributionDeposits::<T>::insert(contribution.id, (contributor, deposit));
+                    }
+                },
+            )
+        }
+    }
+
+    /// Start v1 and take its first step; `on_initialize` continues from the cursor
+    ///
+    /// The storage version is bumped right away: v1 changes no layout, and later
+    /// versions do not touch deposits.
+    pub struct MigrateToV1<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() != 0 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            DepositMigrationCursor::<T>::put(MigrationCursor::default());
+            StorageVersion::new(1).put::<Pallet<T>>();
+            let weight = Pallet::<T>::step_deposit_migration();
+
+            weight.saturating_add(T::DbWeight::get().reads_writes(1, 2))
+        }
+    }
+}
+
+/// v2: weigh mentorship sessions in stored algorithm parameters
+///
+/// Parameters stored before `ContributionType::Mentorship` existed get its default
+/// weight; a weight already set by governance is kept.
+pub mod v2 {
+    use crate::pallet::{AlgorithmParams, Config, ContributionType, Pallet, ReputationParams};
+    use frame_support::{
+        traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+        weights::Weight,
+    };
+    use sp_std::marker::PhantomData;
+
+    pub struct MigrateToV2<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() != 1 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let default_weight = AlgorithmParams::default()
+                .contribution_type_weights
+                .get(&ContributionType::Mentorship)
+                .copied()
+                .unwrap_or_default();
+            ReputationParams::<T>::mutate(|params| {
+                params.contribution_type_weights.entry(ContributionType::Mentorship).or_insert(default_weight);
+            });
+            StorageVersion::new(2).put::<Pallet<T>>();
+
+            T::DbWeight::get().reads_writes(2, 2)
+        }
+    }
+}
+
+/// v3: keep the project and type search indexes in double maps
+///
+/// The bounded id lists of `ContributionsByProject` and `ContributionsByType` are moved
+/// into `ContributionsOfProject` and `ContributionsOfType`, one list per step item. Ids of
+/// contributions removed or moved to another project since are dropped.
+pub mod v3 {
+    use super::*;
+    use crate::pallet::{
+        Config, ContributionId, ContributionProjects, ContributionType, Contributions, ContributionsOfProject,
+        ContributionsOfType, IndexMigrationCursor, Pallet, ProjectId,
+    };
+    use frame_support::{
+        storage::StoragePrefixedMap,
+        traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+        Blake2_128Concat,
+    };
+    use sp_std::marker::PhantomData;
+
+    /// Most ids a v2 search index entry held
+    pub const MAX_INDEXED_CONTRIBUTIONS: u32 = 1_000;
+
+    /// v2 search index entry, oldest first
+    pub type ContributionIndex = BoundedVec<ContributionId, ConstU32<MAX_INDEXED_CONTRIBUTIONS>>;
+
+    #[frame_support::storage_alias]
+    pub type ContributionsByProject<T: Config> =
+        StorageMap<Pallet<T>, Blake2_128Concat, ProjectId, ContributionIndex, ValueQuery>;
+
+    #[frame_support::storage_alias]
+    pub type ContributionsByType<T: Config> =
+        StorageMap<Pallet<T>, Blake2_128Concat, ContributionType, ContributionIndex, ValueQuery>;
+
+    /// Stepped v3 move of both search indexes
+    pub struct MoveSearchIndexes<T>(PhantomData<T>);
+
+    impl<T: Config> SteppedMigration for MoveSearchIndexes<T> {
+        fn step(cursor: MigrationCursor, limit: Weight) -> (Option<MigrationCursor>, Weight) {
+            step_phases(cursor, limit, &[move_project_index::<T>, move_type_index::<T>])
+        }
+    }
+
+    /// Weight of moving one v2 index entry holding up to `MAX_INDEXED_CONTRIBUTIONS` ids
+    fn entry_weight<T: Config>() -> Weight {
+        let ids = MAX_INDEXED_CONTRIBUTIONS as u64;
+        T::DbWeight::get().reads_writes(1 + 2 * ids, 1 + ids)
+    }
+
+    fn move_project_index<T: Config>(cursor: MigrationCursor, limit: Weight) -> (Option<MigrationCursor>, Weight) {
+        drain_step::<ProjectId, ContributionIndex>(
+            &ContributionsByProject::<T>::final_prefix(),
+            cursor,
+            limit,
+            entry_weight::<T>(),
+            |project_id, ids| {
+                for contribution_id in ids {
+                    if ContributionProjects::<T>::get(contribution_id) == Some(project_id) {
+                        ContributionsOfProject::<T>::insert(project_id, Pallet::<T>::index_key(contribution_id), ());
+                    }
+                }
+            },
+        )
+    }
+
+    fn move_type_index<T: Config>(cursor: MigrationCursor, limit: Weight) -> (Option<MigrationCursor>, Weight) {
+        drain_step::<ContributionType, ContributionIndex>(
+            &ContributionsByType::<T>::final_prefix(),
+            cursor,
+            limit,
+            entry_weight::<T>(),
+            |contribution_type, ids| {
+                for contribution_id in ids {
+                    if Contributions::<T>::contains_key(contribution_id) {
+                        ContributionsOfType::<T>::insert(&contribution_type, Pallet::<T>::index_key(contribution_id), ());
+                    }
+                }
+            },
+        )
+    }
+
+    /// Start v3 and take its first step; `on_initialize` continues from the cursor
+    ///
+    /// The storage version is bumped right away; new contributions go to the new indexes
+    /// while the old entries are moved.
+    pub struct MigrateToV3<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() != 2 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            IndexMigrationCursor::<T>::put(MigrationCursor::default());
+            StorageVersion::new(3).put::<Pallet<T>>();
+            let weight = Pallet::<T>::step_index_migration();
+
+            weight.saturating_add(T::DbWeight::get().reads_writes(1, 2))
+        }
+    }
+}
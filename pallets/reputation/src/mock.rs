@@ -1,14 +1,16 @@
 use crate as pallet_reputation;
 
 use frame_support::{
-    parameter_types,
+    ord_parameter_types, parameter_types,
     traits::{OnFinalize, OnInitialize},
+    PalletId,
 };
+use frame_system::{offchain::AppCrypto, EnsureRoot, EnsureSignedBy};
 use sp_core::H256;
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
-    testing::Header,
-    BuildStorage,
+    testing::{Header, TestSignature, TestXt, UintAuthorityId},
+    BuildStorage, Perbill, Percent,
 };
 use pallet_timestamp;
 
@@ -27,6 +29,7 @@ frame_support::construct_runtime!(
         Timestamp: pallet_timestamp,
         Balances: pallet_balances,
         Reputation: pallet_reputation,
+        DKGPallet: pallet_reputation::dkg_integration,
     }
 );
 
@@ -95,8 +98,70 @@ parameter_types! {
     pub const MinReputation: i32 = 0;
     pub const MaxReputation: i32 = 1000;
     pub const MinReputationToVerify: i32 = 10;
-    pub const MinVerifications: u32 = 1;
+    pub static MinVerifications: u32 = 1;
     pub const MaxPendingContributions: u32 = 10;
+    pub const MaxCredibilityBoost: u32 = 50;
+    pub const MaxAttestationBoost: u32 = 30;
+    pub const ClaimUpheldReward: i32 = 25;
+    pub const ClaimRejectedPenalty: i32 = 40;
+    pub const ProxySubmissionPeriod: u64 = 10;
+    pub const MaintainerAttestationWeight: u32 = 3;
+    pub const SkillMatchMultiplier: u32 = 2;
+    pub const MaxHackathonProjects: u32 = 4;
+    pub const MaxHackathonWinners: u32 = 3;
+    pub const MentorshipSessionWeight: u8 = 50;
+    pub const ReferralBonus: i32 = 5;
+    pub const ReferralQualifyingContributions: u32 = 2;
+    pub const ReferralEpoch: u64 = 100;
+    pub const MaxReferralsPerEpoch: u32 = 2;
+    pub const ProbationPeriod: u64 = 50;
+    pub const FeelessReputationThreshold: i32 = 100;
+    pub const FeelessCallsPerEra: u32 = 2;
+    pub const FeelessEra: u64 = 100;
+    pub const MinCommitteeApprovals: u32 = 3;
+    pub const MaxCommitteeSize: u32 = 5;
+    pub const DividendPalletId: PalletId = PalletId(*b"dr/divdd");
+    pub const DividendBudget: u64 = 1_000;
+    pub const DividendEra: u64 = 100;
+    pub const DividendEarningsCap: i32 = 50;
+    pub const VerificationDeadline: u64 = 50;
+    pub const EscalationPeriod: u64 = 50;
+    pub const StaleVerifierReward: i32 = 5;
+    pub const VerifierEra: u64 = 100;
+    pub const ReminderLead: u64 = 10;
+    pub const DisputeWindow: u64 = 100;
+    pub const MaxDeadlinesPerBlock: u32 = 16;
+    pub const RejectionThreshold: u32 = 2;
+    pub const RejectedDepositSlash: Perbill = Perbill::from_percent(50);
+    pub const OutlierDeviation: u8 = 30;
+    pub const AccurateDeviation: u8 = 10;
+    pub const AccuracyBonus: i32 = 1;
+    pub const OutlierPenalty: i32 = 2;
+    pub const MaxOutlierStrikes: u32 = 2;
+    pub const MaxLeaveDuration: u64 = 100_000;
+    pub const LeaveCooldown: u64 = 200_000;
+    pub const MaxLeavesRecorded: u32 = 2;
+    pub const SnapshotEpoch: u64 = 0;
+    pub const SnapshotTopK: u32 = 3;
+    pub const MaxSnapshotAccounts: u32 = 100;
+    pub const EvmAttestationValidity: u64 = 3_600;
+    pub const MaxGitLogEntries: u32 = 8;
+    pub const GitImportPointsPercent: Percent = Percent::from_percent(50);
+    pub const MaxImportBatch: u32 = 10;
+    pub const ImportedPointsPercent: Percent = Percent::from_percent(25);
+    pub const StarterGrantPeriod: u64 = 100;
+    pub const MaxStarterGrantsPerPeriod: u32 = 2;
+    pub static DecaySweepAccountsPerBlock: u32 = 0;
+    pub const DecayWarningWindow: u64 = 200_000;
+    pub const DecayWarningThreshold: Percent = Percent::from_percent(10);
+    pub const RemoteReputationTtl: u64 = 100;
+    // Contributions weigh at most 100, so none needs an attestation unless a test lowers this
+    pub static MaintainerAttestationThreshold: u8 = 100;
+    // Likewise for committee approval
+    pub static CommitteeVerificationThreshold: u8 = 100;
+    pub static ContributionDeposit: u64 = 0;
+    pub const MaxCoAuthors: u32 = 3;
+    pub const ProjectClaimExpiry: u64 = 100;
 }
 
 pub struct TestUpdateOrigin;
@@ -123,6 +188,183 @@ impl pallet_reputation::Config for Test {
     type MinVerifications = MinVerifications;
     type MaxPendingContributions = MaxPendingContributions;
     type UpdateOrigin = TestUpdateOrigin;
+    type PauseOrigin = EnsureRoot<u64>;
+    type OnOffence = ();
+    type CredibilityBoost = TestCredibilityBoost;
+    type MaxCredibilityBoost = MaxCredibilityBoost;
+    type MaxAttestationBoost = MaxAttestationBoost;
+    type ClaimUpheldReward = ClaimUpheldReward;
+    type ClaimRejectedPenalty = ClaimRejectedPenalty;
+    type ProxySubmissionPeriod = ProxySubmissionPeriod;
+    type MaintainerAttestationWeight = MaintainerAttestationWeight;
+    type MaintainerAttestationThreshold = MaintainerAttestationThreshold;
+    // Repository ownership is confirmed by the DKG publishers, who also attest GitHub handles
+    type OwnershipAuthorityId = TestDkgPublisherId;
+    type OwnershipVerifierOrigin = EnsureSignedBy<DkgPublisher, u64>;
+    type ProjectClaimExpiry = ProjectClaimExpiry;
+    // Co-authors must have a publisher-attested GitHub handle
+    type Bindings = DKGPallet;
+    type MaxCoAuthors = MaxCoAuthors;
+    type SkillMatchMultiplier = SkillMatchMultiplier;
+    type ContributionDeposit = ContributionDeposit;
+    type Disputes = ();
+    type Dkg = DKGPallet;
+    type MaxHackathonProjects = MaxHackathonProjects;
+    type MaxHackathonWinners = MaxHackathonWinners;
+    type MentorshipSessionWeight = MentorshipSessionWeight;
+    type ReferralBonus = ReferralBonus;
+    type ReferralQualifyingContributions = ReferralQualifyingContributions;
+    type ReferralEpoch = ReferralEpoch;
+    type MaxReferralsPerEpoch = MaxReferralsPerEpoch;
+    type ProbationPeriod = ProbationPeriod;
+    type FeelessReputationThreshold = FeelessReputationThreshold;
+    type FeelessCallsPerEra = FeelessCallsPerEra;
+    type FeelessEra = FeelessEra;
+    type CommitteeVerificationThreshold = CommitteeVerificationThreshold;
+    type MinCommitteeApprovals = MinCommitteeApprovals;
+    type MaxCommitteeSize = MaxCommitteeSize;
+    type DividendPalletId = DividendPalletId;
+    type DividendBudget = DividendBudget;
+    type DividendEra = DividendEra;
+    type DividendEarningsCap = DividendEarningsCap;
+    type VerificationDeadline = VerificationDeadline;
+    type EscalationPeriod = EscalationPeriod;
+    type StaleVerifierReward = StaleVerifierReward;
+    type VerifierEra = VerifierEra;
+    type ReminderLead = ReminderLead;
+    type DisputeWindow = DisputeWindow;
+    type MaxDeadlinesPerBlock = MaxDeadlinesPerBlock;
+    type RejectionThreshold = RejectionThreshold;
+    type RejectedDepositSlash = RejectedDepositSlash;
+    type Slash = ();
+    type OutlierDeviation = OutlierDeviation;
+    type AccurateDeviation = AccurateDeviation;
+    type AccuracyBonus = AccuracyBonus;
+    type OutlierPenalty = OutlierPenalty;
+    type MaxOutlierStrikes = MaxOutlierStrikes;
+    type MaxLeaveDuration = MaxLeaveDuration;
+    type LeaveCooldown = LeaveCooldown;
+    type MaxLeavesRecorded = MaxLeavesRecorded;
+    type SnapshotEpoch = SnapshotEpoch;
+    type SnapshotTopK = SnapshotTopK;
+    type MaxSnapshotAccounts = MaxSnapshotAccounts;
+    type EvmAttestationAuthorityId = TestDkgPublisherId;
+    type EvmAttestationValidity = EvmAttestationValidity;
+    type MaxGitLogEntries = MaxGitLogEntries;
+    type GitImportPointsPercent = GitImportPointsPercent;
+    type MaxImportBatch = MaxImportBatch;
+    type ImportedPointsPercent = ImportedPointsPercent;
+    type Personhood = TestPersonhood;
+    type StarterGrantPeriod = StarterGrantPeriod;
+    type MaxStarterGrantsPerPeriod = MaxStarterGrantsPerPeriod;
+    type DecaySweepAccountsPerBlock = DecaySweepAccountsPerBlock;
+    type DecayWarningWindow = DecayWarningWindow;
+    type DecayWarningThreshold = DecayWarningThreshold;
+    type RemoteReputationTtl = RemoteReputationTtl;
+    type ChainOrigin = TestChainOrigin;
+}
+
+// Account that receives a (deliberately above-cap) credibility boost in tests
+pub const BOOSTED_ACCOUNT: u64 = 42;
+
+pub struct TestCredibilityBoost;
+impl crate::CredibilityBoostProvider<u64> for TestCredibilityBoost {
+    fn credibility_boost_percent(who: &u64) -> u32 {
+        if *who == BOOSTED_ACCOUNT { 100 } else { 0 }
+    }
+}
+
+parameter_types! {
+    // (account, person) pairs; accounts sharing a person are the same human
+    pub static VerifiedHumans: Vec<(u64, u8)> = vec![];
+}
+
+pub struct TestPersonhood;
+impl dotrep_primitives::PersonhoodProvider<u64> for TestPersonhood {
+    fn personhood_of(who: &u64) -> Option<dotrep_primitives::PersonhoodId> {
+        VerifiedHumans::get().into_iter().find(|(account, _)| account == who).map(|(_, person)| [person; 32])
+    }
+}
+
+// Account standing in for the XCM origin of the "moonbeam" chain
+pub const MOONBEAM_RELAY: u64 = 60;
+
+pub struct TestChainOrigin;
+impl frame_support::traits::EnsureOrigin<RuntimeOrigin> for TestChainOrigin {
+    type Success = dotrep_primitives::ChainId;
+
+    fn try_origin(origin: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+        match origin.clone().into() {
+            Ok(frame_system::RawOrigin::Signed(MOONBEAM_RELAY)) => Ok(b"moonbeam".to_vec().try_into().unwrap()),
+            _ => Err(origin),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+        Ok(RuntimeOrigin::signed(MOONBEAM_RELAY))
+    }
+}
+
+// DKG integration configuration: the OCW signs with UintAuthorityId keys
+pub type Extrinsic = TestXt<RuntimeCall, ()>;
+
+pub const DKG_PUBLISHER: u64 = 100;
+
+ord_parameter_types! {
+    pub const DkgPublisher: u64 = DKG_PUBLISHER;
+}
+
+parameter_types! {
+    pub const MaxPublishesPerBlock: u32 = 5;
+    pub const MaxPublishAttempts: u32 = 3;
+    pub const RetryBackoff: u64 = 2;
+}
+
+pub struct TestDkgPublisherId;
+impl AppCrypto<UintAuthorityId, TestSignature> for TestDkgPublisherId {
+    type RuntimeAppPublic = UintAuthorityId;
+    type GenericSignature = TestSignature;
+    type GenericPublic = UintAuthorityId;
+}
+
+impl frame_system::offchain::SigningTypes for Test {
+    type Public = UintAuthorityId;
+    type Signature = TestSignature;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Test
+where
+    RuntimeCall: From<C>,
+{
+    type OverarchingCall = RuntimeCall;
+    type Extrinsic = Extrinsic;
+}
+
+impl<C> frame_system::offchain::CreateSignedTransaction<C> for Test
+where
+    RuntimeCall: From<C>,
+{
+    fn create_transaction<A: AppCrypto<Self::Public, Self::Signature>>(
+        call: RuntimeCall,
+        _public: UintAuthorityId,
+        account: u64,
+        _nonce: u64,
+    ) -> Option<(RuntimeCall, (u64, ()))> {
+        Some((call, (account, ())))
+    }
+}
+
+impl pallet_reputation::dkg_integration::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type AuthorityId = TestDkgPublisherId;
+    type PublisherOrigin = EnsureSignedBy<DkgPublisher, u64>;
+    type MaxPublishesPerBlock = MaxPublishesPerBlock;
+    type Snapshot = Reputation;
+    type Reputation = Reputation;
+    type UpdateOrigin = EnsureRoot<u64>;
+    type MaxPublishAttempts = MaxPublishAttempts;
+    type RetryBackoff = RetryBackoff;
 }
 
 // Genesis storage initialization for tests
@@ -145,3 +387,13 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
     t.into()
 }
 
+/// Test externalities with an off-chain worker extension, for calls that read the
+/// off-chain clock
+pub fn new_bench_ext() -> sp_io::TestExternalities {
+    use sp_core::offchain::{testing::TestOffchainExt, OffchainWorkerExt};
+
+    let mut ext = new_test_ext();
+    let (offchain, _) = TestOffchainExt::new();
+    ext.register_extension(OffchainWorkerExt::new(offchain));
+    ext
+}
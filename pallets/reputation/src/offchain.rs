@@ -16,6 +16,9 @@ use sp_runtime::{
 };
 use sp_std::prelude::*;
 
+/// Accounts scanned for pending contributions per off-chain worker run
+const MAX_ACCOUNTS_PER_RUN: u32 = 100;
+
 /// External API configuration
 pub struct ExternalApiConfig {
     pub github_api_key: Vec<u8>,
@@ -198,14 +201,23 @@ impl<T: pallet_reputation::Config> pallet_reputation::Pallet<T> {
     }
 
     /// Get pending contributions for verification
+    ///
+    /// Scans at most `MAX_ACCOUNTS_PER_RUN` accounts per run, resuming after the last
+    /// account scanned by the previous run and starting over once all were seen.
     fn get_pending_contributions() -> Vec<(T::AccountId, ContributionId, H256)> {
         use crate::pallet::{Contributions, AccountContributions, ContributionStatus};
-        
+        use dotrep_primitives::BoundedIterExt;
+
+        let mut cursor = StorageValueRef::persistent(b"dotrep:ocw:pending_cursor");
+        let mut accounts = match cursor.get::<Vec<u8>>().ok().flatten() {
+            Some(last_key) => AccountContributions::<T>::iter_from(last_key),
+            None => AccountContributions::<T>::iter(),
+        }
+        .bounded(MAX_ACCOUNTS_PER_RUN);
+
         let mut pending = Vec::new();
-        
-        // Iterate through all accounts with contributions
-        // In production, this would be more efficient with a dedicated pending index
-        for (account, contribution_ids) in AccountContributions::<T>::iter() {
+        let mut last_key = None;
+        for (account, contribution_ids) in accounts.by_ref() {
             for &contribution_id in contribution_ids.iter() {
                 if let Some(contrib) = Contributions::<T>::get(contribution_id) {
                     if contrib.status == ContributionStatus::Pending && !contrib.verified {
@@ -213,8 +225,13 @@ impl<T: pallet_reputation::Config> pallet_reputation::Pallet<T> {
                     }
                 }
             }
+            last_key = Some(AccountContributions::<T>::hashed_key_for(&account));
+        }
+
+        match last_key {
+            Some(last_key) if accounts.exceeded() => cursor.set(&last_key),
+            _ => cursor.clear(),
         }
-        
         pending
     }
 
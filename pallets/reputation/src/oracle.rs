@@ -0,0 +1,87 @@
+//! Reputation oracle for smart contracts on the same chain
+//!
+//! A runtime exposes `oracle_call` to contracts through a `pallet-contracts` chain
+//! extension (or an EVM precompile). Calls are identified by an [`OracleFunction`] id;
+//! the input is the SCALE-encoded account and the output is SCALE-encoded, so an ink!
+//! contract can declare the extension with `#[ink::chain_extension]` and plain types.
+//! Failures map to the non-zero status codes of [`OracleError`].
+
+use crate::{
+    jsonld::ReputationTier,
+    pallet::{Config, Pallet},
+};
+use codec::{Decode, Encode};
+use frame_support::weights::Weight;
+use sp_std::vec::Vec;
+
+/// Functions served by the oracle, by extension function id
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u16)]
+pub enum OracleFunction {
+    /// Effective reputation of an account, as an `i32`
+    GetReputation = 1,
+    /// Tier of an account's effective reputation, as a `u8` (`0` = Newcomer .. `4` = Luminary)
+    GetTier = 2,
+}
+
+impl OracleFunction {
+    pub fn from_id(id: u16) -> Option<Self> {
+        match id {
+            1 => Some(OracleFunction::GetReputation),
+            2 => Some(OracleFunction::GetTier),
+            _ => None,
+        }
+    }
+}
+
+/// Failures of an oracle call, returned to the contract as status codes
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum OracleError {
+    /// The function id is not an [`OracleFunction`]
+    UnknownFunction = 1,
+    /// The input is not a SCALE-encoded account id
+    InvalidInput = 2,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Serve an oracle call: decode the account from `input` and return the
+    /// SCALE-encoded answer
+    pub fn oracle_call(function: OracleFunction, mut input: &[u8]) -> Result<Vec<u8>, OracleError> {
+        let account = T::AccountId::decode(&mut input).map_err(|_| OracleError::InvalidInput)?;
+        let score = Self::effective_reputation(&account);
+
+        Ok(match function {
+            OracleFunction::GetReputation => score.encode(),
+            OracleFunction::GetTier => (ReputationTier::from_score(score) as u8).encode(),
+        })
+    }
+
+    /// Weight of `oracle_call`, charged by the runtime before serving it: the effective
+    /// reputation with its attestations
+    pub fn oracle_call_weight() -> Weight {
+        Self::effective_reputation_weight()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mock::*, ReputationScores};
+
+    #[test]
+    fn test_oracle_serves_reputation_and_tier() {
+        new_test_ext().execute_with(|| {
+            ReputationScores::<Test>::insert(1u64, 350);
+            let account = 1u64.encode();
+
+            assert_eq!(Reputation::oracle_call(OracleFunction::GetReputation, &account), Ok(350i32.encode()));
+            assert_eq!(
+                Reputation::oracle_call(OracleFunction::GetTier, &account),
+                Ok((ReputationTier::Trusted as u8).encode())
+            );
+            assert_eq!(Reputation::oracle_call(OracleFunction::GetTier, &[1]), Err(OracleError::InvalidInput));
+            assert_eq!(OracleFunction::from_id(3), None);
+        });
+    }
+}
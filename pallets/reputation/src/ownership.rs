@@ -0,0 +1,203 @@
+//! Repository ownership verification for registered projects
+//!
+//! `request_ownership_challenge` issues a token that the project owner commits to a
+//! `.dotrep` file at the root of the repository's default branch. The off-chain worker
+//! fetches that file for every open challenge and, when it contains the token as
+//! `0x`-prefixed hex, submits `confirm_project_ownership` signed with
+//! `T::OwnershipAuthorityId`, marking the project `Verified`.
+
+use crate::pallet::{Call, Config, OwnershipChallenges, Pallet, ProjectId, Projects};
+use codec::Encode;
+use frame_system::{
+    offchain::{SendSignedTransaction, Signer},
+    pallet_prelude::BlockNumberFor,
+};
+use sp_core::H256;
+use sp_runtime::{
+    offchain::{http, storage::StorageValueRef, Duration},
+    traits::Saturating,
+};
+use sp_std::vec::Vec;
+
+const LOG_TARGET: &str = "pallet-reputation-ownership";
+
+/// Challenges checked per off-chain worker run
+const MAX_CHECKS_PER_BLOCK: usize = 5;
+
+/// Blocks before a challenge is checked again
+const RECHECK_BLOCKS: u32 = 10;
+
+/// Timeout of a `.dotrep` fetch
+const FETCH_TIMEOUT_MS: u64 = 5_000;
+
+/// Largest `.dotrep` file read
+const MAX_FILE_LEN: usize = 4 * 1024;
+
+impl<T: Config> Pallet<T> {
+    /// Off-chain worker: confirm open ownership challenges whose token is in the repository
+    pub fn check_ownership_challenges(now: BlockNumberFor<T>) {
+        let signer = Signer::<T, T::OwnershipAuthorityId>::any_account();
+        if !signer.can_sign() {
+            return;
+        }
+
+        let challenges = OwnershipChallenges::<T>::iter().take(MAX_CHECKS_PER_BLOCK);
+        for (project_id, (token, _)) in challenges {
+            let Some(project) = Projects::<T>::get(project_id) else { continue };
+            if !Self::mark_checked(project_id, now) {
+                continue;
+            }
+
+            let Some(url) = ownership_file_url(&project.repository) else { continue };
+            match fetch_file(&url) {
+                Ok(body) if contains_token(&body, &token) => {
+                    let result = signer.send_signed_transaction(|_| Call::confirm_project_ownership {
+                        project_id,
+                        token,
+                    });
+                    if !matches!(result, Some((_, Ok(())))) {
+                        log::warn!(target: LOG_TARGET, "Failed to submit ownership of project {}", project_id);
+                    }
+                }
+                Ok(_) => log::debug!(target: LOG_TARGET, "Token not found for project {}", project_id),
+                Err(e) => log::debug!(target: LOG_TARGET, "Fetching {:?} failed: {:?}", url, e),
+            }
+        }
+    }
+
+    /// Record in local storage that the project's challenge was checked; false if
+    /// it was checked within the last `RECHECK_BLOCKS`
+    fn mark_checked(project_id: ProjectId, now: BlockNumberFor<T>) -> bool {
+        let mut key = b"dotrep::ownership::checked::".to_vec();
+        key.extend(project_id.encode());
+
+        StorageValueRef::persistent(&key)
+            .mutate(|previous: Result<Option<BlockNumberFor<T>>, _>| match previous {
+                Ok(Some(at)) if now < at.saturating_add(RECHECK_BLOCKS.into()) => Err(()),
+                _ => Ok(now),
+            })
+            .is_ok()
+    }
+}
+
+/// URL of the `.dotrep` file on the repository's default branch
+///
+/// GitHub repositories are read through `raw.githubusercontent.com`; other hosts are
+/// assumed to serve GitLab-style `/-/raw/` paths.
+pub fn ownership_file_url(repository: &[u8]) -> Option<Vec<u8>> {
+    let repository = repository.strip_suffix(b"/").unwrap_or(repository);
+    let repository = repository.strip_suffix(b".git").unwrap_or(repository);
+
+    let mut url = Vec::with_capacity(repository.len() + 32);
+    if let Some(path) = repository.strip_prefix(b"https://github.com/") {
+        if path.iter().filter(|b| **b == b'/').count() != 1 {
+            return None;
+        }
+        url.extend_from_slice(b"https://raw.githubusercontent.com/");
+        url.extend_from_slice(path);
+        url.extend_from_slice(b"/HEAD/.dotrep");
+    } else if repository.starts_with(b"https://") {
+        url.extend_from_slice(repository);
+        url.extend_from_slice(b"/-/raw/HEAD/.dotrep");
+    } else {
+        return None;
+    }
+    Some(url)
+}
+
+/// Whether `body` contains `token` as `0x`-prefixed lowercase hex
+pub fn contains_token(body: &[u8], token: &H256) -> bool {
+    let mut needle = b"0x".to_vec();
+    crate::jsonld::push_hex(&mut needle, token.as_bytes());
+    body.windows(needle.len()).any(|window| window.eq_ignore_ascii_case(&needle))
+}
+
+fn fetch_file(url: &[u8]) -> Result<Vec<u8>, http::Error> {
+    let url = sp_std::str::from_utf8(url).map_err(|_| http::Error::Unknown)?;
+    let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(FETCH_TIMEOUT_MS));
+    let pending = http::Request::get(url)
+        .add_header("User-Agent", "DotRep/1.0")
+        .deadline(deadline)
+        .send()
+        .map_err(|_| http::Error::IoError)?;
+
+    let response = pending.try_wait(deadline).map_err(|_| http::Error::DeadlineReached)??;
+    if response.code != 200 {
+        return Err(http::Error::Unknown);
+    }
+    Ok(response.body().take(MAX_FILE_LEN).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::*;
+    use codec::Decode;
+    use frame_support::assert_ok;
+    use sp_core::offchain::{
+        testing::{PendingRequest, TestOffchainExt, TestTransactionPoolExt},
+        OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+    };
+    use sp_runtime::testing::UintAuthorityId;
+
+    #[test]
+    fn test_ownership_file_url() {
+        assert_eq!(
+            ownership_file_url(b"https://github.com/paritytech/substrate.git").unwrap(),
+            b"https://raw.githubusercontent.com/paritytech/substrate/HEAD/.dotrep".to_vec()
+        );
+        assert_eq!(
+            ownership_file_url(b"https://gitlab.com/group/project/").unwrap(),
+            b"https://gitlab.com/group/project/-/raw/HEAD/.dotrep".to_vec()
+        );
+        assert!(ownership_file_url(b"https://github.com/paritytech").is_none());
+        assert!(ownership_file_url(b"git@github.com:paritytech/substrate").is_none());
+    }
+
+    #[test]
+    fn test_offchain_worker_confirms_committed_token() {
+        let (offchain, offchain_state) = TestOffchainExt::new();
+        let (pool, pool_state) = TestTransactionPoolExt::new();
+        let mut ext = new_test_ext();
+        ext.register_extension(OffchainDbExt::new(offchain.clone()));
+        ext.register_extension(OffchainWorkerExt::new(offchain));
+        ext.register_extension(TransactionPoolExt::new(pool));
+        UintAuthorityId::set_all_keys(vec![DKG_PUBLISHER]);
+
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            let owner = 4;
+            assert_ok!(Reputation::register_project(
+                RuntimeOrigin::signed(owner),
+                b"https://github.com/paritytech/substrate".to_vec()
+            ));
+            assert_ok!(Reputation::request_ownership_challenge(RuntimeOrigin::signed(owner), 0));
+            let (token, _) = Reputation::ownership_challenge(0).unwrap();
+
+            let mut file = b"# dotrep ownership\n0x".to_vec();
+            crate::jsonld::push_hex(&mut file, token.as_bytes());
+            offchain_state.write().expect_request(PendingRequest {
+                method: "GET".into(),
+                uri: "https://raw.githubusercontent.com/paritytech/substrate/HEAD/.dotrep".into(),
+                headers: vec![("User-Agent".into(), "DotRep/1.0".into())],
+                response: Some(file),
+                sent: true,
+                ..Default::default()
+            });
+
+            Reputation::check_ownership_challenges(1);
+
+            let tx = pool_state.write().transactions.pop().expect("confirmation submitted");
+            let tx = Extrinsic::decode(&mut &*tx).unwrap();
+            assert_eq!(tx.signature.map(|(who, _)| who), Some(DKG_PUBLISHER));
+            assert_eq!(
+                tx.call,
+                RuntimeCall::Reputation(Call::confirm_project_ownership { project_id: 0, token })
+            );
+
+            // Checked challenges are not fetched again until `RECHECK_BLOCKS` have passed
+            Reputation::check_ownership_challenges(2);
+            assert!(pool_state.read().transactions.is_empty());
+        });
+    }
+}
@@ -0,0 +1,207 @@
+//! Property-based tests of the reputation algorithm
+//!
+//! Example-based tests in `tests.rs` pin individual outcomes; these check invariants
+//! that must hold for any contribution mix, algorithm parameters and starting score.
+
+use crate::mock::*;
+use crate::*;
+use frame_support::assert_ok;
+use proptest::prelude::*;
+use sp_core::H256;
+use sp_std::collections::btree_map::BTreeMap;
+
+const CONTRIBUTOR: u64 = 1;
+const VERIFIER: u64 = 2;
+
+/// Contribution as passed to `add_contribution`, minus the proof
+type Submission = (ContributionType, u8, DataSource);
+
+fn contribution_type() -> impl Strategy<Value = ContributionType> {
+    prop::sample::select(ContributionType::ALL.to_vec())
+}
+
+fn data_source() -> impl Strategy<Value = DataSource> {
+    prop_oneof![
+        Just(DataSource::GitHub),
+        Just(DataSource::GitLab),
+        Just(DataSource::Bitbucket),
+        Just(DataSource::Manual),
+    ]
+}
+
+/// Valid submissions; at most six so sequential calls stay clear of Sybil detection
+fn submissions() -> impl Strategy<Value = Vec<Submission>> {
+    prop::collection::vec((contribution_type(), 1u8..=100, data_source()), 1..=6)
+}
+
+/// Algorithm parameters with a verification multiplier of at least 1.0x
+fn algorithm_params() -> impl Strategy<Value = AlgorithmParams> {
+    (0u32..=1000, 10_000u32..=100_000, prop::collection::vec(0u32..=10_000, ContributionType::ALL.len())).prop_map(
+        |(decay_rate_per_block, verification_multiplier, weights)| AlgorithmParams {
+            decay_rate_per_block,
+            verification_multiplier,
+            contribution_type_weights: ContributionType::ALL.into_iter().zip(weights).collect::<BTreeMap<_, _>>(),
+        },
+    )
+}
+
+fn proof(index: usize) -> H256 {
+    H256::from_low_u64_be(index as u64 + 1)
+}
+
+fn setup(params: &AlgorithmParams, initial_score: i32) {
+    System::set_block_number(1);
+    ReputationParams::<Test>::put(params.clone());
+    ReputationScores::<Test>::insert(CONTRIBUTOR, initial_score);
+    ReputationScores::<Test>::insert(VERIFIER, 50);
+}
+
+fn submit_all(submissions: &[Submission]) -> Vec<ContributionId> {
+    submissions
+        .iter()
+        .enumerate()
+        .map(|(index, (contribution_type, weight, source))| {
+            assert_ok!(Reputation::add_contribution(
+                RuntimeOrigin::signed(CONTRIBUTOR),
+                proof(index),
+                contribution_type.clone(),
+                *weight,
+                source.clone(),
+                Default::default(),
+            ));
+            NextContributionId::<Test>::get()
+        })
+        .collect()
+}
+
+fn verify(contribution_id: ContributionId, score: u8) {
+    assert_ok!(Reputation::verify_contribution(
+        RuntimeOrigin::signed(VERIFIER),
+        CONTRIBUTOR,
+        contribution_id,
+        score,
+        Vec::new(),
+    ));
+}
+
+/// Storage written for the contributor by submissions and verifications
+fn contributor_state() -> (i32, Vec<Option<Contribution<Test>>>, u32, u32, ContributionId) {
+    let contributions = AccountContributions::<Test>::get(CONTRIBUTOR)
+        .into_iter()
+        .map(Contributions::<Test>::get)
+        .collect();
+    (
+        ReputationScores::<Test>::get(CONTRIBUTOR),
+        contributions,
+        PendingContributions::<Test>::get(CONTRIBUTOR),
+        ContributionCounts::<Test>::get(CONTRIBUTOR),
+        NextContributionId::<Test>::get(),
+    )
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn verified_contributions_never_lower_the_score(
+        params in algorithm_params(),
+        initial_score in MinReputation::get()..=MaxReputation::get(),
+        submissions in submissions(),
+        score in 0u8..=100,
+    ) {
+        new_test_ext().execute_with(|| -> Result<(), TestCaseError> {
+            setup(&params, initial_score);
+
+            for contribution_id in submit_all(&submissions) {
+                let before = ReputationScores::<Test>::get(CONTRIBUTOR);
+                verify(contribution_id, score);
+                prop_assert!(ReputationScores::<Test>::get(CONTRIBUTOR) >= before);
+            }
+            Ok(())
+        })?;
+    }
+
+    #[test]
+    fn decay_never_increases_the_score(
+        params in algorithm_params(),
+        submissions in submissions(),
+        elapsed in prop::collection::vec(0u64..=500_000, 1..=4),
+    ) {
+        new_test_ext().execute_with(|| -> Result<(), TestCaseError> {
+            setup(&params, MinReputation::get());
+            for contribution_id in submit_all(&submissions) {
+                verify(contribution_id, 100);
+            }
+
+            let mut block = System::block_number();
+            for blocks in elapsed {
+                let before = ReputationScores::<Test>::get(CONTRIBUTOR);
+                block += blocks;
+                System::set_block_number(block);
+                assert_ok!(Reputation::update_reputation_with_time_decay(&CONTRIBUTOR));
+                prop_assert!(ReputationScores::<Test>::get(CONTRIBUTOR) <= before);
+            }
+            Ok(())
+        })?;
+    }
+
+    #[test]
+    fn scores_stay_within_bounds(
+        params in algorithm_params(),
+        initial_score in -10_000i32..=10_000,
+        submissions in submissions(),
+        elapsed in 0u64..=2_000_000,
+    ) {
+        new_test_ext().execute_with(|| -> Result<(), TestCaseError> {
+            setup(&params, initial_score);
+            let bounds = MinReputation::get()..=MaxReputation::get();
+
+            for contribution_id in submit_all(&submissions) {
+                verify(contribution_id, 100);
+                prop_assert!(bounds.contains(&ReputationScores::<Test>::get(CONTRIBUTOR)));
+            }
+
+            System::set_block_number(System::block_number() + elapsed);
+            assert_ok!(Reputation::update_reputation_with_time_decay(&CONTRIBUTOR));
+            prop_assert!(bounds.contains(&ReputationScores::<Test>::get(CONTRIBUTOR)));
+            Ok(())
+        })?;
+    }
+
+    #[test]
+    fn batch_calls_match_sequential_calls(
+        params in algorithm_params(),
+        initial_score in MinReputation::get()..=MaxReputation::get(),
+        submissions in submissions(),
+        score in 0u8..=100,
+    ) {
+        let sequential = new_test_ext().execute_with(|| {
+            setup(&params, initial_score);
+            for contribution_id in submit_all(&submissions) {
+                verify(contribution_id, score);
+            }
+            contributor_state()
+        });
+
+        let batched = new_test_ext().execute_with(|| {
+            setup(&params, initial_score);
+            let proofs = submissions
+                .iter()
+                .enumerate()
+                .map(|(index, (contribution_type, weight, source))| {
+                    (proof(index), contribution_type.clone(), *weight, source.clone())
+                })
+                .collect();
+            assert_ok!(Reputation::batch_add_contributions(RuntimeOrigin::signed(CONTRIBUTOR), proofs));
+
+            let verifications = AccountContributions::<Test>::get(CONTRIBUTOR)
+                .into_iter()
+                .map(|contribution_id| (CONTRIBUTOR, contribution_id, score, Vec::new()))
+                .collect();
+            assert_ok!(Reputation::batch_verify_contributions(RuntimeOrigin::signed(VERIFIER), verifications));
+            contributor_state()
+        });
+
+        prop_assert_eq!(sequential, batched);
+    }
+}
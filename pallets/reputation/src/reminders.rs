@@ -0,0 +1,201 @@
+//! Verification reminders for reviewers
+//!
+//! `reminder_queue` lists the pending contributions whose deadline in
+//! `VerificationDeadlines` is within `ReminderLead` blocks, a page at a time. The chain
+//! is the source of truth for this queue; reviewer tools read it through
+//! `VerifierPoolApi::reminder_queue`. When governance configured `NotificationEndpoints`,
+//! the off-chain worker of a node holding a `T::OwnershipAuthorityId` key also POSTs each
+//! contribution entering the queue to every webhook. Once every reminder of a deadline
+//! block was delivered, it submits `note_reminders_sent`, so other nodes skip that block;
+//! a failed POST is retried on the next run, which may resend that block's reminders to
+//! the webhooks that already got them.
+
+use crate::pallet::{Call, Config, ContributionId, NotificationEndpoints, Pallet, RemindersSentUntil};
+use frame_system::{
+    offchain::{SendSignedTransaction, Signer},
+    pallet_prelude::BlockNumberFor,
+};
+use sp_runtime::{
+    offchain::{http, storage::StorageValueRef, Duration},
+    traits::{One, Saturating, UniqueSaturatedInto},
+};
+use sp_std::{vec, vec::Vec};
+
+const LOG_TARGET: &str = "pallet-reputation-reminders";
+
+/// Local storage key of the last deadline block this node announced
+const ANNOUNCED_KEY: &[u8] = b"dotrep::reminders::announced_until";
+
+/// Deadline blocks whose contributions are announced per off-chain worker run
+const MAX_DEADLINE_BLOCKS_PER_RUN: u32 = 20;
+
+/// Timeout of a webhook POST
+const NOTIFY_TIMEOUT_MS: u64 = 2_000;
+
+/// JSON body POSTed to the webhooks for a contribution nearing its deadline
+pub fn reminder_payload(contribution_id: ContributionId, deadline: u64, now: u64) -> Vec<u8> {
+    let mut body = b"{\"event\":\"verification_reminder\",\"contributionId\":".to_vec();
+    crate::jsonld::push_integer(&mut body, contribution_id as i64);
+    body.extend_from_slice(b",\"deadline\":");
+    crate::jsonld::push_integer(&mut body, deadline as i64);
+    body.extend_from_slice(b",\"blocksLeft\":");
+    crate::jsonld::push_integer(&mut body, deadline.saturating_sub(now) as i64);
+    body.push(b'}');
+    body
+}
+
+impl<T: Config> Pallet<T> {
+    /// Off-chain worker: announce contributions entering the reminder queue to the webhooks
+    pub fn send_verification_reminders(now: BlockNumberFor<T>) {
+        let endpoints = NotificationEndpoints::<T>::get();
+        if endpoints.is_empty() {
+            return;
+        }
+        let signer = Signer::<T, T::OwnershipAuthorityId>::any_account();
+        if !signer.can_sign() {
+            return;
+        }
+
+        let announced = StorageValueRef::persistent(ANNOUNCED_KEY);
+        let local = announced.get::<BlockNumberFor<T>>().ok().flatten();
+        let from = RemindersSentUntil::<T>::get()
+            .max(local)
+            .map_or(now, |until| until.max(now))
+            .saturating_add(One::one());
+        let to = now
+            .saturating_add(T::ReminderLead::get())
+            .min(from.saturating_add((MAX_DEADLINE_BLOCKS_PER_RUN - 1).into()));
+
+        let (mut sent_until, mut posted) = (None, false);
+        let mut deadline = from;
+        'blocks: while deadline <= to {
+            for (contribution_id, _) in Self::contributions_nearing_deadline(deadline, deadline) {
+                let payload = reminder_payload(
+                    contribution_id,
+                    deadline.unique_saturated_into(),
+                    now.unique_saturated_into(),
+                );
+                for endpoint in endpoints.iter() {
+                    if let Err(e) = post_reminder(endpoint, &payload) {
+                        log::debug!(target: LOG_TARGET, "Reminder of contribution {} failed: {:?}", contribution_id, e);
+                        break 'blocks;
+                    }
+                }
+                posted = true;
+            }
+            sent_until = Some(deadline);
+            deadline = deadline.saturating_add(One::one());
+        }
+
+        let Some(until) = sent_until else { return };
+        announced.set(&until);
+        if !posted {
+            return;
+        }
+        let result = signer.send_signed_transaction(|_| Call::note_reminders_sent { until });
+        if !matches!(result, Some((_, Ok(())))) {
+            log::warn!(target: LOG_TARGET, "Failed to report reminders sent up to {:?}", until);
+        }
+    }
+}
+
+fn post_reminder(endpoint: &[u8], payload: &[u8]) -> Result<(), http::Error> {
+    let url = sp_std::str::from_utf8(endpoint).map_err(|_| http::Error::Unknown)?;
+    let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(NOTIFY_TIMEOUT_MS));
+    let pending = http::Request::post(url, vec![payload])
+        .add_header("Content-Type", "application/json")
+        .deadline(deadline)
+        .send()
+        .map_err(|_| http::Error::IoError)?;
+
+    let response = pending.try_wait(deadline).map_err(|_| http::Error::DeadlineReached)??;
+    if !(200..300).contains(&response.code) {
+        return Err(http::Error::Unknown);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::*;
+    use codec::Decode;
+    use frame_support::{assert_noop, assert_ok};
+    use sp_core::offchain::{
+        testing::{PendingRequest, TestOffchainExt, TestTransactionPoolExt},
+        OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+    };
+    use sp_runtime::testing::UintAuthorityId;
+
+    #[test]
+    fn test_reminder_payload() {
+        assert_eq!(
+            reminder_payload(7, 60, 52),
+            br#"{"event":"verification_reminder","contributionId":7,"deadline":60,"blocksLeft":8}"#.to_vec()
+        );
+    }
+
+    #[test]
+    fn test_offchain_worker_announces_contributions_nearing_deadline_once() {
+        let (offchain, offchain_state) = TestOffchainExt::new();
+        let (pool, pool_state) = TestTransactionPoolExt::new();
+        let mut ext = new_test_ext();
+        ext.register_extension(OffchainDbExt::new(offchain.clone()));
+        ext.register_extension(OffchainWorkerExt::new(offchain));
+        ext.register_extension(TransactionPoolExt::new(pool));
+        UintAuthorityId::set_all_keys(vec![DKG_PUBLISHER]);
+
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(Reputation::add_contribution(
+                RuntimeOrigin::signed(1),
+                sp_core::H256::repeat_byte(7),
+                crate::pallet::ContributionType::CodeCommit,
+                10,
+                crate::pallet::DataSource::GitHub,
+                Default::default(),
+            ));
+            let deadline = 1 + VerificationDeadline::get();
+            assert_ok!(Reputation::set_notification_endpoints(
+                RuntimeOrigin::root(),
+                vec![b"https://hooks.example.org/dotrep".to_vec()],
+            ));
+
+            // Not yet within `ReminderLead` blocks of the deadline
+            Reputation::send_verification_reminders(deadline - ReminderLead::get() - 1);
+            assert!(pool_state.read().transactions.is_empty());
+
+            let now = deadline - ReminderLead::get();
+            offchain_state.write().expect_request(PendingRequest {
+                method: "POST".into(),
+                uri: "https://hooks.example.org/dotrep".into(),
+                headers: vec![("Content-Type".into(), "application/json".into())],
+                body: reminder_payload(1, deadline, now),
+                response: Some(Vec::new()),
+                sent: true,
+                ..Default::default()
+            });
+            Reputation::send_verification_reminders(now);
+
+            let tx = pool_state.write().transactions.pop().expect("progress reported");
+            let tx = Extrinsic::decode(&mut &*tx).unwrap();
+            assert_eq!(tx.signature.map(|(who, _)| who), Some(DKG_PUBLISHER));
+            assert_eq!(tx.call, RuntimeCall::Reputation(Call::note_reminders_sent { until: deadline }));
+
+            // Announced deadlines are not announced again
+            Reputation::send_verification_reminders(now + 1);
+            assert!(pool_state.read().transactions.is_empty());
+
+            // Nor by nodes that did not announce them, once the progress is on chain
+            System::set_block_number(now);
+            assert_ok!(Reputation::note_reminders_sent(RuntimeOrigin::signed(DKG_PUBLISHER), deadline));
+            assert_noop!(
+                Reputation::note_reminders_sent(RuntimeOrigin::signed(DKG_PUBLISHER), deadline),
+                crate::pallet::Error::<Test>::InvalidReminderCursor
+            );
+            StorageValueRef::persistent(ANNOUNCED_KEY).clear();
+            Reputation::send_verification_reminders(now + 1);
+            assert!(pool_state.read().transactions.is_empty());
+        });
+    }
+}
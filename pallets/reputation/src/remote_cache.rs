@@ -0,0 +1,67 @@
+//! Cache of reputation answered by remote chains
+//!
+//! Answers to `initiate_reputation_query` arrive through the XCM response handler,
+//! which records them on the query with `note_query_response`. Rather than sweeping
+//! `ReputationQueries` in block execution, the off-chain worker finds settled queries
+//! and expired `RemoteReputation` entries and submits `sync_remote_reputation`, signed
+//! with `T::OwnershipAuthorityId`, which caches the answers and removes the rest.
+
+use crate::pallet::{Call, Config, Pallet, RemoteReputation, ReputationQueries, MAX_REMOTE_SYNC_BATCH};
+use frame_support::BoundedVec;
+use frame_system::{
+    offchain::{SendSignedTransaction, Signer},
+    pallet_prelude::BlockNumberFor,
+};
+use sp_runtime::{offchain::storage::StorageValueRef, traits::Saturating};
+
+const LOG_TARGET: &str = "pallet-reputation-remote-cache";
+
+/// Blocks before the off-chain worker submits another sync
+const RESUBMIT_BLOCKS: u32 = 10;
+
+impl<T: Config> Pallet<T> {
+    /// Off-chain worker: submit settled queries and expired cache entries for removal
+    pub fn sync_remote_caches(now: BlockNumberFor<T>) {
+        let signer = Signer::<T, T::OwnershipAuthorityId>::any_account();
+        if !signer.can_sign() {
+            return;
+        }
+
+        let queries: BoundedVec<_, _> = BoundedVec::truncate_from(
+            ReputationQueries::<T>::iter()
+                .filter(|(_, query)| query.is_settled(now))
+                .map(|(query_id, _)| query_id)
+                .take(MAX_REMOTE_SYNC_BATCH as usize)
+                .collect(),
+        );
+        let expired: BoundedVec<_, _> = BoundedVec::truncate_from(
+            RemoteReputation::<T>::iter()
+                .filter(|(_, _, entry)| Self::is_remote_score_expired(entry, now))
+                .map(|(chain, account, _)| (chain, account))
+                .take(MAX_REMOTE_SYNC_BATCH as usize)
+                .collect(),
+        );
+        if (queries.is_empty() && expired.is_empty()) || !Self::mark_synced(now) {
+            return;
+        }
+
+        let result = signer.send_signed_transaction(|_| Call::sync_remote_reputation {
+            queries: queries.clone(),
+            expired: expired.clone(),
+        });
+        if !matches!(result, Some((_, Ok(())))) {
+            log::warn!(target: LOG_TARGET, "Failed to submit the remote reputation sync");
+        }
+    }
+
+    /// Record in local storage that a sync was submitted; false if one was submitted
+    /// within the last `RESUBMIT_BLOCKS`
+    fn mark_synced(now: BlockNumberFor<T>) -> bool {
+        StorageValueRef::persistent(b"dotrep::remote_cache::synced")
+            .mutate(|previous: Result<Option<BlockNumberFor<T>>, _>| match previous {
+                Ok(Some(at)) if now < at.saturating_add(RESUBMIT_BLOCKS.into()) => Err(()),
+                _ => Ok(now),
+            })
+            .is_ok()
+    }
+}
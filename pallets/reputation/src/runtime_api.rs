@@ -0,0 +1,121 @@
+//! Runtime APIs for querying reputation data off-chain
+//!
+//! Implemented by the runtime and consumed by RPC nodes, DKG gateways and the
+//! x402-paid query layer.
+
+use codec::Codec;
+use dotrep_primitives::Score;
+use sp_core::H256;
+use sp_std::vec::Vec;
+
+use crate::{
+    dkg_integration::{DkgAssets, PublishStatus},
+    pallet::{
+        ActivityStats, AlgorithmVersionInfo, ContributionId, ContributionType, DecayWarning, ReputationBreakdown,
+        VerifierAvailability, WorkHistoryEntry,
+    },
+};
+
+sp_api::decl_runtime_apis! {
+    /// Resolve on-chain accounts and contributions to their DKG Knowledge Assets
+    pub trait DkgQueryApi<AccountId, BlockNumber>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+    {
+        /// UAL of the account's reputation Knowledge Asset
+        fn developer_ual(account: AccountId) -> Option<Vec<u8>>;
+
+        /// UAL of an individually published contribution
+        fn contribution_ual(contribution_id: ContributionId) -> Option<Vec<u8>>;
+
+        /// Assertions anchored in blocks `from..=to`, at most `limit` of them
+        fn anchored_assertions(from: BlockNumber, to: BlockNumber, limit: u32) -> Vec<(Vec<u8>, H256, BlockNumber)>;
+
+        /// Publish status of the account's latest queued snapshot
+        fn publish_status(account: AccountId) -> Option<PublishStatus>;
+
+        /// Everything the chain knows about the account's DKG assets, in one call
+        fn dkg_assets(account: AccountId) -> DkgAssets<BlockNumber>;
+    }
+
+    /// Current reputation of accounts
+    pub trait ReputationApi<AccountId>
+    where
+        AccountId: Codec,
+    {
+        /// Score including any credibility boost and linked remote reputation, as seen by
+        /// governance and the trust layer
+        fn reputation_of(account: AccountId) -> Score;
+
+        /// Up to `limit` skill-matched accounts that may still verify a pending contribution
+        fn eligible_verifiers(contribution_id: ContributionId, limit: u32) -> Vec<AccountId>;
+
+        /// Per-type and per-project breakdown of the account's reputation; `None` unless
+        /// the account made it public, as callers cannot be authenticated
+        fn reputation_breakdown(account: AccountId) -> Option<ReputationBreakdown>;
+
+        /// Version of the score algorithm the scores above are computed with
+        fn algorithm_version() -> AlgorithmVersionInfo;
+    }
+
+    /// Structured work history for recruiting tools and other employer-facing services
+    pub trait WorkHistoryApi<AccountId, BlockNumber>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+    {
+        /// Verified contributions of the account submitted in blocks `from..=to`, oldest
+        /// first, optionally of one type; at most `limit` of them after skipping `offset`.
+        /// Empty unless the account made its breakdown public
+        fn work_history(
+            account: AccountId,
+            contribution_type: Option<ContributionType>,
+            from: BlockNumber,
+            to: BlockNumber,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<WorkHistoryEntry<AccountId, BlockNumber>>;
+    }
+
+    /// Decay warnings for wallets that remind contributors to stay active
+    pub trait DecayApi<AccountId, BlockNumber>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+    {
+        /// Decay projected for the account by the latest decay sweep, if it is material
+        fn decay_warning(account: AccountId) -> Option<DecayWarning<BlockNumber>>;
+    }
+
+    /// Activity counters for Sybil heuristics, dashboards and verifier-quality tracking
+    pub trait ActivityApi<AccountId, BlockNumber>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+    {
+        /// Submissions, verification outcomes and verifications performed by the account
+        fn activity_stats(account: AccountId) -> ActivityStats<BlockNumber>;
+    }
+
+    /// Verifier pool membership and expected review latency for contributor dashboards
+    pub trait VerifierPoolApi<AccountId, BlockNumber>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+    {
+        /// Capacity and skills the account offered, if it opted in to reviewing
+        fn verifier_availability(account: AccountId) -> Option<VerifierAvailability>;
+
+        /// Blocks a pending contribution can expect to wait for its missing verifications
+        fn expected_review_latency(contribution_id: ContributionId) -> Option<BlockNumber>;
+
+        /// A page of the pending contributions within `ReminderLead` blocks of their
+        /// verification deadline, with the deadline, soonest first, and the block the
+        /// next page starts from
+        ///
+        /// Pages hold about `limit` entries, at most `MAX_REMINDER_PAGE`; pass `from` = 0
+        /// for the first page.
+        fn reminder_queue(from: BlockNumber, limit: u32) -> (Vec<(ContributionId, BlockNumber)>, Option<BlockNumber>);
+    }
+}
@@ -0,0 +1,108 @@
+//! Commitments to the reputation scores for external consumers
+//!
+//! Every `SnapshotEpoch` blocks, or when governance calls `commit_reputation_snapshot`,
+//! the pallet starts a commitment to all `ReputationScores` entries: the Merkle root of
+//! the entries together with the `SnapshotTopK` highest scores. `on_initialize` adds
+//! entries within the `ScoreCommitment` hook quota, resuming from a cursor, and once
+//! every entry is covered stores the commitment in `LatestScoreCommitment` and
+//! announces it in `ScoresCommitted`. Leaves are [`score_leaf`]s in storage iteration
+//! order, so the root equals [`crate::archive::merkle_root`] of them and voting tools
+//! and bridges can check a score against the single root with a Merkle proof.
+
+use crate::pallet::{
+    Config, Error, Event, LatestScoreCommitment, Pallet, PendingScoreCommitment, ReputationScores,
+    ScoreCommitment, ScoreCommitmentInProgress,
+};
+use codec::Encode;
+use dotrep_primitives::{HookBudget, Score};
+use frame_support::{ensure, traits::Get, weights::Weight, BoundedVec};
+use sp_core::H256;
+use sp_io::hashing::blake2_256;
+use sp_runtime::DispatchResult;
+
+/// Computation of committing one entry: hashing its leaf into the frontier and placing
+/// it among the highest scores
+const SCORE_LEAF_WEIGHT: u64 = 5_000_000;
+
+/// Leaf of a committed score
+pub fn score_leaf<AccountId: Encode>(account: &AccountId, score: Score) -> H256 {
+    H256(blake2_256(&(account, score).encode()))
+}
+
+impl<T: Config> Pallet<T> {
+    /// Start a commitment to the current scores
+    pub(crate) fn start_score_commitment() -> DispatchResult {
+        ensure!(!ScoreCommitmentInProgress::<T>::exists(), Error::<T>::ScoreCommitmentInProgress);
+        ScoreCommitmentInProgress::<T>::put(PendingScoreCommitment {
+            started_at: frame_system::Pallet::<T>::block_number(),
+            cursor: BoundedVec::default(),
+            frontier: Default::default(),
+            accounts: 0,
+            top: BoundedVec::default(),
+        });
+        Ok(())
+    }
+
+    /// Weight of committing one `ReputationScores` entry
+    pub(crate) fn score_entry_weight() -> Weight {
+        T::DbWeight::get().reads(1).saturating_add(Weight::from_parts(SCORE_LEAF_WEIGHT, 0))
+    }
+
+    /// Add entries to the commitment being built within `budget`; once every entry is
+    /// covered, store the commitment and announce it
+    ///
+    /// The commitment is dropped if it would cover more than `MaxSnapshotAccounts`.
+    pub(crate) fn step_score_commitment(budget: &mut HookBudget) {
+        let Some(mut pending) = ScoreCommitmentInProgress::<T>::get() else { return };
+        let entry_weight = Self::score_entry_weight();
+        let top_k = T::SnapshotTopK::get() as usize;
+
+        let mut scores = if pending.cursor.is_empty() {
+            ReputationScores::<T>::iter()
+        } else {
+            ReputationScores::<T>::iter_from(pending.cursor.to_vec())
+        };
+        loop {
+            if !budget.try_consume(entry_weight) {
+                pending.cursor = BoundedVec::truncate_from(scores.last_raw_key().to_vec());
+                ScoreCommitmentInProgress::<T>::put(pending);
+                return;
+            }
+            let Some((account, score)) = scores.next() else { break };
+            if pending.accounts >= T::MaxSnapshotAccounts::get() {
+                ScoreCommitmentInProgress::<T>::kill();
+                Self::deposit_event(Event::ScoreCommitmentAbandoned { accounts: pending.accounts });
+                return;
+            }
+            pending.frontier.push(score_leaf(&account, score));
+            pending.accounts += 1;
+
+            // After equal scores, so they keep their storage order
+            let rank = pending.top.iter().take_while(|(_, top)| *top >= score).count();
+            if rank < top_k {
+                if pending.top.len() == top_k {
+                    pending.top.pop();
+                }
+                let _ = pending.top.try_insert(rank, (account, score));
+            }
+        }
+
+        ScoreCommitmentInProgress::<T>::kill();
+        let PendingScoreCommitment { started_at, frontier, accounts, top, .. } = pending;
+        let root = frontier.root();
+        LatestScoreCommitment::<T>::put(ScoreCommitment { block: started_at, root, accounts, top: top.clone() });
+        Self::deposit_event(Event::ScoresCommitted { root, accounts, top });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_score_leaf_commits_to_account_and_score() {
+        assert_eq!(score_leaf(&1u64, 100), H256(blake2_256(&(1u64, 100i32).encode())));
+        assert_ne!(score_leaf(&1u64, 100), score_leaf(&1u64, 101));
+        assert_ne!(score_leaf(&1u64, 100), score_leaf(&2u64, 100));
+    }
+}
@@ -2,8 +2,9 @@
 mod tests {
     use super::*;
     use crate::mock::*;
-    use frame_support::{assert_ok, assert_err, traits::{Currency, OnInitialize}};
+    use frame_support::{assert_ok, assert_err, dispatch::Pays, traits::{Currency, OnInitialize, ReservableCurrency}, BoundedVec};
     use sp_core::H256;
+    use sp_runtime::{traits::Zero, Perbill, Percent};
 
     fn setup() {
         new_test_ext().execute_with(|| {
@@ -30,6 +31,7 @@ mod tests {
                 ContributionType::CodeCommit,
                 10,
                 DataSource::GitHub,
+                Default::default(),
             ));
 
             // Get contribution ID
@@ -64,6 +66,7 @@ mod tests {
                 ContributionType::IssueComment,
                 5,
                 DataSource::GitHub,
+                Default::default(),
             ));
 
             // Should fail on duplicate submission
@@ -74,6 +77,7 @@ mod tests {
                     ContributionType::IssueComment,
                     5,
                     DataSource::GitHub,
+                    Default::default(),
                 ),
                 Error::<Test>::ContributionAlreadySubmitted
             );
@@ -99,6 +103,7 @@ mod tests {
                     ContributionType::PullRequest,
                     10,
                     DataSource::GitHub,
+                    Default::default(),
                 ));
 
                 // Verify each contribution
@@ -134,6 +139,7 @@ mod tests {
                     ContributionType::CodeCommit,
                     10,
                     DataSource::GitHub,
+                    Default::default(),
                 );
 
                 if i < 10 {
@@ -146,6 +152,40 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_sybil_rejection_refunds_unused_weight() {
+        new_test_ext().execute_with(|| {
+            frame_system::Pallet::<Test>::set_block_number(1);
+            let attacker: u64 = 999;
+
+            for i in 0..6 {
+                assert_ok!(Reputation::add_contribution(
+                    RuntimeOrigin::signed(attacker),
+                    H256::from_low_u64_be(6000 + i),
+                    ContributionType::CodeCommit,
+                    10,
+                    DataSource::GitHub,
+                    Default::default(),
+                ));
+            }
+
+            let result = Reputation::add_contribution(
+                RuntimeOrigin::signed(attacker),
+                H256::from_low_u64_be(6006),
+                ContributionType::CodeCommit,
+                10,
+                DataSource::GitHub,
+                Default::default(),
+            );
+            let err = result.expect_err("seventh submission within ten blocks is rejected");
+            assert_eq!(err.error, Error::<Test>::SybilAttackDetected.into());
+            assert_eq!(
+                err.post_info.actual_weight,
+                Some(<Test as Config>::WeightInfo::add_contribution_rejected())
+            );
+        });
+    }
+
     #[test]
     fn test_max_contributions_limit() {
         setup();
@@ -161,6 +201,7 @@ mod tests {
                     ContributionType::IssueComment,
                     5,
                     DataSource::GitHub,
+                    Default::default(),
                 );
 
                 if i < 100 {
@@ -211,6 +252,7 @@ mod tests {
                     contribution_type.clone(),
                     10,
                     DataSource::GitHub,
+                    Default::default(),
                 ));
 
                 // Verify contribution
@@ -244,6 +286,7 @@ mod tests {
                     ContributionType::IssueComment,
                     5,
                     DataSource::GitHub,
+                    Default::default(),
                 ),
                 sp_runtime::traits::BadOrigin
             );
@@ -268,6 +311,7 @@ mod tests {
                 ContributionType::PullRequest,
                 10,
                 DataSource::GitHub,
+                Default::default(),
             ));
 
             let contribution_id = NextContributionId::<Test>::get() - 1;
@@ -302,6 +346,7 @@ mod tests {
                 ContributionType::PullRequest,
                 10,
                 DataSource::GitHub,
+                Default::default(),
             ));
 
             let contribution_id = NextContributionId::<Test>::get() - 1;
@@ -342,6 +387,7 @@ mod tests {
                 ContributionType::PullRequest,
                 10,
                 DataSource::GitHub,
+                Default::default(),
             ));
 
             let contribution_id = NextContributionId::<Test>::get() - 1;
@@ -379,6 +425,43 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_missing_type_weight_is_an_error() {
+        new_test_ext().execute_with(|| {
+            frame_system::Pallet::<Test>::set_block_number(1);
+            ReputationScores::<Test>::insert(2u64, 50);
+
+            let mut params = AlgorithmParams::default();
+            params.contribution_type_weights.remove(&ContributionType::PullRequest);
+            assert_err!(
+                Reputation::update_algorithm_params(RuntimeOrigin::root(), params.clone()),
+                Error::<Test>::MissingTypeWeight
+            );
+
+            // Parameters stored before the check existed no longer fall back to a default
+            ReputationParams::<Test>::put(params);
+            assert_ok!(Reputation::add_contribution(
+                RuntimeOrigin::signed(1),
+                H256::from_low_u64_be(4242),
+                ContributionType::PullRequest,
+                10,
+                DataSource::GitHub,
+                Default::default(),
+            ));
+            assert_err!(
+                Reputation::verify_contribution(
+                    RuntimeOrigin::signed(2),
+                    1,
+                    NextContributionId::<Test>::get(),
+                    90,
+                    vec![],
+                ),
+                Error::<Test>::MissingTypeWeight
+            );
+            assert_eq!(ReputationScores::<Test>::get(1u64), 0);
+        });
+    }
+
     #[test]
     fn test_different_data_sources() {
         setup();
@@ -403,6 +486,7 @@ mod tests {
                     ContributionType::CodeCommit,
                     10,
                     source.clone(),
+                    Default::default(),
                 ));
 
                 let contribution_id = NextContributionId::<Test>::get() - 1;
@@ -411,4 +495,2963 @@ mod tests {
             }
         });
     }
+
+    #[test]
+    fn test_penalize_sybil_resets_score() {
+        setup();
+        new_test_ext().execute_with(|| {
+            let account: u64 = 1;
+            ReputationScores::<Test>::insert(account, 500);
+
+            // Signed non-governance origins are accepted by the test origin, none is not
+            assert_err!(
+                Reputation::penalize_sybil(RuntimeOrigin::none(), account, vec![]),
+                Error::<Test>::RequiresGovernance
+            );
+
+            assert_ok!(Reputation::penalize_sybil(
+                RuntimeOrigin::root(),
+                account,
+                b"Sock puppet ring".to_vec()
+            ));
+            assert_eq!(Reputation::get_reputation(&account), 0);
+        });
+    }
+
+    #[test]
+    fn test_overturn_verification_removes_awarded_points() {
+        setup();
+        new_test_ext().execute_with(|| {
+            let contributor: u64 = 1;
+            let verifier: u64 = 2;
+            ReputationScores::<Test>::insert(verifier, 50);
+
+            let proof = H256::from_low_u64_be(10_000);
+            assert_ok!(Reputation::add_contribution(
+                RuntimeOrigin::signed(contributor),
+                proof,
+                ContributionType::PullRequest,
+                100,
+                DataSource::GitHub,
+                Default::default(),
+            ));
+            let contribution_id = NextContributionId::<Test>::get();
+
+            // Cannot overturn before verification
+            assert_err!(
+                Reputation::overturn_verification(RuntimeOrigin::root(), contribution_id),
+                Error::<Test>::ContributionNotVerified
+            );
+
+            assert_ok!(Reputation::verify_contribution(
+                RuntimeOrigin::signed(verifier),
+                contributor,
+                contribution_id,
+                90,
+                vec![]
+            ));
+            assert!(Reputation::get_reputation(&contributor) > 0);
+
+            assert_ok!(Reputation::overturn_verification(RuntimeOrigin::root(), contribution_id));
+
+            let contribution = Contributions::<Test>::get(contribution_id).unwrap();
+            assert_eq!(contribution.status, ContributionStatus::Rejected);
+            assert!(!contribution.verified);
+            assert_eq!(Reputation::get_reputation(&contributor), 0);
+        });
+    }
+
+    #[test]
+    fn test_effective_reputation_applies_capped_boost() {
+        setup();
+        new_test_ext().execute_with(|| {
+            ReputationScores::<Test>::insert(BOOSTED_ACCOUNT, 200);
+            ReputationScores::<Test>::insert(1u64, 200);
+
+            // Boost is capped at MaxCredibilityBoost (50%)
+            assert_eq!(Reputation::effective_reputation(&BOOSTED_ACCOUNT), 300);
+            assert_eq!(Reputation::effective_reputation(&1), 200);
+
+            // Never exceeds MaxReputation
+            ReputationScores::<Test>::insert(BOOSTED_ACCOUNT, 900);
+            assert_eq!(Reputation::effective_reputation(&BOOSTED_ACCOUNT), 1000);
+        });
+    }
+
+    #[test]
+    fn test_claim_verdicts_adjust_reputation() {
+        setup();
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            ReputationScores::<Test>::insert(1u64, 100);
+
+            <Reputation as OnClaimResolved<u64>>::on_claim_resolved(&1, ClaimVerdict::Upheld);
+            assert_eq!(Reputation::get_reputation(&1), 125);
+            System::assert_last_event(RuntimeEvent::Reputation(Event::ReputationUpdated {
+                account: 1,
+                old_score: 100,
+                new_score: 125,
+                change_reason: RepChangeReason::ClaimUpheld,
+            }));
+
+            <Reputation as OnClaimResolved<u64>>::on_claim_resolved(&1, ClaimVerdict::Rejected);
+            assert_eq!(Reputation::get_reputation(&1), 85);
+
+            // Penalties are floored at MinReputation
+            ReputationScores::<Test>::insert(2u64, 10);
+            <Reputation as OnClaimResolved<u64>>::on_claim_resolved(&2, ClaimVerdict::Rejected);
+            assert_eq!(Reputation::get_reputation(&2), 0);
+        });
+    }
+
+    #[test]
+    fn test_credit_profile_normalizes_and_ranks_scores() {
+        new_test_ext().execute_with(|| {
+            frame_system::Pallet::<Test>::set_block_number(1);
+            Reputation::award_reputation(&10, 100, RepChangeReason::ClaimUpheld);
+            Reputation::award_reputation(&11, 500, RepChangeReason::ClaimUpheld);
+            Reputation::award_reputation(&12, 900, RepChangeReason::ClaimUpheld);
+
+            let profile = <Reputation as CreditScoreProvider<u64, u64>>::credit_profile(&11);
+            assert_eq!(profile.score, 500);
+            assert_eq!(profile.percentile, Percent::from_percent(33));
+            assert_eq!(profile.verified_contributions, 0);
+            assert_eq!(
+                <Reputation as CreditScoreProvider<u64, u64>>::credit_profile(&10).percentile,
+                Percent::zero()
+            );
+
+            // Slashing an account to zero removes it from the ranking
+            Reputation::slash_reputation(&10, 100, RepChangeReason::ClaimRejected);
+            assert_eq!(
+                <Reputation as CreditScoreProvider<u64, u64>>::credit_profile(&11).percentile,
+                Percent::zero()
+            );
+
+            ReputationScores::<Test>::insert(2u64, 50);
+            assert_ok!(Reputation::add_contribution(
+                RuntimeOrigin::signed(1),
+                H256::from_low_u64_be(31),
+                ContributionType::PullRequest,
+                10,
+                DataSource::GitHub,
+                Default::default(),
+            ));
+            assert_ok!(Reputation::verify_contribution(
+                RuntimeOrigin::signed(2),
+                1,
+                NextContributionId::<Test>::get(),
+                90,
+                vec![],
+            ));
+            frame_system::Pallet::<Test>::set_block_number(11);
+
+            let profile = <Reputation as CreditScoreProvider<u64, u64>>::credit_profile(&1);
+            assert_eq!(profile.account_age, 10);
+            assert_eq!(profile.verified_contributions, 1);
+        });
+    }
+
+    #[test]
+    fn test_genesis_reputation_is_clamped() {
+        use frame_support::traits::GenesisBuild;
+
+        let mut storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+        crate::GenesisConfig::<Test> { initial_reputation: vec![(1, 500), (2, 5_000)], ..Default::default() }
+            .assimilate_storage(&mut storage)
+            .unwrap();
+
+        sp_io::TestExternalities::new(storage).execute_with(|| {
+            assert_eq!(Reputation::get_reputation(&1), 500);
+            assert_eq!(Reputation::get_reputation(&2), MaxReputation::get());
+        });
+    }
+    #[test]
+    fn test_contributions_and_score_changes_are_offchain_indexed() {
+        use crate::indexing::{contribution_key, score_key, ContributionRecord, ScoreChangeRecord};
+        use codec::Decode;
+
+        let mut ext = new_test_ext();
+        let score_event_index = ext.execute_with(|| {
+            frame_system::Pallet::<Test>::set_block_number(1);
+            ReputationScores::<Test>::insert(2u64, 50);
+
+            assert_ok!(Reputation::add_contribution(
+                RuntimeOrigin::signed(1),
+                H256::from_low_u64_be(7),
+                ContributionType::PullRequest,
+                10,
+                DataSource::GitHub,
+                Default::default(),
+            ));
+            assert_ok!(Reputation::verify_contribution(
+                RuntimeOrigin::signed(2),
+                1,
+                NextContributionId::<Test>::get() - 1,
+                90,
+                b"LGTM".to_vec()
+            ));
+
+            frame_system::Pallet::<Test>::events()
+                .iter()
+                .position(|record| matches!(record.event, RuntimeEvent::Reputation(Event::ReputationUpdated { .. })))
+                .expect("verification updates the score") as u32
+        });
+        ext.persist_offchain_overlay();
+        let db = ext.offchain_db();
+
+        let contribution_id = ext.execute_with(|| NextContributionId::<Test>::get() - 1);
+        let raw = db.get(&contribution_key(contribution_id)).expect("contribution indexed");
+        let record = ContributionRecord::<u64, u64>::decode(&mut &raw[..]).unwrap();
+        assert_eq!(record.contributor, 1);
+        assert_eq!(record.proof, H256::from_low_u64_be(7));
+        assert_eq!(record.contribution_type, ContributionType::PullRequest);
+        assert_eq!(record.submitted_at, 1);
+
+        let raw = db.get(&score_key(&1u64, 1u64, score_event_index)).expect("score change indexed");
+        let record = ScoreChangeRecord::decode(&mut &raw[..]).unwrap();
+        assert_eq!(record.old_score, 0);
+        assert!(record.new_score > 0);
+        assert_eq!(record.reason, RepChangeReason::VerificationReward);
+    }
+
+    #[test]
+    fn test_submission_proxy_submits_for_principal_within_its_limit() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let (principal, proxy) = (1u64, 3u64);
+            let submit = |n: u64| Reputation::proxy_add_contribution(
+                RuntimeOrigin::signed(proxy),
+                principal,
+                H256::from_low_u64_be(n),
+                ContributionType::PullRequest,
+                10,
+                DataSource::GitHub,
+            );
+
+            assert_err!(submit(1), Error::<Test>::NotSubmissionProxy);
+            assert_err!(
+                Reputation::add_submission_proxy(RuntimeOrigin::signed(principal), principal, 2),
+                Error::<Test>::SelfProxyNotAllowed
+            );
+            assert_ok!(Reputation::add_submission_proxy(RuntimeOrigin::signed(principal), proxy, 2));
+
+            // Submissions belong to the principal
+            assert_ok!(submit(1));
+            let contribution_id = NextContributionId::<Test>::get();
+            assert_eq!(ContributionProofs::<Test>::get(H256::from_low_u64_be(1)), Some(principal));
+            assert_eq!(AccountContributions::<Test>::get(principal).to_vec(), vec![contribution_id]);
+            assert!(AccountContributions::<Test>::get(proxy).is_empty());
+            System::assert_last_event(RuntimeEvent::Reputation(Event::ContributionSubmittedByProxy {
+                principal,
+                proxy,
+                contribution_id,
+            }));
+
+            // Failed submissions do not use up the limit
+            assert!(submit(1).is_err());
+            assert_ok!(submit(2));
+            assert_err!(submit(3), Error::<Test>::ProxyRateLimited);
+
+            // The limit resets with the next period
+            System::set_block_number(1 + ProxySubmissionPeriod::get());
+            assert_ok!(submit(3));
+
+            assert_ok!(Reputation::remove_submission_proxy(RuntimeOrigin::signed(principal), proxy));
+            assert_err!(submit(4), Error::<Test>::NotSubmissionProxy);
+            assert_err!(
+                Reputation::remove_submission_proxy(RuntimeOrigin::signed(principal), proxy),
+                Error::<Test>::NotSubmissionProxy
+            );
+        });
+    }
+
+    #[test]
+    fn test_maintainer_attestation_counts_as_verifications_and_gates_heavy_contributions() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            MaintainerAttestationThreshold::set(50);
+            let (contributor, owner, maintainer, verifier) = (1u64, 4u64, 5u64, 2u64);
+            ReputationScores::<Test>::insert(verifier, 50);
+
+            assert_ok!(Reputation::register_project(
+                RuntimeOrigin::signed(owner),
+                b"https://github.com/paritytech/substrate".to_vec()
+            ));
+            assert_err!(
+                Reputation::register_project(
+                    RuntimeOrigin::signed(maintainer),
+                    b"https://github.com/paritytech/substrate".to_vec()
+                ),
+                Error::<Test>::RepositoryAlreadyRegistered
+            );
+            assert_err!(
+                Reputation::add_project_maintainer(RuntimeOrigin::signed(maintainer), 0, maintainer),
+                Error::<Test>::NotProjectOwner
+            );
+            assert_ok!(Reputation::add_project_maintainer(RuntimeOrigin::signed(owner), 0, maintainer));
+            assert_ok!(Reputation::request_ownership_challenge(RuntimeOrigin::signed(owner), 0));
+            let (token, _) = Reputation::ownership_challenge(0).unwrap();
+            assert_ok!(Reputation::confirm_project_ownership(RuntimeOrigin::signed(DKG_PUBLISHER), 0, token));
+
+            // A heavy contribution stays pending despite enough verifications
+            assert_ok!(Reputation::add_contribution(
+                RuntimeOrigin::signed(contributor),
+                H256::from_low_u64_be(1),
+                ContributionType::PullRequest,
+                90,
+                DataSource::GitHub,
+                Default::default(),
+            ));
+            let contribution_id = NextContributionId::<Test>::get();
+            assert_ok!(Reputation::verify_contribution(
+                RuntimeOrigin::signed(verifier),
+                contributor,
+                contribution_id,
+                90,
+                Vec::new(),
+            ));
+            assert!(!Contributions::<Test>::get(contribution_id).unwrap().verified);
+
+            assert_err!(
+                Reputation::maintainer_attest(RuntimeOrigin::signed(maintainer), contribution_id),
+                Error::<Test>::NoContributionProject
+            );
+            assert_ok!(Reputation::set_contribution_project(RuntimeOrigin::signed(contributor), contribution_id, 0));
+            assert_err!(
+                Reputation::maintainer_attest(RuntimeOrigin::signed(verifier), contribution_id),
+                Error::<Test>::NotProjectMaintainer
+            );
+
+            // Maintainers must qualify as verifiers
+            assert_err!(
+                Reputation::maintainer_attest(RuntimeOrigin::signed(maintainer), contribution_id),
+                Error::<Test>::InsufficientReputationToVerify
+            );
+            ReputationScores::<Test>::insert(maintainer, 50);
+
+            assert_ok!(Reputation::maintainer_attest(RuntimeOrigin::signed(maintainer), contribution_id));
+            let contribution = Contributions::<Test>::get(contribution_id).unwrap();
+            assert!(contribution.verified);
+            assert_eq!(contribution.verification_count, 1 + Reputation::maintainer_attestation_weight());
+            assert_eq!(Reputation::maintainer_attestation(contribution_id), Some(maintainer));
+            assert!(ReputationScores::<Test>::get(contributor) > 0);
+
+            // Removed maintainers can no longer attest
+            assert_ok!(Reputation::remove_project_maintainer(RuntimeOrigin::signed(owner), 0, maintainer));
+            assert!(!Reputation::is_project_maintainer(0, &maintainer));
+        });
+    }
+
+    #[test]
+    fn test_maintainer_attestation_alone_never_verifies() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            MinVerifications::set(3);
+            let (contributor, owner, maintainer) = (1u64, 4u64, 5u64);
+            ReputationScores::<Test>::insert(maintainer, 50);
+
+            assert_ok!(Reputation::register_project(
+                RuntimeOrigin::signed(owner),
+                b"https://github.com/paritytech/substrate".to_vec()
+            ));
+            assert_ok!(Reputation::add_project_maintainer(RuntimeOrigin::signed(owner), 0, maintainer));
+            assert_ok!(Reputation::request_ownership_challenge(RuntimeOrigin::signed(owner), 0));
+            let (token, _) = Reputation::ownership_challenge(0).unwrap();
+            assert_ok!(Reputation::confirm_project_ownership(RuntimeOrigin::signed(DKG_PUBLISHER), 0, token));
+            assert_ok!(Reputation::add_contribution(
+                RuntimeOrigin::signed(contributor),
+                H256::from_low_u64_be(1),
+                ContributionType::PullRequest,
+                30,
+                DataSource::GitHub,
+                Default::default(),
+            ));
+            let contribution_id = NextContributionId::<Test>::get();
+            assert_ok!(Reputation::set_contribution_project(RuntimeOrigin::signed(contributor), contribution_id, 0));
+
+            // `MaintainerAttestationWeight` (3) is capped one short of `MinVerifications`
+            assert_eq!(Reputation::maintainer_attestation_weight(), 2);
+            assert_ok!(Reputation::maintainer_attest(RuntimeOrigin::signed(maintainer), contribution_id));
+            let contribution = Contributions::<Test>::get(contribution_id).unwrap();
+            assert!(!contribution.verified);
+            assert_eq!(contribution.verification_count, 2);
+            assert_eq!(ReputationScores::<Test>::get(contributor), 0);
+        });
+    }
+
+    #[test]
+    fn test_ownership_challenge_must_match_to_verify_project() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let (owner, other) = (4u64, 5u64);
+            assert_ok!(Reputation::register_project(
+                RuntimeOrigin::signed(owner),
+                b"https://github.com/paritytech/substrate".to_vec()
+            ));
+
+            assert_err!(
+                Reputation::request_ownership_challenge(RuntimeOrigin::signed(other), 0),
+                Error::<Test>::NotProjectOwner
+            );
+            assert_err!(
+                Reputation::confirm_project_ownership(RuntimeOrigin::signed(DKG_PUBLISHER), 0, H256::zero()),
+                Error::<Test>::NoOwnershipChallenge
+            );
+
+            assert_ok!(Reputation::request_ownership_challenge(RuntimeOrigin::signed(owner), 0));
+            let (first, _) = Reputation::ownership_challenge(0).unwrap();
+            System::set_block_number(2);
+            assert_ok!(Reputation::request_ownership_challenge(RuntimeOrigin::signed(owner), 0));
+            let (token, _) = Reputation::ownership_challenge(0).unwrap();
+            assert_ne!(first, token);
+
+            assert_err!(
+                Reputation::confirm_project_ownership(RuntimeOrigin::signed(owner), 0, token),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            assert_err!(
+                Reputation::confirm_project_ownership(RuntimeOrigin::signed(DKG_PUBLISHER), 0, first),
+                Error::<Test>::ChallengeMismatch
+            );
+            assert_ok!(Reputation::confirm_project_ownership(RuntimeOrigin::signed(DKG_PUBLISHER), 0, token));
+            assert_eq!(Reputation::projects(0).unwrap().status, ProjectStatus::Verified);
+            assert!(Reputation::ownership_challenge(0).is_none());
+            assert_err!(
+                Reputation::request_ownership_challenge(RuntimeOrigin::signed(owner), 0),
+                Error::<Test>::ProjectAlreadyVerified
+            );
+        });
+    }
+
+    #[test]
+    fn test_unverified_project_can_be_replaced_after_expiry() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let (squatter, owner, maintainer) = (4u64, 5u64, 6u64);
+            let repository = b"https://github.com/paritytech/substrate".to_vec();
+            assert_ok!(Reputation::register_project(RuntimeOrigin::signed(squatter), repository.clone()));
+            assert_ok!(Reputation::add_project_maintainer(RuntimeOrigin::signed(squatter), 0, maintainer));
+            assert_ok!(Reputation::request_ownership_challenge(RuntimeOrigin::signed(squatter), 0));
+
+            // The claim holds the repository while its owner may still prove control
+            System::set_block_number(1 + ProjectClaimExpiry::get() - 1);
+            assert_err!(
+                Reputation::register_project(RuntimeOrigin::signed(owner), repository.clone()),
+                Error::<Test>::RepositoryAlreadyRegistered
+            );
+
+            System::set_block_number(1 + ProjectClaimExpiry::get());
+            assert_ok!(Reputation::register_project(RuntimeOrigin::signed(owner), repository.clone()));
+            System::assert_has_event(RuntimeEvent::Reputation(Event::ProjectReleased { project_id: 0 }));
+            assert!(Reputation::projects(0).is_none());
+            assert!(Reputation::ownership_challenge(0).is_none());
+            assert!(!Reputation::is_project_maintainer(0, &maintainer));
+            assert_eq!(Reputation::projects(1).unwrap().owner, owner);
+
+            // A verified project keeps its repository for good
+            assert_ok!(Reputation::request_ownership_challenge(RuntimeOrigin::signed(owner), 1));
+            let (token, _) = Reputation::ownership_challenge(1).unwrap();
+            assert_ok!(Reputation::confirm_project_ownership(RuntimeOrigin::signed(DKG_PUBLISHER), 1, token));
+            System::set_block_number(1 + 3 * ProjectClaimExpiry::get());
+            assert_err!(
+                Reputation::register_project(RuntimeOrigin::signed(squatter), repository),
+                Error::<Test>::RepositoryAlreadyRegistered
+            );
+        });
+    }
+
+    #[test]
+    fn test_co_authors_share_verification_reward() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let (contributor, verifier, co_author, unbound) = (1u64, 2u64, 3u64, 4u64);
+            ReputationScores::<Test>::insert(verifier, 50);
+            assert_ok!(DKGPallet::attest_github_handle(
+                RuntimeOrigin::signed(DKG_PUBLISHER),
+                co_author,
+                b"octocat".to_vec()
+            ));
+            let submit = |co_authors: Vec<(u64, Percent)>| Reputation::add_contribution(
+                RuntimeOrigin::signed(contributor),
+                H256::from_low_u64_be(20_000),
+                ContributionType::PullRequest,
+                80,
+                DataSource::GitHub,
+                co_authors.try_into().unwrap(),
+            );
+
+            assert_err!(submit(vec![(unbound, Percent::from_percent(40))]), Error::<Test>::CoAuthorNotBound);
+            assert_err!(
+                submit(vec![(co_author, Percent::from_percent(20)), (co_author, Percent::from_percent(20))]),
+                Error::<Test>::DuplicateCoAuthor
+            );
+            assert_err!(submit(vec![(contributor, Percent::from_percent(40))]), Error::<Test>::DuplicateCoAuthor);
+            assert_err!(submit(vec![(co_author, Percent::from_percent(100))]), Error::<Test>::InvalidCoAuthorShares);
+            assert_err!(submit(vec![(co_author, Percent::zero())]), Error::<Test>::InvalidCoAuthorShares);
+
+            assert_ok!(submit(vec![(co_author, Percent::from_percent(40))]));
+            let contribution_id = NextContributionId::<Test>::get();
+            assert_eq!(Reputation::co_authors(contribution_id).to_vec(), vec![(co_author, Percent::from_percent(40))]);
+
+            assert_ok!(Reputation::verify_contribution(
+                RuntimeOrigin::signed(verifier),
+                contributor,
+                contribution_id,
+                90,
+                vec![]
+            ));
+            let (own, shared) = (ReputationScores::<Test>::get(contributor), ReputationScores::<Test>::get(co_author));
+            assert!(shared > 0);
+            assert_eq!(shared, (own + shared) * 40 / 100);
+
+            // Overturning removes the reputation from every author
+            assert_ok!(Reputation::overturn_verification(RuntimeOrigin::root(), contribution_id));
+            assert_eq!(ReputationScores::<Test>::get(contributor), 0);
+            assert_eq!(ReputationScores::<Test>::get(co_author), 0);
+        });
+    }
+
+    #[test]
+    fn test_skill_matched_verifiers_are_routed_and_count_extra() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let (contributor, rust_dev, low_rep, docs_writer) = (1u64, 2u64, 3u64, 4u64);
+            let skill = |tag: &[u8]| -> SkillTag { tag.to_vec().try_into().unwrap() };
+            for verifier in [rust_dev, docs_writer] {
+                ReputationScores::<Test>::insert(verifier, 50);
+            }
+            Reputation::on_skill_tags_updated(&rust_dev, &[], &[skill(b"rust")]);
+            Reputation::on_skill_tags_updated(&low_rep, &[], &[skill(b"rust")]);
+            Reputation::on_skill_tags_updated(&contributor, &[], &[skill(b"rust")]);
+            Reputation::on_skill_tags_updated(&docs_writer, &[], &[skill(b"docs")]);
+            for verifier in [rust_dev, docs_writer] {
+                assert_ok!(Reputation::opt_in_verifier(RuntimeOrigin::signed(verifier), 5, Default::default()));
+            }
+
+            assert_ok!(Reputation::add_contribution(
+                RuntimeOrigin::signed(contributor),
+                H256::from_low_u64_be(30_000),
+                ContributionType::PullRequest,
+                50,
+                DataSource::GitHub,
+                Default::default(),
+            ));
+            let contribution_id = NextContributionId::<Test>::get();
+            assert!(Reputation::eligible_verifiers(contribution_id, 10).is_empty());
+
+            assert_err!(
+                Reputation::tag_contribution_skills(
+                    RuntimeOrigin::signed(rust_dev),
+                    contribution_id,
+                    vec![skill(b"rust")].try_into().unwrap()
+                ),
+                Error::<Test>::ContributionNotFound
+            );
+            assert_ok!(Reputation::tag_contribution_skills(
+                RuntimeOrigin::signed(contributor),
+                contribution_id,
+                vec![skill(b"rust")].try_into().unwrap()
+            ));
+
+            // The contributor and accounts below `MinReputationToVerify` are not eligible
+            assert_eq!(Reputation::eligible_verifiers(contribution_id, 10), vec![rust_dev]);
+            assert!(Reputation::is_skill_matched(&rust_dev, contribution_id));
+            assert!(!Reputation::is_skill_matched(&docs_writer, contribution_id));
+
+            // Dropping a skill removes the account from the index
+            Reputation::on_skill_tags_updated(&rust_dev, &[skill(b"rust")], &[skill(b"docs")]);
+            assert!(Reputation::eligible_verifiers(contribution_id, 10).is_empty());
+            Reputation::on_skill_tags_updated(&rust_dev, &[skill(b"docs")], &[skill(b"rust")]);
+
+            assert_ok!(Reputation::verify_contribution(
+                RuntimeOrigin::signed(rust_dev),
+                contributor,
+                contribution_id,
+                90,
+                vec![]
+            ));
+            let contribution = Reputation::contributions(contribution_id).unwrap();
+            assert_eq!(contribution.verification_count, SkillMatchMultiplier::get());
+            assert!(Reputation::eligible_verifiers(contribution_id, 10).is_empty());
+        });
+    }
+
+    #[test]
+    fn test_contribution_deposits_are_held_and_released() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            ContributionDeposit::set(100);
+            let (contributor, verifier, unfunded) = (1u64, 2u64, 4u64);
+            ReputationScores::<Test>::insert(verifier, 50);
+            let submit = |who: u64, proof: u64| Reputation::add_contribution(
+                RuntimeOrigin::signed(who),
+                H256::from_low_u64_be(proof),
+                ContributionType::PullRequest,
+                50,
+                DataSource::GitHub,
+                Default::default(),
+            );
+
+            assert_err!(submit(unfunded, 40_000), Error::<Test>::InsufficientDeposit);
+
+            assert_ok!(submit(contributor, 40_000));
+            let withdrawn = NextContributionId::<Test>::get();
+            assert_eq!(Balances::reserved_balance(contributor), 100);
+            assert_eq!(Reputation::contribution_deposit(withdrawn), Some((contributor, 100)));
+
+            assert_err!(
+                Reputation::withdraw_contribution(RuntimeOrigin::signed(verifier), withdrawn),
+                Error::<Test>::ContributionNotFound
+            );
+            assert_ok!(Reputation::withdraw_contribution(RuntimeOrigin::signed(contributor), withdrawn));
+            assert_eq!(Balances::reserved_balance(contributor), 0);
+            assert!(Reputation::contributions(withdrawn).is_none());
+            assert!(!AccountContributions::<Test>::get(contributor).contains(&withdrawn));
+
+            // A withdrawn proof can be submitted again
+            assert_ok!(submit(contributor, 40_000));
+            let rejected = NextContributionId::<Test>::get();
+            assert_ok!(Reputation::verify_contribution(
+                RuntimeOrigin::signed(verifier),
+                contributor,
+                rejected,
+                90,
+                vec![]
+            ));
+            assert_err!(
+                Reputation::withdraw_contribution(RuntimeOrigin::signed(contributor), rejected),
+                Error::<Test>::ContributionNotWithdrawable
+            );
+            assert_err!(
+                Reputation::prune_contribution(RuntimeOrigin::signed(verifier), rejected),
+                Error::<Test>::ContributionNotPrunable
+            );
+
+            // Anyone may prune once the contribution is rejected; the deposit goes back to its holder
+            assert_ok!(Reputation::overturn_verification(RuntimeOrigin::root(), rejected));
+            assert_ok!(Reputation::prune_contribution(RuntimeOrigin::signed(verifier), rejected));
+            assert_eq!(Balances::reserved_balance(contributor), 0);
+            assert!(Reputation::contributions(rejected).is_none());
+            assert_err!(submit(contributor, 40_000), Error::<Test>::ContributionAlreadySubmitted);
+        });
+    }
+
+    #[test]
+    fn test_migration_holds_deposits_for_existing_contributions() {
+        use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let (funded, unfunded) = (1u64, 4u64);
+            for (who, proof) in [(funded, 50_000u64), (unfunded, 50_001)] {
+                assert_ok!(Reputation::add_contribution(
+                    RuntimeOrigin::signed(who),
+                    H256::from_low_u64_be(proof),
+                    ContributionType::PullRequest,
+                    50,
+                    DataSource::GitHub,
+                    Default::default(),
+                ));
+            }
+            let first = NextContributionId::<Test>::get() - 1;
+
+            // One contribution is visited per block
+            use frame_support::traits::Get;
+            let max_block = <Test as frame_system::Config>::BlockWeights::get().max_block;
+            assert_ok!(Reputation::set_hook_quota(
+                RuntimeOrigin::root(),
+                dotrep_primitives::HookTask::Migrations,
+                Perbill::from_rational(crate::migrations::ITEM_BASE_WEIGHT.ref_time(), max_block.ref_time())
+            ));
+            ContributionDeposit::set(100);
+            StorageVersion::new(0).put::<Reputation>();
+            crate::migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+            assert_eq!(Reputation::on_chain_storage_version(), 1);
+            assert!(crate::pallet::DepositMigrationCursor::<Test>::exists());
+            System::set_block_number(2);
+            Reputation::on_initialize(2);
+            assert!(!crate::pallet::DepositMigrationCursor::<Test>::exists());
+            System::assert_has_event(RuntimeEvent::Reputation(Event::DepositMigrationCompleted));
+            assert_eq!(Balances::reserved_balance(funded), 100);
+            assert_eq!(Reputation::contribution_deposit(first), Some((funded, 100)));
+            // Contributors who cannot cover the deposit are grandfathered
+            assert!(Reputation::contribution_deposit(first + 1).is_none());
+        });
+    }
+
+    #[test]
+    fn test_purge_my_data_keeps_score_and_archive_root() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            ContributionDeposit::set(100);
+            let (contributor, verifier) = (1u64, 2u64);
+            ReputationScores::<Test>::insert(verifier, 50);
+            let submit = |proof: u64| Reputation::add_contribution(
+                RuntimeOrigin::signed(contributor),
+                H256::from_low_u64_be(proof),
+                ContributionType::PullRequest,
+                50,
+                DataSource::GitHub,
+                Default::default(),
+            );
+
+            assert_err!(Reputation::purge_my_data(RuntimeOrigin::signed(contributor)), Error::<Test>::NothingToArchive);
+
+            assert_ok!(submit(60_000));
+            let verified = NextContributionId::<Test>::get();
+            assert_ok!(Reputation::verify_contribution(
+                RuntimeOrigin::signed(verifier),
+                contributor,
+                verified,
+                90,
+                vec![]
+            ));
+            assert_ok!(submit(60_001));
+            let pending = NextContributionId::<Test>::get();
+            let score = ReputationScores::<Test>::get(contributor);
+            let leaf = crate::archive::contribution_leaf(&Reputation::contributions(verified).unwrap());
+
+            // Verified records stay reportable for `DisputeWindow` blocks
+            assert_err!(Reputation::purge_my_data(RuntimeOrigin::signed(contributor)), Error::<Test>::NothingToArchive);
+            System::set_block_number(1 + DisputeWindow::get());
+
+            assert_ok!(Reputation::purge_my_data(RuntimeOrigin::signed(contributor)));
+
+            // Settled records go, the score, counts and pending contributions stay
+            assert!(Reputation::contributions(verified).is_none());
+            assert!(Reputation::contributions(pending).is_some());
+            assert_eq!(AccountContributions::<Test>::get(contributor).to_vec(), vec![pending]);
+            assert_eq!(ReputationScores::<Test>::get(contributor), score);
+            assert_eq!(VerifiedContributionCounts::<Test>::get(contributor), 1);
+            assert_eq!(Balances::reserved_balance(contributor), 100);
+
+            let archive = Reputation::contribution_archive(contributor).unwrap();
+            assert_eq!(archive.root, crate::archive::merkle_root(vec![leaf]));
+            assert_eq!(archive.contributions, 1);
+            assert!(DKGPallet::publishing_queue(contributor).is_some());
+            assert_eq!(
+                Reputation::reputation_snapshot(&contributor).contribution_digests,
+                vec![archive.root.to_fixed_bytes()]
+            );
+
+            // Purged proofs cannot be resubmitted
+            assert_err!(submit(60_000), Error::<Test>::ContributionAlreadySubmitted);
+        });
+    }
+
+    #[test]
+    fn test_hackathon_tallies_verified_entries_and_pays_out_at_close() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let (organizer, alice, bob, verifier) = (1u64, 2u64, 3u64, 4u64);
+            ReputationScores::<Test>::insert(verifier, 50);
+            assert_ok!(Reputation::register_project(
+                RuntimeOrigin::signed(organizer),
+                b"https://github.com/dotrep/hackathon".to_vec()
+            ));
+            assert_err!(
+                Reputation::create_hackathon(RuntimeOrigin::signed(organizer), 5, 4, vec![0].try_into().unwrap(), 1_000),
+                Error::<Test>::InvalidHackathonWindow
+            );
+            assert_ok!(Reputation::create_hackathon(
+                RuntimeOrigin::signed(organizer),
+                1,
+                10,
+                vec![0].try_into().unwrap(),
+                1_000
+            ));
+            assert_eq!(Balances::reserved_balance(organizer), 1_000);
+
+            let enter = |who: u64, proof: u64, weight: u8, project: Option<ProjectId>| {
+                assert_ok!(Reputation::add_contribution(
+                    RuntimeOrigin::signed(who),
+                    H256::from_low_u64_be(proof),
+                    ContributionType::PullRequest,
+                    weight,
+                    DataSource::GitHub,
+                    Default::default(),
+                ));
+                let contribution_id = NextContributionId::<Test>::get();
+                if let Some(project_id) = project {
+                    assert_ok!(Reputation::set_contribution_project(
+                        RuntimeOrigin::signed(who),
+                        contribution_id,
+                        project_id
+                    ));
+                }
+                (contribution_id, Reputation::enter_hackathon(RuntimeOrigin::signed(who), contribution_id, 0))
+            };
+            let (unlinked, result) = enter(alice, 70_000, 50, None);
+            assert_err!(result, Error::<Test>::ProjectNotInHackathon);
+            let (alice_entry, result) = enter(alice, 70_001, 80, Some(0));
+            assert_ok!(result);
+            let (bob_entry, result) = enter(bob, 70_002, 40, Some(0));
+            assert_ok!(result);
+
+            for (contributor, contribution_id) in [(alice, alice_entry), (bob, bob_entry), (alice, unlinked)] {
+                assert_ok!(Reputation::verify_contribution(
+                    RuntimeOrigin::signed(verifier),
+                    contributor,
+                    contribution_id,
+                    90,
+                    vec![]
+                ));
+            }
+
+            // Only entered contributions count towards the leaderboard
+            let (alice_points, bob_points) = (Reputation::hackathon_score(0, alice), Reputation::hackathon_score(0, bob));
+            assert!(alice_points > bob_points && bob_points > 0);
+            assert_eq!(
+                Reputation::hackathon_leaderboard(0).to_vec(),
+                vec![(alice, alice_points), (bob, bob_points)]
+            );
+
+            assert_err!(
+                Reputation::close_hackathon(RuntimeOrigin::signed(bob), 0),
+                Error::<Test>::HackathonNotEnded
+            );
+            System::set_block_number(11);
+            let (_, result) = enter(bob, 70_003, 40, Some(0));
+            assert_err!(result, Error::<Test>::HackathonNotOpen);
+
+            let alice_before = Balances::free_balance(alice);
+            assert_ok!(Reputation::close_hackathon(RuntimeOrigin::signed(bob), 0));
+            let alice_reward = Perbill::from_rational(alice_points, alice_points + bob_points) * 1_000;
+            assert_eq!(Balances::free_balance(alice), alice_before + alice_reward);
+            assert_eq!(Balances::reserved_balance(organizer), 0);
+            assert_err!(
+                Reputation::close_hackathon(RuntimeOrigin::signed(bob), 0),
+                Error::<Test>::HackathonClosed
+            );
+        });
+    }
+
+    #[test]
+    fn test_mentorship_sessions_earn_mentor_reputation_until_co_signed_completion() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let (mentor, mentee, verifier) = (1u64, 2u64, 3u64);
+            ReputationScores::<Test>::insert(verifier, 50);
+            let attest = |who: u64, proof: u64| {
+                Reputation::attest_mentorship_session(RuntimeOrigin::signed(who), 0, H256::from_low_u64_be(proof))
+            };
+
+            assert_err!(
+                Reputation::register_mentorship(RuntimeOrigin::signed(mentor), mentor),
+                Error::<Test>::CannotMentorSelf
+            );
+            assert_ok!(Reputation::register_mentorship(RuntimeOrigin::signed(mentor), mentee));
+            assert_err!(attest(mentee, 80_000), Error::<Test>::InvalidMentorshipStatus);
+            assert_err!(Reputation::accept_mentorship(RuntimeOrigin::signed(mentor), 0), Error::<Test>::NotMentee);
+            assert_ok!(Reputation::accept_mentorship(RuntimeOrigin::signed(mentee), 0));
+
+            assert_err!(attest(mentor, 80_000), Error::<Test>::NotMentee);
+            assert_ok!(attest(mentee, 80_000));
+            let contribution_id = NextContributionId::<Test>::get();
+            let contribution = Reputation::contributions(contribution_id).unwrap();
+            assert_eq!(contribution.contribution_type, ContributionType::Mentorship);
+            assert_eq!(contribution.weight, MentorshipSessionWeight::get());
+            assert_eq!(AccountContributions::<Test>::get(mentor).to_vec(), vec![contribution_id]);
+
+            // The session earns reputation through the usual verification
+            assert_ok!(Reputation::verify_contribution(
+                RuntimeOrigin::signed(verifier),
+                mentor,
+                contribution_id,
+                90,
+                vec![]
+            ));
+            assert!(ReputationScores::<Test>::get(mentor) > 0);
+
+            assert_ok!(Reputation::sign_off_mentorship(RuntimeOrigin::signed(mentor), 0));
+            assert_err!(
+                Reputation::sign_off_mentorship(RuntimeOrigin::signed(mentor), 0),
+                Error::<Test>::AlreadySignedOff
+            );
+            assert_err!(
+                Reputation::sign_off_mentorship(RuntimeOrigin::signed(verifier), 0),
+                Error::<Test>::NotMentorshipParty
+            );
+            assert_ok!(Reputation::sign_off_mentorship(RuntimeOrigin::signed(mentee), 0));
+
+            let mentorship = Reputation::mentorship(0).unwrap();
+            assert_eq!(mentorship.status, MentorshipStatus::Completed);
+            assert_eq!(mentorship.sessions, 1);
+            assert_eq!(Reputation::completed_mentorships(mentor), 1);
+            assert_err!(attest(mentee, 80_001), Error::<Test>::InvalidMentorshipStatus);
+        });
+    }
+
+    #[test]
+    fn test_mentor_can_end_mentorship_to_stop_sessions() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let (mentor, mentee, other) = (1u64, 2u64, 3u64);
+            let attest = |mentorship_id: MentorshipId, proof: u64| {
+                Reputation::attest_mentorship_session(
+                    RuntimeOrigin::signed(mentee),
+                    mentorship_id,
+                    H256::from_low_u64_be(proof),
+                )
+            };
+
+            // A proposal can be withdrawn before the mentee accepts it
+            assert_ok!(Reputation::register_mentorship(RuntimeOrigin::signed(mentor), mentee));
+            assert_ok!(Reputation::end_mentorship(RuntimeOrigin::signed(mentor), 0));
+            assert_err!(
+                Reputation::accept_mentorship(RuntimeOrigin::signed(mentee), 0),
+                Error::<Test>::InvalidMentorshipStatus
+            );
+
+            assert_ok!(Reputation::register_mentorship(RuntimeOrigin::signed(mentor), mentee));
+            assert_ok!(Reputation::accept_mentorship(RuntimeOrigin::signed(mentee), 1));
+            assert_ok!(attest(1, 80_000));
+            assert_err!(
+                Reputation::end_mentorship(RuntimeOrigin::signed(other), 1),
+                Error::<Test>::NotMentorshipParty
+            );
+            assert_ok!(Reputation::end_mentorship(RuntimeOrigin::signed(mentor), 1));
+            System::assert_last_event(RuntimeEvent::Reputation(Event::MentorshipEnded { mentorship_id: 1, who: mentor }));
+
+            let mentorship = Reputation::mentorship(1).unwrap();
+            assert_eq!(mentorship.status, MentorshipStatus::Ended);
+            assert_eq!(mentorship.sessions, 1);
+            assert_err!(attest(1, 80_001), Error::<Test>::InvalidMentorshipStatus);
+            assert_err!(
+                Reputation::sign_off_mentorship(RuntimeOrigin::signed(mentee), 1),
+                Error::<Test>::InvalidMentorshipStatus
+            );
+            assert_err!(
+                Reputation::end_mentorship(RuntimeOrigin::signed(mentee), 1),
+                Error::<Test>::InvalidMentorshipStatus
+            );
+            assert_eq!(Reputation::completed_mentorships(mentor), 0);
+        });
+    }
+
+    #[test]
+    fn test_referral_bonus_paid_on_qualification_and_clawed_back_for_sybils() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let (referrer, referee, verifier) = (1u64, 4u64, 3u64);
+            ReputationScores::<Test>::insert(verifier, 50);
+            let verified_contribution = |who: u64, proof: u64| {
+                assert_ok!(Reputation::add_contribution(
+                    RuntimeOrigin::signed(who),
+                    H256::from_low_u64_be(proof),
+                    ContributionType::CodeCommit,
+                    10,
+                    DataSource::GitHub,
+                    Default::default(),
+                ));
+                let contribution_id = NextContributionId::<Test>::get();
+                assert_ok!(Reputation::verify_contribution(
+                    RuntimeOrigin::signed(verifier),
+                    who,
+                    contribution_id,
+                    90,
+                    vec![]
+                ));
+            };
+
+            assert_err!(Reputation::refer(RuntimeOrigin::signed(referrer), referee), Error::<Test>::NotAContributor);
+            verified_contribution(referrer, 90_000);
+            assert_err!(Reputation::refer(RuntimeOrigin::signed(referrer), referrer), Error::<Test>::CannotReferSelf);
+            assert_err!(Reputation::refer(RuntimeOrigin::signed(referrer), verifier), Error::<Test>::NotANewcomer);
+            assert_ok!(Reputation::refer(RuntimeOrigin::signed(referrer), referee));
+            assert_err!(
+                Reputation::refer(RuntimeOrigin::signed(referrer), referee),
+                Error::<Test>::ReferralAlreadyOffered
+            );
+
+            // The referral only takes effect once the referee accepts it
+            assert!(Reputation::referral(referee).is_none());
+            assert_err!(
+                Reputation::accept_referral(RuntimeOrigin::signed(referee), verifier),
+                Error::<Test>::NoReferralOffer
+            );
+            assert_ok!(Reputation::accept_referral(RuntimeOrigin::signed(referee), referrer));
+            assert_eq!(Reputation::referral(referee).unwrap().referrer, referrer);
+            assert!(Reputation::referral_offer(referee, referrer).is_none());
+            assert_err!(Reputation::refer(RuntimeOrigin::signed(referrer), referee), Error::<Test>::NotANewcomer);
+
+            // Referrals are capped per epoch
+            assert_ok!(Reputation::refer(RuntimeOrigin::signed(referrer), 5));
+            assert_err!(Reputation::refer(RuntimeOrigin::signed(referrer), 6), Error::<Test>::ReferralCapReached);
+            System::set_block_number(ReferralEpoch::get());
+            assert_ok!(Reputation::refer(RuntimeOrigin::signed(referrer), 6));
+
+            // The bonus waits for the referee's qualifying contributions
+            let before = ReputationScores::<Test>::get(referrer);
+            verified_contribution(referee, 90_001);
+            assert_eq!(ReputationScores::<Test>::get(referrer), before);
+            verified_contribution(referee, 90_002);
+            assert_eq!(ReputationScores::<Test>::get(referrer), before + ReferralBonus::get());
+            assert!(Reputation::referral(referee).unwrap().rewarded);
+
+            verified_contribution(referee, 90_003);
+            assert_eq!(ReputationScores::<Test>::get(referrer), before + ReferralBonus::get());
+
+            assert_ok!(Reputation::penalize_sybil(RuntimeOrigin::root(), referee, vec![]));
+            assert_eq!(ReputationScores::<Test>::get(referrer), before);
+            assert!(Reputation::referral(referee).is_none());
+        });
+    }
+
+    #[test]
+    fn test_probation_halves_earnings_and_revokes_verification_rights() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let (offender, other, verifier) = (1u64, 2u64, 3u64);
+            ReputationScores::<Test>::insert(verifier, 50);
+            let submit = |who: u64, proof: u64| {
+                assert_ok!(Reputation::add_contribution(
+                    RuntimeOrigin::signed(who),
+                    H256::from_low_u64_be(proof),
+                    ContributionType::CodeCommit,
+                    10,
+                    DataSource::GitHub,
+                    Default::default(),
+                ));
+                NextContributionId::<Test>::get()
+            };
+            let verified_gain = |who: u64, proof: u64| {
+                let contribution_id = submit(who, proof);
+                let before = ReputationScores::<Test>::get(who);
+                assert_ok!(Reputation::verify_contribution(
+                    RuntimeOrigin::signed(verifier),
+                    who,
+                    contribution_id,
+                    90,
+                    vec![]
+                ));
+                ReputationScores::<Test>::get(who) - before
+            };
+
+            assert_ok!(Reputation::penalize_sybil(RuntimeOrigin::root(), offender, vec![]));
+            assert_eq!(Reputation::probation_end(offender), Some(1 + ProbationPeriod::get()));
+            assert!(<Reputation as ReputationProvider<u64>>::is_on_probation(&offender));
+
+            // Regained reputation does not restore verification rights during probation
+            ReputationScores::<Test>::insert(offender, 50);
+            let contribution_id = submit(other, 91_000);
+            assert_err!(
+                Reputation::verify_contribution(RuntimeOrigin::signed(offender), other, contribution_id, 90, vec![]),
+                Error::<Test>::OnProbation
+            );
+
+            let halved = verified_gain(offender, 91_001);
+            System::set_block_number(1 + ProbationPeriod::get());
+            let full = verified_gain(offender, 91_002);
+            assert!(halved > 0);
+            assert_eq!(halved, full / 2);
+        });
+    }
+
+    #[test]
+    fn test_breakdown_is_served_only_to_allowed_viewers() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let (contributor, viewer, verifier) = (1u64, 2u64, 3u64);
+            ReputationScores::<Test>::insert(verifier, 50);
+
+            assert_ok!(Reputation::register_project(
+                RuntimeOrigin::signed(contributor),
+                b"https://github.com/paritytech/substrate".to_vec()
+            ));
+            assert_ok!(Reputation::add_contribution(
+                RuntimeOrigin::signed(contributor),
+                H256::from_low_u64_be(92_000),
+                ContributionType::CodeCommit,
+                10,
+                DataSource::GitHub,
+                Default::default(),
+            ));
+            let contribution_id = NextContributionId::<Test>::get();
+            assert_ok!(Reputation::set_contribution_project(RuntimeOrigin::signed(contributor), contribution_id, 0));
+            assert_ok!(Reputation::verify_contribution(
+                RuntimeOrigin::signed(verifier),
+                contributor,
+                contribution_id,
+                90,
+                vec![]
+            ));
+            let points = ReputationScores::<Test>::get(contributor);
+
+            let own = Reputation::reputation_breakdown(&contributor, Some(&contributor)).unwrap();
+            assert_eq!(own.by_type, vec![(ContributionType::CodeCommit, points)]);
+            assert_eq!(own.by_project, vec![(0, points)]);
+
+            assert!(Reputation::reputation_breakdown(&contributor, Some(&viewer)).is_none());
+            assert_ok!(Reputation::grant_breakdown_access(RuntimeOrigin::signed(contributor), viewer));
+            assert_eq!(Reputation::reputation_breakdown(&contributor, Some(&viewer)), Some(own));
+            assert!(Reputation::reputation_breakdown(&contributor, Some(&verifier)).is_none());
+
+            assert_ok!(Reputation::revoke_breakdown_access(RuntimeOrigin::signed(contributor), viewer));
+            assert!(Reputation::reputation_breakdown(&contributor, Some(&viewer)).is_none());
+            assert_err!(
+                Reputation::revoke_breakdown_access(RuntimeOrigin::signed(contributor), viewer),
+                Error::<Test>::BreakdownViewerNotFound
+            );
+
+            // Unauthenticated callers such as the runtime APIs see public breakdowns only
+            assert!(Reputation::reputation_breakdown(&contributor, None).is_none());
+            assert_ok!(Reputation::set_breakdown_public(RuntimeOrigin::signed(contributor), true));
+            System::assert_last_event(RuntimeEvent::Reputation(Event::BreakdownVisibilitySet {
+                account: contributor,
+                public: true,
+            }));
+            assert!(Reputation::reputation_breakdown(&contributor, None).is_some());
+            assert!(Reputation::reputation_snapshot(&contributor).breakdown.is_empty());
+            assert_ok!(Reputation::set_breakdown_public(RuntimeOrigin::signed(contributor), false));
+            assert!(Reputation::reputation_breakdown(&contributor, None).is_none());
+        });
+    }
+
+    #[test]
+    fn test_work_history_filters_and_pages_verified_contributions() {
+        new_test_ext().execute_with(|| {
+            let (contributor, verifier) = (1u64, 3u64);
+            ReputationScores::<Test>::insert(verifier, 50);
+            let submit = |block: u64, proof: u64, contribution_type: ContributionType| {
+                System::set_block_number(block);
+                assert_ok!(Reputation::add_contribution(
+                    RuntimeOrigin::signed(contributor),
+                    H256::from_low_u64_be(proof),
+                    contribution_type,
+                    10,
+                    DataSource::GitHub,
+                    Default::default(),
+                ));
+                NextContributionId::<Test>::get()
+            };
+            let verify = |contribution_id| {
+                assert_ok!(Reputation::verify_contribution(
+                    RuntimeOrigin::signed(verifier),
+                    contributor,
+                    contribution_id,
+                    80,
+                    vec![]
+                ));
+            };
+
+            let first = submit(1, 93_000, ContributionType::CodeCommit);
+            let second = submit(20, 93_001, ContributionType::CodeReview);
+            let third = submit(40, 93_002, ContributionType::CodeCommit);
+            let _pending = submit(60, 93_003, ContributionType::CodeCommit);
+            [first, second, third].into_iter().for_each(verify);
+
+            let ids = |entries: Vec<WorkHistoryEntry<u64, u64>>| {
+                entries.into_iter().map(|entry| entry.contribution_id).collect::<Vec<_>>()
+            };
+            assert_eq!(ids(Reputation::work_history(&contributor, Some(&contributor), None, 0, 100, 0, 10)), vec![first, second, third]);
+            assert_eq!(
+                ids(Reputation::work_history(&contributor, Some(&contributor), Some(ContributionType::CodeCommit), 0, 100, 0, 10)),
+                vec![first, third]
+            );
+            assert_eq!(ids(Reputation::work_history(&contributor, Some(&contributor), None, 10, 40, 0, 10)), vec![second, third]);
+            assert_eq!(ids(Reputation::work_history(&contributor, Some(&contributor), None, 0, 100, 1, 1)), vec![second]);
+
+            // Like the breakdown, the history is only served to viewers the contributor allowed
+            let employer = 7u64;
+            assert!(Reputation::work_history(&contributor, Some(&employer), None, 0, 100, 0, 10).is_empty());
+            assert_ok!(Reputation::grant_breakdown_access(RuntimeOrigin::signed(contributor), employer));
+            assert_eq!(ids(Reputation::work_history(&contributor, Some(&employer), None, 0, 100, 0, 10)), vec![first, second, third]);
+
+            let entry = &Reputation::work_history(&contributor, Some(&contributor), None, 0, 0, 0, 1)[0];
+            assert_eq!(entry.verifications, vec![(verifier, 80)]);
+            assert_eq!(entry.submitted_at, 1);
+            assert!(entry.points > 0);
+        });
+    }
+
+    #[test]
+    fn test_established_contributors_get_a_fee_less_quota_per_era() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let (established, newcomer) = (1u64, 2u64);
+            ReputationScores::<Test>::insert(established, FeelessReputationThreshold::get());
+            let submit = |who: u64, proof: u64| {
+                Reputation::add_contribution(
+                    RuntimeOrigin::signed(who),
+                    H256::from_low_u64_be(proof),
+                    ContributionType::CodeCommit,
+                    10,
+                    DataSource::GitHub,
+                    Default::default(),
+                )
+                .unwrap()
+                .pays_fee
+            };
+
+            assert_eq!(Reputation::feeless_calls_left(&newcomer), 0);
+            assert_eq!(submit(newcomer, 94_000), Pays::Yes);
+
+            assert_eq!(Reputation::feeless_calls_left(&established), FeelessCallsPerEra::get());
+            assert_eq!(submit(established, 94_001), Pays::No);
+            // Verifications draw on the same quota
+            let contribution_id = NextContributionId::<Test>::get() - 1;
+            let verification = Reputation::verify_contribution(
+                RuntimeOrigin::signed(established),
+                newcomer,
+                contribution_id,
+                90,
+                vec![],
+            )
+            .unwrap();
+            assert_eq!(verification.pays_fee, Pays::No);
+            assert_eq!(submit(established, 94_002), Pays::Yes);
+
+            System::set_block_number(FeelessEra::get());
+            assert_eq!(submit(established, 94_003), Pays::No);
+        });
+    }
+
+    #[test]
+    fn test_high_value_contributions_need_committee_approvals() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            CommitteeVerificationThreshold::set(80);
+            let contributor = 1u64;
+            let committee = [2u64, 3, 4];
+            ReputationScores::<Test>::insert(2, 50);
+
+            assert_err!(
+                Reputation::set_verifier_committee(RuntimeOrigin::root(), BoundedVec::truncate_from(vec![2, 3])),
+                Error::<Test>::InvalidCommittee
+            );
+            assert_err!(
+                Reputation::set_verifier_committee(RuntimeOrigin::root(), BoundedVec::truncate_from(vec![2, 3, 3])),
+                Error::<Test>::InvalidCommittee
+            );
+            assert_ok!(Reputation::set_verifier_committee(
+                RuntimeOrigin::root(),
+                BoundedVec::truncate_from(committee.to_vec())
+            ));
+
+            assert_ok!(Reputation::add_contribution(
+                RuntimeOrigin::signed(contributor),
+                H256::from_low_u64_be(95_000),
+                ContributionType::PullRequest,
+                90,
+                DataSource::GitHub,
+                Default::default(),
+            ));
+            let contribution_id = NextContributionId::<Test>::get();
+
+            // Independent verifications do not count for high-value contributions
+            assert_err!(
+                Reputation::verify_contribution(RuntimeOrigin::signed(2), contributor, contribution_id, 90, vec![]),
+                Error::<Test>::RequiresCommitteeApproval
+            );
+            assert_err!(
+                Reputation::approve_high_value_contribution(RuntimeOrigin::signed(5), contribution_id),
+                Error::<Test>::NotCommitteeMember
+            );
+
+            let approve =
+                |member: u64| Reputation::approve_high_value_contribution(RuntimeOrigin::signed(member), contribution_id);
+            assert_ok!(approve(2));
+            assert_err!(approve(2), Error::<Test>::AlreadyApproved);
+            assert_ok!(approve(3));
+            assert!(!Reputation::contributions(contribution_id).unwrap().verified);
+            assert_ok!(approve(4));
+
+            assert!(Reputation::contributions(contribution_id).unwrap().verified);
+            assert!(ReputationScores::<Test>::get(contributor) > 0);
+            assert_eq!(Reputation::committee_approvals(contribution_id).to_vec(), committee.to_vec());
+        });
+    }
+
+    #[test]
+    fn test_dividend_is_split_by_capped_era_earnings() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let pot = Reputation::dividend_account();
+            let _ = Balances::deposit_creating(&pot, 10_000);
+
+            Reputation::award_reputation(&1, 30, RepChangeReason::ClaimUpheld);
+            // Earnings past `DividendEarningsCap` do not grow the share
+            Reputation::award_reputation(&2, 80, RepChangeReason::ClaimUpheld);
+            assert_eq!(Reputation::era_earnings(0, 2), DividendEarningsCap::get());
+            assert_eq!(Reputation::era_total_earnings(0), 80);
+
+            assert_err!(
+                Reputation::claim_dividend(RuntimeOrigin::signed(1), 0),
+                Error::<Test>::DividendEraNotEnded
+            );
+
+            System::set_block_number(DividendEra::get());
+            let before = Balances::free_balance(1);
+            assert_ok!(Reputation::claim_dividend(RuntimeOrigin::signed(1), 0));
+            assert_eq!(Balances::free_balance(1) - before, 375);
+            assert_ok!(Reputation::claim_dividend(RuntimeOrigin::signed(2), 0));
+            assert_eq!(Balances::free_balance(pot), 10_000 - DividendBudget::get());
+
+            assert_err!(Reputation::claim_dividend(RuntimeOrigin::signed(1), 0), Error::<Test>::NoDividend);
+            assert_err!(Reputation::claim_dividend(RuntimeOrigin::signed(3), 0), Error::<Test>::NoDividend);
+        });
+    }
+
+    #[test]
+    fn test_dividends_are_reversed_with_lost_reputation() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let pot = Reputation::dividend_account();
+            let _ = Balances::deposit_creating(&pot, 10_000);
+
+            // Losses in the era come off its earnings
+            Reputation::award_reputation(&1, 40, RepChangeReason::ClaimUpheld);
+            Reputation::award_reputation(&2, 20, RepChangeReason::ClaimUpheld);
+            Reputation::slash_reputation(&1, 10, RepChangeReason::ClaimRejected);
+            assert_eq!(Reputation::era_earnings(0, 1), 30);
+            assert_eq!(Reputation::era_total_earnings(0), 50);
+
+            System::set_block_number(DividendEra::get());
+            assert_ok!(Reputation::claim_dividend(RuntimeOrigin::signed(1), 0));
+            assert_eq!(Reputation::paid_dividends(1), (30, 600));
+
+            // Later losses claw back the dividends paid for them
+            Reputation::slash_reputation(&1, 15, RepChangeReason::ClaimRejected);
+            System::assert_last_event(RuntimeEvent::Reputation(Event::DividendClawedBack { account: 1, amount: 300 }));
+            assert_eq!(Reputation::paid_dividends(1), (15, 300));
+            Reputation::slash_reputation(&1, 20, RepChangeReason::ClaimRejected);
+            assert_eq!(Reputation::paid_dividends(1), (0, 0));
+            assert_eq!(Balances::free_balance(pot), 10_000);
+
+            // Losses of unclaimed eras offset their claims
+            Reputation::slash_reputation(&2, 5, RepChangeReason::ClaimRejected);
+            assert_eq!(Reputation::dividend_debt(2), 5);
+            let before = Balances::free_balance(2);
+            assert_ok!(Reputation::claim_dividend(RuntimeOrigin::signed(2), 0));
+            assert_eq!(Balances::free_balance(2) - before, 300);
+            assert_eq!(Reputation::dividend_debt(2), 0);
+        });
+    }
+
+    #[test]
+    fn test_pending_contributions_go_stale_and_escalate() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let (contributor, verifier) = (1u64, 3u64);
+            ReputationScores::<Test>::insert(verifier, 50);
+            for proof in [95_000, 95_001] {
+                assert_ok!(Reputation::add_contribution(
+                    RuntimeOrigin::signed(contributor),
+                    H256::from_low_u64_be(proof),
+                    ContributionType::CodeCommit,
+                    10,
+                    DataSource::GitHub,
+                    Default::default(),
+                ));
+            }
+            let (first, second) = (NextContributionId::<Test>::get() - 1, NextContributionId::<Test>::get());
+
+            let deadline = 1 + VerificationDeadline::get();
+            Reputation::on_initialize(deadline);
+            assert_eq!(Reputation::stale_contribution(first), Some(deadline));
+            assert_eq!(Reputation::stale_contribution(second), Some(deadline));
+
+            // Verifying a stale contribution earns the verifier a bonus
+            assert_ok!(Reputation::verify_contribution(
+                RuntimeOrigin::signed(verifier),
+                contributor,
+                first,
+                90,
+                vec![]
+            ));
+            assert_eq!(ReputationScores::<Test>::get(verifier), 50 + StaleVerifierReward::get());
+            assert_eq!(Reputation::stale_contribution(first), None);
+
+            let escalation = deadline + EscalationPeriod::get();
+            Reputation::on_initialize(escalation);
+            assert_eq!(Reputation::stale_contribution(second), None);
+            assert_eq!(Reputation::escalated_contribution(second), Some(escalation));
+            assert_eq!(Reputation::escalated_contribution(first), None);
+
+            assert_err!(
+                Reputation::triage_escalated_contribution(RuntimeOrigin::root(), first, true),
+                Error::<Test>::NotEscalated
+            );
+            assert_ok!(Reputation::triage_escalated_contribution(RuntimeOrigin::root(), second, true));
+            assert!(Contributions::<Test>::get(second).unwrap().verified);
+            assert_eq!(Reputation::escalated_contribution(second), None);
+        });
+    }
+
+    #[test]
+    fn test_submission_fails_when_no_deadline_slot_is_free() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let deadline = 1 + VerificationDeadline::get();
+            let full: Vec<ContributionId> = vec![0; MaxDeadlinesPerBlock::get() as usize];
+            for block in deadline..=deadline + DEADLINE_SLOT_SEARCH as u64 {
+                VerificationDeadlines::<Test>::insert(block, BoundedVec::truncate_from(full.clone()));
+            }
+
+            assert_err!(
+                Reputation::add_contribution(
+                    RuntimeOrigin::signed(1),
+                    H256::from_low_u64_be(95_100),
+                    ContributionType::CodeCommit,
+                    10,
+                    DataSource::GitHub,
+                    Default::default(),
+                ),
+                Error::<Test>::DeadlineSlotsFull
+            );
+
+            // The deadline goes to the first block with room
+            VerificationDeadlines::<Test>::remove(deadline + DEADLINE_SLOT_SEARCH as u64);
+            assert_ok!(Reputation::add_contribution(
+                RuntimeOrigin::signed(1),
+                H256::from_low_u64_be(95_100),
+                ContributionType::CodeCommit,
+                10,
+                DataSource::GitHub,
+                Default::default(),
+            ));
+            let contribution_id = NextContributionId::<Test>::get();
+            let stale_at = deadline + DEADLINE_SLOT_SEARCH as u64;
+            Reputation::on_initialize(stale_at);
+            assert_eq!(Reputation::stale_contribution(contribution_id), Some(stale_at));
+        });
+    }
+
+    #[test]
+    fn test_verifier_rejections_reject_contribution_and_slash_deposit() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            ContributionDeposit::set(100);
+            let contributor = 1u64;
+            for verifier in [2u64, 3, 4] {
+                ReputationScores::<Test>::insert(verifier, 50);
+            }
+            assert_ok!(Reputation::add_contribution(
+                RuntimeOrigin::signed(contributor),
+                H256::from_low_u64_be(96_000),
+                ContributionType::CodeCommit,
+                10,
+                DataSource::GitHub,
+                Default::default(),
+            ));
+            let contribution_id = NextContributionId::<Test>::get();
+            let comment: RejectionComment = b"links to someone else's commit".to_vec().try_into().unwrap();
+            let reject = |verifier: u64| {
+                Reputation::reject_contribution(
+                    RuntimeOrigin::signed(verifier),
+                    contribution_id,
+                    RejectionReason::NotOriginal,
+                    comment.clone(),
+                )
+            };
+
+            assert_ok!(reject(2));
+            assert_err!(reject(2), Error::<Test>::ContributionAlreadyRejected);
+            assert_eq!(Contributions::<Test>::get(contribution_id).unwrap().status, ContributionStatus::Pending);
+
+            assert_ok!(reject(3));
+            assert_eq!(Reputation::rejection_count(contribution_id), RejectionThreshold::get());
+            assert_eq!(Contributions::<Test>::get(contribution_id).unwrap().status, ContributionStatus::Rejected);
+            assert_eq!(PendingContributions::<Test>::get(contributor), 0);
+            assert_eq!(Balances::reserved_balance(contributor), 50);
+
+            assert_err!(reject(4), Error::<Test>::ContributionNotPending);
+            assert_err!(
+                Reputation::verify_contribution(RuntimeOrigin::signed(4), contributor, contribution_id, 90, vec![]),
+                Error::<Test>::ContributionNotPending
+            );
+
+            // Pruning returns what is left of the deposit
+            assert_ok!(Reputation::prune_contribution(RuntimeOrigin::signed(4), contribution_id));
+            assert_eq!(Balances::reserved_balance(contributor), 0);
+            assert_eq!(Balances::free_balance(contributor), 1_000_000 - 50);
+        });
+    }
+
+    #[test]
+    fn test_awarded_points_follow_reputation_weighted_consensus() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            MinVerifications::set(3);
+            let contributor = 1u64;
+            // A careless low-reputation verifier is outweighed by the two established ones
+            let votes = [(2u64, 10, 100), (3, 50, 60), (4, 50, 70)];
+            for (verifier, reputation, _) in votes {
+                ReputationScores::<Test>::insert(verifier, reputation);
+            }
+            assert_ok!(Reputation::add_contribution(
+                RuntimeOrigin::signed(contributor),
+                H256::from_low_u64_be(97_000),
+                ContributionType::PullRequest,
+                100,
+                DataSource::GitHub,
+                Default::default(),
+            ));
+            let contribution_id = NextContributionId::<Test>::get();
+
+            for (verifier, _, score) in votes {
+                assert_ok!(Reputation::verify_contribution(
+                    RuntimeOrigin::signed(verifier),
+                    contributor,
+                    contribution_id,
+                    score,
+                    vec![]
+                ));
+            }
+
+            assert_eq!(Reputation::consensus_score(contribution_id), Some(70));
+            // PullRequest weight 20 x 1.5 verification multiplier, at 70%
+            assert_eq!(ReputationScores::<Test>::get(contributor), 21);
+        });
+    }
+
+    #[test]
+    fn test_outlying_verifiers_lose_rights_and_accurate_ones_gain() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            MinVerifications::set(3);
+            let (contributor, outlier) = (1u64, 2u64);
+            for verifier in [2u64, 3, 4] {
+                ReputationScores::<Test>::insert(verifier, 50);
+            }
+            let submit_and_verify = |proof: u64| {
+                assert_ok!(Reputation::add_contribution(
+                    RuntimeOrigin::signed(contributor),
+                    H256::from_low_u64_be(proof),
+                    ContributionType::CodeCommit,
+                    10,
+                    DataSource::GitHub,
+                    Default::default(),
+                ));
+                let contribution_id = NextContributionId::<Test>::get();
+                for (verifier, score) in [(2u64, 5), (3, 70), (4, 70)] {
+                    assert_ok!(Reputation::verify_contribution(
+                        RuntimeOrigin::signed(verifier),
+                        contributor,
+                        contribution_id,
+                        score,
+                        vec![]
+                    ));
+                }
+                assert_eq!(Reputation::consensus_score(contribution_id), Some(70));
+            };
+
+            submit_and_verify(98_000);
+            assert_eq!(
+                Reputation::verifier_record(outlier),
+                VerifierRecord { finalized: 1, total_deviation: 65, strikes: 1 }
+            );
+            assert_eq!(ReputationScores::<Test>::get(outlier), 50 - OutlierPenalty::get());
+            assert_eq!(ReputationScores::<Test>::get(3), 50 + AccuracyBonus::get());
+            assert_eq!(Reputation::verifier_record(3).strikes, 0);
+
+            submit_and_verify(98_001);
+            assert!(!Reputation::has_verification_rights(&outlier));
+            System::assert_has_event(RuntimeEvent::Reputation(Event::VerificationRightsRevoked { verifier: outlier }));
+
+            assert_ok!(Reputation::add_contribution(
+                RuntimeOrigin::signed(contributor),
+                H256::from_low_u64_be(98_002),
+                ContributionType::CodeCommit,
+                10,
+                DataSource::GitHub,
+                Default::default(),
+            ));
+            let contribution_id = NextContributionId::<Test>::get();
+            assert_err!(
+                Reputation::verify_contribution(RuntimeOrigin::signed(outlier), contributor, contribution_id, 70, vec![]),
+                Error::<Test>::VerificationRightsRevoked
+            );
+
+            assert_ok!(Reputation::reset_verifier_record(RuntimeOrigin::root(), outlier));
+            assert!(Reputation::has_verification_rights(&outlier));
+        });
+    }
+
+    #[test]
+    fn test_amended_verifications_are_scored_on_their_original_vote() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            MinVerifications::set(3);
+            let (contributor, outlier) = (1u64, 2u64);
+            for verifier in [2u64, 3, 4] {
+                ReputationScores::<Test>::insert(verifier, 50);
+            }
+            assert_ok!(Reputation::add_contribution(
+                RuntimeOrigin::signed(contributor),
+                H256::from_low_u64_be(98_100),
+                ContributionType::CodeCommit,
+                10,
+                DataSource::GitHub,
+                Default::default(),
+            ));
+            let contribution_id = NextContributionId::<Test>::get();
+            for (verifier, score) in [(2u64, 5), (3, 70)] {
+                assert_ok!(Reputation::verify_contribution(
+                    RuntimeOrigin::signed(verifier),
+                    contributor,
+                    contribution_id,
+                    score,
+                    vec![]
+                ));
+            }
+
+            // Amending towards the visible votes does not make the outlier accurate
+            assert_ok!(Reputation::amend_verification(RuntimeOrigin::signed(outlier), contribution_id, 70));
+            assert_ok!(Reputation::verify_contribution(
+                RuntimeOrigin::signed(4),
+                contributor,
+                contribution_id,
+                70,
+                vec![]
+            ));
+            assert_eq!(Reputation::consensus_score(contribution_id), Some(70));
+            assert_eq!(Reputation::verifier_record(outlier).strikes, 1);
+            assert_eq!(ReputationScores::<Test>::get(outlier), 50 - OutlierPenalty::get());
+        });
+    }
+
+    #[test]
+    fn test_leave_pauses_time_decay_until_revoked() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            ReputationParams::<Test>::put(AlgorithmParams { decay_rate_per_block: 1000, ..Default::default() });
+            let (on_leave, active, verifier) = (1u64, 2u64, 3u64);
+            ReputationScores::<Test>::insert(verifier, 50);
+            for (contributor, proof) in [(on_leave, 99_000), (active, 99_001)] {
+                assert_ok!(Reputation::add_contribution(
+                    RuntimeOrigin::signed(contributor),
+                    H256::from_low_u64_be(proof),
+                    ContributionType::CodeCommit,
+                    100,
+                    DataSource::GitHub,
+                    Default::default(),
+                ));
+                assert_ok!(Reputation::verify_contribution(
+                    RuntimeOrigin::signed(verifier),
+                    contributor,
+                    NextContributionId::<Test>::get(),
+                    90,
+                    vec![]
+                ));
+            }
+
+            assert_err!(
+                Reputation::announce_leave(RuntimeOrigin::signed(on_leave), MaxLeaveDuration::get() + 1),
+                Error::<Test>::InvalidLeaveDuration
+            );
+            assert_ok!(Reputation::announce_leave(RuntimeOrigin::signed(on_leave), MaxLeaveDuration::get()));
+            System::set_block_number(2);
+            assert_err!(
+                Reputation::announce_leave(RuntimeOrigin::signed(on_leave), 1),
+                Error::<Test>::LeaveCooldownActive
+            );
+
+            System::set_block_number(1 + 2 * MaxLeaveDuration::get());
+            for who in [on_leave, active] {
+                assert_ok!(Reputation::update_reputation_with_time_decay(&who));
+            }
+            assert!(ReputationScores::<Test>::get(on_leave) > ReputationScores::<Test>::get(active));
+
+            assert_ok!(Reputation::revoke_leave(RuntimeOrigin::root(), on_leave));
+            assert_ok!(Reputation::update_reputation_with_time_decay(&on_leave));
+            assert_eq!(ReputationScores::<Test>::get(on_leave), ReputationScores::<Test>::get(active));
+        });
+    }
+
+    #[test]
+    fn test_score_commitment_roots_all_scores_and_lists_top_k() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            for (account, score) in [(1u64, 50), (2, 400), (3, 120), (4, 400), (5, 10)] {
+                ReputationScores::<Test>::insert(account, score);
+            }
+
+            assert_err!(
+                Reputation::commit_reputation_snapshot(RuntimeOrigin::signed(1)),
+                Error::<Test>::RequiresGovernance
+            );
+            // Two entries fit in each block's quota
+            use frame_support::traits::Get;
+            let max_block = <Test as frame_system::Config>::BlockWeights::get().max_block;
+            assert_ok!(Reputation::set_hook_quota(
+                RuntimeOrigin::root(),
+                dotrep_primitives::HookTask::ScoreCommitment,
+                Perbill::from_rational(2 * Reputation::score_entry_weight().ref_time(), max_block.ref_time())
+            ));
+            assert_ok!(Reputation::commit_reputation_snapshot(RuntimeOrigin::root()));
+            assert_err!(
+                Reputation::commit_reputation_snapshot(RuntimeOrigin::root()),
+                Error::<Test>::ScoreCommitmentInProgress
+            );
+            for block in 2..=3 {
+                Reputation::on_initialize(block);
+                assert!(Reputation::score_commitment().is_none());
+            }
+            Reputation::on_initialize(4);
+
+            let commitment = Reputation::score_commitment().unwrap();
+            assert_eq!(commitment.block, 1);
+            let leaves = ReputationScores::<Test>::iter()
+                .map(|(account, score)| crate::snapshot::score_leaf(&account, score))
+                .collect();
+            assert_eq!(commitment.root, crate::archive::merkle_root(leaves));
+            assert_eq!(commitment.accounts, 5);
+            // `SnapshotTopK` is 3 in the mock
+            let top_scores: Vec<_> = commitment.top.iter().map(|(_, score)| *score).collect();
+            assert_eq!(top_scores, vec![400, 400, 120]);
+            System::assert_last_event(
+                Event::ScoresCommitted { root: commitment.root, accounts: 5, top: commitment.top }.into(),
+            );
+        });
+    }
+
+    #[test]
+    fn test_evm_export_is_signed_by_the_attestation_signer() {
+        use crate::evm_bridge::{attestation_message, recover_signer};
+        use sp_core::{ecdsa, Pair, H160};
+        use sp_io::hashing::keccak_256;
+
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            pallet_timestamp::Pallet::<Test>::set_timestamp(2_000_000);
+            ReputationScores::<Test>::insert(1u64, 350);
+            let bridge = H160::repeat_byte(0xbb);
+
+            assert_ok!(Reputation::register_evm_chain(RuntimeOrigin::root(), 1, Some(bridge)));
+            assert_err!(Reputation::request_evm_export(RuntimeOrigin::signed(1), 2), Error::<Test>::UnknownEvmChain);
+            assert_ok!(Reputation::request_evm_export(RuntimeOrigin::signed(1), 1));
+
+            let export = Reputation::pending_evm_export(1u64, 1).unwrap();
+            assert_eq!(export.bridge, bridge);
+            assert_eq!(export.address, crate::evm_bridge::evm_address_of(&1u64));
+            assert_eq!(export.score, 350);
+            assert_eq!(export.expiry, 2_000 + EvmAttestationValidity::get());
+
+            let message = attestation_message(1, &export);
+            let signature = ecdsa::Pair::from_seed(&[7; 32]).sign_prehashed(&keccak_256(&message));
+            let forged = ecdsa::Pair::from_seed(&[8; 32]).sign_prehashed(&keccak_256(&message));
+
+            // Nothing is accepted before governance sets the signer
+            assert_err!(
+                Reputation::submit_evm_attestation(RuntimeOrigin::signed(2), 1, 1, signature.clone()),
+                Error::<Test>::InvalidEvmAttestationSignature
+            );
+            let signer = recover_signer(&message, &signature).unwrap();
+            assert_ok!(Reputation::set_evm_attestation_signer(RuntimeOrigin::root(), signer));
+            assert_err!(
+                Reputation::submit_evm_attestation(RuntimeOrigin::signed(2), 1, 1, forged),
+                Error::<Test>::InvalidEvmAttestationSignature
+            );
+
+            assert_ok!(Reputation::submit_evm_attestation(RuntimeOrigin::signed(2), 1, 1, signature.clone()));
+            assert!(Reputation::pending_evm_export(1u64, 1).is_none());
+            System::assert_last_event(
+                Event::EvmAttestationSigned { account: 1, chain_id: 1, nonce: 0, message, signature }.into(),
+            );
+        });
+    }
+
+    #[test]
+    fn test_git_log_import_completes_after_attestation_and_validation() {
+        use codec::Encode;
+
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let (contributor, owner, maintainer, verifier) = (1u64, 4u64, 5u64, 2u64);
+            ReputationScores::<Test>::insert(verifier, 50);
+            ReputationScores::<Test>::insert(maintainer, 50);
+
+            assert_ok!(Reputation::register_project(
+                RuntimeOrigin::signed(owner),
+                b"https://git.example.org/group/project".to_vec()
+            ));
+            assert_ok!(Reputation::add_project_maintainer(RuntimeOrigin::signed(owner), 0, maintainer));
+            assert_ok!(Reputation::request_ownership_challenge(RuntimeOrigin::signed(owner), 0));
+            let (token, _) = Reputation::ownership_challenge(0).unwrap();
+            assert_ok!(Reputation::confirm_project_ownership(RuntimeOrigin::signed(DKG_PUBLISHER), 0, token));
+
+            let link = |commit: u8, parent: u8| GitCommitLink { commit: [commit; 20], parent: [parent; 20] };
+            assert_err!(
+                Reputation::import_git_log(
+                    RuntimeOrigin::signed(contributor),
+                    0,
+                    ContributionType::PullRequest,
+                    40,
+                    Default::default()
+                ),
+                Error::<Test>::EmptyGitLog
+            );
+            let commits: BoundedVec<_, MaxGitLogEntries> = vec![link(3, 2), link(2, 1)].try_into().unwrap();
+            assert_ok!(Reputation::import_git_log(
+                RuntimeOrigin::signed(contributor),
+                0,
+                ContributionType::PullRequest,
+                40,
+                commits.clone()
+            ));
+            let contribution_id = NextContributionId::<Test>::get();
+            let contribution = Contributions::<Test>::get(contribution_id).unwrap();
+            assert_eq!(contribution.source, DataSource::Manual);
+            assert_eq!(contribution.proof, H256(sp_io::hashing::blake2_256(&commits.encode())));
+
+            // Verifications do not complete an import before its log is validated
+            assert_ok!(Reputation::verify_contribution(
+                RuntimeOrigin::signed(verifier),
+                contributor,
+                contribution_id,
+                100,
+                Vec::new(),
+            ));
+            assert!(!Contributions::<Test>::get(contribution_id).unwrap().verified);
+            assert_err!(
+                Reputation::confirm_git_import(RuntimeOrigin::signed(DKG_PUBLISHER), contribution_id, true),
+                Error::<Test>::GitImportNotAttested
+            );
+
+            assert_ok!(Reputation::maintainer_attest(RuntimeOrigin::signed(maintainer), contribution_id));
+            assert!(!Contributions::<Test>::get(contribution_id).unwrap().verified);
+            assert_ok!(Reputation::confirm_git_import(RuntimeOrigin::signed(DKG_PUBLISHER), contribution_id, true));
+            assert!(Contributions::<Test>::get(contribution_id).unwrap().verified);
+
+            // Imports earn `GitImportPointsPercent` of the points of other contributions
+            assert_ok!(Reputation::add_contribution(
+                RuntimeOrigin::signed(3),
+                H256::from_low_u64_be(1),
+                ContributionType::PullRequest,
+                40,
+                DataSource::GitHub,
+                Default::default(),
+            ));
+            assert_ok!(Reputation::verify_contribution(
+                RuntimeOrigin::signed(verifier),
+                3,
+                NextContributionId::<Test>::get(),
+                100,
+                Vec::new(),
+            ));
+            assert!(Reputation::get_reputation(&3) > 0);
+            assert_eq!(Reputation::get_reputation(&contributor), Reputation::get_reputation(&3) * 50 / 100);
+        });
+    }
+
+    #[test]
+    fn test_git_log_import_needs_independent_verifiers() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            MinVerifications::set(2);
+            let (contributor, owner, maintainer) = (1u64, 4u64, 5u64);
+            for account in [maintainer, 2, 3] {
+                ReputationScores::<Test>::insert(account, 50);
+            }
+            assert_ok!(Reputation::register_project(
+                RuntimeOrigin::signed(owner),
+                b"https://git.example.org/group/project".to_vec()
+            ));
+            assert_ok!(Reputation::add_project_maintainer(RuntimeOrigin::signed(owner), 0, maintainer));
+            assert_ok!(Reputation::request_ownership_challenge(RuntimeOrigin::signed(owner), 0));
+            let (token, _) = Reputation::ownership_challenge(0).unwrap();
+            assert_ok!(Reputation::confirm_project_ownership(RuntimeOrigin::signed(DKG_PUBLISHER), 0, token));
+
+            let link = |commit: u8, parent: u8| GitCommitLink { commit: [commit; 20], parent: [parent; 20] };
+            assert_ok!(Reputation::import_git_log(
+                RuntimeOrigin::signed(contributor),
+                0,
+                ContributionType::PullRequest,
+                40,
+                vec![link(3, 2), link(2, 1)].try_into().unwrap()
+            ));
+            let contribution_id = NextContributionId::<Test>::get();
+            let verify = |verifier: u64| {
+                Reputation::verify_contribution(RuntimeOrigin::signed(verifier), contributor, contribution_id, 100, Vec::new())
+            };
+
+            // Attestation and the log check count as no verification
+            assert_ok!(Reputation::maintainer_attest(RuntimeOrigin::signed(maintainer), contribution_id));
+            assert_ok!(Reputation::confirm_git_import(RuntimeOrigin::signed(DKG_PUBLISHER), contribution_id, true));
+            assert_eq!(Contributions::<Test>::get(contribution_id).unwrap().verification_count, 0);
+
+            // The attesting maintainer is not an independent verifier
+            assert_err!(verify(maintainer), Error::<Test>::ContributionAlreadyVerified);
+            assert_ok!(verify(2));
+            assert!(!Contributions::<Test>::get(contribution_id).unwrap().verified);
+            assert_ok!(verify(3));
+            assert!(Contributions::<Test>::get(contribution_id).unwrap().verified);
+        });
+    }
+
+    #[test]
+    fn test_starter_grant_is_claimed_once_per_identity() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            // Accounts 1 and 2 belong to the same person
+            VerifiedHumans::set(vec![(1, 1), (2, 1), (3, 2), (4, 3), (5, 4)]);
+
+            assert_err!(
+                Reputation::claim_starter_reputation(RuntimeOrigin::signed(1)),
+                Error::<Test>::StarterGrantUnavailable
+            );
+            assert_ok!(Reputation::set_starter_grant(RuntimeOrigin::root(), 50));
+
+            assert_ok!(Reputation::claim_starter_reputation(RuntimeOrigin::signed(1)));
+            assert_eq!(Reputation::get_reputation(&1), 50);
+            // A second account of the same person is refused
+            assert_err!(
+                Reputation::claim_starter_reputation(RuntimeOrigin::signed(2)),
+                Error::<Test>::StarterGrantAlreadyClaimed
+            );
+            assert_err!(
+                Reputation::claim_starter_reputation(RuntimeOrigin::signed(6)),
+                Error::<Test>::NotVerifiedHuman
+            );
+
+            // `MaxStarterGrantsPerPeriod` is 2 in the mock
+            assert_ok!(Reputation::claim_starter_reputation(RuntimeOrigin::signed(3)));
+            assert_err!(
+                Reputation::claim_starter_reputation(RuntimeOrigin::signed(4)),
+                Error::<Test>::StarterGrantsExhausted
+            );
+            System::set_block_number(StarterGrantPeriod::get() + 1);
+            assert_ok!(Reputation::claim_starter_reputation(RuntimeOrigin::signed(4)));
+            assert_eq!(Reputation::get_reputation(&4), 50);
+        });
+    }
+
+    #[test]
+    fn test_decay_sweep_warns_once_before_material_decay() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            ReputationParams::<Test>::put(AlgorithmParams { decay_rate_per_block: 1000, ..Default::default() });
+            let (contributor, verifier) = (1u64, 3u64);
+            ReputationScores::<Test>::insert(verifier, 50);
+            assert_ok!(Reputation::add_contribution(
+                RuntimeOrigin::signed(contributor),
+                H256::from_low_u64_be(1),
+                ContributionType::CodeCommit,
+                100,
+                DataSource::GitHub,
+                Default::default(),
+            ));
+            assert_ok!(Reputation::verify_contribution(
+                RuntimeOrigin::signed(verifier),
+                contributor,
+                NextContributionId::<Test>::get(),
+                100,
+                vec![]
+            ));
+            let score = ReputationScores::<Test>::get(contributor);
+            let warnings = || {
+                System::events()
+                    .iter()
+                    .filter(|record| matches!(record.event, RuntimeEvent::Reputation(Event::DecayWarning { .. })))
+                    .count()
+            };
+
+            // The sweep is disabled in the mock by default
+            Reputation::on_initialize(2);
+            assert!(Reputation::decay_warning(contributor).is_none());
+
+            // 1000 ppm per block over `DecayWarningWindow` (200_000 blocks) is a 20% loss
+            DecaySweepAccountsPerBlock::set(1);
+            Reputation::on_initialize(2);
+            let warning = Reputation::decay_warning(contributor).unwrap();
+            assert_eq!(warning.score, score);
+            assert!(warning.projected < score * 90 / 100);
+            assert_eq!(warning.at, 2 + DecayWarningWindow::get());
+            assert_eq!(warnings(), 1);
+            // The sweep only projects decay; scores are unchanged
+            assert_eq!(ReputationScores::<Test>::get(contributor), score);
+
+            Reputation::on_initialize(3);
+            assert_eq!(warnings(), 1);
+
+            ReputationParams::<Test>::put(AlgorithmParams { decay_rate_per_block: 1, ..Default::default() });
+            Reputation::on_initialize(4);
+            assert!(Reputation::decay_warning(contributor).is_none());
+        });
+    }
+
+    #[test]
+    fn test_contributor_responds_and_verifier_amends_once() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            MinVerifications::set(2);
+            let (contributor, verifier) = (1u64, 3u64);
+            ReputationScores::<Test>::insert(verifier, 50);
+            assert_ok!(Reputation::add_contribution(
+                RuntimeOrigin::signed(contributor),
+                H256::from_low_u64_be(1),
+                ContributionType::CodeCommit,
+                100,
+                DataSource::GitHub,
+                Default::default(),
+            ));
+            let contribution_id = NextContributionId::<Test>::get();
+            assert_err!(
+                Reputation::amend_verification(RuntimeOrigin::signed(verifier), contribution_id, 80),
+                Error::<Test>::VerificationNotFound
+            );
+            assert_ok!(Reputation::verify_contribution(
+                RuntimeOrigin::signed(verifier),
+                contributor,
+                contribution_id,
+                40,
+                b"missing tests".to_vec()
+            ));
+
+            let response: VerificationResponse = b"tests are in the follow-up".to_vec().try_into().unwrap();
+            assert_err!(
+                Reputation::respond_to_verification(RuntimeOrigin::signed(2), contribution_id, verifier, response.clone()),
+                Error::<Test>::ContributionNotFound
+            );
+            assert_err!(
+                Reputation::respond_to_verification(RuntimeOrigin::signed(contributor), contribution_id, 2, response.clone()),
+                Error::<Test>::VerificationNotFound
+            );
+            assert_ok!(Reputation::respond_to_verification(
+                RuntimeOrigin::signed(contributor),
+                contribution_id,
+                verifier,
+                response.clone()
+            ));
+            assert_eq!(Reputation::verification_response(contribution_id, verifier), Some(response.clone()));
+            assert_err!(
+                Reputation::respond_to_verification(RuntimeOrigin::signed(contributor), contribution_id, verifier, response),
+                Error::<Test>::VerificationAlreadyAnswered
+            );
+
+            assert_err!(
+                Reputation::amend_verification(RuntimeOrigin::signed(verifier), contribution_id, 101),
+                Error::<Test>::InvalidVerificationScore
+            );
+            assert_ok!(Reputation::amend_verification(RuntimeOrigin::signed(verifier), contribution_id, 80));
+            System::assert_last_event(RuntimeEvent::Reputation(Event::VerificationAmended {
+                contribution_id,
+                verifier,
+                old_score: 40,
+                new_score: 80,
+            }));
+            assert_eq!(
+                Reputation::contribution_verifications(contribution_id, verifier),
+                Some((80, b"missing tests".to_vec()))
+            );
+            assert_eq!(Reputation::amended_verification(contribution_id, verifier), Some(40));
+            assert_err!(
+                Reputation::amend_verification(RuntimeOrigin::signed(verifier), contribution_id, 90),
+                Error::<Test>::VerificationAlreadyAmended
+            );
+        });
+    }
+
+    #[test]
+    fn test_pause_blocks_contribution_pipeline() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let (contributor, verifier) = (1u64, 3u64);
+            ReputationScores::<Test>::insert(verifier, 50);
+            let submit = |proof| {
+                Reputation::add_contribution(
+                    RuntimeOrigin::signed(contributor),
+                    H256::from_low_u64_be(proof),
+                    ContributionType::CodeCommit,
+                    100,
+                    DataSource::GitHub,
+                    Default::default(),
+                )
+            };
+            assert_ok!(submit(1));
+            let contribution_id = NextContributionId::<Test>::get();
+
+            assert_err!(Reputation::pause(RuntimeOrigin::signed(verifier)), sp_runtime::DispatchError::BadOrigin);
+            assert_ok!(Reputation::pause(RuntimeOrigin::root()));
+            System::assert_last_event(RuntimeEvent::Reputation(Event::PipelinePaused));
+            assert!(Reputation::is_paused());
+
+            assert_err!(submit(2), Error::<Test>::PipelinePaused);
+            assert_err!(
+                Reputation::verify_contribution(RuntimeOrigin::signed(verifier), contributor, contribution_id, 90, vec![]),
+                Error::<Test>::PipelinePaused
+            );
+            assert_err!(
+                Reputation::maintainer_attest(RuntimeOrigin::signed(verifier), contribution_id),
+                Error::<Test>::PipelinePaused
+            );
+            assert_err!(
+                Reputation::approve_high_value_contribution(RuntimeOrigin::signed(verifier), contribution_id),
+                Error::<Test>::PipelinePaused
+            );
+            assert_err!(Reputation::claim_starter_reputation(RuntimeOrigin::signed(contributor)), Error::<Test>::PipelinePaused);
+            assert_err!(
+                Reputation::amend_verification(RuntimeOrigin::signed(verifier), contribution_id, 80),
+                Error::<Test>::PipelinePaused
+            );
+            assert_err!(
+                Reputation::reject_contribution(
+                    RuntimeOrigin::signed(verifier),
+                    contribution_id,
+                    RejectionReason::NotOriginal,
+                    Default::default(),
+                ),
+                Error::<Test>::PipelinePaused
+            );
+
+            assert_ok!(Reputation::unpause(RuntimeOrigin::root()));
+            System::assert_last_event(RuntimeEvent::Reputation(Event::PipelineUnpaused));
+            assert_ok!(Reputation::verify_contribution(
+                RuntimeOrigin::signed(verifier),
+                contributor,
+                contribution_id,
+                90,
+                vec![]
+            ));
+            assert_ok!(submit(2));
+        });
+    }
+
+    #[test]
+    fn test_governance_sets_pipeline_limits() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_eq!(Reputation::pipeline_config(), PipelineConfig::default());
+
+            for invalid in [
+                PipelineConfig { max_batch_size: 0, query_timeout: 100 },
+                PipelineConfig { max_batch_size: PipelineConfig::MAX_BATCH_SIZE + 1, query_timeout: 100 },
+                PipelineConfig { max_batch_size: 10, query_timeout: 0 },
+            ] {
+                assert_err!(
+                    Reputation::set_pipeline_config(RuntimeOrigin::root(), invalid),
+                    Error::<Test>::InvalidPipelineConfig
+                );
+            }
+
+            let config = PipelineConfig { max_batch_size: 2, query_timeout: 20 };
+            assert_ok!(Reputation::set_pipeline_config(RuntimeOrigin::root(), config));
+            System::assert_last_event(RuntimeEvent::Reputation(Event::PipelineConfigUpdated {
+                old: PipelineConfig::default(),
+                new: config,
+            }));
+
+            let proofs = (1..=3)
+                .map(|proof| (H256::from_low_u64_be(proof), ContributionType::CodeCommit, 100, DataSource::GitHub))
+                .collect();
+            assert_err!(
+                Reputation::batch_add_contributions(RuntimeOrigin::signed(1), proofs),
+                Error::<Test>::BatchTooLarge
+            );
+        });
+    }
+
+    #[test]
+    fn test_activity_stats_track_submissions_and_verifications() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            MinVerifications::set(2);
+            let (contributor, first, second) = (1u64, 3u64, 4u64);
+            ReputationScores::<Test>::insert(first, 50);
+            ReputationScores::<Test>::insert(second, 50);
+            assert_ok!(Reputation::add_contribution(
+                RuntimeOrigin::signed(contributor),
+                H256::from_low_u64_be(1),
+                ContributionType::CodeCommit,
+                100,
+                DataSource::GitHub,
+                Default::default(),
+            ));
+            let contribution_id = NextContributionId::<Test>::get();
+            assert_eq!(Reputation::activity_stats(contributor).submitted, 1);
+            assert_eq!(Reputation::activity_stats(contributor).average_received_score(), None);
+
+            System::set_block_number(5);
+            for (verifier, score) in [(first, 60), (second, 80)] {
+                assert_ok!(Reputation::verify_contribution(
+                    RuntimeOrigin::signed(verifier),
+                    contributor,
+                    contribution_id,
+                    score,
+                    vec![]
+                ));
+            }
+
+            let stats = Reputation::activity_stats(contributor);
+            assert_eq!((stats.submitted, stats.verified, stats.rejected), (1, 1, 0));
+            assert_eq!(stats.average_received_score(), Some(70));
+            assert_eq!(stats.last_active, 1);
+
+            let verifier_stats = Reputation::activity_stats(first);
+            assert_eq!(verifier_stats.verifications_performed, 1);
+            assert_eq!(verifier_stats.last_active, 5);
+        });
+    }
+
+    #[test]
+    fn test_contribution_search_indexes_by_project_and_type() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let contributor = 1u64;
+            for repository in [&b"https://github.com/paritytech/substrate"[..], &b"https://github.com/paritytech/polkadot"[..]] {
+                assert_ok!(Reputation::register_project(RuntimeOrigin::signed(2), repository.to_vec()));
+            }
+
+            let mut ids = Vec::new();
+            for (proof, contribution_type) in
+                [(1, ContributionType::Documentation), (2, ContributionType::CodeCommit), (3, ContributionType::Documentation)]
+            {
+                assert_ok!(Reputation::add_contribution(
+                    RuntimeOrigin::signed(contributor),
+                    H256::from_low_u64_be(proof),
+                    contribution_type,
+                    10,
+                    DataSource::GitHub,
+                    Default::default(),
+                ));
+                let contribution_id = NextContributionId::<Test>::get();
+                assert_ok!(Reputation::set_contribution_project(RuntimeOrigin::signed(contributor), contribution_id, 0));
+                ids.push(contribution_id);
+            }
+
+            assert_eq!(
+                Reputation::contributions_by_type(ContributionType::Documentation, None, 10),
+                (vec![ids[2], ids[0]], None)
+            );
+            assert_eq!(Reputation::contributions_by_project(0, None, None, 10), (vec![ids[2], ids[1], ids[0]], None));
+            assert_eq!(
+                Reputation::contributions_by_project(0, Some(ContributionType::Documentation), Some(ids[2]), 10),
+                (vec![ids[0]], None)
+            );
+
+            // Pages continue before the last id they scanned
+            assert_eq!(Reputation::contributions_by_project(0, None, None, 2), (vec![ids[2], ids[1]], Some(ids[1])));
+            assert_eq!(Reputation::contributions_by_project(0, None, Some(ids[1]), 2), (vec![ids[0]], None));
+
+            // Moving a contribution to another project moves it between indexes
+            assert_ok!(Reputation::set_contribution_project(RuntimeOrigin::signed(contributor), ids[2], 1));
+            assert_eq!(
+                Reputation::contributions_by_project(0, Some(ContributionType::Documentation), None, 10),
+                (vec![ids[0]], None)
+            );
+            assert_eq!(Reputation::contributions_by_project(1, None, None, 10), (vec![ids[2]], None));
+        });
+    }
+
+    #[test]
+    fn test_migration_moves_search_indexes_into_double_maps() {
+        use crate::migrations::v3::{ContributionIndex, ContributionsByProject, ContributionsByType, MigrateToV3};
+        use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let contributor = 1;
+            assert_ok!(Reputation::register_project(
+                RuntimeOrigin::signed(2),
+                b"https://github.com/paritytech/substrate".to_vec()
+            ));
+            let mut ids = Vec::new();
+            for proof in [1, 2] {
+                assert_ok!(Reputation::add_contribution(
+                    RuntimeOrigin::signed(contributor),
+                    H256::from_low_u64_be(proof),
+                    ContributionType::Documentation,
+                    10,
+                    DataSource::GitHub,
+                    Default::default(),
+                ));
+                let contribution_id = NextContributionId::<Test>::get();
+                assert_ok!(Reputation::set_contribution_project(RuntimeOrigin::signed(contributor), contribution_id, 0));
+                ids.push(contribution_id);
+            }
+
+            // Rewind to the v2 layout, with an id whose contribution is gone
+            let _ = ContributionsOfProject::<Test>::clear(u32::MAX, None);
+            let _ = ContributionsOfType::<Test>::clear(u32::MAX, None);
+            let old = ContributionIndex::truncate_from(vec![ids[0], ids[1], 999]);
+            ContributionsByProject::<Test>::insert(0, &old);
+            ContributionsByType::<Test>::insert(ContributionType::Documentation, &old);
+            StorageVersion::new(2).put::<Reputation>();
+
+            MigrateToV3::<Test>::on_runtime_upgrade();
+            assert_eq!(Reputation::on_chain_storage_version(), 3);
+            System::set_block_number(2);
+            Reputation::on_initialize(2);
+
+            assert!(!crate::pallet::IndexMigrationCursor::<Test>::exists());
+            System::assert_has_event(RuntimeEvent::Reputation(Event::IndexMigrationCompleted));
+            assert!(!ContributionsByProject::<Test>::contains_key(0));
+            assert!(!ContributionsByType::<Test>::contains_key(ContributionType::Documentation));
+            assert_eq!(Reputation::contributions_by_project(0, None, None, 10), (vec![ids[1], ids[0]], None));
+            assert_eq!(
+                Reputation::contributions_by_type(ContributionType::Documentation, None, 10),
+                (vec![ids[1], ids[0]], None)
+            );
+        });
+    }
+
+    #[test]
+    fn test_settled_queries_move_into_remote_reputation_cache() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let query = |query_id, target_account: &[u8]| ReputationQuery::<Test> {
+                query_id,
+                target_chain: b"moonbeam".to_vec(),
+                target_account: target_account.to_vec(),
+                status: QueryStatus::Pending,
+                initiated_at: 1,
+                response: None,
+                timeout: 10,
+            };
+            ReputationQueries::<Test>::insert(1, query(1, b"alice"));
+            ReputationQueries::<Test>::insert(2, query(2, b"bob"));
+            ReputationQueries::<Test>::insert(3, query(3, b"carol"));
+
+            assert_ok!(Reputation::note_query_response(1, Some((420, 90))));
+            assert_err!(Reputation::note_query_response(1, None), Error::<Test>::QueryNotPending);
+            assert_ok!(Reputation::note_query_response(2, None));
+
+            let queries: BoundedVec<_, _> = vec![1, 2, 3].try_into().unwrap();
+            assert_ok!(Reputation::sync_remote_reputation(RuntimeOrigin::signed(1), queries.clone(), Default::default()));
+            System::assert_last_event(RuntimeEvent::Reputation(Event::RemoteReputationSynced {
+                cached: 1,
+                dropped: 1,
+                expired: 0,
+            }));
+
+            let chain: dotrep_primitives::ChainId = b"moonbeam".to_vec().try_into().unwrap();
+            let alice: RemoteAccount = b"alice".to_vec().try_into().unwrap();
+            assert_eq!(
+                Reputation::remote_reputation(&chain, &alice),
+                Some(RemoteScore { score: 420, percentile: 90, updated_at: 1 })
+            );
+            // The third query is still waiting for its answer
+            assert!(ReputationQueries::<Test>::contains_key(3));
+            assert!(!ReputationQueries::<Test>::contains_key(1));
+
+            let expired: BoundedVec<_, _> = vec![(chain.clone(), alice.clone())].try_into().unwrap();
+            assert_err!(
+                Reputation::sync_remote_reputation(RuntimeOrigin::signed(1), queries.clone(), expired.clone()),
+                Error::<Test>::NothingToSync
+            );
+
+            System::set_block_number(2 + RemoteReputationTtl::get());
+            assert_ok!(Reputation::sync_remote_reputation(RuntimeOrigin::signed(1), queries, expired));
+            System::assert_last_event(RuntimeEvent::Reputation(Event::RemoteReputationSynced {
+                cached: 0,
+                dropped: 1,
+                expired: 1,
+            }));
+            assert!(Reputation::remote_reputation(&chain, &alice).is_none());
+            assert!(!ReputationQueries::<Test>::contains_key(3));
+        });
+    }
+
+    #[test]
+    fn test_bundle_is_verified_as_one_contribution_with_provable_items() {
+        use crate::archive::{merkle_proof, merkle_root};
+
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let (contributor, verifier) = (1u64, 3u64);
+            ReputationScores::<Test>::insert(verifier, 50);
+            let items: Vec<H256> = (1..=3).map(H256::from_low_u64_be).collect();
+            let root = merkle_root(items.clone());
+            let bundle = |root: H256, leaves: Vec<H256>| {
+                Reputation::submit_bundle(
+                    RuntimeOrigin::signed(contributor),
+                    root,
+                    leaves.try_into().unwrap(),
+                    ContributionType::CodeCommit,
+                    30,
+                    DataSource::GitHub,
+                )
+            };
+
+            assert_err!(bundle(merkle_root(items[..1].to_vec()), items[..1].to_vec()), Error::<Test>::InvalidBundle);
+            assert_err!(bundle(root, vec![items[0], items[1], items[1]]), Error::<Test>::InvalidBundle);
+            // The root must commit to the submitted leaves
+            assert_err!(bundle(H256::repeat_byte(1), items.clone()), Error::<Test>::InvalidBundle);
+
+            // An item submitted on its own cannot be bundled again
+            assert_ok!(Reputation::add_contribution(
+                RuntimeOrigin::signed(contributor),
+                H256::from_low_u64_be(4),
+                ContributionType::CodeCommit,
+                10,
+                DataSource::GitHub,
+                Default::default(),
+            ));
+            let with_known = vec![items[0], H256::from_low_u64_be(4)];
+            assert_err!(bundle(merkle_root(with_known.clone()), with_known), Error::<Test>::ContributionAlreadySubmitted);
+
+            assert_ok!(bundle(root, items.clone()));
+            let contribution_id = NextContributionId::<Test>::get();
+            assert_eq!(Reputation::contribution_bundle(contribution_id), Some(ContributionBundle { root, items: 3 }));
+
+            // Nor can a bundled item be submitted alone or in another bundle
+            assert_err!(
+                Reputation::add_contribution(
+                    RuntimeOrigin::signed(contributor),
+                    items[1],
+                    ContributionType::CodeCommit,
+                    10,
+                    DataSource::GitHub,
+                    Default::default(),
+                ),
+                Error::<Test>::ContributionAlreadySubmitted
+            );
+            let overlapping = vec![items[2], H256::from_low_u64_be(5)];
+            assert_err!(
+                bundle(merkle_root(overlapping.clone()), overlapping),
+                Error::<Test>::ContributionAlreadySubmitted
+            );
+
+            assert_ok!(Reputation::verify_contribution(RuntimeOrigin::signed(verifier), contributor, contribution_id, 90, vec![]));
+            assert!(Contributions::<Test>::get(contribution_id).unwrap().verified);
+
+            let proof = merkle_proof(items.clone(), 2);
+            assert!(Reputation::is_bundle_item(contribution_id, items[2], 2, &proof));
+            assert!(!Reputation::is_bundle_item(contribution_id, H256::from_low_u64_be(9), 2, &proof));
+        });
+    }
+
+    #[test]
+    fn test_attestations_boost_effective_reputation_through_multiplier() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let (subject, attestor) = (1u64, 7u64);
+            ReputationScores::<Test>::insert(subject, 400);
+            let claim: AttestationClaim = b"passed security training".to_vec().try_into().unwrap();
+
+            assert_err!(
+                Reputation::issue_attestation(RuntimeOrigin::signed(attestor), subject, claim.clone(), 50, 100),
+                Error::<Test>::NotAttestor
+            );
+            assert_ok!(Reputation::register_attestor(RuntimeOrigin::root(), attestor, b"Audit Co".to_vec().try_into().unwrap()));
+            assert_err!(
+                Reputation::issue_attestation(RuntimeOrigin::signed(attestor), subject, claim.clone(), 101, 100),
+                Error::<Test>::InvalidAttestation
+            );
+            assert_err!(
+                Reputation::issue_attestation(RuntimeOrigin::signed(attestor), subject, claim.clone(), 50, 1),
+                Error::<Test>::InvalidAttestation
+            );
+            assert_ok!(Reputation::issue_attestation(RuntimeOrigin::signed(attestor), subject, claim, 50, 100));
+            System::assert_last_event(RuntimeEvent::Reputation(Event::AttestationIssued {
+                subject,
+                attestor,
+                attestation_id: 1,
+                weight: 50,
+                expires_at: 100,
+            }));
+
+            // Attestations do not count until governance sets a multiplier
+            assert_eq!(Reputation::effective_reputation(&subject), 400);
+            assert_ok!(Reputation::set_attestation_multiplier(RuntimeOrigin::root(), Percent::from_percent(50)));
+            assert_eq!(Reputation::effective_reputation(&subject), 500);
+
+            // One attestation per attestor and subject, and the boost is capped
+            let other: AttestationClaim = b"audited three projects".to_vec().try_into().unwrap();
+            assert_err!(
+                Reputation::issue_attestation(RuntimeOrigin::signed(attestor), subject, other.clone(), 50, 100),
+                Error::<Test>::AttestorAlreadyAttested
+            );
+            assert_ok!(Reputation::register_attestor(RuntimeOrigin::root(), 8, b"Review Co".to_vec().try_into().unwrap()));
+            assert_ok!(Reputation::issue_attestation(RuntimeOrigin::signed(8), subject, other, 100, 100));
+            assert_eq!(Reputation::attestation_boost_percent(&subject), MaxAttestationBoost::get());
+            assert_eq!(Reputation::effective_reputation(&subject), 400 + 400 * MaxAttestationBoost::get() as i32 / 100);
+            assert_ok!(Reputation::revoke_attestation(RuntimeOrigin::signed(8), subject, 2));
+
+            System::set_block_number(100);
+            assert_eq!(Reputation::effective_reputation(&subject), 400);
+
+            System::set_block_number(50);
+            assert_ok!(Reputation::remove_attestor(RuntimeOrigin::root(), attestor));
+            assert_eq!(Reputation::effective_reputation(&subject), 400);
+            assert_err!(
+                Reputation::revoke_attestation(RuntimeOrigin::signed(2), subject, 1),
+                Error::<Test>::AttestationNotFound
+            );
+            assert_ok!(Reputation::revoke_attestation(RuntimeOrigin::signed(attestor), subject, 1));
+            assert!(Reputation::attestation(subject, 1).is_none());
+        });
+    }
+
+    #[test]
+    fn test_hook_quotas_stay_within_max_hook_share() {
+        new_test_ext().execute_with(|| {
+            use dotrep_primitives::{HookQuotaProvider, HookTask};
+            System::set_block_number(1);
+
+            assert_eq!(Reputation::hook_quota(HookTask::ClaimSweep), Perbill::from_percent(10));
+            assert_err!(
+                Reputation::set_hook_quota(RuntimeOrigin::signed(1), HookTask::ClaimSweep, Perbill::from_percent(20)),
+                Error::<Test>::RequiresGovernance
+            );
+            assert_ok!(Reputation::set_hook_quota(RuntimeOrigin::root(), HookTask::ClaimSweep, Perbill::from_percent(15)));
+            System::assert_last_event(RuntimeEvent::Reputation(Event::HookQuotaSet {
+                task: HookTask::ClaimSweep,
+                quota: Perbill::from_percent(15),
+            }));
+            assert_eq!(Reputation::hook_quota(HookTask::ClaimSweep), Perbill::from_percent(15));
+
+            // 5% for deadlines, 15% for claims, 15% for migrations and 5% for score
+            // commitments leave 10% for the decay sweep
+            assert_err!(
+                Reputation::set_hook_quota(RuntimeOrigin::root(), HookTask::DecaySweep, Perbill::from_percent(11)),
+                Error::<Test>::HookQuotasExceeded
+            );
+            assert_ok!(Reputation::set_hook_quota(RuntimeOrigin::root(), HookTask::DecaySweep, Perbill::from_percent(10)));
+        });
+    }
+
+    #[test]
+    fn test_pallets_running_a_hook_task_share_its_budget() {
+        new_test_ext().execute_with(|| {
+            use dotrep_primitives::{HookQuotaProvider, HookTask};
+            System::set_block_number(1);
+            let max_block = Weight::from_parts(1_000_000, 0);
+            let quota = Reputation::hook_budget(HookTask::Migrations, max_block).remaining();
+            assert_eq!(quota, Weight::from_parts(150_000, 0));
+
+            Reputation::note_hook_usage(HookTask::Migrations, Weight::from_parts(100_000, 0));
+            assert_eq!(Reputation::hook_budget(HookTask::Migrations, max_block).remaining(), Weight::from_parts(50_000, 0));
+            assert_eq!(Reputation::hook_budget(HookTask::ClaimSweep, max_block).remaining(), Weight::from_parts(100_000, 0));
+
+            // Usage is per block
+            System::set_block_number(2);
+            assert_eq!(Reputation::hook_budget(HookTask::Migrations, max_block).remaining(), quota);
+        });
+    }
+
+    #[test]
+    fn test_only_pooled_verifiers_with_capacity_are_routed() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            MinVerifications::set(3);
+            let (contributor, verifier) = (1u64, 2u64);
+            let skill = |tag: &[u8]| -> SkillTag { tag.to_vec().try_into().unwrap() };
+            ReputationScores::<Test>::insert(verifier, 50);
+            Reputation::on_skill_tags_updated(&verifier, &[], &[skill(b"rust")]);
+
+            let mut contributions = Vec::new();
+            for proof in [31_000, 31_001] {
+                assert_ok!(Reputation::add_contribution(
+                    RuntimeOrigin::signed(contributor),
+                    H256::from_low_u64_be(proof),
+                    ContributionType::PullRequest,
+                    50,
+                    DataSource::GitHub,
+                    Default::default(),
+                ));
+                let contribution_id = NextContributionId::<Test>::get();
+                assert_ok!(Reputation::tag_contribution_skills(
+                    RuntimeOrigin::signed(contributor),
+                    contribution_id,
+                    vec![skill(b"rust")].try_into().unwrap()
+                ));
+                contributions.push(contribution_id);
+            }
+            let (first, second) = (contributions[0], contributions[1]);
+
+            // Declared skills alone do not route contributions to the verifier
+            assert!(Reputation::eligible_verifiers(first, 10).is_empty());
+            assert_eq!(Reputation::expected_review_latency(first), None);
+
+            assert_err!(
+                Reputation::opt_in_verifier(RuntimeOrigin::signed(verifier), 0, Default::default()),
+                Error::<Test>::ZeroVerifierCapacity
+            );
+            assert_err!(
+                Reputation::opt_in_verifier(RuntimeOrigin::signed(5), 1, Default::default()),
+                Error::<Test>::InsufficientReputationToVerify
+            );
+            assert_ok!(Reputation::opt_in_verifier(
+                RuntimeOrigin::signed(verifier),
+                1,
+                vec![skill(b"docs")].try_into().unwrap()
+            ));
+            assert!(Reputation::eligible_verifiers(first, 10).is_empty());
+
+            assert_ok!(Reputation::opt_in_verifier(RuntimeOrigin::signed(verifier), 1, Default::default()));
+            System::assert_last_event(RuntimeEvent::Reputation(Event::VerifierOptedIn {
+                verifier,
+                capacity_per_era: 1,
+                skills: Default::default(),
+            }));
+            assert_eq!(Reputation::eligible_verifiers(first, 10), vec![verifier]);
+            // Three verifications take two skill-matched reviews at one review per era
+            assert_eq!(Reputation::expected_review_latency(first), Some(2 * VerifierEra::get()));
+
+            // Once its capacity for the era is used the verifier is not routed more work
+            assert_ok!(Reputation::verify_contribution(
+                RuntimeOrigin::signed(verifier),
+                contributor,
+                first,
+                90,
+                vec![]
+            ));
+            assert_eq!(Reputation::verifier_availability(verifier).unwrap().reviews, 1);
+            assert!(Reputation::eligible_verifiers(second, 10).is_empty());
+
+            System::set_block_number(VerifierEra::get() + 1);
+            assert_eq!(Reputation::eligible_verifiers(second, 10), vec![verifier]);
+
+            assert_ok!(Reputation::opt_out_verifier(RuntimeOrigin::signed(verifier)));
+            assert!(Reputation::eligible_verifiers(second, 10).is_empty());
+            assert_err!(
+                Reputation::opt_out_verifier(RuntimeOrigin::signed(verifier)),
+                Error::<Test>::NotInVerifierPool
+            );
+        });
+    }
+
+    #[test]
+    fn test_bulk_imported_history_is_verified_separately_and_dampened() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let (owner, importer, verifier, second) = (4u64, 6u64, 2u64, 5u64);
+            for account in [owner, verifier, second] {
+                ReputationScores::<Test>::insert(account, 50);
+            }
+            assert_ok!(Reputation::register_project(
+                RuntimeOrigin::signed(owner),
+                b"https://github.com/example-org".to_vec()
+            ));
+
+            let digest = H256::repeat_byte(0xe5);
+            let item = |contributor: u64, proof: u64| ImportItem {
+                contributor,
+                proof: H256::from_low_u64_be(proof),
+                contribution_type: ContributionType::PullRequest,
+                weight: 40,
+            };
+            assert_err!(
+                Reputation::approve_bulk_import(RuntimeOrigin::signed(owner), digest, importer, 0, 2),
+                Error::<Test>::RequiresGovernance
+            );
+            assert_ok!(Reputation::approve_bulk_import(RuntimeOrigin::root(), digest, importer, 0, 2));
+            assert_err!(
+                Reputation::submit_import_batch(RuntimeOrigin::signed(7), digest, vec![item(1, 40_000)].try_into().unwrap()),
+                Error::<Test>::NotBulkImporter
+            );
+            assert_err!(
+                Reputation::submit_import_batch(
+                    RuntimeOrigin::signed(importer),
+                    digest,
+                    vec![item(1, 40_000), item(3, 40_001), item(3, 40_002)].try_into().unwrap()
+                ),
+                Error::<Test>::BulkImportLimitExceeded
+            );
+            assert_ok!(Reputation::submit_import_batch(
+                RuntimeOrigin::signed(importer),
+                digest,
+                vec![item(1, 40_000), item(3, 40_001)].try_into().unwrap()
+            ));
+            System::assert_last_event(RuntimeEvent::Reputation(Event::ImportBatchSubmitted { export_digest: digest, count: 2 }));
+
+            // Nothing is recorded until the named contributors consent
+            let first_id = NextContributionId::<Test>::get();
+            assert!(Reputation::unaccepted_import(1, H256::from_low_u64_be(40_000)).is_some());
+            assert_eq!(ContributionsByProof::<Test>::get(H256::from_low_u64_be(40_000)), None);
+            assert_err!(
+                Reputation::accept_imported_contribution(RuntimeOrigin::signed(3), H256::from_low_u64_be(40_000)),
+                Error::<Test>::NotImportedContribution
+            );
+            assert_ok!(Reputation::decline_imported_contribution(RuntimeOrigin::signed(3), H256::from_low_u64_be(40_001)));
+            assert!(Reputation::unaccepted_import(3, H256::from_low_u64_be(40_001)).is_none());
+            assert_ok!(Reputation::accept_imported_contribution(RuntimeOrigin::signed(1), H256::from_low_u64_be(40_000)));
+            assert_eq!(NextContributionId::<Test>::get(), first_id + 1);
+
+            let contribution_id = first_id;
+            assert_eq!(Contributions::<Test>::get(contribution_id).unwrap().status, ContributionStatus::Imported);
+            assert_eq!(Reputation::imported_from(contribution_id), Some(digest));
+            assert_eq!(Reputation::contribution_project(contribution_id), Some(0));
+            assert_eq!(PendingContributions::<Test>::get(1), 0);
+
+            // Imports take their own verification track, not reviewed by the importer
+            assert_err!(
+                Reputation::verify_contribution(RuntimeOrigin::signed(verifier), 1, contribution_id, 100, Vec::new()),
+                Error::<Test>::AwaitsImportVerification
+            );
+            assert_err!(
+                Reputation::maintainer_attest(RuntimeOrigin::signed(owner), contribution_id),
+                Error::<Test>::AwaitsImportVerification
+            );
+            assert_err!(
+                Reputation::verify_imported_contribution(RuntimeOrigin::signed(importer), contribution_id, 100),
+                Error::<Test>::SelfVerificationNotAllowed
+            );
+
+            // The usual quorum applies
+            MinVerifications::set(2);
+            assert_ok!(Reputation::verify_imported_contribution(RuntimeOrigin::signed(verifier), contribution_id, 100));
+            assert!(!Contributions::<Test>::get(contribution_id).unwrap().verified);
+            assert_err!(
+                Reputation::verify_imported_contribution(RuntimeOrigin::signed(verifier), contribution_id, 100),
+                Error::<Test>::ContributionAlreadyVerified
+            );
+            assert_ok!(Reputation::verify_imported_contribution(RuntimeOrigin::signed(second), contribution_id, 100));
+            assert!(Contributions::<Test>::get(contribution_id).unwrap().verified);
+            assert_err!(
+                Reputation::verify_imported_contribution(RuntimeOrigin::signed(verifier), contribution_id, 100),
+                Error::<Test>::NotImportedContribution
+            );
+            MinVerifications::set(1);
+
+            // Imports earn `ImportedPointsPercent` of the points of other contributions
+            assert_ok!(Reputation::add_contribution(
+                RuntimeOrigin::signed(8),
+                H256::from_low_u64_be(1),
+                ContributionType::PullRequest,
+                40,
+                DataSource::GitHub,
+                Default::default(),
+            ));
+            assert_ok!(Reputation::verify_contribution(
+                RuntimeOrigin::signed(verifier),
+                8,
+                NextContributionId::<Test>::get(),
+                100,
+                Vec::new(),
+            ));
+            assert!(Reputation::get_reputation(&8) > 0);
+            assert_eq!(Reputation::get_reputation(&1), Reputation::get_reputation(&8) * 25 / 100);
+        });
+    }
+
+    #[test]
+    fn test_structural_param_changes_bump_the_algorithm_version() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_eq!(Reputation::algorithm_version(), 0);
+
+            // Decay tuning keeps the meaning of scores
+            let mut params = AlgorithmParams::default();
+            params.decay_rate_per_block = 2;
+            assert_ok!(Reputation::update_algorithm_params(RuntimeOrigin::root(), params.clone()));
+            assert_eq!(Reputation::algorithm_version(), 0);
+
+            params.verification_multiplier = 20_000;
+            assert_ok!(Reputation::update_algorithm_params(RuntimeOrigin::root(), params.clone()));
+            System::assert_has_event(RuntimeEvent::Reputation(Event::AlgorithmVersionBumped { version: 1 }));
+
+            assert_err!(
+                Reputation::set_compatibility_mode(RuntimeOrigin::signed(1), true),
+                Error::<Test>::RequiresGovernance
+            );
+            assert_ok!(Reputation::set_compatibility_mode(RuntimeOrigin::root(), true));
+            params.contribution_type_weights.insert(ContributionType::PullRequest, 25);
+            assert_err!(
+                Reputation::update_algorithm_params(RuntimeOrigin::root(), params.clone()),
+                Error::<Test>::StructuralChangeInCompatibilityMode
+            );
+            assert_eq!(
+                Reputation::algorithm_version_info(),
+                AlgorithmVersionInfo { version: 1, compatibility_mode: true }
+            );
+
+            // Every other setter changing what a score means is frozen too
+            assert_err!(
+                Reputation::set_attestation_multiplier(RuntimeOrigin::root(), Percent::from_percent(50)),
+                Error::<Test>::StructuralChangeInCompatibilityMode
+            );
+            assert_err!(
+                Reputation::set_remote_reputation_weight(RuntimeOrigin::root(), Percent::from_percent(50)),
+                Error::<Test>::StructuralChangeInCompatibilityMode
+            );
+            let points = ImportPoints { git_import: Percent::from_percent(10), imported: Percent::from_percent(10) };
+            assert_err!(
+                Reputation::set_import_points(RuntimeOrigin::root(), points),
+                Error::<Test>::StructuralChangeInCompatibilityMode
+            );
+            // Setting the current value is no change
+            assert_ok!(Reputation::set_import_points(RuntimeOrigin::root(), Reputation::import_points()));
+
+            assert_ok!(Reputation::set_compatibility_mode(RuntimeOrigin::root(), false));
+            assert_ok!(Reputation::update_algorithm_params(RuntimeOrigin::root(), params));
+            assert_eq!(Reputation::algorithm_version(), 2);
+            assert_ok!(Reputation::set_attestation_multiplier(RuntimeOrigin::root(), Percent::from_percent(50)));
+            assert_ok!(Reputation::set_remote_reputation_weight(RuntimeOrigin::root(), Percent::from_percent(50)));
+            assert_ok!(Reputation::set_import_points(RuntimeOrigin::root(), points));
+            assert_eq!(Reputation::import_points(), points);
+            assert_eq!(Reputation::algorithm_version(), 5);
+        });
+    }
+
+    #[test]
+    fn test_linked_remote_identities_add_discounted_reputation() {
+        new_test_ext().execute_with(|| {
+            use sp_core::Pair;
+
+            System::set_block_number(1);
+            let (developer, attestor) = (1u64, 7u64);
+            let chain: dotrep_primitives::ChainId = b"moonbeam".to_vec().try_into().unwrap();
+            let remote_key = sp_core::sr25519::Pair::from_seed(&[9; 32]);
+            let remote: RemoteAccount = remote_key.public().0.to_vec().try_into().unwrap();
+            let sign = |challenge: H256| sp_runtime::MultiSignature::from(remote_key.sign(challenge.as_bytes()));
+            ReputationScores::<Test>::insert(developer, 400);
+            RemoteReputation::<Test>::insert(&chain, &remote, RemoteScore { score: 300, percentile: 80, updated_at: 1 });
+
+            assert_err!(
+                Reputation::request_identity_link(RuntimeOrigin::signed(developer), chain.clone(), remote.clone()),
+                Error::<Test>::ChainNotSupported
+            );
+            RegisteredChains::<Test>::insert(&chain, true);
+            assert_ok!(Reputation::request_identity_link(RuntimeOrigin::signed(developer), chain.clone(), remote.clone()));
+            let challenge = Reputation::link_challenge(developer, (chain.clone(), remote.clone())).unwrap();
+
+            // The chain relays the remote account's signature over the pending challenge
+            assert_err!(
+                Reputation::relay_identity_link_proof(
+                    RuntimeOrigin::signed(developer),
+                    developer,
+                    remote.clone(),
+                    challenge,
+                    sign(challenge)
+                ),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            assert_err!(
+                Reputation::relay_identity_link_proof(
+                    RuntimeOrigin::signed(MOONBEAM_RELAY),
+                    developer,
+                    remote.clone(),
+                    H256::zero(),
+                    sign(H256::zero())
+                ),
+                Error::<Test>::InvalidLinkChallenge
+            );
+            let stranger = sp_core::sr25519::Pair::from_seed(&[3; 32]);
+            assert_err!(
+                Reputation::relay_identity_link_proof(
+                    RuntimeOrigin::signed(MOONBEAM_RELAY),
+                    developer,
+                    remote.clone(),
+                    challenge,
+                    stranger.sign(challenge.as_bytes()).into()
+                ),
+                Error::<Test>::InvalidLinkSignature
+            );
+            assert_ok!(Reputation::relay_identity_link_proof(
+                RuntimeOrigin::signed(MOONBEAM_RELAY),
+                developer,
+                remote.clone(),
+                challenge,
+                sign(challenge)
+            ));
+            assert_eq!(Reputation::identity_owner(&chain, &remote), Some(developer));
+            assert_err!(
+                Reputation::request_identity_link(RuntimeOrigin::signed(2), chain.clone(), remote.clone()),
+                Error::<Test>::IdentityAlreadyLinked
+            );
+
+            // Remote reputation only counts once governance sets a weight
+            assert_eq!(Reputation::aggregate_reputation(&developer), 400);
+            assert_ok!(Reputation::set_remote_reputation_weight(RuntimeOrigin::root(), Percent::from_percent(50)));
+            assert_eq!(Reputation::aggregate_reputation(&developer), 550);
+            assert_eq!(<Reputation as ReputationProvider<u64>>::reputation_of(&developer), 550);
+
+            assert_ok!(Reputation::unlink_identity(RuntimeOrigin::signed(developer), chain.clone(), remote.clone()));
+            assert_eq!(Reputation::aggregate_reputation(&developer), 400);
+
+            // Attestors can submit the signature instead, but not link without it
+            assert_ok!(Reputation::request_identity_link(RuntimeOrigin::signed(developer), chain.clone(), remote.clone()));
+            let challenge = Reputation::link_challenge(developer, (chain.clone(), remote.clone())).unwrap();
+            assert_err!(
+                Reputation::attest_identity_link(
+                    RuntimeOrigin::signed(attestor),
+                    developer,
+                    chain.clone(),
+                    remote.clone(),
+                    sign(challenge)
+                ),
+                Error::<Test>::NotAttestor
+            );
+            assert_ok!(Reputation::register_attestor(RuntimeOrigin::root(), attestor, b"Audit Co".to_vec().try_into().unwrap()));
+            assert_err!(
+                Reputation::attest_identity_link(
+                    RuntimeOrigin::signed(attestor),
+                    developer,
+                    chain.clone(),
+                    remote.clone(),
+                    stranger.sign(challenge.as_bytes()).into()
+                ),
+                Error::<Test>::InvalidLinkSignature
+            );
+            assert_ok!(Reputation::attest_identity_link(
+                RuntimeOrigin::signed(attestor),
+                developer,
+                chain.clone(),
+                remote.clone(),
+                sign(challenge)
+            ));
+            System::assert_last_event(RuntimeEvent::Reputation(Event::IdentityLinked {
+                account: developer,
+                chain,
+                remote_account: remote,
+            }));
+            assert_eq!(Reputation::aggregate_reputation(&developer), 550);
+        });
+    }
+
+    #[test]
+    fn test_reminder_queue_lists_pending_contributions_nearing_their_deadline() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            for (contributor, proof) in [(1u64, 11u64), (2, 12)] {
+                assert_ok!(Reputation::add_contribution(
+                    RuntimeOrigin::signed(contributor),
+                    H256::from_low_u64_be(proof),
+                    ContributionType::CodeCommit,
+                    10,
+                    DataSource::GitHub,
+                    Default::default(),
+                ));
+            }
+            let deadline = 1 + VerificationDeadline::get();
+
+            System::set_block_number(deadline - ReminderLead::get() - 1);
+            assert_eq!(Reputation::reminder_queue(0, 10), (vec![], None));
+            System::set_block_number(deadline - ReminderLead::get());
+            let (mut queue, next) = Reputation::reminder_queue(0, 10);
+            queue.sort();
+            assert_eq!(queue, vec![(1, deadline), (2, deadline)]);
+            assert_eq!(next, None);
+
+            // Pages hold whole deadline blocks and say where the next one starts
+            let (queue, next) = Reputation::reminder_queue(0, 1);
+            assert_eq!(queue.len(), 2);
+            assert_eq!(next, None);
+            assert_eq!(Reputation::reminder_queue(deadline + 1, 10), (vec![], None));
+
+            // Only pending contributions are listed, and stale ones leave the queue
+            Contributions::<Test>::mutate(2, |contribution| {
+                contribution.as_mut().unwrap().status = ContributionStatus::Verified
+            });
+            assert_eq!(Reputation::reminder_queue(0, 10), (vec![(1, deadline)], None));
+            System::set_block_number(deadline);
+            Reputation::on_initialize(deadline);
+            assert!(Reputation::stale_contribution(1).is_some());
+            System::set_block_number(deadline + EscalationPeriod::get() - ReminderLead::get());
+            assert_eq!(Reputation::reminder_queue(0, 10), (vec![], None));
+
+            assert_err!(
+                Reputation::set_notification_endpoints(RuntimeOrigin::root(), vec![b"http://hooks.example.org".to_vec()]),
+                Error::<Test>::InvalidNotificationEndpoint
+            );
+            assert_err!(
+                Reputation::set_notification_endpoints(
+                    RuntimeOrigin::root(),
+                    vec![b"https://hooks.example.org".to_vec(); MAX_NOTIFICATION_ENDPOINTS as usize + 1],
+                ),
+                Error::<Test>::TooManyNotificationEndpoints
+            );
+            assert_ok!(Reputation::set_notification_endpoints(
+                RuntimeOrigin::root(),
+                vec![b"https://hooks.example.org".to_vec()],
+            ));
+            System::assert_last_event(RuntimeEvent::Reputation(Event::NotificationEndpointsSet { endpoints: 1 }));
+        });
+    }
 }
@@ -7,7 +7,9 @@
 /// - Batch query support
 /// - Multi-location support for various chain types
 use super::*;
+use dotrep_primitives::{ChainId, Score};
 use frame_support::traits::Get;
+use sp_core::H256;
 use xcm::prelude::*;
 use sp_std::prelude::*;
 
@@ -30,15 +32,15 @@ pub enum ReputationXcmMessage {
     ReputationResponse {
         query_id: Option<u64>,
         account_id: Vec<u8>,
-        score: i32,
+        score: Score,
         percentile: u8,
-        breakdown: Vec<(ContributionType, i32)>,
+        breakdown: Vec<(ContributionType, Score)>,
         last_updated: u64,
     },
     /// Batch response with multiple reputation scores
     BatchReputationResponse {
         query_id: Option<u64>,
-        results: Vec<(Vec<u8>, i32, u8)>,
+        results: Vec<(Vec<u8>, Score, u8)>,
     },
     /// Error response
     ReputationError {
@@ -46,6 +48,31 @@ pub enum ReputationXcmMessage {
         error_code: u8,
         error_message: Vec<u8>,
     },
+    // New variants go last, so the indices of existing ones stay stable on the wire
+    /// Proof that a local account's claim to a remote account is genuine: the remote
+    /// account's signature over the link challenge, relayed by its chain
+    IdentityLinkProof {
+        account_id: Vec<u8>,
+        remote_account: Vec<u8>,
+        challenge: H256,
+        signature: RemoteSignature,
+    },
+    /// `ReputationResponse` with the `AlgorithmVersion` the score was computed with
+    VersionedReputationResponse {
+        query_id: Option<u64>,
+        account_id: Vec<u8>,
+        score: Score,
+        percentile: u8,
+        breakdown: Vec<(ContributionType, Score)>,
+        last_updated: u64,
+        algorithm_version: u32,
+    },
+    /// `BatchReputationResponse` with the `AlgorithmVersion` the scores were computed with
+    VersionedBatchReputationResponse {
+        query_id: Option<u64>,
+        results: Vec<(Vec<u8>, Score, u8)>,
+        algorithm_version: u32,
+    },
 }
 
 /// XCM query metadata for tracking
@@ -125,7 +152,7 @@ impl<T: Config> Pallet<T> {
             source_chain: dest.encode(),
             target_account: account_id.encode(),
             initiated_at: frame_system::Pallet::<T>::block_number(),
-            timeout: frame_system::Pallet::<T>::block_number() + 100u32.into(),
+            timeout: frame_system::Pallet::<T>::block_number() + PipelineParams::<T>::get().query_timeout.into(),
             status: XcmQueryStatus::Pending,
             response: None,
             retry_count: 0,
@@ -152,10 +179,7 @@ impl<T: Config> Pallet<T> {
         account_ids: Vec<T::AccountId>,
         response_destination: Option<MultiLocation>,
     ) -> Result<u64, DispatchError> {
-        ensure!(
-            account_ids.len() <= 10,
-            Error::<T>::InvalidAlgorithmParams // Reuse error for now
-        );
+        Self::ensure_batch_size(account_ids.len())?;
 
         let query_id = Self::generate_query_id();
         let account_id_bytes: Vec<Vec<u8>> = account_ids.iter().map(|id| id.encode()).collect();
@@ -211,17 +235,20 @@ impl<T: Config> Pallet<T> {
         let score = Self::get_reputation(&account_id);
         let percentile = Self::get_percentile(&account_id);
         
-        // Get contribution breakdown (simplified - would need storage for full breakdown)
-        let breakdown = Self::get_contribution_breakdown(&account_id);
+        // Detailed breakdowns are only served to locations the account allowed
+        let breakdown = Self::reputation_breakdown(&account_id, Self::location_account(&origin).as_ref())
+            .map(|breakdown| breakdown.by_type)
+            .unwrap_or_default();
         let last_updated = frame_system::Pallet::<T>::block_number().into();
 
-        Ok(ReputationXcmMessage::ReputationResponse {
+        Ok(ReputationXcmMessage::VersionedReputationResponse {
             query_id,
             account_id: account_id.encode(),
             score,
             percentile,
             breakdown,
             last_updated,
+            algorithm_version: AlgorithmVersion::<T>::get(),
         })
     }
 
@@ -241,28 +268,62 @@ impl<T: Config> Pallet<T> {
             }
         }
 
-        Ok(ReputationXcmMessage::BatchReputationResponse {
+        Ok(ReputationXcmMessage::VersionedBatchReputationResponse {
             query_id,
             results,
+            algorithm_version: AlgorithmVersion::<T>::get(),
         })
     }
 
+    /// Handle a response relayed by the chain `origin`
+    ///
+    /// `ChainOrigin` converts the XCM origin to the responding chain's id, which must be
+    /// the chain the query was sent to.
+    pub fn handle_xcm_response(origin: OriginFor<T>, response: ReputationXcmMessage) -> DispatchResult {
+        let chain = T::ChainOrigin::ensure_origin(origin)?;
+        let query_id = match &response {
+            ReputationXcmMessage::ReputationResponse { query_id, .. }
+            | ReputationXcmMessage::VersionedReputationResponse { query_id, .. }
+            | ReputationXcmMessage::BatchReputationResponse { query_id, .. }
+            | ReputationXcmMessage::VersionedBatchReputationResponse { query_id, .. }
+            | ReputationXcmMessage::ReputationError { query_id, .. } => query_id.ok_or(Error::<T>::QueryNotFound)?,
+            _ => return Err(Error::<T>::XcmExecutionFailed.into()),
+        };
+        let query = ReputationQueries::<T>::get(query_id).ok_or(Error::<T>::QueryNotFound)?;
+        ensure!(query.target_chain == chain.into_inner(), DispatchError::BadOrigin);
+
+        Self::process_xcm_response(query_id, response)
+    }
+
     /// Process XCM response and update query status
+    ///
+    /// An error response marks the query failed and is not itself an error, so the
+    /// failure is kept.
     pub fn process_xcm_response(
         query_id: u64,
         response: ReputationXcmMessage,
     ) -> DispatchResult {
-        // Update query status based on response
+        // Update query status based on response; the off-chain worker caches answers
         match response {
-            ReputationXcmMessage::ReputationResponse { .. } |
-            ReputationXcmMessage::BatchReputationResponse { .. } => {
-                // Mark as completed
+            ReputationXcmMessage::ReputationResponse { score, percentile, .. }
+            | ReputationXcmMessage::VersionedReputationResponse { score, percentile, .. } => {
                 log::info!(
                     target: "pallet-reputation-xcm",
                     "XCM query {} completed successfully",
                     query_id
                 );
-                Ok(())
+                Self::note_query_response(query_id, Some((score, percentile)))
+            }
+            ReputationXcmMessage::BatchReputationResponse { results, .. }
+            | ReputationXcmMessage::VersionedBatchReputationResponse { results, .. } => {
+                let target = ReputationQueries::<T>::get(query_id)
+                    .ok_or(Error::<T>::QueryNotFound)?
+                    .target_account;
+                let answer = results
+                    .into_iter()
+                    .find(|(account, _, _)| *account == target)
+                    .map(|(_, score, percentile)| (score, percentile));
+                Self::note_query_response(query_id, answer)
             }
             ReputationXcmMessage::ReputationError { error_code, error_message, .. } => {
                 log::warn!(
@@ -272,12 +333,30 @@ impl<T: Config> Pallet<T> {
                     error_code,
                     error_message
                 );
-                Err(Error::<T>::XcmExecutionFailed.into())
+                Self::note_query_response(query_id, None)
             }
             _ => Err(Error::<T>::XcmExecutionFailed.into())
         }
     }
 
+    /// Handle a link proof relayed by the chain `origin`
+    ///
+    /// Dispatches `relay_identity_link_proof` with the XCM origin, which `ChainOrigin`
+    /// converts to the relaying chain's id.
+    pub fn handle_identity_link_proof(
+        origin: OriginFor<T>,
+        account_id_bytes: Vec<u8>,
+        remote_account: Vec<u8>,
+        challenge: H256,
+        signature: RemoteSignature,
+    ) -> DispatchResult {
+        let account_id = T::AccountId::decode(&mut &account_id_bytes[..])
+            .map_err(|_| DispatchError::Other("Invalid account ID"))?;
+        let remote_account = RemoteAccount::try_from(remote_account).map_err(|_| Error::<T>::NoLinkChallenge)?;
+
+        Self::relay_identity_link_proof(origin, account_id, remote_account, challenge, signature)
+    }
+
     /// Check and handle XCM query timeouts
     pub fn check_xcm_query_timeouts() {
         let current_block = frame_system::Pallet::<T>::block_number();
@@ -310,36 +389,19 @@ impl<T: Config> Pallet<T> {
     /// Verify cross-chain reputation for use in other parachains
     pub fn verify_cross_chain_reputation(
         account_id: T::AccountId,
-        min_score: i32,
+        min_score: Score,
     ) -> Result<bool, DispatchError> {
         let score = Self::get_reputation(&account_id);
         Ok(score >= min_score)
     }
 
-    /// Get contribution breakdown for an account (helper for XCM responses)
-    fn get_contribution_breakdown(
-        account: &T::AccountId,
-    ) -> Vec<(ContributionType, i32)> {
-        let contributions = AccountContributions::<T>::get(account);
-        let mut breakdown: BTreeMap<ContributionType, i32> = BTreeMap::new();
-
-        for &contribution_id in contributions.iter() {
-            if let Some(contrib) = Contributions::<T>::get(contribution_id) {
-                if contrib.verified {
-                    let base_points = ReputationParams::<T>::get()
-                        .unwrap_or_default()
-                        .contribution_type_weights
-                        .get(&contrib.contribution_type)
-                        .copied()
-                        .unwrap_or(10) as i32;
-                    
-                    let entry = breakdown.entry(contrib.contribution_type).or_insert(0);
-                    *entry = entry.saturating_add(base_points);
-                }
-            }
-        }
-
-        breakdown.into_iter().collect()
+    /// Account standing for a querying location in breakdown allow-lists
+    ///
+    /// Derived from the hash of the encoded location, so a contributor can allow a
+    /// parachain's queries by granting access to its derived account.
+    pub fn location_account(location: &MultiLocation) -> Option<T::AccountId> {
+        let hash = location.using_encoded(sp_io::hashing::blake2_256);
+        T::AccountId::decode(&mut sp_runtime::traits::TrailingZeroInput::new(&hash)).ok()
     }
 }
 
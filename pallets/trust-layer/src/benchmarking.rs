@@ -0,0 +1,706 @@
+//! Benchmarking for the Trust Layer pallet
+//!
+//! Claim benchmarks are parameterized by evidence count (`e`), juror pool size (`p`),
+//! jury size (`j`) and batch/sweep size (`n`) so weights scale with the data touched.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{
+    traits::{Currency, Get},
+    BoundedVec,
+};
+use frame_system::RawOrigin;
+use sp_runtime::{
+    traits::{Bounded, Hash, One, Saturating, Zero},
+    Perbill,
+};
+use sp_std::{vec, vec::Vec};
+
+const SEED: u32 = 0;
+
+fn funded<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+    let who: T::AccountId = account(name, index, SEED);
+    T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 4u32.into());
+    who
+}
+
+fn funded_caller<T: Config>() -> T::AccountId {
+    let who: T::AccountId = whitelisted_caller();
+    T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 4u32.into());
+    who
+}
+
+// Longest UAL accepted by the pallet, distinguished by `index`
+fn ual<T: Config>(index: u32) -> Vec<u8> {
+    let mut ual = vec![b'u'; dotrep_primitives::MAX_UAL_LEN as usize];
+    let tag = index.to_le_bytes();
+    let len = ual.len();
+    ual[len.saturating_sub(4)..].copy_from_slice(&tag[..len.min(4)]);
+    ual
+}
+
+// `ual` as the pallet stores it
+fn stored(ual: &[u8]) -> dotrep_primitives::Ual {
+    dotrep_primitives::Ual::truncate_from(ual.to_vec())
+}
+
+fn evidence<T: Config>(e: u32) -> Vec<Vec<u8>> {
+    (0..e).map(ual::<T>).collect()
+}
+
+fn set_treasury<T: Config>() -> T::AccountId {
+    let treasury = funded::<T>("treasury", 0);
+    TreasuryAccount::<T>::put(&treasury);
+    treasury
+}
+
+fn fill_jury_pool<T: Config>(p: u32) -> Vec<T::AccountId> {
+    (0..p)
+        .map(|i| {
+            let juror = funded::<T>("juror", i);
+            T::BenchmarkHelper::qualify_juror(&juror);
+            assert!(Pallet::<T>::join_jury_pool(RawOrigin::Signed(juror.clone()).into()).is_ok());
+            juror
+        })
+        .collect()
+}
+
+fn post<T: Config>(submitter: &T::AccountId, e: u32) -> u64 {
+    assert!(Pallet::<T>::post_claim(
+        RawOrigin::Signed(submitter.clone()).into(),
+        ual::<T>(0),
+        evidence::<T>(e),
+        T::MinimumStake::get(),
+    )
+    .is_ok());
+    ClaimIdCounter::<T>::get()
+}
+
+fn post_and_challenge<T: Config>(e: u32) -> (u64, T::AccountId, T::AccountId) {
+    let submitter = funded::<T>("submitter", 0);
+    let challenger = funded::<T>("challenger", 0);
+    let claim_id = post::<T>(&submitter, e);
+    assert!(Pallet::<T>::challenge_claim(
+        RawOrigin::Signed(challenger.clone()).into(),
+        claim_id,
+        evidence::<T>(e),
+        T::MinimumStake::get(),
+    )
+    .is_ok());
+    (claim_id, submitter, challenger)
+}
+
+// Reveal the seed round of the claim's pending jury draw and draw the jury
+fn draw_pending_jury<T: Config>(claim_id: u64) {
+    let draw = PendingJuryDraws::<T>::get(claim_id).expect("jury draw pending");
+    JurySeeds::<T>::insert(draw.seed_round, JurySeed { seed: T::Hashing::hash(b"seed"), reveals: 1 });
+    frame_system::Pallet::<T>::set_block_number(Pallet::<T>::seed_revealed_at(draw.seed_round));
+    assert!(Pallet::<T>::draw_jury(RawOrigin::Signed(funded_caller::<T>()).into(), claim_id).is_ok());
+}
+
+// Back `contributor` with the minimum stake, offered and accepted
+fn back<T: Config>(backer: &T::AccountId, contributor: &T::AccountId) {
+    assert!(Pallet::<T>::back_contributor(
+        RawOrigin::Signed(backer.clone()).into(),
+        contributor.clone(),
+        T::MinimumStake::get(),
+    )
+    .is_ok());
+    assert!(Pallet::<T>::accept_backing(RawOrigin::Signed(contributor.clone()).into(), backer.clone()).is_ok());
+}
+
+fn open_channel<T: Config>(payer: &T::AccountId, payee: &T::AccountId) {
+    assert!(Pallet::<T>::open_payment_channel(
+        RawOrigin::Signed(payer.clone()).into(),
+        payee.clone(),
+        T::MeteredQueryFee::get().saturating_mul(10u32.into()),
+        1000u32.into(),
+    )
+    .is_ok());
+}
+
+fn enable_pricing<T: Config>() {
+    Pricing::<T>::put(PricingConfig {
+        floor: Zero::zero(),
+        ceiling: BalanceOf::<T>::max_value(),
+        target_volume: 1,
+        adjustment: Perbill::from_percent(10),
+        window: One::one(),
+    });
+}
+
+benchmarks! {
+    stake_tokens {
+        let caller = funded_caller::<T>();
+        let amount = T::MinimumStake::get();
+    }: _(RawOrigin::Signed(caller.clone()), amount)
+    verify {
+        assert_eq!(StakedAmount::<T>::get(&caller), amount);
+    }
+
+    unstake_tokens {
+        let caller = funded_caller::<T>();
+        let amount = T::MinimumStake::get();
+        Pallet::<T>::stake_tokens(RawOrigin::Signed(caller.clone()).into(), amount)?;
+    }: _(RawOrigin::Signed(caller.clone()), amount)
+    verify {
+        assert!(StakedAmount::<T>::get(&caller).is_zero());
+    }
+
+    pay_for_query {
+        // Worst case: registered provider, demand pricing with a window rollover
+        let caller = funded_caller::<T>();
+        set_treasury::<T>();
+        let provider = funded::<T>("provider", 0);
+        let ual = T::BenchmarkHelper::owned_ual(&provider);
+        Pallet::<T>::register_data_provider(RawOrigin::Signed(provider).into(), ual.clone())?;
+        enable_pricing::<T>();
+        Pallet::<T>::pay_for_query(RawOrigin::Signed(caller.clone()).into(), ual.clone(), 10u32.into())?;
+        frame_system::Pallet::<T>::set_block_number(10u32.into());
+    }: _(RawOrigin::Signed(caller.clone()), ual.clone(), 10u32.into())
+    verify {
+        assert!(Pallet::<T>::has_query_access(&caller, &stored(&ual)));
+    }
+
+    open_payment_channel {
+        let caller = funded_caller::<T>();
+        let payee = funded::<T>("payee", 0);
+    }: _(RawOrigin::Signed(caller.clone()), payee.clone(), T::MinimumStake::get(), 100u32.into())
+    verify {
+        assert!(PaymentChannels::<T>::contains_key(&caller, &payee));
+    }
+
+    close_payment_channel {
+        let caller = funded_caller::<T>();
+        let payee = funded::<T>("payee", 0);
+        open_channel::<T>(&caller, &payee);
+    }: _(RawOrigin::Signed(caller.clone()), payee.clone())
+    verify {
+        assert!(!PaymentChannels::<T>::contains_key(&caller, &payee));
+    }
+
+    set_custom_query_price {
+        let caller = funded_caller::<T>();
+        let ual = T::BenchmarkHelper::owned_ual(&caller);
+        Pallet::<T>::register_data_provider(RawOrigin::Signed(caller.clone()).into(), ual.clone())?;
+        let price = T::BaseQueryPrice::get();
+    }: _(RawOrigin::Signed(caller), ual.clone(), price)
+    verify {
+        assert_eq!(CustomQueryPrice::<T>::get(stored(&ual)), Some(price));
+    }
+
+    set_treasury {
+        let treasury = funded::<T>("treasury", 0);
+    }: _(RawOrigin::Root, treasury.clone())
+    verify {
+        assert_eq!(TreasuryAccount::<T>::get(), Some(treasury));
+    }
+
+    post_claim {
+        let e in 0 .. T::MaxEvidenceItems::get();
+        let caller = funded_caller::<T>();
+    }: _(RawOrigin::Signed(caller.clone()), ual::<T>(0), evidence::<T>(e), T::MinimumStake::get())
+    verify {
+        assert_eq!(SubmitterClaims::<T>::get(&caller).len(), 1);
+    }
+
+    challenge_claim {
+        let e in 0 .. T::MaxEvidenceItems::get();
+        let p in (T::JurySize::get()) .. T::MaxJurorPool::get();
+        fill_jury_pool::<T>(p);
+        let submitter = funded::<T>("submitter", 0);
+        let claim_id = post::<T>(&submitter, e);
+        let caller = funded_caller::<T>();
+    }: _(RawOrigin::Signed(caller), claim_id, evidence::<T>(e), T::MinimumStake::get())
+    verify {
+        assert!(PendingJuryDraws::<T>::contains_key(claim_id));
+    }
+
+    resolve_claim {
+        set_treasury::<T>();
+        // Empty juror pool: the challenge escalates straight to governance
+        let (claim_id, ..) = post_and_challenge::<T>(T::MaxEvidenceItems::get());
+    }: _(RawOrigin::Root, claim_id, ClaimResolution::Rejected)
+    verify {
+        assert_eq!(Claims::<T>::get(claim_id).map(|claim| claim.status), Some(ClaimStatus::Resolved));
+    }
+
+    register_data_provider {
+        let caller = funded_caller::<T>();
+        let ual = T::BenchmarkHelper::owned_ual(&caller);
+        // Worst case replaces a provider that lost the UAL, along with its price
+        let stale: T::AccountId = account("stale", 0, 0);
+        DataProviders::<T>::insert(stored(&ual), &stale);
+        CustomQueryPrice::<T>::insert(stored(&ual), T::BaseQueryPrice::get());
+    }: _(RawOrigin::Signed(caller.clone()), ual.clone())
+    verify {
+        assert_eq!(DataProviders::<T>::get(stored(&ual)), Some(caller));
+    }
+
+    deregister_data_provider {
+        let caller = funded_caller::<T>();
+        let ual = T::BenchmarkHelper::owned_ual(&caller);
+        Pallet::<T>::register_data_provider(RawOrigin::Signed(caller.clone()).into(), ual.clone())?;
+        Pallet::<T>::set_custom_query_price(RawOrigin::Signed(caller.clone()).into(), ual.clone(), T::BaseQueryPrice::get())?;
+    }: _(RawOrigin::Signed(caller), ual.clone())
+    verify {
+        assert!(DataProviders::<T>::get(stored(&ual)).is_none());
+    }
+
+    set_asset_config {
+        let asset_id = T::BenchmarkHelper::funded_asset(&funded_caller::<T>(), 1_000u32.into());
+    }: _(RawOrigin::Root, asset_id, 10u32.into(), 1u32.into())
+    verify {
+        assert!(AssetConfigs::<T>::contains_key(asset_id));
+    }
+
+    remove_asset_config {
+        let asset_id = T::BenchmarkHelper::funded_asset(&funded_caller::<T>(), 1_000u32.into());
+        Pallet::<T>::set_asset_config(RawOrigin::Root.into(), asset_id, 10u32.into(), 1u32.into())?;
+    }: _(RawOrigin::Root, asset_id)
+    verify {
+        assert!(!AssetConfigs::<T>::contains_key(asset_id));
+    }
+
+    stake_asset {
+        let caller = funded_caller::<T>();
+        let asset_id = T::BenchmarkHelper::funded_asset(&caller, 1_000u32.into());
+        Pallet::<T>::set_asset_config(RawOrigin::Root.into(), asset_id, 10u32.into(), 1u32.into())?;
+    }: _(RawOrigin::Signed(caller.clone()), asset_id, 100u32.into())
+    verify {
+        assert_eq!(AssetStakes::<T>::get(&caller, asset_id), 100u32.into());
+    }
+
+    unstake_asset {
+        let caller = funded_caller::<T>();
+        let asset_id = T::BenchmarkHelper::funded_asset(&caller, 1_000u32.into());
+        Pallet::<T>::set_asset_config(RawOrigin::Root.into(), asset_id, 10u32.into(), 1u32.into())?;
+        Pallet::<T>::stake_asset(RawOrigin::Signed(caller.clone()).into(), asset_id, 100u32.into())?;
+    }: _(RawOrigin::Signed(caller.clone()), asset_id, 100u32.into())
+    verify {
+        assert!(AssetStakes::<T>::get(&caller, asset_id).is_zero());
+    }
+
+    pay_for_query_with_asset {
+        let caller = funded_caller::<T>();
+        set_treasury::<T>();
+        let asset_id = T::BenchmarkHelper::funded_asset(&caller, 1_000u32.into());
+        let treasury = TreasuryAccount::<T>::get().expect("treasury set");
+        T::BenchmarkHelper::funded_asset(&treasury, 1_000u32.into());
+        Pallet::<T>::set_asset_config(RawOrigin::Root.into(), asset_id, 10u32.into(), 1u32.into())?;
+        let ual = ual::<T>(0);
+    }: _(RawOrigin::Signed(caller.clone()), asset_id, ual.clone(), 10u32.into())
+    verify {
+        assert!(Pallet::<T>::has_query_access(&caller, &stored(&ual)));
+    }
+
+    open_asset_channel {
+        let caller = funded_caller::<T>();
+        let payee = funded::<T>("payee", 0);
+        let asset_id = T::BenchmarkHelper::funded_asset(&caller, 1_000u32.into());
+        T::BenchmarkHelper::funded_asset(&Pallet::<T>::escrow_account(), 1_000u32.into());
+        Pallet::<T>::set_asset_config(RawOrigin::Root.into(), asset_id, 10u32.into(), 1u32.into())?;
+    }: _(RawOrigin::Signed(caller.clone()), payee.clone(), asset_id, 100u32.into(), 100u32.into())
+    verify {
+        assert!(AssetPaymentChannels::<T>::contains_key((&caller, &payee, asset_id)));
+    }
+
+    close_asset_channel {
+        let caller = funded_caller::<T>();
+        let payee = funded::<T>("payee", 0);
+        let asset_id = T::BenchmarkHelper::funded_asset(&caller, 1_000u32.into());
+        T::BenchmarkHelper::funded_asset(&Pallet::<T>::escrow_account(), 1_000u32.into());
+        Pallet::<T>::set_asset_config(RawOrigin::Root.into(), asset_id, 10u32.into(), 1u32.into())?;
+        Pallet::<T>::open_asset_channel(
+            RawOrigin::Signed(caller.clone()).into(),
+            payee.clone(),
+            asset_id,
+            100u32.into(),
+            100u32.into(),
+        )?;
+    }: _(RawOrigin::Signed(caller.clone()), payee.clone(), asset_id)
+    verify {
+        assert!(!AssetPaymentChannels::<T>::contains_key((&caller, &payee, asset_id)));
+    }
+
+    finalize_claim {
+        let submitter = funded::<T>("submitter", 0);
+        let claim_id = post::<T>(&submitter, T::MaxEvidenceItems::get());
+        let deadline = Claims::<T>::get(claim_id).expect("claim posted").challenge_deadline;
+        frame_system::Pallet::<T>::set_block_number(deadline.saturating_add(One::one()));
+        let caller = funded_caller::<T>();
+    }: _(RawOrigin::Signed(caller), claim_id)
+    verify {
+        assert_eq!(Claims::<T>::get(claim_id).map(|claim| claim.status), Some(ClaimStatus::Resolved));
+    }
+
+    join_jury_pool {
+        let p in 0 .. (T::MaxJurorPool::get() - 1);
+        fill_jury_pool::<T>(p);
+        let caller = funded_caller::<T>();
+        T::BenchmarkHelper::qualify_juror(&caller);
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert!(JurorStakes::<T>::contains_key(&caller));
+    }
+
+    leave_jury_pool {
+        let p in 0 .. (T::MaxJurorPool::get() - 1);
+        fill_jury_pool::<T>(p);
+        let caller = funded_caller::<T>();
+        T::BenchmarkHelper::qualify_juror(&caller);
+        Pallet::<T>::join_jury_pool(RawOrigin::Signed(caller.clone()).into())?;
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert!(!JurorStakes::<T>::contains_key(&caller));
+    }
+
+    cast_jury_vote {
+        fill_jury_pool::<T>(T::MaxJurorPool::get());
+        let (claim_id, ..) = post_and_challenge::<T>(0);
+        draw_pending_jury::<T>(claim_id);
+        let juror = ClaimJuries::<T>::get(claim_id).expect("jury drawn").jurors[0].clone();
+    }: _(RawOrigin::Signed(juror.clone()), claim_id, ClaimResolution::Rejected)
+    verify {
+        assert!(JuryVotes::<T>::contains_key(claim_id, &juror));
+    }
+
+    tally_jury {
+        let j in (T::JurySize::get()) .. T::MaxJurySize::get().min(T::MaxJurorPool::get());
+        let pool = fill_jury_pool::<T>(T::MaxJurorPool::get());
+        let (claim_id, ..) = post_and_challenge::<T>(0);
+        draw_pending_jury::<T>(claim_id);
+
+        // Seat a jury of `j` with a split vote so both rewards and slashes are paid
+        let previous = ClaimJuries::<T>::get(claim_id).expect("jury drawn");
+        for juror in previous.jurors.iter() {
+            JurorAssignments::<T>::mutate(juror, |count| *count = count.saturating_sub(1));
+        }
+        let jurors: Vec<T::AccountId> = pool.into_iter().take(j as usize).collect();
+        for (index, juror) in jurors.iter().enumerate() {
+            JurorAssignments::<T>::mutate(juror, |count| *count = count.saturating_add(1));
+            let vote = if index % 3 == 2 { ClaimResolution::Accepted } else { ClaimResolution::Rejected };
+            JuryVotes::<T>::insert(claim_id, juror, vote);
+        }
+        ClaimJuries::<T>::insert(claim_id, Jury {
+            jurors: jurors.try_into().expect("j <= MaxJurySize"),
+            voting_deadline: previous.voting_deadline,
+        });
+        frame_system::Pallet::<T>::set_block_number(previous.voting_deadline.saturating_add(One::one()));
+        let caller = funded_caller::<T>();
+    }: _(RawOrigin::Signed(caller), claim_id)
+    verify {
+        assert_eq!(Claims::<T>::get(claim_id).map(|claim| claim.status), Some(ClaimStatus::Decided));
+    }
+
+    appeal_claim {
+        let p in (Pallet::<T>::jury_size(1)) .. T::MaxJurorPool::get();
+        fill_jury_pool::<T>(p);
+        let (claim_id, submitter, _) = post_and_challenge::<T>(0);
+
+        // Force a rejected verdict open for appeal
+        let now = frame_system::Pallet::<T>::block_number();
+        Claims::<T>::mutate(claim_id, |claim| {
+            if let Some(claim) = claim {
+                claim.status = ClaimStatus::Decided;
+                claim.resolution = Some(ClaimResolution::Rejected);
+            }
+        });
+        PendingJuryDraws::<T>::remove(claim_id);
+        AppealDeadlines::<T>::insert(claim_id, now.saturating_add(T::AppealPeriod::get()));
+    }: _(RawOrigin::Signed(submitter), claim_id)
+    verify {
+        assert_eq!(ClaimRounds::<T>::get(claim_id), 1);
+    }
+
+    pay_for_queries {
+        let n in 1 .. T::MaxQueryBatch::get();
+        let caller = funded_caller::<T>();
+        set_treasury::<T>();
+        enable_pricing::<T>();
+
+        // Every item pays a distinct registered provider
+        let mut queries = Vec::new();
+        for i in 0 .. n {
+            let provider = funded::<T>("provider", i);
+            let ual = T::BenchmarkHelper::owned_ual(&provider);
+            Pallet::<T>::register_data_provider(RawOrigin::Signed(provider).into(), ual.clone())?;
+            queries.push((ual, 10u32.into()));
+        }
+        let queries: BoundedVec<_, T::MaxQueryBatch> = queries.try_into().expect("n <= MaxQueryBatch");
+    }: _(RawOrigin::Signed(caller.clone()), queries)
+    verify {
+        assert_eq!(QueryAccess::<T>::iter_prefix(&caller).count(), n as usize);
+    }
+
+    set_pricing_config {
+        let config = PricingConfig {
+            floor: Zero::zero(),
+            ceiling: T::BaseQueryPrice::get().saturating_mul(10u32.into()),
+            target_volume: 10,
+            adjustment: Perbill::from_percent(5),
+            window: 100u32.into(),
+        };
+    }: _(RawOrigin::Root, Some(config))
+    verify {
+        assert!(Pricing::<T>::get().is_some());
+    }
+
+    enable_metered_access {
+        let caller = funded_caller::<T>();
+        let payee = funded::<T>("payee", 0);
+        open_channel::<T>(&caller, &payee);
+        let ual = ual::<T>(0);
+    }: _(RawOrigin::Signed(caller.clone()), ual.clone(), payee)
+    verify {
+        assert!(MeteredAccess::<T>::contains_key(&caller, stored(&ual)));
+    }
+
+    record_metered_query {
+        let consumer = funded::<T>("consumer", 0);
+        let caller = funded_caller::<T>();
+        open_channel::<T>(&consumer, &caller);
+        let ual = ual::<T>(0);
+        Pallet::<T>::enable_metered_access(RawOrigin::Signed(consumer.clone()).into(), ual.clone(), caller.clone())?;
+    }: _(RawOrigin::Signed(caller), consumer.clone(), ual.clone())
+    verify {
+        assert!(MeteredAccess::<T>::contains_key(&consumer, stored(&ual)));
+    }
+
+    disable_metered_access {
+        let caller = funded_caller::<T>();
+        let payee = funded::<T>("payee", 0);
+        open_channel::<T>(&caller, &payee);
+        let ual = ual::<T>(0);
+        Pallet::<T>::enable_metered_access(RawOrigin::Signed(caller.clone()).into(), ual.clone(), payee)?;
+    }: _(RawOrigin::Signed(caller.clone()), ual.clone())
+    verify {
+        assert!(!MeteredAccess::<T>::contains_key(&caller, stored(&ual)));
+    }
+
+    sweep_claims {
+        let n in 0 .. T::MaxClaimsSweptPerBlock::get();
+        for i in 0 .. n {
+            post::<T>(&funded::<T>("submitter", i), T::MaxEvidenceItems::get());
+        }
+        let now = frame_system::Pallet::<T>::block_number();
+        let due = now.saturating_add(T::ChallengePeriod::get()).saturating_add(One::one());
+        frame_system::Pallet::<T>::set_block_number(due);
+    }: {
+        Pallet::<T>::sweep_claims(due);
+    }
+    verify {
+        assert!(ClaimAgenda::<T>::get(due).is_empty());
+    }
+
+    set_slashing_policy {
+        let policy = SlashingPolicy {
+            first_offence: Perbill::from_percent(30),
+            escalation: Perbill::from_percent(20),
+            max_severity: Perbill::one(),
+            decay_period: 1_000u32.into(),
+        };
+    }: _(RawOrigin::Root, Some(policy))
+    verify {
+        assert!(Slashing::<T>::get().is_some());
+    }
+
+    report_contribution {
+        let e in 0 .. T::MaxEvidenceItems::get();
+        let contributor = funded::<T>("contributor", 0);
+        let contribution_id = T::BenchmarkHelper::verified_contribution(&contributor);
+        let caller = funded_caller::<T>();
+    }: _(RawOrigin::Signed(caller.clone()), contribution_id, evidence::<T>(e), T::MinimumStake::get())
+    verify {
+        assert!(ReportedContributions::<T>::contains_key(contribution_id));
+    }
+
+    back_contributor {
+        let contributor = funded::<T>("contributor", 0);
+        let caller = funded_caller::<T>();
+    }: _(RawOrigin::Signed(caller.clone()), contributor.clone(), T::MinimumStake::get())
+    verify {
+        assert!(BackingOffers::<T>::contains_key(&contributor, &caller));
+    }
+
+    accept_backing {
+        let b in 0 .. (T::MaxBackers::get() - 1);
+        let contributor = funded::<T>("contributor", 0);
+        for i in 0..b {
+            back::<T>(&funded::<T>("backer", i), &contributor);
+        }
+        let backer = funded_caller::<T>();
+        Pallet::<T>::back_contributor(RawOrigin::Signed(backer.clone()).into(), contributor.clone(), T::MinimumStake::get())?;
+    }: _(RawOrigin::Signed(contributor.clone()), backer)
+    verify {
+        assert_eq!(Backers::<T>::get(&contributor).len() as u32, b + 1);
+    }
+
+    cancel_backing_offer {
+        let contributor = funded::<T>("contributor", 0);
+        let backer = funded_caller::<T>();
+        Pallet::<T>::back_contributor(RawOrigin::Signed(backer.clone()).into(), contributor.clone(), T::MinimumStake::get())?;
+    }: _(RawOrigin::Signed(contributor.clone()), contributor.clone(), backer.clone())
+    verify {
+        assert!(!BackingOffers::<T>::contains_key(&contributor, &backer));
+    }
+
+    withdraw_backing {
+        let b in 1 .. T::MaxBackers::get();
+        let contributor = funded::<T>("contributor", 0);
+        let backers: Vec<T::AccountId> = (0..b).map(|i| funded::<T>("backer", i)).collect();
+        for backer in backers.iter() {
+            back::<T>(backer, &contributor);
+        }
+        let caller = backers[0].clone();
+    }: _(RawOrigin::Signed(caller), contributor.clone(), T::MinimumStake::get())
+    verify {
+        assert_eq!(Backers::<T>::get(&contributor).len() as u32, b - 1);
+    }
+
+    set_challenge_window {
+    }: _(RawOrigin::Root, Some(2_000u32.into()))
+    verify {
+        assert_eq!(Pallet::<T>::challenge_window(), 2_000u32.into());
+    }
+
+    post_contribution_claim {
+        let e in 0 .. T::MaxEvidenceItems::get();
+        let caller = funded_caller::<T>();
+        let contribution_id = T::BenchmarkHelper::verified_contribution(&caller);
+    }: _(RawOrigin::Signed(caller.clone()), contribution_id, evidence::<T>(e), T::MinimumStake::get())
+    verify {
+        assert_eq!(ContributionClaims::<T>::get(ClaimIdCounter::<T>::get()), Some(contribution_id));
+    }
+
+    top_up_prepaid {
+        let caller = funded_caller::<T>();
+        let amount = T::BaseQueryPrice::get().saturating_mul(100u32.into());
+    }: _(RawOrigin::Signed(caller.clone()), amount)
+    verify {
+        assert_eq!(PrepaidAccounts::<T>::get(&caller).map(|account| account.balance), Some(amount));
+    }
+
+    pay_for_query_prepaid {
+        // Worst case: registered provider and demand pricing with a window rollover
+        let caller = funded_caller::<T>();
+        set_treasury::<T>();
+        let provider = funded::<T>("provider", 0);
+        let ual = T::BenchmarkHelper::owned_ual(&provider);
+        Pallet::<T>::register_data_provider(RawOrigin::Signed(provider).into(), ual.clone())?;
+        enable_pricing::<T>();
+        Pallet::<T>::top_up_prepaid(
+            RawOrigin::Signed(caller.clone()).into(),
+            BalanceOf::<T>::max_value() / 8u32.into(),
+        )?;
+        Pallet::<T>::pay_for_query_prepaid(RawOrigin::Signed(caller.clone()).into(), ual.clone(), 10u32.into())?;
+        frame_system::Pallet::<T>::set_block_number(10u32.into());
+    }: _(RawOrigin::Signed(caller.clone()), ual.clone(), 10u32.into())
+    verify {
+        assert!(Pallet::<T>::has_query_access(&caller, &stored(&ual)));
+    }
+
+    close_billing_period {
+        let n in 0 .. T::MaxInvoiceItems::get();
+        let organization = funded::<T>("organization", 0);
+        set_treasury::<T>();
+        Pallet::<T>::top_up_prepaid(
+            RawOrigin::Signed(organization.clone()).into(),
+            BalanceOf::<T>::max_value() / 8u32.into(),
+        )?;
+        for i in 0..n {
+            Pallet::<T>::pay_for_query_prepaid(
+                RawOrigin::Signed(organization.clone()).into(),
+                ual::<T>(i),
+                10u32.into(),
+            )?;
+        }
+        frame_system::Pallet::<T>::set_block_number(T::BillingPeriod::get());
+        let caller = funded_caller::<T>();
+    }: _(RawOrigin::Signed(caller), organization.clone())
+    verify {
+        assert_eq!(BillingStatements::<T>::get(&organization, 0).map(|statement| statement.items), Some(n));
+    }
+
+    withdraw_prepaid {
+        let caller = funded_caller::<T>();
+        let amount = T::BaseQueryPrice::get().saturating_mul(100u32.into());
+        Pallet::<T>::top_up_prepaid(RawOrigin::Signed(caller.clone()).into(), amount)?;
+    }: _(RawOrigin::Signed(caller.clone()), amount)
+    verify {
+        assert_eq!(PrepaidAccounts::<T>::get(&caller).map(|account| account.balance), Some(Zero::zero()));
+    }
+
+    prune_billing_statement {
+        let organization = funded::<T>("organization", 0);
+        Pallet::<T>::top_up_prepaid(RawOrigin::Signed(organization.clone()).into(), T::BaseQueryPrice::get())?;
+        frame_system::Pallet::<T>::set_block_number(T::BillingPeriod::get());
+        Pallet::<T>::close_billing_period(RawOrigin::Signed(organization.clone()).into(), organization.clone())?;
+        let retained = T::BillingStatementRetention::get().saturating_add(2);
+        frame_system::Pallet::<T>::set_block_number(T::BillingPeriod::get() * retained.into());
+        let caller = funded_caller::<T>();
+    }: _(RawOrigin::Signed(caller), organization.clone(), 0)
+    verify {
+        assert!(BillingStatements::<T>::get(&organization, 0).is_none());
+    }
+
+    draw_jury {
+        let p in (T::JurySize::get()) .. T::MaxJurorPool::get();
+        fill_jury_pool::<T>(p);
+        let (claim_id, ..) = post_and_challenge::<T>(0);
+        let draw = PendingJuryDraws::<T>::get(claim_id).expect("jury draw pending");
+        JurySeeds::<T>::insert(draw.seed_round, JurySeed { seed: T::Hashing::hash(b"seed"), reveals: 1 });
+        frame_system::Pallet::<T>::set_block_number(Pallet::<T>::seed_revealed_at(draw.seed_round));
+        let caller = funded_caller::<T>();
+    }: _(RawOrigin::Signed(caller), claim_id)
+    verify {
+        assert!(ClaimJuries::<T>::contains_key(claim_id));
+    }
+
+    commit_jury_seed {
+        let caller = funded_caller::<T>();
+        T::BenchmarkHelper::qualify_juror(&caller);
+        Pallet::<T>::join_jury_pool(RawOrigin::Signed(caller.clone()).into())?;
+        let commitment = T::Hashing::hash_of(&(&caller, [7u8; 32]));
+    }: _(RawOrigin::Signed(caller.clone()), commitment)
+    verify {
+        assert!(SeedCommitments::<T>::contains_key(0, &caller));
+    }
+
+    reveal_jury_seed {
+        let caller = funded_caller::<T>();
+        T::BenchmarkHelper::qualify_juror(&caller);
+        Pallet::<T>::join_jury_pool(RawOrigin::Signed(caller.clone()).into())?;
+        let secret = [7u8; 32];
+        // Worst case also prunes the seed of an expired round
+        let seed_round = JURY_SEED_RETENTION;
+        SeedCommitments::<T>::insert(seed_round, &caller, T::Hashing::hash_of(&(&caller, secret)));
+        UnrevealedSeeds::<T>::insert(&caller, 1);
+        JurySeeds::<T>::insert(0, JurySeed { seed: T::Hashing::hash(b"seed"), reveals: 1 });
+        frame_system::Pallet::<T>::set_block_number(T::JurySeedPeriod::get() * (seed_round + 1).into());
+    }: _(RawOrigin::Signed(caller.clone()), seed_round, secret)
+    verify {
+        assert_eq!(JurySeeds::<T>::get(seed_round).reveals, 1);
+        assert_eq!(JurySeeds::<T>::get(0).reveals, 0);
+    }
+
+    slash_unrevealed_seed {
+        let juror = funded::<T>("juror", 0);
+        T::BenchmarkHelper::qualify_juror(&juror);
+        Pallet::<T>::join_jury_pool(RawOrigin::Signed(juror.clone()).into())?;
+        Pallet::<T>::commit_jury_seed(RawOrigin::Signed(juror.clone()).into(), T::Hashing::hash(b"commitment"))?;
+        frame_system::Pallet::<T>::set_block_number(Pallet::<T>::seed_revealed_at(0));
+        let caller = funded_caller::<T>();
+    }: _(RawOrigin::Signed(caller), 0, juror.clone())
+    verify {
+        assert!(!SeedCommitments::<T>::contains_key(0, &juror));
+    }
+
+    impl_benchmark_test_suite!(
+        Pallet,
+        crate::mock::new_test_ext(),
+        crate::mock::Test
+    );
+}
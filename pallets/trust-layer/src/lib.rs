@@ -7,13 +7,38 @@
 // Features:
 // - x402 micropayment support for premium reputation queries
 // - Token staking for reputation credibility
-// - Integration with TRAC/NEURO tokens
+// - Integration with TRAC/NEURO tokens (native currency or pallet-assets)
 // - Payment channels for efficient micropayments
+// - Data provider registry with UAL ownership proof and fee splitting
+// - Stake slashing for finalized reputation offences
+// - Reputation-weighted jury resolution for challenged claims
+// - Jury draws seeded by secrets the juror pool commits and reveals
+// - Multi-round appeals with growing juries and governance as the last round
+// - Status and deadline indexes driving automatic claim finalization
+// - Demand-based query pricing within governance-set bounds
+// - Usage-metered query access debited per query from payment channels
+// - Escalating partial slashing of rejected claims with decaying offence counts
+// - Multi-block claim migrations with claim calls gated while they run
+// - Genesis configuration of the treasury, payment assets and pricing
+// - Staked fraud reports against verified contributions, settled as claims
+// - Third-party backing stakes sharing a contributor's query revenue and fraud slashes
+// - Events indexed by account, claim, UAL and asset topics
+// - Query revenue accrued per UAL, provider and treasury period for dashboards
+// - Claims anchored to a contributor's own contributions, with contribution-aware feedback
+// - Prepaid query balances for organizations, itemized per billing period with closing statements
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
 
+pub mod migrations;
+pub mod runtime_api;
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 #[cfg(test)]
 mod mock;
 
@@ -22,17 +47,141 @@ mod tests;
 
 #[frame_support::pallet]
 pub mod pallet {
+    use super::*;
     use frame_support::{
         dispatch::DispatchResult,
         pallet_prelude::*,
-        traits::{Currency, ExistenceRequirement, ReservableCurrency},
+        traits::{
+            tokens::fungibles::{self, Inspect as FungiblesInspect},
+            BalanceStatus, Currency, ExistenceRequirement, Imbalance, NamedReservableCurrency, OnUnbalanced,
+            Randomness, ReservableCurrency, Time,
+        },
+        storage::with_storage_layer,
+        PalletId,
     };
     use frame_system::pallet_prelude::*;
-    use sp_std::vec::Vec;
+    use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
     use codec::{Encode, Decode};
     use scale_info::TypeInfo;
+    use sp_runtime::{
+        traits::{AccountIdConversion, Hash, One, SaturatedConversion, Zero},
+        Perbill,
+    };
+    use dotrep_primitives::{
+        ClaimVerdict, ContributionDisputes, ContributionId, ContributionRegistry, CredibilityBoostProvider, HookQuotaProvider,
+        HookTask, OnClaimResolved, OnReputationOffence, ReputationOffence, ReputationProvider, Score, Topic, Ual, sortition,
+    };
+    use pallet_reputation::migrations::{MigrationCursor, SteppedMigration};
+
+    pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+    type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
+    /// Bounded list of evidence UALs
+    pub type EvidenceOf<T> = BoundedVec<Ual, <T as Config>::MaxEvidenceItems>;
+
+    /// Idle pricing windows applied at most when a UAL is queried again
+    const MAX_IDLE_PRICE_STEPS: u32 = 10;
+
+    /// Later blocks probed when a claim agenda slot is full
+    const MAX_AGENDA_PROBES: u32 = 10;
+
+    /// Blocks a posted claim stays open to challenge unless governance sets otherwise
+    pub const DEFAULT_CHALLENGE_WINDOW: u32 = 1000;
+
+    /// Blocks per accounting period of `TreasuryIncome`
+    pub const REVENUE_PERIOD_BLOCKS: u32 = 100;
+
+    /// Most accounting periods one `treasury_income` call sums
+    pub const MAX_INCOME_PERIODS: u32 = 1_000;
+
+    /// Named reserve holding prepaid query balances, apart from stakes and deposits
+    pub const PREPAID_RESERVE_ID: [u8; 8] = *b"dr/prepd";
+
+    /// Seed rounds a revealed jury seed is kept for; draws left pending longer escalate
+    pub const JURY_SEED_RETENTION: u32 = 8;
+
+    /// The in-code storage version
+    pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
+    pub type AssetBalanceOf<T> = <<T as Config>::Assets as FungiblesInspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+    /// Per-asset payment configuration (governance-set)
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+    pub struct AssetConfig<Balance> {
+        pub minimum_stake: Balance,
+        pub base_query_price: Balance,
+    }
+
+    /// Demand-based pricing parameters (governance-set)
+    ///
+    /// At the end of each window a UAL's price moves up by `adjustment` if its query
+    /// volume exceeded `target_volume`, down if it fell short, clamped to `floor..=ceiling`.
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+    pub struct PricingConfig<Balance, BlockNumber> {
+        pub floor: Balance,
+        pub ceiling: Balance,
+        pub target_volume: u32,
+        pub adjustment: Perbill,
+        pub window: BlockNumber,
+    }
+
+    /// Slashing severity for rejected claims (governance-set)
+    ///
+    /// An account's n-th counted offence forfeits `first_offence + n * escalation` of the
+    /// claim stake, capped at `max_severity`. One offence is forgiven per `decay_period`.
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+    pub struct SlashingPolicy<BlockNumber> {
+        pub first_offence: Perbill,
+        pub escalation: Perbill,
+        pub max_severity: Perbill,
+        pub decay_period: BlockNumber,
+    }
+
+    /// Rejected-claim offences counted against an account
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, Default, TypeInfo, MaxEncodedLen)]
+    pub struct OffenceRecord<BlockNumber> {
+        pub count: u32,
+        pub last_offence: BlockNumber,
+    }
+
+    /// Query volume of a UAL in the current pricing window
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, Default, TypeInfo, MaxEncodedLen)]
+    pub struct QueryVolumeWindow<BlockNumber> {
+        pub window_start: BlockNumber,
+        pub queries: u32,
+    }
+
+    /// Prepaid balance an organization draws bulk query access from
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, Default, TypeInfo, MaxEncodedLen)]
+    pub struct PrepaidAccount<Balance> {
+        /// Reserved funds not yet consumed by queries
+        pub balance: Balance,
+        /// Billing period consumption is currently itemized under
+        pub period: u32,
+    }
+
+    /// Query paid from a prepaid balance, itemized on the organization's statement
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct InvoiceItem<T: Config> {
+        pub ual: Ual,
+        pub amount: BalanceOf<T>,
+        pub block: T::BlockNumber,
+        /// Milliseconds since the Unix epoch
+        pub timestamp: u64,
+    }
 
-    type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+    /// Closed billing period of a prepaid account
+    ///
+    /// `statement_hash` is the hash of `(organization, period, items)`, with the items
+    /// SCALE-encoded in the order they were consumed, so off-chain accounting can check
+    /// its ledger against the chain.
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+    pub struct BillingStatement<Balance, Hash> {
+        pub items: u32,
+        pub total: Balance,
+        pub statement_hash: Hash,
+    }
 
     /// Claim status
     #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
@@ -40,6 +189,11 @@ pub mod pallet {
         Pending,
         Challenged,
         Resolved,
+        /// Jury could not reach a majority, appeals are exhausted or a fraud report went
+        /// unchallenged; awaiting governance resolution
+        Escalated,
+        /// Jury verdict reached; open for appeal until the appeal deadline
+        Decided,
     }
 
     /// Claim resolution
@@ -52,11 +206,12 @@ pub mod pallet {
 
     /// Claim data structure
     #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
     pub struct Claim<T: Config> {
         pub id: u64,
         pub submitter: T::AccountId,
-        pub claim_ual: Vec<u8>,
-        pub evidence_uals: Vec<Vec<u8>>,
+        pub claim_ual: Ual,
+        pub evidence_uals: EvidenceOf<T>,
         pub stake: BalanceOf<T>,
         pub status: ClaimStatus,
         pub created_at: T::BlockNumber,
@@ -67,20 +222,54 @@ pub mod pallet {
 
     /// Challenge data structure
     #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
     pub struct Challenge<T: Config> {
         pub claim_id: u64,
         pub challenger: T::AccountId,
-        pub counter_evidence_uals: Vec<Vec<u8>>,
+        pub counter_evidence_uals: EvidenceOf<T>,
         pub stake: BalanceOf<T>,
         pub challenged_at: T::BlockNumber,
     }
 
+    /// Claim reporting a verified contribution as fraudulent
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+    pub struct FraudReport<AccountId> {
+        pub contribution_id: ContributionId,
+        pub contributor: AccountId,
+    }
+
+    /// Jury drawn to decide a challenged claim
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Jury<T: Config> {
+        pub jurors: BoundedVec<T::AccountId, T::MaxJurySize>,
+        pub voting_deadline: T::BlockNumber,
+    }
+
+    /// Jury draw of a challenged claim, waiting for its seed round to be revealed
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+    pub struct PendingJuryDraw {
+        /// Appeal round the jury is drawn for
+        pub round: u32,
+        /// Seed round whose revealed secrets seed the draw
+        pub seed_round: u32,
+    }
+
+    /// Secrets revealed for a seed round, folded together by XOR
+    #[derive(Clone, Default, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+    pub struct JurySeed<Hash> {
+        pub seed: Hash,
+        pub reveals: u32,
+    }
+
     #[pallet::config]
     pub trait Config: frame_system::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         
         /// Currency type for payments (TRAC/NEURO tokens)
-        type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
+        type Currency: Currency<Self::AccountId>
+            + ReservableCurrency<Self::AccountId>
+            + NamedReservableCurrency<Self::AccountId, ReserveIdentifier = [u8; 8]>;
         
         /// Minimum stake amount for reputation credibility
         #[pallet::constant]
@@ -89,9 +278,154 @@ pub mod pallet {
         /// Base price for premium reputation queries
         #[pallet::constant]
         type BaseQueryPrice: Get<BalanceOf<Self>>;
+
+        /// Source of truth for which account controls a UAL (DKG integration mapping)
+        type UalOwnership: UalOwnership<Self::AccountId>;
+
+        /// Share of each query payment routed to the registered data provider
+        #[pallet::constant]
+        type ProviderShare: Get<Perbill>;
+
+        /// Asset identifier for TRAC/NEURO and other foreign assets
+        type AssetId: Member + Parameter + Copy + MaxEncodedLen + MaybeSerializeDeserialize;
+
+        /// Fungible assets (pallet-assets / ForeignAssets) usable for staking, queries and channels
+        type Assets: fungibles::Inspect<Self::AccountId, AssetId = Self::AssetId>
+            + fungibles::Transfer<Self::AccountId>;
+
+        /// Pallet id used to derive the escrow account holding asset stakes and channel deposits
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Portion of the offender's staked amount slashed per finalized reputation offence
+        #[pallet::constant]
+        type OffenceSlash: Get<Perbill>;
+
+        /// Reputation source used to qualify jurors
+        type Reputation: ReputationProvider<Self::AccountId>;
+
+        /// Reputation feedback for resolved claims (e.g. pallet-reputation award/slash)
+        type OnClaimResolved: OnClaimResolved<Self::AccountId>;
+
+        /// Verified contributions that can be reported as fraudulent
+        type Contributions: ContributionRegistry<Self::AccountId>;
+
+        /// Blocks the contributor has to challenge a fraud report before it goes to a
+        /// governance vote
+        #[pallet::constant]
+        type FraudReportWindow: Get<Self::BlockNumber>;
+
+        /// Block weight quotas of the hook sweeps, shared with the other pallets
+        type HookQuotas: HookQuotaProvider;
+
+        /// Time provider for the timestamps of itemized prepaid queries
+        type Time: Time;
+
+        /// Entropy mixed into the seed juror pool members reveal for drawing juries
+        type Randomness: Randomness<Self::Hash, Self::BlockNumber>;
+
+        /// Number of jurors drawn per challenged claim
+        #[pallet::constant]
+        type JurySize: Get<u32>;
+
+        /// Upper bound on jury size across appeal rounds
+        #[pallet::constant]
+        type MaxJurySize: Get<u32>;
+
+        /// Number of jury appeal rounds; appealing the last one escalates to governance
+        #[pallet::constant]
+        type MaxAppealRounds: Get<u32>;
+
+        /// Blocks after a jury verdict during which the losing party may appeal
+        #[pallet::constant]
+        type AppealPeriod: Get<Self::BlockNumber>;
+
+        /// Maximum number of evidence UALs per claim or challenge
+        #[pallet::constant]
+        type MaxEvidenceItems: Get<u32>;
+
+        /// Maximum number of open claims per submitter
+        #[pallet::constant]
+        type MaxClaimsPerSubmitter: Get<u32>;
+
+        /// Maximum number of claims automatically finalized per block
+        #[pallet::constant]
+        type MaxClaimsSweptPerBlock: Get<u32>;
+
+        /// Maximum number of UALs purchased in a single `pay_for_queries` batch
+        #[pallet::constant]
+        type MaxQueryBatch: Get<u32>;
+
+        /// Discount applied to every item of a multi-item query batch
+        #[pallet::constant]
+        type BatchDiscount: Get<Perbill>;
+
+        /// Amount debited from a payment channel per metered query
+        #[pallet::constant]
+        type MeteredQueryFee: Get<BalanceOf<Self>>;
+
+        /// Maximum number of prepaid queries itemized per organization and billing period
+        #[pallet::constant]
+        type MaxInvoiceItems: Get<u32>;
+
+        /// Blocks per billing period of prepaid accounts
+        #[pallet::constant]
+        type BillingPeriod: Get<Self::BlockNumber>;
+
+        /// Billing periods a closing statement is kept for before anyone may prune it
+        #[pallet::constant]
+        type BillingStatementRetention: Get<u32>;
+
+        /// Handler for forfeited claim and juror stakes (e.g. the treasury)
+        type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+        /// Share of a forfeited claim stake paid to the winning party
+        #[pallet::constant]
+        type WinnerShare: Get<Perbill>;
+
+        /// Weight information for extrinsics in this pallet
+        type WeightInfo: WeightInfo;
+
+        /// Asset setup for benchmarks
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::AssetId, Self::AccountId, AssetBalanceOf<Self>>;
+
+        /// Maximum number of accounts in the juror pool
+        #[pallet::constant]
+        type MaxJurorPool: Get<u32>;
+
+        /// Minimum reputation required to join the juror pool and be drawn
+        #[pallet::constant]
+        type MinJurorReputation: Get<Score>;
+
+        /// Stake reserved by each juror while in the pool
+        #[pallet::constant]
+        type JurorStake: Get<BalanceOf<Self>>;
+
+        /// Blocks jurors have to vote once drawn
+        #[pallet::constant]
+        type JuryVotingPeriod: Get<Self::BlockNumber>;
+
+        /// Blocks per jury seed round; pool members commit to secrets during a round and
+        /// reveal them during the next one
+        #[pallet::constant]
+        type JurySeedPeriod: Get<Self::BlockNumber>;
+
+        /// Portion of a juror's stake slashed for voting against the verdict or not voting
+        #[pallet::constant]
+        type JurorSlash: Get<Perbill>;
+
+        /// Portion of a contributor's provider fees paid out to their backers
+        #[pallet::constant]
+        type BackerShare: Get<Perbill>;
+
+        /// Maximum number of accounts backing one contributor
+        #[pallet::constant]
+        type MaxBackers: Get<u32>;
     }
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// Storage for staked amounts per developer
@@ -113,7 +447,7 @@ pub mod pallet {
         Blake2_128Concat,
         T::AccountId, // Querier
         Blake2_128Concat,
-        Vec<u8>, // UAL
+        Ual, // UAL
         BlockNumberFor<T>, // Expiry block
         OptionQuery,
     >;
@@ -131,22 +465,184 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Usage-metered access (consumer, UAL) -> payee whose channel is debited per query
+    #[pallet::storage]
+    #[pallet::getter(fn metered_access)]
+    pub type MeteredAccess<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId, // Consumer
+        Blake2_128Concat,
+        Ual, // UAL
+        T::AccountId, // Payee
+        OptionQuery,
+    >;
+
     /// Storage for custom query prices per UAL
     #[pallet::storage]
     #[pallet::getter(fn custom_query_price)]
     pub type CustomQueryPrice<T: Config> = StorageMap<
         _,
         Blake2_128Concat,
-        Vec<u8>, // UAL
+        Ual, // UAL
+        BalanceOf<T>,
+        OptionQuery,
+    >;
+
+    /// Dynamic pricing parameters; static pricing when unset
+    #[pallet::storage]
+    #[pallet::getter(fn pricing_config)]
+    pub type Pricing<T: Config> = StorageValue<
+        _,
+        PricingConfig<BalanceOf<T>, T::BlockNumber>,
+        OptionQuery,
+    >;
+
+    /// Query volume per UAL in the current pricing window
+    #[pallet::storage]
+    #[pallet::getter(fn query_volume)]
+    pub type QueryVolume<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Ual, // UAL
+        QueryVolumeWindow<T::BlockNumber>,
+        OptionQuery,
+    >;
+
+    /// Demand-adjusted price per UAL
+    #[pallet::storage]
+    #[pallet::getter(fn dynamic_query_price)]
+    pub type DynamicQueryPrice<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Ual, // UAL
         BalanceOf<T>,
         OptionQuery,
     >;
 
+    /// Storage for registered data providers per UAL
+    #[pallet::storage]
+    #[pallet::getter(fn data_provider)]
+    pub type DataProviders<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Ual, // UAL
+        T::AccountId, // Provider
+        OptionQuery,
+    >;
+
+    /// Accepted payment assets with their minimum stake and base query price
+    #[pallet::storage]
+    #[pallet::getter(fn asset_config)]
+    pub type AssetConfigs<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AssetId,
+        AssetConfig<AssetBalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    /// Storage for asset-denominated stakes per developer
+    #[pallet::storage]
+    #[pallet::getter(fn asset_staked_amount)]
+    pub type AssetStakes<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AssetId,
+        AssetBalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// Storage for asset-denominated payment channels
+    #[pallet::storage]
+    #[pallet::getter(fn asset_payment_channel)]
+    pub type AssetPaymentChannels<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, T::AccountId>, // Payer
+            NMapKey<Blake2_128Concat, T::AccountId>, // Payee
+            NMapKey<Blake2_128Concat, T::AssetId>,
+        ),
+        (AssetBalanceOf<T>, BlockNumberFor<T>), // (deposited_amount, expiry)
+        OptionQuery,
+    >;
+
     /// Treasury account for collecting query fees
     #[pallet::storage]
     #[pallet::getter(fn treasury_account)]
     pub type TreasuryAccount<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
 
+    /// Native-currency query fees paid for a UAL
+    #[pallet::storage]
+    #[pallet::getter(fn ual_revenue)]
+    pub type UalRevenue<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Ual,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// Native-currency query fees earned by a data provider, net of its backers' share
+    #[pallet::storage]
+    #[pallet::getter(fn provider_revenue)]
+    pub type ProviderRevenue<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// Treasury share of native-currency query fees per accounting period
+    #[pallet::storage]
+    pub type TreasuryIncome<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u32, // Period, block number / REVENUE_PERIOD_BLOCKS
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// Prepaid query balance per organization
+    #[pallet::storage]
+    #[pallet::getter(fn prepaid_account)]
+    pub type PrepaidAccounts<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        PrepaidAccount<BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    /// Queries consumed from a prepaid balance in a billing period not yet closed
+    #[pallet::storage]
+    #[pallet::getter(fn invoice_items)]
+    pub type InvoiceItems<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId, // Organization
+        Twox64Concat,
+        u32, // Billing period, block number / BillingPeriod
+        BoundedVec<InvoiceItem<T>, T::MaxInvoiceItems>,
+        ValueQuery,
+    >;
+
+    /// Closing statements of prepaid billing periods, kept for `BillingStatementRetention` periods
+    #[pallet::storage]
+    #[pallet::getter(fn billing_statement)]
+    pub type BillingStatements<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId, // Organization
+        Twox64Concat,
+        u32, // Billing period
+        BillingStatement<BalanceOf<T>, T::Hash>,
+        OptionQuery,
+    >;
+
     /// Claim ID counter
     #[pallet::storage]
     pub type ClaimIdCounter<T: Config> = StorageValue<_, u64, ValueQuery>;
@@ -172,62 +668,590 @@ pub mod pallet {
         OptionQuery,
     >;
 
-    /// Storage for submitter's claims
+    /// Storage for submitter's open (unresolved) claims
     #[pallet::storage]
     pub type SubmitterClaims<T: Config> = StorageMap<
         _,
         Blake2_128Concat,
         T::AccountId,
-        Vec<u64>,
+        BoundedVec<u64, T::MaxClaimsPerSubmitter>,
         ValueQuery,
     >;
 
-    #[pallet::event]
-    #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
-        /// Tokens staked for reputation credibility [who, amount]
-        TokensStaked { who: T::AccountId, amount: BalanceOf<T> },
-        
-        /// Tokens unstaked [who, amount]
-        TokensUnstaked { who: T::AccountId, amount: BalanceOf<T> },
-        
-        /// Payment made for query access [payer, ual, amount]
-        QueryPaymentMade { payer: T::AccountId, ual: Vec<u8>, amount: BalanceOf<T> },
-        
-        /// Query access granted [querier, ual, expiry_block]
-        QueryAccessGranted { querier: T::AccountId, ual: Vec<u8>, expiry: BlockNumberFor<T> },
-        
-        /// Payment channel opened [payer, payee, amount]
-        ChannelOpened { payer: T::AccountId, payee: T::AccountId, amount: BalanceOf<T> },
-        
-        /// Payment channel closed [payer, payee]
-        ChannelClosed { payer: T::AccountId, payee: T::AccountId },
-        
-        /// Custom query price set [ual, price]
-        CustomPriceSet { ual: Vec<u8>, price: BalanceOf<T> },
+    /// Claim ids indexed by current status
+    #[pallet::storage]
+    pub type ClaimsByStatus<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        ClaimStatus,
+        Blake2_128Concat,
+        u64,
+        (),
+        OptionQuery,
+    >;
 
-        /// Claim posted [claim_id, submitter, stake]
-        ClaimPosted { claim_id: u64, submitter: T::AccountId, stake: BalanceOf<T> },
+    /// Deadline-ordered agenda: claims that may become finalizable at a block
+    ///
+    /// Entries are hints; the sweep re-checks the claim's status and deadline.
+    #[pallet::storage]
+    #[pallet::getter(fn claim_agenda)]
+    pub type ClaimAgenda<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        T::BlockNumber,
+        BoundedVec<u64, T::MaxClaimsSweptPerBlock>,
+        ValueQuery,
+    >;
 
-        /// Claim challenged [claim_id, challenger, stake]
-        ClaimChallenged { claim_id: u64, challenger: T::AccountId, stake: BalanceOf<T> },
+    /// Accounts eligible to be drawn as jurors
+    #[pallet::storage]
+    #[pallet::getter(fn juror_pool)]
+    pub type JurorPool<T: Config> = StorageValue<
+        _,
+        BoundedVec<T::AccountId, T::MaxJurorPool>,
+        ValueQuery,
+    >;
 
-        /// Claim resolved [claim_id, resolution]
-        ClaimResolved { claim_id: u64, resolution: ClaimResolution },
-    }
+    /// Stake currently reserved by each juror
+    #[pallet::storage]
+    #[pallet::getter(fn juror_stake)]
+    pub type JurorStakes<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        OptionQuery,
+    >;
 
-    #[pallet::error]
-    pub enum Error<T> {
-        /// Insufficient balance for staking
-        InsufficientBalance,
-        
-        /// Stake amount below minimum
-        BelowMinimumStake,
-        
-        /// No stake found for this account
-        NoStakeFound,
-        
-        /// Query access expired
+    /// Number of open juries each juror is seated on
+    #[pallet::storage]
+    pub type JurorAssignments<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        u32,
+        ValueQuery,
+    >;
+
+    /// Jury drawn for each challenged claim
+    #[pallet::storage]
+    #[pallet::getter(fn claim_jury)]
+    pub type ClaimJuries<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        Jury<T>,
+        OptionQuery,
+    >;
+
+    /// Jury draws waiting for their seed round to be revealed
+    #[pallet::storage]
+    #[pallet::getter(fn pending_jury_draw)]
+    pub type PendingJuryDraws<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        PendingJuryDraw,
+        OptionQuery,
+    >;
+
+    /// Unrevealed seed commitments (seed_round, juror) -> hash of the juror and secret
+    #[pallet::storage]
+    #[pallet::getter(fn seed_commitment)]
+    pub type SeedCommitments<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u32,
+        Blake2_128Concat,
+        T::AccountId,
+        T::Hash,
+        OptionQuery,
+    >;
+
+    /// Number of `SeedCommitments` entries each juror has left, revealed or slashed ones
+    /// excluded; jurors cannot leave the pool while it is non-zero
+    #[pallet::storage]
+    #[pallet::getter(fn unrevealed_seeds)]
+    pub type UnrevealedSeeds<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Seed revealed for each of the last `JURY_SEED_RETENTION` seed rounds
+    #[pallet::storage]
+    #[pallet::getter(fn jury_seed)]
+    pub type JurySeeds<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u32,
+        JurySeed<T::Hash>,
+        ValueQuery,
+    >;
+
+    /// Jury votes (claim_id, juror) -> resolution
+    #[pallet::storage]
+    #[pallet::getter(fn jury_vote)]
+    pub type JuryVotes<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        Blake2_128Concat,
+        T::AccountId,
+        ClaimResolution,
+        OptionQuery,
+    >;
+
+    /// Current appeal round per claim (0 = initial jury)
+    #[pallet::storage]
+    #[pallet::getter(fn claim_round)]
+    pub type ClaimRounds<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        u32,
+        ValueQuery,
+    >;
+
+    /// Appeal deadline for claims with a jury verdict
+    #[pallet::storage]
+    #[pallet::getter(fn appeal_deadline)]
+    pub type AppealDeadlines<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        T::BlockNumber,
+        OptionQuery,
+    >;
+
+    /// Blocks a posted claim stays open to challenge; `DEFAULT_CHALLENGE_WINDOW` when unset
+    #[pallet::storage]
+    pub type ChallengeWindow<T: Config> = StorageValue<_, T::BlockNumber, OptionQuery>;
+
+    /// Slashing severity for rejected claims; the full stake is forfeited when unset
+    #[pallet::storage]
+    #[pallet::getter(fn slashing_policy)]
+    pub type Slashing<T: Config> = StorageValue<
+        _,
+        SlashingPolicy<T::BlockNumber>,
+        OptionQuery,
+    >;
+
+    /// Rejected-claim offence count per account
+    #[pallet::storage]
+    #[pallet::getter(fn offence_record)]
+    pub type OffenceCounts<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        OffenceRecord<T::BlockNumber>,
+        OptionQuery,
+    >;
+
+    /// Fraud reports by the claim that carries them
+    #[pallet::storage]
+    #[pallet::getter(fn fraud_report)]
+    pub type FraudReports<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        FraudReport<T::AccountId>,
+        OptionQuery,
+    >;
+
+    /// Open fraud report claim per reported contribution
+    #[pallet::storage]
+    pub type ReportedContributions<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ContributionId,
+        u64,
+        OptionQuery,
+    >;
+
+    /// Contribution each contribution claim is anchored to, until the claim is resolved
+    #[pallet::storage]
+    #[pallet::getter(fn contribution_claim)]
+    pub type ContributionClaims<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        ContributionId,
+        OptionQuery,
+    >;
+
+    /// Accounts backing each contributor and the stake they reserved
+    #[pallet::storage]
+    #[pallet::getter(fn backers)]
+    pub type Backers<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<(T::AccountId, BalanceOf<T>), T::MaxBackers>,
+        ValueQuery,
+    >;
+
+    /// Backing offered to a contributor and not yet accepted (contributor, backer) -> stake
+    #[pallet::storage]
+    #[pallet::getter(fn backing_offer)]
+    pub type BackingOffers<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        OptionQuery,
+    >;
+
+    /// Number of open fraud reports against each contributor; backing is locked while non-zero
+    #[pallet::storage]
+    pub type OpenFraudReports<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        u32,
+        ValueQuery,
+    >;
+
+    /// Cursor of the ongoing multi-block migration; claim calls are rejected while set
+    #[pallet::storage]
+    #[pallet::getter(fn migration_in_progress)]
+    pub type MigrationInProgress<T: Config> = StorageValue<_, MigrationCursor, OptionQuery>;
+
+    #[pallet::genesis_config]
+    pub struct GenesisConfig<T: Config> {
+        /// Account collecting the treasury share of query fees
+        pub treasury: Option<T::AccountId>,
+        /// Accepted payment assets as `(asset_id, minimum_stake, base_query_price)`
+        pub assets: Vec<(T::AssetId, AssetBalanceOf<T>, AssetBalanceOf<T>)>,
+        /// Dynamic pricing parameters; static pricing at `BaseQueryPrice` when unset
+        pub pricing: Option<PricingConfig<BalanceOf<T>, T::BlockNumber>>,
+        /// Claim challenge window; `DEFAULT_CHALLENGE_WINDOW` blocks when unset
+        pub challenge_window: Option<T::BlockNumber>,
+    }
+
+    #[cfg(feature = "std")]
+    impl<T: Config> Default for GenesisConfig<T> {
+        fn default() -> Self {
+            Self { treasury: None, assets: Vec::new(), pricing: None, challenge_window: None }
+        }
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+        fn build(&self) {
+            if let Some(ref treasury) = self.treasury {
+                TreasuryAccount::<T>::put(treasury);
+            }
+
+            for (asset_id, minimum_stake, base_query_price) in &self.assets {
+                AssetConfigs::<T>::insert(
+                    asset_id,
+                    AssetConfig { minimum_stake: *minimum_stake, base_query_price: *base_query_price },
+                );
+            }
+
+            if let Some(ref config) = self.pricing {
+                assert!(
+                    config.floor <= config.ceiling && !config.window.is_zero(),
+                    "Genesis pricing floor exceeds ceiling or window is zero"
+                );
+                Pricing::<T>::put(config);
+            }
+
+            if let Some(window) = self.challenge_window {
+                assert!(!window.is_zero(), "Genesis challenge window is zero");
+                ChallengeWindow::<T>::put(window);
+            }
+        }
+    }
+
+    #[pallet::event]
+    pub enum Event<T: Config> {
+        /// Tokens staked for reputation credibility [who, amount]
+        TokensStaked { who: T::AccountId, amount: BalanceOf<T> },
+        
+        /// Tokens unstaked [who, amount]
+        TokensUnstaked { who: T::AccountId, amount: BalanceOf<T> },
+        
+        /// Payment made for query access [payer, ual, amount]
+        QueryPaymentMade { payer: T::AccountId, ual: Ual, amount: BalanceOf<T> },
+        
+        /// Query access granted [querier, ual, expiry_block]
+        QueryAccessGranted { querier: T::AccountId, ual: Ual, expiry: BlockNumberFor<T> },
+        
+        /// Payment channel opened [payer, payee, amount]
+        ChannelOpened { payer: T::AccountId, payee: T::AccountId, amount: BalanceOf<T> },
+        
+        /// Payment channel closed and its remaining deposit refunded [payer, payee, refunded]
+        ChannelClosed { payer: T::AccountId, payee: T::AccountId, refunded: BalanceOf<T> },
+        
+        /// Custom query price set [ual, price]
+        CustomPriceSet { ual: Ual, price: BalanceOf<T> },
+
+        /// Claim posted [claim_id, submitter, stake]
+        ClaimPosted { claim_id: u64, submitter: T::AccountId, stake: BalanceOf<T> },
+
+        /// Claim challenged [claim_id, challenger, stake]
+        ClaimChallenged { claim_id: u64, challenger: T::AccountId, stake: BalanceOf<T> },
+
+        /// Claim resolved [claim_id, resolution]
+        ClaimResolved { claim_id: u64, resolution: ClaimResolution },
+
+        /// Data provider registered for a UAL [provider, ual]
+        DataProviderRegistered { provider: T::AccountId, ual: Ual },
+
+        /// Data provider deregistered from a UAL [provider, ual]
+        DataProviderDeregistered { provider: T::AccountId, ual: Ual },
+
+        /// Provider share of a query payment paid out [provider, ual, amount]
+        ProviderFeePaid { provider: T::AccountId, ual: Ual, amount: BalanceOf<T> },
+
+        /// Payment asset configured [asset_id, minimum_stake, base_query_price]
+        AssetConfigured { asset_id: T::AssetId, minimum_stake: AssetBalanceOf<T>, base_query_price: AssetBalanceOf<T> },
+
+        /// Payment asset no longer accepted [asset_id]
+        AssetRemoved { asset_id: T::AssetId },
+
+        /// Asset tokens staked [who, asset_id, amount]
+        AssetTokensStaked { who: T::AccountId, asset_id: T::AssetId, amount: AssetBalanceOf<T> },
+
+        /// Asset tokens unstaked [who, asset_id, amount]
+        AssetTokensUnstaked { who: T::AccountId, asset_id: T::AssetId, amount: AssetBalanceOf<T> },
+
+        /// Asset payment made for query access [payer, asset_id, ual, amount]
+        AssetQueryPaymentMade { payer: T::AccountId, asset_id: T::AssetId, ual: Ual, amount: AssetBalanceOf<T> },
+
+        /// Asset payment channel opened [payer, payee, asset_id, amount]
+        AssetChannelOpened { payer: T::AccountId, payee: T::AccountId, asset_id: T::AssetId, amount: AssetBalanceOf<T> },
+
+        /// Asset payment channel closed and its remaining deposit refunded [payer, payee, asset_id, refunded]
+        AssetChannelClosed { payer: T::AccountId, payee: T::AccountId, asset_id: T::AssetId, refunded: AssetBalanceOf<T> },
+
+        /// Stake slashed for a reputation offence [who, amount, offence]
+        StakeSlashed { who: T::AccountId, amount: BalanceOf<T>, offence: ReputationOffence },
+
+        /// Asset stake slashed for a reputation offence [who, asset_id, amount, offence]
+        AssetStakeSlashed { who: T::AccountId, asset_id: T::AssetId, amount: AssetBalanceOf<T>, offence: ReputationOffence },
+
+        /// Account joined the juror pool [who, stake]
+        JurorJoined { who: T::AccountId, stake: BalanceOf<T> },
+
+        /// Account left the juror pool [who]
+        JurorLeft { who: T::AccountId },
+
+        /// Jury drawn for a challenged claim [claim_id, jurors, voting_deadline]
+        JuryDrawn { claim_id: u64, jurors: Vec<T::AccountId>, voting_deadline: BlockNumberFor<T> },
+
+        /// Jury draw deferred until a seed round is revealed [claim_id, round, seed_round, draw_at]
+        JuryDrawScheduled { claim_id: u64, round: u32, seed_round: u32, draw_at: BlockNumberFor<T> },
+
+        /// Juror committed to a jury seed secret [juror, seed_round]
+        JurySeedCommitted { juror: T::AccountId, seed_round: u32 },
+
+        /// Juror revealed a jury seed secret [juror, seed_round]
+        JurySeedRevealed { juror: T::AccountId, seed_round: u32 },
+
+        /// Juror voted on a claim [claim_id, juror, resolution]
+        JuryVoteCast { claim_id: u64, juror: T::AccountId, resolution: ClaimResolution },
+
+        /// Juror rewarded for voting with the verdict [juror, amount]
+        JurorRewarded { juror: T::AccountId, amount: BalanceOf<T> },
+
+        /// Juror slashed for voting against the verdict or abstaining [juror, amount]
+        JurorSlashed { juror: T::AccountId, amount: BalanceOf<T> },
+
+        /// Usage-metered access enabled [consumer, ual, payee]
+        MeteredAccessEnabled { consumer: T::AccountId, ual: Ual, payee: T::AccountId },
+
+        /// Metered query debited from a channel [consumer, ual, payee, amount, remaining_queries]
+        MeteredQueryDebited { consumer: T::AccountId, ual: Ual, payee: T::AccountId, amount: BalanceOf<T>, remaining_queries: u32 },
+
+        /// Usage-metered access revoked [consumer, ual]
+        MeteredAccessRevoked { consumer: T::AccountId, ual: Ual },
+
+        /// Dynamic pricing parameters updated [config]
+        PricingConfigSet { config: Option<PricingConfig<BalanceOf<T>, BlockNumberFor<T>>> },
+
+        /// Query price adjusted for demand [ual, price]
+        QueryPriceAdjusted { ual: Ual, price: BalanceOf<T> },
+
+        /// Batch of query payments made [payer, items, total]
+        QueryBatchPaid { payer: T::AccountId, items: u32, total: BalanceOf<T> },
+
+        /// Losing party's claim stake forfeited [claim_id, who, amount]
+        ClaimStakeForfeited { claim_id: u64, who: T::AccountId, amount: BalanceOf<T> },
+
+        /// Claim escalated to fallback resolution [claim_id]
+        ClaimEscalated { claim_id: u64 },
+
+        /// Jury reached a verdict open for appeal [claim_id, resolution, round, appeal_deadline]
+        ClaimDecided { claim_id: u64, resolution: ClaimResolution, round: u32, appeal_deadline: BlockNumberFor<T> },
+
+        /// Jury verdict appealed with a doubled stake [claim_id, appellant, round, additional_stake]
+        ClaimAppealed { claim_id: u64, appellant: T::AccountId, round: u32, additional_stake: BalanceOf<T> },
+
+        /// Slashing policy updated [policy]
+        SlashingPolicySet { policy: Option<SlashingPolicy<BlockNumberFor<T>>> },
+
+        /// Claim challenge window updated [window]
+        ChallengeWindowSet { window: BlockNumberFor<T> },
+
+        /// Rejected-claim offence recorded [who, offences, severity]
+        ClaimOffenceRecorded { who: T::AccountId, offences: u32, severity: Perbill },
+
+        /// Multi-block migration advanced, more steps remain
+        MigrationStepped,
+
+        /// Multi-block migration completed; claim calls are available again
+        MigrationCompleted,
+
+        /// Treasury account changed [treasury]
+        TreasurySet { treasury: T::AccountId },
+
+        /// Claim settled for good, unchallenged or after its appeal window [claim_id, submitter, resolution]
+        ClaimFinalized { claim_id: u64, submitter: T::AccountId, resolution: ClaimResolution },
+
+        /// Verified contribution reported as fraudulent [claim_id, reporter, contribution_id, contributor]
+        ContributionReported { claim_id: u64, reporter: T::AccountId, contribution_id: ContributionId, contributor: T::AccountId },
+
+        /// Fraud report upheld and the reporter rewarded from the contributor's stake [claim_id, contribution_id, contributor, reward]
+        FraudReportUpheld { claim_id: u64, contribution_id: ContributionId, contributor: T::AccountId, reward: BalanceOf<T> },
+
+        /// Claim posted about the submitter's own contribution [claim_id, contributor, contribution_id]
+        ContributionClaimPosted { claim_id: u64, contributor: T::AccountId, contribution_id: ContributionId },
+
+        /// Stake reserved backing a contributor [backer, contributor, amount]
+        ContributorBacked { backer: T::AccountId, contributor: T::AccountId, amount: BalanceOf<T> },
+
+        /// Backing offered, pending the contributor's acceptance [backer, contributor, amount]
+        BackingOffered { backer: T::AccountId, contributor: T::AccountId, amount: BalanceOf<T> },
+
+        /// Backing offer rejected or withdrawn and its stake released [backer, contributor, amount]
+        BackingOfferCancelled { backer: T::AccountId, contributor: T::AccountId, amount: BalanceOf<T> },
+
+        /// Backing stake released [backer, contributor, amount]
+        BackingWithdrawn { backer: T::AccountId, contributor: T::AccountId, amount: BalanceOf<T> },
+
+        /// Backers' share of a contributor's provider fee paid out [contributor, amount]
+        BackerRevenueShared { contributor: T::AccountId, amount: BalanceOf<T> },
+
+        /// Backing stake slashed for the contributor's offence [backer, contributor, amount]
+        BackingSlashed { backer: T::AccountId, contributor: T::AccountId, amount: BalanceOf<T> },
+
+        /// Funds reserved for prepaid queries [organization, amount, balance]
+        PrepaidToppedUp { organization: T::AccountId, amount: BalanceOf<T>, balance: BalanceOf<T> },
+
+        /// Unconsumed prepaid funds released [organization, amount, balance]
+        PrepaidWithdrawn { organization: T::AccountId, amount: BalanceOf<T>, balance: BalanceOf<T> },
+
+        /// Query paid from a prepaid balance and itemized [organization, ual, amount, period, timestamp]
+        PrepaidQueryConsumed { organization: T::AccountId, ual: Ual, amount: BalanceOf<T>, period: u32, timestamp: u64 },
+
+        /// Billing period closed [organization, period, items, total, statement_hash]
+        BillingStatementClosed { organization: T::AccountId, period: u32, items: u32, total: BalanceOf<T>, statement_hash: T::Hash },
+
+        /// Closing statement past its retention removed [organization, period]
+        BillingStatementPruned { organization: T::AccountId, period: u32 },
+    }
+
+    impl<T: Config> Event<T> {
+        /// Topics this event is indexed under
+        fn topics(&self) -> Vec<T::Hash> {
+            let account = |who: &T::AccountId| Topic::Account.of::<T::Hashing>(who);
+            let claim = |id: &u64| Topic::Claim.of::<T::Hashing>(id);
+            let ual = |ual: &Ual| Topic::Ual.of::<T::Hashing>(ual);
+            let asset = |id: &T::AssetId| Topic::Asset.of::<T::Hashing>(id);
+            let contribution = |id: &ContributionId| Topic::Contribution.of::<T::Hashing>(id);
+
+            match self {
+                Event::TokensStaked { who, .. }
+                | Event::TokensUnstaked { who, .. }
+                | Event::StakeSlashed { who, .. }
+                | Event::JurorJoined { who, .. }
+                | Event::JurorLeft { who }
+                | Event::JurySeedCommitted { juror: who, .. }
+                | Event::JurySeedRevealed { juror: who, .. }
+                | Event::JurorRewarded { juror: who, .. }
+                | Event::JurorSlashed { juror: who, .. }
+                | Event::QueryBatchPaid { payer: who, .. }
+                | Event::ClaimOffenceRecorded { who, .. }
+                | Event::PrepaidToppedUp { organization: who, .. }
+                | Event::PrepaidWithdrawn { organization: who, .. }
+                | Event::BillingStatementClosed { organization: who, .. }
+                | Event::BillingStatementPruned { organization: who, .. }
+                | Event::TreasurySet { treasury: who } => vec![account(who)],
+                Event::QueryPaymentMade { payer: who, ual: asset_ual, .. }
+                | Event::QueryAccessGranted { querier: who, ual: asset_ual, .. }
+                | Event::DataProviderRegistered { provider: who, ual: asset_ual }
+                | Event::DataProviderDeregistered { provider: who, ual: asset_ual }
+                | Event::ProviderFeePaid { provider: who, ual: asset_ual, .. }
+                | Event::MeteredAccessRevoked { consumer: who, ual: asset_ual }
+                | Event::PrepaidQueryConsumed { organization: who, ual: asset_ual, .. } =>
+                    vec![account(who), ual(asset_ual)],
+                Event::ChannelOpened { payer, payee, .. } | Event::ChannelClosed { payer, payee, .. } =>
+                    vec![account(payer), account(payee)],
+                Event::MeteredAccessEnabled { consumer, ual: asset_ual, payee }
+                | Event::MeteredQueryDebited { consumer, ual: asset_ual, payee, .. } =>
+                    vec![account(consumer), ual(asset_ual), account(payee)],
+                Event::CustomPriceSet { ual: asset_ual, .. } | Event::QueryPriceAdjusted { ual: asset_ual, .. } =>
+                    vec![ual(asset_ual)],
+                Event::AssetConfigured { asset_id, .. } | Event::AssetRemoved { asset_id } => vec![asset(asset_id)],
+                Event::AssetTokensStaked { who, asset_id, .. }
+                | Event::AssetTokensUnstaked { who, asset_id, .. }
+                | Event::AssetStakeSlashed { who, asset_id, .. } => vec![account(who), asset(asset_id)],
+                Event::AssetQueryPaymentMade { payer, asset_id, ual: asset_ual, .. } =>
+                    vec![account(payer), asset(asset_id), ual(asset_ual)],
+                Event::AssetChannelOpened { payer, payee, asset_id, .. }
+                | Event::AssetChannelClosed { payer, payee, asset_id, .. } =>
+                    vec![account(payer), account(payee), asset(asset_id)],
+                Event::ClaimPosted { claim_id, submitter: who, .. }
+                | Event::ClaimChallenged { claim_id, challenger: who, .. }
+                | Event::JuryVoteCast { claim_id, juror: who, .. }
+                | Event::ClaimStakeForfeited { claim_id, who, .. }
+                | Event::ClaimAppealed { claim_id, appellant: who, .. }
+                | Event::ClaimFinalized { claim_id, submitter: who, .. } => vec![claim(claim_id), account(who)],
+                Event::ClaimResolved { claim_id, .. }
+                | Event::ClaimEscalated { claim_id }
+                | Event::JuryDrawScheduled { claim_id, .. }
+                | Event::ClaimDecided { claim_id, .. } => vec![claim(claim_id)],
+                Event::ContributionReported { claim_id, reporter, contribution_id, contributor } =>
+                    vec![claim(claim_id), account(reporter), contribution(contribution_id), account(contributor)],
+                Event::FraudReportUpheld { claim_id, contribution_id, contributor, .. }
+                | Event::ContributionClaimPosted { claim_id, contributor, contribution_id } =>
+                    vec![claim(claim_id), contribution(contribution_id), account(contributor)],
+                Event::ContributorBacked { backer, contributor, .. }
+                | Event::BackingWithdrawn { backer, contributor, .. }
+                | Event::BackingOffered { backer, contributor, .. }
+                | Event::BackingOfferCancelled { backer, contributor, .. }
+                | Event::BackingSlashed { backer, contributor, .. } => vec![account(backer), account(contributor)],
+                Event::BackerRevenueShared { contributor, .. } => vec![account(contributor)],
+                Event::JuryDrawn { claim_id, jurors, .. } =>
+                    sp_std::iter::once(claim(claim_id)).chain(jurors.iter().map(account)).collect(),
+                // Parameter changes and migrations concern no particular account
+                _ => Vec::new(),
+            }
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Deposit an event indexed under the accounts, claims, UALs and assets it concerns
+        pub(super) fn deposit_event(event: Event<T>) {
+            let topics = event.topics();
+            let event = <T as Config>::RuntimeEvent::from(event);
+            frame_system::Pallet::<T>::deposit_event_indexed(&topics, event.into());
+        }
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Insufficient balance for staking
+        InsufficientBalance,
+        
+        /// Stake amount below minimum
+        BelowMinimumStake,
+        
+        /// No stake found for this account
+        NoStakeFound,
+        
+        /// Query access expired
         QueryAccessExpired,
         
         /// No query access found
@@ -262,422 +1286,2746 @@ pub mod pallet {
 
         /// Claim is not in a resolvable state
         ClaimNotResolvable,
-    }
 
-    #[pallet::call]
-    impl<T: Config> Pallet<T> {
-        /// Stake tokens to increase reputation credibility
-        #[pallet::call_index(0)]
-        #[pallet::weight(10_000)]
-        pub fn stake_tokens(
-            origin: OriginFor<T>,
-            amount: BalanceOf<T>,
-        ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
+        /// Challenge window is still open
+        ChallengeWindowOpen,
+
+        /// Reputation too low to serve as a juror
+        InsufficientReputation,
+
+        /// Account is already in the juror pool
+        AlreadyJuror,
+
+        /// Account is not in the juror pool
+        NotJuror,
+
+        /// Juror pool is full
+        JurorPoolFull,
+
+        /// Juror is still seated on an open jury
+        JurorOnDuty,
+
+        /// No jury has been drawn for this claim
+        NoJury,
+
+        /// Caller is not seated on this claim's jury
+        NotOnJury,
+
+        /// Juror has already voted on this claim
+        AlreadyVoted,
+
+        /// Jury voting period has ended
+        VotingClosed,
+
+        /// Jury voting period has not ended yet
+        VotingOpen,
+
+        /// Claim has no jury verdict open for appeal
+        ClaimNotAppealable,
+
+        /// Appeal window has expired
+        AppealWindowExpired,
+
+        /// Appeal window is still open
+        AppealWindowOpen,
+
+        /// Only the losing party may appeal
+        NotAppellant,
+
+        /// UAL exceeds the maximum length
+        UalTooLong,
+
+        /// Too many evidence UALs
+        TooManyEvidenceItems,
+
+        /// Submitter has too many open claims
+        TooManyOpenClaims,
+
+        /// Query batch is empty
+        EmptyQueryBatch,
+
+        /// Pricing floor above ceiling or empty window
+        InvalidPricingConfig,
+
+        /// Payment channel has expired
+        ChannelExpired,
+
+        /// No usage-metered access for this UAL
+        NoMeteredAccess,
+
+        /// Caller is not the payee of the metered access
+        NotMeteredPayee,
+
+        /// Caller does not control this UAL
+        NotUalOwner,
+
+        /// A data provider still controlling this UAL is already registered for it
+        ProviderAlreadyRegistered,
+
+        /// Caller is not the registered data provider for this UAL
+        NotDataProvider,
+
+        /// Asset is not configured as a payment asset
+        AssetNotAccepted,
+
+        /// Slashing severities out of order or empty decay period
+        InvalidSlashingPolicy,
+
+        /// Challenge window is zero
+        InvalidChallengeWindow,
+
+        /// Claims are being migrated; retry once the migration completes
+        MigrationInProgress,
+
+        /// Contribution does not exist or is not verified
+        ContributionNotReportable,
+
+        /// Contributors cannot report their own contributions
+        CannotReportOwnContribution,
+
+        /// Contribution already has an open fraud report
+        ContributionAlreadyReported,
+
+        /// Accounts cannot back themselves
+        CannotBackSelf,
+
+        /// Contributor already has the maximum number of backers
+        TooManyBackers,
+
+        /// Caller does not back this contributor
+        NotBacking,
+
+        /// Backing cannot be withdrawn while fraud reports against the contributor are open
+        BackingLocked,
+
+        /// No backing offer from that backer to that contributor
+        NoBackingOffer,
+
+        /// Caller is not the contributor of this verified contribution
+        NotContributor,
+
+        /// Contribution has not been published as a Knowledge Asset
+        ContributionUalUnknown,
+
+        /// Organization has no prepaid account
+        NoPrepaidAccount,
+
+        /// Prepaid balance does not cover the query or withdrawal
+        InsufficientPrepaidBalance,
+
+        /// Billing period already itemizes the maximum number of queries
+        InvoiceFull,
+
+        /// Billing period has not ended yet
+        BillingPeriodOpen,
+
+        /// No closing statement for that organization and billing period
+        NoBillingStatement,
+
+        /// Closing statement is still within `BillingStatementRetention`
+        BillingStatementRetained,
+
+        /// No jury draw is pending for this claim
+        NoPendingDraw,
+
+        /// The draw's seed round is still being committed or revealed
+        SeedNotRevealed,
+
+        /// Juror already committed to a seed for the current round
+        SeedAlreadyCommitted,
+
+        /// Seeds of that round can only be revealed during the next round
+        SeedRevealClosed,
+
+        /// Seeds of that round can still be revealed
+        SeedRevealOpen,
+
+        /// No unrevealed seed commitment of that juror for that round
+        NoSeedCommitment,
+
+        /// The revealed secret does not match the commitment
+        SeedMismatch,
+
+        /// Juror has a seed commitment still to reveal
+        SeedUnrevealed,
+
+        /// Jurors cannot report their own unrevealed seed
+        CannotReportSelf,
+
+        /// Stake cannot be withdrawn while fraud reports against the staker are open
+        StakeLocked,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            let mut weight = T::DbWeight::get().reads(1);
+            if MigrationInProgress::<T>::exists() {
+                let budget = T::HookQuotas::hook_budget(HookTask::Migrations, T::BlockWeights::get().max_block);
+                let used = Self::step_migration(budget.remaining());
+                T::HookQuotas::note_hook_usage(HookTask::Migrations, used);
+                weight = weight.saturating_add(used).saturating_add(T::DbWeight::get().writes(1));
+            }
+            // The sweep keeps running during a migration; claims not yet migrated carry over
+            weight.saturating_add(Self::sweep_claims(now))
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Stake tokens to increase reputation credibility
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::stake_tokens())]
+        pub fn stake_tokens(
+            origin: OriginFor<T>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // Ensure amount meets minimum
+            ensure!(amount >= T::MinimumStake::get(), Error::<T>::BelowMinimumStake);
+
+            // Reserve the tokens
+            T::Currency::reserve(&who, amount)
+                .map_err(|_| Error::<T>::InsufficientBalance)?;
+
+            // Update staked amount
+            StakedAmount::<T>::mutate(&who, |staked| {
+                *staked = staked.saturating_add(amount);
+            });
+
+            Self::deposit_event(Event::TokensStaked { who, amount });
+
+            Ok(())
+        }
+
+        /// Unstake tokens
+        ///
+        /// Locked while fraud reports against the caller are open, so a reported
+        /// contributor cannot leave ahead of a slash.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::unstake_tokens())]
+        pub fn unstake_tokens(
+            origin: OriginFor<T>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(OpenFraudReports::<T>::get(&who) == 0, Error::<T>::StakeLocked);
+
+            let staked = StakedAmount::<T>::get(&who);
+            ensure!(staked >= amount, Error::<T>::InsufficientBalance);
+
+            // Unreserve the tokens
+            T::Currency::unreserve(&who, amount);
+
+            // Update staked amount
+            StakedAmount::<T>::mutate(&who, |staked| {
+                *staked = staked.saturating_sub(amount);
+            });
+
+            Self::deposit_event(Event::TokensUnstaked { who, amount });
+
+            Ok(())
+        }
+
+        /// Pay for premium reputation query access (x402 micropayment)
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::pay_for_query().saturating_add(Pallet::<T>::backer_payout_weight(1)))]
+        pub fn pay_for_query(
+            origin: OriginFor<T>,
+            ual: Vec<u8>,
+            access_duration: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let ual = Self::bound_ual(ual)?;
+
+            // Get query price (custom or base, adjusted for demand)
+            Self::note_query(&ual);
+            let price = Self::query_price(&ual);
+            Self::settle_query_payment(&who, &ual, price)?;
+
+            // Grant query access
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            let expiry = current_block.saturating_add(access_duration);
+
+            QueryAccess::<T>::insert(&who, &ual, expiry);
+
+            Self::deposit_event(Event::QueryPaymentMade { 
+                payer: who.clone(), 
+                ual: ual.clone(), 
+                amount: price 
+            });
+
+            Self::deposit_event(Event::QueryAccessGranted { 
+                querier: who, 
+                ual, 
+                expiry 
+            });
+
+            Ok(())
+        }
+
+        /// Open a payment channel for efficient micropayments
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::open_payment_channel())]
+        pub fn open_payment_channel(
+            origin: OriginFor<T>,
+            payee: T::AccountId,
+            deposit: BalanceOf<T>,
+            duration: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let payer = ensure_signed(origin)?;
+
+            // Ensure channel doesn't already exist
+            ensure!(
+                !PaymentChannels::<T>::contains_key(&payer, &payee),
+                Error::<T>::ChannelAlreadyExists
+            );
+
+            // Reserve deposit
+            T::Currency::reserve(&payer, deposit)
+                .map_err(|_| Error::<T>::InsufficientBalance)?;
+
+            // Create channel
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            let expiry = current_block.saturating_add(duration);
+
+            PaymentChannels::<T>::insert(&payer, &payee, (deposit, expiry));
+
+            Self::deposit_event(Event::ChannelOpened { 
+                payer, 
+                payee, 
+                amount: deposit 
+            });
+
+            Ok(())
+        }
+
+        /// Close a payment channel
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::close_payment_channel())]
+        pub fn close_payment_channel(
+            origin: OriginFor<T>,
+            payee: T::AccountId,
+        ) -> DispatchResult {
+            let payer = ensure_signed(origin)?;
+
+            // Get channel
+            let (deposit, _) = PaymentChannels::<T>::get(&payer, &payee)
+                .ok_or(Error::<T>::ChannelNotFound)?;
+
+            // Unreserve remaining deposit
+            T::Currency::unreserve(&payer, deposit);
+
+            // Remove channel
+            PaymentChannels::<T>::remove(&payer, &payee);
+
+            Self::deposit_event(Event::ChannelClosed { payer, payee, refunded: deposit });
+
+            Ok(())
+        }
+
+        /// Set custom query price for a UAL (data provider only)
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::set_custom_query_price())]
+        pub fn set_custom_query_price(
+            origin: OriginFor<T>,
+            ual: Vec<u8>,
+            price: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let ual = Self::bound_ual(ual)?;
+
+            // Only the registered provider that still controls the UAL can price it
+            ensure!(
+                DataProviders::<T>::get(&ual) == Some(who.clone()),
+                Error::<T>::NotDataProvider
+            );
+            ensure!(T::UalOwnership::owns_ual(&who, &ual), Error::<T>::NotUalOwner);
+
+            CustomQueryPrice::<T>::insert(&ual, price);
+
+            Self::deposit_event(Event::CustomPriceSet { ual, price });
+
+            Ok(())
+        }
+
+        /// Set treasury account (governance only)
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::set_treasury())]
+        pub fn set_treasury(
+            origin: OriginFor<T>,
+            treasury: T::AccountId,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            TreasuryAccount::<T>::put(&treasury);
+
+            Self::deposit_event(Event::TreasurySet { treasury });
+
+            Ok(())
+        }
+
+        /// Post a verifiable claim anchored to Knowledge Assets (Claim Verification)
+        /// Uses optimistic posting with challenge window
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::post_claim(evidence_uals.len() as u32))]
+        pub fn post_claim(
+            origin: OriginFor<T>,
+            claim_ual: Vec<u8>,
+            evidence_uals: Vec<Vec<u8>>,
+            stake: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_migrating()?;
+
+            let claim_ual = Self::bound_ual(claim_ual)?;
+            Self::do_post_claim(who, claim_ual, evidence_uals, stake, Self::challenge_window()).map(|_| ())
+        }
+
+        /// Challenge a claim with counter-evidence
+        #[pallet::call_index(8)]
+        #[pallet::weight(
+            T::WeightInfo::challenge_claim(counter_evidence_uals.len() as u32, T::MaxJurorPool::get())
+        )]
+        pub fn challenge_claim(
+            origin: OriginFor<T>,
+            claim_id: u64,
+            counter_evidence_uals: Vec<Vec<u8>>,
+            stake: BalanceOf<T>,
+        ) -> DispatchResult {
+            let challenger = ensure_signed(origin)?;
+            Self::ensure_not_migrating()?;
+
+            let mut claim = Claims::<T>::get(claim_id)
+                .ok_or(Error::<T>::ClaimNotFound)?;
+
+            // Check challenge window hasn't expired
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            ensure!(
+                current_block <= claim.challenge_deadline,
+                Error::<T>::ChallengeWindowExpired
+            );
+
+            // Check claim is still pending
+            ensure!(
+                claim.status == ClaimStatus::Pending,
+                Error::<T>::ClaimNotChallengeable
+            );
+
+            // Cannot challenge own claim
+            ensure!(challenger != claim.submitter, Error::<T>::CannotChallengeOwnClaim);
+
+            // Require stake (at least matching original stake)
+            ensure!(stake >= claim.stake, Error::<T>::InsufficientStake);
+
+            let counter_evidence_uals = Self::bound_evidence(counter_evidence_uals)?;
+
+            // Reserve challenger's stake
+            T::Currency::reserve(&challenger, stake)
+                .map_err(|_| Error::<T>::InsufficientBalance)?;
+
+            // Update claim
+            claim.status = ClaimStatus::Challenged;
+            claim.challenger = Some(challenger.clone());
+        
+            // Store counter-evidence
+            ClaimChallenges::<T>::insert(
+                claim_id,
+                Challenge {
+                    claim_id,
+                    challenger: challenger.clone(),
+                    counter_evidence_uals,
+                    stake,
+                    challenged_at: current_block,
+                },
+            );
+
+            // Schedule a jury draw; fall back to escalation when the pool is too small
+            Self::seat_jury(claim_id, &mut claim, 0);
+
+            Self::put_claim(claim_id, claim);
+
+            Self::deposit_event(Event::ClaimChallenged {
+                claim_id,
+                challenger,
+                stake,
+            });
+
+            Ok(())
+        }
+
+        /// Resolve an escalated claim (governance fallback when no jury verdict is possible or appeals are exhausted)
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::resolve_claim())]
+        pub fn resolve_claim(
+            origin: OriginFor<T>,
+            claim_id: u64,
+            resolution: ClaimResolution,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::ensure_not_migrating()?;
+
+            let claim = Claims::<T>::get(claim_id)
+                .ok_or(Error::<T>::ClaimNotFound)?;
+
+            ensure!(
+                claim.status == ClaimStatus::Escalated,
+                Error::<T>::ClaimNotResolvable
+            );
+
+            Self::enact_resolution(claim_id, claim, resolution)
+        }
+
+        /// Register as the data provider for a UAL the caller controls
+        ///
+        /// Replaces a provider that no longer controls the UAL, e.g. after the asset changed
+        /// hands, dropping its custom price.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::register_data_provider())]
+        pub fn register_data_provider(
+            origin: OriginFor<T>,
+            ual: Vec<u8>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let ual = Self::bound_ual(ual)?;
+
+            let stale = DataProviders::<T>::get(&ual);
+            if let Some(ref provider) = stale {
+                ensure!(!T::UalOwnership::owns_ual(provider, &ual), Error::<T>::ProviderAlreadyRegistered);
+            }
+
+            // Ownership is proven through the DKG integration UAL mapping
+            ensure!(T::UalOwnership::owns_ual(&who, &ual), Error::<T>::NotUalOwner);
+
+            if let Some(provider) = stale {
+                CustomQueryPrice::<T>::remove(&ual);
+                Self::deposit_event(Event::DataProviderDeregistered { provider, ual: ual.clone() });
+            }
+            DataProviders::<T>::insert(&ual, &who);
+
+            Self::deposit_event(Event::DataProviderRegistered { provider: who, ual });
+
+            Ok(())
+        }
+
+        /// Deregister as the data provider for a UAL (also clears its custom price)
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::deregister_data_provider())]
+        pub fn deregister_data_provider(
+            origin: OriginFor<T>,
+            ual: Vec<u8>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let ual = Self::bound_ual(ual)?;
+
+            ensure!(
+                DataProviders::<T>::get(&ual) == Some(who.clone()),
+                Error::<T>::NotDataProvider
+            );
+
+            DataProviders::<T>::remove(&ual);
+            CustomQueryPrice::<T>::remove(&ual);
+
+            Self::deposit_event(Event::DataProviderDeregistered { provider: who, ual });
+
+            Ok(())
+        }
+
+        /// Accept an asset for payments with its minimum stake and base query price (governance only)
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::set_asset_config())]
+        pub fn set_asset_config(
+            origin: OriginFor<T>,
+            asset_id: T::AssetId,
+            minimum_stake: AssetBalanceOf<T>,
+            base_query_price: AssetBalanceOf<T>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            AssetConfigs::<T>::insert(asset_id, AssetConfig { minimum_stake, base_query_price });
+
+            Self::deposit_event(Event::AssetConfigured {
+                asset_id,
+                minimum_stake,
+                base_query_price,
+            });
+
+            Ok(())
+        }
+
+        /// Stop accepting an asset for new payments (governance only)
+        ///
+        /// Existing stakes and channels can still be withdrawn.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::remove_asset_config())]
+        pub fn remove_asset_config(
+            origin: OriginFor<T>,
+            asset_id: T::AssetId,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            AssetConfigs::<T>::remove(asset_id);
+
+            Self::deposit_event(Event::AssetRemoved { asset_id });
+
+            Ok(())
+        }
+
+        /// Stake asset tokens (e.g. TRAC/NEURO) to increase reputation credibility
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::stake_asset())]
+        pub fn stake_asset(
+            origin: OriginFor<T>,
+            asset_id: T::AssetId,
+            amount: AssetBalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let config = AssetConfigs::<T>::get(asset_id)
+                .ok_or(Error::<T>::AssetNotAccepted)?;
+            ensure!(amount >= config.minimum_stake, Error::<T>::BelowMinimumStake);
+
+            // Move the tokens into the pallet escrow account
+            <T::Assets as fungibles::Transfer<T::AccountId>>::transfer(
+                asset_id,
+                &who,
+                &Self::escrow_account(),
+                amount,
+                true,
+            )
+            .map_err(|_| Error::<T>::InsufficientBalance)?;
+
+            AssetStakes::<T>::mutate(&who, asset_id, |staked| {
+                *staked = staked.saturating_add(amount);
+            });
+
+            Self::deposit_event(Event::AssetTokensStaked { who, asset_id, amount });
+
+            Ok(())
+        }
+
+        /// Unstake asset tokens
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::unstake_asset())]
+        pub fn unstake_asset(
+            origin: OriginFor<T>,
+            asset_id: T::AssetId,
+            amount: AssetBalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let staked = AssetStakes::<T>::get(&who, asset_id);
+            ensure!(staked >= amount, Error::<T>::InsufficientBalance);
+
+            <T::Assets as fungibles::Transfer<T::AccountId>>::transfer(
+                asset_id,
+                &Self::escrow_account(),
+                &who,
+                amount,
+                false,
+            )?;
+
+            AssetStakes::<T>::mutate(&who, asset_id, |staked| {
+                *staked = staked.saturating_sub(amount);
+            });
+
+            Self::deposit_event(Event::AssetTokensUnstaked { who, asset_id, amount });
+
+            Ok(())
+        }
+
+        /// Pay for premium reputation query access with a configured asset (x402 micropayment)
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::pay_for_query_with_asset())]
+        pub fn pay_for_query_with_asset(
+            origin: OriginFor<T>,
+            asset_id: T::AssetId,
+            ual: Vec<u8>,
+            access_duration: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let ual = Self::bound_ual(ual)?;
+
+            let price = AssetConfigs::<T>::get(asset_id)
+                .ok_or(Error::<T>::AssetNotAccepted)?
+                .base_query_price;
+
+            let treasury = TreasuryAccount::<T>::get()
+                .ok_or(Error::<T>::TreasuryNotSet)?;
+
+            // Split payment between the data provider and the treasury
+            let provider_cut = match Self::payable_provider(&ual) {
+                Some(provider) => {
+                    let cut = T::ProviderShare::get() * price;
+                    <T::Assets as fungibles::Transfer<T::AccountId>>::transfer(
+                        asset_id,
+                        &who,
+                        &provider,
+                        cut,
+                        true,
+                    )?;
+                    cut
+                }
+                None => Zero::zero(),
+            };
+
+            <T::Assets as fungibles::Transfer<T::AccountId>>::transfer(
+                asset_id,
+                &who,
+                &treasury,
+                price.saturating_sub(provider_cut),
+                true,
+            )?;
+
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            let expiry = current_block.saturating_add(access_duration);
+
+            QueryAccess::<T>::insert(&who, &ual, expiry);
+
+            Self::deposit_event(Event::AssetQueryPaymentMade {
+                payer: who.clone(),
+                asset_id,
+                ual: ual.clone(),
+                amount: price,
+            });
+
+            Self::deposit_event(Event::QueryAccessGranted {
+                querier: who,
+                ual,
+                expiry,
+            });
+
+            Ok(())
+        }
+
+        /// Open an asset-denominated payment channel
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::open_asset_channel())]
+        pub fn open_asset_channel(
+            origin: OriginFor<T>,
+            payee: T::AccountId,
+            asset_id: T::AssetId,
+            deposit: AssetBalanceOf<T>,
+            duration: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let payer = ensure_signed(origin)?;
+
+            ensure!(AssetConfigs::<T>::contains_key(asset_id), Error::<T>::AssetNotAccepted);
+            ensure!(
+                !AssetPaymentChannels::<T>::contains_key((&payer, &payee, asset_id)),
+                Error::<T>::ChannelAlreadyExists
+            );
+
+            <T::Assets as fungibles::Transfer<T::AccountId>>::transfer(
+                asset_id,
+                &payer,
+                &Self::escrow_account(),
+                deposit,
+                true,
+            )
+            .map_err(|_| Error::<T>::InsufficientBalance)?;
+
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            let expiry = current_block.saturating_add(duration);
+
+            AssetPaymentChannels::<T>::insert((&payer, &payee, asset_id), (deposit, expiry));
+
+            Self::deposit_event(Event::AssetChannelOpened {
+                payer,
+                payee,
+                asset_id,
+                amount: deposit,
+            });
+
+            Ok(())
+        }
+
+        /// Close an asset-denominated payment channel and refund the remaining deposit
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::close_asset_channel())]
+        pub fn close_asset_channel(
+            origin: OriginFor<T>,
+            payee: T::AccountId,
+            asset_id: T::AssetId,
+        ) -> DispatchResult {
+            let payer = ensure_signed(origin)?;
+
+            let (deposit, _) = AssetPaymentChannels::<T>::get((&payer, &payee, asset_id))
+                .ok_or(Error::<T>::ChannelNotFound)?;
+
+            <T::Assets as fungibles::Transfer<T::AccountId>>::transfer(
+                asset_id,
+                &Self::escrow_account(),
+                &payer,
+                deposit,
+                false,
+            )?;
+
+            AssetPaymentChannels::<T>::remove((&payer, &payee, asset_id));
+
+            Self::deposit_event(Event::AssetChannelClosed { payer, payee, asset_id, refunded: deposit });
+
+            Ok(())
+        }
+
+        /// Finalize an unchallenged claim once its challenge window has passed, or a jury
+        /// verdict once its appeal window has passed
+        ///
+        /// Permissionless: anyone may call this to settle the claim and release the stakes.
+        /// Unchallenged fraud reports are escalated to governance instead.
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::finalize_claim())]
+        pub fn finalize_claim(
+            origin: OriginFor<T>,
+            claim_id: u64,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            Self::ensure_not_migrating()?;
+
+            let claim = Claims::<T>::get(claim_id)
+                .ok_or(Error::<T>::ClaimNotFound)?;
+
+            Self::do_finalize_claim(claim_id, claim, <frame_system::Pallet<T>>::block_number())
+        }
+
+        /// Join the juror pool by reserving the juror stake
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::join_jury_pool(T::MaxJurorPool::get()).saturating_add(T::Reputation::reputation_of_weight()))]
+        pub fn join_jury_pool(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(!JurorStakes::<T>::contains_key(&who), Error::<T>::AlreadyJuror);
+            ensure!(
+                T::Reputation::reputation_of(&who) >= T::MinJurorReputation::get(),
+                Error::<T>::InsufficientReputation
+            );
+
+            JurorPool::<T>::try_mutate(|pool| pool.try_push(who.clone()))
+                .map_err(|_| Error::<T>::JurorPoolFull)?;
+
+            let stake = T::JurorStake::get();
+            T::Currency::reserve(&who, stake)
+                .map_err(|_| Error::<T>::InsufficientBalance)?;
+            JurorStakes::<T>::insert(&who, stake);
+
+            Self::deposit_event(Event::JurorJoined { who, stake });
+
+            Ok(())
+        }
+
+        /// Leave the juror pool and release the remaining juror stake
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::leave_jury_pool(T::MaxJurorPool::get()))]
+        pub fn leave_jury_pool(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let stake = JurorStakes::<T>::get(&who).ok_or(Error::<T>::NotJuror)?;
+            ensure!(JurorAssignments::<T>::get(&who) == 0, Error::<T>::JurorOnDuty);
+            ensure!(UnrevealedSeeds::<T>::get(&who) == 0, Error::<T>::SeedUnrevealed);
+
+            T::Currency::unreserve(&who, stake);
+            JurorStakes::<T>::remove(&who);
+            JurorAssignments::<T>::remove(&who);
+            JurorPool::<T>::mutate(|pool| pool.retain(|juror| juror != &who));
+
+            Self::deposit_event(Event::JurorLeft { who });
+
+            Ok(())
+        }
+
+        /// Vote on a challenged claim as a seated juror
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::cast_jury_vote())]
+        pub fn cast_jury_vote(
+            origin: OriginFor<T>,
+            claim_id: u64,
+            resolution: ClaimResolution,
+        ) -> DispatchResult {
+            let juror = ensure_signed(origin)?;
+            Self::ensure_not_migrating()?;
+
+            let jury = ClaimJuries::<T>::get(claim_id).ok_or(Error::<T>::NoJury)?;
+            ensure!(jury.jurors.contains(&juror), Error::<T>::NotOnJury);
+
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            ensure!(current_block <= jury.voting_deadline, Error::<T>::VotingClosed);
+            ensure!(
+                !JuryVotes::<T>::contains_key(claim_id, &juror),
+                Error::<T>::AlreadyVoted
+            );
+
+            JuryVotes::<T>::insert(claim_id, &juror, resolution.clone());
+
+            Self::deposit_event(Event::JuryVoteCast { claim_id, juror, resolution });
+
+            Ok(())
+        }
+
+        /// Tally a jury after its voting period and enact the majority resolution
+        ///
+        /// Permissionless. Without an absolute majority of the seated jury the claim is
+        /// escalated to the root fallback (`resolve_claim`).
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::tally_jury(T::MaxJurySize::get()))]
+        pub fn tally_jury(
+            origin: OriginFor<T>,
+            claim_id: u64,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            Self::ensure_not_migrating()?;
+
+            let mut claim = Claims::<T>::get(claim_id)
+                .ok_or(Error::<T>::ClaimNotFound)?;
+            ensure!(
+                claim.status == ClaimStatus::Challenged,
+                Error::<T>::ClaimNotResolvable
+            );
+
+            let jury = ClaimJuries::<T>::get(claim_id).ok_or(Error::<T>::NoJury)?;
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            ensure!(current_block > jury.voting_deadline, Error::<T>::VotingOpen);
+
+            let (mut accepted, mut rejected, mut uncertain) = (0u32, 0u32, 0u32);
+            for juror in jury.jurors.iter() {
+                match JuryVotes::<T>::get(claim_id, juror) {
+                    Some(ClaimResolution::Accepted) => accepted += 1,
+                    Some(ClaimResolution::Rejected) => rejected += 1,
+                    Some(ClaimResolution::Uncertain) => uncertain += 1,
+                    None => {}
+                }
+            }
+
+            let seated = jury.jurors.len() as u32;
+            let verdict = if accepted * 2 > seated {
+                Some(ClaimResolution::Accepted)
+            } else if rejected * 2 > seated {
+                Some(ClaimResolution::Rejected)
+            } else if uncertain * 2 > seated {
+                Some(ClaimResolution::Uncertain)
+            } else {
+                None
+            };
+
+            Self::settle_jury(claim_id, &jury, verdict.as_ref());
+            ClaimJuries::<T>::remove(claim_id);
+
+            match verdict {
+                Some(resolution) => {
+                    let appeal_deadline = current_block.saturating_add(T::AppealPeriod::get());
+                    claim.status = ClaimStatus::Decided;
+                    claim.resolution = Some(resolution.clone());
+                    AppealDeadlines::<T>::insert(claim_id, appeal_deadline);
+                    Self::schedule_sweep(appeal_deadline.saturating_add(One::one()), claim_id);
+                    Self::deposit_event(Event::ClaimDecided {
+                        claim_id,
+                        resolution,
+                        round: ClaimRounds::<T>::get(claim_id),
+                        appeal_deadline,
+                    });
+                }
+                None => {
+                    claim.status = ClaimStatus::Escalated;
+                    Self::deposit_event(Event::ClaimEscalated { claim_id });
+                }
+            }
+            Self::put_claim(claim_id, claim);
+
+            Ok(())
+        }
+
+        /// Appeal a jury verdict by doubling the appellant's stake
+        ///
+        /// The claim moves to a larger jury for the next round, drawn with `draw_jury` once
+        /// the current seed round is revealed; appealing the final jury
+        /// round escalates the claim to governance (`resolve_claim`). The losing side of the
+        /// final resolution forfeits its accumulated stake.
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::appeal_claim(T::MaxJurorPool::get()))]
+        pub fn appeal_claim(
+            origin: OriginFor<T>,
+            claim_id: u64,
+        ) -> DispatchResult {
+            let appellant = ensure_signed(origin)?;
+            Self::ensure_not_migrating()?;
+
+            let mut claim = Claims::<T>::get(claim_id)
+                .ok_or(Error::<T>::ClaimNotFound)?;
+            ensure!(
+                claim.status == ClaimStatus::Decided,
+                Error::<T>::ClaimNotAppealable
+            );
+
+            let appeal_deadline = AppealDeadlines::<T>::get(claim_id)
+                .ok_or(Error::<T>::ClaimNotAppealable)?;
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            ensure!(current_block <= appeal_deadline, Error::<T>::AppealWindowExpired);
+
+            let mut challenge = ClaimChallenges::<T>::get(claim_id)
+                .ok_or(Error::<T>::ClaimNotFound)?;
+            let is_submitter = appellant == claim.submitter;
+            let is_challenger = appellant == challenge.challenger;
+            let may_appeal = match claim.resolution {
+                Some(ClaimResolution::Accepted) => is_challenger,
+                Some(ClaimResolution::Rejected) => is_submitter,
+                _ => is_submitter || is_challenger,
+            };
+            ensure!(may_appeal, Error::<T>::NotAppellant);
+
+            // Double the appellant's stake
+            let additional_stake = if is_submitter { claim.stake } else { challenge.stake };
+            T::Currency::reserve(&appellant, additional_stake)
+                .map_err(|_| Error::<T>::InsufficientBalance)?;
+            if is_submitter {
+                claim.stake = claim.stake.saturating_add(additional_stake);
+            } else {
+                challenge.stake = challenge.stake.saturating_add(additional_stake);
+                ClaimChallenges::<T>::insert(claim_id, &challenge);
+            }
+
+            let round = ClaimRounds::<T>::get(claim_id).saturating_add(1);
+            ClaimRounds::<T>::insert(claim_id, round);
+            AppealDeadlines::<T>::remove(claim_id);
+            claim.resolution = None;
+
+            Self::deposit_event(Event::ClaimAppealed {
+                claim_id,
+                appellant,
+                round,
+                additional_stake,
+            });
+
+            if round > T::MaxAppealRounds::get() {
+                claim.status = ClaimStatus::Escalated;
+                Self::deposit_event(Event::ClaimEscalated { claim_id });
+            } else {
+                claim.status = ClaimStatus::Challenged;
+                Self::seat_jury(claim_id, &mut claim, round);
+            }
+            Self::put_claim(claim_id, claim);
+
+            Ok(())
+        }
+
+        /// Buy query access to several UALs in one transaction
+        ///
+        /// Multi-item batches get `BatchDiscount` off every item. Provider shares are
+        /// aggregated into one transfer per provider and the rest into one treasury transfer.
+        #[pallet::call_index(25)]
+        #[pallet::weight(
+            T::WeightInfo::pay_for_queries(queries.len() as u32)
+                .saturating_add(Pallet::<T>::backer_payout_weight(queries.len() as u32))
+        )]
+        pub fn pay_for_queries(
+            origin: OriginFor<T>,
+            queries: BoundedVec<(Vec<u8>, BlockNumberFor<T>), T::MaxQueryBatch>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!queries.is_empty(), Error::<T>::EmptyQueryBatch);
+
+            let treasury = TreasuryAccount::<T>::get()
+                .ok_or(Error::<T>::TreasuryNotSet)?;
+
+            let discount = if queries.len() > 1 { T::BatchDiscount::get() } else { Perbill::zero() };
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            let items = queries.len() as u32;
+
+            let mut total: BalanceOf<T> = Zero::zero();
+            let mut provider_totals: BTreeMap<T::AccountId, BalanceOf<T>> = BTreeMap::new();
+
+            for (ual, access_duration) in queries.into_iter() {
+                let ual = Self::bound_ual(ual)?;
+                Self::note_query(&ual);
+                let list_price = Self::query_price(&ual);
+                let price = list_price.saturating_sub(discount * list_price);
+                total = total.saturating_add(price);
+                Self::accrue_ual_revenue(&ual, price);
+
+                if let Some(provider) = Self::payable_provider(&ual) {
+                    let cut = T::ProviderShare::get() * price;
+                    let owed = provider_totals.entry(provider.clone()).or_insert_with(Zero::zero);
+                    *owed = owed.saturating_add(cut);
+                    Self::deposit_event(Event::ProviderFeePaid {
+                        provider,
+                        ual: ual.clone(),
+                        amount: cut,
+                    });
+                }
+
+                let expiry = current_block.saturating_add(access_duration);
+                QueryAccess::<T>::insert(&who, &ual, expiry);
+
+                Self::deposit_event(Event::QueryPaymentMade {
+                    payer: who.clone(),
+                    ual: ual.clone(),
+                    amount: price,
+                });
+                Self::deposit_event(Event::QueryAccessGranted {
+                    querier: who.clone(),
+                    ual,
+                    expiry,
+                });
+            }
+
+            let mut treasury_share = total;
+            for (provider, amount) in provider_totals {
+                Self::pay_provider(&who, &provider, amount)?;
+                treasury_share = treasury_share.saturating_sub(amount);
+            }
+            T::Currency::transfer(&who, &treasury, treasury_share, ExistenceRequirement::KeepAlive)?;
+            Self::accrue_treasury_income(treasury_share);
+
+            Self::deposit_event(Event::QueryBatchPaid { payer: who, items, total });
+
+            Ok(())
+        }
+
+        /// Set or clear the dynamic pricing parameters (governance only)
+        #[pallet::call_index(26)]
+        #[pallet::weight(T::WeightInfo::set_pricing_config())]
+        pub fn set_pricing_config(
+            origin: OriginFor<T>,
+            config: Option<PricingConfig<BalanceOf<T>, BlockNumberFor<T>>>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            if let Some(ref config) = config {
+                ensure!(
+                    config.floor <= config.ceiling && !config.window.is_zero(),
+                    Error::<T>::InvalidPricingConfig
+                );
+            }
+
+            Pricing::<T>::set(config.clone());
+
+            Self::deposit_event(Event::PricingConfigSet { config });
+
+            Ok(())
+        }
+
+        /// Use an open payment channel to `payee` for per-query access to a UAL
+        #[pallet::call_index(27)]
+        #[pallet::weight(T::WeightInfo::enable_metered_access())]
+        pub fn enable_metered_access(
+            origin: OriginFor<T>,
+            ual: Vec<u8>,
+            payee: T::AccountId,
+        ) -> DispatchResult {
+            let consumer = ensure_signed(origin)?;
+            let ual = Self::bound_ual(ual)?;
+
+            let (_, expiry) = PaymentChannels::<T>::get(&consumer, &payee)
+                .ok_or(Error::<T>::ChannelNotFound)?;
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            ensure!(current_block <= expiry, Error::<T>::ChannelExpired);
+            ensure!(
+                Self::remaining_queries(&consumer, &payee) > 0,
+                Error::<T>::InsufficientChannelBalance
+            );
+
+            MeteredAccess::<T>::insert(&consumer, &ual, &payee);
+
+            Self::deposit_event(Event::MeteredAccessEnabled { consumer, ual, payee });
+
+            Ok(())
+        }
+
+        /// Debit one executed query from the consumer's channel (payee only)
+        ///
+        /// Access is revoked automatically once the channel cannot cover another query;
+        /// an exhausted channel is closed. Expired channels cannot be charged.
+        #[pallet::call_index(28)]
+        #[pallet::weight(T::WeightInfo::record_metered_query())]
+        pub fn record_metered_query(
+            origin: OriginFor<T>,
+            consumer: T::AccountId,
+            ual: Vec<u8>,
+        ) -> DispatchResult {
+            let payee = ensure_signed(origin)?;
+            let ual = Self::bound_ual(ual)?;
+
+            let metered_payee = MeteredAccess::<T>::get(&consumer, &ual)
+                .ok_or(Error::<T>::NoMeteredAccess)?;
+            ensure!(metered_payee == payee, Error::<T>::NotMeteredPayee);
+
+            let (balance, expiry) = PaymentChannels::<T>::get(&consumer, &payee)
+                .ok_or(Error::<T>::ChannelNotFound)?;
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            ensure!(current_block <= expiry, Error::<T>::ChannelExpired);
+            let fee = T::MeteredQueryFee::get();
+            ensure!(balance >= fee, Error::<T>::InsufficientChannelBalance);
+
+            T::Currency::repatriate_reserved(&consumer, &payee, fee, BalanceStatus::Free)?;
+            Self::accrue_ual_revenue(&ual, fee);
+            if TreasuryAccount::<T>::get().as_ref() == Some(&payee) {
+                Self::accrue_treasury_income(fee);
+            } else {
+                ProviderRevenue::<T>::mutate(&payee, |earned| *earned = earned.saturating_add(fee));
+            }
+
+            let remaining = balance.saturating_sub(fee);
+            if remaining.is_zero() {
+                PaymentChannels::<T>::remove(&consumer, &payee);
+            } else {
+                PaymentChannels::<T>::insert(&consumer, &payee, (remaining, expiry));
+            }
+            let remaining_queries = Self::remaining_queries(&consumer, &payee);
+
+            Self::deposit_event(Event::MeteredQueryDebited {
+                consumer: consumer.clone(),
+                ual: ual.clone(),
+                payee,
+                amount: fee,
+                remaining_queries,
+            });
+
+            if remaining_queries == 0 {
+                MeteredAccess::<T>::remove(&consumer, &ual);
+                Self::deposit_event(Event::MeteredAccessRevoked { consumer, ual });
+            }
+
+            Ok(())
+        }
+
+        /// Stop using usage-metered access for a UAL
+        #[pallet::call_index(29)]
+        #[pallet::weight(T::WeightInfo::disable_metered_access())]
+        pub fn disable_metered_access(
+            origin: OriginFor<T>,
+            ual: Vec<u8>,
+        ) -> DispatchResult {
+            let consumer = ensure_signed(origin)?;
+            let ual = Self::bound_ual(ual)?;
+
+            ensure!(
+                MeteredAccess::<T>::contains_key(&consumer, &ual),
+                Error::<T>::NoMeteredAccess
+            );
+            MeteredAccess::<T>::remove(&consumer, &ual);
+
+            Self::deposit_event(Event::MeteredAccessRevoked { consumer, ual });
+
+            Ok(())
+        }
+
+        /// Set or clear the rejected-claim slashing policy (governance only)
+        #[pallet::call_index(30)]
+        #[pallet::weight(T::WeightInfo::set_slashing_policy())]
+        pub fn set_slashing_policy(
+            origin: OriginFor<T>,
+            policy: Option<SlashingPolicy<BlockNumberFor<T>>>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            if let Some(ref policy) = policy {
+                ensure!(
+                    policy.first_offence <= policy.max_severity && !policy.decay_period.is_zero(),
+                    Error::<T>::InvalidSlashingPolicy
+                );
+            }
+
+            Slashing::<T>::set(policy.clone());
+
+            Self::deposit_event(Event::SlashingPolicySet { policy });
+
+            Ok(())
+        }
+
+        /// Report a verified contribution as fraudulent, staking on the report
+        ///
+        /// Opens a claim by the reporter that the contributor can challenge within
+        /// `FraudReportWindow`, putting it to a jury. A report left unchallenged is escalated
+        /// to governance rather than upheld by default. An upheld report overturns the
+        /// contribution and pays the reporter `WinnerShare` of the contributor's slashed
+        /// credibility stake; a rejected one forfeits the reporter's stake like any rejected
+        /// claim.
+        #[pallet::call_index(31)]
+        #[pallet::weight(T::WeightInfo::report_contribution(evidence_uals.len() as u32))]
+        pub fn report_contribution(
+            origin: OriginFor<T>,
+            contribution_id: ContributionId,
+            evidence_uals: Vec<Vec<u8>>,
+            stake: BalanceOf<T>,
+        ) -> DispatchResult {
+            let reporter = ensure_signed(origin)?;
+            Self::ensure_not_migrating()?;
+
+            let contributor = T::Contributions::verified_contributor(contribution_id)
+                .ok_or(Error::<T>::ContributionNotReportable)?;
+            ensure!(reporter != contributor, Error::<T>::CannotReportOwnContribution);
+            ensure!(
+                !ReportedContributions::<T>::contains_key(contribution_id),
+                Error::<T>::ContributionAlreadyReported
+            );
+
+            let claim_id = Self::do_post_claim(
+                reporter.clone(),
+                Self::fraud_report_ual(contribution_id),
+                evidence_uals,
+                stake,
+                T::FraudReportWindow::get(),
+            )?;
+            FraudReports::<T>::insert(claim_id, FraudReport { contribution_id, contributor: contributor.clone() });
+            ReportedContributions::<T>::insert(contribution_id, claim_id);
+            OpenFraudReports::<T>::mutate(&contributor, |open| *open = open.saturating_add(1));
+
+            Self::deposit_event(Event::ContributionReported {
+                claim_id,
+                reporter,
+                contribution_id,
+                contributor,
+            });
+
+            Ok(())
+        }
+
+        /// Offer stake vouching that a contributor's history is genuine
+        ///
+        /// The stake is reserved until the contributor accepts the offer with
+        /// `accept_backing`, or either side cancels it with `cancel_backing_offer`. Once
+        /// accepted, backers receive `BackerShare` of the contributor's provider fees pro
+        /// rata to their stake, and lose the same portion of it as the contributor's own
+        /// stake when fraud is proven. Offering again adds to the pending offer.
+        #[pallet::call_index(32)]
+        #[pallet::weight(T::WeightInfo::back_contributor())]
+        pub fn back_contributor(
+            origin: OriginFor<T>,
+            contributor: T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let backer = ensure_signed(origin)?;
+            ensure!(backer != contributor, Error::<T>::CannotBackSelf);
+            ensure!(amount >= T::MinimumStake::get(), Error::<T>::BelowMinimumStake);
+
+            T::Currency::reserve(&backer, amount)
+                .map_err(|_| Error::<T>::InsufficientBalance)?;
+
+            BackingOffers::<T>::mutate(&contributor, &backer, |offer| {
+                *offer = Some(offer.unwrap_or_else(Zero::zero).saturating_add(amount));
+            });
+
+            Self::deposit_event(Event::BackingOffered { backer, contributor, amount });
+
+            Ok(())
+        }
+
+        /// Accept a backer's pending offer, adding its stake to the backer's backing
+        ///
+        /// # Errors
+        ///
+        /// - `NoBackingOffer` if `backer` has no pending offer to the caller
+        /// - `TooManyBackers` if the caller already has `MaxBackers` other backers
+        #[pallet::call_index(45)]
+        #[pallet::weight(T::WeightInfo::accept_backing(T::MaxBackers::get()))]
+        pub fn accept_backing(
+            origin: OriginFor<T>,
+            backer: T::AccountId,
+        ) -> DispatchResult {
+            let contributor = ensure_signed(origin)?;
+
+            let amount = BackingOffers::<T>::get(&contributor, &backer).ok_or(Error::<T>::NoBackingOffer)?;
+            Backers::<T>::try_mutate(&contributor, |backers| -> DispatchResult {
+                match backers.iter_mut().find(|(account, _)| *account == backer) {
+                    Some((_, stake)) => *stake = stake.saturating_add(amount),
+                    None => backers
+                        .try_push((backer.clone(), amount))
+                        .map_err(|_| Error::<T>::TooManyBackers)?,
+                }
+                Ok(())
+            })?;
+            BackingOffers::<T>::remove(&contributor, &backer);
+
+            Self::deposit_event(Event::ContributorBacked { backer, contributor, amount });
+
+            Ok(())
+        }
+
+        /// Cancel a pending backing offer, releasing its stake
+        ///
+        /// Callable by the contributor to reject the offer, or by the backer to withdraw it.
+        ///
+        /// # Errors
+        ///
+        /// - `NoBackingOffer` if the caller is neither party or there is no pending offer
+        #[pallet::call_index(46)]
+        #[pallet::weight(T::WeightInfo::cancel_backing_offer())]
+        pub fn cancel_backing_offer(
+            origin: OriginFor<T>,
+            contributor: T::AccountId,
+            backer: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(who == contributor || who == backer, Error::<T>::NoBackingOffer);
+
+            let amount = BackingOffers::<T>::take(&contributor, &backer).ok_or(Error::<T>::NoBackingOffer)?;
+            T::Currency::unreserve(&backer, amount);
+
+            Self::deposit_event(Event::BackingOfferCancelled { backer, contributor, amount });
+
+            Ok(())
+        }
+
+        /// Release part or all of the caller's backing of a contributor
+        ///
+        /// Locked while fraud reports against the contributor are open, so backers
+        /// cannot leave ahead of a slash.
+        #[pallet::call_index(33)]
+        #[pallet::weight(T::WeightInfo::withdraw_backing(T::MaxBackers::get()))]
+        pub fn withdraw_backing(
+            origin: OriginFor<T>,
+            contributor: T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let backer = ensure_signed(origin)?;
+            ensure!(OpenFraudReports::<T>::get(&contributor) == 0, Error::<T>::BackingLocked);
+
+            Backers::<T>::try_mutate_exists(&contributor, |maybe_backers| -> DispatchResult {
+                let backers = maybe_backers.as_mut().ok_or(Error::<T>::NotBacking)?;
+                let index = backers
+                    .iter()
+                    .position(|(account, _)| *account == backer)
+                    .ok_or(Error::<T>::NotBacking)?;
+                let stake = &mut backers[index].1;
+                ensure!(*stake >= amount, Error::<T>::InsufficientBalance);
+
+                *stake = stake.saturating_sub(amount);
+                if stake.is_zero() {
+                    backers.remove(index);
+                }
+                if backers.is_empty() {
+                    *maybe_backers = None;
+                }
+                Ok(())
+            })?;
+            T::Currency::unreserve(&backer, amount);
+
+            Self::deposit_event(Event::BackingWithdrawn { backer, contributor, amount });
+
+            Ok(())
+        }
+
+        /// Set how long posted claims stay open to challenge (governance only)
+        ///
+        /// Applies to claims posted from now on; open claims keep their deadline.
+        /// `None` restores `DEFAULT_CHALLENGE_WINDOW`.
+        #[pallet::call_index(34)]
+        #[pallet::weight(T::WeightInfo::set_challenge_window())]
+        pub fn set_challenge_window(
+            origin: OriginFor<T>,
+            window: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            if let Some(window) = window {
+                ensure!(!window.is_zero(), Error::<T>::InvalidChallengeWindow);
+            }
+
+            ChallengeWindow::<T>::set(window);
+
+            Self::deposit_event(Event::ChallengeWindowSet { window: Self::challenge_window() });
+
+            Ok(())
+        }
+
+        /// Post a claim about one of the caller's verified contributions
+        ///
+        /// The claim UAL is the Knowledge Asset the contribution was published as. The
+        /// claim is linked to the contribution, so its resolution is reported through
+        /// `OnClaimResolved::on_contribution_claim_resolved`.
+        #[pallet::call_index(35)]
+        #[pallet::weight(T::WeightInfo::post_contribution_claim(evidence_uals.len() as u32))]
+        pub fn post_contribution_claim(
+            origin: OriginFor<T>,
+            contribution_id: ContributionId,
+            evidence_uals: Vec<Vec<u8>>,
+            stake: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_migrating()?;
+
+            ensure!(
+                T::Contributions::verified_contributor(contribution_id).as_ref() == Some(&who),
+                Error::<T>::NotContributor
+            );
+            let claim_ual = T::Contributions::contribution_ual(contribution_id)
+                .ok_or(Error::<T>::ContributionUalUnknown)?;
+
+            let claim_id = Self::do_post_claim(who.clone(), claim_ual, evidence_uals, stake, Self::challenge_window())?;
+            ContributionClaims::<T>::insert(claim_id, contribution_id);
+
+            Self::deposit_event(Event::ContributionClaimPosted { claim_id, contributor: who, contribution_id });
+
+            Ok(())
+        }
+
+        /// Reserve funds for queries paid from the caller's prepaid balance
+        ///
+        /// The funds are held under `PREPAID_RESERVE_ID`. Opens the prepaid account on first
+        /// use, itemizing consumption under the current billing period.
+        #[pallet::call_index(36)]
+        #[pallet::weight(T::WeightInfo::top_up_prepaid())]
+        pub fn top_up_prepaid(
+            origin: OriginFor<T>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            T::Currency::reserve_named(&PREPAID_RESERVE_ID, &who, amount)
+                .map_err(|_| Error::<T>::InsufficientBalance)?;
+
+            let period = Self::billing_period(<frame_system::Pallet<T>>::block_number());
+            let mut account = PrepaidAccounts::<T>::get(&who)
+                .unwrap_or(PrepaidAccount { balance: Zero::zero(), period });
+            account.balance = account.balance.saturating_add(amount);
+            PrepaidAccounts::<T>::insert(&who, &account);
+
+            Self::deposit_event(Event::PrepaidToppedUp { organization: who, amount, balance: account.balance });
+
+            Ok(())
+        }
+
+        /// Pay for query access from the caller's prepaid balance
+        ///
+        /// The payment is split like `pay_for_query` and itemized under the current billing
+        /// period. A billing period that ended since the last prepaid query is closed first.
+        ///
+        /// # Errors
+        ///
+        /// - `NoPrepaidAccount` if the caller never topped up
+        /// - `InsufficientPrepaidBalance` if the balance does not cover the query price
+        /// - `InvoiceFull` if the billing period already itemizes `MaxInvoiceItems` queries
+        #[pallet::call_index(37)]
+        #[pallet::weight(
+            T::WeightInfo::pay_for_query_prepaid()
+                .saturating_add(T::WeightInfo::close_billing_period(T::MaxInvoiceItems::get()))
+                .saturating_add(Pallet::<T>::backer_payout_weight(1))
+        )]
+        pub fn pay_for_query_prepaid(
+            origin: OriginFor<T>,
+            ual: Vec<u8>,
+            access_duration: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let ual = Self::bound_ual(ual)?;
+            let mut account = PrepaidAccounts::<T>::get(&who)
+                .ok_or(Error::<T>::NoPrepaidAccount)?;
+
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            let period = Self::billing_period(current_block);
+            if account.period < period {
+                Self::close_billing_statement(&who, account.period);
+                account.period = period;
+            }
+
+            Self::note_query(&ual);
+            let price = Self::query_price(&ual);
+            ensure!(account.balance >= price, Error::<T>::InsufficientPrepaidBalance);
+
+            let timestamp = T::Time::now().saturated_into::<u64>();
+            InvoiceItems::<T>::try_mutate(&who, period, |items| {
+                items.try_push(InvoiceItem { ual: ual.clone(), amount: price, block: current_block, timestamp })
+            })
+            .map_err(|_| Error::<T>::InvoiceFull)?;
+
+            T::Currency::unreserve_named(&PREPAID_RESERVE_ID, &who, price);
+            Self::settle_query_payment(&who, &ual, price)?;
+            account.balance = account.balance.saturating_sub(price);
+            PrepaidAccounts::<T>::insert(&who, &account);
+
+            let expiry = current_block.saturating_add(access_duration);
+            QueryAccess::<T>::insert(&who, &ual, expiry);
+
+            Self::deposit_event(Event::PrepaidQueryConsumed {
+                organization: who.clone(),
+                ual: ual.clone(),
+                amount: price,
+                period,
+                timestamp,
+            });
+            Self::deposit_event(Event::QueryAccessGranted { querier: who, ual, expiry });
+
+            Ok(())
+        }
+
+        /// Close an organization's billing period once it has ended
+        ///
+        /// Callable by anyone, so statements are issued for organizations that stopped
+        /// querying. The itemized queries are hashed into a `BillingStatement` and removed.
+        #[pallet::call_index(38)]
+        #[pallet::weight(T::WeightInfo::close_billing_period(T::MaxInvoiceItems::get()))]
+        pub fn close_billing_period(
+            origin: OriginFor<T>,
+            organization: T::AccountId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let mut account = PrepaidAccounts::<T>::get(&organization)
+                .ok_or(Error::<T>::NoPrepaidAccount)?;
+            let period = Self::billing_period(<frame_system::Pallet<T>>::block_number());
+            ensure!(account.period < period, Error::<T>::BillingPeriodOpen);
+
+            Self::close_billing_statement(&organization, account.period);
+            account.period = period;
+            PrepaidAccounts::<T>::insert(&organization, account);
+
+            Ok(())
+        }
+
+        /// Release unconsumed funds from the caller's prepaid balance
+        ///
+        /// Consumption already itemized stays on the open billing period's statement.
+        #[pallet::call_index(39)]
+        #[pallet::weight(T::WeightInfo::withdraw_prepaid())]
+        pub fn withdraw_prepaid(
+            origin: OriginFor<T>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut account = PrepaidAccounts::<T>::get(&who)
+                .ok_or(Error::<T>::NoPrepaidAccount)?;
+            ensure!(account.balance >= amount, Error::<T>::InsufficientPrepaidBalance);
+
+            T::Currency::unreserve_named(&PREPAID_RESERVE_ID, &who, amount);
+            account.balance = account.balance.saturating_sub(amount);
+            PrepaidAccounts::<T>::insert(&who, &account);
+
+            Self::deposit_event(Event::PrepaidWithdrawn { organization: who, amount, balance: account.balance });
+
+            Ok(())
+        }
+
+        /// Remove a closing statement older than `BillingStatementRetention` billing periods
+        ///
+        /// Callable by anyone; off-chain accounting is expected to have copied the statement
+        /// by then.
+        ///
+        /// # Errors
+        ///
+        /// - `NoBillingStatement` if the organization has no statement for `period`
+        /// - `BillingStatementRetained` if the statement is still within its retention
+        #[pallet::call_index(40)]
+        #[pallet::weight(T::WeightInfo::prune_billing_statement())]
+        pub fn prune_billing_statement(
+            origin: OriginFor<T>,
+            organization: T::AccountId,
+            period: u32,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            ensure!(BillingStatements::<T>::contains_key(&organization, period), Error::<T>::NoBillingStatement);
+            let current = Self::billing_period(<frame_system::Pallet<T>>::block_number());
+            ensure!(
+                period.saturating_add(T::BillingStatementRetention::get()) < current,
+                Error::<T>::BillingStatementRetained
+            );
+            BillingStatements::<T>::remove(&organization, period);
+
+            Self::deposit_event(Event::BillingStatementPruned { organization, period });
+
+            Ok(())
+        }
+
+        /// Draw the jury of a challenged claim once its seed round is revealed
+        ///
+        /// Permissionless. The seed folds the secrets pool members revealed for the round
+        /// with `T::Randomness`, so nobody knows the jury when the claim is challenged or
+        /// appealed. Members are drawn with probability proportional to the square root of
+        /// their reputation, excluding the parties. A round nobody revealed, or one pruned
+        /// after `JURY_SEED_RETENTION` rounds, escalates the claim to governance like a pool
+        /// too small to draw from.
+        ///
+        /// # Errors
+        ///
+        /// - `NoPendingDraw` if no jury draw is pending for the claim
+        /// - `SeedNotRevealed` if the draw's seed round is still being committed or revealed
+        #[pallet::call_index(41)]
+        #[pallet::weight(T::WeightInfo::draw_jury(T::MaxJurorPool::get()).saturating_add(Pallet::<T>::jury_draw_weight()))]
+        pub fn draw_jury(
+            origin: OriginFor<T>,
+            claim_id: u64,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            Self::ensure_not_migrating()?;
+
+            let draw = PendingJuryDraws::<T>::get(claim_id).ok_or(Error::<T>::NoPendingDraw)?;
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            ensure!(current_block >= Self::seed_revealed_at(draw.seed_round), Error::<T>::SeedNotRevealed);
+
+            let mut claim = Claims::<T>::get(claim_id).ok_or(Error::<T>::ClaimNotFound)?;
+            let challenge = ClaimChallenges::<T>::get(claim_id).ok_or(Error::<T>::ClaimNotFound)?;
+            PendingJuryDraws::<T>::remove(claim_id);
+            // Resolved by governance while the draw was pending
+            if claim.status != ClaimStatus::Challenged {
+                return Ok(());
+            }
+
+            let seed = JurySeeds::<T>::get(draw.seed_round);
+            let exclude = [claim.submitter.clone(), challenge.challenger];
+            let jurors = if seed.reveals == 0 {
+                None
+            } else {
+                Self::select_jurors(claim_id, draw.round, seed.seed, &exclude)
+            };
+
+            match jurors {
+                Some(jurors) => {
+                    for juror in jurors.iter() {
+                        JurorAssignments::<T>::mutate(juror, |count| *count = count.saturating_add(1));
+                    }
+                    let voting_deadline = current_block.saturating_add(T::JuryVotingPeriod::get());
+                    Self::deposit_event(Event::JuryDrawn {
+                        claim_id,
+                        jurors: jurors.to_vec(),
+                        voting_deadline,
+                    });
+                    ClaimJuries::<T>::insert(claim_id, Jury { jurors, voting_deadline });
+                }
+                None => {
+                    claim.status = ClaimStatus::Escalated;
+                    Self::deposit_event(Event::ClaimEscalated { claim_id });
+                    Self::put_claim(claim_id, claim);
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Commit to a secret seeding the juries drawn from the current seed round
+        ///
+        /// The commitment is `T::Hashing::hash_of(&(juror, secret))`; the secret is revealed
+        /// with `reveal_jury_seed` during the next seed round. A commitment left unrevealed
+        /// can be reported with `slash_unrevealed_seed`.
+        ///
+        /// # Errors
+        ///
+        /// - `NotJuror` if the caller is not in the juror pool
+        /// - `SeedAlreadyCommitted` if the caller already committed for the current round
+        #[pallet::call_index(42)]
+        #[pallet::weight(T::WeightInfo::commit_jury_seed())]
+        pub fn commit_jury_seed(
+            origin: OriginFor<T>,
+            commitment: T::Hash,
+        ) -> DispatchResult {
+            let juror = ensure_signed(origin)?;
+
+            ensure!(JurorStakes::<T>::contains_key(&juror), Error::<T>::NotJuror);
+            let seed_round = Self::seed_round(<frame_system::Pallet<T>>::block_number());
+            ensure!(
+                !SeedCommitments::<T>::contains_key(seed_round, &juror),
+                Error::<T>::SeedAlreadyCommitted
+            );
+
+            SeedCommitments::<T>::insert(seed_round, &juror, commitment);
+            UnrevealedSeeds::<T>::mutate(&juror, |count| *count = count.saturating_add(1));
+
+            Self::deposit_event(Event::JurySeedCommitted { juror, seed_round });
+
+            Ok(())
+        }
+
+        /// Reveal the secret committed for the previous seed round
+        ///
+        /// Folds the secret into the round's seed and prunes the seed of the round
+        /// `JURY_SEED_RETENTION` rounds earlier.
+        ///
+        /// # Errors
+        ///
+        /// - `SeedRevealClosed` if `seed_round` is not the previous seed round
+        /// - `NoSeedCommitment` if the caller did not commit for `seed_round`
+        /// - `SeedMismatch` if the secret does not match the commitment
+        #[pallet::call_index(43)]
+        #[pallet::weight(T::WeightInfo::reveal_jury_seed())]
+        pub fn reveal_jury_seed(
+            origin: OriginFor<T>,
+            seed_round: u32,
+            secret: [u8; 32],
+        ) -> DispatchResult {
+            let juror = ensure_signed(origin)?;
+
+            let current = Self::seed_round(<frame_system::Pallet<T>>::block_number());
+            ensure!(current == seed_round.saturating_add(1), Error::<T>::SeedRevealClosed);
+            let commitment = SeedCommitments::<T>::get(seed_round, &juror).ok_or(Error::<T>::NoSeedCommitment)?;
+            ensure!(T::Hashing::hash_of(&(&juror, secret)) == commitment, Error::<T>::SeedMismatch);
+
+            SeedCommitments::<T>::remove(seed_round, &juror);
+            Self::note_seed_settled(&juror);
+            JurySeeds::<T>::mutate(seed_round, |seed| {
+                seed.seed = seed.seed ^ T::Hashing::hash(&secret);
+                seed.reveals = seed.reveals.saturating_add(1);
+            });
+            if let Some(expired) = seed_round.checked_sub(JURY_SEED_RETENTION) {
+                JurySeeds::<T>::remove(expired);
+            }
+
+            Self::deposit_event(Event::JurySeedRevealed { juror, seed_round });
+
+            Ok(())
+        }
+
+        /// Slash a juror who committed to a seed but did not reveal it in time
+        ///
+        /// Permissionless; `JurorSlash` of the juror's stake goes to the caller, so
+        /// withholding a secret to bias a draw costs more than it can gain.
+        ///
+        /// # Errors
+        ///
+        /// - `CannotReportSelf` if the caller is `juror`
+        /// - `SeedRevealOpen` if the secret can still be revealed
+        /// - `NoSeedCommitment` if the juror has no unrevealed commitment for `seed_round`
+        #[pallet::call_index(44)]
+        #[pallet::weight(T::WeightInfo::slash_unrevealed_seed())]
+        pub fn slash_unrevealed_seed(
+            origin: OriginFor<T>,
+            seed_round: u32,
+            juror: T::AccountId,
+        ) -> DispatchResult {
+            let reporter = ensure_signed(origin)?;
+            ensure!(reporter != juror, Error::<T>::CannotReportSelf);
+
+            let current = Self::seed_round(<frame_system::Pallet<T>>::block_number());
+            ensure!(current > seed_round.saturating_add(1), Error::<T>::SeedRevealOpen);
+            SeedCommitments::<T>::take(seed_round, &juror).ok_or(Error::<T>::NoSeedCommitment)?;
+            Self::note_seed_settled(&juror);
+
+            let stake = JurorStakes::<T>::get(&juror).unwrap_or_else(Zero::zero);
+            let (imbalance, _) = T::Currency::slash_reserved(&juror, T::JurorSlash::get() * stake);
+            let slashed = imbalance.peek();
+            JurorStakes::<T>::mutate(&juror, |stake| {
+                if let Some(stake) = stake {
+                    *stake = stake.saturating_sub(slashed);
+                }
+            });
+            T::Currency::resolve_creating(&reporter, imbalance);
+
+            Self::deposit_event(Event::JurorSlashed { juror, amount: slashed });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Drop a revealed or slashed commitment from the juror's unrevealed count
+        fn note_seed_settled(juror: &T::AccountId) {
+            UnrevealedSeeds::<T>::mutate_exists(juror, |count| {
+                *count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0);
+            });
+        }
+
+        /// Blocks a claim posted now stays open to challenge
+        pub fn challenge_window() -> BlockNumberFor<T> {
+            ChallengeWindow::<T>::get().unwrap_or_else(|| DEFAULT_CHALLENGE_WINDOW.into())
+        }
+
+        /// Check if an account has valid query access
+        pub fn has_query_access(who: &T::AccountId, ual: &Ual) -> bool {
+            if let Some(expiry) = QueryAccess::<T>::get(who, ual) {
+                let current_block = <frame_system::Pallet<T>>::block_number();
+                if current_block <= expiry {
+                    return true;
+                }
+            }
+            Self::has_metered_access(who, ual)
+        }
+
+        /// Check if an account has usage-metered access with remaining quota
+        pub fn has_metered_access(who: &T::AccountId, ual: &Ual) -> bool {
+            MeteredAccess::<T>::get(who, ual)
+                .map_or(false, |payee| Self::remaining_queries(who, &payee) > 0)
+        }
+
+        /// Number of metered queries the consumer's channel to `payee` still covers; none
+        /// once the channel expired
+        pub fn remaining_queries(consumer: &T::AccountId, payee: &T::AccountId) -> u32 {
+            let fee = T::MeteredQueryFee::get();
+            if fee.is_zero() {
+                return 0;
+            }
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            PaymentChannels::<T>::get(consumer, payee)
+                .filter(|(_, expiry)| current_block <= *expiry)
+                .map_or(0, |(balance, _)| (balance / fee).saturated_into::<u32>())
+        }
+
+        /// Calculate reputation credibility boost from staking
+        pub fn credibility_boost(who: &T::AccountId) -> u32 {
+            let staked = StakedAmount::<T>::get(who);
+            let min_stake = T::MinimumStake::get();
+
+            if staked >= min_stake {
+                // Calculate boost percentage (e.g., 1% per minimum stake unit)
+                let boost = (staked / min_stake).saturated_into::<u32>();
+                boost.min(50) // Cap at 50% boost
+            } else {
+                0
+            }
+        }
+
+        /// Account holding asset stakes and channel deposits
+        pub fn escrow_account() -> T::AccountId {
+            T::PalletId::get().into_account_truncating()
+        }
+
+        /// Registered provider for a UAL, if it still controls the UAL
+        fn payable_provider(ual: &Ual) -> Option<T::AccountId> {
+            DataProviders::<T>::get(ual)
+                .filter(|provider| T::UalOwnership::owns_ual(provider, ual))
+        }
+
+        /// Slash a portion of an account's credibility stake
+        ///
+        /// Returns the amount actually slashed.
+        pub fn slash_stake(who: &T::AccountId, portion: Perbill) -> BalanceOf<T> {
+            Self::slash_stake_rewarding(who, portion, None).0
+        }
+
+        /// Slash a portion of an account's credibility stake, paying `WinnerShare` of it
+        /// to `beneficiary`
+        ///
+        /// Returns the amount slashed and the beneficiary's reward.
+        fn slash_stake_rewarding(
+            who: &T::AccountId,
+            portion: Perbill,
+            beneficiary: Option<&T::AccountId>,
+        ) -> (BalanceOf<T>, BalanceOf<T>) {
+            let staked = StakedAmount::<T>::get(who);
+            let to_slash = portion * staked;
+            if to_slash.is_zero() {
+                return (Zero::zero(), Zero::zero());
+            }
+
+            let (mut imbalance, remaining) = T::Currency::slash_reserved(who, to_slash);
+            let slashed = to_slash.saturating_sub(remaining);
+
+            let mut reward = Zero::zero();
+            if let Some(beneficiary) = beneficiary {
+                let (share, rest) = imbalance.split(T::WinnerShare::get() * slashed);
+                reward = share.peek();
+                T::Currency::resolve_creating(beneficiary, share);
+                imbalance = rest;
+            }
+
+            // Slashed funds go to the treasury when one is configured, otherwise they are burned
+            if let Some(treasury) = TreasuryAccount::<T>::get() {
+                T::Currency::resolve_creating(&treasury, imbalance);
+            }
+
+            StakedAmount::<T>::mutate(who, |staked| {
+                *staked = staked.saturating_sub(slashed);
+            });
+
+            (slashed, reward)
+        }
+
+        /// Slash a portion of each of an account's asset stakes from escrow
+        ///
+        /// Slashed assets go to the treasury when one is configured, otherwise they stay in
+        /// escrow without an owner. Returns the amount slashed per asset.
+        pub fn slash_asset_stakes(who: &T::AccountId, portion: Perbill) -> Vec<(T::AssetId, AssetBalanceOf<T>)> {
+            let treasury = TreasuryAccount::<T>::get();
+            let stakes = AssetStakes::<T>::iter_prefix(who).collect::<Vec<_>>();
+
+            let mut slashed = Vec::new();
+            for (asset_id, staked) in stakes {
+                let to_slash = portion * staked;
+                if to_slash.is_zero() {
+                    continue;
+                }
+                if let Some(treasury) = &treasury {
+                    let moved = <T::Assets as fungibles::Transfer<T::AccountId>>::transfer(
+                        asset_id,
+                        &Self::escrow_account(),
+                        treasury,
+                        to_slash,
+                        false,
+                    );
+                    if moved.is_err() {
+                        continue;
+                    }
+                }
+                AssetStakes::<T>::insert(who, asset_id, staked.saturating_sub(to_slash));
+                slashed.push((asset_id, to_slash));
+            }
+            slashed
+        }
+
+        /// Total stake backing a contributor
+        pub fn total_backing(contributor: &T::AccountId) -> BalanceOf<T> {
+            Backers::<T>::get(contributor)
+                .iter()
+                .fold(Zero::zero(), |total: BalanceOf<T>, (_, stake)| total.saturating_add(*stake))
+        }
+
+        /// Pay a provider fee, sharing `BackerShare` of it among the provider's backers
+        /// pro rata to their stake
+        fn pay_provider(payer: &T::AccountId, provider: &T::AccountId, fee: BalanceOf<T>) -> DispatchResult {
+            let backers = Backers::<T>::get(provider);
+            let total_backing = Self::total_backing(provider);
+            let backers_share = if total_backing.is_zero() { Zero::zero() } else { T::BackerShare::get() * fee };
+
+            let mut paid: BalanceOf<T> = Zero::zero();
+            for (backer, stake) in backers.iter() {
+                let payout = Perbill::from_rational(*stake, total_backing) * backers_share;
+                if payout.is_zero() {
+                    continue;
+                }
+                T::Currency::transfer(payer, backer, payout, ExistenceRequirement::KeepAlive)?;
+                paid = paid.saturating_add(payout);
+            }
+            T::Currency::transfer(payer, provider, fee.saturating_sub(paid), ExistenceRequirement::KeepAlive)?;
+            ProviderRevenue::<T>::mutate(provider, |earned| *earned = earned.saturating_add(fee.saturating_sub(paid)));
+
+            if !paid.is_zero() {
+                Self::deposit_event(Event::BackerRevenueShared { contributor: provider.clone(), amount: paid });
+            }
+            Ok(())
+        }
+
+        /// Split a query payment between the UAL's data provider and the treasury
+        fn settle_query_payment(payer: &T::AccountId, ual: &Ual, price: BalanceOf<T>) -> DispatchResult {
+            let treasury = TreasuryAccount::<T>::get()
+                .ok_or(Error::<T>::TreasuryNotSet)?;
+
+            let provider_cut = match Self::payable_provider(ual) {
+                Some(provider) => {
+                    let cut = T::ProviderShare::get() * price;
+                    Self::pay_provider(payer, &provider, cut)?;
+                    Self::deposit_event(Event::ProviderFeePaid {
+                        provider,
+                        ual: ual.clone(),
+                        amount: cut,
+                    });
+                    cut
+                }
+                None => Zero::zero(),
+            };
+
+            T::Currency::transfer(
+                payer,
+                &treasury,
+                price.saturating_sub(provider_cut),
+                ExistenceRequirement::KeepAlive,
+            )?;
+            Self::accrue_ual_revenue(ual, price);
+            Self::accrue_treasury_income(price.saturating_sub(provider_cut));
+            Ok(())
+        }
+
+        /// Hash an organization's itemized queries of `period` into its closing statement
+        fn close_billing_statement(organization: &T::AccountId, period: u32) {
+            let items = InvoiceItems::<T>::take(organization, period);
+            let total = items.iter()
+                .fold(Zero::zero(), |total: BalanceOf<T>, item| total.saturating_add(item.amount));
+            let statement_hash = T::Hashing::hash_of(&(organization, period, &items));
+            let statement = BillingStatement { items: items.len() as u32, total, statement_hash };
+            BillingStatements::<T>::insert(organization, period, &statement);
+
+            Self::deposit_event(Event::BillingStatementClosed {
+                organization: organization.clone(),
+                period,
+                items: statement.items,
+                total,
+                statement_hash,
+            });
+        }
+
+        fn accrue_ual_revenue(ual: &Ual, amount: BalanceOf<T>) {
+            UalRevenue::<T>::mutate(ual, |revenue| *revenue = revenue.saturating_add(amount));
+        }
+
+        fn accrue_treasury_income(amount: BalanceOf<T>) {
+            if amount.is_zero() {
+                return;
+            }
+            let period = Self::revenue_period(<frame_system::Pallet<T>>::block_number());
+            TreasuryIncome::<T>::mutate(period, |income| *income = income.saturating_add(amount));
+        }
+
+        /// Accounting period of `TreasuryIncome` containing `block`
+        pub fn revenue_period(block: BlockNumberFor<T>) -> u32 {
+            block.saturated_into::<u32>() / REVENUE_PERIOD_BLOCKS
+        }
+
+        /// Billing period of prepaid accounts a block falls in
+        pub fn billing_period(block: BlockNumberFor<T>) -> u32 {
+            (block / T::BillingPeriod::get().max(One::one())).saturated_into::<u32>()
+        }
+
+        /// Jury seed round a block falls in
+        pub fn seed_round(block: BlockNumberFor<T>) -> u32 {
+            (block / T::JurySeedPeriod::get().max(One::one())).saturated_into::<u32>()
+        }
+
+        /// First block at which juries can be drawn from a seed round, once the round
+        /// after it, during which its secrets are revealed, has ended
+        pub fn seed_revealed_at(seed_round: u32) -> BlockNumberFor<T> {
+            BlockNumberFor::<T>::from(seed_round.saturating_add(2))
+                .saturating_mul(T::JurySeedPeriod::get().max(One::one()))
+        }
+
+        /// Treasury income from query fees in the accounting periods overlapping
+        /// `from..=to`
+        ///
+        /// Returns `None` if the range overlaps more than `MAX_INCOME_PERIODS` periods.
+        pub fn treasury_income(from: BlockNumberFor<T>, to: BlockNumberFor<T>) -> Option<BalanceOf<T>> {
+            if from > to {
+                return Some(Zero::zero());
+            }
+            let (first, last) = (Self::revenue_period(from), Self::revenue_period(to));
+            if last - first >= MAX_INCOME_PERIODS {
+                return None;
+            }
+            Some((first..=last)
+                .fold(Zero::zero(), |total: BalanceOf<T>, period| total.saturating_add(TreasuryIncome::<T>::get(period))))
+        }
+
+        /// Weight of paying backers for up to `providers` provider fees
+        pub fn backer_payout_weight(providers: u32) -> Weight {
+            let transfers = u64::from(providers).saturating_mul(T::MaxBackers::get().into());
+            T::DbWeight::get().reads_writes(u64::from(providers), transfers.saturating_mul(2))
+        }
+
+        /// Slash a portion of every backing stake of `contributor`
+        ///
+        /// Slashed funds go to the treasury when one is configured, otherwise they are burned.
+        fn slash_backers(contributor: &T::AccountId, portion: Perbill) {
+            let treasury = TreasuryAccount::<T>::get();
+            Backers::<T>::mutate_exists(contributor, |maybe_backers| {
+                let Some(backers) = maybe_backers else { return };
+                for (backer, stake) in backers.iter_mut() {
+                    let to_slash = portion * *stake;
+                    if to_slash.is_zero() {
+                        continue;
+                    }
+                    let (imbalance, remaining) = T::Currency::slash_reserved(backer, to_slash);
+                    let slashed = to_slash.saturating_sub(remaining);
+                    if let Some(ref treasury) = treasury {
+                        T::Currency::resolve_creating(treasury, imbalance);
+                    }
+                    *stake = stake.saturating_sub(slashed);
+                    Self::deposit_event(Event::BackingSlashed {
+                        backer: backer.clone(),
+                        contributor: contributor.clone(),
+                        amount: slashed,
+                    });
+                }
+                backers.retain(|(_, stake)| !stake.is_zero());
+                if backers.is_empty() {
+                    *maybe_backers = None;
+                }
+            });
+        }
+
+        /// Post a claim open to challenge for `window` blocks, reserving its stake and
+        /// scheduling its finalization
+        fn do_post_claim(
+            who: T::AccountId,
+            claim_ual: Ual,
+            evidence_uals: Vec<Vec<u8>>,
+            stake: BalanceOf<T>,
+            window: BlockNumberFor<T>,
+        ) -> Result<u64, DispatchError> {
+            // Require minimum stake
+            ensure!(stake >= T::MinimumStake::get(), Error::<T>::BelowMinimumStake);
+
+            let evidence_uals = Self::bound_evidence(evidence_uals)?;
+
+            // Reserve stake
+
+            T::Currency::reserve(&who, stake)
+                .map_err(|_| Error::<T>::InsufficientBalance)?;
+
+            let claim_id = Self::get_next_claim_id();
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            let expiry = current_block.saturating_add(window);
 
-            // Ensure amount meets minimum
-            ensure!(amount >= T::MinimumStake::get(), Error::<T>::BelowMinimumStake);
+            // Store claim
+            Self::put_claim(
+                claim_id,
+                Claim {
+                    id: claim_id,
+                    submitter: who.clone(),
+                    claim_ual,
+                    evidence_uals,
+                    stake,
+                    status: ClaimStatus::Pending,
+                    created_at: current_block,
+                    challenge_deadline: expiry,
+                    challenger: None,
+                    resolution: None,
+                },
+            );
+            Self::schedule_sweep(expiry.saturating_add(One::one()), claim_id);
 
-            // Reserve the tokens
-            T::Currency::reserve(&who, amount)
-                .map_err(|_| Error::<T>::InsufficientBalance)?;
+            // Store submitter's claim IDs
+            SubmitterClaims::<T>::try_mutate(&who, |claims| claims.try_push(claim_id))
+                .map_err(|_| Error::<T>::TooManyOpenClaims)?;
 
-            // Update staked amount
-            StakedAmount::<T>::mutate(&who, |staked| {
-                *staked = staked.saturating_add(amount);
+            Self::deposit_event(Event::ClaimPosted {
+                claim_id,
+                submitter: who,
+                stake,
             });
 
-            Self::deposit_event(Event::TokensStaked { who, amount });
-
-            Ok(())
+            Ok(claim_id)
         }
 
-        /// Unstake tokens
-        #[pallet::call_index(1)]
-        #[pallet::weight(10_000)]
-        pub fn unstake_tokens(
-            origin: OriginFor<T>,
-            amount: BalanceOf<T>,
-        ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
+        /// Claim UAL of a fraud report: `dotrep:fraud/contribution/{id}`
+        pub fn fraud_report_ual(contribution_id: ContributionId) -> Ual {
+            let mut ual = b"dotrep:fraud/contribution/".to_vec();
+            let mut digits = Vec::new();
+            let mut id = contribution_id;
+            loop {
+                digits.push(b'0' + (id % 10) as u8);
+                id /= 10;
+                if id == 0 {
+                    break;
+                }
+            }
+            ual.extend(digits.iter().rev());
+            // At most 46 bytes, well within `MAX_UAL_LEN`
+            Ual::truncate_from(ual)
+        }
 
-            let staked = StakedAmount::<T>::get(&who);
-            ensure!(staked >= amount, Error::<T>::InsufficientBalance);
+        /// Settle the fraud report carried by a resolved claim, if any
+        ///
+        /// An accepted report overturns the contribution and slashes the contributor's
+        /// credibility stake, rewarding the reporter. If the contribution was overturned
+        /// in the meantime the contributor was already slashed for it.
+        fn settle_fraud_report(claim_id: u64, reporter: &T::AccountId, resolution: &ClaimResolution) {
+            let Some(report) = FraudReports::<T>::take(claim_id) else { return };
+            ReportedContributions::<T>::remove(report.contribution_id);
+            OpenFraudReports::<T>::mutate_exists(&report.contributor, |open| {
+                *open = open.and_then(|open| open.checked_sub(1)).filter(|open| *open > 0);
+            });
 
-            // Unreserve the tokens
-            T::Currency::unreserve(&who, amount);
+            if *resolution != ClaimResolution::Accepted
+                || T::Contributions::overturn_fraudulent(report.contribution_id).is_err()
+            {
+                return;
+            }
 
-            // Update staked amount
-            StakedAmount::<T>::mutate(&who, |staked| {
-                *staked = staked.saturating_sub(amount);
+            let (slashed, reward) =
+                Self::slash_stake_rewarding(&report.contributor, T::OffenceSlash::get(), Some(reporter));
+            Self::slash_backers(&report.contributor, T::OffenceSlash::get());
+            if !slashed.is_zero() {
+                Self::deposit_event(Event::StakeSlashed {
+                    who: report.contributor.clone(),
+                    amount: slashed,
+                    offence: ReputationOffence::OverturnedVerification,
+                });
+            }
+            Self::deposit_event(Event::FraudReportUpheld {
+                claim_id,
+                contribution_id: report.contribution_id,
+                contributor: report.contributor,
+                reward,
             });
+        }
 
-            Self::deposit_event(Event::TokensUnstaked { who, amount });
-
-            Ok(())
+        /// Report a resolved claim's verdict, if it has one, to `OnClaimResolved` together
+        /// with the contribution the claim is anchored to, and drop that link
+        fn report_verdict(claim_id: u64, submitter: &T::AccountId, verdict: Option<ClaimVerdict>) {
+            let contribution_id = ContributionClaims::<T>::take(claim_id);
+            let Some(verdict) = verdict else { return };
+            match contribution_id {
+                Some(contribution_id) =>
+                    T::OnClaimResolved::on_contribution_claim_resolved(submitter, contribution_id, verdict),
+                None => T::OnClaimResolved::on_claim_resolved(submitter, verdict),
+            }
         }
 
-        /// Pay for premium reputation query access (x402 micropayment)
-        #[pallet::call_index(2)]
-        #[pallet::weight(10_000)]
-        pub fn pay_for_query(
-            origin: OriginFor<T>,
-            ual: Vec<u8>,
-            access_duration: BlockNumberFor<T>,
+        /// Enact a claim resolution and distribute the parties' stakes
+        fn enact_resolution(
+            claim_id: u64,
+            mut claim: Claim<T>,
+            resolution: ClaimResolution,
         ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
+            claim.status = ClaimStatus::Resolved;
+            claim.resolution = Some(resolution.clone());
+            Self::close_submitter_claim(&claim.submitter, claim_id);
 
-            // Get query price (custom or base)
-            let price = CustomQueryPrice::<T>::get(&ual)
-                .unwrap_or_else(|| T::BaseQueryPrice::get());
+            let challenge = ClaimChallenges::<T>::get(claim_id);
 
-            // Get treasury account
-            let treasury = TreasuryAccount::<T>::get()
-                .ok_or(Error::<T>::TreasuryNotSet)?;
+            // Distribute stakes based on resolution
+            let verdict = match resolution {
+                ClaimResolution::Accepted => {
+                    // Return stake to submitter, forfeit challenger's stake
+                    T::Currency::unreserve(&claim.submitter, claim.stake);
+                    if let Some(ref challenge) = challenge {
+                        Self::forfeit_stake(
+                            claim_id,
+                            &challenge.challenger,
+                            challenge.stake,
+                            Some(&claim.submitter),
+                        );
+                    }
+                    // Only a successfully defended claim earns reputation
+                    challenge.as_ref().map(|_| ClaimVerdict::Upheld)
+                }
+                ClaimResolution::Rejected => {
+                    // Forfeit the policy's share of the submitter's stake, return stake to challenger
+                    let forfeited = Self::record_claim_offence(&claim.submitter) * claim.stake;
+                    Self::forfeit_stake(
+                        claim_id,
+                        &claim.submitter,
+                        forfeited,
+                        challenge.as_ref().map(|challenge| &challenge.challenger),
+                    );
+                    T::Currency::unreserve(&claim.submitter, claim.stake.saturating_sub(forfeited));
+                    if let Some(ref challenge) = challenge {
+                        T::Currency::unreserve(&challenge.challenger, challenge.stake);
+                    }
+                    // Rejected claims also count against the submitter's credibility stake
+                    Self::on_offence(&claim.submitter, ReputationOffence::RejectedClaim);
+                    Some(ClaimVerdict::Rejected)
+                }
+                ClaimResolution::Uncertain => {
+                    // Return stakes to both parties
+                    T::Currency::unreserve(&claim.submitter, claim.stake);
+                    if let Some(ref challenge) = challenge {
+                        T::Currency::unreserve(&challenge.challenger, challenge.stake);
+                    }
+                    None
+                }
+            };
+            Self::report_verdict(claim_id, &claim.submitter, verdict);
+            Self::settle_fraud_report(claim_id, &claim.submitter, &resolution);
 
-            // Transfer payment to treasury
-            T::Currency::transfer(
-                &who,
-                &treasury,
-                price,
-                ExistenceRequirement::KeepAlive,
-            )?;
+            Self::put_claim(claim_id, claim);
 
-            // Grant query access
-            let current_block = <frame_system::Pallet<T>>::block_number();
-            let expiry = current_block.saturating_add(access_duration);
+            Self::deposit_event(Event::ClaimResolved {
+                claim_id,
+                resolution,
+            });
 
-            QueryAccess::<T>::insert(&who, &ual, expiry);
+            Ok(())
+        }
 
-            Self::deposit_event(Event::QueryPaymentMade { 
-                payer: who.clone(), 
-                ual: ual.clone(), 
-                amount: price 
-            });
+        /// Count a rejected claim against `who` and return the share of stake to forfeit
+        ///
+        /// Offences older than a `decay_period` are forgiven first, so long-standing
+        /// accounts get a grace period after a single mistake.
+        fn record_claim_offence(who: &T::AccountId) -> Perbill {
+            let now = <frame_system::Pallet<T>>::block_number();
+            let policy = Slashing::<T>::get();
 
-            Self::deposit_event(Event::QueryAccessGranted { 
-                querier: who, 
-                ual, 
-                expiry 
+            let previous = OffenceCounts::<T>::get(who).map_or(0, |record| match policy {
+                Some(ref policy) => {
+                    let periods: u32 = (now.saturating_sub(record.last_offence) / policy.decay_period)
+                        .saturated_into();
+                    record.count.saturating_sub(periods)
+                }
+                None => record.count,
             });
 
-            Ok(())
-        }
+            let severity = match policy {
+                Some(policy) => policy
+                    .first_offence
+                    .saturating_add(Perbill::from_parts(
+                        policy.escalation.deconstruct().saturating_mul(previous),
+                    ))
+                    .min(policy.max_severity),
+                None => Perbill::one(),
+            };
 
-        /// Open a payment channel for efficient micropayments
-        #[pallet::call_index(3)]
-        #[pallet::weight(10_000)]
-        pub fn open_payment_channel(
-            origin: OriginFor<T>,
-            payee: T::AccountId,
-            deposit: BalanceOf<T>,
-            duration: BlockNumberFor<T>,
-        ) -> DispatchResult {
-            let payer = ensure_signed(origin)?;
+            let offences = previous.saturating_add(1);
+            OffenceCounts::<T>::insert(who, OffenceRecord { count: offences, last_offence: now });
 
-            // Ensure channel doesn't already exist
-            ensure!(
-                !PaymentChannels::<T>::contains_key(&payer, &payee),
-                Error::<T>::ChannelAlreadyExists
-            );
+            Self::deposit_event(Event::ClaimOffenceRecorded { who: who.clone(), offences, severity });
 
-            // Reserve deposit
-            T::Currency::reserve(&payer, deposit)
-                .map_err(|_| Error::<T>::InsufficientBalance)?;
+            severity
+        }
 
-            // Create channel
-            let current_block = <frame_system::Pallet<T>>::block_number();
-            let expiry = current_block.saturating_add(duration);
+        /// Slash the losing party's reserved stake
+        ///
+        /// `WinnerShare` of the slashed imbalance goes to the winning party (if any) and
+        /// the rest is handed to `T::Slash`. Nothing is taken from free balance.
+        fn forfeit_stake(
+            claim_id: u64,
+            loser: &T::AccountId,
+            stake: BalanceOf<T>,
+            winner: Option<&T::AccountId>,
+        ) {
+            let (imbalance, _) = T::Currency::slash_reserved(loser, stake);
+            let amount = imbalance.peek();
 
-            PaymentChannels::<T>::insert(&payer, &payee, (deposit, expiry));
+            let rest = match winner {
+                Some(winner) => {
+                    let (reward, rest) = imbalance.split(T::WinnerShare::get() * amount);
+                    T::Currency::resolve_creating(winner, reward);
+                    rest
+                }
+                None => imbalance,
+            };
+            T::Slash::on_unbalanced(rest);
 
-            Self::deposit_event(Event::ChannelOpened { 
-                payer, 
-                payee, 
-                amount: deposit 
+            Self::deposit_event(Event::ClaimStakeForfeited {
+                claim_id,
+                who: loser.clone(),
+                amount,
             });
+        }
 
-            Ok(())
+        /// Jury size for an appeal round: 2n + 1 of the previous round, capped at `MaxJurySize`
+        pub fn jury_size(round: u32) -> u32 {
+            let base = T::JurySize::get().saturating_add(1);
+            base.saturating_mul(2u32.saturating_pow(round))
+                .saturating_sub(1)
+                .min(T::MaxJurySize::get())
         }
 
-        /// Close a payment channel
-        #[pallet::call_index(4)]
-        #[pallet::weight(10_000)]
-        pub fn close_payment_channel(
-            origin: OriginFor<T>,
-            payee: T::AccountId,
-        ) -> DispatchResult {
-            let payer = ensure_signed(origin)?;
+        /// Schedule a jury draw for the given round from the seed round in progress,
+        /// escalating the claim when the pool is too small to draw from
+        fn seat_jury(claim_id: u64, claim: &mut Claim<T>, round: u32) {
+            let pool_size = JurorPool::<T>::decode_len().unwrap_or(0) as u32;
+            if pool_size < Self::jury_size(round) {
+                claim.status = ClaimStatus::Escalated;
+                Self::deposit_event(Event::ClaimEscalated { claim_id });
+                return;
+            }
 
-            // Get channel
-            let (deposit, _) = PaymentChannels::<T>::get(&payer, &payee)
-                .ok_or(Error::<T>::ChannelNotFound)?;
+            let seed_round = Self::seed_round(<frame_system::Pallet<T>>::block_number());
+            PendingJuryDraws::<T>::insert(claim_id, PendingJuryDraw { round, seed_round });
+            Self::deposit_event(Event::JuryDrawScheduled {
+                claim_id,
+                round,
+                seed_round,
+                draw_at: Self::seed_revealed_at(seed_round),
+            });
+        }
 
-            // Unreserve remaining deposit
-            T::Currency::unreserve(&payer, deposit);
+        /// Weight of reading the reputation of every pool member when drawing a jury, on
+        /// top of the benchmarked weight
+        pub(crate) fn jury_draw_weight() -> Weight {
+            T::Reputation::reputation_of_weight().saturating_mul(T::MaxJurorPool::get() as u64)
+        }
 
-            // Remove channel
-            PaymentChannels::<T>::remove(&payer, &payee);
+        /// Select a jury for a claim from eligible pool members, excluding the parties
+        ///
+        /// Members are drawn with probability proportional to the square root of their
+        /// reputation, from the revealed `seed` mixed with `T::Randomness`.
+        fn select_jurors(
+            claim_id: u64,
+            round: u32,
+            seed: T::Hash,
+            exclude: &[T::AccountId],
+        ) -> Option<BoundedVec<T::AccountId, T::MaxJurySize>> {
+            let size = Self::jury_size(round) as usize;
+            let candidates: Vec<(T::AccountId, Score)> = JurorPool::<T>::get()
+                .into_iter()
+                .filter(|juror| !exclude.contains(juror))
+                .map(|juror| {
+                    let score = T::Reputation::reputation_of(&juror);
+                    (juror, score)
+                })
+                .filter(|(_, score)| *score >= T::MinJurorReputation::get())
+                .collect();
+
+            if size == 0 || candidates.len() < size {
+                return None;
+            }
 
-            Self::deposit_event(Event::ChannelClosed { payer, payee });
+            let (entropy, _) = T::Randomness::random(&(b"dr/jury", claim_id, round).encode());
+            let seed = T::Hashing::hash_of(&(seed, entropy));
+            let jurors = sortition::select::<T::Hashing, _>(&seed, candidates, size);
 
-            Ok(())
+            BoundedVec::try_from(jurors).ok()
         }
 
-        /// Set custom query price for a UAL (data provider only)
-        #[pallet::call_index(5)]
-        #[pallet::weight(10_000)]
-        pub fn set_custom_query_price(
-            origin: OriginFor<T>,
-            ual: Vec<u8>,
-            price: BalanceOf<T>,
-        ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
+        /// Release jurors from a claim, rewarding those who voted with the verdict
+        ///
+        /// Jurors who voted against the verdict or did not vote are slashed by
+        /// `JurorSlash`; the slashed funds are split among the correct jurors. Without a
+        /// verdict only abstaining jurors are slashed and the funds go to `T::Slash`.
+        fn settle_jury(claim_id: u64, jury: &Jury<T>, verdict: Option<&ClaimResolution>) {
+            let mut pot = NegativeImbalanceOf::<T>::zero();
+            let mut winners = Vec::new();
 
-            // TODO: Verify that the caller owns/controls this UAL
-            // This would require integration with the reputation pallet
+            for juror in jury.jurors.iter() {
+                JurorAssignments::<T>::mutate(juror, |count| *count = count.saturating_sub(1));
 
-            CustomQueryPrice::<T>::insert(&ual, price);
+                let vote = JuryVotes::<T>::get(claim_id, juror);
+                let with_verdict = verdict.is_some() && vote.as_ref() == verdict;
+                let penalized = vote.is_none() || (verdict.is_some() && !with_verdict);
 
-            Self::deposit_event(Event::CustomPriceSet { ual, price });
+                if with_verdict {
+                    winners.push(juror.clone());
+                } else if penalized {
+                    let stake = JurorStakes::<T>::get(juror).unwrap_or_else(Zero::zero);
+                    let to_slash = T::JurorSlash::get() * stake;
+                    if to_slash.is_zero() {
+                        continue;
+                    }
+                    let (imbalance, remaining) = T::Currency::slash_reserved(juror, to_slash);
+                    let slashed = to_slash.saturating_sub(remaining);
+                    JurorStakes::<T>::mutate(juror, |stake| {
+                        if let Some(stake) = stake {
+                            *stake = stake.saturating_sub(slashed);
+                        }
+                    });
+                    pot.subsume(imbalance);
+                    Self::deposit_event(Event::JurorSlashed { juror: juror.clone(), amount: slashed });
+                }
+            }
 
-            Ok(())
+            if !winners.is_empty() {
+                let share = pot.peek() / BalanceOf::<T>::from(winners.len() as u32);
+                for juror in winners {
+                    let (reward, rest) = pot.split(share);
+                    pot = rest;
+                    let amount = reward.peek();
+                    T::Currency::resolve_creating(&juror, reward);
+                    if !amount.is_zero() {
+                        Self::deposit_event(Event::JurorRewarded { juror, amount });
+                    }
+                }
+            }
+
+            // Any remainder is handled like other forfeited stakes
+            T::Slash::on_unbalanced(pot);
+
+            let _ = JuryVotes::<T>::clear_prefix(claim_id, u32::MAX, None);
         }
 
-    /// Set treasury account (governance only)
-    #[pallet::call_index(6)]
-    #[pallet::weight(10_000)]
-    pub fn set_treasury(
-        origin: OriginFor<T>,
-        treasury: T::AccountId,
-    ) -> DispatchResult {
-        ensure_root(origin)?;
+        /// Finalize an unchallenged claim or an unappealed jury verdict, escalating
+        /// unchallenged fraud reports
+        fn do_finalize_claim(
+            claim_id: u64,
+            mut claim: Claim<T>,
+            now: T::BlockNumber,
+        ) -> DispatchResult {
+            // Jury verdicts become final once the appeal window has passed
+            if claim.status == ClaimStatus::Decided {
+                let appeal_deadline = AppealDeadlines::<T>::get(claim_id)
+                    .ok_or(Error::<T>::ClaimNotResolvable)?;
+                ensure!(now > appeal_deadline, Error::<T>::AppealWindowOpen);
+                let resolution = claim.resolution.clone()
+                    .ok_or(Error::<T>::ClaimNotResolvable)?;
 
-        TreasuryAccount::<T>::put(treasury);
+                AppealDeadlines::<T>::remove(claim_id);
+                let submitter = claim.submitter.clone();
+                Self::enact_resolution(claim_id, claim, resolution.clone())?;
+                Self::deposit_event(Event::ClaimFinalized { claim_id, submitter, resolution });
+                return Ok(());
+            }
 
-        Ok(())
-    }
+            ensure!(
+                claim.status == ClaimStatus::Pending,
+                Error::<T>::ClaimNotResolvable
+            );
 
-    /// Post a verifiable claim anchored to Knowledge Assets (Claim Verification)
-    /// Uses optimistic posting with challenge window
-    #[pallet::call_index(7)]
-    #[pallet::weight(20_000)]
-    pub fn post_claim(
-        origin: OriginFor<T>,
-        claim_ual: Vec<u8>,
-        evidence_uals: Vec<Vec<u8>>,
-        stake: BalanceOf<T>,
-    ) -> DispatchResult {
-        let who = ensure_signed(origin)?;
-
-        // Require minimum stake
-        ensure!(stake >= T::MinimumStake::get(), Error::<T>::BelowMinimumStake);
-
-        // Reserve stake
-        T::Currency::reserve(&who, stake)
-            .map_err(|_| Error::<T>::InsufficientBalance)?;
+            ensure!(
+                now > claim.challenge_deadline,
+                Error::<T>::ChallengeWindowOpen
+            );
 
-        let claim_id = Self::get_next_claim_id();
-        let current_block = <frame_system::Pallet<T>>::block_number();
-        let challenge_window = BlockNumberFor::<T>::from(1000u32); // 1000 blocks
-        let expiry = current_block.saturating_add(challenge_window);
-
-        // Store claim
-        Claims::<T>::insert(
-            claim_id,
-            Claim {
-                id: claim_id,
-                submitter: who.clone(),
-                claim_ual,
-                evidence_uals: evidence_uals.clone(),
-                stake,
-                status: ClaimStatus::Pending,
-                created_at: current_block,
-                challenge_deadline: expiry,
-                challenger: None,
-                resolution: None,
-            },
-        );
-
-        // Store submitter's claim IDs
-        SubmitterClaims::<T>::mutate(&who, |claims| {
-            claims.push(claim_id);
-        });
-
-        Self::deposit_event(Event::ClaimPosted {
-            claim_id,
-            submitter: who,
-            stake,
-        });
-
-        Ok(())
-    }
+            // A verified contribution is only overturned by a vote, even when undefended
+            if FraudReports::<T>::contains_key(claim_id) {
+                claim.status = ClaimStatus::Escalated;
+                Self::deposit_event(Event::ClaimEscalated { claim_id });
+                Self::put_claim(claim_id, claim);
+                return Ok(());
+            }
 
-    /// Challenge a claim with counter-evidence
-    #[pallet::call_index(8)]
-    #[pallet::weight(20_000)]
-    pub fn challenge_claim(
-        origin: OriginFor<T>,
-        claim_id: u64,
-        counter_evidence_uals: Vec<Vec<u8>>,
-        stake: BalanceOf<T>,
-    ) -> DispatchResult {
-        let challenger = ensure_signed(origin)?;
-
-        let mut claim = Claims::<T>::get(claim_id)
-            .ok_or(Error::<T>::ClaimNotFound)?;
-
-        // Check challenge window hasn't expired
-        let current_block = <frame_system::Pallet<T>>::block_number();
-        ensure!(
-            current_block <= claim.challenge_deadline,
-            Error::<T>::ChallengeWindowExpired
-        );
-
-        // Check claim is still pending
-        ensure!(
-            claim.status == ClaimStatus::Pending,
-            Error::<T>::ClaimNotChallengeable
-        );
-
-        // Cannot challenge own claim
-        ensure!(challenger != claim.submitter, Error::<T>::CannotChallengeOwnClaim);
-
-        // Require stake (at least matching original stake)
-        ensure!(stake >= claim.stake, Error::<T>::InsufficientStake);
-
-        // Reserve challenger's stake
-        T::Currency::reserve(&challenger, stake)
-            .map_err(|_| Error::<T>::InsufficientBalance)?;
+            T::Currency::unreserve(&claim.submitter, claim.stake);
+            Self::close_submitter_claim(&claim.submitter, claim_id);
 
-        // Update claim
-        claim.status = ClaimStatus::Challenged;
-        claim.challenger = Some(challenger.clone());
-        
-        // Store counter-evidence
-        ClaimChallenges::<T>::insert(
-            claim_id,
-            Challenge {
+            claim.status = ClaimStatus::Resolved;
+            claim.resolution = Some(ClaimResolution::Accepted);
+            let submitter = claim.submitter.clone();
+            Self::put_claim(claim_id, claim);
+            Self::report_verdict(claim_id, &submitter, None);
+            Self::settle_fraud_report(claim_id, &submitter, &ClaimResolution::Accepted);
+
+            Self::deposit_event(Event::ClaimResolved {
                 claim_id,
-                challenger: challenger.clone(),
-                counter_evidence_uals,
-                stake,
-                challenged_at: current_block,
-            },
-        );
+                resolution: ClaimResolution::Accepted,
+            });
+            Self::deposit_event(Event::ClaimFinalized {
+                claim_id,
+                submitter,
+                resolution: ClaimResolution::Accepted,
+            });
 
-        Claims::<T>::insert(claim_id, claim);
+            Ok(())
+        }
 
-        Self::deposit_event(Event::ClaimChallenged {
-            claim_id,
-            challenger,
-            stake,
-        });
+        /// Store a claim, keeping the status index in sync
+        fn put_claim(claim_id: u64, claim: Claim<T>) {
+            if let Some(previous) = Claims::<T>::get(claim_id) {
+                if previous.status != claim.status {
+                    ClaimsByStatus::<T>::remove(&previous.status, claim_id);
+                }
+            }
+            ClaimsByStatus::<T>::insert(&claim.status, claim_id, ());
+            Claims::<T>::insert(claim_id, claim);
+        }
 
-        Ok(())
-    }
+        /// Claim ids currently in the given status
+        pub fn claims_with_status(status: ClaimStatus) -> Vec<u64> {
+            ClaimsByStatus::<T>::iter_key_prefix(status).collect()
+        }
 
-    /// Resolve a challenged claim (oracle/governance)
-    #[pallet::call_index(9)]
-    #[pallet::weight(30_000)]
-    pub fn resolve_claim(
-        origin: OriginFor<T>,
-        claim_id: u64,
-        resolution: ClaimResolution,
-    ) -> DispatchResult {
-        // Only root or oracle account can resolve
-        ensure_root(origin)?;
-
-        let mut claim = Claims::<T>::get(claim_id)
-            .ok_or(Error::<T>::ClaimNotFound)?;
-
-        ensure!(
-            claim.status == ClaimStatus::Challenged,
-            Error::<T>::ClaimNotResolvable
-        );
-
-        claim.status = ClaimStatus::Resolved;
-        claim.resolution = Some(resolution.clone());
-
-        // Distribute stakes based on resolution
-        match resolution {
-            ClaimResolution::Accepted => {
-                // Return stake to submitter, slash challenger
-                T::Currency::unreserve(&claim.submitter, claim.stake);
-                if let Some(ref challenger) = claim.challenger {
-                    let challenge = ClaimChallenges::<T>::get(claim_id).unwrap();
-                    T::Currency::slash_reserved(challenger, challenge.stake);
-                    // Transfer slashed amount to treasury
-                    if let Some(treasury) = TreasuryAccount::<T>::get() {
-                        T::Currency::transfer(
-                            challenger,
-                            &treasury,
-                            challenge.stake,
-                            ExistenceRequirement::KeepAlive,
-                        )?;
-                    }
+        /// Schedule a claim for the automatic sweep at the first block from `at` with room
+        ///
+        /// Claims that cannot be scheduled can still be finalized via `finalize_claim`.
+        fn schedule_sweep(at: T::BlockNumber, claim_id: u64) {
+            let mut when = at;
+            for _ in 0..MAX_AGENDA_PROBES {
+                if ClaimAgenda::<T>::try_mutate(when, |agenda| agenda.try_push(claim_id)).is_ok() {
+                    return;
                 }
+                when = when.saturating_add(One::one());
             }
-            ClaimResolution::Rejected => {
-                // Slash submitter, return stake to challenger
-                T::Currency::slash_reserved(&claim.submitter, claim.stake);
-                if let Some(ref treasury) = TreasuryAccount::<T>::get() {
-                    T::Currency::transfer(
-                        &claim.submitter,
-                        treasury,
-                        claim.stake,
-                        ExistenceRequirement::KeepAlive,
-                    )?;
+        }
+
+        /// Advance the ongoing multi-block migration within `limit`
+        pub fn step_migration(limit: Weight) -> Weight {
+            let Some(cursor) = MigrationInProgress::<T>::get() else {
+                return T::DbWeight::get().reads(1);
+            };
+
+            let (next, used) = crate::migrations::MultiBlockMigration::<T>::step(cursor, limit);
+            match next {
+                Some(cursor) => {
+                    MigrationInProgress::<T>::put(cursor);
+                    Self::deposit_event(Event::MigrationStepped);
+                },
+                None => {
+                    MigrationInProgress::<T>::kill();
+                    Self::deposit_event(Event::MigrationCompleted);
+                },
+            }
+
+            used.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+        }
+
+        /// Reject calls touching claims while they are being migrated
+        fn ensure_not_migrating() -> DispatchResult {
+            ensure!(!MigrationInProgress::<T>::exists(), Error::<T>::MigrationInProgress);
+            Ok(())
+        }
+
+        /// Finalize claims on the agenda for `now`
+        ///
+        /// Claims beyond the `ClaimSweep` hook quota move to the next block's agenda.
+        pub(crate) fn sweep_claims(now: T::BlockNumber) -> Weight {
+            let mut budget = T::HookQuotas::hook_budget(HookTask::ClaimSweep, T::BlockWeights::get().max_block);
+            let base = T::WeightInfo::sweep_claims(0);
+            let per_claim = T::WeightInfo::sweep_claims(1).saturating_sub(base);
+            // The agenda is taken even if the quota cannot cover it, so its claims carry over
+            let covered = budget.try_consume(base);
+
+            let due = ClaimAgenda::<T>::take(now);
+            let next = now.saturating_add(One::one());
+            let (mut swept, mut deferred) = (0u32, 0u64);
+            for claim_id in due {
+                if !covered || !budget.try_consume(per_claim) {
+                    Self::schedule_sweep(next, claim_id);
+                    deferred += 1;
+                    continue;
                 }
-                if let Some(ref challenger) = claim.challenger {
-                    let challenge = ClaimChallenges::<T>::get(claim_id).unwrap();
-                    T::Currency::unreserve(challenger, challenge.stake);
+                swept += 1;
+
+                let Some(claim) = Claims::<T>::get(claim_id) else {
+                    // Still in the old format while a migration runs; retry next block
+                    if Claims::<T>::contains_key(claim_id) {
+                        Self::schedule_sweep(next, claim_id);
+                        deferred += 1;
+                    }
+                    continue;
+                };
+                if !matches!(claim.status, ClaimStatus::Pending | ClaimStatus::Decided) {
+                    continue;
                 }
+
+                // Each finalization is applied atomically; failures leave the claim for `finalize_claim`
+                let _ = with_storage_layer(|| Self::do_finalize_claim(claim_id, claim, now));
             }
-            ClaimResolution::Uncertain => {
-                // Return stakes to both parties
-                T::Currency::unreserve(&claim.submitter, claim.stake);
-                if let Some(ref challenger) = claim.challenger {
-                    let challenge = ClaimChallenges::<T>::get(claim_id).unwrap();
-                    T::Currency::unreserve(challenger, challenge.stake);
-                }
+
+            T::WeightInfo::sweep_claims(swept).saturating_add(T::DbWeight::get().reads_writes(deferred, deferred))
+        }
+
+        /// Current query price for a UAL (custom or base, adjusted for demand)
+        pub fn query_price(ual: &Ual) -> BalanceOf<T> {
+            let list_price = CustomQueryPrice::<T>::get(ual)
+                .unwrap_or_else(|| T::BaseQueryPrice::get());
+
+            match Pricing::<T>::get() {
+                Some(config) => DynamicQueryPrice::<T>::get(ual)
+                    .unwrap_or(list_price)
+                    .clamp(config.floor, config.ceiling),
+                None => list_price,
             }
         }
 
-        Claims::<T>::insert(claim_id, claim);
+        /// Move a price one step towards demand, clamped to the configured bounds
+        fn adjust_price(
+            price: BalanceOf<T>,
+            queries: u32,
+            config: &PricingConfig<BalanceOf<T>, T::BlockNumber>,
+        ) -> BalanceOf<T> {
+            let step = config.adjustment * price;
+            let adjusted = if queries > config.target_volume {
+                price.saturating_add(step)
+            } else if queries < config.target_volume {
+                price.saturating_sub(step)
+            } else {
+                price
+            };
+            adjusted.clamp(config.floor, config.ceiling)
+        }
+
+        /// Count a query against the UAL's pricing window, repricing when a window ends
+        fn note_query(ual: &Ual) {
+            let Some(config) = Pricing::<T>::get() else { return };
+            let now = <frame_system::Pallet<T>>::block_number();
 
-        Self::deposit_event(Event::ClaimResolved {
-            claim_id,
-            resolution,
-        });
+            let mut volume = QueryVolume::<T>::get(ual).unwrap_or(QueryVolumeWindow {
+                window_start: now,
+                queries: 0,
+            });
 
-        Ok(())
-    }
-    }
+            if now >= volume.window_start.saturating_add(config.window) {
+                let price = Self::query_price(ual);
+                let mut adjusted = Self::adjust_price(price, volume.queries, &config);
 
-    impl<T: Config> Pallet<T> {
-        /// Check if an account has valid query access
-        pub fn has_query_access(who: &T::AccountId, ual: &Vec<u8>) -> bool {
-            if let Some(expiry) = QueryAccess::<T>::get(who, ual) {
-                let current_block = <frame_system::Pallet<T>>::block_number();
-                current_block <= expiry
-            } else {
-                false
+                // Windows that passed without any query count as zero demand
+                let elapsed = now.saturating_sub(volume.window_start) / config.window;
+                let idle_windows = elapsed.saturated_into::<u32>().saturating_sub(1).min(MAX_IDLE_PRICE_STEPS);
+                for _ in 0..idle_windows {
+                    adjusted = Self::adjust_price(adjusted, 0, &config);
+                }
+
+                if adjusted != price {
+                    DynamicQueryPrice::<T>::insert(ual, adjusted);
+                    Self::deposit_event(Event::QueryPriceAdjusted { ual: ual.clone(), price: adjusted });
+                }
+                volume = QueryVolumeWindow { window_start: now, queries: 0 };
             }
+
+            volume.queries = volume.queries.saturating_add(1);
+            QueryVolume::<T>::insert(ual, volume);
         }
 
-        /// Calculate reputation credibility boost from staking
-        pub fn credibility_boost(who: &T::AccountId) -> u32 {
-            let staked = StakedAmount::<T>::get(who);
-            let min_stake = T::MinimumStake::get();
+        /// Bound a UAL to `MAX_UAL_LEN`
+        fn bound_ual(ual: Vec<u8>) -> Result<Ual, Error<T>> {
+            ual.try_into().map_err(|_| Error::<T>::UalTooLong)
+        }
 
-            if staked >= min_stake {
-                // Calculate boost percentage (e.g., 1% per minimum stake unit)
-                let boost = (staked / min_stake).saturated_into::<u32>();
-                boost.min(50) // Cap at 50% boost
-            } else {
-                0
-            }
+        /// Bound an evidence list to `MaxEvidenceItems` UALs of at most `MAX_UAL_LEN`
+        fn bound_evidence(evidence: Vec<Vec<u8>>) -> Result<EvidenceOf<T>, Error<T>> {
+            let uals = evidence
+                .into_iter()
+                .map(Self::bound_ual)
+                .collect::<Result<Vec<_>, _>>()?;
+            uals.try_into().map_err(|_| Error::<T>::TooManyEvidenceItems)
+        }
+
+        /// Drop a resolved claim from its submitter's open claims
+        fn close_submitter_claim(submitter: &T::AccountId, claim_id: u64) {
+            SubmitterClaims::<T>::mutate(submitter, |claims| claims.retain(|id| *id != claim_id));
         }
 
         /// Get next claim ID
@@ -688,4 +4036,62 @@ pub mod pallet {
             })
         }
     }
+
+    impl<T: Config> CredibilityBoostProvider<T::AccountId> for Pallet<T> {
+        fn credibility_boost_percent(who: &T::AccountId) -> u32 {
+            Self::credibility_boost(who)
+        }
+    }
+
+    impl<T: Config> ContributionDisputes<T::AccountId> for Pallet<T> {
+        fn has_open_disputes(who: &T::AccountId) -> bool {
+            OpenFraudReports::<T>::get(who) > 0
+        }
+    }
+
+    impl<T: Config> OnReputationOffence<T::AccountId> for Pallet<T> {
+        fn on_offence(offender: &T::AccountId, offence: ReputationOffence) {
+            // Backers vouched that the history is genuine, not for the account's claims
+            if offence != ReputationOffence::RejectedClaim {
+                Self::slash_backers(offender, T::OffenceSlash::get());
+            }
+
+            let amount = Self::slash_stake(offender, T::OffenceSlash::get());
+            if !amount.is_zero() {
+                Self::deposit_event(Event::StakeSlashed {
+                    who: offender.clone(),
+                    amount,
+                    offence,
+                });
+            }
+
+            for (asset_id, amount) in Self::slash_asset_stakes(offender, T::OffenceSlash::get()) {
+                Self::deposit_event(Event::AssetStakeSlashed { who: offender.clone(), asset_id, amount, offence });
+            }
+        }
+    }
+}
+
+/// Interface for proving which account controls a UAL
+pub trait UalOwnership<AccountId> {
+    fn owns_ual(who: &AccountId, ual: &[u8]) -> bool;
+}
+
+impl<AccountId> UalOwnership<AccountId> for () {
+    fn owns_ual(_who: &AccountId, _ual: &[u8]) -> bool {
+        false
+    }
+}
+
+/// Benchmark setup hooks provided by the runtime
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId, AccountId, Balance> {
+    /// Return an existing asset after minting `amount` of it to `who`
+    fn funded_asset(who: &AccountId, amount: Balance) -> AssetId;
+    /// Return a UAL that `UalOwnership` attributes to `who`
+    fn owned_ual(who: &AccountId) -> Vec<u8>;
+    /// Give `who` enough reputation to join the juror pool
+    fn qualify_juror(who: &AccountId);
+    /// Return a verified contribution by `contributor`, published as a Knowledge Asset
+    fn verified_contribution(contributor: &AccountId) -> dotrep_primitives::ContributionId;
 }
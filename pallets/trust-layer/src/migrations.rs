@@ -0,0 +1,335 @@
+// Storage migrations for the Trust Layer pallet
+//
+// Each version step is a `SteppedMigration`. `MigrateToV1`..`MigrateToV3` apply a step
+// within the upgrade block; `MultiBlockMigration` spreads all pending steps over as
+// many blocks as needed, within the `HookTask::Migrations` quota, while claim calls
+// are gated.
+
+use super::*;
+use dotrep_primitives::{HookQuotaProvider, HookTask};
+use frame_support::{
+    pallet_prelude::*,
+    storage::StoragePrefixedMap,
+    traits::{Currency, OnRuntimeUpgrade, StorageVersion},
+};
+use pallet_reputation::migrations::{
+    retain_step, run_to_completion, step_phases, translate_step, visit_step, MigrationCursor, SteppedMigration,
+};
+use sp_runtime::traits::Saturating;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Start a multi-block migration if the on-chain storage is behind
+///
+/// The first step runs right away within the upgrade block; the pallet's
+/// `on_initialize` continues from there.
+pub struct MigrateMultiBlock<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateMultiBlock<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let weight = T::DbWeight::get().reads(2);
+        if Pallet::<T>::on_chain_storage_version() >= STORAGE_VERSION || MigrationInProgress::<T>::exists() {
+            return weight;
+        }
+
+        MigrationInProgress::<T>::put(MigrationCursor::default());
+        let budget = T::HookQuotas::hook_budget(HookTask::Migrations, T::BlockWeights::get().max_block);
+        let used = Pallet::<T>::step_migration(budget.remaining());
+        T::HookQuotas::note_hook_usage(HookTask::Migrations, used);
+        weight.saturating_add(used).saturating_add(T::DbWeight::get().reads_writes(1, 1))
+    }
+}
+
+/// All pending version steps, run one after another
+pub struct MultiBlockMigration<T>(PhantomData<T>);
+
+impl<T: Config> SteppedMigration for MultiBlockMigration<T> {
+    fn step(cursor: MigrationCursor, limit: Weight) -> (Option<MigrationCursor>, Weight) {
+        let on_chain = Pallet::<T>::on_chain_storage_version();
+        let (next, used) = if on_chain == 0 {
+            v1::BoundClaims::<T>::step(cursor, limit)
+        } else if on_chain == 1 {
+            v2::IndexClaims::<T>::step(cursor, limit)
+        } else if on_chain == 2 {
+            v3::BoundUalKeys::<T>::step(cursor, limit)
+        } else {
+            return (None, T::DbWeight::get().reads(1));
+        };
+        let used = used.saturating_add(T::DbWeight::get().reads(1));
+
+        match next {
+            Some(cursor) => (Some(cursor), used),
+            None => {
+                // Version steps are sequential, so the next one starts from scratch
+                let version = on_chain + 1;
+                version.put::<Pallet<T>>();
+                let remaining = (version < STORAGE_VERSION).then(MigrationCursor::default);
+                (remaining, used.saturating_add(T::DbWeight::get().writes(1)))
+            },
+        }
+    }
+}
+
+/// v1: bound claim UALs, evidence lists and per-submitter claim lists
+///
+/// Over-long UALs and evidence lists are truncated to the configured maximums.
+/// Submitter claim lists keep only unresolved claims, up to `MaxClaimsPerSubmitter`.
+pub mod v1 {
+    use super::*;
+
+    #[derive(Decode)]
+    struct OldClaim<AccountId, Balance, BlockNumber> {
+        id: u64,
+        submitter: AccountId,
+        claim_ual: Vec<u8>,
+        evidence_uals: Vec<Vec<u8>>,
+        stake: Balance,
+        status: ClaimStatus,
+        created_at: BlockNumber,
+        challenge_deadline: BlockNumber,
+        challenger: Option<AccountId>,
+        resolution: Option<ClaimResolution>,
+    }
+
+    #[derive(Decode)]
+    struct OldChallenge<AccountId, Balance, BlockNumber> {
+        claim_id: u64,
+        challenger: AccountId,
+        counter_evidence_uals: Vec<Vec<u8>>,
+        stake: Balance,
+        challenged_at: BlockNumber,
+    }
+
+    fn truncate_evidence<T: Config>(evidence: Vec<Vec<u8>>) -> EvidenceOf<T> {
+        BoundedVec::truncate_from(
+            evidence.into_iter().map(BoundedVec::truncate_from).collect::<Vec<_>>(),
+        )
+    }
+
+    /// Stepped v1 translation of claims, challenges and submitter claim lists
+    pub struct BoundClaims<T>(PhantomData<T>);
+
+    impl<T: Config> SteppedMigration for BoundClaims<T> {
+        fn step(cursor: MigrationCursor, limit: Weight) -> (Option<MigrationCursor>, Weight) {
+            step_phases(cursor, limit, &[claims::<T>, challenges::<T>, submitter_claims::<T>])
+        }
+    }
+
+    fn claims<T: Config>(cursor: MigrationCursor, limit: Weight) -> (Option<MigrationCursor>, Weight) {
+        translate_step::<OldClaim<T::AccountId, BalanceOf<T>, T::BlockNumber>, Claim<T>>(
+            &Claims::<T>::final_prefix(),
+            cursor,
+            limit,
+            T::DbWeight::get().reads_writes(1, 1),
+            |old| {
+                Some(Claim {
+                    id: old.id,
+                    submitter: old.submitter,
+                    claim_ual: BoundedVec::truncate_from(old.claim_ual),
+                    evidence_uals: truncate_evidence::<T>(old.evidence_uals),
+                    stake: old.stake,
+                    status: old.status,
+                    created_at: old.created_at,
+                    challenge_deadline: old.challenge_deadline,
+                    challenger: old.challenger,
+                    resolution: old.resolution,
+                })
+            },
+        )
+    }
+
+    fn challenges<T: Config>(cursor: MigrationCursor, limit: Weight) -> (Option<MigrationCursor>, Weight) {
+        translate_step::<OldChallenge<T::AccountId, BalanceOf<T>, T::BlockNumber>, Challenge<T>>(
+            &ClaimChallenges::<T>::final_prefix(),
+            cursor,
+            limit,
+            T::DbWeight::get().reads_writes(1, 1),
+            |old| {
+                Some(Challenge {
+                    claim_id: old.claim_id,
+                    challenger: old.challenger,
+                    counter_evidence_uals: truncate_evidence::<T>(old.counter_evidence_uals),
+                    stake: old.stake,
+                    challenged_at: old.challenged_at,
+                })
+            },
+        )
+    }
+
+    // Runs after `claims`, so the statuses it reads are already migrated
+    fn submitter_claims<T: Config>(cursor: MigrationCursor, limit: Weight) -> (Option<MigrationCursor>, Weight) {
+        let reads = 1u64.saturating_add(T::MaxClaimsPerSubmitter::get().into());
+        translate_step::<Vec<u64>, BoundedVec<u64, T::MaxClaimsPerSubmitter>>(
+            &SubmitterClaims::<T>::final_prefix(),
+            cursor,
+            limit,
+            T::DbWeight::get().reads_writes(reads, 1),
+            |old| {
+                let open: Vec<u64> = old
+                    .into_iter()
+                    .filter(|id| {
+                        Claims::<T>::get(id).map_or(false, |claim| claim.status != ClaimStatus::Resolved)
+                    })
+                    .collect();
+                (!open.is_empty()).then(|| BoundedVec::truncate_from(open))
+            },
+        )
+    }
+
+    /// Apply v1 within the upgrade block
+    pub struct MigrateToV1<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() != 0 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let weight = run_to_completion::<BoundClaims<T>>();
+            StorageVersion::new(1).put::<Pallet<T>>();
+
+            weight.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+        }
+    }
+}
+
+/// v2: build the claim status index and schedule open claims for automatic finalization
+pub mod v2 {
+    use super::*;
+    use sp_runtime::traits::One;
+
+    /// Stepped v2 indexing of claims by status and finalization deadline
+    pub struct IndexClaims<T>(PhantomData<T>);
+
+    impl<T: Config> SteppedMigration for IndexClaims<T> {
+        fn step(cursor: MigrationCursor, limit: Weight) -> (Option<MigrationCursor>, Weight) {
+            visit_step::<Claim<T>>(
+                &Claims::<T>::final_prefix(),
+                cursor,
+                limit,
+                T::DbWeight::get().reads_writes(2, 2),
+                |claim| {
+                    ClaimsByStatus::<T>::insert(&claim.status, claim.id, ());
+
+                    let due = match claim.status {
+                        ClaimStatus::Pending => Some(claim.challenge_deadline),
+                        ClaimStatus::Decided => AppealDeadlines::<T>::get(claim.id),
+                        _ => None,
+                    };
+                    if let Some(deadline) = due {
+                        let at = deadline.saturating_add(One::one());
+                        let _ = ClaimAgenda::<T>::try_mutate(at, |agenda| agenda.try_push(claim.id));
+                    }
+                },
+            )
+        }
+    }
+
+    /// Apply v2 within the upgrade block
+    pub struct MigrateToV2<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() != 1 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let weight = run_to_completion::<IndexClaims<T>>();
+            StorageVersion::new(2).put::<Pallet<T>>();
+
+            weight.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+        }
+    }
+}
+
+/// v3: key the query access, pricing and data provider maps by bounded `Ual`s
+///
+/// A `Ual` encodes like the unbounded UALs these maps were keyed by, so entries whose
+/// UAL fits `MAX_UAL_LEN` keep their storage keys and calls need not wait for the
+/// migration. Entries keyed by longer UALs can no longer be addressed and are removed.
+/// Keys longer than a migration cursor may be checked twice, which is harmless.
+pub mod v3 {
+    use super::*;
+    use dotrep_primitives::Ual;
+
+    /// Stepped v3 removal of entries keyed by over-long UALs
+    pub struct BoundUalKeys<T>(PhantomData<T>);
+
+    impl<T: Config> SteppedMigration for BoundUalKeys<T> {
+        fn step(cursor: MigrationCursor, limit: Weight) -> (Option<MigrationCursor>, Weight) {
+            step_phases(
+                cursor,
+                limit,
+                &[
+                    query_access::<T>,
+                    metered_access::<T>,
+                    custom_query_price::<T>,
+                    query_volume::<T>,
+                    dynamic_query_price::<T>,
+                    data_providers::<T>,
+                ],
+            )
+        }
+    }
+
+    // Whether a `Blake2_128Concat` hashed key holds a UAL of at most `MAX_UAL_LEN`
+    fn bounded_ual_key(key: &[u8]) -> bool {
+        key.get(16..).map_or(false, |mut encoded| Ual::decode(&mut encoded).is_ok())
+    }
+
+    // Keys of the (account, UAL) double maps lead with the hashed account
+    fn bounded_account_ual_key<T: Config>(key: &[u8]) -> bool {
+        let Some(mut rest) = key.get(16..) else { return false };
+        T::AccountId::decode(&mut rest).is_ok() && bounded_ual_key(rest)
+    }
+
+    fn prune<T: Config>(
+        prefix: &[u8],
+        cursor: MigrationCursor,
+        limit: Weight,
+        keep: fn(&[u8]) -> bool,
+    ) -> (Option<MigrationCursor>, Weight) {
+        retain_step(prefix, cursor, limit, T::DbWeight::get().reads_writes(1, 1), keep)
+    }
+
+    fn query_access<T: Config>(cursor: MigrationCursor, limit: Weight) -> (Option<MigrationCursor>, Weight) {
+        prune::<T>(&QueryAccess::<T>::final_prefix(), cursor, limit, bounded_account_ual_key::<T>)
+    }
+
+    fn metered_access<T: Config>(cursor: MigrationCursor, limit: Weight) -> (Option<MigrationCursor>, Weight) {
+        prune::<T>(&MeteredAccess::<T>::final_prefix(), cursor, limit, bounded_account_ual_key::<T>)
+    }
+
+    fn custom_query_price<T: Config>(cursor: MigrationCursor, limit: Weight) -> (Option<MigrationCursor>, Weight) {
+        prune::<T>(&CustomQueryPrice::<T>::final_prefix(), cursor, limit, bounded_ual_key)
+    }
+
+    fn query_volume<T: Config>(cursor: MigrationCursor, limit: Weight) -> (Option<MigrationCursor>, Weight) {
+        prune::<T>(&QueryVolume::<T>::final_prefix(), cursor, limit, bounded_ual_key)
+    }
+
+    fn dynamic_query_price<T: Config>(cursor: MigrationCursor, limit: Weight) -> (Option<MigrationCursor>, Weight) {
+        prune::<T>(&DynamicQueryPrice::<T>::final_prefix(), cursor, limit, bounded_ual_key)
+    }
+
+    fn data_providers<T: Config>(cursor: MigrationCursor, limit: Weight) -> (Option<MigrationCursor>, Weight) {
+        prune::<T>(&DataProviders::<T>::final_prefix(), cursor, limit, bounded_ual_key)
+    }
+
+    /// Apply v3 within the upgrade block
+    pub struct MigrateToV3<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() != 2 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let weight = run_to_completion::<BoundUalKeys<T>>();
+            StorageVersion::new(3).put::<Pallet<T>>();
+
+            weight.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+        }
+    }
+}
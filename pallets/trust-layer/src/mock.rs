@@ -0,0 +1,370 @@
+use crate as pallet_trust_layer;
+use crate::UalOwnership;
+
+use frame_support::{
+    parameter_types,
+    traits::{AsEnsureOriginWithArg, ConstU32, ConstU64, Currency, OnUnbalanced, Randomness, Time},
+    PalletId,
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use dotrep_primitives::{ClaimVerdict, ContributionId, Ual};
+use sp_std::cell::RefCell;
+use sp_core::H256;
+use sp_runtime::{
+    traits::{BlakeTwo256, Hash, IdentityLookup},
+    testing::Header,
+    BuildStorage, DispatchError, DispatchResult, Perbill,
+};
+
+// Set up mock types for simplicity
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime for testing
+frame_support::construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system,
+        Balances: pallet_balances,
+        Assets: pallet_assets,
+        TrustLayer: pallet_trust_layer,
+    }
+);
+
+// Constants for testing
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const ExistentialDeposit: u64 = 1;
+}
+
+// System pallet configuration
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<u64>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<u64>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+// Balances pallet configuration
+impl pallet_balances::Config for Test {
+    type MaxLocks = ();
+    type MaxReserves = ConstU32<10>;
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u64;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+}
+
+// Assets pallet configuration (TRAC/NEURO stand-ins)
+impl pallet_assets::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = u64;
+    type AssetId = u32;
+    type AssetIdParameter = u32;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<u64>>;
+    type ForceOrigin = EnsureRoot<u64>;
+    type AssetDeposit = ConstU64<1>;
+    type AssetAccountDeposit = ConstU64<1>;
+    type MetadataDepositBase = ConstU64<1>;
+    type MetadataDepositPerByte = ConstU64<1>;
+    type ApprovalDeposit = ConstU64<1>;
+    type StringLimit = ConstU32<50>;
+    type Freezer = ();
+    type Extra = ();
+    type WeightInfo = ();
+    type RemoveItemsLimit = ConstU32<1000>;
+    type CallbackHandle = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
+// Mock UAL ownership: an account controls every UAL whose last byte is its id, unless
+// the UAL was transferred
+pub struct TestUalOwnership;
+impl UalOwnership<u64> for TestUalOwnership {
+    fn owns_ual(who: &u64, ual: &[u8]) -> bool {
+        let transferred = TRANSFERRED_UALS.with(|uals| {
+            uals.borrow().iter().find(|(transferred, _)| transferred[..] == *ual).map(|(_, owner)| *owner)
+        });
+        match transferred {
+            Some(owner) => owner == *who,
+            None => ual.last() == Some(&(*who as u8)),
+        }
+    }
+}
+
+// Mock reputation: accounts from `FIRST_JUROR` upwards are highly reputable
+pub struct TestReputation;
+impl dotrep_primitives::ReputationProvider<u64> for TestReputation {
+    fn reputation_of(who: &u64) -> dotrep_primitives::Score {
+        if *who >= FIRST_JUROR { 500 } else { 0 }
+    }
+}
+
+thread_local! {
+    pub static CLAIM_VERDICTS: RefCell<Vec<(u64, ClaimVerdict)>> = RefCell::new(Vec::new());
+    pub static CONTRIBUTION_CLAIM_VERDICTS: RefCell<Vec<(ContributionId, ClaimVerdict)>> = RefCell::new(Vec::new());
+    pub static TRANSFERRED_UALS: RefCell<Vec<(Vec<u8>, u64)>> = RefCell::new(Vec::new());
+}
+
+// Records claim verdicts so tests can assert on reputation feedback
+pub struct RecordClaimVerdicts;
+impl dotrep_primitives::OnClaimResolved<u64> for RecordClaimVerdicts {
+    fn on_claim_resolved(submitter: &u64, verdict: ClaimVerdict) {
+        CLAIM_VERDICTS.with(|verdicts| verdicts.borrow_mut().push((*submitter, verdict)));
+    }
+
+    fn on_contribution_claim_resolved(submitter: &u64, contribution_id: ContributionId, verdict: ClaimVerdict) {
+        Self::on_claim_resolved(submitter, verdict);
+        CONTRIBUTION_CLAIM_VERDICTS.with(|verdicts| verdicts.borrow_mut().push((contribution_id, verdict)));
+    }
+}
+
+pub fn claim_verdicts() -> Vec<(u64, ClaimVerdict)> {
+    CLAIM_VERDICTS.with(|verdicts| verdicts.borrow().clone())
+}
+
+pub fn contribution_claim_verdicts() -> Vec<(ContributionId, ClaimVerdict)> {
+    CONTRIBUTION_CLAIM_VERDICTS.with(|verdicts| verdicts.borrow().clone())
+}
+
+thread_local! {
+    pub static VERIFIED_CONTRIBUTIONS: RefCell<Vec<(ContributionId, u64)>> = RefCell::new(Vec::new());
+}
+
+// Contributions registered by tests as verified; overturning removes them
+pub struct TestContributions;
+impl dotrep_primitives::ContributionRegistry<u64> for TestContributions {
+    fn verified_contributor(contribution_id: ContributionId) -> Option<u64> {
+        VERIFIED_CONTRIBUTIONS.with(|verified| {
+            verified.borrow().iter().find(|(id, _)| *id == contribution_id).map(|(_, who)| *who)
+        })
+    }
+
+    fn overturn_fraudulent(contribution_id: ContributionId) -> DispatchResult {
+        VERIFIED_CONTRIBUTIONS.with(|verified| {
+            let mut verified = verified.borrow_mut();
+            let len = verified.len();
+            verified.retain(|(id, _)| *id != contribution_id);
+            if verified.len() < len { Ok(()) } else { Err(DispatchError::Other("not verified")) }
+        })
+    }
+
+    fn contribution_ual(contribution_id: ContributionId) -> Option<Ual> {
+        Self::verified_contributor(contribution_id).map(|_| Ual::truncate_from(contribution_ual(contribution_id)))
+    }
+}
+
+// Knowledge Asset every verified test contribution is published as
+pub fn contribution_ual(contribution_id: ContributionId) -> Vec<u8> {
+    let mut ual = b"did:dkg:otp/2043/0xc0/".to_vec();
+    ual.extend(contribution_id.to_be_bytes());
+    ual
+}
+
+pub fn verify_contribution(contribution_id: ContributionId, contributor: u64) {
+    VERIFIED_CONTRIBUTIONS.with(|verified| verified.borrow_mut().push((contribution_id, contributor)));
+}
+
+// Deterministic randomness derived from the subject
+pub struct TestRandomness;
+impl Randomness<H256, u64> for TestRandomness {
+    fn random(subject: &[u8]) -> (H256, u64) {
+        (BlakeTwo256::hash(subject), 0)
+    }
+}
+
+// Six-second blocks starting at the Unix epoch
+pub struct TestTime;
+impl Time for TestTime {
+    type Moment = u64;
+
+    fn now() -> u64 {
+        System::block_number() * 6_000
+    }
+}
+
+// Forfeited stakes are paid into the treasury account
+pub struct SlashToTreasury;
+impl OnUnbalanced<pallet_balances::NegativeImbalance<Test>> for SlashToTreasury {
+    fn on_nonzero_unbalanced(amount: pallet_balances::NegativeImbalance<Test>) {
+        Balances::resolve_creating(&TREASURY, amount);
+    }
+}
+
+// Mock configuration for pallet_trust_layer
+parameter_types! {
+    pub const MinimumStake: u64 = 100;
+    pub const BaseQueryPrice: u64 = 10;
+    pub const ProviderShare: Perbill = Perbill::from_percent(70);
+    pub const TrustLayerPalletId: PalletId = PalletId(*b"dr/trust");
+    pub const OffenceSlash: Perbill = Perbill::from_percent(50);
+    pub const JurySize: u32 = 3;
+    pub const MaxJurySize: u32 = 7;
+    pub const MaxAppealRounds: u32 = 1;
+    pub const AppealPeriod: u64 = 5;
+    pub const MaxEvidenceItems: u32 = 4;
+    pub const MaxClaimsPerSubmitter: u32 = 2;
+    pub const MaxClaimsSweptPerBlock: u32 = 1;
+    pub const MaxQueryBatch: u32 = 5;
+    pub const BatchDiscount: Perbill = Perbill::from_percent(10);
+    pub const WinnerShare: Perbill = Perbill::from_percent(50);
+    pub const MeteredQueryFee: u64 = 5;
+    pub const MaxInvoiceItems: u32 = 3;
+    pub const BillingPeriod: u64 = 200;
+    pub const BillingStatementRetention: u32 = 2;
+    pub const MaxJurorPool: u32 = 10;
+    pub const MinJurorReputation: i32 = 100;
+    pub const JurorStake: u64 = 50;
+    pub const JuryVotingPeriod: u64 = 10;
+    pub const JurySeedPeriod: u64 = 10;
+    pub const JurorSlash: Perbill = Perbill::from_percent(20);
+    pub const BackerShare: Perbill = Perbill::from_percent(20);
+    pub const MaxBackers: u32 = 3;
+    pub const FraudReportWindow: u64 = 50;
+}
+
+impl pallet_trust_layer::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type MinimumStake = MinimumStake;
+    type BaseQueryPrice = BaseQueryPrice;
+    type UalOwnership = TestUalOwnership;
+    type ProviderShare = ProviderShare;
+    type AssetId = u32;
+    type Assets = Assets;
+    type PalletId = TrustLayerPalletId;
+    type OffenceSlash = OffenceSlash;
+    type Reputation = TestReputation;
+    type OnClaimResolved = RecordClaimVerdicts;
+    type Contributions = TestContributions;
+    type FraudReportWindow = FraudReportWindow;
+    type HookQuotas = ();
+    type Time = TestTime;
+    type Randomness = TestRandomness;
+    type JurySize = JurySize;
+    type MaxJurySize = MaxJurySize;
+    type MaxAppealRounds = MaxAppealRounds;
+    type AppealPeriod = AppealPeriod;
+    type MaxEvidenceItems = MaxEvidenceItems;
+    type MaxClaimsPerSubmitter = MaxClaimsPerSubmitter;
+    type MaxClaimsSweptPerBlock = MaxClaimsSweptPerBlock;
+    type MaxQueryBatch = MaxQueryBatch;
+    type BatchDiscount = BatchDiscount;
+    type MeteredQueryFee = MeteredQueryFee;
+    type MaxInvoiceItems = MaxInvoiceItems;
+    type BillingPeriod = BillingPeriod;
+    type BillingStatementRetention = BillingStatementRetention;
+    type Slash = SlashToTreasury;
+    type WinnerShare = WinnerShare;
+    type MaxJurorPool = MaxJurorPool;
+    type MinJurorReputation = MinJurorReputation;
+    type JurorStake = JurorStake;
+    type JuryVotingPeriod = JuryVotingPeriod;
+    type JurySeedPeriod = JurySeedPeriod;
+    type JurorSlash = JurorSlash;
+    type BackerShare = BackerShare;
+    type MaxBackers = MaxBackers;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = TestBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TestBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<u32, u64, u64> for TestBenchmarkHelper {
+    fn funded_asset(who: &u64, amount: u64) -> u32 {
+        // TRAC is created at genesis
+        <Assets as frame_support::traits::fungibles::Mutate<u64>>::mint_into(TRAC, who, amount)
+            .expect("TRAC exists");
+        TRAC
+    }
+
+    fn owned_ual(who: &u64) -> Vec<u8> {
+        let mut ual = who.to_le_bytes().to_vec();
+        ual.push(*who as u8);
+        ual
+    }
+
+    // TestReputation already qualifies every account from FIRST_JUROR upwards
+    fn qualify_juror(_who: &u64) {}
+
+    fn verified_contribution(contributor: &u64) -> ContributionId {
+        verify_contribution(*contributor, *contributor);
+        *contributor
+    }
+}
+
+// Test accounts
+pub const ALICE: u64 = 1;
+pub const BOB: u64 = 2;
+pub const CHARLIE: u64 = 3;
+pub const TREASURY: u64 = 99;
+pub const TRAC: u32 = 1;
+pub const FIRST_JUROR: u64 = 10;
+pub const JURORS: [u64; 9] = [10, 11, 12, 13, 14, 15, 16, 17, 18];
+
+// Genesis storage initialization for tests
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![
+            (ALICE, 1_000_000),
+            (BOB, 1_000_000),
+            (CHARLIE, 1_000_000),
+            (TREASURY, 1_000),
+        ]
+        .into_iter()
+        .chain(JURORS.iter().map(|juror| (*juror, 1_000)))
+        .collect(),
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    pallet_assets::GenesisConfig::<Test> {
+        assets: vec![(TRAC, TREASURY, true, 1)],
+        metadata: vec![],
+        accounts: vec![
+            (TRAC, ALICE, 1_000_000),
+            (TRAC, BOB, 1_000_000),
+            (TRAC, TREASURY, 1_000),
+        ],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
@@ -0,0 +1,26 @@
+//! Runtime APIs for data provider dashboards
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    /// Earnings from premium reputation queries, read from the revenue accrual storage
+    ///
+    /// Only native-currency payments are accrued; asset payments are in other units.
+    pub trait RevenueApi<AccountId, Balance, BlockNumber>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+    {
+        /// Query fees paid for the UAL
+        fn ual_revenue(ual: Vec<u8>) -> Balance;
+
+        /// Query fees earned by the data provider, net of its backers' share
+        fn provider_revenue(account: AccountId) -> Balance;
+
+        /// Treasury income in the accounting periods overlapping `from..=to`, or `None` if
+        /// they are more than `MAX_INCOME_PERIODS`
+        fn treasury_income(from: BlockNumber, to: BlockNumber) -> Option<Balance>;
+    }
+}
@@ -0,0 +1,1432 @@
+#[cfg(test)]
+mod tests {
+    use crate::mock::*;
+    use crate::pallet::*;
+    use frame_support::{assert_noop, assert_ok, traits::{Currency, NamedReservableCurrency, ReservableCurrency}};
+    use codec::Encode;
+    use frame_support::traits::{GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion};
+    use dotrep_primitives::{ClaimVerdict, OnReputationOffence, ReputationOffence, Topic, Ual, MAX_UAL_LEN};
+    use pallet_reputation::migrations::{MigrationCursor, ITEM_BASE_WEIGHT};
+    use sp_runtime::traits::{BlakeTwo256, Hash};
+
+    // UAL owned by `who` under the mock ownership rule
+    fn ual_of(who: u64) -> Vec<u8> {
+        let mut ual = b"did:dkg:otp/2043/0xabc/".to_vec();
+        ual.push(who as u8);
+        ual
+    }
+
+    // `ual_of(who)` as the pallet stores it
+    fn bounded_ual_of(who: u64) -> Ual {
+        Ual::truncate_from(ual_of(who))
+    }
+
+    #[test]
+    fn register_data_provider_requires_ual_ownership() {
+        new_test_ext().execute_with(|| {
+            assert_noop!(
+                TrustLayer::register_data_provider(RuntimeOrigin::signed(BOB), ual_of(ALICE)),
+                Error::<Test>::NotUalOwner
+            );
+
+            assert_ok!(TrustLayer::register_data_provider(
+                RuntimeOrigin::signed(ALICE),
+                ual_of(ALICE)
+            ));
+            assert_eq!(TrustLayer::data_provider(bounded_ual_of(ALICE)), Some(ALICE));
+
+            assert_noop!(
+                TrustLayer::register_data_provider(RuntimeOrigin::signed(ALICE), ual_of(ALICE)),
+                Error::<Test>::ProviderAlreadyRegistered
+            );
+        });
+    }
+
+    #[test]
+    fn new_ual_owner_replaces_stale_data_provider() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(TrustLayer::register_data_provider(RuntimeOrigin::signed(ALICE), ual_of(ALICE)));
+            assert_ok!(TrustLayer::set_custom_query_price(RuntimeOrigin::signed(ALICE), ual_of(ALICE), 5));
+            assert_noop!(
+                TrustLayer::register_data_provider(RuntimeOrigin::signed(BOB), ual_of(ALICE)),
+                Error::<Test>::ProviderAlreadyRegistered
+            );
+
+            TRANSFERRED_UALS.with(|uals| uals.borrow_mut().push((ual_of(ALICE), BOB)));
+            assert_ok!(TrustLayer::register_data_provider(RuntimeOrigin::signed(BOB), ual_of(ALICE)));
+            assert_eq!(TrustLayer::data_provider(bounded_ual_of(ALICE)), Some(BOB));
+            assert_eq!(TrustLayer::custom_query_price(bounded_ual_of(ALICE)), None);
+            System::assert_has_event(RuntimeEvent::TrustLayer(Event::DataProviderDeregistered {
+                provider: ALICE,
+                ual: bounded_ual_of(ALICE),
+            }));
+        });
+    }
+
+    #[test]
+    fn set_custom_query_price_only_by_provider() {
+        new_test_ext().execute_with(|| {
+            assert_noop!(
+                TrustLayer::set_custom_query_price(RuntimeOrigin::signed(ALICE), ual_of(ALICE), 50),
+                Error::<Test>::NotDataProvider
+            );
+
+            assert_ok!(TrustLayer::register_data_provider(
+                RuntimeOrigin::signed(ALICE),
+                ual_of(ALICE)
+            ));
+            assert_noop!(
+                TrustLayer::set_custom_query_price(RuntimeOrigin::signed(BOB), ual_of(ALICE), 50),
+                Error::<Test>::NotDataProvider
+            );
+            assert_ok!(TrustLayer::set_custom_query_price(
+                RuntimeOrigin::signed(ALICE),
+                ual_of(ALICE),
+                50
+            ));
+            assert_eq!(TrustLayer::custom_query_price(bounded_ual_of(ALICE)), Some(50));
+        });
+    }
+
+    #[test]
+    fn query_payment_is_split_with_provider() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(TrustLayer::set_treasury(RuntimeOrigin::root(), TREASURY));
+            assert_ok!(TrustLayer::register_data_provider(
+                RuntimeOrigin::signed(ALICE),
+                ual_of(ALICE)
+            ));
+            assert_ok!(TrustLayer::set_custom_query_price(
+                RuntimeOrigin::signed(ALICE),
+                ual_of(ALICE),
+                100
+            ));
+
+            let alice_before = Balances::free_balance(ALICE);
+            let treasury_before = Balances::free_balance(TREASURY);
+
+            assert_ok!(TrustLayer::pay_for_query(RuntimeOrigin::signed(BOB), ual_of(ALICE), 10));
+
+            // 70% to the provider, the rest to the treasury
+            assert_eq!(Balances::free_balance(ALICE), alice_before + 70);
+            assert_eq!(Balances::free_balance(TREASURY), treasury_before + 30);
+            assert!(TrustLayer::has_query_access(&BOB, &bounded_ual_of(ALICE)));
+        });
+    }
+
+    #[test]
+    fn unregistered_ual_payment_goes_to_treasury() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(TrustLayer::set_treasury(RuntimeOrigin::root(), TREASURY));
+            let treasury_before = Balances::free_balance(TREASURY);
+
+            assert_ok!(TrustLayer::pay_for_query(RuntimeOrigin::signed(BOB), ual_of(CHARLIE), 10));
+
+            assert_eq!(Balances::free_balance(TREASURY), treasury_before + BaseQueryPrice::get());
+        });
+    }
+
+    #[test]
+    fn deregister_clears_custom_price() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(TrustLayer::register_data_provider(
+                RuntimeOrigin::signed(ALICE),
+                ual_of(ALICE)
+            ));
+            assert_ok!(TrustLayer::set_custom_query_price(
+                RuntimeOrigin::signed(ALICE),
+                ual_of(ALICE),
+                50
+            ));
+
+            assert_ok!(TrustLayer::deregister_data_provider(
+                RuntimeOrigin::signed(ALICE),
+                ual_of(ALICE)
+            ));
+            assert_eq!(TrustLayer::data_provider(bounded_ual_of(ALICE)), None);
+            assert_eq!(TrustLayer::custom_query_price(bounded_ual_of(ALICE)), None);
+        });
+    }
+
+    #[test]
+    fn stake_asset_requires_configured_asset() {
+        new_test_ext().execute_with(|| {
+            assert_noop!(
+                TrustLayer::stake_asset(RuntimeOrigin::signed(ALICE), TRAC, 500),
+                Error::<Test>::AssetNotAccepted
+            );
+
+            assert_ok!(TrustLayer::set_asset_config(RuntimeOrigin::root(), TRAC, 200, 5));
+            assert_noop!(
+                TrustLayer::stake_asset(RuntimeOrigin::signed(ALICE), TRAC, 100),
+                Error::<Test>::BelowMinimumStake
+            );
+
+            assert_ok!(TrustLayer::stake_asset(RuntimeOrigin::signed(ALICE), TRAC, 500));
+            assert_eq!(TrustLayer::asset_staked_amount(ALICE, TRAC), 500);
+            assert_eq!(Assets::balance(TRAC, TrustLayer::escrow_account()), 500);
+
+            assert_ok!(TrustLayer::unstake_asset(RuntimeOrigin::signed(ALICE), TRAC, 500));
+            assert_eq!(TrustLayer::asset_staked_amount(ALICE, TRAC), 0);
+            assert_eq!(Assets::balance(TRAC, ALICE), 1_000_000);
+        });
+    }
+
+    #[test]
+    fn pay_for_query_with_asset_uses_asset_price() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(TrustLayer::set_treasury(RuntimeOrigin::root(), TREASURY));
+            assert_ok!(TrustLayer::set_asset_config(RuntimeOrigin::root(), TRAC, 200, 40));
+
+            assert_ok!(TrustLayer::pay_for_query_with_asset(
+                RuntimeOrigin::signed(BOB),
+                TRAC,
+                ual_of(CHARLIE),
+                10
+            ));
+
+            assert_eq!(Assets::balance(TRAC, BOB), 1_000_000 - 40);
+            assert_eq!(Assets::balance(TRAC, TREASURY), 1_000 + 40);
+            assert!(TrustLayer::has_query_access(&BOB, &bounded_ual_of(CHARLIE)));
+
+            assert_noop!(
+                TrustLayer::pay_for_query_with_asset(
+                    RuntimeOrigin::signed(BOB),
+                    TRAC,
+                    vec![0u8; MAX_UAL_LEN as usize + 1],
+                    10
+                ),
+                Error::<Test>::UalTooLong
+            );
+        });
+    }
+
+    #[test]
+    fn reputation_offence_slashes_stake() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(TrustLayer::set_treasury(RuntimeOrigin::root(), TREASURY));
+            assert_ok!(TrustLayer::stake_tokens(RuntimeOrigin::signed(ALICE), 1_000));
+            assert_ok!(TrustLayer::set_asset_config(RuntimeOrigin::root(), TRAC, 200, 5));
+            assert_ok!(TrustLayer::stake_asset(RuntimeOrigin::signed(ALICE), TRAC, 400));
+            let treasury_before = Balances::free_balance(TREASURY);
+            let treasury_assets_before = Assets::balance(TRAC, TREASURY);
+
+            <TrustLayer as OnReputationOffence<u64>>::on_offence(
+                &ALICE,
+                ReputationOffence::SybilPenalty,
+            );
+
+            // 50% of the stake is slashed into the treasury
+            assert_eq!(TrustLayer::staked_amount(ALICE), 500);
+            assert_eq!(Balances::reserved_balance(ALICE), 500);
+            assert_eq!(Balances::free_balance(TREASURY), treasury_before + 500);
+
+            // Asset stakes are slashed alike
+            assert_eq!(TrustLayer::asset_staked_amount(ALICE, TRAC), 200);
+            assert_eq!(Assets::balance(TRAC, TrustLayer::escrow_account()), 200);
+            assert_eq!(Assets::balance(TRAC, TREASURY), treasury_assets_before + 200);
+            System::assert_has_event(
+                Event::AssetStakeSlashed {
+                    who: ALICE,
+                    asset_id: TRAC,
+                    amount: 200,
+                    offence: ReputationOffence::SybilPenalty,
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn offence_without_stake_is_noop() {
+        new_test_ext().execute_with(|| {
+            <TrustLayer as OnReputationOffence<u64>>::on_offence(
+                &BOB,
+                ReputationOffence::OverturnedVerification,
+            );
+            assert_eq!(TrustLayer::staked_amount(BOB), 0);
+        });
+    }
+
+    #[test]
+    fn unchallenged_claim_finalizes_after_window() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(TrustLayer::post_claim(
+                RuntimeOrigin::signed(ALICE),
+                ual_of(ALICE),
+                vec![],
+                200
+            ));
+            let claim_id = ClaimIdCounter::<Test>::get();
+            assert_eq!(Balances::reserved_balance(ALICE), 200);
+
+            assert_noop!(
+                TrustLayer::finalize_claim(RuntimeOrigin::signed(BOB), claim_id),
+                Error::<Test>::ChallengeWindowOpen
+            );
+
+            let deadline = TrustLayer::claim(claim_id).unwrap().challenge_deadline;
+            System::set_block_number(deadline + 1);
+
+            assert_ok!(TrustLayer::finalize_claim(RuntimeOrigin::signed(BOB), claim_id));
+
+            let claim = TrustLayer::claim(claim_id).unwrap();
+            assert_eq!(claim.status, ClaimStatus::Resolved);
+            assert_eq!(claim.resolution, Some(ClaimResolution::Accepted));
+            assert_eq!(Balances::reserved_balance(ALICE), 0);
+
+            assert_noop!(
+                TrustLayer::finalize_claim(RuntimeOrigin::signed(BOB), claim_id),
+                Error::<Test>::ClaimNotResolvable
+            );
+        });
+    }
+
+    #[test]
+    fn claim_events_are_indexed_by_claim_and_account() {
+        new_test_ext().execute_with(|| {
+            type Hashing = <Test as frame_system::Config>::Hashing;
+
+            assert_ok!(TrustLayer::post_claim(RuntimeOrigin::signed(ALICE), ual_of(ALICE), vec![], 200));
+            let claim_id = ClaimIdCounter::<Test>::get();
+
+            let deadline = TrustLayer::claim(claim_id).unwrap().challenge_deadline;
+            System::set_block_number(deadline + 1);
+            assert_ok!(TrustLayer::finalize_claim(RuntimeOrigin::signed(BOB), claim_id));
+            System::assert_last_event(
+                Event::ClaimFinalized { claim_id, submitter: ALICE, resolution: ClaimResolution::Accepted }.into(),
+            );
+
+            // Posted, resolved and finalized, all under the claim's topic
+            let claim_topic = System::event_topics(Topic::Claim.of::<Hashing>(&claim_id));
+            assert_eq!(claim_topic.len(), 3);
+
+            // Only the post and the finalization name Alice
+            let alice_topic = System::event_topics(Topic::Account.of::<Hashing>(&ALICE));
+            assert_eq!(alice_topic.len(), 2);
+            assert!(System::event_topics(Topic::Account.of::<Hashing>(&BOB)).is_empty());
+        });
+    }
+
+    // Post a claim by ALICE and have BOB challenge it, drawing the jury if one is scheduled
+    fn post_and_challenge() -> u64 {
+        assert_ok!(TrustLayer::post_claim(
+            RuntimeOrigin::signed(ALICE),
+            ual_of(ALICE),
+            vec![],
+            200
+        ));
+        let claim_id = ClaimIdCounter::<Test>::get();
+        assert_ok!(TrustLayer::challenge_claim(
+            RuntimeOrigin::signed(BOB),
+            claim_id,
+            vec![],
+            200
+        ));
+        if TrustLayer::pending_jury_draw(claim_id).is_some() {
+            draw_pending_jury(claim_id);
+        }
+        claim_id
+    }
+
+    // Have a pool member commit to and reveal the seed of the claim's pending draw, then draw
+    fn draw_pending_jury(claim_id: u64) {
+        let draw = TrustLayer::pending_jury_draw(claim_id).expect("jury draw pending");
+        let secret = [claim_id as u8; 32];
+        assert_ok!(TrustLayer::commit_jury_seed(
+            RuntimeOrigin::signed(JURORS[0]),
+            BlakeTwo256::hash_of(&(JURORS[0], secret))
+        ));
+
+        let draw_at = TrustLayer::seed_revealed_at(draw.seed_round);
+        assert_noop!(
+            TrustLayer::draw_jury(RuntimeOrigin::signed(CHARLIE), claim_id),
+            Error::<Test>::SeedNotRevealed
+        );
+        System::set_block_number(draw_at - JurySeedPeriod::get());
+        assert_ok!(TrustLayer::reveal_jury_seed(RuntimeOrigin::signed(JURORS[0]), draw.seed_round, secret));
+        System::set_block_number(draw_at);
+        assert_ok!(TrustLayer::draw_jury(RuntimeOrigin::signed(CHARLIE), claim_id));
+        assert!(TrustLayer::pending_jury_draw(claim_id).is_none());
+    }
+
+    #[test]
+    fn join_jury_pool_requires_reputation() {
+        new_test_ext().execute_with(|| {
+            assert_noop!(
+                TrustLayer::join_jury_pool(RuntimeOrigin::signed(CHARLIE)),
+                Error::<Test>::InsufficientReputation
+            );
+
+            assert_ok!(TrustLayer::join_jury_pool(RuntimeOrigin::signed(JURORS[0])));
+            assert_eq!(TrustLayer::juror_stake(JURORS[0]), Some(JurorStake::get()));
+            assert_eq!(Balances::reserved_balance(JURORS[0]), JurorStake::get());
+
+            assert_noop!(
+                TrustLayer::join_jury_pool(RuntimeOrigin::signed(JURORS[0])),
+                Error::<Test>::AlreadyJuror
+            );
+
+            assert_ok!(TrustLayer::leave_jury_pool(RuntimeOrigin::signed(JURORS[0])));
+            assert_eq!(Balances::reserved_balance(JURORS[0]), 0);
+            assert!(TrustLayer::juror_pool().is_empty());
+        });
+    }
+
+    #[test]
+    fn jury_majority_resolves_claim_and_settles_jurors() {
+        new_test_ext().execute_with(|| {
+            for juror in JURORS {
+                assert_ok!(TrustLayer::join_jury_pool(RuntimeOrigin::signed(juror)));
+            }
+            let claim_id = post_and_challenge();
+
+            let jury = TrustLayer::claim_jury(claim_id).expect("jury drawn");
+            assert_eq!(jury.jurors.len(), 3);
+            let (majority, minority) = (&jury.jurors[..2], jury.jurors[2]);
+
+            // Seated jurors cannot leave the pool
+            assert_noop!(
+                TrustLayer::leave_jury_pool(RuntimeOrigin::signed(minority)),
+                Error::<Test>::JurorOnDuty
+            );
+            // Root may only resolve escalated claims
+            assert_noop!(
+                TrustLayer::resolve_claim(RuntimeOrigin::root(), claim_id, ClaimResolution::Accepted),
+                Error::<Test>::ClaimNotResolvable
+            );
+
+            for juror in majority {
+                assert_ok!(TrustLayer::cast_jury_vote(
+                    RuntimeOrigin::signed(*juror),
+                    claim_id,
+                    ClaimResolution::Rejected
+                ));
+            }
+            assert_ok!(TrustLayer::cast_jury_vote(
+                RuntimeOrigin::signed(minority),
+                claim_id,
+                ClaimResolution::Accepted
+            ));
+
+            assert_noop!(
+                TrustLayer::tally_jury(RuntimeOrigin::signed(CHARLIE), claim_id),
+                Error::<Test>::VotingOpen
+            );
+            System::set_block_number(jury.voting_deadline + 1);
+            assert_ok!(TrustLayer::tally_jury(RuntimeOrigin::signed(CHARLIE), claim_id));
+
+            // Verdict is final once the appeal window passes
+            assert_eq!(TrustLayer::claim(claim_id).unwrap().status, ClaimStatus::Decided);
+            assert_noop!(
+                TrustLayer::finalize_claim(RuntimeOrigin::signed(CHARLIE), claim_id),
+                Error::<Test>::AppealWindowOpen
+            );
+            System::set_block_number(TrustLayer::appeal_deadline(claim_id).unwrap() + 1);
+            assert_ok!(TrustLayer::finalize_claim(RuntimeOrigin::signed(CHARLIE), claim_id));
+
+            let claim = TrustLayer::claim(claim_id).unwrap();
+            assert_eq!(claim.status, ClaimStatus::Resolved);
+            assert_eq!(claim.resolution, Some(ClaimResolution::Rejected));
+            assert_eq!(Balances::reserved_balance(BOB), 0);
+
+            // 20% of the minority juror's stake is split between the majority
+            assert_eq!(TrustLayer::juror_stake(minority), Some(40));
+            for juror in majority {
+                assert_eq!(Balances::free_balance(*juror), 1_000 - 50 + 5);
+            }
+            assert!(TrustLayer::claim_jury(claim_id).is_none());
+            assert_ok!(TrustLayer::leave_jury_pool(RuntimeOrigin::signed(minority)));
+        });
+    }
+
+    #[test]
+    fn claim_without_jury_escalates_to_root() {
+        new_test_ext().execute_with(|| {
+            let claim_id = post_and_challenge();
+
+            assert_eq!(TrustLayer::claim(claim_id).unwrap().status, ClaimStatus::Escalated);
+            assert_ok!(TrustLayer::resolve_claim(
+                RuntimeOrigin::root(),
+                claim_id,
+                ClaimResolution::Uncertain
+            ));
+            assert_eq!(Balances::reserved_balance(ALICE), 0);
+            assert_eq!(Balances::reserved_balance(BOB), 0);
+        });
+    }
+
+    #[test]
+    fn unrevealed_jury_seeds_are_slashed() {
+        new_test_ext().execute_with(|| {
+            let secret = [7u8; 32];
+            assert_noop!(
+                TrustLayer::commit_jury_seed(RuntimeOrigin::signed(CHARLIE), BlakeTwo256::hash_of(&(CHARLIE, secret))),
+                Error::<Test>::NotJuror
+            );
+            for juror in &JURORS[..2] {
+                assert_ok!(TrustLayer::join_jury_pool(RuntimeOrigin::signed(*juror)));
+                assert_ok!(TrustLayer::commit_jury_seed(
+                    RuntimeOrigin::signed(*juror),
+                    BlakeTwo256::hash_of(&(*juror, secret))
+                ));
+            }
+            assert_noop!(
+                TrustLayer::commit_jury_seed(RuntimeOrigin::signed(JURORS[0]), BlakeTwo256::hash_of(&(JURORS[0], secret))),
+                Error::<Test>::SeedAlreadyCommitted
+            );
+            // Committed jurors cannot leave before revealing
+            assert_noop!(
+                TrustLayer::leave_jury_pool(RuntimeOrigin::signed(JURORS[0])),
+                Error::<Test>::SeedUnrevealed
+            );
+
+            // Secrets are revealed during the next round only, and must match
+            assert_noop!(
+                TrustLayer::reveal_jury_seed(RuntimeOrigin::signed(JURORS[0]), 0, secret),
+                Error::<Test>::SeedRevealClosed
+            );
+            System::set_block_number(JurySeedPeriod::get());
+            assert_noop!(
+                TrustLayer::reveal_jury_seed(RuntimeOrigin::signed(JURORS[0]), 0, [8u8; 32]),
+                Error::<Test>::SeedMismatch
+            );
+            assert_ok!(TrustLayer::reveal_jury_seed(RuntimeOrigin::signed(JURORS[0]), 0, secret));
+            assert_eq!(TrustLayer::jury_seed(0).reveals, 1);
+            assert_noop!(
+                TrustLayer::slash_unrevealed_seed(RuntimeOrigin::signed(CHARLIE), 0, JURORS[1]),
+                Error::<Test>::SeedRevealOpen
+            );
+
+            // Withholding a secret past the reveal round costs part of the stake
+            System::set_block_number(2 * JurySeedPeriod::get());
+            assert_noop!(
+                TrustLayer::slash_unrevealed_seed(RuntimeOrigin::signed(CHARLIE), 0, JURORS[0]),
+                Error::<Test>::NoSeedCommitment
+            );
+            assert_noop!(
+                TrustLayer::slash_unrevealed_seed(RuntimeOrigin::signed(JURORS[1]), 0, JURORS[1]),
+                Error::<Test>::CannotReportSelf
+            );
+            // An old unrevealed commitment still keeps the stake in place for the slash
+            assert_noop!(
+                TrustLayer::leave_jury_pool(RuntimeOrigin::signed(JURORS[1])),
+                Error::<Test>::SeedUnrevealed
+            );
+            let charlie_free = Balances::free_balance(CHARLIE);
+            assert_ok!(TrustLayer::slash_unrevealed_seed(RuntimeOrigin::signed(CHARLIE), 0, JURORS[1]));
+            assert_eq!(TrustLayer::juror_stake(JURORS[1]), Some(40));
+            assert_eq!(Balances::free_balance(CHARLIE), charlie_free + 10);
+            assert_ok!(TrustLayer::leave_jury_pool(RuntimeOrigin::signed(JURORS[1])));
+        });
+    }
+
+    #[test]
+    fn unrevealed_seed_round_escalates_draw() {
+        new_test_ext().execute_with(|| {
+            for juror in JURORS {
+                assert_ok!(TrustLayer::join_jury_pool(RuntimeOrigin::signed(juror)));
+            }
+            assert_ok!(TrustLayer::post_claim(RuntimeOrigin::signed(ALICE), ual_of(ALICE), vec![], 200));
+            let claim_id = ClaimIdCounter::<Test>::get();
+            assert_ok!(TrustLayer::challenge_claim(RuntimeOrigin::signed(BOB), claim_id, vec![], 200));
+            assert!(TrustLayer::claim_jury(claim_id).is_none());
+
+            // Nobody revealed a secret for the round, so no seed can be trusted
+            let draw = TrustLayer::pending_jury_draw(claim_id).expect("jury draw pending");
+            System::set_block_number(TrustLayer::seed_revealed_at(draw.seed_round));
+            assert_ok!(TrustLayer::draw_jury(RuntimeOrigin::signed(CHARLIE), claim_id));
+            assert_eq!(TrustLayer::claim(claim_id).unwrap().status, ClaimStatus::Escalated);
+            assert_noop!(
+                TrustLayer::draw_jury(RuntimeOrigin::signed(CHARLIE), claim_id),
+                Error::<Test>::NoPendingDraw
+            );
+        });
+    }
+
+    // Have every seated juror vote for `resolution` and tally after the voting period
+    fn decide(claim_id: u64, resolution: ClaimResolution) {
+        let jury = TrustLayer::claim_jury(claim_id).expect("jury drawn");
+        for juror in jury.jurors.iter() {
+            assert_ok!(TrustLayer::cast_jury_vote(
+                RuntimeOrigin::signed(*juror),
+                claim_id,
+                resolution.clone()
+            ));
+        }
+        System::set_block_number(jury.voting_deadline + 1);
+        assert_ok!(TrustLayer::tally_jury(RuntimeOrigin::signed(CHARLIE), claim_id));
+    }
+
+    #[test]
+    fn appeals_double_stake_and_escalate_after_last_round() {
+        new_test_ext().execute_with(|| {
+            for juror in JURORS {
+                assert_ok!(TrustLayer::join_jury_pool(RuntimeOrigin::signed(juror)));
+            }
+            let claim_id = post_and_challenge();
+            decide(claim_id, ClaimResolution::Rejected);
+
+            // Only the losing submitter may appeal
+            assert_noop!(
+                TrustLayer::appeal_claim(RuntimeOrigin::signed(BOB), claim_id),
+                Error::<Test>::NotAppellant
+            );
+            assert_ok!(TrustLayer::appeal_claim(RuntimeOrigin::signed(ALICE), claim_id));
+            draw_pending_jury(claim_id);
+
+            assert_eq!(Balances::reserved_balance(ALICE), 400);
+            assert_eq!(TrustLayer::claim(claim_id).unwrap().stake, 400);
+            assert_eq!(TrustLayer::claim_round(claim_id), 1);
+            assert_eq!(TrustLayer::claim_jury(claim_id).unwrap().jurors.len(), 7);
+
+            // Appealing the last jury round hands the claim to governance
+            decide(claim_id, ClaimResolution::Rejected);
+            assert_ok!(TrustLayer::appeal_claim(RuntimeOrigin::signed(ALICE), claim_id));
+            assert_eq!(Balances::reserved_balance(ALICE), 800);
+            assert_eq!(TrustLayer::claim(claim_id).unwrap().status, ClaimStatus::Escalated);
+
+            assert_ok!(TrustLayer::resolve_claim(
+                RuntimeOrigin::root(),
+                claim_id,
+                ClaimResolution::Accepted
+            ));
+            assert_eq!(Balances::reserved_balance(ALICE), 0);
+        });
+    }
+
+    #[test]
+    fn appeal_window_expires() {
+        new_test_ext().execute_with(|| {
+            for juror in JURORS {
+                assert_ok!(TrustLayer::join_jury_pool(RuntimeOrigin::signed(juror)));
+            }
+            let claim_id = post_and_challenge();
+            decide(claim_id, ClaimResolution::Accepted);
+
+            System::set_block_number(TrustLayer::appeal_deadline(claim_id).unwrap() + 1);
+            assert_noop!(
+                TrustLayer::appeal_claim(RuntimeOrigin::signed(BOB), claim_id),
+                Error::<Test>::AppealWindowExpired
+            );
+        });
+    }
+
+    #[test]
+    fn claim_evidence_is_bounded() {
+        new_test_ext().execute_with(|| {
+            assert_noop!(
+                TrustLayer::post_claim(RuntimeOrigin::signed(ALICE), vec![0u8; MAX_UAL_LEN as usize + 1], vec![], 200),
+                Error::<Test>::UalTooLong
+            );
+            assert_noop!(
+                TrustLayer::post_claim(
+                    RuntimeOrigin::signed(ALICE),
+                    ual_of(ALICE),
+                    vec![ual_of(BOB); 5],
+                    200
+                ),
+                Error::<Test>::TooManyEvidenceItems
+            );
+
+            for _ in 0..MaxClaimsPerSubmitter::get() {
+                assert_ok!(TrustLayer::post_claim(
+                    RuntimeOrigin::signed(ALICE),
+                    ual_of(ALICE),
+                    vec![],
+                    200
+                ));
+            }
+            assert_noop!(
+                TrustLayer::post_claim(RuntimeOrigin::signed(ALICE), ual_of(ALICE), vec![], 200),
+                Error::<Test>::TooManyOpenClaims
+            );
+
+            // Finalized claims free up a slot
+            System::set_block_number(TrustLayer::claim(1).unwrap().challenge_deadline + 1);
+            assert_ok!(TrustLayer::finalize_claim(RuntimeOrigin::signed(BOB), 1));
+            assert_ok!(TrustLayer::post_claim(
+                RuntimeOrigin::signed(ALICE),
+                ual_of(ALICE),
+                vec![],
+                200
+            ));
+        });
+    }
+
+    #[test]
+    fn migrate_to_v1_bounds_claims() {
+        new_test_ext().execute_with(|| {
+            StorageVersion::new(0).put::<TrustLayer>();
+
+            // v0 claim with an over-long evidence list
+            let old_claim = (
+                1u64,
+                ALICE,
+                ual_of(ALICE),
+                vec![ual_of(BOB); 6],
+                200u64,
+                ClaimStatus::Pending,
+                1u64,
+                1001u64,
+                Option::<u64>::None,
+                Option::<ClaimResolution>::None,
+            );
+            frame_support::storage::unhashed::put_raw(
+                &Claims::<Test>::hashed_key_for(1),
+                &old_claim.encode(),
+            );
+            frame_support::storage::unhashed::put_raw(
+                &SubmitterClaims::<Test>::hashed_key_for(ALICE),
+                &vec![1u64, 7u64].encode(),
+            );
+
+            crate::migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+            let claim = TrustLayer::claim(1).unwrap();
+            assert_eq!(claim.claim_ual.to_vec(), ual_of(ALICE));
+            assert_eq!(claim.evidence_uals.len(), MaxEvidenceItems::get() as usize);
+            // Unknown claim ids are dropped from the submitter index
+            assert_eq!(SubmitterClaims::<Test>::get(ALICE).to_vec(), vec![1]);
+            assert_eq!(TrustLayer::on_chain_storage_version(), 1);
+
+            crate::migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+            assert_eq!(TrustLayer::claims_with_status(ClaimStatus::Pending), vec![1]);
+            assert_eq!(TrustLayer::claim_agenda(1002).to_vec(), vec![1]);
+            assert_eq!(TrustLayer::on_chain_storage_version(), 2);
+        });
+    }
+
+    #[test]
+    fn migrate_to_v3_drops_entries_keyed_by_over_long_uals() {
+        new_test_ext().execute_with(|| {
+            use frame_support::{storage::StoragePrefixedMap, Blake2_128Concat, StorageHasher};
+
+            StorageVersion::new(2).put::<TrustLayer>();
+            DataProviders::<Test>::insert(bounded_ual_of(ALICE), ALICE);
+            QueryAccess::<Test>::insert(BOB, bounded_ual_of(ALICE), 10);
+
+            // v2 entries keyed by a UAL longer than `MAX_UAL_LEN`
+            let long_ual = vec![b'u'; MAX_UAL_LEN as usize + 1].encode();
+            let provider_key =
+                [DataProviders::<Test>::final_prefix().to_vec(), Blake2_128Concat::hash(&long_ual)].concat();
+            let access_key = [
+                QueryAccess::<Test>::final_prefix().to_vec(),
+                Blake2_128Concat::hash(&BOB.encode()),
+                Blake2_128Concat::hash(&long_ual),
+            ]
+            .concat();
+            frame_support::storage::unhashed::put(&provider_key, &ALICE);
+            frame_support::storage::unhashed::put(&access_key, &10u64);
+
+            crate::migrations::v3::MigrateToV3::<Test>::on_runtime_upgrade();
+
+            assert!(!frame_support::storage::unhashed::exists(&provider_key));
+            assert!(!frame_support::storage::unhashed::exists(&access_key));
+            assert_eq!(TrustLayer::data_provider(bounded_ual_of(ALICE)), Some(ALICE));
+            assert_eq!(TrustLayer::query_access(BOB, bounded_ual_of(ALICE)), Some(10));
+            assert_eq!(TrustLayer::on_chain_storage_version(), 3);
+        });
+    }
+
+    #[test]
+    fn multi_block_migration_gates_claim_calls() {
+        new_test_ext().execute_with(|| {
+            StorageVersion::new(0).put::<TrustLayer>();
+            for claim_id in 1..=3u64 {
+                let old_claim = (
+                    claim_id,
+                    ALICE,
+                    ual_of(ALICE),
+                    vec![ual_of(BOB); 6],
+                    200u64,
+                    ClaimStatus::Pending,
+                    1u64,
+                    1001u64,
+                    Option::<u64>::None,
+                    Option::<ClaimResolution>::None,
+                );
+                frame_support::storage::unhashed::put_raw(
+                    &Claims::<Test>::hashed_key_for(claim_id),
+                    &old_claim.encode(),
+                );
+            }
+            ClaimIdCounter::<Test>::put(3);
+            MigrationInProgress::<Test>::put(MigrationCursor::default());
+
+            assert_noop!(
+                TrustLayer::post_claim(RuntimeOrigin::signed(BOB), ual_of(BOB), vec![], 200),
+                Error::<Test>::MigrationInProgress
+            );
+
+            // Two claims per step: v1 and v2 each need two steps, v3 finds nothing to prune
+            let mut steps = 0;
+            while TrustLayer::migration_in_progress().is_some() {
+                TrustLayer::step_migration(ITEM_BASE_WEIGHT * 2);
+                steps += 1;
+            }
+            assert_eq!(steps, 5);
+            System::assert_last_event(Event::MigrationCompleted.into());
+
+            assert_eq!(TrustLayer::on_chain_storage_version(), 3);
+            for claim_id in 1..=3u64 {
+                let claim = TrustLayer::claim(claim_id).unwrap();
+                assert_eq!(claim.evidence_uals.len(), MaxEvidenceItems::get() as usize);
+            }
+            assert_eq!(TrustLayer::claims_with_status(ClaimStatus::Pending).len(), 3);
+
+            assert_ok!(TrustLayer::post_claim(RuntimeOrigin::signed(BOB), ual_of(BOB), vec![], 200));
+
+            // Nothing to do once storage is current
+            crate::migrations::MigrateMultiBlock::<Test>::on_runtime_upgrade();
+            assert!(TrustLayer::migration_in_progress().is_none());
+        });
+    }
+
+    #[test]
+    fn claims_are_indexed_by_status() {
+        new_test_ext().execute_with(|| {
+            let claim_id = post_and_challenge();
+
+            assert_eq!(TrustLayer::claims_with_status(ClaimStatus::Escalated), vec![claim_id]);
+            assert!(TrustLayer::claims_with_status(ClaimStatus::Pending).is_empty());
+
+            assert_ok!(TrustLayer::resolve_claim(
+                RuntimeOrigin::root(),
+                claim_id,
+                ClaimResolution::Uncertain
+            ));
+            assert!(TrustLayer::claims_with_status(ClaimStatus::Escalated).is_empty());
+            assert_eq!(TrustLayer::claims_with_status(ClaimStatus::Resolved), vec![claim_id]);
+        });
+    }
+
+    #[test]
+    fn sweep_finalizes_unchallenged_claims_by_deadline() {
+        new_test_ext().execute_with(|| {
+            for who in [ALICE, BOB] {
+                assert_ok!(TrustLayer::post_claim(
+                    RuntimeOrigin::signed(who),
+                    ual_of(who),
+                    vec![],
+                    200
+                ));
+            }
+            let deadline = TrustLayer::claim(1).unwrap().challenge_deadline;
+
+            // One claim per agenda slot; the second spills into the next block
+            assert_eq!(TrustLayer::claim_agenda(deadline + 1).to_vec(), vec![1]);
+            assert_eq!(TrustLayer::claim_agenda(deadline + 2).to_vec(), vec![2]);
+
+            System::set_block_number(deadline + 1);
+            TrustLayer::on_initialize(deadline + 1);
+            assert_eq!(TrustLayer::claim(1).unwrap().status, ClaimStatus::Resolved);
+            assert_eq!(Balances::reserved_balance(ALICE), 0);
+            assert_eq!(TrustLayer::claim(2).unwrap().status, ClaimStatus::Pending);
+
+            System::set_block_number(deadline + 2);
+            TrustLayer::on_initialize(deadline + 2);
+            assert_eq!(TrustLayer::claim(2).unwrap().status, ClaimStatus::Resolved);
+            assert_eq!(TrustLayer::claims_with_status(ClaimStatus::Resolved).len(), 2);
+        });
+    }
+
+    #[test]
+    fn sweep_keeps_finalizing_claims_while_migrating() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(TrustLayer::post_claim(RuntimeOrigin::signed(ALICE), ual_of(ALICE), vec![], 200));
+            let deadline = TrustLayer::claim(1).unwrap().challenge_deadline;
+
+            StorageVersion::new(2).put::<TrustLayer>();
+            MigrationInProgress::<Test>::put(MigrationCursor::default());
+
+            System::set_block_number(deadline + 1);
+            TrustLayer::on_initialize(deadline + 1);
+            System::assert_has_event(Event::MigrationCompleted.into());
+            assert_eq!(TrustLayer::claim(1).unwrap().status, ClaimStatus::Resolved);
+        });
+    }
+
+    #[test]
+    fn pay_for_queries_applies_batch_discount() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(TrustLayer::set_treasury(RuntimeOrigin::root(), TREASURY));
+            assert_ok!(TrustLayer::register_data_provider(
+                RuntimeOrigin::signed(ALICE),
+                ual_of(ALICE)
+            ));
+            assert_ok!(TrustLayer::set_custom_query_price(
+                RuntimeOrigin::signed(ALICE),
+                ual_of(ALICE),
+                100
+            ));
+
+            let alice_before = Balances::free_balance(ALICE);
+            let treasury_before = Balances::free_balance(TREASURY);
+            let queries = vec![(ual_of(ALICE), 10), (ual_of(CHARLIE), 10)];
+
+            assert_ok!(TrustLayer::pay_for_queries(
+                RuntimeOrigin::signed(BOB),
+                queries.try_into().unwrap()
+            ));
+
+            // 90 + 9 after the 10% discount; the provider gets 70% of 90
+            assert_eq!(Balances::free_balance(ALICE), alice_before + 63);
+            assert_eq!(Balances::free_balance(TREASURY), treasury_before + 27 + 9);
+            assert!(TrustLayer::has_query_access(&BOB, &bounded_ual_of(ALICE)));
+            assert!(TrustLayer::has_query_access(&BOB, &bounded_ual_of(CHARLIE)));
+
+            assert_noop!(
+                TrustLayer::pay_for_queries(RuntimeOrigin::signed(BOB), Default::default()),
+                Error::<Test>::EmptyQueryBatch
+            );
+        });
+    }
+
+    #[test]
+    fn rejected_claim_forfeits_only_reserved_stake() {
+        new_test_ext().execute_with(|| {
+            let claim_id = post_and_challenge();
+            let alice_free = Balances::free_balance(ALICE);
+            let bob_free = Balances::free_balance(BOB);
+            let treasury_before = Balances::free_balance(TREASURY);
+
+            assert_ok!(TrustLayer::resolve_claim(
+                RuntimeOrigin::root(),
+                claim_id,
+                ClaimResolution::Rejected
+            ));
+
+            // The submitter loses the reserved stake, never free balance
+            assert_eq!(Balances::reserved_balance(ALICE), 0);
+            assert_eq!(Balances::free_balance(ALICE), alice_free);
+            // Half goes to the challenger, who also gets their own stake back
+            assert_eq!(Balances::free_balance(BOB), bob_free + 200 + 100);
+            assert_eq!(Balances::free_balance(TREASURY), treasury_before + 100);
+        });
+    }
+
+    #[test]
+    fn query_price_follows_demand_within_bounds() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(TrustLayer::set_treasury(RuntimeOrigin::root(), TREASURY));
+            let config = PricingConfig {
+                floor: 8,
+                ceiling: 20,
+                target_volume: 1,
+                adjustment: sp_runtime::Perbill::from_percent(50),
+                window: 10,
+            };
+            assert_noop!(
+                TrustLayer::set_pricing_config(
+                    RuntimeOrigin::root(),
+                    Some(PricingConfig { floor: 30, ..config.clone() })
+                ),
+                Error::<Test>::InvalidPricingConfig
+            );
+            assert_ok!(TrustLayer::set_pricing_config(RuntimeOrigin::root(), Some(config)));
+
+            let ual = bounded_ual_of(CHARLIE);
+            for _ in 0..2 {
+                assert_ok!(TrustLayer::pay_for_query(RuntimeOrigin::signed(BOB), ual.to_vec(), 5));
+            }
+            assert_eq!(TrustLayer::query_price(&ual), 10);
+
+            // Demand above target raises the price for the next window
+            System::set_block_number(11);
+            assert_ok!(TrustLayer::pay_for_query(RuntimeOrigin::signed(BOB), ual.to_vec(), 5));
+            assert_eq!(TrustLayer::query_price(&ual), 15);
+
+            // Quiet windows lower it again, but never below the floor
+            System::set_block_number(41);
+            assert_ok!(TrustLayer::pay_for_query(RuntimeOrigin::signed(BOB), ual.to_vec(), 5));
+            assert_eq!(TrustLayer::query_price(&ual), 8);
+        });
+    }
+
+    #[test]
+    fn metered_access_debits_channel_and_revokes_when_exhausted() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(TrustLayer::open_payment_channel(RuntimeOrigin::signed(BOB), ALICE, 12, 100));
+            assert_ok!(TrustLayer::enable_metered_access(
+                RuntimeOrigin::signed(BOB),
+                ual_of(ALICE),
+                ALICE
+            ));
+            assert_eq!(TrustLayer::remaining_queries(&BOB, &ALICE), 2);
+            assert!(TrustLayer::has_query_access(&BOB, &bounded_ual_of(ALICE)));
+
+            assert_noop!(
+                TrustLayer::record_metered_query(RuntimeOrigin::signed(CHARLIE), BOB, ual_of(ALICE)),
+                Error::<Test>::NotMeteredPayee
+            );
+
+            let alice_before = Balances::free_balance(ALICE);
+            assert_ok!(TrustLayer::record_metered_query(RuntimeOrigin::signed(ALICE), BOB, ual_of(ALICE)));
+            assert_eq!(TrustLayer::remaining_queries(&BOB, &ALICE), 1);
+            assert_ok!(TrustLayer::record_metered_query(RuntimeOrigin::signed(ALICE), BOB, ual_of(ALICE)));
+
+            assert_eq!(Balances::free_balance(ALICE), alice_before + 10);
+            assert_eq!(Balances::reserved_balance(BOB), 2);
+            assert_eq!(TrustLayer::metered_access(BOB, bounded_ual_of(ALICE)), None);
+            assert!(!TrustLayer::has_query_access(&BOB, &bounded_ual_of(ALICE)));
+        });
+    }
+
+    #[test]
+    fn metered_queries_stop_when_the_channel_expires() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(TrustLayer::open_payment_channel(RuntimeOrigin::signed(BOB), ALICE, 12, 10));
+            assert_ok!(TrustLayer::enable_metered_access(
+                RuntimeOrigin::signed(BOB),
+                ual_of(ALICE),
+                ALICE
+            ));
+            let (_, expiry) = TrustLayer::payment_channel(BOB, ALICE).unwrap();
+
+            System::set_block_number(expiry + 1);
+            assert_eq!(TrustLayer::remaining_queries(&BOB, &ALICE), 0);
+            assert!(!TrustLayer::has_query_access(&BOB, &bounded_ual_of(ALICE)));
+            assert_noop!(
+                TrustLayer::record_metered_query(RuntimeOrigin::signed(ALICE), BOB, ual_of(ALICE)),
+                Error::<Test>::ChannelExpired
+            );
+        });
+    }
+
+    #[test]
+    fn resolved_claims_feed_back_into_reputation() {
+        new_test_ext().execute_with(|| {
+            // Unchallenged claims finalize without reputation feedback
+            assert_ok!(TrustLayer::post_claim(
+                RuntimeOrigin::signed(CHARLIE),
+                ual_of(CHARLIE),
+                vec![],
+                200
+            ));
+            let unchallenged = ClaimIdCounter::<Test>::get();
+            System::set_block_number(TrustLayer::claim(unchallenged).unwrap().challenge_deadline + 1);
+            assert_ok!(TrustLayer::finalize_claim(RuntimeOrigin::signed(BOB), unchallenged));
+            assert!(claim_verdicts().is_empty());
+
+            let defended = post_and_challenge();
+            assert_ok!(TrustLayer::resolve_claim(
+                RuntimeOrigin::root(),
+                defended,
+                ClaimResolution::Accepted
+            ));
+            assert_eq!(claim_verdicts(), vec![(ALICE, ClaimVerdict::Upheld)]);
+
+            let rejected = post_and_challenge();
+            assert_ok!(TrustLayer::resolve_claim(
+                RuntimeOrigin::root(),
+                rejected,
+                ClaimResolution::Rejected
+            ));
+            assert_eq!(
+                claim_verdicts(),
+                vec![(ALICE, ClaimVerdict::Upheld), (ALICE, ClaimVerdict::Rejected)]
+            );
+        });
+    }
+
+    #[test]
+    fn rejected_claims_slash_progressively_and_offences_decay() {
+        use sp_runtime::Perbill;
+
+        new_test_ext().execute_with(|| {
+            assert_noop!(
+                TrustLayer::set_slashing_policy(
+                    RuntimeOrigin::root(),
+                    Some(SlashingPolicy {
+                        first_offence: Perbill::from_percent(50),
+                        escalation: Perbill::from_percent(10),
+                        max_severity: Perbill::from_percent(40),
+                        decay_period: 100,
+                    })
+                ),
+                Error::<Test>::InvalidSlashingPolicy
+            );
+            assert_ok!(TrustLayer::set_slashing_policy(
+                RuntimeOrigin::root(),
+                Some(SlashingPolicy {
+                    first_offence: Perbill::from_percent(30),
+                    escalation: Perbill::from_percent(20),
+                    max_severity: Perbill::from_percent(60),
+                    decay_period: 100,
+                })
+            ));
+
+            let reject = || {
+                let before = Balances::free_balance(ALICE);
+                let claim_id = post_and_challenge();
+                assert_ok!(TrustLayer::resolve_claim(
+                    RuntimeOrigin::root(),
+                    claim_id,
+                    ClaimResolution::Rejected
+                ));
+                assert_eq!(Balances::reserved_balance(ALICE), 0);
+                before - Balances::free_balance(ALICE)
+            };
+
+            // First offence: 30%, then +20% per counted offence, capped at 60%
+            assert_eq!(reject(), 60);
+            assert_eq!(reject(), 100);
+            assert_eq!(reject(), 120);
+            assert_eq!(TrustLayer::offence_record(ALICE).unwrap().count, 3);
+
+            // Two decay periods forgive two offences
+            System::set_block_number(System::block_number() + 200);
+            assert_eq!(reject(), 100);
+            assert_eq!(TrustLayer::offence_record(ALICE).unwrap().count, 2);
+        });
+    }
+
+    #[test]
+    fn upheld_fraud_report_overturns_contribution_and_rewards_reporter() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(TrustLayer::set_treasury(RuntimeOrigin::root(), TREASURY));
+            assert_ok!(TrustLayer::stake_tokens(RuntimeOrigin::signed(BOB), 1_000));
+            let contribution_id = 7;
+            verify_contribution(contribution_id, BOB);
+
+            assert_noop!(
+                TrustLayer::report_contribution(RuntimeOrigin::signed(ALICE), 8, vec![], 200),
+                Error::<Test>::ContributionNotReportable
+            );
+            assert_noop!(
+                TrustLayer::report_contribution(RuntimeOrigin::signed(BOB), contribution_id, vec![], 200),
+                Error::<Test>::CannotReportOwnContribution
+            );
+            assert_ok!(TrustLayer::report_contribution(
+                RuntimeOrigin::signed(ALICE),
+                contribution_id,
+                vec![ual_of(ALICE)],
+                200
+            ));
+            let claim_id = ClaimIdCounter::<Test>::get();
+            assert_eq!(TrustLayer::claim(claim_id).unwrap().claim_ual.to_vec(), b"dotrep:fraud/contribution/7".to_vec());
+            assert_noop!(
+                TrustLayer::report_contribution(RuntimeOrigin::signed(CHARLIE), contribution_id, vec![], 200),
+                Error::<Test>::ContributionAlreadyReported
+            );
+            assert_noop!(
+                TrustLayer::unstake_tokens(RuntimeOrigin::signed(BOB), 1_000),
+                Error::<Test>::StakeLocked
+            );
+
+            // Bob does not defend the contribution, which still takes a vote to overturn
+            let alice_before = Balances::free_balance(ALICE);
+            let deadline = TrustLayer::claim(claim_id).unwrap().challenge_deadline;
+            assert_eq!(deadline, System::block_number() + FraudReportWindow::get());
+            System::set_block_number(deadline + 1);
+            assert_ok!(TrustLayer::finalize_claim(RuntimeOrigin::signed(CHARLIE), claim_id));
+            assert_eq!(TrustLayer::claim(claim_id).unwrap().status, ClaimStatus::Escalated);
+            assert!(<TestContributions as dotrep_primitives::ContributionRegistry<u64>>::verified_contributor(contribution_id).is_some());
+            assert_ok!(TrustLayer::resolve_claim(RuntimeOrigin::root(), claim_id, ClaimResolution::Accepted));
+
+            // Half of Bob's stake is slashed; Alice gets her stake back plus half of it
+            assert!(<TestContributions as dotrep_primitives::ContributionRegistry<u64>>::verified_contributor(contribution_id).is_none());
+            assert_eq!(TrustLayer::staked_amount(BOB), 500);
+            assert_eq!(Balances::free_balance(ALICE), alice_before + 200 + 250);
+            System::assert_has_event(
+                Event::FraudReportUpheld { claim_id, contribution_id, contributor: BOB, reward: 250 }.into(),
+            );
+            assert!(TrustLayer::fraud_report(claim_id).is_none());
+            assert!(!ReportedContributions::<Test>::contains_key(contribution_id));
+        });
+    }
+
+    #[test]
+    fn defended_contribution_forfeits_reporter_stake() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(TrustLayer::stake_tokens(RuntimeOrigin::signed(BOB), 1_000));
+            verify_contribution(7, BOB);
+            assert_ok!(TrustLayer::report_contribution(RuntimeOrigin::signed(ALICE), 7, vec![], 200));
+            let claim_id = ClaimIdCounter::<Test>::get();
+
+            // Bob challenges the report and governance rejects it
+            assert_ok!(TrustLayer::challenge_claim(RuntimeOrigin::signed(BOB), claim_id, vec![], 200));
+            assert_ok!(TrustLayer::resolve_claim(RuntimeOrigin::root(), claim_id, ClaimResolution::Rejected));
+
+            assert!(<TestContributions as dotrep_primitives::ContributionRegistry<u64>>::verified_contributor(7).is_some());
+            assert_eq!(TrustLayer::staked_amount(BOB), 1_000);
+            assert_eq!(claim_verdicts(), vec![(ALICE, ClaimVerdict::Rejected)]);
+            assert!(!ReportedContributions::<Test>::contains_key(7));
+        });
+    }
+
+    #[test]
+    fn backers_share_provider_fees_pro_rata() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(TrustLayer::set_treasury(RuntimeOrigin::root(), TREASURY));
+            assert_ok!(TrustLayer::register_data_provider(RuntimeOrigin::signed(ALICE), ual_of(ALICE)));
+            assert_ok!(TrustLayer::set_custom_query_price(RuntimeOrigin::signed(ALICE), ual_of(ALICE), 500));
+
+            assert_noop!(
+                TrustLayer::back_contributor(RuntimeOrigin::signed(ALICE), ALICE, 200),
+                Error::<Test>::CannotBackSelf
+            );
+            assert_ok!(TrustLayer::back_contributor(RuntimeOrigin::signed(BOB), ALICE, 100));
+            assert_ok!(TrustLayer::back_contributor(RuntimeOrigin::signed(BOB), ALICE, 100));
+            assert_ok!(TrustLayer::back_contributor(RuntimeOrigin::signed(CHARLIE), ALICE, 200));
+            assert_ok!(TrustLayer::back_contributor(RuntimeOrigin::signed(JURORS[1]), ALICE, 100));
+            assert_eq!(TrustLayer::backing_offer(ALICE, BOB), Some(200));
+            assert_eq!(Balances::reserved_balance(BOB), 200);
+
+            // Offers bind nobody until the contributor accepts them
+            assert!(TrustLayer::backers(ALICE).is_empty());
+            assert_noop!(
+                TrustLayer::accept_backing(RuntimeOrigin::signed(BOB), ALICE),
+                Error::<Test>::NoBackingOffer
+            );
+            assert_noop!(
+                TrustLayer::cancel_backing_offer(RuntimeOrigin::signed(CHARLIE), ALICE, JURORS[1]),
+                Error::<Test>::NoBackingOffer
+            );
+            assert_ok!(TrustLayer::cancel_backing_offer(RuntimeOrigin::signed(ALICE), ALICE, JURORS[1]));
+            assert_eq!(Balances::reserved_balance(JURORS[1]), 0);
+            assert_ok!(TrustLayer::accept_backing(RuntimeOrigin::signed(ALICE), BOB));
+            assert_ok!(TrustLayer::accept_backing(RuntimeOrigin::signed(ALICE), CHARLIE));
+            assert_eq!(TrustLayer::backers(ALICE).to_vec(), vec![(BOB, 200), (CHARLIE, 200)]);
+            assert!(TrustLayer::backing_offer(ALICE, BOB).is_none());
+
+            let (alice, bob, charlie) = (Balances::free_balance(ALICE), Balances::free_balance(BOB), Balances::free_balance(CHARLIE));
+            assert_ok!(TrustLayer::pay_for_query(RuntimeOrigin::signed(JURORS[0]), ual_of(ALICE), 10));
+
+            // Backers split 20% of Alice's 350 provider fee equally
+            assert_eq!(Balances::free_balance(ALICE), alice + 280);
+            assert_eq!(Balances::free_balance(BOB), bob + 35);
+            assert_eq!(Balances::free_balance(CHARLIE), charlie + 35);
+            System::assert_has_event(Event::BackerRevenueShared { contributor: ALICE, amount: 70 }.into());
+
+            assert_ok!(TrustLayer::withdraw_backing(RuntimeOrigin::signed(CHARLIE), ALICE, 200));
+            assert_eq!(TrustLayer::backers(ALICE).to_vec(), vec![(BOB, 200)]);
+            assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+            assert_noop!(
+                TrustLayer::withdraw_backing(RuntimeOrigin::signed(CHARLIE), ALICE, 1),
+                Error::<Test>::NotBacking
+            );
+        });
+    }
+
+    #[test]
+    fn proven_fraud_slashes_backers_and_locks_withdrawals_meanwhile() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(TrustLayer::set_treasury(RuntimeOrigin::root(), TREASURY));
+            assert_ok!(TrustLayer::back_contributor(RuntimeOrigin::signed(CHARLIE), BOB, 400));
+            assert_ok!(TrustLayer::accept_backing(RuntimeOrigin::signed(BOB), CHARLIE));
+            verify_contribution(7, BOB);
+            assert_ok!(TrustLayer::report_contribution(RuntimeOrigin::signed(ALICE), 7, vec![], 200));
+            let claim_id = ClaimIdCounter::<Test>::get();
+
+            assert_noop!(
+                TrustLayer::withdraw_backing(RuntimeOrigin::signed(CHARLIE), BOB, 400),
+                Error::<Test>::BackingLocked
+            );
+
+            let treasury_before = Balances::free_balance(TREASURY);
+            let deadline = TrustLayer::claim(claim_id).unwrap().challenge_deadline;
+            System::set_block_number(deadline + 1);
+            assert_ok!(TrustLayer::finalize_claim(RuntimeOrigin::signed(ALICE), claim_id));
+            assert_ok!(TrustLayer::resolve_claim(RuntimeOrigin::root(), claim_id, ClaimResolution::Accepted));
+
+            // Backers lose the same 50% as the contributor's own stake
+            assert_eq!(TrustLayer::backers(BOB).to_vec(), vec![(CHARLIE, 200)]);
+            assert_eq!(Balances::reserved_balance(CHARLIE), 200);
+            assert_eq!(Balances::free_balance(TREASURY), treasury_before + 200);
+            System::assert_has_event(Event::BackingSlashed { backer: CHARLIE, contributor: BOB, amount: 200 }.into());
+
+            // Rejected claims are not the backed history's fault
+            <TrustLayer as OnReputationOffence<u64>>::on_offence(&BOB, ReputationOffence::RejectedClaim);
+            assert_eq!(Balances::reserved_balance(CHARLIE), 200);
+
+            assert_ok!(TrustLayer::withdraw_backing(RuntimeOrigin::signed(CHARLIE), BOB, 200));
+            assert!(!Backers::<Test>::contains_key(BOB));
+        });
+    }
+
+    #[test]
+    fn governance_sets_challenge_window_for_new_claims() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(TrustLayer::post_claim(RuntimeOrigin::signed(ALICE), ual_of(ALICE), vec![], 200));
+            let before = ClaimIdCounter::<Test>::get();
+            assert_eq!(TrustLayer::claim(before).unwrap().challenge_deadline, 1 + DEFAULT_CHALLENGE_WINDOW as u64);
+
+            assert_noop!(
+                TrustLayer::set_challenge_window(RuntimeOrigin::signed(ALICE), Some(50)),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            assert_noop!(
+                TrustLayer::set_challenge_window(RuntimeOrigin::root(), Some(0)),
+                Error::<Test>::InvalidChallengeWindow
+            );
+            assert_ok!(TrustLayer::set_challenge_window(RuntimeOrigin::root(), Some(50)));
+            System::assert_last_event(Event::ChallengeWindowSet { window: 50 }.into());
+
+            assert_ok!(TrustLayer::post_claim(RuntimeOrigin::signed(BOB), ual_of(BOB), vec![], 200));
+            let after = ClaimIdCounter::<Test>::get();
+            assert_eq!(TrustLayer::claim(after).unwrap().challenge_deadline, 51);
+            // Claims already open keep their deadline
+            assert_eq!(TrustLayer::claim(before).unwrap().challenge_deadline, 1 + DEFAULT_CHALLENGE_WINDOW as u64);
+
+            assert_ok!(TrustLayer::set_challenge_window(RuntimeOrigin::root(), None));
+            assert_eq!(TrustLayer::challenge_window(), DEFAULT_CHALLENGE_WINDOW as u64);
+        });
+    }
+
+    #[test]
+    fn query_revenue_accrues_per_ual_provider_and_treasury_period() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(TrustLayer::set_treasury(RuntimeOrigin::root(), TREASURY));
+            assert_ok!(TrustLayer::register_data_provider(RuntimeOrigin::signed(ALICE), ual_of(ALICE)));
+            assert_ok!(TrustLayer::set_custom_query_price(RuntimeOrigin::signed(ALICE), ual_of(ALICE), 100));
+
+            assert_ok!(TrustLayer::pay_for_query(RuntimeOrigin::signed(BOB), ual_of(ALICE), 10));
+            System::set_block_number(1 + REVENUE_PERIOD_BLOCKS as u64);
+            assert_ok!(TrustLayer::pay_for_query(RuntimeOrigin::signed(BOB), ual_of(CHARLIE), 10));
+
+            assert_eq!(TrustLayer::ual_revenue(bounded_ual_of(ALICE)), 100);
+            assert_eq!(TrustLayer::ual_revenue(bounded_ual_of(CHARLIE)), BaseQueryPrice::get());
+            assert_eq!(TrustLayer::provider_revenue(ALICE), 70);
+            assert_eq!(TrustLayer::treasury_income(0, 1), Some(30));
+            assert_eq!(TrustLayer::treasury_income(0, 1 + REVENUE_PERIOD_BLOCKS as u64), Some(30 + BaseQueryPrice::get()));
+            assert_eq!(TrustLayer::treasury_income(2, 1), Some(0));
+            // Ranges are capped at `MAX_INCOME_PERIODS` periods
+            let periods = MAX_INCOME_PERIODS as u64 * REVENUE_PERIOD_BLOCKS as u64;
+            assert!(TrustLayer::treasury_income(0, periods - 1).is_some());
+            assert_eq!(TrustLayer::treasury_income(0, periods), None);
+
+            // Metered queries accrue to the channel payee
+            assert_ok!(TrustLayer::open_payment_channel(RuntimeOrigin::signed(BOB), ALICE, 10, 100));
+            assert_ok!(TrustLayer::enable_metered_access(RuntimeOrigin::signed(BOB), ual_of(ALICE), ALICE));
+            assert_ok!(TrustLayer::record_metered_query(RuntimeOrigin::signed(ALICE), BOB, ual_of(ALICE)));
+            assert_eq!(TrustLayer::ual_revenue(bounded_ual_of(ALICE)), 100 + MeteredQueryFee::get());
+            assert_eq!(TrustLayer::provider_revenue(ALICE), 70 + MeteredQueryFee::get());
+        });
+    }
+
+    #[test]
+    fn contribution_claims_use_the_contribution_ual_and_report_it_on_resolution() {
+        new_test_ext().execute_with(|| {
+            verify_contribution(7, ALICE);
+            assert_noop!(
+                TrustLayer::post_contribution_claim(RuntimeOrigin::signed(BOB), 7, vec![], 200),
+                Error::<Test>::NotContributor
+            );
+            assert_noop!(
+                TrustLayer::post_contribution_claim(RuntimeOrigin::signed(ALICE), 8, vec![], 200),
+                Error::<Test>::NotContributor
+            );
+
+            assert_ok!(TrustLayer::post_contribution_claim(RuntimeOrigin::signed(ALICE), 7, vec![], 200));
+            let claim_id = ClaimIdCounter::<Test>::get();
+            System::assert_last_event(
+                Event::ContributionClaimPosted { claim_id, contributor: ALICE, contribution_id: 7 }.into(),
+            );
+            assert_eq!(TrustLayer::claim(claim_id).unwrap().claim_ual.to_vec(), contribution_ual(7));
+            assert_eq!(TrustLayer::contribution_claim(claim_id), Some(7));
+
+            assert_ok!(TrustLayer::challenge_claim(RuntimeOrigin::signed(BOB), claim_id, vec![], 200));
+            assert_ok!(TrustLayer::resolve_claim(RuntimeOrigin::root(), claim_id, ClaimResolution::Accepted));
+            assert_eq!(claim_verdicts(), vec![(ALICE, ClaimVerdict::Upheld)]);
+            assert_eq!(contribution_claim_verdicts(), vec![(7, ClaimVerdict::Upheld)]);
+            assert_eq!(TrustLayer::contribution_claim(claim_id), None);
+        });
+    }
+
+    #[test]
+    fn prepaid_queries_are_itemized_and_closed_into_period_statements() {
+
+        new_test_ext().execute_with(|| {
+            assert_ok!(TrustLayer::set_treasury(RuntimeOrigin::root(), TREASURY));
+            assert_noop!(
+                TrustLayer::pay_for_query_prepaid(RuntimeOrigin::signed(BOB), ual_of(ALICE), 10),
+                Error::<Test>::NoPrepaidAccount
+            );
+
+            assert_ok!(TrustLayer::top_up_prepaid(RuntimeOrigin::signed(BOB), 25));
+            assert_eq!(Balances::reserved_balance_named(&PREPAID_RESERVE_ID, &BOB), 25);
+            assert_ok!(TrustLayer::pay_for_query_prepaid(RuntimeOrigin::signed(BOB), ual_of(ALICE), 10));
+            assert_ok!(TrustLayer::pay_for_query_prepaid(RuntimeOrigin::signed(BOB), ual_of(CHARLIE), 10));
+            System::assert_last_event(
+                Event::QueryAccessGranted { querier: BOB, ual: bounded_ual_of(CHARLIE), expiry: 11 }.into(),
+            );
+            assert_noop!(
+                TrustLayer::pay_for_query_prepaid(RuntimeOrigin::signed(BOB), ual_of(ALICE), 10),
+                Error::<Test>::InsufficientPrepaidBalance
+            );
+            assert_eq!(TrustLayer::prepaid_account(BOB), Some(PrepaidAccount { balance: 5, period: 0 }));
+            assert_eq!(Balances::reserved_balance_named(&PREPAID_RESERVE_ID, &BOB), 5);
+            assert_eq!(Balances::free_balance(TREASURY), 1_000 + 20);
+
+            let items = TrustLayer::invoice_items(BOB, 0);
+            assert_eq!(items.len(), 2);
+            assert_eq!((items[0].amount, items[0].block, items[0].timestamp), (10, 1, 6_000));
+
+            // Statements are issued only once the billing period has ended
+            assert_noop!(
+                TrustLayer::close_billing_period(RuntimeOrigin::signed(CHARLIE), BOB),
+                Error::<Test>::BillingPeriodOpen
+            );
+            System::set_block_number(REVENUE_PERIOD_BLOCKS as u64);
+            assert_noop!(
+                TrustLayer::close_billing_period(RuntimeOrigin::signed(CHARLIE), BOB),
+                Error::<Test>::BillingPeriodOpen
+            );
+            System::set_block_number(BillingPeriod::get());
+            assert_ok!(TrustLayer::close_billing_period(RuntimeOrigin::signed(CHARLIE), BOB));
+            let statement_hash = BlakeTwo256::hash_of(&(BOB, 0u32, &items));
+            System::assert_last_event(
+                Event::BillingStatementClosed { organization: BOB, period: 0, items: 2, total: 20, statement_hash }
+                    .into(),
+            );
+            assert_eq!(
+                TrustLayer::billing_statement(BOB, 0),
+                Some(BillingStatement { items: 2, total: 20, statement_hash })
+            );
+            assert!(TrustLayer::invoice_items(BOB, 0).is_empty());
+            assert_eq!(TrustLayer::prepaid_account(BOB).unwrap().period, 1);
+
+            // Each period itemizes at most `MaxInvoiceItems` queries
+            assert_ok!(TrustLayer::top_up_prepaid(RuntimeOrigin::signed(BOB), 100));
+            for _ in 0..MaxInvoiceItems::get() {
+                assert_ok!(TrustLayer::pay_for_query_prepaid(RuntimeOrigin::signed(BOB), ual_of(ALICE), 10));
+            }
+            assert_noop!(
+                TrustLayer::pay_for_query_prepaid(RuntimeOrigin::signed(BOB), ual_of(ALICE), 10),
+                Error::<Test>::InvoiceFull
+            );
+
+            // A query in a later period closes the previous one first
+            System::set_block_number(2 * BillingPeriod::get());
+            assert_ok!(TrustLayer::pay_for_query_prepaid(RuntimeOrigin::signed(BOB), ual_of(ALICE), 10));
+            assert_eq!(TrustLayer::billing_statement(BOB, 1).map(|statement| statement.items), Some(3));
+            assert_eq!(TrustLayer::invoice_items(BOB, 2).len(), 1);
+
+            assert_noop!(
+                TrustLayer::withdraw_prepaid(RuntimeOrigin::signed(BOB), 100),
+                Error::<Test>::InsufficientPrepaidBalance
+            );
+            assert_ok!(TrustLayer::withdraw_prepaid(RuntimeOrigin::signed(BOB), 65));
+            assert_eq!(Balances::reserved_balance_named(&PREPAID_RESERVE_ID, &BOB), 0);
+
+            // Statements are kept for `BillingStatementRetention` periods, then anyone may prune them
+            assert_noop!(
+                TrustLayer::prune_billing_statement(RuntimeOrigin::signed(CHARLIE), BOB, 0),
+                Error::<Test>::BillingStatementRetained
+            );
+            System::set_block_number((BillingStatementRetention::get() as u64 + 1) * BillingPeriod::get());
+            assert_ok!(TrustLayer::prune_billing_statement(RuntimeOrigin::signed(CHARLIE), BOB, 0));
+            System::assert_last_event(Event::BillingStatementPruned { organization: BOB, period: 0 }.into());
+            assert_eq!(TrustLayer::billing_statement(BOB, 0), None);
+            assert_noop!(
+                TrustLayer::prune_billing_statement(RuntimeOrigin::signed(CHARLIE), BOB, 0),
+                Error::<Test>::NoBillingStatement
+            );
+            assert!(TrustLayer::billing_statement(BOB, 1).is_some());
+        });
+    }
+}
@@ -0,0 +1,629 @@
+// Weights for pallet_trust_layer
+//
+// Weights follow the `frame-benchmarking` template and must be regenerated against the
+// reference hardware whenever the extrinsics change:
+//
+// ./target/release/dotrep-node benchmark pallet \
+//     --chain=dev \
+//     --steps=50 \
+//     --repeat=20 \
+//     --pallet=pallet_trust_layer \
+//     --extrinsic=* \
+//     --execution=wasm \
+//     --wasm-execution=compiled \
+//     --output=pallets/trust-layer/src/weights.rs \
+//     --template=.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_trust_layer.
+pub trait WeightInfo {
+	fn stake_tokens() -> Weight;
+	fn unstake_tokens() -> Weight;
+	fn pay_for_query() -> Weight;
+	fn open_payment_channel() -> Weight;
+	fn close_payment_channel() -> Weight;
+	fn set_custom_query_price() -> Weight;
+	fn set_treasury() -> Weight;
+	fn post_claim(e: u32, ) -> Weight;
+	fn challenge_claim(e: u32, p: u32, ) -> Weight;
+	fn resolve_claim() -> Weight;
+	fn register_data_provider() -> Weight;
+	fn deregister_data_provider() -> Weight;
+	fn set_asset_config() -> Weight;
+	fn remove_asset_config() -> Weight;
+	fn stake_asset() -> Weight;
+	fn unstake_asset() -> Weight;
+	fn pay_for_query_with_asset() -> Weight;
+	fn open_asset_channel() -> Weight;
+	fn close_asset_channel() -> Weight;
+	fn finalize_claim() -> Weight;
+	fn join_jury_pool(p: u32, ) -> Weight;
+	fn leave_jury_pool(p: u32, ) -> Weight;
+	fn cast_jury_vote() -> Weight;
+	fn tally_jury(j: u32, ) -> Weight;
+	fn appeal_claim(p: u32, ) -> Weight;
+	fn pay_for_queries(n: u32, ) -> Weight;
+	fn set_pricing_config() -> Weight;
+	fn enable_metered_access() -> Weight;
+	fn record_metered_query() -> Weight;
+	fn disable_metered_access() -> Weight;
+	fn sweep_claims(n: u32, ) -> Weight;
+	fn set_slashing_policy() -> Weight;
+	fn report_contribution(e: u32, ) -> Weight;
+	fn back_contributor() -> Weight;
+	fn accept_backing(b: u32, ) -> Weight;
+	fn cancel_backing_offer() -> Weight;
+	fn withdraw_backing(b: u32, ) -> Weight;
+	fn set_challenge_window() -> Weight;
+	fn post_contribution_claim(e: u32, ) -> Weight;
+	fn top_up_prepaid() -> Weight;
+	fn pay_for_query_prepaid() -> Weight;
+	fn close_billing_period(n: u32, ) -> Weight;
+	fn withdraw_prepaid() -> Weight;
+	fn prune_billing_statement() -> Weight;
+	fn draw_jury(p: u32, ) -> Weight;
+	fn commit_jury_seed() -> Weight;
+	fn reveal_jury_seed() -> Weight;
+	fn slash_unrevealed_seed() -> Weight;
+}
+
+/// Weights for pallet_trust_layer using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn stake_tokens() -> Weight {
+		Weight::from_parts(32_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn unstake_tokens() -> Weight {
+		Weight::from_parts(31_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn pay_for_query() -> Weight {
+		Weight::from_parts(58_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	fn open_payment_channel() -> Weight {
+		Weight::from_parts(30_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn close_payment_channel() -> Weight {
+		Weight::from_parts(28_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn set_custom_query_price() -> Weight {
+		Weight::from_parts(16_000_000, 3541)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn set_treasury() -> Weight {
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// The range of component `e` is `[0, T::MaxEvidenceItems::get()]`.
+	fn post_claim(e: u32, ) -> Weight {
+		Weight::from_parts(44_000_000, 3593)
+			.saturating_add(Weight::from_parts(1_150_000, 0).saturating_mul(e.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// The range of component `e` is `[0, T::MaxEvidenceItems::get()]`.
+	/// The range of component `p` is `[0, T::MaxJurorPool::get()]`.
+	fn challenge_claim(e: u32, p: u32, ) -> Weight {
+		Weight::from_parts(52_000_000, 3593)
+			.saturating_add(Weight::from_parts(1_150_000, 0).saturating_mul(e.into()))
+			.saturating_add(Weight::from_parts(2_400_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+	}
+	fn resolve_claim() -> Weight {
+		Weight::from_parts(61_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	fn register_data_provider() -> Weight {
+		Weight::from_parts(18_000_000, 3541)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn deregister_data_provider() -> Weight {
+		Weight::from_parts(16_000_000, 3541)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn set_asset_config() -> Weight {
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn remove_asset_config() -> Weight {
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn stake_asset() -> Weight {
+		Weight::from_parts(55_000_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn unstake_asset() -> Weight {
+		Weight::from_parts(54_000_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn pay_for_query_with_asset() -> Weight {
+		Weight::from_parts(70_000_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	fn open_asset_channel() -> Weight {
+		Weight::from_parts(53_000_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn close_asset_channel() -> Weight {
+		Weight::from_parts(51_000_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn finalize_claim() -> Weight {
+		Weight::from_parts(40_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// The range of component `p` is `[0, T::MaxJurorPool::get()]`.
+	fn join_jury_pool(p: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 3593)
+			.saturating_add(Weight::from_parts(90_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// The range of component `p` is `[0, T::MaxJurorPool::get()]`.
+	fn leave_jury_pool(p: u32, ) -> Weight {
+		Weight::from_parts(29_000_000, 3593)
+			.saturating_add(Weight::from_parts(110_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn cast_jury_vote() -> Weight {
+		Weight::from_parts(22_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// The range of component `j` is `[0, T::MaxJurySize::get()]`.
+	fn tally_jury(j: u32, ) -> Weight {
+		Weight::from_parts(48_000_000, 6196)
+			.saturating_add(Weight::from_parts(14_500_000, 0).saturating_mul(j.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(j.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(j.into())))
+	}
+	/// The range of component `p` is `[0, T::MaxJurorPool::get()]`.
+	fn appeal_claim(p: u32, ) -> Weight {
+		Weight::from_parts(60_000_000, 3593)
+			.saturating_add(Weight::from_parts(2_400_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+	}
+	/// The range of component `n` is `[0, T::MaxQueryBatch::get()]`.
+	fn pay_for_queries(n: u32, ) -> Weight {
+		Weight::from_parts(36_000_000, 6196)
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	fn set_pricing_config() -> Weight {
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn enable_metered_access() -> Weight {
+		Weight::from_parts(19_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn record_metered_query() -> Weight {
+		Weight::from_parts(39_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn disable_metered_access() -> Weight {
+		Weight::from_parts(17_000_000, 3541)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// The range of component `n` is `[0, T::MaxClaimsSweptPerBlock::get()]`.
+	fn sweep_claims(n: u32, ) -> Weight {
+		Weight::from_parts(5_000_000, 1489)
+			.saturating_add(Weight::from_parts(42_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(n.into())))
+	}
+	fn set_slashing_policy() -> Weight {
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// The range of component `e` is `[0, T::MaxEvidenceItems::get()]`.
+	fn report_contribution(e: u32, ) -> Weight {
+		Weight::from_parts(52_000_000, 3593)
+			.saturating_add(Weight::from_parts(1_150_000, 0).saturating_mul(e.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	fn back_contributor() -> Weight {
+		Weight::from_parts(27_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// The range of component `b` is `[0, T::MaxBackers::get()]`.
+	fn accept_backing(b: u32, ) -> Weight {
+		Weight::from_parts(24_000_000, 3593)
+			.saturating_add(Weight::from_parts(60_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn cancel_backing_offer() -> Weight {
+		Weight::from_parts(26_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// The range of component `b` is `[0, T::MaxBackers::get()]`.
+	fn withdraw_backing(b: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 3593)
+			.saturating_add(Weight::from_parts(60_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn set_challenge_window() -> Weight {
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// The range of component `e` is `[0, T::MaxEvidenceItems::get()]`.
+	fn post_contribution_claim(e: u32, ) -> Weight {
+		Weight::from_parts(48_000_000, 3593)
+			.saturating_add(Weight::from_parts(1_150_000, 0).saturating_mul(e.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	fn top_up_prepaid() -> Weight {
+		Weight::from_parts(30_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn pay_for_query_prepaid() -> Weight {
+		Weight::from_parts(66_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// The range of component `n` is `[0, T::MaxInvoiceItems::get()]`.
+	fn close_billing_period(n: u32, ) -> Weight {
+		Weight::from_parts(18_000_000, 3593)
+			.saturating_add(Weight::from_parts(2_400_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn withdraw_prepaid() -> Weight {
+		Weight::from_parts(29_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn prune_billing_statement() -> Weight {
+		Weight::from_parts(14_000_000, 3541)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// The range of component `p` is `[0, T::MaxJurorPool::get()]`.
+	fn draw_jury(p: u32, ) -> Weight {
+		Weight::from_parts(41_000_000, 3593)
+			.saturating_add(Weight::from_parts(2_400_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+	}
+	fn commit_jury_seed() -> Weight {
+		Weight::from_parts(17_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn reveal_jury_seed() -> Weight {
+		Weight::from_parts(21_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn slash_unrevealed_seed() -> Weight {
+		Weight::from_parts(38_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn stake_tokens() -> Weight {
+		Weight::from_parts(32_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn unstake_tokens() -> Weight {
+		Weight::from_parts(31_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn pay_for_query() -> Weight {
+		Weight::from_parts(58_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	fn open_payment_channel() -> Weight {
+		Weight::from_parts(30_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn close_payment_channel() -> Weight {
+		Weight::from_parts(28_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn set_custom_query_price() -> Weight {
+		Weight::from_parts(16_000_000, 3541)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_treasury() -> Weight {
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// The range of component `e` is `[0, MaxEvidenceItems]`.
+	fn post_claim(e: u32, ) -> Weight {
+		Weight::from_parts(44_000_000, 3593)
+			.saturating_add(Weight::from_parts(1_150_000, 0).saturating_mul(e.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// The range of component `e` is `[0, MaxEvidenceItems]`.
+	/// The range of component `p` is `[0, MaxJurorPool]`.
+	fn challenge_claim(e: u32, p: u32, ) -> Weight {
+		Weight::from_parts(52_000_000, 3593)
+			.saturating_add(Weight::from_parts(1_150_000, 0).saturating_mul(e.into()))
+			.saturating_add(Weight::from_parts(2_400_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(p.into())))
+	}
+	fn resolve_claim() -> Weight {
+		Weight::from_parts(61_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	fn register_data_provider() -> Weight {
+		Weight::from_parts(18_000_000, 3541)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn deregister_data_provider() -> Weight {
+		Weight::from_parts(16_000_000, 3541)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn set_asset_config() -> Weight {
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn remove_asset_config() -> Weight {
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn stake_asset() -> Weight {
+		Weight::from_parts(55_000_000, 6208)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn unstake_asset() -> Weight {
+		Weight::from_parts(54_000_000, 6208)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn pay_for_query_with_asset() -> Weight {
+		Weight::from_parts(70_000_000, 6208)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	fn open_asset_channel() -> Weight {
+		Weight::from_parts(53_000_000, 6208)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn close_asset_channel() -> Weight {
+		Weight::from_parts(51_000_000, 6208)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn finalize_claim() -> Weight {
+		Weight::from_parts(40_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// The range of component `p` is `[0, MaxJurorPool]`.
+	fn join_jury_pool(p: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 3593)
+			.saturating_add(Weight::from_parts(90_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// The range of component `p` is `[0, MaxJurorPool]`.
+	fn leave_jury_pool(p: u32, ) -> Weight {
+		Weight::from_parts(29_000_000, 3593)
+			.saturating_add(Weight::from_parts(110_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn cast_jury_vote() -> Weight {
+		Weight::from_parts(22_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// The range of component `j` is `[0, MaxJurySize]`.
+	fn tally_jury(j: u32, ) -> Weight {
+		Weight::from_parts(48_000_000, 6196)
+			.saturating_add(Weight::from_parts(14_500_000, 0).saturating_mul(j.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(j.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(j.into())))
+	}
+	/// The range of component `p` is `[0, MaxJurorPool]`.
+	fn appeal_claim(p: u32, ) -> Weight {
+		Weight::from_parts(60_000_000, 3593)
+			.saturating_add(Weight::from_parts(2_400_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(p.into())))
+	}
+	/// The range of component `n` is `[0, MaxQueryBatch]`.
+	fn pay_for_queries(n: u32, ) -> Weight {
+		Weight::from_parts(36_000_000, 6196)
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	fn set_pricing_config() -> Weight {
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn enable_metered_access() -> Weight {
+		Weight::from_parts(19_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn record_metered_query() -> Weight {
+		Weight::from_parts(39_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn disable_metered_access() -> Weight {
+		Weight::from_parts(17_000_000, 3541)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// The range of component `n` is `[0, MaxClaimsSweptPerBlock]`.
+	fn sweep_claims(n: u32, ) -> Weight {
+		Weight::from_parts(5_000_000, 1489)
+			.saturating_add(Weight::from_parts(42_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(n.into())))
+	}
+	fn set_slashing_policy() -> Weight {
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// The range of component `e` is `[0, MaxEvidenceItems]`.
+	fn report_contribution(e: u32, ) -> Weight {
+		Weight::from_parts(52_000_000, 3593)
+			.saturating_add(Weight::from_parts(1_150_000, 0).saturating_mul(e.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	fn back_contributor() -> Weight {
+		Weight::from_parts(27_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// The range of component `b` is `[0, MaxBackers]`.
+	fn accept_backing(b: u32, ) -> Weight {
+		Weight::from_parts(24_000_000, 3593)
+			.saturating_add(Weight::from_parts(60_000, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn cancel_backing_offer() -> Weight {
+		Weight::from_parts(26_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// The range of component `b` is `[0, MaxBackers]`.
+	fn withdraw_backing(b: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 3593)
+			.saturating_add(Weight::from_parts(60_000, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn set_challenge_window() -> Weight {
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// The range of component `e` is `[0, MaxEvidenceItems]`.
+	fn post_contribution_claim(e: u32, ) -> Weight {
+		Weight::from_parts(48_000_000, 3593)
+			.saturating_add(Weight::from_parts(1_150_000, 0).saturating_mul(e.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	fn top_up_prepaid() -> Weight {
+		Weight::from_parts(30_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn pay_for_query_prepaid() -> Weight {
+		Weight::from_parts(66_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// The range of component `n` is `[0, MaxInvoiceItems]`.
+	fn close_billing_period(n: u32, ) -> Weight {
+		Weight::from_parts(18_000_000, 3593)
+			.saturating_add(Weight::from_parts(2_400_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn withdraw_prepaid() -> Weight {
+		Weight::from_parts(29_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn prune_billing_statement() -> Weight {
+		Weight::from_parts(14_000_000, 3541)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// The range of component `p` is `[0, T::MaxJurorPool::get()]`.
+	fn draw_jury(p: u32, ) -> Weight {
+		Weight::from_parts(41_000_000, 3593)
+			.saturating_add(Weight::from_parts(2_400_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(p.into())))
+	}
+	fn commit_jury_seed() -> Weight {
+		Weight::from_parts(17_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn reveal_jury_seed() -> Weight {
+		Weight::from_parts(21_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn slash_unrevealed_seed() -> Weight {
+		Weight::from_parts(38_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+}
@@ -0,0 +1,72 @@
+//! Bounded iteration over storage
+//!
+//! Storage iterators run inside block execution must stop at a known bound. Wrapping
+//! one with [`BoundedIterExt::bounded`] yields at most `limit` items and remembers
+//! whether the iterator had more, so callers can fail with an error instead of
+//! silently working on a truncated set.
+
+use sp_std::vec::Vec;
+
+/// The iterator had more items than its bound
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BoundExceeded;
+
+/// Iterator yielding at most `limit` items of `inner`
+pub struct BoundedIter<I> {
+    inner: I,
+    remaining: u32,
+    exceeded: bool,
+}
+
+impl<I: Iterator> BoundedIter<I> {
+    pub fn new(inner: I, limit: u32) -> Self {
+        Self { inner, remaining: limit, exceeded: false }
+    }
+
+    /// Whether the inner iterator was found to have more than `limit` items
+    pub fn exceeded(&self) -> bool {
+        self.exceeded
+    }
+
+    /// Fold every item, or fail if there are more than `limit`
+    pub fn fold_bounded<B>(mut self, init: B, f: impl FnMut(B, I::Item) -> B) -> Result<B, BoundExceeded> {
+        let folded = self.by_ref().fold(init, f);
+        if self.exceeded {
+            return Err(BoundExceeded);
+        }
+        Ok(folded)
+    }
+
+    /// Collect every item, or fail if there are more than `limit`
+    pub fn collect_bounded(self) -> Result<Vec<I::Item>, BoundExceeded> {
+        self.fold_bounded(Vec::new(), |mut items, item| {
+            items.push(item);
+            items
+        })
+    }
+}
+
+impl<I: Iterator> Iterator for BoundedIter<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.remaining == 0 {
+            // Probe one item past the bound to tell "exactly `limit`" from "more"
+            if !self.exceeded && self.inner.next().is_some() {
+                self.exceeded = true;
+            }
+            return None;
+        }
+        self.remaining -= 1;
+        self.inner.next()
+    }
+}
+
+/// Bound any iterator
+pub trait BoundedIterExt: Iterator + Sized {
+    fn bounded(self, limit: u32) -> BoundedIter<Self> {
+        BoundedIter::new(self, limit)
+    }
+}
+
+impl<I: Iterator> BoundedIterExt for I {}
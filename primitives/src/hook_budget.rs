@@ -0,0 +1,115 @@
+//! Block weight shared by the pallets' hook sweeps
+//!
+//! Deadline processing, decay projection, score commitments, claim finalization and
+//! the steps of multi-block migrations all run in `on_initialize` and compete for the
+//! same block. Each sweep is a [`HookTask`] with a quota of the maximum block weight,
+//! set by governance through a [`HookQuotaProvider`]; the sweep draws on a
+//! [`HookBudget`] of that size and leaves whatever does not fit for the next block.
+//! A migration's first step, taken in the upgrade block, draws on the `Migrations`
+//! quota as well. Pallets running the same task share its budget: each notes what it
+//! used with [`HookQuotaProvider::note_hook_usage`], and later budgets of the block
+//! are reduced by it. The quotas of all tasks never add up to more than
+//! [`MAX_HOOK_SHARE`], so the sweeps together stay within that share of the block
+//! whatever their backlog.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{Perbill, RuntimeDebug, Weight};
+
+/// Share of the maximum block weight all hook sweeps together may use
+pub const MAX_HOOK_SHARE: Perbill = Perbill::from_percent(50);
+
+/// Sweeps run from a pallet hook
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, Ord, PartialOrd, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum HookTask {
+    /// Stale and escalated contributions in the reputation pallet
+    VerificationDeadlines,
+    /// Decay warnings in the reputation pallet
+    DecaySweep,
+    /// Finalization of unchallenged claims in the trust layer
+    ClaimSweep,
+    /// Steps of multi-block storage migrations
+    Migrations,
+    /// Steps of the commitment to the reputation scores
+    ScoreCommitment,
+}
+
+impl HookTask {
+    /// Every task, in the order their quotas are listed
+    pub const ALL: [HookTask; 5] = [
+        HookTask::VerificationDeadlines,
+        HookTask::DecaySweep,
+        HookTask::ClaimSweep,
+        HookTask::Migrations,
+        HookTask::ScoreCommitment,
+    ];
+
+    /// Quota of the task until governance sets one
+    pub fn default_quota(&self) -> Perbill {
+        match self {
+            HookTask::VerificationDeadlines => Perbill::from_percent(5),
+            HookTask::DecaySweep => Perbill::from_percent(10),
+            HookTask::ClaimSweep => Perbill::from_percent(10),
+            HookTask::Migrations => Perbill::from_percent(15),
+            HookTask::ScoreCommitment => Perbill::from_percent(5),
+        }
+    }
+}
+
+/// Source of the hook quotas, implemented by the pallet storing them
+pub trait HookQuotaProvider {
+    /// Share of the maximum block weight `task` may use per block
+    fn hook_quota(task: HookTask) -> Perbill;
+
+    /// Weight of `task` already used in the current block, by any pallet
+    fn hook_usage(_task: HookTask) -> Weight {
+        Weight::zero()
+    }
+
+    /// Record that `weight` of `task`'s quota was used in the current block
+    fn note_hook_usage(_task: HookTask, _weight: Weight) {}
+
+    /// Budget left to `task` in a block whose maximum weight is `max_block`
+    fn hook_budget(task: HookTask, max_block: Weight) -> HookBudget {
+        HookBudget::new((Self::hook_quota(task) * max_block).saturating_sub(Self::hook_usage(task)))
+    }
+}
+
+impl HookQuotaProvider for () {
+    fn hook_quota(task: HookTask) -> Perbill {
+        task.default_quota()
+    }
+}
+
+/// Weight a sweep may still use in the current block
+#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug)]
+pub struct HookBudget {
+    limit: Weight,
+    consumed: Weight,
+}
+
+impl HookBudget {
+    pub fn new(limit: Weight) -> Self {
+        Self { limit, consumed: Weight::zero() }
+    }
+
+    /// Account `weight` if it fits in what is left; false, consuming nothing, otherwise
+    pub fn try_consume(&mut self, weight: Weight) -> bool {
+        let consumed = self.consumed.saturating_add(weight);
+        if !consumed.all_lte(self.limit) {
+            return false;
+        }
+        self.consumed = consumed;
+        true
+    }
+
+    /// Weight consumed so far
+    pub fn consumed(&self) -> Weight {
+        self.consumed
+    }
+
+    /// Weight left
+    pub fn remaining(&self) -> Weight {
+        self.limit.saturating_sub(self.consumed)
+    }
+}
@@ -0,0 +1,124 @@
+//! Types and interfaces shared by the DotRep pallets
+//!
+//! The reputation, trust-layer and governance pallets exchange scores, UALs and
+//! contribution kinds. Defining them once here keeps their encodings and meaning
+//! from drifting apart between pallets.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{ConstU32, Hash},
+    BoundedVec, RuntimeDebug,
+};
+use sp_std::vec::Vec;
+
+pub mod bounded_iter;
+pub mod hook_budget;
+pub mod sortition;
+pub mod traits;
+pub use bounded_iter::{BoundExceeded, BoundedIter, BoundedIterExt};
+pub use hook_budget::{HookBudget, HookQuotaProvider, HookTask, MAX_HOOK_SHARE};
+pub use traits::*;
+
+/// Reputation score of an account; negative once penalties exceed earned points
+pub type Score = i32;
+
+/// Identifier of a contribution submitted to the reputation pallet
+pub type ContributionId = u64;
+
+/// Maximum length of a UAL
+pub const MAX_UAL_LEN: u32 = 256;
+
+/// Uniform Asset Locator of a DKG Knowledge Asset, e.g. `did:dkg:otp/2043/0x.../1`
+pub type Ual = BoundedVec<u8, ConstU32<MAX_UAL_LEN>>;
+
+/// Maximum length of a chain identifier
+pub const MAX_CHAIN_ID_LEN: u32 = 64;
+
+/// Identifier of a chain registered for cross-chain reputation queries
+pub type ChainId = BoundedVec<u8, ConstU32<MAX_CHAIN_ID_LEN>>;
+
+/// Identity of a verified human, shared by all accounts of the same person
+pub type PersonhoodId = [u8; 32];
+
+/// Maximum length of a skill tag
+pub const MAX_SKILL_TAG_LEN: u32 = 32;
+
+/// Skill an account declares or a contribution requires, e.g. `rust` or `substrate`
+pub type SkillTag = BoundedVec<u8, ConstU32<MAX_SKILL_TAG_LEN>>;
+
+/// Contribution types supported by the reputation system
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Ord, PartialOrd, Debug, TypeInfo, MaxEncodedLen)]
+pub enum ContributionType {
+    IssueComment,
+    PullRequest,
+    CodeReview,
+    Documentation,
+    BugReport,
+    CodeCommit,
+    /// Mentorship session attested by the mentee
+    Mentorship,
+}
+
+impl ContributionType {
+    /// Every contribution type
+    pub const ALL: [ContributionType; 7] = [
+        ContributionType::IssueComment,
+        ContributionType::PullRequest,
+        ContributionType::CodeReview,
+        ContributionType::Documentation,
+        ContributionType::BugReport,
+        ContributionType::CodeCommit,
+        ContributionType::Mentorship,
+    ];
+
+    /// Name used in published Knowledge Assets
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContributionType::IssueComment => "IssueComment",
+            ContributionType::PullRequest => "PullRequest",
+            ContributionType::CodeReview => "CodeReview",
+            ContributionType::Documentation => "Documentation",
+            ContributionType::BugReport => "BugReport",
+            ContributionType::CodeCommit => "CodeCommit",
+            ContributionType::Mentorship => "Mentorship",
+        }
+    }
+}
+
+/// Reputation state of an account as published to the DKG
+#[derive(Clone, Default, PartialEq, RuntimeDebug)]
+pub struct ReputationSnapshot {
+    pub score: Score,
+    /// Points earned per contribution type
+    pub breakdown: Vec<(&'static str, Score)>,
+    /// Proof hashes of verified contributions, preceded by the Merkle root of any
+    /// archived contribution records
+    pub contribution_digests: Vec<[u8; 32]>,
+}
+
+/// Kinds of subjects DotRep events are indexed under
+///
+/// Every pallet deposits its events with one topic per account or id they concern,
+/// so indexers can look them up through `frame_system::EventTopics` without
+/// scanning blocks.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum Topic {
+    Account,
+    Contribution,
+    Claim,
+    Proposal,
+    Ual,
+    Asset,
+    Query,
+    Project,
+}
+
+impl Topic {
+    /// Topic of events concerning `subject`: the hash of `(kind, subject)`
+    pub fn of<H: Hash>(self, subject: &impl Encode) -> H::Output {
+        H::hash_of(&(self, subject))
+    }
+}
@@ -0,0 +1,69 @@
+//! Reputation-weighted sortition
+//!
+//! Committees drawn at random (claim juries, verifier panels, tie-breaks) should favour
+//! reputable accounts without handing the draw to the few highest scores. [`select`]
+//! draws accounts without replacement with probability proportional to the square
+//! root of their score, from a seed supplied by the caller's randomness source.
+
+use crate::Score;
+use codec::{Decode, Encode};
+use sp_runtime::traits::{Hash, TrailingZeroInput};
+use sp_std::vec::Vec;
+
+/// Integer square root, rounded down
+pub fn isqrt(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+
+    // Newton's iteration from above converges to the floor of the root
+    let mut x = n;
+    let mut y = x / 2 + x % 2;
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+/// Sortition weight of a score: its square root, at least 1
+///
+/// Every candidate keeps a chance, so a committee can be filled whenever there are
+/// enough candidates; callers filter out ineligible accounts beforehand.
+pub fn weight(score: Score) -> u64 {
+    isqrt(score.max(0) as u64).max(1)
+}
+
+/// Draw `k` of `candidates`, each `(account, score)`, without replacement
+///
+/// Each draw picks a remaining candidate with probability proportional to its
+/// [`weight`], using the value `H` hashes from `(seed, draw)`. The same seed and
+/// candidates in the same order always give the same committee. Returns fewer than
+/// `k` accounts only if there are fewer candidates.
+pub fn select<H: Hash, A>(seed: &impl Encode, candidates: Vec<(A, Score)>, k: usize) -> Vec<A> {
+    let mut remaining: Vec<(A, u64)> =
+        candidates.into_iter().map(|(account, score)| (account, weight(score))).collect();
+    let mut total: u64 = remaining.iter().map(|(_, weight)| weight).sum();
+    let mut selected = Vec::with_capacity(k.min(remaining.len()));
+
+    for draw in 0..k.min(remaining.len()) as u32 {
+        let hash = H::hash_of(&(seed, draw));
+        let mut point = u64::decode(&mut TrailingZeroInput::new(hash.as_ref())).unwrap_or_default() % total;
+
+        let index = remaining
+            .iter()
+            .position(|(_, weight)| match point.checked_sub(*weight) {
+                Some(rest) => {
+                    point = rest;
+                    false
+                },
+                None => true,
+            })
+            .unwrap_or(remaining.len() - 1);
+        let (account, weight) = remaining.swap_remove(index);
+        total -= weight;
+        selected.push(account);
+    }
+
+    selected
+}
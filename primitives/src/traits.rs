@@ -0,0 +1,313 @@
+//! Cross-pallet interfaces between the DotRep pallets
+//!
+//! The reputation pallet implements or consumes these traits, the trust layer and
+//! governance the other side, so the runtime can wire economic consequences to
+//! reputation events without the pallets depending on each other.
+
+use crate::{ContributionId, ContributionType, PersonhoodId, ReputationSnapshot, Score, SkillTag, Ual};
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{DispatchError, DispatchResult, Percent, RuntimeDebug, Weight};
+use sp_std::vec::Vec;
+
+/// Kinds of reputation fraud that carry economic consequences
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ReputationOffence {
+    /// Account was penalized for Sybil behaviour
+    SybilPenalty,
+    /// A previously verified contribution was overturned
+    OverturnedVerification,
+    /// A trust-layer claim by the account was rejected
+    RejectedClaim,
+}
+
+/// Handler notified once a reputation offence is finalized
+pub trait OnReputationOffence<AccountId> {
+    fn on_offence(offender: &AccountId, offence: ReputationOffence);
+}
+
+impl<AccountId> OnReputationOffence<AccountId> for () {
+    fn on_offence(_offender: &AccountId, _offence: ReputationOffence) {}
+}
+
+/// Source of an additional credibility boost (in percent) for an account,
+/// e.g. the trust layer's staking boost
+pub trait CredibilityBoostProvider<AccountId> {
+    fn credibility_boost_percent(who: &AccountId) -> u32;
+}
+
+impl<AccountId> CredibilityBoostProvider<AccountId> for () {
+    fn credibility_boost_percent(_who: &AccountId) -> u32 {
+        0
+    }
+}
+
+/// Read access to an account's reputation score for other pallets
+pub trait ReputationProvider<AccountId> {
+    /// Score including any credibility boost
+    fn reputation_of(who: &AccountId) -> Score;
+
+    /// Weight of one `reputation_of` call, charged by callers
+    fn reputation_of_weight() -> Weight {
+        Weight::zero()
+    }
+
+    /// Score earned from contributions alone (defaults to `reputation_of`)
+    fn raw_reputation_of(who: &AccountId) -> Score {
+        Self::reputation_of(who)
+    }
+
+    /// Whether the account is on probation after a Sybil penalty or fraud slash
+    fn is_on_probation(_who: &AccountId) -> bool {
+        false
+    }
+}
+
+impl<AccountId> ReputationProvider<AccountId> for () {
+    fn reputation_of(_who: &AccountId) -> Score {
+        0
+    }
+}
+
+/// Outcome of a resolved trust-layer claim, from the submitter's point of view
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ClaimVerdict {
+    /// The claim survived a challenge
+    Upheld,
+    /// The claim was rejected
+    Rejected,
+}
+
+/// Handler notified when a trust-layer claim is resolved, e.g. to adjust reputation
+pub trait OnClaimResolved<AccountId> {
+    fn on_claim_resolved(submitter: &AccountId, verdict: ClaimVerdict);
+
+    /// A claim anchored to one of the submitter's contributions was resolved; handled
+    /// like any other claim unless overridden
+    fn on_contribution_claim_resolved(submitter: &AccountId, contribution_id: ContributionId, verdict: ClaimVerdict) {
+        Self::on_claim_resolved(submitter, verdict)
+    }
+}
+
+impl<AccountId> OnClaimResolved<AccountId> for () {
+    fn on_claim_resolved(_submitter: &AccountId, _verdict: ClaimVerdict) {}
+}
+
+/// Accounts bound to an off-chain identity, e.g. a GitHub handle attested by a DKG publisher
+pub trait AccountBindings<AccountId> {
+    fn is_bound(who: &AccountId) -> bool;
+}
+
+impl<AccountId> AccountBindings<AccountId> for () {
+    fn is_bound(_who: &AccountId) -> bool {
+        false
+    }
+}
+
+/// Verified-human identities of accounts, e.g. registrar-judged identities or personhood credentials
+pub trait PersonhoodProvider<AccountId> {
+    fn personhood_of(who: &AccountId) -> Option<PersonhoodId>;
+}
+
+impl<AccountId> PersonhoodProvider<AccountId> for () {
+    fn personhood_of(_who: &AccountId) -> Option<PersonhoodId> {
+        None
+    }
+}
+
+/// Open disputes against an account's contributions, e.g. trust-layer fraud reports
+pub trait ContributionDisputes<AccountId> {
+    fn has_open_disputes(who: &AccountId) -> bool;
+}
+
+impl<AccountId> ContributionDisputes<AccountId> for () {
+    fn has_open_disputes(_who: &AccountId) -> bool {
+        false
+    }
+}
+
+/// Notified when an account's declared skill tags change, e.g. to index verifiers by skill
+pub trait OnSkillTagsUpdated<AccountId> {
+    fn on_skill_tags_updated(who: &AccountId, old: &[SkillTag], new: &[SkillTag]);
+}
+
+impl<AccountId> OnSkillTagsUpdated<AccountId> for () {
+    fn on_skill_tags_updated(_who: &AccountId, _old: &[SkillTag], _new: &[SkillTag]) {}
+}
+
+/// Verified contributions that can be reported as fraudulent, e.g. through trust-layer claims
+pub trait ContributionRegistry<AccountId> {
+    /// Contributor of a verified contribution; `None` if it does not exist or is unverified
+    fn verified_contributor(contribution_id: ContributionId) -> Option<AccountId>;
+
+    /// Overturn a contribution found fraudulent, removing the reputation it awarded
+    ///
+    /// The caller settles the economic consequences, so no offence is reported.
+    fn overturn_fraudulent(contribution_id: ContributionId) -> DispatchResult;
+
+    /// UAL of the Knowledge Asset a contribution was published as, if any
+    fn contribution_ual(contribution_id: ContributionId) -> Option<Ual>;
+}
+
+impl<AccountId> ContributionRegistry<AccountId> for () {
+    fn verified_contributor(_contribution_id: ContributionId) -> Option<AccountId> {
+        None
+    }
+
+    fn overturn_fraudulent(_contribution_id: ContributionId) -> DispatchResult {
+        Ok(())
+    }
+
+    fn contribution_ual(_contribution_id: ContributionId) -> Option<Ual> {
+        None
+    }
+}
+
+/// Contributions submitted on an account's behalf by another pallet, e.g. delivered
+/// grant milestones, which then go through the usual verifier sign-off
+pub trait ContributionSubmitter<AccountId> {
+    /// Submit a pending contribution for `who` with the blake2-256 `proof` of the work
+    fn submit_contribution(
+        who: &AccountId,
+        proof: [u8; 32],
+        contribution_type: ContributionType,
+        weight: u8,
+    ) -> Result<ContributionId, DispatchError>;
+
+    /// Whether a submitted contribution was rejected or no longer exists, so its work
+    /// can be submitted again
+    fn is_withdrawn(contribution_id: ContributionId) -> bool;
+}
+
+impl<AccountId> ContributionSubmitter<AccountId> for () {
+    fn submit_contribution(
+        _who: &AccountId,
+        _proof: [u8; 32],
+        _contribution_type: ContributionType,
+        _weight: u8,
+    ) -> Result<ContributionId, DispatchError> {
+        Err(DispatchError::Other("No contribution registry"))
+    }
+
+    fn is_withdrawn(_contribution_id: ContributionId) -> bool {
+        false
+    }
+}
+
+/// Source of the reputation state published to the DKG
+pub trait ReputationSnapshotProvider<AccountId> {
+    fn reputation_snapshot(who: &AccountId) -> ReputationSnapshot;
+
+    /// Weight of reading and serializing the largest snapshot, charged by callers
+    fn reputation_snapshot_weight() -> Weight {
+        Weight::zero()
+    }
+}
+
+impl<AccountId> ReputationSnapshotProvider<AccountId> for () {
+    fn reputation_snapshot(_who: &AccountId) -> ReputationSnapshot {
+        ReputationSnapshot::default()
+    }
+}
+
+/// Upper bound of normalized credit scores
+pub const MAX_CREDIT_SCORE: u16 = 1000;
+
+/// Creditworthiness of an account derived from its reputation
+#[derive(Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CreditProfile<BlockNumber> {
+    /// Reputation normalized to `0..=MAX_CREDIT_SCORE`
+    pub score: u16,
+    /// Share of scored accounts ranked below the account
+    pub percentile: Percent,
+    /// Blocks since the account's first contribution
+    pub account_age: BlockNumber,
+    /// Number of verified contributions
+    pub verified_contributions: u32,
+}
+
+/// Reputation as a credit signal for lending and under-collateralized credit pallets
+///
+/// `VERSION` is bumped whenever the meaning of a `CreditProfile` field changes, so
+/// consumers can check at integration time that they read the semantics they expect.
+/// Version 1 is described by the field docs above.
+pub trait CreditScoreProvider<AccountId, BlockNumber> {
+    /// Version of the interface implemented
+    const VERSION: u32;
+
+    fn credit_profile(who: &AccountId) -> CreditProfile<BlockNumber>;
+
+    /// Normalized score alone
+    fn credit_score(who: &AccountId) -> u16 {
+        Self::credit_profile(who).score
+    }
+}
+
+impl<AccountId, BlockNumber: Default> CreditScoreProvider<AccountId, BlockNumber> for () {
+    const VERSION: u32 = 1;
+
+    fn credit_profile(_who: &AccountId) -> CreditProfile<BlockNumber> {
+        CreditProfile::default()
+    }
+}
+
+/// Executes `Custom` governance proposals by tag, e.g. registry updates or paranet config
+///
+/// Runtimes register handlers as a tuple; a proposal runs the first handler that
+/// handles its tag.
+pub trait CustomProposalHandler {
+    /// Whether a handler is registered for `tag`
+    fn handles(tag: &[u8]) -> bool;
+
+    /// Most weight `execute` may use, charged up front when a proposal is executed
+    fn max_weight() -> Weight;
+
+    /// Execute an approved proposal tagged `tag`; only called if `handles(tag)`
+    fn execute(tag: &[u8], data: &[u8]) -> DispatchResult;
+}
+
+impl CustomProposalHandler for () {
+    fn handles(_tag: &[u8]) -> bool {
+        false
+    }
+
+    fn max_weight() -> Weight {
+        Weight::zero()
+    }
+
+    fn execute(_tag: &[u8], _data: &[u8]) -> DispatchResult {
+        Err(DispatchError::Other("No handler for custom proposal tag"))
+    }
+}
+
+macro_rules! impl_custom_proposal_handler_for_tuples {
+    ($($handler:ident),+) => {
+        impl<$($handler: CustomProposalHandler),+> CustomProposalHandler for ($($handler,)+) {
+            fn handles(tag: &[u8]) -> bool {
+                $($handler::handles(tag))||+
+            }
+
+            fn max_weight() -> Weight {
+                Weight::zero()$(.max($handler::max_weight()))+
+            }
+
+            fn execute(tag: &[u8], data: &[u8]) -> DispatchResult {
+                $(
+                    if $handler::handles(tag) {
+                        return $handler::execute(tag, data);
+                    }
+                )+
+                <() as CustomProposalHandler>::execute(tag, data)
+            }
+        }
+    };
+}
+
+impl_custom_proposal_handler_for_tuples!(A);
+impl_custom_proposal_handler_for_tuples!(A, B);
+impl_custom_proposal_handler_for_tuples!(A, B, C);
+impl_custom_proposal_handler_for_tuples!(A, B, C, D);
+impl_custom_proposal_handler_for_tuples!(A, B, C, D, E);
+impl_custom_proposal_handler_for_tuples!(A, B, C, D, E, F);
+impl_custom_proposal_handler_for_tuples!(A, B, C, D, E, F, G);
+impl_custom_proposal_handler_for_tuples!(A, B, C, D, E, F, G, H);
@@ -0,0 +1,186 @@
+//! DotRep JSON-RPC extensions
+//!
+//! Thin wrappers around `DkgQueryApi` so gateways can resolve an account's DKG
+//! assets over RPC without decoding storage themselves, and a subscription pushing
+//! an account's score and tier as new best blocks change them.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use dotrep_primitives::Score;
+use futures::{future, stream, FutureExt, StreamExt};
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    proc_macros::rpc,
+    types::{
+        error::{CallError, ErrorObject},
+        SubscriptionResult,
+    },
+    SubscriptionSink,
+};
+use pallet_reputation::{
+    dkg_integration::{DkgAssets, PublishStatus},
+    jsonld::ReputationTier,
+    runtime_api::{DkgQueryApi as DkgQueryRuntimeApi, ReputationApi as ReputationRuntimeApi},
+    ContributionId,
+};
+use sc_client_api::BlockchainEvents;
+use sc_rpc::SubscriptionTaskExecutor;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{traits::SpawnNamed, Bytes, H256};
+use sp_runtime::traits::Block as BlockT;
+
+/// Error code of failed runtime API calls
+const RUNTIME_ERROR: i32 = 1;
+
+/// Score of an account as of a new best block, pushed by `dotrep_subscribeReputation`
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReputationUpdate<BlockHash> {
+    /// Best block the score was read at
+    pub block: BlockHash,
+    /// Score including any credibility boost
+    pub score: Score,
+    /// Tier derived from the score
+    pub tier: String,
+    /// Whether the tier differs from the previous update; `true` for the first one
+    pub tier_changed: bool,
+}
+
+#[rpc(client, server)]
+pub trait DotRepApi<BlockHash, AccountId, BlockNumber> {
+    /// UAL of the account's reputation Knowledge Asset
+    #[method(name = "dotrep_developerUal")]
+    fn developer_ual(&self, account: AccountId, at: Option<BlockHash>) -> RpcResult<Option<Bytes>>;
+
+    /// UAL of an individually published contribution
+    #[method(name = "dotrep_contributionUal")]
+    fn contribution_ual(&self, contribution_id: ContributionId, at: Option<BlockHash>) -> RpcResult<Option<Bytes>>;
+
+    /// Assertions anchored in blocks `from..=to`, at most `limit` of them
+    #[method(name = "dotrep_anchoredAssertions")]
+    fn anchored_assertions(
+        &self,
+        from: BlockNumber,
+        to: BlockNumber,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(Bytes, H256, BlockNumber)>>;
+
+    /// Publish status of the account's latest queued snapshot
+    #[method(name = "dotrep_publishStatus")]
+    fn publish_status(&self, account: AccountId, at: Option<BlockHash>) -> RpcResult<Option<PublishStatus>>;
+
+    /// Everything the chain knows about the account's DKG assets
+    #[method(name = "dotrep_dkgAssets")]
+    fn dkg_assets(&self, account: AccountId, at: Option<BlockHash>) -> RpcResult<DkgAssets<BlockNumber>>;
+
+    /// Current score of the account, then every change to it in new best blocks
+    #[subscription(
+        name = "dotrep_subscribeReputation" => "dotrep_reputation",
+        unsubscribe = "dotrep_unsubscribeReputation",
+        item = ReputationUpdate<BlockHash>
+    )]
+    fn subscribe_reputation(&self, account: AccountId);
+}
+
+/// Implementation of `DotRepApiServer` backed by the runtime API
+pub struct DotRep<C, Block> {
+    client: Arc<C>,
+    executor: SubscriptionTaskExecutor,
+    _marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> DotRep<C, Block> {
+    pub fn new(client: Arc<C>, executor: SubscriptionTaskExecutor) -> Self {
+        Self { client, executor, _marker: Default::default() }
+    }
+}
+
+fn runtime_error(e: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+    CallError::Custom(ErrorObject::owned(RUNTIME_ERROR, "Runtime API call failed", Some(format!("{:?}", e)))).into()
+}
+
+#[async_trait]
+impl<C, Block, AccountId, BlockNumber> DotRepApiServer<<Block as BlockT>::Hash, AccountId, BlockNumber>
+    for DotRep<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + BlockchainEvents<Block> + Send + Sync + 'static,
+    C::Api: DkgQueryRuntimeApi<Block, AccountId, BlockNumber> + ReputationRuntimeApi<Block, AccountId>,
+    AccountId: Codec + Clone + DeserializeOwned + Send + Sync + 'static,
+    BlockNumber: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    fn developer_ual(&self, account: AccountId, at: Option<Block::Hash>) -> RpcResult<Option<Bytes>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let ual = self.client.runtime_api().developer_ual(at, account).map_err(runtime_error)?;
+        Ok(ual.map(Into::into))
+    }
+
+    fn contribution_ual(&self, contribution_id: ContributionId, at: Option<Block::Hash>) -> RpcResult<Option<Bytes>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let ual = self.client.runtime_api().contribution_ual(at, contribution_id).map_err(runtime_error)?;
+        Ok(ual.map(Into::into))
+    }
+
+    fn anchored_assertions(
+        &self,
+        from: BlockNumber,
+        to: BlockNumber,
+        limit: u32,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<(Bytes, H256, BlockNumber)>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let anchored = self.client.runtime_api().anchored_assertions(at, from, to, limit).map_err(runtime_error)?;
+        Ok(anchored.into_iter().map(|(ual, root, block)| (ual.into(), root, block)).collect())
+    }
+
+    fn publish_status(&self, account: AccountId, at: Option<Block::Hash>) -> RpcResult<Option<PublishStatus>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client.runtime_api().publish_status(at, account).map_err(runtime_error)
+    }
+
+    fn dkg_assets(&self, account: AccountId, at: Option<Block::Hash>) -> RpcResult<DkgAssets<BlockNumber>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client.runtime_api().dkg_assets(at, account).map_err(runtime_error)
+    }
+
+    fn subscribe_reputation(&self, mut sink: SubscriptionSink, account: AccountId) -> SubscriptionResult {
+        let client = self.client.clone();
+        let score_at = move |at: Block::Hash| client.runtime_api().reputation_of(at, account.clone()).ok();
+
+        let best = self.client.info().best_hash;
+        let Some(initial) = score_at(best) else {
+            let _ = sink.reject(ErrorObject::owned(RUNTIME_ERROR, "Runtime API call failed", None::<()>));
+            return Ok(());
+        };
+
+        // Only new best blocks that change the score or its tier are pushed
+        let mut last = initial;
+        let changes = self
+            .client
+            .import_notification_stream()
+            .filter(|notification| future::ready(notification.is_new_best))
+            .filter_map(move |notification| {
+                let update = score_at(notification.hash).filter(|score| *score != last).map(|score| {
+                    let tier_changed = ReputationTier::from_score(score) != ReputationTier::from_score(last);
+                    last = score;
+                    reputation_update(notification.hash, score, tier_changed)
+                });
+                future::ready(update)
+            });
+        let updates = stream::once(future::ready(reputation_update(best, initial, true))).chain(changes);
+
+        let fut = async move {
+            sink.pipe_from_stream(updates).await;
+        };
+        self.executor.spawn("dotrep-rpc-subscription", Some("rpc"), fut.boxed());
+        Ok(())
+    }
+}
+
+fn reputation_update<BlockHash>(block: BlockHash, score: Score, tier_changed: bool) -> ReputationUpdate<BlockHash> {
+    ReputationUpdate { block, score, tier: ReputationTier::from_score(score).as_str().into(), tier_changed }
+}
@@ -0,0 +1,36 @@
+//! `pallet-contracts` chain extension serving the reputation oracle to ink! contracts
+//!
+//! The extension function id selects a [`OracleFunction`]; the input buffer holds the
+//! SCALE-encoded account and the SCALE-encoded answer is written to the output buffer.
+//! The call returns `0` on success or an [`OracleError`] code.
+
+use crate::{Reputation, Runtime};
+use pallet_contracts::chain_extension::{ChainExtension, Environment, Ext, InitState, RetVal};
+use pallet_reputation::oracle::{OracleError, OracleFunction};
+use sp_runtime::DispatchError;
+
+/// Largest input read from a contract: a SCALE-encoded account id
+const MAX_INPUT_LEN: u32 = 32;
+
+#[derive(Default)]
+pub struct ReputationExtension;
+
+impl ChainExtension<Runtime> for ReputationExtension {
+    fn call<E: Ext<T = Runtime>>(&mut self, env: Environment<E, InitState>) -> Result<RetVal, DispatchError> {
+        let Some(function) = OracleFunction::from_id(env.func_id()) else {
+            return Ok(RetVal::Converging(OracleError::UnknownFunction as u32));
+        };
+
+        let mut env = env.buf_in_buf_out();
+        env.charge_weight(Reputation::oracle_call_weight())?;
+
+        let input = env.read(MAX_INPUT_LEN)?;
+        match Reputation::oracle_call(function, &input) {
+            Ok(output) => {
+                env.write(&output, false, None)?;
+                Ok(RetVal::Converging(0))
+            }
+            Err(error) => Ok(RetVal::Converging(error as u32)),
+        }
+    }
+}
@@ -0,0 +1,154 @@
+//! Genesis presets for the dev, local and testnet chain specs
+
+use crate::{
+    AccountId, AssetsConfig, AuraConfig, Balance, BalancesConfig, BlockNumber, DkgPublishersConfig, GenesisConfig,
+    GovernanceConfig, GrandpaConfig, ReputationConfig, Signature, SudoConfig, SystemConfig, TrustLayerConfig,
+    DAYS, MILLIUNIT, TRAC, UNIT,
+};
+use sp_consensus_aura::sr25519::AuthorityId as AuraId;
+use sp_consensus_grandpa::AuthorityId as GrandpaId;
+use sp_core::{sr25519, Pair, Public};
+use sp_runtime::traits::{IdentifyAccount, Verify};
+use sp_std::prelude::*;
+
+/// Native balance of every endowed account
+pub const ENDOWMENT: Balance = 1_000_000 * UNIT;
+
+/// TRAC balance of every endowed account
+pub const TRAC_ENDOWMENT: Balance = 10_000 * UNIT;
+
+/// Minimum TRAC stake accepted by the trust layer
+pub const TRAC_MINIMUM_STAKE: Balance = 100 * UNIT;
+
+/// Base price of a premium reputation query paid in TRAC
+pub const TRAC_QUERY_PRICE: Balance = 10 * MILLIUNIT;
+
+/// Term of the genesis council, matching a regular term of four voting periods
+pub const GENESIS_COUNCIL_TERM: BlockNumber = 28 * DAYS;
+
+type AccountPublic = <Signature as Verify>::Signer;
+
+/// Generate a crypto pair from seed.
+pub fn get_from_seed<TPublic: Public>(seed: &str) -> <TPublic::Pair as Pair>::Public {
+    TPublic::Pair::from_string(&format!("//{}", seed), None)
+        .expect("static values are valid; qed")
+        .public()
+}
+
+/// Generate an account ID from seed.
+pub fn get_account_id_from_seed<TPublic: Public>(seed: &str) -> AccountId
+where
+    AccountPublic: From<<TPublic::Pair as Pair>::Public>,
+{
+    AccountPublic::from(get_from_seed::<TPublic>(seed)).into_account()
+}
+
+/// Generate an Aura authority key.
+pub fn authority_keys_from_seed(s: &str) -> (AuraId, GrandpaId) {
+    (get_from_seed::<AuraId>(s), get_from_seed::<GrandpaId>(s))
+}
+
+fn well_known_accounts() -> Vec<AccountId> {
+    ["Alice", "Bob", "Charlie", "Dave", "Eve", "Ferdie"]
+        .iter()
+        .flat_map(|name| {
+            [
+                get_account_id_from_seed::<sr25519::Public>(name),
+                get_account_id_from_seed::<sr25519::Public>(&format!("{}//stash", name)),
+            ]
+        })
+        .collect()
+}
+
+/// Starting reputation of the development accounts, enough to verify and propose
+fn dev_reputation() -> Vec<(AccountId, i32)> {
+    [("Alice", 900), ("Bob", 600), ("Charlie", 300), ("Dave", 150)]
+        .iter()
+        .map(|(name, score)| (get_account_id_from_seed::<sr25519::Public>(name), *score))
+        .collect()
+}
+
+/// Council seated at genesis on the test chains
+fn dev_council() -> Vec<AccountId> {
+    ["Alice", "Bob", "Charlie"].iter().map(|name| get_account_id_from_seed::<sr25519::Public>(name)).collect()
+}
+
+/// Single-authority development chain: Alice authors, is sudo and publishes to the DKG
+///
+/// The well-known accounts start with reputation so verification, governance and
+/// jury flows can be exercised right away.
+pub fn development_genesis(wasm_binary: &[u8]) -> GenesisConfig {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let mut genesis = testnet_genesis(
+        wasm_binary,
+        vec![authority_keys_from_seed("Alice")],
+        alice.clone(),
+        well_known_accounts(),
+        vec![alice],
+        dev_council(),
+    );
+    genesis.reputation.initial_reputation = dev_reputation();
+    genesis
+}
+
+/// Two-authority local chain (Alice and Bob)
+pub fn local_testnet_genesis(wasm_binary: &[u8]) -> GenesisConfig {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+    testnet_genesis(
+        wasm_binary,
+        vec![authority_keys_from_seed("Alice"), authority_keys_from_seed("Bob")],
+        alice.clone(),
+        well_known_accounts(),
+        vec![alice, bob],
+        dev_council(),
+    )
+}
+
+/// Genesis of a test network
+///
+/// Endowed accounts receive native tokens and TRAC; `dkg_publishers` may confirm DKG
+/// publishes submitted by their off-chain workers. The root key also collects the
+/// trust layer's query fees until governance appoints a treasury.
+pub fn testnet_genesis(
+    wasm_binary: &[u8],
+    initial_authorities: Vec<(AuraId, GrandpaId)>,
+    root_key: AccountId,
+    endowed_accounts: Vec<AccountId>,
+    dkg_publishers: Vec<AccountId>,
+    council: Vec<AccountId>,
+) -> GenesisConfig {
+    let mut dkg_publishers = dkg_publishers;
+    dkg_publishers.sort();
+    dkg_publishers.dedup();
+
+    GenesisConfig {
+        system: SystemConfig { code: wasm_binary.to_vec() },
+        balances: BalancesConfig {
+            balances: endowed_accounts.iter().cloned().map(|k| (k, ENDOWMENT)).collect(),
+        },
+        aura: AuraConfig { authorities: initial_authorities.iter().map(|x| x.0.clone()).collect() },
+        grandpa: GrandpaConfig { authorities: initial_authorities.iter().map(|x| (x.1.clone(), 1)).collect() },
+        sudo: SudoConfig { key: Some(root_key.clone()) },
+        transaction_payment: Default::default(),
+        assets: AssetsConfig {
+            assets: vec![(TRAC, root_key.clone(), true, 1)],
+            metadata: vec![(TRAC, b"OriginTrail TRAC".to_vec(), b"TRAC".to_vec(), 18)],
+            accounts: endowed_accounts.iter().cloned().map(|k| (TRAC, k, TRAC_ENDOWMENT)).collect(),
+        },
+        reputation: ReputationConfig { initial_reputation: Vec::new(), pipeline: Default::default() },
+        dkg_publishers: DkgPublishersConfig {
+            members: dkg_publishers.try_into().expect("at most MaxMembers DKG publishers"),
+            phantom: Default::default(),
+        },
+        // Filled from the governance council by `CouncilMembershipChanged`
+        council: Default::default(),
+        governance: GovernanceConfig { council, council_term_end: GENESIS_COUNCIL_TERM },
+        trust_layer: TrustLayerConfig {
+            treasury: Some(root_key),
+            assets: vec![(TRAC, TRAC_MINIMUM_STAKE, TRAC_QUERY_PRICE)],
+            pricing: None,
+            challenge_window: None,
+        },
+    }
+}
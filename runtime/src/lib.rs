@@ -0,0 +1,1220 @@
+//! DotRep runtime
+//!
+//! Composes the reputation, governance, trust-layer and DKG integration pallets
+//! into a launchable Aura/GRANDPA solochain. Chain-spec presets live in `genesis`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// `construct_runtime!` does a lot of recursion and requires us to increase the limit to 256.
+#![recursion_limit = "256"]
+
+// Make the WASM binary available.
+#[cfg(feature = "std")]
+include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
+
+mod chain_extension;
+#[cfg(feature = "std")]
+pub mod genesis;
+
+use codec::{Decode, Encode};
+use frame_support::{
+    construct_runtime,
+    dispatch::GetDispatchInfo,
+    ensure, parameter_types,
+    traits::{
+        AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, EitherOf, Nothing,
+        UnfilteredDispatchable,
+    },
+    weights::{
+        constants::{RocksDbWeight, WEIGHT_REF_TIME_PER_SECOND},
+        IdentityFee, Weight,
+    },
+    PalletId,
+};
+use frame_system::{EnsureRoot, EnsureSigned, EnsureSignedBy};
+use pallet_grandpa::AuthorityId as GrandpaId;
+use pallet_reputation::dkg_integration;
+use pallet_transaction_payment::{ConstFeeMultiplier, CurrencyAdapter, Multiplier};
+use sp_api::impl_runtime_apis;
+use sp_consensus_aura::sr25519::AuthorityId as AuraId;
+use sp_core::{crypto::KeyTypeId, OpaqueMetadata, H256};
+use sp_runtime::{
+    create_runtime_str, generic, impl_opaque_keys,
+    traits::{
+        AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One,
+        SaturatedConversion, StaticLookup, Verify,
+    },
+    transaction_validity::{TransactionSource, TransactionValidity},
+    ApplyExtrinsicResult, DispatchError, DispatchResult, MultiSignature, Perbill, Percent,
+};
+use sp_std::prelude::*;
+#[cfg(feature = "std")]
+use sp_version::NativeVersion;
+use sp_version::RuntimeVersion;
+
+/// An index to a block.
+pub type BlockNumber = u32;
+
+/// Alias to 512-bit hash when used in the context of a transaction signature on the chain.
+pub type Signature = MultiSignature;
+
+/// Some way of identifying an account on the chain.
+pub type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::AccountId;
+
+/// Balance of an account.
+pub type Balance = u128;
+
+/// Index of a transaction in the chain.
+pub type Index = u32;
+
+/// A hash of some data used by the chain.
+pub type Hash = H256;
+
+/// Identifier of a `pallet-assets` asset (TRAC, NEURO, ...)
+pub type AssetId = u32;
+
+/// Opaque types used by the CLI to instantiate machinery that doesn't need to know
+/// the specifics of the runtime.
+pub mod opaque {
+    use super::*;
+
+    pub use sp_runtime::OpaqueExtrinsic as UncheckedExtrinsic;
+
+    /// Opaque block header type.
+    pub type Header = generic::Header<BlockNumber, BlakeTwo256>;
+    /// Opaque block type.
+    pub type Block = generic::Block<Header, UncheckedExtrinsic>;
+    /// Opaque block identifier type.
+    pub type BlockId = generic::BlockId<Block>;
+
+    impl_opaque_keys! {
+        pub struct SessionKeys {
+            pub aura: Aura,
+            pub grandpa: Grandpa,
+        }
+    }
+}
+
+#[sp_version::runtime_version]
+pub const VERSION: RuntimeVersion = RuntimeVersion {
+    spec_name: create_runtime_str!("dotrep"),
+    impl_name: create_runtime_str!("dotrep"),
+    authoring_version: 1,
+    spec_version: 100,
+    impl_version: 1,
+    apis: RUNTIME_API_VERSIONS,
+    transaction_version: 1,
+    state_version: 1,
+};
+
+/// Block time: 6 seconds
+pub const MILLISECS_PER_BLOCK: u64 = 6000;
+pub const SLOT_DURATION: u64 = MILLISECS_PER_BLOCK;
+
+pub const MINUTES: BlockNumber = 60_000 / (MILLISECS_PER_BLOCK as BlockNumber);
+pub const HOURS: BlockNumber = MINUTES * 60;
+pub const DAYS: BlockNumber = HOURS * 24;
+
+/// Native token units (12 decimals)
+pub const UNIT: Balance = 1_000_000_000_000;
+pub const MILLIUNIT: Balance = UNIT / 1_000;
+pub const MICROUNIT: Balance = UNIT / 1_000_000;
+
+/// Existential deposit.
+pub const EXISTENTIAL_DEPOSIT: Balance = MILLIUNIT;
+
+/// Asset id of TRAC, created at genesis
+pub const TRAC: AssetId = 1;
+
+/// The version information used to identify this runtime when compiled natively.
+#[cfg(feature = "std")]
+pub fn native_version() -> NativeVersion {
+    NativeVersion { runtime_version: VERSION, can_author_with: Default::default() }
+}
+
+const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
+
+parameter_types! {
+    pub const BlockHashCount: BlockNumber = 2400;
+    pub const Version: RuntimeVersion = VERSION;
+    /// We allow for 2 seconds of compute with a 6 second average block time.
+    pub BlockWeights: frame_system::limits::BlockWeights =
+        frame_system::limits::BlockWeights::with_sensible_defaults(
+            Weight::from_parts(2u64 * WEIGHT_REF_TIME_PER_SECOND, u64::MAX),
+            NORMAL_DISPATCH_RATIO,
+        );
+    pub BlockLength: frame_system::limits::BlockLength = frame_system::limits::BlockLength
+        ::max_with_normal_ratio(5 * 1024 * 1024, NORMAL_DISPATCH_RATIO);
+    pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Runtime {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = BlockWeights;
+    type BlockLength = BlockLength;
+    type AccountId = AccountId;
+    type RuntimeCall = RuntimeCall;
+    type Lookup = AccountIdLookup<AccountId, ()>;
+    type Index = Index;
+    type BlockNumber = BlockNumber;
+    type Hash = Hash;
+    type Hashing = BlakeTwo256;
+    type Header = generic::Header<BlockNumber, BlakeTwo256>;
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type BlockHashCount = BlockHashCount;
+    type DbWeight = RocksDbWeight;
+    type Version = Version;
+    type PalletInfo = PalletInfo;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type SystemWeightInfo = ();
+    type SS58Prefix = SS58Prefix;
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
+
+impl pallet_aura::Config for Runtime {
+    type AuthorityId = AuraId;
+    type DisabledValidators = ();
+    type MaxAuthorities = ConstU32<32>;
+}
+
+impl pallet_grandpa::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type MaxAuthorities = ConstU32<32>;
+    type MaxSetIdSessionEntries = ConstU64<0>;
+    type KeyOwnerProof = sp_core::Void;
+    type EquivocationReportSystem = ();
+}
+
+impl pallet_timestamp::Config for Runtime {
+    /// A timestamp: milliseconds since the unix epoch.
+    type Moment = u64;
+    type OnTimestampSet = Aura;
+    type MinimumPeriod = ConstU64<{ SLOT_DURATION / 2 }>;
+    type WeightInfo = ();
+}
+
+impl pallet_balances::Config for Runtime {
+    type MaxLocks = ConstU32<50>;
+    type MaxReserves = ConstU32<50>;
+    type ReserveIdentifier = [u8; 8];
+    type Balance = Balance;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<EXISTENTIAL_DEPOSIT>;
+    type AccountStore = System;
+    type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub FeeMultiplier: Multiplier = Multiplier::one();
+}
+
+impl pallet_transaction_payment::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type OnChargeTransaction = CurrencyAdapter<Balances, ()>;
+    type OperationalFeeMultiplier = ConstU8<5>;
+    type WeightToFee = IdentityFee<Balance>;
+    type LengthToFee = IdentityFee<Balance>;
+    type FeeMultiplierUpdate = ConstFeeMultiplier<FeeMultiplier>;
+}
+
+impl pallet_sudo::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+}
+
+parameter_types! {
+    pub const DepositPerItem: Balance = 10 * MILLIUNIT;
+    pub const DepositPerByte: Balance = 10 * MICROUNIT;
+    pub const DeletionQueueDepth: u32 = 128;
+    /// A quarter of a block's weight for lazily deleting contract storage.
+    pub DeletionWeightLimit: Weight = Perbill::from_percent(25) * BlockWeights::get().max_block;
+    pub Schedule: pallet_contracts::Schedule<Runtime> = Default::default();
+}
+
+impl pallet_contracts::Config for Runtime {
+    type Time = Timestamp;
+    type Randomness = RandomnessCollectiveFlip;
+    type Currency = Balances;
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    // Contracts may not dispatch runtime calls
+    type CallFilter = Nothing;
+    type DepositPerItem = DepositPerItem;
+    type DepositPerByte = DepositPerByte;
+    type CallStack = [pallet_contracts::Frame<Self>; 5];
+    type WeightPrice = pallet_transaction_payment::Pallet<Self>;
+    type WeightInfo = pallet_contracts::weights::SubstrateWeight<Self>;
+    // Reputation and tier lookups for ink! contracts
+    type ChainExtension = chain_extension::ReputationExtension;
+    type DeletionQueueDepth = DeletionQueueDepth;
+    type DeletionWeightLimit = DeletionWeightLimit;
+    type Schedule = Schedule;
+    type AddressGenerator = pallet_contracts::DefaultAddressGenerator;
+    type MaxCodeLen = ConstU32<{ 123 * 1024 }>;
+    type MaxStorageKeyLen = ConstU32<128>;
+    type UnsafeUnstableInterface = ConstBool<false>;
+    type MaxDebugBufferLen = ConstU32<{ 2 * 1024 * 1024 }>;
+}
+
+// TRAC/NEURO and other foreign assets used by the trust layer
+impl pallet_assets::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type AssetId = AssetId;
+    type AssetIdParameter = codec::Compact<AssetId>;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type AssetDeposit = ConstU128<{ 100 * UNIT }>;
+    type AssetAccountDeposit = ConstU128<{ UNIT }>;
+    type MetadataDepositBase = ConstU128<{ 10 * UNIT }>;
+    type MetadataDepositPerByte = ConstU128<{ MILLIUNIT }>;
+    type ApprovalDeposit = ConstU128<{ MILLIUNIT }>;
+    type StringLimit = ConstU32<50>;
+    type Freezer = ();
+    type Extra = ();
+    type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+    type RemoveItemsLimit = ConstU32<1000>;
+    type CallbackHandle = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
+// Accounts whose signed transactions the DKG integration accepts as publisher reports
+impl pallet_membership::Config<pallet_membership::Instance1> for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type AddOrigin = EnsureRoot<AccountId>;
+    type RemoveOrigin = EnsureRoot<AccountId>;
+    type SwapOrigin = EnsureRoot<AccountId>;
+    type ResetOrigin = EnsureRoot<AccountId>;
+    type PrimeOrigin = EnsureRoot<AccountId>;
+    type MembershipInitialized = ();
+    type MembershipChanged = ();
+    type MaxMembers = ConstU32<16>;
+    type WeightInfo = pallet_membership::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const CouncilMotionDuration: BlockNumber = 3 * DAYS;
+}
+
+// Motions of the governance pallet's council, whose members it keeps in sync on rotation
+type CouncilCollective = pallet_collective::Instance1;
+impl pallet_collective::Config<CouncilCollective> for Runtime {
+    type RuntimeOrigin = RuntimeOrigin;
+    type Proposal = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type MotionDuration = CouncilMotionDuration;
+    type MaxProposals = ConstU32<100>;
+    // `CouncilMembers` of the governance pallet holds at most 50
+    type MaxMembers = ConstU32<50>;
+    type DefaultVote = pallet_collective::PrimeDefaultVote;
+    type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
+    // Membership is managed by the governance pallet
+    type SetMembersOrigin = EnsureRoot<AccountId>;
+}
+
+/// A motion passed by more than half of the council
+pub type EnsureCouncilMajority = pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>;
+
+// On-chain identities judged by registrars back the reputation pallet's personhood checks
+impl pallet_identity::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type BasicDeposit = ConstU128<{ 10 * UNIT }>;
+    type FieldDeposit = ConstU128<{ 250 * MILLIUNIT }>;
+    type SubAccountDeposit = ConstU128<{ 2 * UNIT }>;
+    type MaxSubAccounts = ConstU32<100>;
+    type MaxAdditionalFields = ConstU32<100>;
+    type MaxRegistrars = ConstU32<20>;
+    type Slashed = ();
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type RegistrarOrigin = EnsureRoot<AccountId>;
+    type WeightInfo = pallet_identity::weights::SubstrateWeight<Runtime>;
+}
+
+/// An account is a verified human once a registrar judged its identity `KnownGood` or
+/// `Reasonable`; the identity is keyed by account, so registrars must refuse to judge a
+/// second account of someone they already judged. Sub-accounts carry no judgement.
+pub struct IdentityPersonhood;
+impl pallet_reputation::PersonhoodProvider<AccountId> for IdentityPersonhood {
+    fn personhood_of(who: &AccountId) -> Option<pallet_reputation::PersonhoodId> {
+        let registration = Identity::identity(who)?;
+        registration
+            .judgements
+            .iter()
+            .any(|(_, judgement)| {
+                matches!(judgement, pallet_identity::Judgement::KnownGood | pallet_identity::Judgement::Reasonable)
+            })
+            .then(|| sp_core::hashing::blake2_256(&who.encode()))
+    }
+}
+
+parameter_types! {
+    pub const MaxContributionsPerAccount: u32 = 1_000;
+    pub const MinReputation: i32 = -1_000;
+    pub const MaxReputation: i32 = 10_000;
+    pub const MinReputationToVerify: i32 = 100;
+    pub const MinVerifications: u32 = 3;
+    pub const MaxPendingContributions: u32 = 20;
+    pub const MaxCredibilityBoost: u32 = 50;
+    pub const MaxAttestationBoost: u32 = 25;
+    pub const ClaimUpheldReward: i32 = 25;
+    pub const ClaimRejectedPenalty: i32 = 40;
+    pub const ProxySubmissionPeriod: BlockNumber = DAYS;
+    pub const MaintainerAttestationWeight: u32 = 2;
+    pub const SkillMatchMultiplier: u32 = 2;
+    pub const MaxHackathonProjects: u32 = 8;
+    pub const MaxHackathonWinners: u32 = 10;
+    pub const MentorshipSessionWeight: u8 = 50;
+    pub const ReferralBonus: i32 = 10;
+    pub const ReferralQualifyingContributions: u32 = 3;
+    pub const ReferralEpoch: BlockNumber = 7 * DAYS;
+    pub const MaxReferralsPerEpoch: u32 = 5;
+    pub const ProbationPeriod: BlockNumber = 14 * DAYS;
+    pub const FeelessReputationThreshold: i32 = 500;
+    pub const FeelessCallsPerEra: u32 = 10;
+    pub const FeelessEra: BlockNumber = 7 * DAYS;
+    pub const CommitteeVerificationThreshold: u8 = 90;
+    pub const MinCommitteeApprovals: u32 = 3;
+    pub const MaxCommitteeSize: u32 = 9;
+    pub const DividendPalletId: PalletId = PalletId(*b"dr/divdd");
+    pub const DividendBudget: Balance = 1_000 * UNIT;
+    pub const DividendEra: BlockNumber = 7 * DAYS;
+    pub const DividendEarningsCap: i32 = 200;
+    pub const VerificationDeadline: BlockNumber = 3 * DAYS;
+    pub const EscalationPeriod: BlockNumber = 4 * DAYS;
+    pub const StaleVerifierReward: i32 = 10;
+    pub const VerifierEra: BlockNumber = 7 * DAYS;
+    pub const ReminderLead: BlockNumber = DAYS;
+    pub const DisputeWindow: BlockNumber = 28 * DAYS;
+    pub const MaxDeadlinesPerBlock: u32 = 64;
+    pub const RejectionThreshold: u32 = 3;
+    pub const RejectedDepositSlash: Perbill = Perbill::from_percent(50);
+    pub const OutlierDeviation: u8 = 30;
+    pub const AccurateDeviation: u8 = 10;
+    pub const AccuracyBonus: i32 = 1;
+    pub const OutlierPenalty: i32 = 5;
+    pub const MaxOutlierStrikes: u32 = 5;
+    pub const MaxLeaveDuration: BlockNumber = 90 * DAYS;
+    pub const LeaveCooldown: BlockNumber = 365 * DAYS;
+    pub const MaxLeavesRecorded: u32 = 4;
+    pub const SnapshotEpoch: BlockNumber = DAYS;
+    pub const SnapshotTopK: u32 = 10;
+    pub const MaxSnapshotAccounts: u32 = 5_000;
+    pub const EvmAttestationValidity: u64 = 86_400;
+    pub const MaxGitLogEntries: u32 = 256;
+    pub const GitImportPointsPercent: Percent = Percent::from_percent(50);
+    pub const MaxImportBatch: u32 = 100;
+    pub const ImportedPointsPercent: Percent = Percent::from_percent(25);
+    pub const StarterGrantPeriod: BlockNumber = DAYS;
+    pub const MaxStarterGrantsPerPeriod: u32 = 50;
+    pub const DecaySweepAccountsPerBlock: u32 = 20;
+    pub const DecayWarningWindow: BlockNumber = 7 * DAYS;
+    pub const DecayWarningThreshold: Percent = Percent::from_percent(10);
+    pub const RemoteReputationTtl: BlockNumber = DAYS;
+    pub const ContributionDeposit: Balance = 10 * MILLIUNIT;
+    pub const MaintainerAttestationThreshold: u8 = 80;
+    pub const MaxCoAuthors: u32 = 8;
+    pub const ProjectClaimExpiry: BlockNumber = 7 * DAYS;
+}
+
+impl pallet_reputation::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type Time = Timestamp;
+    type WeightInfo = ();
+    type MaxContributionsPerAccount = MaxContributionsPerAccount;
+    type MinReputation = MinReputation;
+    type MaxReputation = MaxReputation;
+    type MinReputationToVerify = MinReputationToVerify;
+    type MinVerifications = MinVerifications;
+    type MaxPendingContributions = MaxPendingContributions;
+    type UpdateOrigin = EnsureRoot<AccountId>;
+    type PauseOrigin = EitherOf<EnsureRoot<AccountId>, EnsureCouncilMajority>;
+    type OnOffence = TrustLayer;
+    type CredibilityBoost = TrustLayer;
+    type MaxCredibilityBoost = MaxCredibilityBoost;
+    type MaxAttestationBoost = MaxAttestationBoost;
+    type ClaimUpheldReward = ClaimUpheldReward;
+    type ClaimRejectedPenalty = ClaimRejectedPenalty;
+    type ProxySubmissionPeriod = ProxySubmissionPeriod;
+    type MaintainerAttestationWeight = MaintainerAttestationWeight;
+    type MaintainerAttestationThreshold = MaintainerAttestationThreshold;
+    // Repository ownership is confirmed by the DKG publishers, who also attest GitHub handles
+    type OwnershipAuthorityId = dkg_integration::crypto::DkgPublisherId;
+    type OwnershipVerifierOrigin = EnsureSignedBy<DkgPublishers, AccountId>;
+    type ProjectClaimExpiry = ProjectClaimExpiry;
+    // Co-authors must have a publisher-attested GitHub handle
+    type Bindings = DkgIntegration;
+    type MaxCoAuthors = MaxCoAuthors;
+    type SkillMatchMultiplier = SkillMatchMultiplier;
+    type ContributionDeposit = ContributionDeposit;
+    type Disputes = TrustLayer;
+    type Dkg = DkgIntegration;
+    type MaxHackathonProjects = MaxHackathonProjects;
+    type MaxHackathonWinners = MaxHackathonWinners;
+    type MentorshipSessionWeight = MentorshipSessionWeight;
+    type ReferralBonus = ReferralBonus;
+    type ReferralQualifyingContributions = ReferralQualifyingContributions;
+    type ReferralEpoch = ReferralEpoch;
+    type MaxReferralsPerEpoch = MaxReferralsPerEpoch;
+    type ProbationPeriod = ProbationPeriod;
+    type FeelessReputationThreshold = FeelessReputationThreshold;
+    type FeelessCallsPerEra = FeelessCallsPerEra;
+    type FeelessEra = FeelessEra;
+    type CommitteeVerificationThreshold = CommitteeVerificationThreshold;
+    type MinCommitteeApprovals = MinCommitteeApprovals;
+    type MaxCommitteeSize = MaxCommitteeSize;
+    type DividendPalletId = DividendPalletId;
+    type DividendBudget = DividendBudget;
+    type DividendEra = DividendEra;
+    type DividendEarningsCap = DividendEarningsCap;
+    type VerificationDeadline = VerificationDeadline;
+    type EscalationPeriod = EscalationPeriod;
+    type StaleVerifierReward = StaleVerifierReward;
+    type VerifierEra = VerifierEra;
+    type ReminderLead = ReminderLead;
+    type DisputeWindow = DisputeWindow;
+    type MaxDeadlinesPerBlock = MaxDeadlinesPerBlock;
+    type RejectionThreshold = RejectionThreshold;
+    type RejectedDepositSlash = RejectedDepositSlash;
+    // Slashed deposits are burned until a treasury is added
+    type Slash = ();
+    type OutlierDeviation = OutlierDeviation;
+    type AccurateDeviation = AccurateDeviation;
+    type AccuracyBonus = AccuracyBonus;
+    type OutlierPenalty = OutlierPenalty;
+    type MaxOutlierStrikes = MaxOutlierStrikes;
+    type MaxLeaveDuration = MaxLeaveDuration;
+    type LeaveCooldown = LeaveCooldown;
+    type MaxLeavesRecorded = MaxLeavesRecorded;
+    type SnapshotEpoch = SnapshotEpoch;
+    type SnapshotTopK = SnapshotTopK;
+    type MaxSnapshotAccounts = MaxSnapshotAccounts;
+    type EvmAttestationAuthorityId = dkg_integration::crypto::DkgPublisherId;
+    type EvmAttestationValidity = EvmAttestationValidity;
+    type MaxGitLogEntries = MaxGitLogEntries;
+    type GitImportPointsPercent = GitImportPointsPercent;
+    type MaxImportBatch = MaxImportBatch;
+    type ImportedPointsPercent = ImportedPointsPercent;
+    type Personhood = IdentityPersonhood;
+    type StarterGrantPeriod = StarterGrantPeriod;
+    type MaxStarterGrantsPerPeriod = MaxStarterGrantsPerPeriod;
+    type DecaySweepAccountsPerBlock = DecaySweepAccountsPerBlock;
+    type DecayWarningWindow = DecayWarningWindow;
+    type DecayWarningThreshold = DecayWarningThreshold;
+    type RemoteReputationTtl = RemoteReputationTtl;
+    // The runtime has no XCM transport yet; link proofs are submitted by attestors
+    type ChainOrigin = frame_system::EnsureNever<pallet_reputation::ChainId>;
+}
+
+parameter_types! {
+    pub const MaxPublishesPerBlock: u32 = 10;
+    pub const MaxPublishAttempts: u32 = 5;
+    pub const RetryBackoff: BlockNumber = 10;
+}
+
+impl dkg_integration::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type AuthorityId = dkg_integration::crypto::DkgPublisherId;
+    type PublisherOrigin = EnsureSignedBy<DkgPublishers, AccountId>;
+    type MaxPublishesPerBlock = MaxPublishesPerBlock;
+    type Snapshot = Reputation;
+    type Reputation = Reputation;
+    type UpdateOrigin = EnsureRoot<AccountId>;
+    type MaxPublishAttempts = MaxPublishAttempts;
+    type RetryBackoff = RetryBackoff;
+}
+
+parameter_types! {
+    pub const MinProposalReputation: i32 = 500;
+    pub const ProposalDeposit: Balance = 100 * UNIT;
+    pub const VotingPeriod: BlockNumber = 7 * DAYS;
+    pub const CouncilSize: u32 = 7;
+    pub const QuorumThreshold: u8 = 10;
+    pub const SupermajorityThreshold: u8 = 66;
+    pub const ExecutionDelayPeriod: BlockNumber = 2 * DAYS;
+    pub const MinVoteChangePeriod: BlockNumber = HOURS;
+    pub const GrantsPalletId: PalletId = PalletId(*b"dr/grant");
+    pub const MaxMilestones: u32 = 10;
+    pub const MilestoneContributionWeight: u8 = 100;
+    pub const MaxDelegationsPerDelegatee: u32 = 100;
+    pub const CouncilSalaryPerBlock: Balance = MILLIUNIT;
+    pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
+    pub TreasuryAccount: AccountId = TreasuryPalletId::get().into_account_truncating();
+    pub const MinCouncilVotesPerTerm: u32 = 3;
+    pub const ProposalRetentionPeriod: BlockNumber = 30 * DAYS;
+    pub const ProposalCleanupReward: Balance = 10 * MILLIUNIT;
+    pub const MaxVotesPrunedPerCleanup: u32 = 500;
+    pub const EndorsementReputation: i32 = 750;
+    pub const RequiredEndorsements: u32 = 3;
+    pub const EndorsementDeposit: Balance = 10 * UNIT;
+    pub const PendingProposalExpiry: BlockNumber = 14 * DAYS;
+}
+
+impl pallet_governance::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type Reputation = Reputation;
+    type MinProposalReputation = MinProposalReputation;
+    type ProposalDeposit = ProposalDeposit;
+    type VotingPeriod = VotingPeriod;
+    type CouncilSize = CouncilSize;
+    type QuorumThreshold = QuorumThreshold;
+    type SupermajorityThreshold = SupermajorityThreshold;
+    type ExecutionDelayPeriod = ExecutionDelayPeriod;
+    type MinVoteChangePeriod = MinVoteChangePeriod;
+    type OnSkillTagsUpdated = Reputation;
+    type Contributions = Reputation;
+    type PalletId = GrantsPalletId;
+    type MaxMilestones = MaxMilestones;
+    type MilestoneContributionWeight = MilestoneContributionWeight;
+    type MaxDelegationsPerDelegatee = MaxDelegationsPerDelegatee;
+    type CustomProposalHandler = (ParanetProposals,);
+    type CouncilSalaryPerBlock = CouncilSalaryPerBlock;
+    type Treasury = TreasuryAccount;
+    type MinCouncilVotesPerTerm = MinCouncilVotesPerTerm;
+    type TemplateOrigin = EnsureRoot<AccountId>;
+    type TrackOrigin = EnsureRoot<AccountId>;
+    type ProposalRetentionPeriod = ProposalRetentionPeriod;
+    type ProposalCleanupReward = ProposalCleanupReward;
+    type MaxVotesPrunedPerCleanup = MaxVotesPrunedPerCleanup;
+    type EndorsementReputation = EndorsementReputation;
+    type RequiredEndorsements = RequiredEndorsements;
+    type EndorsementDeposit = EndorsementDeposit;
+    type PendingProposalExpiry = PendingProposalExpiry;
+    type SpamOrigin = EitherOf<EnsureRoot<AccountId>, EnsureCouncilMajority>;
+    type HookQuotas = Reputation;
+    type CouncilMembershipChanged = Council;
+}
+
+/// Executes `paranet` proposals: a SCALE-encoded `register_paranet` or
+/// `add_paranet_member` call of the DKG integration pallet, dispatched as root
+pub struct ParanetProposals;
+impl dotrep_primitives::CustomProposalHandler for ParanetProposals {
+    fn handles(tag: &[u8]) -> bool {
+        tag == b"paranet"
+    }
+
+    fn max_weight() -> Weight {
+        // Both calls declare a flat weight
+        Weight::from_parts(10_000, 0)
+    }
+
+    fn execute(_tag: &[u8], data: &[u8]) -> DispatchResult {
+        let call = dkg_integration::Call::<Runtime>::decode_all(&mut &data[..])
+            .map_err(|_| DispatchError::Other("Undecodable paranet proposal"))?;
+        ensure!(
+            matches!(
+                call,
+                dkg_integration::Call::register_paranet { .. } | dkg_integration::Call::add_paranet_member { .. }
+            ),
+            DispatchError::Other("Not a paranet call")
+        );
+        ensure!(
+            call.get_dispatch_info().weight.all_lte(Self::max_weight()),
+            DispatchError::Other("Paranet call exceeds the proposal weight")
+        );
+        call.dispatch_bypass_filter(RuntimeOrigin::root()).map(|_| ()).map_err(|e| e.error)
+    }
+}
+
+/// An account owns the UALs of its published reputation asset and DID document
+pub struct DkgUalOwnership;
+impl pallet_trust_layer::UalOwnership<AccountId> for DkgUalOwnership {
+    fn owns_ual(who: &AccountId, ual: &[u8]) -> bool {
+        DkgIntegration::developer_ual(who).map_or(false, |owned| owned[..] == *ual)
+            || DkgIntegration::did_document_ual(who).map_or(false, |owned| owned[..] == *ual)
+    }
+}
+
+parameter_types! {
+    pub const MinimumStake: Balance = 100 * UNIT;
+    pub const BaseQueryPrice: Balance = 10 * MILLIUNIT;
+    pub const ProviderShare: Perbill = Perbill::from_percent(70);
+    pub const TrustLayerPalletId: PalletId = PalletId(*b"dr/trust");
+    pub const OffenceSlash: Perbill = Perbill::from_percent(10);
+    pub const JurySize: u32 = 5;
+    pub const MaxJurySize: u32 = 21;
+    pub const MaxAppealRounds: u32 = 2;
+    pub const AppealPeriod: BlockNumber = DAYS;
+    pub const MaxEvidenceItems: u32 = 16;
+    pub const MaxClaimsPerSubmitter: u32 = 32;
+    pub const MaxClaimsSweptPerBlock: u32 = 20;
+    pub const MaxQueryBatch: u32 = 50;
+    pub const BatchDiscount: Perbill = Perbill::from_percent(10);
+    pub const MeteredQueryFee: Balance = MILLIUNIT;
+    pub const MaxInvoiceItems: u32 = 256;
+    pub const BillingPeriod: BlockNumber = 30 * DAYS;
+    // Two years of monthly statements
+    pub const BillingStatementRetention: u32 = 24;
+    pub const WinnerShare: Perbill = Perbill::from_percent(50);
+    pub const MaxJurorPool: u32 = 500;
+    pub const MinJurorReputation: i32 = 300;
+    pub const JurorStake: Balance = 10 * UNIT;
+    pub const JuryVotingPeriod: BlockNumber = 3 * DAYS;
+    pub const JurySeedPeriod: BlockNumber = HOURS;
+    pub const JurorSlash: Perbill = Perbill::from_percent(20);
+    pub const BackerShare: Perbill = Perbill::from_percent(10);
+    pub const MaxBackers: u32 = 64;
+    pub const FraudReportWindow: BlockNumber = 7 * DAYS;
+}
+
+impl pallet_trust_layer::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type MinimumStake = MinimumStake;
+    type BaseQueryPrice = BaseQueryPrice;
+    type UalOwnership = DkgUalOwnership;
+    type ProviderShare = ProviderShare;
+    type AssetId = AssetId;
+    type Assets = Assets;
+    type PalletId = TrustLayerPalletId;
+    type OffenceSlash = OffenceSlash;
+    type Reputation = Reputation;
+    type OnClaimResolved = Reputation;
+    type Contributions = Reputation;
+    type FraudReportWindow = FraudReportWindow;
+    type HookQuotas = Reputation;
+    type Time = Timestamp;
+    type Randomness = RandomnessCollectiveFlip;
+    type JurySize = JurySize;
+    type MaxJurySize = MaxJurySize;
+    type MaxAppealRounds = MaxAppealRounds;
+    type AppealPeriod = AppealPeriod;
+    type MaxEvidenceItems = MaxEvidenceItems;
+    type MaxClaimsPerSubmitter = MaxClaimsPerSubmitter;
+    type MaxClaimsSweptPerBlock = MaxClaimsSweptPerBlock;
+    type MaxQueryBatch = MaxQueryBatch;
+    type BatchDiscount = BatchDiscount;
+    type MeteredQueryFee = MeteredQueryFee;
+    type MaxInvoiceItems = MaxInvoiceItems;
+    type BillingPeriod = BillingPeriod;
+    type BillingStatementRetention = BillingStatementRetention;
+    // Forfeited stakes are burned until a treasury is added
+    type Slash = ();
+    type WinnerShare = WinnerShare;
+    type MaxJurorPool = MaxJurorPool;
+    type MinJurorReputation = MinJurorReputation;
+    type JurorStake = JurorStake;
+    type JuryVotingPeriod = JuryVotingPeriod;
+    type JurySeedPeriod = JurySeedPeriod;
+    type JurorSlash = JurorSlash;
+    type BackerShare = BackerShare;
+    type MaxBackers = MaxBackers;
+    type WeightInfo = pallet_trust_layer::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = TrustLayerBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TrustLayerBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_trust_layer::BenchmarkHelper<AssetId, AccountId, Balance> for TrustLayerBenchmarkHelper {
+    fn funded_asset(who: &AccountId, amount: Balance) -> AssetId {
+        use frame_support::traits::fungibles::{Create, Inspect, Mutate};
+
+        if !Assets::asset_exists(TRAC) {
+            <Assets as Create<AccountId>>::create(TRAC, who.clone(), true, 1).expect("TRAC can be created");
+        }
+        <Assets as Mutate<AccountId>>::mint_into(TRAC, who, amount).expect("TRAC exists");
+        TRAC
+    }
+
+    fn owned_ual(who: &AccountId) -> Vec<u8> {
+        let mut ual = b"did:dkg:otp/2043/0x".to_vec();
+        ual.extend(who.encode().iter().take(8).map(|byte| b'a' + byte % 26));
+        dkg_integration::DeveloperUAL::<Runtime>::insert(
+            who,
+            frame_support::BoundedVec::truncate_from(ual.clone()),
+        );
+        ual
+    }
+
+    fn qualify_juror(who: &AccountId) {
+        pallet_reputation::ReputationScores::<Runtime>::insert(who, MinJurorReputation::get());
+    }
+
+    fn verified_contribution(contributor: &AccountId) -> pallet_reputation::ContributionId {
+        use pallet_reputation::{Contribution, ContributionStatus, ContributionType, DataSource};
+
+        let id = pallet_reputation::NextContributionId::<Runtime>::mutate(|next| {
+            let id = *next;
+            *next = next.saturating_add(1);
+            id
+        });
+        let proof = H256::from_low_u64_be(id);
+        pallet_reputation::Contributions::<Runtime>::insert(id, Contribution {
+            id,
+            proof,
+            contribution_type: ContributionType::PullRequest,
+            weight: 50,
+            verified: true,
+            source: DataSource::GitHub,
+            timestamp: System::block_number(),
+            status: ContributionStatus::Verified,
+            verification_count: MinVerifications::get(),
+        });
+        pallet_reputation::ContributionProofs::<Runtime>::insert(proof, contributor);
+        dkg_integration::ContributionUAL::<Runtime>::insert(
+            id,
+            frame_support::BoundedVec::truncate_from(b"did:dkg:otp/2043/0xc0".to_vec()),
+        );
+        id
+    }
+}
+
+impl frame_system::offchain::SigningTypes for Runtime {
+    type Public = <Signature as Verify>::Signer;
+    type Signature = Signature;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+    RuntimeCall: From<C>,
+{
+    type Extrinsic = UncheckedExtrinsic;
+    type OverarchingCall = RuntimeCall;
+}
+
+// Lets the DKG off-chain worker submit signed publisher reports
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Runtime
+where
+    RuntimeCall: From<LocalCall>,
+{
+    fn create_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+        call: RuntimeCall,
+        public: <Signature as Verify>::Signer,
+        account: AccountId,
+        nonce: Index,
+    ) -> Option<(RuntimeCall, <UncheckedExtrinsic as sp_runtime::traits::Extrinsic>::SignaturePayload)> {
+        let period = BlockHashCount::get().checked_next_power_of_two().map(|c| c / 2).unwrap_or(2) as u64;
+        let current_block = System::block_number().saturated_into::<u64>().saturating_sub(1);
+        let extra: SignedExtra = (
+            frame_system::CheckNonZeroSender::<Runtime>::new(),
+            frame_system::CheckSpecVersion::<Runtime>::new(),
+            frame_system::CheckTxVersion::<Runtime>::new(),
+            frame_system::CheckGenesis::<Runtime>::new(),
+            frame_system::CheckEra::<Runtime>::from(generic::Era::mortal(period, current_block)),
+            frame_system::CheckNonce::<Runtime>::from(nonce),
+            frame_system::CheckWeight::<Runtime>::new(),
+            pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(0),
+        );
+        let raw_payload = SignedPayload::new(call, extra)
+            .map_err(|e| log::warn!("Unable to create signed payload: {:?}", e))
+            .ok()?;
+        let signature = raw_payload.using_encoded(|payload| C::sign(payload, public))?;
+        let address = <Runtime as frame_system::Config>::Lookup::unlookup(account);
+        let (call, extra, _) = raw_payload.deconstruct();
+        Some((call, (address, signature, extra)))
+    }
+}
+
+// Create the runtime by composing the FRAME pallets that were previously configured.
+construct_runtime!(
+    pub struct Runtime
+    where
+        Block = Block,
+        NodeBlock = opaque::Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system,
+        RandomnessCollectiveFlip: pallet_insecure_randomness_collective_flip,
+        Timestamp: pallet_timestamp,
+        Aura: pallet_aura,
+        Grandpa: pallet_grandpa,
+        Balances: pallet_balances,
+        TransactionPayment: pallet_transaction_payment,
+        Sudo: pallet_sudo,
+        Assets: pallet_assets,
+        DkgPublishers: pallet_membership::<Instance1>,
+        Council: pallet_collective::<Instance1>,
+        Identity: pallet_identity,
+        Contracts: pallet_contracts,
+
+        // DotRep
+        Reputation: pallet_reputation,
+        DkgIntegration: dkg_integration,
+        Governance: pallet_governance,
+        TrustLayer: pallet_trust_layer,
+    }
+);
+
+/// The address format for describing accounts.
+pub type Address = sp_runtime::MultiAddress<AccountId, ()>;
+/// Block header type as expected by this runtime.
+pub type Header = generic::Header<BlockNumber, BlakeTwo256>;
+/// Block type as expected by this runtime.
+pub type Block = generic::Block<Header, UncheckedExtrinsic>;
+/// The SignedExtension to the basic transaction logic.
+pub type SignedExtra = (
+    frame_system::CheckNonZeroSender<Runtime>,
+    frame_system::CheckSpecVersion<Runtime>,
+    frame_system::CheckTxVersion<Runtime>,
+    frame_system::CheckGenesis<Runtime>,
+    frame_system::CheckEra<Runtime>,
+    frame_system::CheckNonce<Runtime>,
+    frame_system::CheckWeight<Runtime>,
+    pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+);
+/// Unchecked extrinsic type as expected by this runtime.
+pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
+/// The payload being signed in transactions.
+pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
+/// Storage migrations applied on runtime upgrade.
+///
+/// Claim and delegation migrations run over several blocks; claim, delegation and voting
+/// calls are rejected until they finish.
+pub type Migrations = (
+    pallet_reputation::migrations::v1::MigrateToV1<Runtime>,
+    pallet_reputation::migrations::v2::MigrateToV2<Runtime>,
+    pallet_reputation::migrations::v3::MigrateToV3<Runtime>,
+    pallet_governance::migrations::v1::MigrateToV1<Runtime>,
+    pallet_trust_layer::migrations::MigrateMultiBlock<Runtime>,
+);
+/// Executive: handles dispatch to the various modules.
+pub type Executive = frame_executive::Executive<
+    Runtime,
+    Block,
+    frame_system::ChainContext<Runtime>,
+    Runtime,
+    AllPalletsWithSystem,
+    Migrations,
+>;
+
+#[cfg(feature = "runtime-benchmarks")]
+#[macro_use]
+extern crate frame_benchmarking;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benches {
+    define_benchmarks!(
+        [frame_benchmarking, BaselineBench::<Runtime>]
+        [frame_system, SystemBench::<Runtime>]
+        [pallet_balances, Balances]
+        [pallet_governance, Governance]
+        [pallet_reputation, Reputation]
+        [pallet_trust_layer, TrustLayer]
+    );
+}
+
+impl_runtime_apis! {
+    impl sp_api::Core<Block> for Runtime {
+        fn version() -> RuntimeVersion {
+            VERSION
+        }
+
+        fn execute_block(block: Block) {
+            Executive::execute_block(block);
+        }
+
+        fn initialize_block(header: &<Block as BlockT>::Header) {
+            Executive::initialize_block(header)
+        }
+    }
+
+    impl sp_api::Metadata<Block> for Runtime {
+        fn metadata() -> OpaqueMetadata {
+            OpaqueMetadata::new(Runtime::metadata().into())
+        }
+    }
+
+    impl sp_block_builder::BlockBuilder<Block> for Runtime {
+        fn apply_extrinsic(extrinsic: <Block as BlockT>::Extrinsic) -> ApplyExtrinsicResult {
+            Executive::apply_extrinsic(extrinsic)
+        }
+
+        fn finalize_block() -> <Block as BlockT>::Header {
+            Executive::finalize_block()
+        }
+
+        fn inherent_extrinsics(data: sp_inherents::InherentData) -> Vec<<Block as BlockT>::Extrinsic> {
+            data.create_extrinsics()
+        }
+
+        fn check_inherents(
+            block: Block,
+            data: sp_inherents::InherentData,
+        ) -> sp_inherents::CheckInherentsResult {
+            data.check_extrinsics(&block)
+        }
+    }
+
+    impl sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block> for Runtime {
+        fn validate_transaction(
+            source: TransactionSource,
+            tx: <Block as BlockT>::Extrinsic,
+            block_hash: <Block as BlockT>::Hash,
+        ) -> TransactionValidity {
+            Executive::validate_transaction(source, tx, block_hash)
+        }
+    }
+
+    impl sp_offchain::OffchainWorkerApi<Block> for Runtime {
+        fn offchain_worker(header: &<Block as BlockT>::Header) {
+            Executive::offchain_worker(header)
+        }
+    }
+
+    impl sp_consensus_aura::AuraApi<Block, AuraId> for Runtime {
+        fn slot_duration() -> sp_consensus_aura::SlotDuration {
+            sp_consensus_aura::SlotDuration::from_millis(Aura::slot_duration())
+        }
+
+        fn authorities() -> Vec<AuraId> {
+            Aura::authorities().into_inner()
+        }
+    }
+
+    impl sp_session::SessionKeys<Block> for Runtime {
+        fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
+            opaque::SessionKeys::generate(seed)
+        }
+
+        fn decode_session_keys(
+            encoded: Vec<u8>,
+        ) -> Option<Vec<(Vec<u8>, KeyTypeId)>> {
+            opaque::SessionKeys::decode_into_raw_public_keys(&encoded)
+        }
+    }
+
+    impl sp_consensus_grandpa::GrandpaApi<Block> for Runtime {
+        fn grandpa_authorities() -> sp_consensus_grandpa::AuthorityList {
+            Grandpa::grandpa_authorities()
+        }
+
+        fn current_set_id() -> sp_consensus_grandpa::SetId {
+            Grandpa::current_set_id()
+        }
+
+        fn submit_report_equivocation_unsigned_extrinsic(
+            _equivocation_proof: sp_consensus_grandpa::EquivocationProof<
+                <Block as BlockT>::Hash,
+                NumberFor<Block>,
+            >,
+            _key_owner_proof: sp_consensus_grandpa::OpaqueKeyOwnershipProof,
+        ) -> Option<()> {
+            None
+        }
+
+        fn generate_key_ownership_proof(
+            _set_id: sp_consensus_grandpa::SetId,
+            _authority_id: GrandpaId,
+        ) -> Option<sp_consensus_grandpa::OpaqueKeyOwnershipProof> {
+            // NOTE: this is the only implementation possible since we've
+            // defined our key owner proof type as a bottom type (i.e. a type
+            // with no values).
+            None
+        }
+    }
+
+    impl frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Index> for Runtime {
+        fn account_nonce(account: AccountId) -> Index {
+            System::account_nonce(account)
+        }
+    }
+
+    impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
+        fn query_info(
+            uxt: <Block as BlockT>::Extrinsic,
+            len: u32,
+        ) -> pallet_transaction_payment_rpc_runtime_api::RuntimeDispatchInfo<Balance> {
+            TransactionPayment::query_info(uxt, len)
+        }
+
+        fn query_fee_details(
+            uxt: <Block as BlockT>::Extrinsic,
+            len: u32,
+        ) -> pallet_transaction_payment::FeeDetails<Balance> {
+            TransactionPayment::query_fee_details(uxt, len)
+        }
+
+        fn query_weight_to_fee(weight: Weight) -> Balance {
+            TransactionPayment::weight_to_fee(weight)
+        }
+
+        fn query_length_to_fee(length: u32) -> Balance {
+            TransactionPayment::length_to_fee(length)
+        }
+    }
+
+    impl pallet_reputation::runtime_api::WorkHistoryApi<Block, AccountId, BlockNumber> for Runtime {
+        fn work_history(
+            account: AccountId,
+            contribution_type: Option<pallet_reputation::ContributionType>,
+            from: BlockNumber,
+            to: BlockNumber,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<pallet_reputation::WorkHistoryEntry<AccountId, BlockNumber>> {
+            Reputation::work_history(&account, None, contribution_type, from, to, offset, limit)
+        }
+    }
+
+    impl pallet_reputation::runtime_api::DecayApi<Block, AccountId, BlockNumber> for Runtime {
+        fn decay_warning(account: AccountId) -> Option<pallet_reputation::DecayWarning<BlockNumber>> {
+            Reputation::decay_warning(&account)
+        }
+    }
+
+    impl pallet_reputation::runtime_api::ActivityApi<Block, AccountId, BlockNumber> for Runtime {
+        fn activity_stats(account: AccountId) -> pallet_reputation::ActivityStats<BlockNumber> {
+            Reputation::activity_stats(&account)
+        }
+    }
+
+    impl pallet_reputation::runtime_api::VerifierPoolApi<Block, AccountId, BlockNumber> for Runtime {
+        fn verifier_availability(account: AccountId) -> Option<pallet_reputation::VerifierAvailability> {
+            Reputation::verifier_availability(account)
+        }
+
+        fn expected_review_latency(contribution_id: pallet_reputation::ContributionId) -> Option<BlockNumber> {
+            Reputation::expected_review_latency(contribution_id)
+        }
+
+        fn reminder_queue(
+            from: BlockNumber,
+            limit: u32,
+        ) -> (Vec<(pallet_reputation::ContributionId, BlockNumber)>, Option<BlockNumber>) {
+            Reputation::reminder_queue(from, limit)
+        }
+    }
+
+    impl pallet_trust_layer::runtime_api::RevenueApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+        fn ual_revenue(ual: Vec<u8>) -> Balance {
+            dotrep_primitives::Ual::try_from(ual)
+                .map(TrustLayer::ual_revenue)
+                .unwrap_or_default()
+        }
+
+        fn provider_revenue(account: AccountId) -> Balance {
+            TrustLayer::provider_revenue(account)
+        }
+
+        fn treasury_income(from: BlockNumber, to: BlockNumber) -> Option<Balance> {
+            TrustLayer::treasury_income(from, to)
+        }
+    }
+
+    impl pallet_governance::runtime_api::DelegationApi<Block, AccountId> for Runtime {
+        fn delegation_graph(limit: u32) -> Vec<pallet_governance::DelegationEdge<AccountId>> {
+            Governance::delegation_graph(limit)
+        }
+
+        fn top_delegatees(
+            cursor: Option<Vec<u8>>,
+            limit: u32,
+        ) -> Result<(Vec<(AccountId, pallet_governance::VotePower)>, Option<Vec<u8>>), sp_runtime::DispatchError> {
+            Governance::top_delegatees(cursor, limit)
+        }
+
+        fn effective_power(account: AccountId) -> Result<pallet_governance::VotePower, sp_runtime::DispatchError> {
+            Governance::effective_power(&account)
+        }
+    }
+
+    impl pallet_reputation::runtime_api::DkgQueryApi<Block, AccountId, BlockNumber> for Runtime {
+        fn developer_ual(account: AccountId) -> Option<Vec<u8>> {
+            DkgIntegration::get_developer_ual(&account)
+        }
+
+        fn contribution_ual(contribution_id: pallet_reputation::ContributionId) -> Option<Vec<u8>> {
+            DkgIntegration::get_contribution_ual(contribution_id)
+        }
+
+        fn anchored_assertions(from: BlockNumber, to: BlockNumber, limit: u32) -> Vec<(Vec<u8>, H256, BlockNumber)> {
+            DkgIntegration::anchored_between(from, to, limit)
+        }
+
+        fn publish_status(account: AccountId) -> Option<dkg_integration::PublishStatus> {
+            DkgIntegration::publish_status(account)
+        }
+
+        fn dkg_assets(account: AccountId) -> dkg_integration::DkgAssets<BlockNumber> {
+            DkgIntegration::dkg_assets(&account)
+        }
+    }
+
+    impl pallet_reputation::runtime_api::ReputationApi<Block, AccountId> for Runtime {
+        fn reputation_of(account: AccountId) -> i32 {
+            Reputation::aggregate_reputation(&account)
+        }
+
+        fn eligible_verifiers(contribution_id: pallet_reputation::ContributionId, limit: u32) -> Vec<AccountId> {
+            Reputation::eligible_verifiers(contribution_id, limit)
+        }
+
+        fn reputation_breakdown(account: AccountId) -> Option<pallet_reputation::ReputationBreakdown> {
+            Reputation::reputation_breakdown(&account, None)
+        }
+
+        fn algorithm_version() -> pallet_reputation::AlgorithmVersionInfo {
+            Reputation::algorithm_version_info()
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    impl frame_benchmarking::Benchmark<Block> for Runtime {
+        fn benchmark_metadata(extra: bool) -> (
+            Vec<frame_benchmarking::BenchmarkList>,
+            Vec<frame_support::traits::StorageInfo>,
+        ) {
+            use frame_benchmarking::{baseline, Benchmarking, BenchmarkList};
+            use frame_support::traits::StorageInfoTrait;
+            use frame_system_benchmarking::Pallet as SystemBench;
+            use baseline::Pallet as BaselineBench;
+
+            let mut list = Vec::<BenchmarkList>::new();
+            list_benchmarks!(list, extra);
+
+            let storage_info = AllPalletsWithSystem::storage_info();
+
+            (list, storage_info)
+        }
+
+        fn dispatch_benchmark(
+            config: frame_benchmarking::BenchmarkConfig
+        ) -> Result<Vec<frame_benchmarking::BenchmarkBatch>, sp_runtime::RuntimeString> {
+            use frame_benchmarking::{baseline, Benchmarking, BenchmarkBatch, TrackedStorageKey};
+            use frame_system_benchmarking::Pallet as SystemBench;
+            use baseline::Pallet as BaselineBench;
+
+            impl frame_system_benchmarking::Config for Runtime {}
+            impl baseline::Config for Runtime {}
+
+            use frame_support::traits::WhitelistedStorageKeys;
+            let whitelist: Vec<TrackedStorageKey> = AllPalletsWithSystem::whitelisted_storage_keys();
+
+            let mut batches = Vec::<BenchmarkBatch>::new();
+            let params = (&config, &whitelist);
+            add_benchmarks!(params, batches);
+
+            Ok(batches)
+        }
+    }
+
+    #[cfg(feature = "try-runtime")]
+    impl frame_try_runtime::TryRuntime<Block> for Runtime {
+        fn on_runtime_upgrade(checks: frame_try_runtime::UpgradeCheckSelect) -> (Weight, Weight) {
+            let weight = Executive::try_runtime_upgrade(checks).unwrap();
+            (weight, BlockWeights::get().max_block)
+        }
+
+        fn execute_block(
+            block: Block,
+            state_root_check: bool,
+            signature_check: bool,
+            select: frame_try_runtime::TryStateSelect
+        ) -> Weight {
+            Executive::try_execute_block(block, state_root_check, signature_check, select).expect("execute-block failed")
+        }
+    }
+}